@@ -65,19 +65,80 @@ const UI_SFX_COLLECTION_PATHS: [&str; 14] = [
 /// they move together). `key_glyph_collection_matches_mapping_table` pins this
 /// against `nova_hud::key_glyphs`'s mapping table, which OWNS the set.
 #[cfg(test)]
-const KEY_GLYPH_COLLECTION_PATHS: [&str; 13] = [
+const KEY_GLYPH_COLLECTION_PATHS: [&str; 74] = [
+    "input-prompts/keyboard/Alt/T_0_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_1_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_2_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_3_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_5_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_6_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_7_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_8_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_9_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_A_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Alt_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_B_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_BackSpace_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Brackets_L_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Brackets_R_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_C_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_CapsLock_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Crtl_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_D_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Del_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Down_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_E_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_End_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Enter_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Esc_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F10_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F11_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F12_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F1_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F2_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F3_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F4_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F5_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F6_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F7_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F8_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F9_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_F_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_G_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_H_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Home_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_I_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Ins_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_J_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_K_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_L_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Left_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_M_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Minus_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Mouse_Scroll_Key_Dark_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_N_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_O_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_P_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_PageDown_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_PageUp_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Q_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Quotation_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_R_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Right_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_S_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Semicolon_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Shift_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Slash_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Space_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_T_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Tab_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Tilde_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_U_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Up_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_V_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_W_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_X_Key_Alt.png",
+    "input-prompts/keyboard/Alt/T_Y_Key_Alt.png",
     "input-prompts/keyboard/Alt/T_Z_Key_Alt.png",
 ];
 
@@ -170,18 +231,79 @@ pub struct GameAssets {
     /// is not used because folder collections do not work on wasm.
     #[asset(
         paths(
+            "input-prompts/keyboard/Alt/T_0_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_1_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_2_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_3_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_5_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_6_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_7_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_8_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_9_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_A_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Alt_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_B_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_BackSpace_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Brackets_L_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Brackets_R_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_C_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_CapsLock_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Crtl_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_D_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Del_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Down_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_E_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_End_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Enter_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Esc_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F10_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F11_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F12_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F1_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F2_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F3_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F4_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F5_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F6_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F7_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F8_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F9_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_F_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_G_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_H_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Home_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_I_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Ins_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_J_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_K_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_L_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Left_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_M_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Minus_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Mouse_Scroll_Key_Dark_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_N_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_O_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_P_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_PageDown_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_PageUp_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Q_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Quotation_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_R_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Right_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_S_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Semicolon_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Shift_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Slash_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Space_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_T_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Tab_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Tilde_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_U_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Up_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_V_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_W_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_X_Key_Alt.png",
+            "input-prompts/keyboard/Alt/T_Y_Key_Alt.png",
             "input-prompts/keyboard/Alt/T_Z_Key_Alt.png",
         ),
         collection(mapped, typed)
@@ -26,6 +26,7 @@ mod plugin;
 pub mod loose;
 pub mod mod_cache;
 pub mod mod_prefs;
+pub mod mod_profiles;
 pub mod mod_refs;
 pub mod persist;
 pub mod portal;
@@ -47,13 +48,14 @@ pub mod prelude {
     pub use nova_modding::prelude::ModMeta;
 
     pub use super::{
+        mod_profiles::{LaunchModProfile, ModProfile, ModProfiles},
         portal::{
             FetchPortalCatalog, InstallJobs, InstallPortalMod, InstallStatus, PendingRemovals,
             PortalConfig, PortalFetchTimeout, RemoteCatalog, RemoteCatalogState,
             UninstallPortalMod,
         },
         DownloadedMod, DownloadedMods, EnabledMods, GameAssets, GameAssetsPlugin, GameAssetsStates,
-        ModCatalog, ModInfo,
+        MergedMods, ModCatalog, ModInfo,
     };
 }
 
@@ -1,6 +1,6 @@
 //! The content MERGE: flatten every enabled bundle's `Content` in dependency
 //! order and overlay it by id into the game's registries (`GameSections`,
-//! `GameShips`, `GameScenarios`, `GameCampaigns`, `GameStyles`), linting the
+//! `GameShips`, `GameScenarios`, `GameCampaigns`, `GameStyles`, `GameMusic`), linting the
 //! result as it goes.
 
 /// Glob-import surface: `use nova_assets::merge::prelude::*` re-exports the
@@ -12,14 +12,16 @@ pub mod prelude {
 use std::collections::{HashMap, HashSet};
 
 use bevy::prelude::*;
+use nova_gameplay::prelude::{GameMusic, MusicTrackConfig};
 use nova_modding::prelude::{BundleAsset, Content, ContentAsset, InstalledCatalog, BASE_MOD_ID};
 use nova_scenario::prelude::{GameCampaigns, GameScenarios, GameShips, NewGameStart, ShipConfig};
 use nova_ship::prelude::*;
 
 use crate::{
     collections::GameAssets,
+    mod_profiles::{apply_profile_order, ModProfiles},
     mod_refs,
-    mod_set::{DownloadedMods, EnabledMods},
+    mod_set::{DownloadedMods, EnabledMods, MergedMods},
 };
 
 /// Route every ENABLED cataloged bundle's content into the id-keyed game registries,
@@ -46,11 +48,16 @@ use crate::{
 /// [`mark_downloaded_bundles_loaded`](crate::mark_downloaded_bundles_loaded)
 /// re-triggers this system when the load lands, and a `DownloadedMods` change
 /// (install/uninstall) re-triggers it too.
+///
+/// The active [`ModProfiles`] entry's explicit order permutes the input before
+/// the dependency sort ([`apply_profile_order`]), so it decides the overlay
+/// between INDEPENDENT mods only; optional so the slim rigs run without it.
 pub fn register_bundles(
     mut commands: Commands,
     game_assets: Res<GameAssets>,
     enabled: Res<EnabledMods>,
     downloaded: Res<DownloadedMods>,
+    profiles: Option<Res<ModProfiles>>,
     catalogs: Res<Assets<InstalledCatalog>>,
     bundles: Res<Assets<BundleAsset>>,
     contents: Res<Assets<ContentAsset>>,
@@ -125,6 +132,12 @@ pub fn register_bundles(
         })
         .collect();
     let ids: Vec<String> = ordered.iter().map(|(id, _)| id.to_string()).collect();
+    // The profile's order is the tiebreak the sort keeps, so it must be applied
+    // to the INPUT - applied to the output it could undo a dependency edge.
+    let ids = match profiles.as_deref() {
+        Some(profiles) => apply_profile_order(&ids, profiles.active_order()),
+        None => ids,
+    };
     let topo = nova_mod_format::deps::topological_order(&ids, &graph);
     if topo.cycle {
         warn!(
@@ -132,6 +145,7 @@ pub fn register_bundles(
              topological order; merging the cyclic mods in catalog order"
         );
     }
+    commands.insert_resource(MergedMods(topo.order.clone()));
     // `ordered`'s ids are unique (a downloaded id that shadows a shipped one is
     // skipped above), so this id->handle map never drops a bundle.
     let by_id: HashMap<&str, &Handle<BundleAsset>> =
@@ -228,6 +242,7 @@ pub fn register_bundles(
                         Content::Campaign(cfg) => cfg.id.clone(),
                         Content::Style(cfg) => cfg.id.clone(),
                         Content::Ship(cfg) => cfg.id.clone(),
+                        Content::Music(cfg) => cfg.id.clone(),
                     };
                     undeclared_ref_issues.push((id, message));
                 }
@@ -366,6 +381,7 @@ pub fn register_bundles(
     commands.insert_resource(outcome.campaigns);
     commands.insert_resource(GameStyles(outcome.styles));
     commands.insert_resource(GameShips(outcome.ships));
+    commands.insert_resource(GameMusic(outcome.music));
 }
 
 /// The result of merging an ordered list of bundles: the id-keyed registries plus
@@ -384,6 +400,9 @@ pub struct MergeOutcome {
     /// Ships in registration order, overlaid last-wins by id - so a mod
     /// rebuilds a base hull by declaring the same id.
     pub ships: Vec<ShipConfig>,
+    /// Music tracks in registration order, overlaid last-wins by id - so a mod
+    /// rescores a base track by declaring the same id.
+    pub music: Vec<MusicTrackConfig>,
     /// Human-readable messages, one per intra-bundle duplicate id that was
     /// skipped. Empty on clean data.
     pub conflicts: Vec<String>,
@@ -413,6 +432,7 @@ where
     let mut campaigns = GameCampaigns::default();
     let mut styles: Vec<ShipStyleConfig> = Vec::new();
     let mut ships: Vec<ShipConfig> = Vec::new();
+    let mut music: Vec<MusicTrackConfig> = Vec::new();
     let mut conflicts: Vec<String> = Vec::new();
 
     for bundle in bundles {
@@ -423,6 +443,7 @@ where
         let mut seen_campaigns: HashSet<&str> = HashSet::new();
         let mut seen_styles: HashSet<&str> = HashSet::new();
         let mut seen_ships: HashSet<&str> = HashSet::new();
+        let mut seen_music: HashSet<&str> = HashSet::new();
 
         for item in bundle {
             match item {
@@ -442,6 +463,7 @@ where
                         &mut campaigns,
                         &mut styles,
                         &mut ships,
+                        &mut music,
                     );
                 }
                 Content::Scenario(cfg) => {
@@ -460,6 +482,7 @@ where
                         &mut campaigns,
                         &mut styles,
                         &mut ships,
+                        &mut music,
                     );
                 }
                 Content::Campaign(cfg) => {
@@ -478,6 +501,7 @@ where
                         &mut campaigns,
                         &mut styles,
                         &mut ships,
+                        &mut music,
                     );
                 }
                 Content::Style(cfg) => {
@@ -496,6 +520,7 @@ where
                         &mut campaigns,
                         &mut styles,
                         &mut ships,
+                        &mut music,
                     );
                 }
                 Content::Ship(cfg) => {
@@ -514,6 +539,26 @@ where
                         &mut campaigns,
                         &mut styles,
                         &mut ships,
+                        &mut music,
+                    );
+                }
+                Content::Music(cfg) => {
+                    if !seen_music.insert(cfg.id.as_str()) {
+                        conflicts.push(format!(
+                            "music track id '{}' appears more than once in one bundle; \
+                             keeping the first, skipping the duplicate",
+                            cfg.id
+                        ));
+                        continue;
+                    }
+                    merge_content_item(
+                        item,
+                        &mut sections,
+                        &mut scenarios,
+                        &mut campaigns,
+                        &mut styles,
+                        &mut ships,
+                        &mut music,
                     );
                 }
             }
@@ -526,6 +571,7 @@ where
         campaigns,
         styles,
         ships,
+        music,
         conflicts,
     }
 }
@@ -544,6 +590,7 @@ fn merge_content_item(
     campaigns: &mut GameCampaigns,
     styles: &mut Vec<ShipStyleConfig>,
     ships: &mut Vec<ShipConfig>,
+    music: &mut Vec<MusicTrackConfig>,
 ) {
     match item {
         Content::Section(cfg) => match sections.iter_mut().find(|s| s.base.id == cfg.base.id) {
@@ -570,6 +617,11 @@ fn merge_content_item(
             Some(existing) => *existing = cfg.clone(),
             None => ships.push(cfg.clone()),
         },
+        // And again: the track list keeps its authored order, overlaid in place.
+        Content::Music(cfg) => match music.iter_mut().find(|t| t.id == cfg.id) {
+            Some(existing) => *existing = cfg.clone(),
+            None => music.push(cfg.clone()),
+        },
     }
 }
 
@@ -603,6 +655,7 @@ mod tests {
         let mut campaigns = GameCampaigns::default();
         let mut styles: Vec<ShipStyleConfig> = Vec::new();
         let mut ships: Vec<ShipConfig> = Vec::new();
+        let mut music: Vec<MusicTrackConfig> = Vec::new();
 
         // Base bundle: two sections in palette order.
         merge_content_item(
@@ -612,6 +665,7 @@ mod tests {
             &mut campaigns,
             &mut styles,
             &mut ships,
+            &mut music,
         );
         merge_content_item(
             &Content::Section(Box::new(section("thruster", 50.0))),
@@ -620,6 +674,7 @@ mod tests {
             &mut campaigns,
             &mut styles,
             &mut ships,
+            &mut music,
         );
 
         // Mod bundle: overlays "hull" with a new health, leaves "thruster".
@@ -630,6 +685,7 @@ mod tests {
             &mut campaigns,
             &mut styles,
             &mut ships,
+            &mut music,
         );
 
         // No duplicate appended: still two sections, original order kept.
@@ -649,6 +705,7 @@ mod tests {
         let mut campaigns = GameCampaigns::default();
         let mut styles: Vec<ShipStyleConfig> = Vec::new();
         let mut ships: Vec<ShipConfig> = Vec::new();
+        let mut music: Vec<MusicTrackConfig> = Vec::new();
 
         let id = "shakedown_run".to_string();
         let base = ScenarioConfig::new(
@@ -666,6 +723,7 @@ mod tests {
             &mut campaigns,
             &mut styles,
             &mut ships,
+            &mut music,
         );
         merge_content_item(
             &Content::Scenario(modded),
@@ -674,6 +732,7 @@ mod tests {
             &mut campaigns,
             &mut styles,
             &mut ships,
+            &mut music,
         );
 
         assert_eq!(scenarios.len(), 1, "overlay must replace, not add");
@@ -747,6 +806,34 @@ mod tests {
         );
     }
 
+    /// A mod rescores a base track by declaring a music track with the same
+    /// id, and adds a track of its own by declaring a new one.
+    #[test]
+    fn a_mod_overlays_a_base_music_track_by_id_and_adds_its_own() {
+        let track = |id: &str, crossfade: f32| {
+            Content::Music(MusicTrackConfig {
+                id: id.to_string(),
+                stems: Vec::new(),
+                crossfade: Some(crossfade),
+            })
+        };
+        let base = [track("patrol", 1.0)];
+        let modded = [track("patrol", 3.0), track("boss", 0.5)];
+
+        let outcome = merge_bundles([base.iter(), modded.iter()]);
+
+        assert!(outcome.conflicts.is_empty(), "{:?}", outcome.conflicts);
+        assert_eq!(
+            outcome
+                .music
+                .iter()
+                .map(|track| (track.id.as_str(), track.crossfade))
+                .collect::<Vec<_>>(),
+            vec![("patrol", Some(3.0)), ("boss", Some(0.5))],
+            "the mod's track must win in place, and its new one must be added",
+        );
+    }
+
     /// A later bundle (a mod) overlays an earlier bundle (the base) by id:
     /// last-wins across bundles, with a fresh section left added. No conflicts -
     /// same id in DIFFERENT bundles is the intended overlay, not an error.
@@ -0,0 +1,232 @@
+//! Named mod PROFILES: each one an enabled set plus an optional explicit load
+//! order among mods the dependency sort leaves independent.
+//!
+//! A profile is the unit a player switches between ("vanilla balance test",
+//! "campaign + story mods", "sandbox"). [`EnabledMods`](crate::EnabledMods)
+//! stays the one live set the merge reads; the ACTIVE profile is what that set
+//! is loaded from and mirrored back into. With no profile active the game runs
+//! on the global `enabled_mods` store exactly as before profiles existed.
+//!
+//! The order list never beats a dependency: [`apply_profile_order`] only
+//! permutes the merge input, and `register_bundles` still sorts it
+//! topologically, so a profile can decide which of two independent mods
+//! overlays the other but never merge a mod before what it depends on.
+//!
+//! Storage, and its best-effort semantics, belong to [`crate::persist`]; this
+//! module owns the key, the value type and the launch-time pick.
+
+/// Glob-import surface: `use nova_assets::mod_profiles::prelude::*` re-exports
+/// the public API of this module.
+pub mod prelude {
+    pub use super::{
+        apply_profile_order, load_mod_profiles, save_mod_profiles, LaunchModProfile, ModProfile,
+        ModProfiles, MOD_PROFILE_ENV,
+    };
+}
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use nova_modding::prelude::BASE_MOD_ID;
+use serde::{Deserialize, Serialize};
+
+use crate::persist;
+
+/// The store key: `<config_dir>/nova-protocol/mod_profiles.ron` on native,
+/// `nova_protocol.mod_profiles` in localStorage on the web.
+const KEY: &str = "mod_profiles";
+
+/// Environment variable that picks the profile a run boots with - the
+/// transport for a harness that cannot pass `--mod-profile`, the same route
+/// `NOVA_NORENDER` takes to the examples. Any non-empty value names a profile.
+pub const MOD_PROFILE_ENV: &str = "NOVA_MOD_PROFILE";
+
+/// One named profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModProfile {
+    /// The player-facing name, and the key `--mod-profile` matches.
+    pub name: String,
+    /// The enabled mod ids, sorted on save for a diff-friendly file. `base` is
+    /// unioned in at load like the global set, so leaving it out is harmless.
+    pub enabled: Vec<String>,
+    /// An explicit load order among the enabled mods. Ids not listed keep
+    /// their catalog slot; an absent or empty list is plain catalog order.
+    #[serde(default)]
+    pub order: Vec<String>,
+}
+
+/// Every saved profile and which one is active.
+///
+/// Loaded from the store at `OnEnter(Processing)` by `load_enabled_mods`,
+/// persisted whenever it changes. The mods menu switches `active`; a toggle
+/// while a profile is active is mirrored into that profile's `enabled`.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModProfiles {
+    /// The active profile's name; `None` runs on the global enabled set.
+    pub active: Option<String>,
+    /// The saved profiles, in creation order (the menu cycles them this way).
+    pub profiles: Vec<ModProfile>,
+}
+
+impl ModProfiles {
+    /// The profile called `name`, if one is saved.
+    pub fn get(&self, name: &str) -> Option<&ModProfile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// The active profile, if `active` names a saved one.
+    pub fn active_profile(&self) -> Option<&ModProfile> {
+        self.active.as_deref().and_then(|name| self.get(name))
+    }
+
+    /// Mutable [`active_profile`](Self::active_profile).
+    pub fn active_profile_mut(&mut self) -> Option<&mut ModProfile> {
+        let name = self.active.clone()?;
+        self.profiles.iter_mut().find(|p| p.name == name)
+    }
+
+    /// The active profile's explicit order (empty with no profile active).
+    pub fn active_order(&self) -> &[String] {
+        self.active_profile()
+            .map(|p| p.order.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The profile after the active one in the menu's cycle: the global set,
+    /// then every saved profile in order, then back to the global set.
+    pub fn next_active(&self) -> Option<String> {
+        let at = self
+            .active
+            .as_deref()
+            .and_then(|name| self.profiles.iter().position(|p| p.name == name));
+        let next = at.map_or(0, |i| i + 1);
+        self.profiles.get(next).map(|p| p.name.clone())
+    }
+
+    /// Save a new profile holding `enabled` and return its name: `profile-N`,
+    /// with N the first number no saved profile uses.
+    pub fn create_from(&mut self, enabled: &HashSet<String>) -> String {
+        let name = (1..)
+            .map(|n| format!("profile-{n}"))
+            .find(|name| self.get(name).is_none())
+            .expect("an unbounded range always yields an unused name");
+        let mut ids: Vec<String> = enabled.iter().cloned().collect();
+        ids.sort();
+        self.profiles.push(ModProfile {
+            name: name.clone(),
+            enabled: ids,
+            order: Vec::new(),
+        });
+        name
+    }
+}
+
+/// The profile the command line (or [`MOD_PROFILE_ENV`]) asked for.
+///
+/// Inserted by `GameAssetsPlugin` from the environment; the game binary's
+/// `--mod-profile` overwrites it after the build. Consumed once, at
+/// `OnEnter(Processing)`, where it becomes the active profile - and, like a
+/// menu switch, the one the next plain launch remembers.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LaunchModProfile(pub Option<String>);
+
+impl LaunchModProfile {
+    /// Read [`MOD_PROFILE_ENV`]; unset or empty picks nothing.
+    pub fn from_env() -> Self {
+        Self(
+            std::env::var(MOD_PROFILE_ENV)
+                .ok()
+                .filter(|name| !name.trim().is_empty()),
+        )
+    }
+}
+
+/// Reorder `ids` (the merge input, catalog-then-download order) by a profile's
+/// explicit `order`.
+///
+/// The ids `order` names take over the SLOTS those same ids occupy in `ids`,
+/// in `order`'s sequence; every other id keeps its slot. So listing `[b, a]`
+/// swaps two mods wherever they sit without dragging anything else along.
+/// `base` never moves (it anchors the overlay), and ids `order` names that are
+/// not in `ids` are ignored, as is a repeat.
+pub fn apply_profile_order(ids: &[String], order: &[String]) -> Vec<String> {
+    let present: HashSet<&str> = ids.iter().map(String::as_str).collect();
+    let mut seen: HashSet<&str> = HashSet::new();
+    let named: Vec<&String> = order
+        .iter()
+        .filter(|id| id.as_str() != BASE_MOD_ID && present.contains(id.as_str()))
+        .filter(|id| seen.insert(id.as_str()))
+        .collect();
+    let mut queue = named.into_iter();
+    ids.iter()
+        .map(|id| {
+            if seen.contains(id.as_str()) {
+                queue.next().cloned().unwrap_or_else(|| id.clone())
+            } else {
+                id.clone()
+            }
+        })
+        .collect()
+}
+
+/// The saved profiles, or `None` if nothing has been saved yet (or the store
+/// is unreadable/corrupt).
+pub fn load_mod_profiles() -> Option<ModProfiles> {
+    persist::load(KEY)
+}
+
+/// Persist the profiles. Best-effort - failures are logged, not returned.
+pub fn save_mod_profiles(profiles: &ModProfiles) {
+    persist::save(KEY, profiles);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn profile_order_swaps_only_the_named_slots() {
+        let input = ids(&["base", "a", "x", "b"]);
+        assert_eq!(
+            apply_profile_order(&input, &ids(&["b", "a"])),
+            ids(&["base", "b", "x", "a"]),
+            "b and a trade slots; x stays put"
+        );
+        assert_eq!(
+            apply_profile_order(&input, &[]),
+            input,
+            "no order is catalog order"
+        );
+    }
+
+    #[test]
+    fn profile_order_never_moves_base_and_ignores_strangers() {
+        let input = ids(&["base", "a", "b"]);
+        assert_eq!(
+            apply_profile_order(&input, &ids(&["b", "base", "ghost", "b", "a"])),
+            ids(&["base", "b", "a"])
+        );
+    }
+
+    #[test]
+    fn the_menu_cycle_walks_global_then_each_profile() {
+        let mut profiles = ModProfiles::default();
+        let enabled: HashSet<String> = ["base".to_string()].into();
+        let first = profiles.create_from(&enabled);
+        let second = profiles.create_from(&enabled);
+        assert_eq!(
+            (first.as_str(), second.as_str()),
+            ("profile-1", "profile-2")
+        );
+
+        assert_eq!(profiles.next_active(), Some(first.clone()));
+        profiles.active = Some(first);
+        assert_eq!(profiles.next_active(), Some(second.clone()));
+        profiles.active = Some(second);
+        assert_eq!(profiles.next_active(), None, "wraps back to the global set");
+    }
+}
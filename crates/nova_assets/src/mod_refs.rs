@@ -461,7 +461,7 @@ mod tests {
     fn a_base_relative_ref_is_untouched() {
         // `cubemap` is base-relative here (no scheme): it must survive verbatim.
         let mut cfg = scenario_with_refs();
-        cfg.cubemap = AssetRef::from("textures/cubemap.png".to_string());
+        cfg.cubemap = AssetRef::from("textures/cubemap.png".to_string()).into();
         let content = Content::Scenario(cfg);
         let resources = vec!["textures/rock.png".to_string()];
         let rewritten = rewrite_refs(&content, &self_only_scope("mods/example", &resources));
@@ -477,7 +477,7 @@ mod tests {
         // variant errors on serialize). The rewrite must NOT panic; it returns
         // the item unchanged (the Handle survives, still not a path).
         let mut cfg = scenario_with_refs();
-        cfg.cubemap = AssetRef::from(bevy::prelude::Handle::<bevy::prelude::Image>::default());
+        cfg.cubemap = bevy::prelude::Handle::<bevy::prelude::Image>::default().into();
         assert_eq!(
             cfg.cubemap.path(),
             None,
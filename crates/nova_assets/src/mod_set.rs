@@ -2,6 +2,9 @@
 //! downloaded cache composed into the player-facing [`ModCatalog`] rows, plus
 //! the [`EnabledMods`] selection that decides which bundles the merge sees.
 //! `crate::merge::register_bundles` consumes what this module publishes.
+//!
+//! The selection is loaded from (and mirrored back into) the active
+//! [`ModProfiles`] entry when one is active, else from the global store.
 
 /// Glob-import surface: `use nova_assets::mod_set::prelude::*` re-exports the
 /// public API of this module. The two platform halves are gated here as well as
@@ -11,9 +14,9 @@ pub mod prelude {
     #[cfg(not(target_arch = "wasm32"))]
     pub use super::load_downloaded_mods;
     pub use super::{
-        build_mod_catalog, installed_set_changed, load_enabled_mods,
-        mark_downloaded_bundles_loaded, save_enabled_mods, seed_enabled_mods, DownloadedMod,
-        DownloadedMods, EnabledMods, ModCatalog, ModInfo,
+        activate_mod_profile, build_mod_catalog, installed_set_changed, load_enabled_mods,
+        mark_downloaded_bundles_loaded, persist_mod_profiles, save_enabled_mods, seed_enabled_mods,
+        DownloadedMod, DownloadedMods, EnabledMods, MergedMods, ModCatalog, ModInfo,
     };
     #[cfg(target_arch = "wasm32")]
     pub use super::{poll_mod_cache_hydration, start_mod_cache_hydration, ModCacheHydration};
@@ -22,9 +25,13 @@ pub mod prelude {
 use std::collections::HashSet;
 
 use bevy::prelude::*;
-use nova_modding::prelude::{BundleAsset, InstalledCatalog, ModEntry, ModMeta};
+use nova_modding::prelude::{BundleAsset, InstalledCatalog, ModEntry, ModMeta, BASE_MOD_ID};
 
-use crate::{collections::GameAssets, mod_cache, mod_prefs};
+use crate::{
+    collections::GameAssets,
+    mod_cache, mod_prefs,
+    mod_profiles::{self, LaunchModProfile, ModProfiles},
+};
 
 /// handle for its bundle, loaded from the `mods://` source
 /// (`mods://<id>/<bundle>`) through the same loaders as a shipped bundle.
@@ -63,6 +70,13 @@ pub struct DownloadedMods(pub Vec<DownloadedMod>);
 #[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
 pub struct EnabledMods(pub HashSet<String>);
 
+/// The enabled mod ids in the order `register_bundles` last merged them:
+/// dependencies first, the active profile's order between independent mods.
+/// Two sets with the same ids in another order can overlay differently, so
+/// this - not [`EnabledMods`] - is what a replay pins.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergedMods(pub Vec<String>);
+
 /// One PLAYER-FACING installed mod: the catalog declaration's identity + flags
 /// composed with the mod's [`ModMeta`] self-description from its own bundle.
 ///
@@ -204,24 +218,89 @@ pub fn seed_enabled_mods(
 
 /// Restore the saved enabled-mods set at startup, if any.
 ///
-/// Runs FIRST in the `OnEnter(Processing)` chain, before `seed_enabled_mods`. When
-/// the platform store holds a saved set it becomes `EnabledMods`; `seed_enabled_mods`
-/// then unions base in (so base is always on), and the merge reflects the restored
-/// choices. With NO saved set, `EnabledMods` stays empty here and `seed_enabled_mods`
-/// falls back to the base-only default - identical to pre-persistence startup.
-pub fn load_enabled_mods(mut enabled: ResMut<EnabledMods>) {
-    if let Some(ids) = mod_prefs::load_enabled_ids() {
+/// Runs FIRST in the `OnEnter(Processing)` chain, before `seed_enabled_mods`. The
+/// saved [`ModProfiles`] load first and a [`LaunchModProfile`] pick (the
+/// `--mod-profile` flag or `NOVA_MOD_PROFILE`) becomes the active one; an unknown
+/// name warns and falls back to whatever was active. With a profile active its
+/// set becomes `EnabledMods`; otherwise the global store's set does.
+/// `seed_enabled_mods` then unions base in (so base is always on), and the merge
+/// reflects the restored choices. With NOTHING saved, `EnabledMods` stays empty
+/// here and `seed_enabled_mods` falls back to the base-only default - identical to
+/// pre-persistence startup.
+pub fn load_enabled_mods(
+    mut enabled: ResMut<EnabledMods>,
+    mut profiles: ResMut<ModProfiles>,
+    launch: Option<Res<LaunchModProfile>>,
+) {
+    if let Some(saved) = mod_profiles::load_mod_profiles() {
+        *profiles = saved;
+    }
+    if let Some(name) = launch.and_then(|l| l.0.clone()) {
+        if profiles.get(&name).is_some() {
+            profiles.active = Some(name);
+        } else {
+            let known: Vec<&str> = profiles.profiles.iter().map(|p| p.name.as_str()).collect();
+            warn!(
+                "--mod-profile '{name}' matches no saved mod profile (saved: [{}]); \
+                 keeping the previously active set",
+                known.join(", ")
+            );
+        }
+    }
+    if let Some(profile) = profiles.active_profile() {
+        enabled.0 = profile.enabled.iter().cloned().collect();
+    } else if let Some(ids) = mod_prefs::load_enabled_ids() {
         enabled.0 = ids.into_iter().collect();
     }
 }
 
 /// Persist [`EnabledMods`] whenever it changes (a menu toggle, or the startup seed).
 /// Runs in `Update`, gated on `resource_changed::<EnabledMods>`.
-pub fn save_enabled_mods(enabled: Res<EnabledMods>) {
+///
+/// With a profile active the set is MIRRORED into that profile instead of the
+/// global store - [`persist_mod_profiles`] writes it - so switching back to the
+/// global set finds it exactly as it was left.
+pub fn save_enabled_mods(enabled: Res<EnabledMods>, mut profiles: ResMut<ModProfiles>) {
     let mut ids: Vec<String> = enabled.0.iter().cloned().collect();
     // Sort for a stable, diff-friendly on-disk file (HashSet order is arbitrary).
     ids.sort();
-    mod_prefs::save_enabled_ids(&ids);
+    // NOTE: compare before writing - a no-op write through `ResMut` would still
+    // flag the profiles changed and rewrite the file on every startup seed.
+    match profiles.active_profile().map(|p| p.enabled == ids) {
+        None => mod_prefs::save_enabled_ids(&ids),
+        Some(true) => {}
+        Some(false) => {
+            if let Some(profile) = profiles.active_profile_mut() {
+                profile.enabled = ids;
+            }
+        }
+    }
+}
+
+/// Persist [`ModProfiles`] whenever it changes (a switch, a new profile, an
+/// order edit, or a toggle mirrored in by [`save_enabled_mods`]).
+pub fn persist_mod_profiles(profiles: Res<ModProfiles>) {
+    mod_profiles::save_mod_profiles(&profiles);
+}
+
+/// Make `name` the active profile (`None`: back to the global set) and load its
+/// enabled set into `enabled`, base included.
+///
+/// The one switch path the mods menu and any future caller share. The set it
+/// loads is already clean: a profile only ever holds what a toggle mirrored
+/// into it, and the global store what `save_enabled_mods` wrote.
+pub fn activate_mod_profile(
+    name: Option<String>,
+    profiles: &mut ModProfiles,
+    enabled: &mut EnabledMods,
+) {
+    profiles.active = name.filter(|n| profiles.get(n).is_some());
+    let ids: Vec<String> = match profiles.active_profile() {
+        Some(profile) => profile.enabled.clone(),
+        None => mod_prefs::load_enabled_ids().unwrap_or_default(),
+    };
+    enabled.0 = ids.into_iter().collect();
+    enabled.0.insert(BASE_MOD_ID.to_string());
 }
 
 /// Turn the cache-index records into [`DownloadedMods`], kicking each bundle's
@@ -343,12 +422,19 @@ pub fn poll_mod_cache_hydration(
 /// The run condition for the installed-set-driven re-merge: EITHER half of the
 /// installed set changed - [`EnabledMods`] (a menu toggle, the startup seed) or
 /// [`DownloadedMods`] (install/uninstall, or a downloaded bundle's load landing
-/// via [`mark_downloaded_bundles_loaded`]). One reader consuming both change
-/// ticks together, which two chained `resource_changed` conditions would not do
-/// (their or-combinator short-circuits and leaves the second tick primed).
-/// Public so the integration rigs gate on the exact production condition.
-pub fn installed_set_changed(enabled: Res<EnabledMods>, downloaded: Res<DownloadedMods>) -> bool {
-    enabled.is_changed() || downloaded.is_changed()
+/// via [`mark_downloaded_bundles_loaded`]) - or the [`ModProfiles`] a profile's
+/// load order lives in. One reader consuming every change tick together, which
+/// chained `resource_changed` conditions would not do (their or-combinator
+/// short-circuits and leaves the later ticks primed). Public so the integration
+/// rigs gate on the exact production condition; the profiles are optional so a
+/// rig without them still runs.
+pub fn installed_set_changed(
+    enabled: Res<EnabledMods>,
+    downloaded: Res<DownloadedMods>,
+    profiles: Option<Res<ModProfiles>>,
+) -> bool {
+    let profiles_changed = profiles.is_some_and(|p| p.is_changed());
+    enabled.is_changed() || downloaded.is_changed() || profiles_changed
 }
 
 /// Flag [`DownloadedMods`] as changed when one of its bundles finishes loading
@@ -19,10 +19,11 @@ use crate::{
         GameAssets,
     },
     merge::register_bundles,
+    mod_profiles::{LaunchModProfile, ModProfiles},
     mod_set::{
         build_mod_catalog, installed_set_changed, load_enabled_mods,
-        mark_downloaded_bundles_loaded, save_enabled_mods, seed_enabled_mods, DownloadedMods,
-        EnabledMods, ModCatalog,
+        mark_downloaded_bundles_loaded, persist_mod_profiles, save_enabled_mods, seed_enabled_mods,
+        DownloadedMods, EnabledMods, ModCatalog,
     },
     portal,
 };
@@ -67,7 +68,7 @@ pub enum GameAssetsStates {
 /// A plugin that loads game assets and sets up the game.
 ///
 /// Adds the modding and portal-client plugins, inits the mod-set resources
-/// ([`EnabledMods`], [`ModCatalog`], [`DownloadedMods`]), drives the
+/// ([`EnabledMods`], [`ModCatalog`], [`DownloadedMods`], [`ModProfiles`]), drives the
 /// [`GameAssetsStates`] loading state machine, and runs the mod-cache load and
 /// content-merge/registration systems across `Startup`/`Update`/`OnEnter`.
 pub struct GameAssetsPlugin;
@@ -92,6 +93,11 @@ impl Plugin for GameAssetsPlugin {
         app.init_resource::<ModCatalog>();
         // The downloaded half of the installed set, from the local mod cache.
         app.init_resource::<DownloadedMods>();
+        // The named profiles, loaded with the enabled set at Processing. The
+        // launch pick comes from the environment here; the game binary's
+        // `--mod-profile` overwrites it after the build.
+        app.init_resource::<ModProfiles>();
+        app.insert_resource(LaunchModProfile::from_env());
 
         // Read the cache index and kick the mods:// bundle loads. Native reads
         // the filesystem cache directly; the web must first hydrate the
@@ -194,6 +200,17 @@ impl Plugin for GameAssetsPlugin {
                 .run_if(resource_changed::<EnabledMods>)
                 .run_if(not(in_state(GameAssetsStates::Loading))),
         );
+        // The profiles persist on their own change (a switch, an order edit, a
+        // toggle mirrored in above), gated the same way. Chained after the
+        // mirror so a toggle writes the file in the frame it lands.
+        app.add_systems(
+            Update,
+            persist_mod_profiles
+                .after(save_enabled_mods)
+                .run_if(resource_exists::<GameAssets>)
+                .run_if(resource_changed::<ModProfiles>)
+                .run_if(not(in_state(GameAssetsStates::Loading))),
+        );
     }
 }
 
@@ -60,9 +60,11 @@ fn scenario_from(ron_str: &str) -> ScenarioConfig {
         .into_iter()
         .find_map(|c| match c {
             Content::Scenario(s) => Some(s),
-            Content::Section(_) | Content::Campaign(_) | Content::Style(_) | Content::Ship(_) => {
-                None
-            }
+            Content::Section(_)
+            | Content::Campaign(_)
+            | Content::Style(_)
+            | Content::Ship(_)
+            | Content::Music(_) => None,
         })
         .expect("content contains a Scenario")
 }
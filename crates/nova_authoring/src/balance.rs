@@ -27,6 +27,18 @@
 //!   better-turret capital 301u out (inside its 450u reach) is on top of
 //!   the fight the frame it exists.
 //!
+//! A survival template is graded as the waves it expands to (its stamped
+//! seed, or 0): the generator places every hostile outside its own envelope,
+//! and the audit is what holds it to that.
+//!
+//! Both are graded at every [`Difficulty`] tier, through the same
+//! [`DifficultyPolicy`] the game reads. Distance does not move with the tier,
+//! but the AI's arrival grace does: an on-start hostile whose authored
+//! `engage_delay`, scaled by the tier, still buys the player
+//! [`REACTION_GRACE_SECS`] has not spawned them dead, and grades as a
+//! close-spawn at that tier instead. A finding names the tiers it holds at,
+//! and one rule firing at several tiers is still one finding (one ack).
+//!
 //! The per-scenario invariant PINS for base's own encounters live in their own
 //! tests (broadside_assault.rs); this module is the repo-wide generalization
 //! that also covers content nobody hand-pinned (installed mods). `balance_audit_gate` runs it in CI; the `content` CLI's `lint` runs
@@ -36,10 +48,14 @@
 use std::collections::HashMap;
 
 use bevy::math::Vec3;
-use nova_gameplay::prelude::Allegiance;
+use nova_gameplay::prelude::{Allegiance, Difficulty, DifficultyPolicy};
 use nova_mod_format::BASE_MOD_ID;
 use nova_scenario::prelude::*;
-use nova_ship::prelude::{SectionConfig, SectionKind};
+// The per-ship threat numbers live in `nova_scenario::threat`, where the
+// survival generator budgets its waves with them; re-exported as part of the
+// audit's own surface.
+pub use nova_scenario::prelude::{ShipStats, EFFECTIVE_RANGE_MARGIN, TORPEDO_ENVELOPE};
+use nova_ship::prelude::{GameSections, SectionConfig};
 
 /// The audit entry points, the graded findings and their acknowledgments, and
 /// the derived per-ship / per-group / per-scenario metrics they are graded on.
@@ -48,21 +64,10 @@ pub mod prelude {
         audit_bundles_to_audits, audit_content_tree, audit_scenario, partition_findings,
         ship_stats, BalanceAck, BalanceFinding, BalanceSeverity, CoverAudit, FindingKind,
         HostileAudit, ScenarioAudit, SectionCatalog, ShipCatalog, ShipStats, SpawnGroupAudit,
-        BALANCE_ACKS_FILE, EFFECTIVE_RANGE_MARGIN, TORPEDO_ENVELOPE,
+        BALANCE_ACKS_FILE, EFFECTIVE_RANGE_MARGIN, REACTION_GRACE_SECS, TORPEDO_ENVELOPE,
     };
 }
 
-/// The AI's own shot-worth-taking margin: effective range = margin x
-/// muzzle_speed x projectile_lifetime. Aliased to the engine constant rather
-/// than copied, so the audit's threat envelopes cannot drift from the gate
-/// the AI actually fires on.
-pub const EFFECTIVE_RANGE_MARGIN: f32 = nova_ship::prelude::AI_FIRE_RANGE_FACTOR;
-
-/// Mirrors AI_TORPEDO_MAX_RANGE (nova_ship/src/input/ai/torpedo.rs): the
-/// outer edge of the AI launch envelope, whose per-bay cooldown starts ELAPSED
-/// - a tube inside this range is a live opening threat.
-pub const TORPEDO_ENVELOPE: f32 = 1000.0;
-
 /// The section-prototype view a scenario's ships resolve against: the
 /// last-wins overlay of base -> declared dependencies (in declared order)
 /// -> the bundle's own sections. This matches the runtime merge for every
@@ -116,111 +121,30 @@ impl ShipCatalog {
     }
 }
 
-/// A ship's derived combat numbers, summed over its resolved sections.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ShipStats {
-    /// Summed section health (per-section HP is the shipped damage model;
-    /// the sum is the ship's total pool as the HUD aggregates it).
-    pub hp: f32,
-    /// BURST turret dps: sum of fire_rate x bullet_damage - the
-    /// first-magazine rate (reloads make true sustained ~62% of this for
-    /// the catalog turrets, but shipped TTKs land inside one magazine).
-    /// Kinetic resistance is 1.0 everywhere in the shipped table, so
-    /// authored damage IS applied damage for every catalog turret.
-    pub dps: f32,
-    /// The longest effective range among the ship's turrets
-    /// ([`EFFECTIVE_RANGE_MARGIN`] x muzzle_speed x projectile_lifetime).
-    pub max_effective_range: f32,
-    /// Torpedo tubes are counted, not folded into dps: a tube's threat is
-    /// blast area + guidance, not sustained fire.
-    pub torpedo_tubes: usize,
-}
-
-impl ShipStats {
-    /// How far this ship threatens the moment it exists: its longest turret
-    /// reach, or the AI torpedo launch envelope if it carries tubes (the
-    /// bay's first-launch cooldown starts elapsed). Zero = unarmed.
-    pub fn threat_envelope(&self) -> f32 {
-        let tube_reach = if self.torpedo_tubes > 0 {
-            TORPEDO_ENVELOPE
-        } else {
-            0.0
-        };
-        self.max_effective_range.max(tube_reach)
-    }
-}
-
-/// Sum the fire rate of every muzzle in a turret's joint tree. Fire rate is
-/// per-muzzle since the joint-tree refactor; the shipped turrets each carry one
-/// muzzle, so for the catalog this is that one rate.
-fn turret_total_fire_rate(joint: &nova_ship::prelude::TurretJoint) -> f32 {
-    let here = joint.muzzle.as_ref().map(|m| m.fire_rate).unwrap_or(0.0);
-    here + joint
-        .children
-        .iter()
-        .map(turret_total_fire_rate)
-        .sum::<f32>()
+/// The overlay as the runtime catalogs the survival generator reads.
+fn runtime_catalogs(catalog: &SectionCatalog, ships: &ShipCatalog) -> (GameSections, GameShips) {
+    (
+        GameSections(catalog.0.values().cloned().collect()),
+        GameShips(ships.0.values().cloned().collect()),
+    )
 }
 
-/// Sum a ship's stats through the catalogs. An unknown ship or section
-/// prototype contributes nothing (content_lint already errors on them; the
-/// audit stays total).
+/// Sum a ship's stats through the catalogs - [`ship_stats_with`] joined
+/// through the bundle overlay.
 pub fn ship_stats(
     ship: &SpaceshipConfig,
     catalog: &SectionCatalog,
     ships: &ShipCatalog,
 ) -> ShipStats {
-    let mut stats = ShipStats {
-        hp: 0.0,
-        dps: 0.0,
-        max_effective_range: 0.0,
-        torpedo_tubes: 0,
-    };
-    let hull = match &ship.hull {
-        ShipSource::Inline(hull) => Some(hull),
-        ShipSource::Prototype(id) => ships.get(id),
-    };
-    let Some(hull) = hull else {
-        return stats;
-    };
-    for section in &hull.sections {
-        let resolved: Option<&SectionConfig> = match &section.source {
-            SectionSource::Prototype(id) => catalog.get(id),
-            SectionSource::Inline(config) => Some(config),
-        };
-        let Some(config) = resolved else { continue };
-        // An authored SetHealth override wins over the prototype (last one
-        // wins, like the runtime observers applying the list in order), and a
-        // SPAWN override wins over the hull's own for the same reason.
-        let hp_override = ship
-            .modifications
-            .iter()
-            .filter(|m| m.section == section.id)
-            .flat_map(|m| m.modifications.iter())
-            .chain(section.modifications.iter())
-            .rev()
-            .find_map(|m| match m {
-                SectionModification::SetHealth(hp) => Some(*hp),
-                _ => None,
-            });
-        stats.hp += hp_override.unwrap_or(config.base.health);
-        match &config.kind {
-            SectionKind::Turret(turret) => {
-                // Fire rate is per-muzzle now; burst DPS sums every muzzle in
-                // the joint tree. The shipped turrets each carry one muzzle, so
-                // this is unchanged for the catalog.
-                stats.dps += turret_total_fire_rate(&turret.root) * turret.bullet_damage;
-                stats.max_effective_range = stats
-                    .max_effective_range
-                    .max(EFFECTIVE_RANGE_MARGIN * turret.muzzle_speed * turret.projectile_lifetime);
-            }
-            SectionKind::Torpedo(_) => stats.torpedo_tubes += 1,
-            _ => {}
-        }
-    }
-    stats
+    ship_stats_with(ship, |id| catalog.get(id), |id| ships.get(id))
 }
 
+/// Seconds of held fire that make an on-start spawn inside the envelope
+/// survivable: long enough to see the contact, turn and get the first shots
+/// off. An on-start hostile whose arrival grace, at a tier, reaches this
+/// grades as a close-spawn there rather than spawned-dead.
+pub const REACTION_GRACE_SECS: f32 = 3.0;
+
 /// One armed (or unarmed) hostile placed by a handler.
 #[derive(Debug, Clone)]
 pub struct HostileAudit {
@@ -230,6 +154,9 @@ pub struct HostileAudit {
     pub distance: f32,
     /// The hostile's derived combat numbers.
     pub stats: ShipStats,
+    /// The authored arrival grace (`engage_delay`) in seconds, before any
+    /// difficulty scaling. Zero when none is authored.
+    pub engage_delay: f32,
 }
 
 /// The hostiles one handler places, labeled by its trigger.
@@ -307,6 +234,8 @@ pub struct BalanceFinding {
     pub scenario: String,
     /// The offending hostile's scenario object id (what an ack names).
     pub hostile: String,
+    /// The difficulty tiers the rule fires at, easiest first.
+    pub tiers: Vec<Difficulty>,
     /// The rendered explanation, with the numbers that tripped the rule.
     pub message: String,
 }
@@ -413,7 +342,15 @@ impl ScenarioAudit {
         (dps > 0.0).then(|| self.player.hp / dps)
     }
 
-    /// Grade the sheet against the rules, unfiltered by acks.
+    /// [`ttk_against`](Self::ttk_against) with the group's fire scaled by
+    /// the tier's incoming-damage factor.
+    pub fn ttk_against_at(&self, group: &SpawnGroupAudit, difficulty: Difficulty) -> Option<f32> {
+        let factor = DifficultyPolicy::for_difficulty(difficulty).incoming_damage;
+        self.ttk_against(group).map(|ttk| ttk / factor)
+    }
+
+    /// Grade the sheet against the rules at every difficulty tier, unfiltered
+    /// by acks. A rule firing at several tiers is one finding naming them.
     pub fn findings(&self) -> Vec<BalanceFinding> {
         let mut findings = Vec::new();
         for group in &self.groups {
@@ -426,31 +363,52 @@ impl ScenarioAudit {
                 if hostile.distance >= envelope {
                     continue;
                 }
-                if group.on_start {
+                let (dead, close): (Vec<Difficulty>, Vec<Difficulty>) =
+                    Difficulty::ALL.into_iter().partition(|&tier| {
+                        let grace = hostile.engage_delay
+                            * DifficultyPolicy::for_difficulty(tier).ai_engage_grace;
+                        group.on_start && grace < REACTION_GRACE_SECS
+                    });
+                if !dead.is_empty() {
                     findings.push(BalanceFinding {
                         severity: BalanceSeverity::Error,
                         kind: FindingKind::SpawnedDead,
                         scenario: self.scenario.clone(),
                         hostile: hostile.id.clone(),
                         message: format!(
-                            "spawned-dead: '{}' opens the scenario {:.0}u from the player \
+                            "spawned-dead ({}): '{}' opens the scenario {:.0}u from the player \
                              spawn, inside its own {:.0}u threat envelope - the player is \
                              under fire before their first input",
-                            hostile.id, hostile.distance, envelope
+                            tier_list(&dead),
+                            hostile.id,
+                            hostile.distance,
+                            envelope
                         ),
+                        tiers: dead,
                     });
-                } else {
+                }
+                if !close.is_empty() {
+                    let why = if group.on_start {
+                        format!("held off by its {:.1}s engage_delay", hostile.engage_delay)
+                    } else {
+                        "a mid-fight reinforcement arriving on top of the fight".to_string()
+                    };
                     findings.push(BalanceFinding {
                         severity: BalanceSeverity::Warn,
                         kind: FindingKind::CloseSpawn,
                         scenario: self.scenario.clone(),
                         hostile: hostile.id.clone(),
                         message: format!(
-                            "close-spawn: '{}' ({}) spawns {:.0}u from the player spawn, \
-                             inside its own {:.0}u threat envelope - a mid-fight \
-                             reinforcement arriving on top of the fight",
-                            hostile.id, group.trigger, hostile.distance, envelope
+                            "close-spawn ({}): '{}' ({}) spawns {:.0}u from the player spawn, \
+                             inside its own {:.0}u threat envelope - {}",
+                            tier_list(&close),
+                            hostile.id,
+                            group.trigger,
+                            hostile.distance,
+                            envelope,
+                            why
                         ),
+                        tiers: close,
                     });
                 }
             }
@@ -478,7 +436,17 @@ impl ScenarioAudit {
                 .fold(f32::INFINITY, f32::min);
             let tubes: usize = group.hostiles.iter().map(|h| h.stats.torpedo_tubes).sum();
             let ttk = match self.ttk_against(group) {
-                Some(ttk) => format!("{ttk:.1}s"),
+                Some(ttk) => {
+                    let tiers: Vec<String> = Difficulty::ALL
+                        .into_iter()
+                        .filter(|&tier| tier != Difficulty::Normal)
+                        .filter_map(|tier| {
+                            self.ttk_against_at(group, tier)
+                                .map(|ttk| format!("{} {ttk:.1}s", tier.label()))
+                        })
+                        .collect();
+                    format!("{ttk:.1}s ({})", tiers.join(", "))
+                }
                 None => "-".to_string(),
             };
             out.push_str(&format!(
@@ -495,6 +463,15 @@ impl ScenarioAudit {
     }
 }
 
+/// "Easy, Normal" - the tiers a finding holds at, for its message.
+fn tier_list(tiers: &[Difficulty]) -> String {
+    tiers
+        .iter()
+        .map(|tier| tier.label())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn trigger_label(event: &ScenarioEventConfig) -> String {
     let entity_id = event.filters.iter().find_map(|f| match f {
         EventFilterConfig::Entity(entity) => entity.id.clone(),
@@ -515,6 +492,14 @@ pub fn audit_scenario(
     catalog: &SectionCatalog,
     ships: &ShipCatalog,
 ) -> Option<ScenarioAudit> {
+    let expanded;
+    let scenario = if scenario.survival.is_some() {
+        let (sections, game_ships) = runtime_catalogs(catalog, ships);
+        expanded = expand_survival(scenario.clone(), &sections, &game_ships);
+        &expanded
+    } else {
+        scenario
+    };
     let mut player: Option<(Vec3, ShipStats)> = None;
     for event in &scenario.events {
         for action in &event.actions {
@@ -543,10 +528,15 @@ pub fn audit_scenario(
                                 Some(Allegiance::Neutral) | Some(Allegiance::Player)
                             ) =>
                     {
+                        let engage_delay = match &ship.controller {
+                            SpaceshipController::AI(ai) => ai.engage_delay.unwrap_or(0.0).max(0.0),
+                            _ => 0.0,
+                        };
                         hostiles.push(HostileAudit {
                             id: config.base.id.clone(),
                             distance: config.base.position.distance(player_spawn),
                             stats: ship_stats(ship, catalog, ships),
+                            engage_delay,
                         });
                     }
                     ScenarioObjectKind::Asteroid(rock) if rock.invulnerable => {
@@ -649,7 +639,7 @@ pub fn audit_bundles_to_audits(
 
 #[cfg(test)]
 mod tests {
-    use nova_ship::prelude::{BaseSectionConfig, TurretSectionConfig};
+    use nova_ship::prelude::{BaseSectionConfig, SectionKind, TurretSectionConfig};
 
     use super::*;
 
@@ -749,7 +739,10 @@ mod tests {
             thumbnail: None,
             hidden: true,
             menu_backdrop: false,
+            survival: None,
+            post: None,
             watches: vec![],
+            invariants: vec![],
             events,
         }
     }
@@ -825,6 +818,43 @@ mod tests {
         assert!(audit.findings().is_empty(), "{:?}", audit.findings());
     }
 
+    /// The rules grade per difficulty tier: a 4s arrival grace covers the
+    /// player at Easy and Normal, but Hard halves it under the reaction
+    /// floor, so the same spawn is dead at Hard and only close below it.
+    #[test]
+    fn an_engage_delay_grades_the_spawn_per_difficulty_tier() {
+        let catalog =
+            SectionCatalog::resolve(&[&[hull("h", 100.0), turret("t", 60.0, 100.0, 4.0, 100.0)]]);
+        let held = SpaceshipController::AI(AIControllerConfig {
+            engage_delay: Some(4.0),
+            ..Default::default()
+        });
+        let scenario = scenario_of(vec![on_start(vec![
+            spawn_at(
+                "player_spaceship",
+                Vec3::ZERO,
+                ship(player_controller(), &["h"]),
+            ),
+            spawn_at(
+                "gunner",
+                Vec3::new(0.0, 0.0, -175.0),
+                ship(held, &["h", "t"]),
+            ),
+        ])]);
+        let audit = audit_scenario(&scenario, &catalog, &ShipCatalog::resolve(&[]))
+            .expect("player present");
+        let findings = audit.findings();
+        assert_eq!(findings.len(), 2, "{findings:?}");
+        assert_eq!(findings[0].kind, FindingKind::SpawnedDead);
+        assert_eq!(findings[0].tiers, vec![Difficulty::Hard]);
+        assert_eq!(findings[1].kind, FindingKind::CloseSpawn);
+        assert_eq!(
+            findings[1].tiers,
+            vec![Difficulty::Easy, Difficulty::Normal]
+        );
+        assert!(findings[1].message.contains("engage_delay"));
+    }
+
     /// A fail-first pin, permanently in-tree: a TUBE-ONLY hostile has zero
     /// turret dps but the AI launch envelope opens immediately (the bay
     /// cooldown starts elapsed) - it must not evade the rules.
@@ -939,6 +969,7 @@ mod tests {
             kind: FindingKind::CloseSpawn,
             scenario: "s".to_string(),
             hostile: hostile.to_string(),
+            tiers: Difficulty::ALL.to_vec(),
             message: "close-spawn: test".to_string(),
         }
     }
@@ -956,6 +987,35 @@ mod tests {
         )
     }
 
+    /// The shipped survival template grades as its generated waves, and every
+    /// seed's waves come out clean: all eight groups outside their envelopes,
+    /// the last one deadlier than the first.
+    #[test]
+    fn the_survival_template_audits_clean_on_every_seed() {
+        let catalog = SectionCatalog::resolve(&[&crate::generation::build_section_catalog()]);
+        let ships = ShipCatalog::resolve(&[&crate::generation::build_ships()]);
+        let template = crate::generation::build_scenarios()
+            .into_iter()
+            .find(|scenario| scenario.survival.is_some())
+            .expect("base ships a survival template");
+
+        for seed in 0..16 {
+            let mut scenario = template.clone();
+            scenario.survival.as_mut().unwrap().seed = Some(seed);
+            let audit = audit_scenario(&scenario, &catalog, &ships).expect("a player ship");
+            assert_eq!(audit.groups.len(), 8, "seed {seed}: one group per wave");
+            assert!(
+                audit.findings().is_empty(),
+                "seed {seed}: {:?}\n{}",
+                audit.findings(),
+                audit.report()
+            );
+            let first = audit.ttk_against(&audit.groups[0]).unwrap();
+            let last = audit.ttk_against(&audit.groups[7]).unwrap();
+            assert!(last < first, "seed {seed}: {first:.2}s -> {last:.2}s");
+        }
+    }
+
     /// An ack silences exactly its matching WARN; duplicate findings need
     /// duplicate acks (one ack, two identical findings: one stays active).
     #[test]
@@ -980,6 +1040,7 @@ mod tests {
             kind: FindingKind::SpawnedDead,
             scenario: "s".to_string(),
             hostile: "x".to_string(),
+            tiers: Difficulty::ALL.to_vec(),
             message: "spawned-dead: test".to_string(),
         };
         let acks = vec![ack_for("x", "spawned-dead")];
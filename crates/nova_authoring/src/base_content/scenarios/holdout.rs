@@ -0,0 +1,87 @@
+//! Holdout - the built-in survival template.
+//!
+//! Everything here is STAGE: the lights and the player's corvette. The waves
+//! are not authored at all - `survival` hands them to the generator
+//! (`nova_scenario::survival`), which builds them at load from the seed the
+//! picker shows, so the same template plays differently on every reroll and
+//! the same on every Retry.
+//!
+//! The pool is the two hostile grades a player can answer: raider corvettes,
+//! and the CargoB carrying straight-running lances. The Serpent-loaded CargoB
+//! is left out on purpose - weaving torpedoes a defender cannot screen turn a
+//! late wave into a coin flip rather than a harder fight.
+
+use bevy::prelude::*;
+use nova_gameplay::prelude::*;
+use nova_scenario::prelude::*;
+
+use crate::base_content::ships;
+
+pub(crate) const HOLDOUT_SCENARIO_ID: &str = "holdout";
+
+const ID_PLAYER: &str = "player_spaceship";
+
+/// The ring the waves close on: the player spawn at the origin, where the
+/// stage rig is aimed.
+const PLAYER_SPAWN: Vec3 = Vec3::ZERO;
+
+/// The player's corvette: the campaign's cargoa, guns on the triggers, RCS
+/// left on - there is no tutorial beat here to teach it.
+fn player_ship() -> ScenarioObjectConfig {
+    ScenarioObjectConfig {
+        base: BaseScenarioObjectConfig {
+            id: ID_PLAYER.to_string(),
+            name: "Player Spaceship".to_string(),
+            position: PLAYER_SPAWN,
+            rotation: Quat::IDENTITY,
+        },
+        kind: ScenarioObjectKind::Spaceship(SpaceshipConfig {
+            controller: SpaceshipController::Player(PlayerControllerConfig {
+                input_mapping: ships::CARGOA_TURRET_IDS
+                    .iter()
+                    .map(|id| {
+                        (
+                            id.to_string(),
+                            vec![
+                                MouseButton::Left.into(),
+                                GamepadButton::RightTrigger2.into(),
+                            ],
+                        )
+                    })
+                    .collect(),
+                speed_cap: None,
+                infinite_ammo: false,
+                repair_parts: None,
+            }),
+            allegiance: None,
+            hull: ships::hull(ships::CARGOA_SHIP_ID),
+            modifications: vec![],
+        }),
+    }
+}
+
+/// Survive eight generated waves of raiders and lance haulers around the
+/// spawn, with a rest and a rearm between each.
+pub(crate) fn holdout(cubemap: AssetRef<Image>) -> ScenarioConfig {
+    let mut opening = ThreePointRig::around("holdout", PLAYER_SPAWN, 20.0).actions();
+    opening.push(EventActionConfig::SpawnScenarioObject(player_ship()));
+
+    ScenarioConfig {
+        description: "Hold the spawn against generated, escalating waves. Every seed is a \
+                      different fight; clear them all to win."
+            .to_string(),
+        survival: Some(SurvivalConfig {
+            pool: vec![
+                ships::CARGOA_RAIDER_SHIP_ID.to_string(),
+                ships::CARGOB_LANCE_SHIP_ID.to_string(),
+            ],
+            ..default()
+        }),
+        events: vec![ScenarioEventConfig {
+            name: EventConfig::OnStart,
+            filters: vec![],
+            actions: opening,
+        }],
+        ..ScenarioConfig::new(HOLDOUT_SCENARIO_ID, "Holdout", cubemap)
+    }
+}
@@ -156,6 +156,7 @@ pub(crate) fn menu_duel(
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.0, 3.0)),
@@ -175,6 +175,7 @@ pub(crate) fn menu_gauntlet(
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.0, 3.0)),
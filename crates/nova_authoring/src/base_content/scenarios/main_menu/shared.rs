@@ -26,6 +26,7 @@ pub(super) fn backdrop_planetoid(
             invulnerable: true,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     }
 }
@@ -3,6 +3,7 @@
 use bevy::prelude::*;
 use nova_gameplay::prelude::*;
 use nova_scenario::prelude::*;
+use nova_ship::prelude::StationServices;
 
 use super::shared::*;
 use crate::base_content::scenarios::SCATTER_SEED;
@@ -44,6 +45,23 @@ pub(crate) fn menu_waystation(
             Vec3::new(-180.0, -20.0, 40.0),
             Color::srgb(0.3, 0.9, 1.0),
         ),
+        // The waystation itself, above the lane so the cargo rocks clear it.
+        ScenarioObjectConfig {
+            base: BaseScenarioObjectConfig {
+                id: "waystation_hub".to_string(),
+                name: "Waystation".to_string(),
+                position: Vec3::new(175.0, 30.0, -15.0),
+                rotation: Quat::IDENTITY,
+            },
+            kind: ScenarioObjectKind::Station(StationConfig {
+                radius: 14.0,
+                color: Color::srgb(0.6, 0.6, 0.65),
+                ports: Vec::new(),
+                approach: None,
+                services: StationServices::default(),
+                lock_signature: None,
+            }),
+        },
     ];
     objects.extend(backdrop_rig("waystation").objects());
 
@@ -77,6 +95,7 @@ pub(crate) fn menu_waystation(
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.0, 2.5)),
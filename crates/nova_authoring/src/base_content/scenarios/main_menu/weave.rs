@@ -119,6 +119,7 @@ pub(crate) fn menu_weave(
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.0, 3.0)),
@@ -4,6 +4,7 @@ use nova_scenario::prelude::ScenarioConfig;
 
 use super::assets::BaseContentAssets;
 
+mod holdout;
 pub(crate) mod main_menu;
 pub(crate) mod nova_protocol;
 
@@ -27,5 +28,6 @@ pub(crate) fn catalog(assets: &BaseContentAssets) -> Vec<ScenarioConfig> {
         nova_protocol::broadside_gunship(assets.cubemap_alt.clone(), texture()),
         nova_protocol::lifeline(assets.cubemap_alt.clone(), texture()),
         nova_protocol::final_tally(assets.cubemap_alt.clone(), texture()),
+        holdout::holdout(cubemap()),
     ]
 }
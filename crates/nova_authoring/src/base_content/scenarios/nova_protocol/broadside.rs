@@ -153,6 +153,7 @@ fn player_ship() -> ScenarioObjectConfig {
                 // screen-and-brawl plays with real magazines and the diegetic
                 // ammo gauge instead of unlimited fire.
                 infinite_ammo: false,
+                repair_parts: None,
             }),
             allegiance: None,
             // The cargoa corvette. RCS is off in the mainline campaign until
@@ -281,6 +282,7 @@ fn cover_scatter(asteroid_texture: &AssetRef<Image>) -> EventActionConfig {
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.5, 4.0)),
@@ -314,6 +316,7 @@ fn hard_cover(asteroid_texture: &AssetRef<Image>) -> Vec<ScenarioObjectConfig> {
             invulnerable: true,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     };
     vec![
@@ -618,7 +621,10 @@ pub(crate) fn broadside(
         thumbnail: Some(AssetRef::from("self://thumbnails/broadside.png")),
         hidden: false,
         menu_backdrop: false,
+        survival: None,
+        post: None,
         watches: vec![scenario_elapsed_watch(SCENARIO_ELAPSED_VAR)],
+        invariants: vec![],
         // Chapter two of the Nova Protocol campaign. Membership + order now
         // live in the `nova_protocol` campaign mapping, which also lists the
         // hidden part-two wave (`broadside_gunship`) so it is replayable from
@@ -862,7 +868,10 @@ pub(crate) fn broadside_gunship(
         // campaign mapping so it is replayable from the campaign header.
         hidden: true,
         menu_backdrop: false,
+        survival: None,
+        post: None,
         watches: vec![scenario_elapsed_watch(SCENARIO_ELAPSED_VAR)],
+        invariants: vec![],
         events,
     }
 }
@@ -58,6 +58,9 @@ const OBJ_SURVEY: &str = "survey";
 const OBJ_PICKET: &str = "picket";
 const OBJ_BREAK: &str = "break_flagship";
 
+/// The epilogue's camera track.
+const TRACK_FINALE: &str = "finale";
+
 /// Story act: 1 = live (approach, survey, both fights), 4 = the epilogue
 /// (flagship dead, the win locked - no outcome can overwrite it), 2 = won,
 /// 3 = lost. Terminal acts per the ledger lesson.
@@ -154,6 +157,7 @@ fn player_ship() -> ScenarioObjectConfig {
                     .collect(),
                 speed_cap: None,
                 infinite_ammo: false,
+                repair_parts: None,
             }),
             allegiance: None,
             hull: ships::hull(ships::CARGOA_SHIP_ID),
@@ -184,6 +188,7 @@ fn claim_anchor(asteroid_texture: &AssetRef<Image>) -> ScenarioObjectConfig {
             invulnerable: true,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     }
 }
@@ -214,6 +219,7 @@ fn anchorage_wreck(
             invulnerable: true,
             seed: None,
             lock_signature,
+            ore: Default::default(),
         }),
     }
 }
@@ -323,6 +329,7 @@ fn claim_belt(asteroid_texture: &AssetRef<Image>) -> EventActionConfig {
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.5, 3.5)),
@@ -340,6 +347,48 @@ fn player_travel_locks(target: &str) -> EventFilterConfig {
     })
 }
 
+/// The epilogue's fly-away: off the player's shoulder, out and up until the
+/// claim's well fills the frame, letterboxed. Eased in under the kill line and
+/// home again (7s + 1.5s) before the banner at `BANNER_AFTER`, so the
+/// outcome overlay lands on the player's own camera.
+fn finale_flyaway() -> EventActionConfig {
+    let player = |x: f32, y: f32, z: f32| CameraTrackPointConfig::Entity {
+        id: ID_PLAYER.to_string(),
+        offset: Vec3::new(x, y, z),
+    };
+    let anchor = CameraTrackPointConfig::Entity {
+        id: ID_ANCHOR.to_string(),
+        offset: Vec3::ZERO,
+    };
+    EventActionConfig::PlayCameraTrack(PlayCameraTrackActionConfig {
+        id: TRACK_FINALE.to_string(),
+        keys: vec![
+            CameraTrackKeyConfig {
+                time: 0.0,
+                position: player(0.0, 8.0, 30.0),
+                look_at: player(0.0, 0.0, 0.0),
+                fov: None,
+            },
+            CameraTrackKeyConfig {
+                time: 4.0,
+                position: player(60.0, 30.0, 40.0),
+                look_at: anchor.clone(),
+                fov: None,
+            },
+            CameraTrackKeyConfig {
+                time: 7.0,
+                position: player(120.0, 60.0, -20.0),
+                look_at: anchor,
+                fov: Some(40.0),
+            },
+        ],
+        blend_in: 1.5,
+        blend_out: 1.5,
+        letterbox: 0.1,
+        skippable: true,
+    })
+}
+
 pub(crate) fn final_tally(
     cubemap: AssetRef<Image>,
     asteroid_texture: AssetRef<Image>,
@@ -587,8 +636,8 @@ pub(crate) fn final_tally(
         ),
         // The KILL: the epilogue opens. Act 4 locks the win (a post-kill
         // player death declares nothing; the escort's fate is its own -
-        // it runs, narratively). The confirm line fires now; the close
-        // and the banner ride the epilogue clock.
+        // it runs, narratively). The confirm line fires now with the
+        // fly-away; the close and the banner ride the epilogue clock.
         ScenarioEventConfig {
             name: EventConfig::OnDestroyed,
             filters: vec![entity(ID_FLAGSHIP), number_equals(VAR_ACT, 1.0)],
@@ -601,6 +650,7 @@ pub(crate) fn final_tally(
                     BELT_RELAY,
                     "The Final Tally is breaking up. The claim is going dark.",
                 ),
+                finale_flyaway(),
             ],
         },
         ScenarioEventConfig {
@@ -616,6 +666,7 @@ pub(crate) fn final_tally(
                     "The Final Tally hangs dead - guns cold, engines dark. \
                      The claim is going dark.",
                 ),
+                finale_flyaway(),
             ],
         },
         // Epilogue close line, +4s.
@@ -732,7 +783,10 @@ pub(crate) fn final_tally(
         // header.
         hidden: true,
         menu_backdrop: false,
+        survival: None,
+        post: None,
         watches: vec![scenario_elapsed_watch(SCENARIO_ELAPSED_VAR)],
+        invariants: vec![],
         events,
     }
 }
@@ -180,6 +180,7 @@ fn player_ship() -> ScenarioObjectConfig {
                     .collect(),
                 speed_cap: None,
                 infinite_ammo: false,
+                repair_parts: None,
             }),
             allegiance: None,
             hull: ships::hull(ships::CARGOA_SHIP_ID),
@@ -303,6 +304,7 @@ fn lane_boulders(asteroid_texture: &AssetRef<Image>) -> Vec<ScenarioObjectConfig
             invulnerable: true,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     };
     vec![
@@ -340,6 +342,7 @@ fn lane_chaff(asteroid_texture: &AssetRef<Image>) -> EventActionConfig {
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some((1.5, 3.5)),
@@ -814,7 +817,10 @@ pub(crate) fn lifeline(
         thumbnail: Some(AssetRef::from("self://thumbnails/lifeline.png")),
         hidden: false,
         menu_backdrop: false,
+        survival: None,
+        post: None,
         watches: vec![scenario_elapsed_watch(SCENARIO_ELAPSED_VAR)],
+        invariants: vec![],
         // Chapter three of the Nova Protocol campaign. Membership + order now
         // live in the `nova_protocol` campaign mapping, which also lists the
         // hidden finale (`final_tally`) for replay.
@@ -455,6 +455,7 @@ fn crate_object(index: usize, position: Vec3) -> ScenarioObjectConfig {
             size: 1.5,
             area_radius: CRATE_AREA_RADIUS,
             pickup_sound: Some(AssetRef::from("self://sounds/salvage_pickup.wav")),
+            contents: Default::default(),
         }),
     }
 }
@@ -510,6 +511,7 @@ fn player_ship() -> ScenarioObjectConfig {
                 // recovers on its own; the player sees the ammo readout and
                 // reload cadence from the first scenario.
                 infinite_ammo: false,
+                repair_parts: None,
             }),
             hull: ships::hull(ships::CARGOA_SHIP_ID),
             modifications: vec![ships::on_section(
@@ -577,6 +579,7 @@ fn belt_rock(
             invulnerable: false,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     }
 }
@@ -659,6 +662,7 @@ pub(crate) fn shakedown_run(
             invulnerable: true,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     });
     for (i, (offset, radius)) in ROCK_OFFSETS.iter().zip(ROCK_RADII).enumerate() {
@@ -678,6 +682,7 @@ pub(crate) fn shakedown_run(
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         });
     }
@@ -61,6 +61,17 @@ const KINETIC_PDC_BULLET_DAMAGE: f32 = 4.0;
 /// turn once the two are flown side by side.
 const PIERCE_PDC_BULLET_DAMAGE: f32 = KINETIC_PDC_BULLET_DAMAGE * 0.5;
 
+/// Authored per-hit damage of the mining laser: a QUARTER of the Kinetic PDC.
+///
+/// A tool, not a weapon. Against a ship it is the weakest gun in the catalog,
+/// so fitting one is a trade a combat build notices.
+const MINING_LASER_BULLET_DAMAGE: f32 = KINETIC_PDC_BULLET_DAMAGE * 0.25;
+
+/// The mining laser's rock multiplier: each round bites `1.0 * 16 = 16` out of
+/// an asteroid, four times a Kinetic PDC round. Rock is priced at
+/// `DAMAGE_PER_UNIT_VOLUME`, so this is two cubic units of material per round.
+const MINING_LASER_ROCK_MULTIPLIER: f32 = 16.0;
+
 /// Side of the shared PDC turret's mount box - and the scale its art is
 /// assembled at, which is the point of having one number: the collider, the
 /// sockets and the gun agree, instead of a unit-cube turret balanced on a small
@@ -219,6 +230,7 @@ fn pdc_turret_prototype(
     description: &str,
     bullet_kind: DamageType,
     bullet_damage: f32,
+    rock_multiplier: f32,
 ) -> SectionConfig {
     SectionConfig {
         base: BaseSectionConfig {
@@ -288,6 +300,7 @@ fn pdc_turret_prototype(
                 delay: 3.0,
                 amount: 200,
             }),
+            rock_multiplier,
         }),
     }
 }
@@ -315,6 +328,7 @@ pub fn standard_section_prototypes(meshes: &BaseContentAssets) -> Vec<SectionCon
             kind: SectionKind::Hull(HullSectionConfig {
                 render_mesh: Some(meshes.hull.clone()),
                 render_mesh_transform: None,
+                cargo_capacity: 0,
             }),
         },
         SectionConfig {
@@ -415,6 +429,7 @@ pub fn standard_section_prototypes(meshes: &BaseContentAssets) -> Vec<SectionCon
             kind: SectionKind::Hull(HullSectionConfig {
                 render_mesh: Some(meshes.hull.clone()),
                 render_mesh_transform: None,
+                cargo_capacity: 0,
             }),
         },
         pdc_turret_prototype(
@@ -426,6 +441,7 @@ pub fn standard_section_prototypes(meshes: &BaseContentAssets) -> Vec<SectionCon
              anything they cannot destroy.",
             DamageType::Kinetic,
             KINETIC_PDC_BULLET_DAMAGE,
+            1.0,
         ),
         pdc_turret_prototype(
             meshes,
@@ -437,6 +453,19 @@ pub fn standard_section_prototypes(meshes: &BaseContentAssets) -> Vec<SectionCon
              one.",
             DamageType::Pierce,
             PIERCE_PDC_BULLET_DAMAGE,
+            1.0,
+        ),
+        pdc_turret_prototype(
+            meshes,
+            "mining_laser_turret_section",
+            "Mining Laser",
+            "The same mount refitted to cut rock. A quarter of the kinetic \
+             PDC's hit against a ship, four times its bite out of an asteroid - \
+             and what it cuts loose from an ore-bearing rock can be scooped \
+             into the hold.",
+            DamageType::Kinetic,
+            MINING_LASER_BULLET_DAMAGE,
+            MINING_LASER_ROCK_MULTIPLIER,
         ),
         torpedo_bay_prototype(
             meshes,
@@ -659,10 +688,14 @@ mod tests {
     /// one's barrel, or plate a hull slab across its traverse. One socket, on
     /// the face `turret_joint_tree` plants the assembly against, is what makes
     /// those placements impossible instead of merely unwise. Both shipped PDCs
-    /// share the builder, so both are held to it.
+    /// and the mining laser share the builder, so all three are held to it.
     #[test]
     fn the_shared_mount_sockets_only_its_base_plate() {
-        for id in ["pdc_kinetic_turret_section", "pdc_pierce_turret_section"] {
+        for id in [
+            "pdc_kinetic_turret_section",
+            "pdc_pierce_turret_section",
+            "mining_laser_turret_section",
+        ] {
             let mount = crate::generation::build_section_catalog()
                 .into_iter()
                 .find(|section| section.base.id == id)
@@ -273,6 +273,7 @@ fn hull_kind(spec: PartSpec) -> SectionKind {
     SectionKind::Hull(HullSectionConfig {
         render_mesh: spec.mesh.map(mesh_ref),
         render_mesh_transform: render_transform(spec),
+        cargo_capacity: 0,
     })
 }
 
@@ -4,7 +4,7 @@
 //!
 //! ```text
 //! cargo run content gen
-//! cargo run content lint [--target <mod-dir-or-id>] \
+//! cargo run content lint [--target <mod-dir-or-id>] [--mod-profile <name>] \
 //!     [--report <path>] [--format md|html]
 //! ```
 //!
@@ -26,6 +26,9 @@
 //!   silently double-drives flight). `--target` lints one mod: a mod
 //!   directory anywhere on disk (the dir name is the mod id, portal-style)
 //!   or an in-repo id (`webmods/<id>`, `assets/mods/<id>`, or `base`).
+//!   `--mod-profile <name>` lints against a saved mod profile instead of the
+//!   whole tree: only base and the profile's enabled mods are context (and,
+//!   without `--target`, reported), the set the game merges under it.
 //!   `--report <path>` writes a per-mod document that pinpoints, for every
 //!   finding, the file + element + explanation + suggested fix
 //!   (`--format md|html`, Markdown the default; a `.html` path implies
@@ -67,6 +70,10 @@ enum Command {
         /// Omit to lint the whole repo tree.
         #[arg(long)]
         target: Option<String>,
+        /// Lint against this saved mod profile: only base and the profile's
+        /// enabled mods are in context (and reported, without --target).
+        #[arg(long, value_name = "NAME")]
+        mod_profile: Option<String>,
         /// Write a per-mod findings report to this path (a document that names
         /// file + element + fix for every finding). Omit for stdout only.
         #[arg(long)]
@@ -93,9 +100,10 @@ pub fn main(args: &[OsString]) -> ExitCode {
         Command::Gen => run_gen(),
         Command::Lint {
             target,
+            mod_profile,
             report,
             format,
-        } => run_lint(target.as_deref(), report, format),
+        } => run_lint(target.as_deref(), mod_profile.as_deref(), report, format),
     }
 }
 
@@ -114,11 +122,12 @@ fn run_gen() -> ExitCode {
 
 fn run_lint(
     target: Option<&str>,
+    mod_profile: Option<&str>,
     report: Option<PathBuf>,
     format: Option<ReportFormat>,
 ) -> ExitCode {
-    let report_data = match target {
-        None => crate::lint_walk::collect_tree(),
+    let dir = match target {
+        None => None,
         Some(target) => {
             let Some(dir) = crate::lint_walk::resolve_target(target) else {
                 eprintln!(
@@ -127,9 +136,29 @@ fn run_lint(
                 return ExitCode::FAILURE;
             };
             println!("content lint: target {}", dir.display());
-            crate::lint_walk::collect_target(&dir)
+            Some(dir)
         }
     };
+    let report_data = match (mod_profile, dir) {
+        (Some(name), dir) => {
+            let profiles = nova_assets::mod_profiles::load_mod_profiles().unwrap_or_default();
+            let Some(profile) = profiles.get(name) else {
+                let known: Vec<&str> = profiles.profiles.iter().map(|p| p.name.as_str()).collect();
+                eprintln!(
+                    "content lint: no saved mod profile named '{name}' (saved: [{}])",
+                    known.join(", ")
+                );
+                return ExitCode::FAILURE;
+            };
+            println!(
+                "content lint: mod profile '{name}' ({} mod(s) enabled)",
+                profile.enabled.len()
+            );
+            crate::lint_walk::collect_profile(&profile.enabled, dir.as_deref())
+        }
+        (None, None) => crate::lint_walk::collect_tree(),
+        (None, Some(dir)) => crate::lint_walk::collect_target(&dir),
+    };
 
     print_summary(&report_data);
 
@@ -19,7 +19,7 @@ use nova_scenario::prelude::{
 use nova_ship::prelude::{binding_source, flight_rig_reserved_sources, InputSource, SectionConfig};
 
 use crate::{
-    balance::{BalanceAck, BALANCE_ACKS_FILE},
+    balance::{BalanceAck, BALANCE_ACKS_FILE, REACTION_GRACE_SECS},
     content_report::{AckedFinding, Category, ContentReport, Finding, Severity as ReportSeverity},
 };
 
@@ -27,8 +27,8 @@ use crate::{
 /// the target resolver, and the balance-input view of a walked tree.
 pub mod prelude {
     pub use super::{
-        audit_bundles, collect_target, collect_tree, lint_content_tree, lint_target,
-        resolve_target, tree_acks, AuditBundle,
+        audit_bundles, collect_profile, collect_target, collect_tree, lint_content_tree,
+        lint_target, resolve_target, tree_acks, AuditBundle,
     };
 }
 
@@ -65,6 +65,7 @@ impl WalkedBundle {
                 Content::Campaign(cfg) => cfg.id.as_str(),
                 Content::Style(cfg) => cfg.id.as_str(),
                 Content::Ship(cfg) => cfg.id.as_str(),
+                Content::Music(cfg) => cfg.id.as_str(),
             };
             (id == element_id).then_some(file.as_str())
         })
@@ -115,10 +116,11 @@ fn read_bundle(id: &str, dir: &Path) -> WalkedBundle {
             Content::Ship(ship) => ships.push(ship.clone()),
             Content::Scenario(scenario) => scenarios.push(scenario.clone()),
             Content::Campaign(campaign) => campaigns.push(campaign.clone()),
-            // Styles have no cross-content references of their own - a style
-            // names asset paths and nothing else - so they are walked for their
-            // resource refs (below) and need no bucket here.
-            Content::Style(_) => {}
+            // Styles and music tracks have no cross-content references of
+            // their own - each names asset paths and nothing else - so they
+            // are walked for their resource refs (below) and need no bucket
+            // here.
+            Content::Style(_) | Content::Music(_) => {}
         }
     }
     WalkedBundle {
@@ -308,6 +310,7 @@ fn lint_bundle(bundle: &WalkedBundle, all: &[WalkedBundle]) -> Vec<(String, Lint
             Content::Campaign(cfg) => (cfg.id.clone(), "campaign"),
             Content::Style(cfg) => (cfg.id.clone(), "style"),
             Content::Ship(cfg) => (cfg.id.clone(), "ship"),
+            Content::Music(cfg) => (cfg.id.clone(), "music"),
         };
         for message in nova_assets::mod_refs::resource_ref_violations(item, &scope) {
             issues.push((
@@ -490,8 +493,8 @@ fn lint_severity(severity: LintSeverity) -> ReportSeverity {
 /// Build the unified [`ContentReport`] over an already-walked bundle set,
 /// reporting on the bundles named in `report_ids`. Runs all three checker
 /// families in one pass and attaches each finding's source file from the
-/// walk's provenance. `collect_tree` and `collect_target` are the two
-/// entry points; the split of `all` vs `report_ids` is what lets a
+/// walk's provenance. `collect_tree`, `collect_target` and
+/// `collect_profile` are the entry points; the split of `all` vs `report_ids` is what lets a
 /// `--target` lint see the whole repo for context while reporting only the
 /// target's own findings.
 fn build_report(
@@ -565,8 +568,9 @@ fn build_report(
         let file = file_of(&bundle, &finding.scenario);
         let suggestion = match finding.kind {
             crate::balance::FindingKind::SpawnedDead => Some(format!(
-                "spawn '{}' outside its threat envelope, or delay it past OnStart so the \
-                 player has fired before it opens up",
+                "spawn '{}' outside its threat envelope, delay it past OnStart, or give it \
+                 an engage_delay that holds its fire {REACTION_GRACE_SECS:.0}s at every tier \
+                 the finding names",
                 finding.hostile
             )),
             crate::balance::FindingKind::CloseSpawn => Some(format!(
@@ -690,6 +694,38 @@ pub fn collect_target(dir: &Path) -> ContentReport {
     build_report(&all, &report_ids, Some(target_id))
 }
 
+/// The unified content report as a MOD PROFILE would merge it: the walked
+/// context is base plus the profile's `enabled` ids only, so a reference into a
+/// mod the profile leaves off is reported as the game would meet it. With a
+/// `target` (`--target` + `--mod-profile`) the target joins that context and
+/// only its findings are reported; without one, every bundle in the profile is.
+pub fn collect_profile(enabled: &[String], target: Option<&Path>) -> ContentReport {
+    let in_profile: HashSet<&str> = enabled
+        .iter()
+        .map(String::as_str)
+        .chain(std::iter::once(BASE_MOD_ID))
+        .collect();
+    let target = target.map(|dir| {
+        let id = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "target".to_string());
+        read_bundle(&id, dir)
+    });
+    let target_id = target.as_ref().map(|t| t.id.clone());
+    let mut all: Vec<WalkedBundle> = walk_repo_bundles()
+        .into_iter()
+        .filter(|b| in_profile.contains(b.id.as_str()))
+        .filter(|b| target_id.as_deref() != Some(b.id.as_str()))
+        .collect();
+    all.extend(target);
+    let report_ids: HashSet<String> = match &target_id {
+        Some(id) => std::iter::once(id.clone()).collect(),
+        None => all.iter().map(|b| b.id.clone()).collect(),
+    };
+    build_report(&all, &report_ids, target_id)
+}
+
 #[cfg(test)]
 mod tests {
     use nova_gameplay::prelude::AssetRef;
@@ -514,6 +514,7 @@ mod tests {
             scenario_id: "asteroid_field".to_string(),
             handler_count: 5,
             object_count: 12,
+            survival_seed: None,
         });
         assert!(world.resource::<ScenarioLoadAssertion>().fired);
 
@@ -523,6 +524,7 @@ mod tests {
             scenario_id: "asteroid_next".to_string(),
             handler_count: 1,
             object_count: 0,
+            survival_seed: None,
         });
     }
 
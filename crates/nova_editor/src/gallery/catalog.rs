@@ -1,5 +1,6 @@
 //! What the gallery shows: the browsable slice of [`GameSections`] (category
-//! plus text filter) and the readouts the focus view prints for one prototype.
+//! plus text filter), the refit bay's slice for one fitted part, and the
+//! readouts the focus view prints for one prototype.
 //!
 //! Change this module when a section kind appears, or when a stat belongs on
 //! the focus card.
@@ -81,15 +82,39 @@ pub(crate) fn browsable(
         .enumerate()
         .filter(|(_, section)| !section.base.hide_in_editor)
         .filter(|(_, section)| category.accepts(&section.kind))
-        .filter(|(_, section)| {
-            needle.is_empty()
-                || section.base.name.to_lowercase().contains(&needle)
-                || section.base.id.to_lowercase().contains(&needle)
-        })
+        .filter(|(_, section)| matches_filter(section, &needle))
         .map(|(index, _)| index)
         .collect()
 }
 
+/// The catalog indices the refit bay lists for a section carrying `current`:
+/// exactly what [`refit_parts`] offers, narrowed by the same text filter.
+///
+/// `hide_in_editor` does not apply here. The offer is the station's, the one
+/// the `dock refit` verb answers from, and the bay must not show less than
+/// the terminal does.
+pub(crate) fn refit_listed(sections: &GameSections, current: &str, filter: &str) -> Vec<usize> {
+    let needle = filter.trim().to_lowercase();
+    let offered: Vec<&str> = refit_parts(sections, current)
+        .into_iter()
+        .map(|section| section.base.id.as_str())
+        .collect();
+    sections
+        .iter()
+        .enumerate()
+        .filter(|(_, section)| offered.contains(&section.base.id.as_str()))
+        .filter(|(_, section)| matches_filter(section, &needle))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Whether a prototype's name or id holds the lowercased `needle`.
+fn matches_filter(section: &SectionConfig, needle: &str) -> bool {
+    needle.is_empty()
+        || section.base.name.to_lowercase().contains(needle)
+        || section.base.id.to_lowercase().contains(needle)
+}
+
 /// The part's authored extent, used to fit its preview into a tile. The
 /// collider is the only authored size a section carries; an unset one resolves
 /// to the unit cube, exactly as it does in physics.
@@ -294,6 +319,35 @@ mod tests {
         assert!(browsable(&catalog(), GalleryCategory::Propulsion, "racer").is_empty());
     }
 
+    /// The bay lists the station's offer for the fitted part: the same kind,
+    /// not the part itself, and hidden prototypes too - the `dock refit` verb
+    /// offers those, so the bay must.
+    #[test]
+    fn the_refit_list_is_the_stations_offer_for_the_fitted_part() {
+        assert_eq!(
+            refit_listed(&catalog(), "reinforced_hull_section", ""),
+            vec![1]
+        );
+        assert!(refit_listed(&catalog(), "basic_thruster_section", "").is_empty());
+        assert!(
+            refit_listed(&catalog(), "reinforced_hull_section", "zzz").is_empty(),
+            "the text filter still narrows it"
+        );
+
+        let mut sections = catalog();
+        sections.0.push(section(
+            "light_torpedo_section",
+            "Torpedo Bay Section",
+            SectionKind::Torpedo(TorpedoSectionConfig::default()),
+            false,
+        ));
+        assert_eq!(
+            refit_listed(&sections, "light_torpedo_section", ""),
+            vec![3],
+            "the hidden siege bay is still on offer"
+        );
+    }
+
     /// An unset collider is the unit cube in physics, so it must be the unit
     /// cube here too - a preview fitted to a wrong extent reads as the wrong
     /// size next to its neighbours.
@@ -12,7 +12,7 @@ use nova_ship::prelude::*;
 
 use crate::{
     config::SectionChoice,
-    gallery::{catalog, ui::GalleryCell, GalleryState, COLS, PAGE},
+    gallery::{ui::GalleryCell, GalleryState, RefitBay, COLS, PAGE},
 };
 
 /// Opens and closes the gallery from the editor.
@@ -52,10 +52,13 @@ pub(crate) fn toggle_gallery(keys: Res<ButtonInput<KeyCode>>, mut state: ResMut<
 }
 
 /// Drive the open gallery from the keyboard.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn gallery_keyboard(
+    mut commands: Commands,
     keys: Res<ButtonInput<KeyCode>>,
     mut typed: MessageReader<KeyboardInput>,
     sections: Res<GameSections>,
+    bay: Res<RefitBay>,
     hovered: Query<(&GalleryCell, &Hovered)>,
     mut state: ResMut<GalleryState>,
     mut choice: ResMut<SectionChoice>,
@@ -67,7 +70,7 @@ pub(crate) fn gallery_keyboard(
         return;
     }
 
-    let listed = catalog::browsable(&sections, state.category, &state.filter);
+    let listed = state.listed(&sections);
     let mut next = state.clone();
 
     // Escape backs out one step at a time: out of the filter field, then out of
@@ -89,11 +92,13 @@ pub(crate) fn gallery_keyboard(
             next.filter_focused = false;
             next.focused = !listed.is_empty();
         } else if next.focused {
-            if let Some(id) = next.selected_id(&sections) {
-                *choice = SectionChoice::Section(id);
+            match next.selected_id(&sections) {
+                Some(id) => next.take(id, &bay, &mut choice, &mut commands),
+                None => {
+                    next.open = false;
+                    next.focused = false;
+                }
             }
-            next.open = false;
-            next.focused = false;
         } else if !listed.is_empty() {
             next.focused = true;
         }
@@ -110,9 +115,7 @@ pub(crate) fn gallery_keyboard(
         {
             next.selected = cell;
             if let Some(id) = next.selected_id(&sections) {
-                *choice = SectionChoice::Section(id);
-                next.open = false;
-                next.focused = false;
+                next.take(id, &bay, &mut choice, &mut commands);
             }
         }
     }
@@ -158,7 +161,7 @@ pub(crate) fn gallery_keyboard(
         let pressed = pressed.iter().map(|(key, text)| (*key, text.as_deref()));
         if edit_filter(&mut next, pressed) {
             // A narrower list can leave the selection past its end.
-            let listed = catalog::browsable(&sections, next.category, &next.filter);
+            let listed = next.listed(&sections);
             next.step(0, listed.len());
         }
     }
@@ -204,6 +207,7 @@ fn edit_filter<'a>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gallery::refit::RefitSlot;
 
     /// Typing builds the filter and returns the selection to the first match;
     /// backspace takes it back. Control keys carry no text and must not land in
@@ -255,6 +259,7 @@ mod tests {
         app.add_message::<KeyboardInput>();
         app.insert_resource(state);
         app.insert_resource(SectionChoice::None);
+        app.init_resource::<RefitBay>();
         app.insert_resource(GameSections(vec![
             SectionConfig {
                 base: BaseSectionConfig {
@@ -399,4 +404,56 @@ mod tests {
         );
         assert!(app.world().resource::<GalleryState>().open);
     }
+
+    /// In the refit bay the pick goes onto the docked ship, through the same
+    /// `RefitSection` the `dock refit` verb raises, and never arms the
+    /// placement tool.
+    #[test]
+    fn enter_in_the_refit_bay_fits_the_part_to_the_ship() {
+        #[derive(Resource, Default)]
+        struct Fitted(Vec<(String, String)>);
+
+        let mut app = gallery_app(GalleryState {
+            open: true,
+            focused: true,
+            refit: Some(RefitSlot {
+                section: "fuselage".to_string(),
+                part: "hull".to_string(),
+            }),
+            ..default()
+        });
+        app.world_mut()
+            .resource_mut::<GameSections>()
+            .0
+            .push(SectionConfig {
+                base: BaseSectionConfig {
+                    id: "hull_b".to_string(),
+                    name: "Hull B".to_string(),
+                    ..default()
+                },
+                kind: SectionKind::Hull(HullSectionConfig::default()),
+            });
+        let ship = app.world_mut().spawn_empty().id();
+        app.world_mut().resource_mut::<RefitBay>().ship = Some(ship);
+        app.init_resource::<Fitted>();
+        app.add_observer(|refit: On<RefitSection>, mut fitted: ResMut<Fitted>| {
+            fitted.0.push((refit.section.clone(), refit.part.clone()));
+        });
+
+        tap(&mut app, KeyCode::Enter, None);
+
+        assert_eq!(
+            app.world().resource::<Fitted>().0,
+            vec![("fuselage".to_string(), "hull_b".to_string())],
+            "the only other hull is the one on offer"
+        );
+        assert_eq!(
+            *app.world().resource::<SectionChoice>(),
+            SectionChoice::None
+        );
+        assert!(
+            !app.world().resource::<GalleryState>().open,
+            "fitting closes the gallery, which is what leaves the bay"
+        );
+    }
 }
@@ -7,9 +7,15 @@
 //! fast one: Tab up, point, Q, back to building. Change this module when the
 //! browse flow changes; `catalog` owns WHAT is listed, `ui` the layout, `scene`
 //! the 3D tiles and `input` the keyboard.
+//!
+//! The same gallery is the docked refit bay (`refit`): opened over a live ship
+//! instead of the build view, it lists what a station can swap in for one
+//! section, and the part it hands on goes onto the ship rather than to the
+//! placement tool.
 
 pub(crate) mod catalog;
 mod input;
+mod refit;
 mod scene;
 mod ui;
 
@@ -18,11 +24,14 @@ use bevy::{
     prelude::*,
 };
 pub(crate) use input::gallery_keyboard;
+use nova_gameplay::prelude::*;
 use nova_ship::prelude::*;
+pub(crate) use refit::RefitBay;
+use refit::RefitSlot;
 pub(crate) use scene::EditorCamera;
 pub(crate) use ui::{EditorChrome, GalleryAction};
 
-use crate::{gallery::catalog::GalleryCategory, ExampleStates};
+use crate::{config::SectionChoice, gallery::catalog::GalleryCategory, ExampleStates};
 
 /// Tile grid of one page.
 pub(crate) const COLS: usize = 4;
@@ -48,6 +57,9 @@ pub(crate) struct GalleryState {
     pub(crate) selected: usize,
     /// Whether the focus card is up for the selection.
     pub(crate) focused: bool,
+    /// In the refit bay, the section being refitted: the list is then what the
+    /// station can swap in for its part, not the catalog.
+    pub(crate) refit: Option<RefitSlot>,
 }
 
 impl GalleryState {
@@ -63,13 +75,44 @@ impl GalleryState {
         self.selected = (self.selected as isize + delta).clamp(0, last) as usize;
     }
 
+    /// The catalog indices on show: the browsable slice in the editor, the
+    /// refit options for the picked section in the bay.
+    pub(crate) fn listed(&self, sections: &GameSections) -> Vec<usize> {
+        match &self.refit {
+            Some(slot) => catalog::refit_listed(sections, &slot.part, &self.filter),
+            None => catalog::browsable(sections, self.category, &self.filter),
+        }
+    }
+
     /// The catalog id of the selected prototype, if the filtered list still has
     /// one there.
     pub(crate) fn selected_id(&self, sections: &GameSections) -> Option<String> {
-        let listed = catalog::browsable(sections, self.category, &self.filter);
+        let listed = self.listed(sections);
         let index = *listed.get(self.selected)?;
         Some(sections.get(index)?.base.id.clone())
     }
+
+    /// Hand the prototype `id` on and close. In the editor it arms the
+    /// placement tool; in the refit bay it goes onto the ship in place of the
+    /// picked section, and closing is what leaves the bay.
+    pub(crate) fn take(
+        &mut self,
+        id: String,
+        bay: &RefitBay,
+        choice: &mut SectionChoice,
+        commands: &mut Commands,
+    ) {
+        match (bay.ship, &self.refit) {
+            (Some(ship), Some(slot)) => commands.trigger(RefitSection {
+                entity: ship,
+                section: slot.section.clone(),
+                part: id,
+            }),
+            _ => *choice = SectionChoice::Section(id),
+        }
+        self.open = false;
+        self.focused = false;
+    }
 }
 
 /// True while the gallery is up: the run condition for the editor systems that
@@ -78,10 +121,18 @@ pub(crate) fn gallery_open(state: Res<GalleryState>) -> bool {
     state.open
 }
 
+/// True where the gallery runs: the editor's build mode, and the refit bay over
+/// a docked ship.
+fn gallery_live(editor: Res<State<ExampleStates>>, pause: Option<Res<State<PauseStates>>>) -> bool {
+    *editor.get() == ExampleStates::Editor
+        || pause.is_some_and(|pause| *pause.get() == PauseStates::Refit)
+}
+
 /// Wire the gallery into the editor plugin.
 pub(crate) fn register(app: &mut App) {
     app.init_resource::<GalleryState>();
     app.init_resource::<scene::FocusView>();
+    app.init_resource::<RefitBay>();
 
     // A stale gallery must not survive a scene change, exactly as the section
     // choice and the pending rebind do not.
@@ -107,7 +158,7 @@ pub(crate) fn register(app: &mut App) {
             scene::pose_focused_item,
         )
             .chain()
-            .run_if(in_state(ExampleStates::Editor)),
+            .run_if(gallery_live),
     );
     // The focus view's zoom and orbit, split off so a headless rig with no
     // input plugin (and so no wheel or motion queue) still runs the rest.
@@ -117,10 +168,26 @@ pub(crate) fn register(app: &mut App) {
             .before(scene::place_gallery_items)
             .run_if(resource_exists::<Messages<MouseMotion>>)
             .run_if(resource_exists::<Messages<MouseWheel>>)
-            .run_if(in_state(ExampleStates::Editor)),
+            .run_if(gallery_live),
     );
 
     app.add_observer(ui::on_gallery_action);
+
+    // The refit bay: asked for by the NOVA OS, opened once the computer has
+    // closed, left when the gallery closes. AFTER the gallery's own chain, so
+    // the frame a pick closes the gallery has already triggered the refit.
+    app.add_observer(refit::on_open_refit_bay);
+    app.add_systems(
+        Update,
+        (
+            refit::enter_refit_bay,
+            refit::leave_refit_bay.run_if(in_state(PauseStates::Refit)),
+        )
+            .after(ui::rebuild_gallery)
+            .run_if(resource_exists::<State<PauseStates>>),
+    );
+    app.add_systems(OnEnter(PauseStates::Refit), refit::begin_refit_session);
+    app.add_systems(OnExit(PauseStates::Refit), refit::end_refit_session);
 }
 
 #[cfg(test)]
@@ -0,0 +1,352 @@
+//! The docked refit bay: the gallery reopened over a ship docked at a station
+//! that refits, listing only what the station can swap in for one section.
+//!
+//! The NOVA OS asks for it ([`OpenRefitBay`], from the ship app's Refit button
+//! or a bare `dock refit`), and the bay waits for the computer to close before
+//! it takes the screen as [`PauseStates::Refit`]. Inside, the header's section
+//! strip picks which standing section is being refitted and the grid lists
+//! `refit_parts` for the part it carries; taking a part triggers the same
+//! [`RefitSection`] the verb does, so `refit_spaceship_section` stays the one
+//! place a refit happens. Closing the gallery, by a pick or by backing out,
+//! leaves the bay, and the ship is back in flight still docked. There is no
+//! placement tool and no delete: a refit changes a part, never a mount.
+//!
+//! Change this module when the bay's entry or exit changes; what the grid
+//! lists for a section is `catalog::refit_listed`.
+
+use bevy::prelude::*;
+use nova_events::prelude::{EntityId, EntityTypeName};
+use nova_gameplay::prelude::*;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+
+use crate::gallery::{
+    scene::{EditorCamera, GalleryItem, ParkedPose},
+    ui::GalleryRoot,
+    GalleryState,
+};
+
+/// One standing section the bay can refit: its scenario id and the catalog
+/// part it carries now.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct RefitSlot {
+    /// The section's scenario id, which [`RefitSection`] addresses.
+    pub(crate) section: String,
+    /// The [`GameSections`] id of the part fitted there.
+    pub(crate) part: String,
+}
+
+/// The bay's session: which ship it is open for, its standing sections, and
+/// what it borrowed from the scenario camera. Empty while no refit is asked
+/// for.
+#[derive(Resource, Clone, Debug, Default)]
+pub(crate) struct RefitBay {
+    /// The docked ship root. Set by the request, so it is also what holds the
+    /// bay's door open until the NOVA OS has closed.
+    pub(crate) ship: Option<Entity>,
+    /// Its standing sections, in id order.
+    pub(crate) slots: Vec<RefitSlot>,
+    /// The scenario camera as the bay found it.
+    borrowed: Option<BorrowedCamera>,
+}
+
+impl RefitBay {
+    /// Whether the gallery is working for the bay rather than the editor.
+    pub(crate) fn is_open(&self) -> bool {
+        self.ship.is_some()
+    }
+}
+
+/// Everything the bay took off the scenario camera to park it on the gallery
+/// stage, handed back on the way out as photo mode hands back its own.
+#[derive(Clone, Debug)]
+struct BorrowedCamera {
+    camera: Entity,
+    transform: Transform,
+    pose: Option<ScriptedCameraPose>,
+    chase: bool,
+    free: bool,
+}
+
+/// Take a request for the bay, if the ship is docked at a station that refits.
+/// The NOVA OS checks the same before it asks; this is the bay's own door.
+pub(crate) fn on_open_refit_bay(
+    open: On<OpenRefitBay>,
+    q_docked: Query<&Docked>,
+    q_services: Query<&StationServices>,
+    mut bay: ResMut<RefitBay>,
+) {
+    let refits = q_docked.get(open.entity).is_ok_and(|docked| {
+        q_services
+            .get(docked.station)
+            .is_ok_and(|services| services.refit)
+    });
+    if !refits {
+        warn!(
+            "on_open_refit_bay: ship {:?} is not docked at a station that refits",
+            open.entity
+        );
+        return;
+    }
+    bay.ship = Some(open.entity);
+}
+
+/// Open the bay once gameplay is running again: a request from the NOVA OS
+/// lands while the computer is still sliding shut, and frozen variants never
+/// go directly into one another.
+pub(crate) fn enter_refit_bay(
+    mut bay: ResMut<RefitBay>,
+    q_docked: Query<(), With<Docked>>,
+    pause: Res<State<PauseStates>>,
+    mut next: ResMut<NextState<PauseStates>>,
+) {
+    let Some(ship) = bay.ship else {
+        return;
+    };
+    if *pause.get() != PauseStates::Unpaused {
+        return;
+    }
+    // The frame between the computer closing and the bay opening is a live
+    // one; a ship that burned off the port in it has nothing to refit at.
+    if q_docked.get(ship).is_err() {
+        *bay = RefitBay::default();
+        return;
+    }
+    next.set(PauseStates::Refit);
+}
+
+/// Enter the bay: list the ship's standing sections, open the gallery on the
+/// first one that has something to swap in, and borrow the scenario camera
+/// for the gallery stage.
+#[allow(clippy::type_complexity)]
+pub(crate) fn begin_refit_session(
+    mut commands: Commands,
+    mut bay: ResMut<RefitBay>,
+    mut gallery: ResMut<GalleryState>,
+    sections: Res<GameSections>,
+    q_sections: Query<
+        (&ChildOf, &EntityId, &EntityTypeName),
+        (With<SectionMarker>, Without<HealthZeroMarker>),
+    >,
+    camera: Option<
+        Single<
+            (
+                Entity,
+                &Transform,
+                Option<&ScriptedCameraPose>,
+                Has<SpaceshipCameraController>,
+                Has<WASDCameraController>,
+            ),
+            With<ScenarioCameraMarker>,
+        >,
+    >,
+) {
+    let Some(ship) = bay.ship else {
+        return;
+    };
+    // Lost sections are a damage-control rebuild, not a refit.
+    let mut slots: Vec<RefitSlot> = q_sections
+        .iter()
+        .filter(|(child, ..)| child.0 == ship)
+        .map(|(_, id, part)| RefitSlot {
+            section: id.0.clone(),
+            part: part.0.clone(),
+        })
+        .collect();
+    slots.sort();
+    let first = slots
+        .iter()
+        .find(|slot| !refit_parts(&sections, &slot.part).is_empty())
+        .or(slots.first())
+        .cloned();
+    bay.slots = slots;
+    *gallery = GalleryState {
+        open: true,
+        refit: first,
+        ..default()
+    };
+
+    let Some(camera) = camera else {
+        return;
+    };
+    let (camera, transform, pose, chase, free) = camera.into_inner();
+    bay.borrowed = Some(BorrowedCamera {
+        camera,
+        transform: *transform,
+        pose: pose.copied(),
+        chase,
+        free,
+    });
+    // The gallery parks whatever carries `EditorCamera`; the rigs come off so
+    // nothing fights it for the transform while it does.
+    commands
+        .entity(camera)
+        .remove::<(
+            SpaceshipCameraController,
+            ScriptedCameraPose,
+            WASDCameraController,
+        )>()
+        .insert(EditorCamera);
+}
+
+/// Leave the bay once the gallery has closed: a part was taken, or the player
+/// backed out of it.
+pub(crate) fn leave_refit_bay(
+    gallery: Res<GalleryState>,
+    mut next: ResMut<NextState<PauseStates>>,
+) {
+    if !gallery.open {
+        next.set(PauseStates::Unpaused);
+    }
+}
+
+/// Tear the bay down on every exit, including the one leaving `Playing`: the
+/// overlay and its previews go, the camera is handed back exactly as the bay
+/// found it, and the session empties so the door closes behind it.
+pub(crate) fn end_refit_session(
+    mut commands: Commands,
+    mut bay: ResMut<RefitBay>,
+    mut gallery: ResMut<GalleryState>,
+    overlay: Query<Entity, Or<(With<GalleryRoot>, With<GalleryItem>)>>,
+) {
+    for entity in &overlay {
+        commands.entity(entity).despawn();
+    }
+    *gallery = GalleryState::default();
+    let borrowed = std::mem::take(&mut *bay).borrowed;
+    let Some(borrowed) = borrowed else {
+        return;
+    };
+    let Ok(mut entity) = commands.get_entity(borrowed.camera) else {
+        return;
+    };
+    entity
+        .try_insert(borrowed.transform)
+        .try_remove::<(EditorCamera, ParkedPose, WASDCameraController)>();
+    if borrowed.free {
+        entity.try_insert(WASDCameraController);
+    }
+    if borrowed.chase {
+        entity.try_insert(SpaceshipCameraController);
+    }
+    if let Some(pose) = borrowed.pose {
+        entity.try_insert(pose);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    fn part(id: &str, kind: SectionKind) -> SectionConfig {
+        SectionConfig {
+            base: BaseSectionConfig {
+                id: id.to_string(),
+                name: id.to_string(),
+                ..default()
+            },
+            kind,
+        }
+    }
+
+    /// A ship docked at a yard that refits, with a hull that has a sibling in
+    /// the catalog and a thruster that has none.
+    fn bay_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<PauseStates>();
+        app.init_resource::<RefitBay>();
+        app.init_resource::<GalleryState>();
+        app.insert_resource(GameSections(vec![
+            part("hull_a", SectionKind::Hull(HullSectionConfig::default())),
+            part("hull_b", SectionKind::Hull(HullSectionConfig::default())),
+            part(
+                "thruster",
+                SectionKind::Thruster(ThrusterSectionConfig::default()),
+            ),
+        ]));
+        app.add_observer(on_open_refit_bay);
+        app.add_systems(
+            Update,
+            (
+                enter_refit_bay,
+                leave_refit_bay.run_if(in_state(PauseStates::Refit)),
+            ),
+        );
+        app.add_systems(OnEnter(PauseStates::Refit), begin_refit_session);
+        app.add_systems(OnExit(PauseStates::Refit), end_refit_session);
+
+        let station = app.world_mut().spawn(StationServices::default()).id();
+        let port = app.world_mut().spawn(ChildOf(station)).id();
+        let ship = app
+            .world_mut()
+            .spawn((SpaceshipRootMarker, Docked { port, station }))
+            .id();
+        for (id, fitted) in [("engine", "thruster"), ("fuselage", "hull_a")] {
+            app.world_mut().spawn((
+                SectionMarker,
+                EntityId::new(id.to_string()),
+                EntityTypeName(fitted.to_string()),
+                ChildOf(ship),
+            ));
+        }
+        (app, ship)
+    }
+
+    /// The request opens the bay on the first section that has something to
+    /// swap in, and closing the gallery hands the screen back to flight.
+    #[test]
+    fn the_bay_opens_on_a_refittable_section_and_closes_with_the_gallery() {
+        let (mut app, ship) = bay_app();
+        app.world_mut().trigger(OpenRefitBay { entity: ship });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<PauseStates>>().get(),
+            PauseStates::Refit
+        );
+        let gallery = app.world().resource::<GalleryState>();
+        assert!(gallery.open);
+        assert_eq!(
+            gallery.refit.as_ref().map(|slot| slot.section.as_str()),
+            Some("fuselage"),
+            "the thruster has nothing to swap in, so the bay skips past it"
+        );
+        assert_eq!(app.world().resource::<RefitBay>().slots.len(), 2);
+
+        app.world_mut().resource_mut::<GalleryState>().open = false;
+        app.update();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<State<PauseStates>>().get(),
+            PauseStates::Unpaused
+        );
+        assert!(
+            !app.world().resource::<RefitBay>().is_open(),
+            "a closed bay must not reopen on the next unpaused frame"
+        );
+    }
+
+    /// A station without the service keeps the door shut, whoever asks.
+    #[test]
+    fn a_station_that_does_not_refit_keeps_the_bay_shut() {
+        let (mut app, ship) = bay_app();
+        let station = app.world().get::<Docked>(ship).unwrap().station;
+        app.world_mut().entity_mut(station).insert(StationServices {
+            refit: false,
+            ..default()
+        });
+        app.world_mut().trigger(OpenRefitBay { entity: ship });
+        app.update();
+        app.update();
+
+        assert_eq!(
+            *app.world().resource::<State<PauseStates>>().get(),
+            PauseStates::Unpaused
+        );
+        assert!(!app.world().resource::<GalleryState>().open);
+    }
+}
@@ -317,10 +317,11 @@ pub(crate) fn place_gallery_items(
 /// Zoom and orbit the focused part: the wheel scales it, a left-drag turns it.
 ///
 /// Resets whenever the focus card is not up, so every part is met at the same
-/// framing rather than at whatever the last one was left in.
+/// framing rather than at whatever the last one was left in. Real time, as the
+/// turntable is: the refit bay shows the gallery over stopped clocks.
 pub(crate) fn drive_focus_view(
     state: Res<GalleryState>,
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mouse: Res<ButtonInput<MouseButton>>,
     mut motion: MessageReader<MouseMotion>,
     mut wheel: MessageReader<MouseWheel>,
@@ -366,7 +367,7 @@ pub(crate) fn drive_focus_view(
 /// Pose the focused preview: the builder's orbit, and the turntable once they
 /// have left it alone for [`FOCUS_IDLE_RESUME`].
 pub(crate) fn pose_focused_item(
-    time: Res<Time>,
+    time: Res<Time<Real>>,
     mut view: ResMut<FocusView>,
     mut items: Query<(&GalleryItem, &mut Transform)>,
 ) {
@@ -22,7 +22,7 @@ use crate::{
     gallery::{
         catalog::{self, GalleryCategory},
         scene::{spawn_tile, GalleryItem},
-        GalleryState, COLS, PAGE, ROWS,
+        GalleryState, RefitBay, COLS, PAGE, ROWS,
     },
     ExampleStates,
 };
@@ -86,6 +86,8 @@ pub(crate) enum GalleryAction {
     Page(isize),
     /// Put the caret in the filter field.
     FocusFilter,
+    /// In the refit bay, refit the bay's `n`th standing section instead.
+    RefitSlot(usize),
 }
 
 /// Height of the tile's label strip. The strip is opaque so the name stays
@@ -100,10 +102,14 @@ pub(crate) fn rebuild_gallery(
     state: Res<GalleryState>,
     sections: Res<GameSections>,
     skin: Res<UiSkin>,
+    bay: Res<RefitBay>,
     existing: Query<Entity, Or<(With<GalleryRoot>, With<GalleryItem>)>>,
     mut last: Local<Option<GalleryState>>,
 ) {
-    let dirty = last.as_ref() != Some(&*state) || sections.is_changed() || skin.is_changed();
+    let dirty = last.as_ref() != Some(&*state)
+        || sections.is_changed()
+        || skin.is_changed()
+        || bay.is_changed();
     if !dirty {
         return;
     }
@@ -117,7 +123,8 @@ pub(crate) fn rebuild_gallery(
     }
 
     let skin = *skin;
-    let listed = catalog::browsable(&sections, state.category, &state.filter);
+    let refit = bay.is_open();
+    let listed = state.listed(&sections);
     let pages = listed.len().div_ceil(PAGE).max(1);
     let page = state.selected / PAGE;
     let start = page * PAGE;
@@ -163,7 +170,7 @@ pub(crate) fn rebuild_gallery(
                 header.spawn((
                     Name::new("Gallery Title"),
                     UiText,
-                    Text::new("PARTS"),
+                    Text::new(if refit { "REFIT" } else { "PARTS" }),
                     TextFont {
                         font_size: FontSize::Px(20.0),
                         ..default()
@@ -171,7 +178,23 @@ pub(crate) fn rebuild_gallery(
                     TextColor(theme::PHOSPHOR),
                 ));
 
-                for category in GalleryCategory::ROW {
+                // The bay lists one kind, so its row picks the section
+                // instead.
+                for (index, slot) in bay.slots.iter().enumerate() {
+                    let mut spec = ButtonSpec::new(slot.section.clone());
+                    spec.min_height = 26.0;
+                    spec.font_size = 12.0;
+                    if state.refit.as_ref() != Some(slot) {
+                        spec = spec.ghost();
+                    }
+                    header.spawn((
+                        Name::new(format!("Gallery Refit Section {}", slot.section)),
+                        button(spec),
+                        GalleryAction::RefitSlot(index),
+                    ));
+                }
+
+                for category in GalleryCategory::ROW.into_iter().filter(|_| !refit) {
                     header
                         .spawn((Node {
                             width: px(if matches!(category, GalleryCategory::All) {
@@ -251,7 +274,15 @@ pub(crate) fn rebuild_gallery(
             });
 
             if state.focused {
-                focus_body(root, &sections, &listed, state.selected, skin, &mut stages);
+                focus_body(
+                    root,
+                    &sections,
+                    &listed,
+                    state.selected,
+                    skin,
+                    refit,
+                    &mut stages,
+                );
             } else {
                 grid_body(root, &sections, &listed, start, state.selected, &mut stages);
             }
@@ -261,8 +292,14 @@ pub(crate) fn rebuild_gallery(
                 UiText,
                 // Contextual: the focus card's controls are not the grid's, and
                 // a hint line that lists both is one nobody reads.
-                Text::new(if state.focused {
-                    "drag: turn   wheel: zoom   arrows: next part   Enter: place   Esc: back"
+                Text::new(if refit && listed.is_empty() && !state.filter_focused {
+                    "nothing else of this kind to fit here - pick another section   Esc: leave"
+                } else if state.focused {
+                    if refit {
+                        "drag: turn   wheel: zoom   arrows: next part   Enter: fit   Esc: back"
+                    } else {
+                        "drag: turn   wheel: zoom   arrows: next part   Enter: place   Esc: back"
+                    }
                 } else if state.filter_focused {
                     "type: filter   Enter: the top hit   Esc: leave the field"
                 } else {
@@ -438,6 +475,7 @@ fn focus_body(
     listed: &[usize],
     selected: usize,
     skin: UiSkin,
+    refit: bool,
     stages: &mut Vec<(Entity, usize, bool)>,
 ) {
     let Some((index, section)) = listed
@@ -546,7 +584,15 @@ fn focus_body(
 
                 card.spawn((
                     Name::new("Gallery Place Button"),
-                    button(ButtonSpec::new("Place This Part").primary().key("Enter")),
+                    button(
+                        ButtonSpec::new(if refit {
+                            "Fit This Part"
+                        } else {
+                            "Place This Part"
+                        })
+                        .primary()
+                        .key("Enter"),
+                    ),
                     GalleryAction::Place,
                 ));
                 card.spawn((
@@ -644,8 +690,10 @@ fn cell_fill(selected: bool, hovered: bool) -> Color {
 /// Apply a gallery control's action.
 pub(crate) fn on_gallery_action(
     activate: On<Activate>,
+    mut commands: Commands,
     actions: Query<&GalleryAction>,
     sections: Res<GameSections>,
+    bay: Res<RefitBay>,
     mut state: ResMut<GalleryState>,
     mut choice: ResMut<SectionChoice>,
 ) {
@@ -675,13 +723,13 @@ pub(crate) fn on_gallery_action(
             state.focused = true;
             state.filter_focused = false;
         }
-        GalleryAction::Place => {
-            if let Some(id) = state.selected_id(&sections) {
-                *choice = SectionChoice::Section(id);
+        GalleryAction::Place => match state.selected_id(&sections) {
+            Some(id) => state.take(id, &bay, &mut choice, &mut commands),
+            None => {
+                state.open = false;
+                state.focused = false;
             }
-            state.open = false;
-            state.focused = false;
-        }
+        },
         GalleryAction::Category(category) => {
             state.category = *category;
             state.selected = 0;
@@ -689,8 +737,17 @@ pub(crate) fn on_gallery_action(
             state.filter_focused = false;
         }
         GalleryAction::Page(step) => {
-            let listed = catalog::browsable(&sections, state.category, &state.filter);
+            let listed = state.listed(&sections);
             state.step(step * PAGE as isize, listed.len());
         }
+        GalleryAction::RefitSlot(index) => {
+            if let Some(slot) = bay.slots.get(*index) {
+                state.refit = Some(slot.clone());
+                state.filter.clear();
+                state.filter_focused = false;
+                state.selected = 0;
+                state.focused = false;
+            }
+        }
     }
 }
@@ -7,7 +7,8 @@
 //! - `preview`   - the one place a section config becomes preview entities
 //! - `placement` - creating a ship + the pointer place/preview/delete observers
 //! - `keybind`   - section keybind chips + click-to-rebind
-//! - `gallery`   - the full-screen parts browser that arms the placement tool
+//! - `gallery`   - the full-screen parts browser that arms the placement tool,
+//!   and the docked refit bay it doubles as
 //! - `snap`      - where the armed prototype would land, and why not
 //! - `skin`      - the derived cladding, re-derived live while a part is dragged
 //! - `scenario`  - the player-only asteroid+planetoid scene handed off on Play
@@ -311,6 +311,7 @@ fn planetoid(asteroid_texture: Handle<Image>) -> ScenarioObjectConfig {
             invulnerable: true,
             seed: Some(PLANETOID_SEED),
             lock_signature: None,
+            ore: Default::default(),
         }),
     }
 }
@@ -470,6 +471,7 @@ fn player_ship(player_config: &PlayerSpaceshipConfig) -> ScenarioObjectConfig {
                 // on a range built for shooting: weapons auto-reload, so a dry
                 // gun is a cadence beat rather than a permanent disarm.
                 infinite_ammo: false,
+                repair_parts: None,
             }),
             // What the builder saw is what they fly. The editor shows the same
             // derived skin over the same structure, so the flown ship must not
@@ -512,6 +514,7 @@ fn belt_scatter(belt: &Belt, asteroid_texture: Handle<Image>) -> EventActionConf
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some(belt.radius),
@@ -2,7 +2,7 @@
 //! scenario engine. It defines the game-event kinds a scenario reacts to -
 //! `OnStartEvent`, `OnUpdateEvent`, `OnDefeatedEvent`, `OnDestroyedEvent`,
 //! `OnNeutralizedEvent`,
-//! area, orbit-lifecycle, lock, and timer events - and identity components that
+//! area, orbit-lifecycle, lock, mining, docking and timer events - and identity components that
 //! tag scenario objects so filters can find them (`EntityId`, `EntityTypeName`). It is
 //! engine-light glue: `nova_gameplay` emits these events and `nova_scenario`
 //! filters and dispatches on them. It also owns the [`engine`] that queues and
@@ -37,16 +37,18 @@ pub mod prelude {
             EventKind, EventWorld, GameEvent, GameEventInfo, GameEventsPlugin,
         },
         scale::{LOAD_LIMIT, METERS_PER_UNIT},
-        EntityId, EntityTypeName, LockEventInfo, OnCombatLockEndEvent, OnCombatLockStartEvent,
-        OnDefeatedEvent, OnDefeatedEventInfo, OnDestroyedEvent, OnDestroyedEventInfo, OnEnterEvent,
-        OnEnterEventInfo, OnExitEvent, OnExitEventInfo, OnNeutralizedEvent, OnNeutralizedEventInfo,
-        OnOrbitEndEvent, OnOrbitStableEvent, OnOrbitStartEvent, OnOrbitUnstableEvent, OnStartEvent,
-        OnStartEventInfo, OnTimerEndEvent, OnTimerEndEventInfo, OnTravelLockEndEvent,
-        OnTravelLockStartEvent, OnUpdateEvent, OnUpdateEventInfo, OrbitEventInfo, ANCHOR_TYPE_NAME,
-        ASTEROID_TYPE_NAME, BEACON_TYPE_NAME, ENTITY_ID_COMPONENT_NAME,
-        ENTITY_OTHER_ID_COMPONENT_NAME, ENTITY_OTHER_TYPE_NAME_COMPONENT_NAME,
-        ENTITY_TYPE_NAME_COMPONENT_NAME, LIGHT_TYPE_NAME, SALVAGE_CRATE_TYPE_NAME,
-        SPACESHIP_TYPE_NAME, TIMER_KEY_FIELD_NAME,
+        DockEventInfo, EntityId, EntityTypeName, LockEventInfo, OnCameraTrackEndEvent,
+        OnCameraTrackEndEventInfo, OnCombatLockEndEvent, OnCombatLockStartEvent,
+        OnCourseFinishedEvent, OnCourseFinishedEventInfo, OnDefeatedEvent, OnDefeatedEventInfo,
+        OnDestroyedEvent, OnDestroyedEventInfo, OnDockedEvent, OnEnterEvent, OnEnterEventInfo,
+        OnExitEvent, OnExitEventInfo, OnMinedEvent, OnMinedEventInfo, OnNeutralizedEvent,
+        OnNeutralizedEventInfo, OnOrbitEndEvent, OnOrbitStableEvent, OnOrbitStartEvent,
+        OnOrbitUnstableEvent, OnStartEvent, OnStartEventInfo, OnTimerEndEvent, OnTimerEndEventInfo,
+        OnTravelLockEndEvent, OnTravelLockStartEvent, OnUndockedEvent, OnUpdateEvent,
+        OnUpdateEventInfo, OrbitEventInfo, ANCHOR_TYPE_NAME, ASTEROID_TYPE_NAME, BEACON_TYPE_NAME,
+        COURSE_TYPE_NAME, ENTITY_ID_COMPONENT_NAME, ENTITY_OTHER_ID_COMPONENT_NAME,
+        ENTITY_OTHER_TYPE_NAME_COMPONENT_NAME, ENTITY_TYPE_NAME_COMPONENT_NAME, LIGHT_TYPE_NAME,
+        SALVAGE_CRATE_TYPE_NAME, SPACESHIP_TYPE_NAME, STATION_TYPE_NAME, TIMER_KEY_FIELD_NAME,
     };
 }
 
@@ -97,12 +99,16 @@ pub const ANCHOR_TYPE_NAME: &str = "anchor";
 pub const ASTEROID_TYPE_NAME: &str = "asteroid";
 /// [`EntityTypeName`] value for an authored beacon.
 pub const BEACON_TYPE_NAME: &str = "beacon";
+/// [`EntityTypeName`] value for an authored race course.
+pub const COURSE_TYPE_NAME: &str = "course";
 /// [`EntityTypeName`] value for an authored light.
 pub const LIGHT_TYPE_NAME: &str = "light";
 /// [`EntityTypeName`] value for an authored salvage crate.
 pub const SALVAGE_CRATE_TYPE_NAME: &str = "salvage_crate";
 /// [`EntityTypeName`] value for an authored spaceship.
 pub const SPACESHIP_TYPE_NAME: &str = "spaceship";
+/// [`EntityTypeName`] value for an authored station.
+pub const STATION_TYPE_NAME: &str = "station";
 
 /// Event kind fired once when a keyed scenario timer ends (`ontimerend`).
 #[derive(Debug, Clone, EventKind, Reflect)]
@@ -234,6 +240,34 @@ pub struct OnExitEventInfo {
     pub other_type_name: String,
 }
 
+/// Event kind fired when the player scoops mined ore into the hold
+/// (`onmined`); carries [`OnMinedEventInfo`]. One event per item id per
+/// pickup, so a load carrying two ores fires twice.
+#[derive(Debug, Clone, EventKind, Reflect)]
+#[event_name("onmined")]
+#[event_info(OnMinedEventInfo)]
+pub struct OnMinedEvent;
+
+/// Payload for [`OnMinedEvent`]: the rock the ore came from (`id`), the ship
+/// that scooped it (`other_id` / `other_type_name`), and what landed in the
+/// hold (`item` / `count`).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, Reflect)]
+pub struct OnMinedEventInfo {
+    /// Scenario id of the rock the ore was cut from.
+    #[serde(rename = "id")]
+    pub id: String,
+    /// Scenario id of the ship that scooped it.
+    #[serde(rename = "other_id")]
+    pub other_id: String,
+    /// Type name of the ship that scooped it.
+    #[serde(rename = "other_type_name")]
+    pub other_type_name: String,
+    /// Item id of the ore.
+    pub item: String,
+    /// Units that went into the hold.
+    pub count: u32,
+}
+
 /// An ORBIT maneuver started around a well.
 #[derive(Debug, Clone, EventKind, Reflect)]
 #[event_name("onorbitstart")]
@@ -312,6 +346,80 @@ pub struct LockEventInfo {
     pub other_type_name: String,
 }
 
+/// A ship latched to a station's docking port (`ondocked`); carries
+/// [`DockEventInfo`].
+#[derive(Debug, Clone, EventKind, Reflect)]
+#[event_name("ondocked")]
+#[event_info(DockEventInfo)]
+pub struct OnDockedEvent;
+
+/// A surviving ship left the station it was docked at (`onundocked`).
+#[derive(Debug, Clone, EventKind, Reflect)]
+#[event_name("onundocked")]
+#[event_info(DockEventInfo)]
+pub struct OnUndockedEvent;
+
+/// Shared payload for docking events: the station (`id`) and the ship
+/// (`other_id` / `other_type_name`).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, Reflect)]
+pub struct DockEventInfo {
+    /// Scenario id of the station.
+    #[serde(rename = "id")]
+    pub id: String,
+    /// Scenario id of the ship.
+    #[serde(rename = "other_id")]
+    pub other_id: String,
+    /// Type name of the ship.
+    #[serde(rename = "other_type_name")]
+    pub other_type_name: String,
+}
+
+/// A ship flew the last gate of a race course (`oncoursefinished`); carries
+/// [`OnCourseFinishedEventInfo`]. Fires once per run.
+#[derive(Debug, Clone, EventKind, Reflect)]
+#[event_name("oncoursefinished")]
+#[event_info(OnCourseFinishedEventInfo)]
+pub struct OnCourseFinishedEvent;
+
+/// Payload for [`OnCourseFinishedEvent`]: the course (`id`), the ship that
+/// finished it (`other_id` / `other_type_name`), the run's time and whether it
+/// beat the stored personal best.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, Reflect)]
+pub struct OnCourseFinishedEventInfo {
+    /// Scenario id of the course.
+    #[serde(rename = "id")]
+    pub id: String,
+    /// Scenario id of the ship.
+    #[serde(rename = "other_id")]
+    pub other_id: String,
+    /// Type name of the ship.
+    #[serde(rename = "other_type_name")]
+    pub other_type_name: String,
+    /// Seconds from the start gate to the finish.
+    pub time: f64,
+    /// Whether the run is a new personal best (true for the first run).
+    pub best: bool,
+}
+
+/// A scenario camera track finished or was skipped (`oncameratrackend`);
+/// carries [`OnCameraTrackEndEventInfo`]. Fires once per track, when the
+/// authored keys run out - the blend back to the player's camera follows it.
+#[derive(Debug, Clone, EventKind, Reflect)]
+#[event_name("oncameratrackend")]
+#[event_info(OnCameraTrackEndEventInfo)]
+pub struct OnCameraTrackEndEvent;
+
+/// Payload for [`OnCameraTrackEndEvent`]: the track (`id`) and whether the
+/// player skipped it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, Reflect)]
+pub struct OnCameraTrackEndEventInfo {
+    /// Authored id of the track.
+    #[serde(rename = "id")]
+    pub id: String,
+    /// Whether the player cut the track short with the skip key.
+    pub skipped: bool,
+}
+
 /// Event kind fired every scenario tick (`onupdate`); carries
 /// [`OnUpdateEventInfo`]. `nova_scenario` uses it to run per-frame triggers.
 #[derive(Debug, Clone, EventKind, Reflect)]
@@ -1,13 +1,18 @@
 //! The mixing layer every cue goes through: where the listener is, how far a
-//! sound falls off, and the per-source throttle that keeps a burst of events
-//! from stacking into mud. Game-independent - the per-cue volumes and
-//! intervals that use it are the caller's tuning, not this module's.
+//! sound falls off, which side it pans to, which bus it plays on, and the
+//! per-source throttle that keeps a burst of events from stacking into mud.
+//! Game-independent - the per-cue volumes and intervals that use it are the
+//! caller's tuning, not this module's.
 
 use std::collections::HashMap;
 
-use bevy::prelude::*;
+use bevy::{
+    audio::{SpatialListener, SpatialScale},
+    prelude::*,
+};
 
-use super::sfx::SfxCommandsExt;
+use super::sfx::PlaySfx;
+use crate::settings::{BusVolumes, HarnessMute, MasterVolume};
 
 /// Distance-attenuation rolloff for positional cues, in world units. A cue
 /// plays at full base volume within `SFX_NEAR_DISTANCE`, is inaudible beyond
@@ -27,6 +32,18 @@ pub const SFX_FAR_DISTANCE: f32 = 320.0;
 /// far end (before the final remap to true zero).
 const SFX_ROLLOFF_FLOOR: f32 = 0.05;
 
+/// Distance between the listener's ears, in world units. Only the direction
+/// of a cue decides its pan; the gap matters for cues closer than it, which
+/// sit near the centre instead of snapping hard to one side.
+pub const SFX_EAR_GAP: f32 = 2.0;
+
+/// The spatial scale every positional sink plays at. Bevy's spatial sinks
+/// add their own inverse-square falloff on top of the pan; scaling world
+/// units down by [`SFX_FAR_DISTANCE`] keeps that falloff at unity everywhere
+/// a cue is audible, so [`distance_attenuation`] stays the one rolloff curve
+/// and the spatial sink only contributes the stereo image.
+pub const SFX_SPATIAL_SCALE: f32 = 1.0 / SFX_FAR_DISTANCE;
+
 /// Below this final (attenuated) linear volume a one-shot is not worth
 /// spawning - it would be inaudible. Skipping it avoids audio-entity churn for
 /// far events.
@@ -44,6 +61,61 @@ pub const SFX_AREA_CELL: f32 = 6.0;
 /// map stays bounded as ships move through new cells and turrets come and go.
 const SFX_THROTTLE_PRUNE_WINDOW: f32 = 2.0;
 
+/// The mixer bus a cue plays on. Each bus has its own player-facing volume in
+/// [`BusVolumes`], applied under [`MasterVolume`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, Reflect)]
+pub enum AudioBus {
+    /// World and ship sound effects - every positional cue, the engine loops.
+    #[default]
+    Sfx,
+    /// Interface chrome: menu clicks, HUD toggles, objective chimes.
+    Ui,
+    /// The NOVA OS terminal's cues and its ambient bed.
+    NovaOs,
+    /// The comms panel's voice lines.
+    Comms,
+    /// The music stems and stingers.
+    Music,
+}
+
+impl AudioBus {
+    /// The buses in settings-menu order.
+    pub const ALL: [AudioBus; 5] = [
+        AudioBus::Sfx,
+        AudioBus::Ui,
+        AudioBus::NovaOs,
+        AudioBus::Comms,
+        AudioBus::Music,
+    ];
+
+    /// Short display label for the bus's volume slider.
+    pub fn label(self) -> &'static str {
+        match self {
+            AudioBus::Sfx => "Effects",
+            AudioBus::Ui => "Interface",
+            AudioBus::NovaOs => "NOVA OS",
+            AudioBus::Comms => "Comms",
+            AudioBus::Music => "Music",
+        }
+    }
+}
+
+/// The gain a sink that sets its own volume every frame applies for `bus`:
+/// the master output gain (silence under [`HarnessMute`]) times the bus
+/// factor. One-shots never call this - bevy's `GlobalVolume` carries their
+/// master gain and [`PlaySfx`]'s observer the bus. Every input is `Option` so
+/// audio-only rigs without the settings plugin play at full volume.
+pub fn bus_output_gain(
+    bus: AudioBus,
+    master: Option<&MasterVolume>,
+    buses: Option<&BusVolumes>,
+    mute: Option<&HarnessMute>,
+) -> f32 {
+    let mute = mute.copied().unwrap_or_default();
+    let master = master.map_or(1.0, |m| m.output_gain(mute));
+    master * buses.map_or(1.0, |b| b.factor(bus))
+}
+
 /// Per-source throttle key. Turret fire is keyed by the firing turret entity so
 /// each gun sounds independently (even two guns on one ship); the area cues are
 /// keyed by a quantized world cell so a co-located burst collapses to one sound
@@ -117,12 +189,14 @@ pub fn distance_attenuation(distance: f32) -> f32 {
     }
 }
 
-/// The handle-taking counterpart of the [`PlaySfx`](super::sfx::PlaySfx) bank-key
-/// path: same distance attenuation and
-/// audible-threshold gate, but for an already-resolved [`Handle<AudioSource>`]
-/// rather than a bank key. Lets a caller play a section's own authored sound (a
-/// resolved `AssetRef<AudioSource>`) through the exact same positional path the
-/// bank cues use.
+/// Play an already-resolved [`Handle<AudioSource>`] as a positional world cue
+/// on the SFX bus: distance-attenuated, skipped below the audible threshold,
+/// and panned from `source` relative to the listener. Lets a caller play a
+/// section's own authored sound (a resolved `AssetRef<AudioSource>`) through
+/// the one positional path every world cue shares.
+///
+/// With no listener (early startup, the editor) the cue plays centred at full
+/// volume - there is nothing to pan against.
 pub fn play_positional_handle(
     commands: &mut Commands,
     handle: Handle<AudioSource>,
@@ -135,12 +209,17 @@ pub fn play_positional_handle(
     if volume < SFX_AUDIBLE_THRESHOLD {
         return;
     }
-    commands.play_sfx_volume(handle, volume);
+    let sfx = PlaySfx::new(handle).with_volume(volume);
+    commands.trigger(match listener {
+        Some(_) => sfx.at(source),
+        None => sfx,
+    });
 }
 
 /// Marks the camera that acts as the SFX/juice listener: distance attenuation
-/// for the one-shot cues, camera-shake trauma, and the flash-ring facing all
-/// key off this entity. Exactly one camera should carry it at a time - the
+/// and stereo panning for the positional cues, camera-shake trauma, and the
+/// flash-ring facing all key off this entity. It brings the bevy
+/// [`SpatialListener`] the panning hears through, ears [`SFX_EAR_GAP`] apart. Exactly one camera should carry it at a time - the
 /// gameplay (scenario) camera, tagged where it is spawned. "First `Camera3d`"
 /// was the old signal, but ECS query order is unspecified, so a second camera
 /// (minimap, render-to-texture, a leftover editor camera) could flip the
@@ -154,8 +233,14 @@ pub fn play_positional_handle(
 /// assumption was latent, not a live bug.)
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
+#[require(SpatialListener = SpatialListener::new(SFX_EAR_GAP))]
 pub struct SfxListenerMarker;
 
+/// The [`SpatialScale`] positional sinks play at (see [`SFX_SPATIAL_SCALE`]).
+pub fn sfx_spatial_scale() -> SpatialScale {
+    SpatialScale::new(SFX_SPATIAL_SCALE)
+}
+
 /// The listener position for distance attenuation: the marked gameplay camera's
 /// world translation, or `None` if no listener exists yet (early startup, or
 /// the editor).
@@ -264,6 +349,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn harness_mute_silences_every_bus() {
+        let master = MasterVolume(0.8);
+        let mut buses = BusVolumes::default();
+        buses.comms = 0.5;
+        assert_eq!(
+            bus_output_gain(AudioBus::Comms, Some(&master), Some(&buses), None),
+            0.4
+        );
+        for bus in AudioBus::ALL {
+            assert_eq!(
+                bus_output_gain(bus, Some(&master), Some(&buses), Some(&HarnessMute(true))),
+                0.0,
+                "{bus:?} must be silent under the harness mute"
+            );
+        }
+        // A settings-less rig plays at full volume.
+        assert_eq!(bus_output_gain(AudioBus::Music, None, None, None), 1.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct Heard(Vec<PlaySfx>);
+
+    #[test]
+    fn positional_cues_pan_only_against_a_listener() {
+        let mut world = World::new();
+        world.init_resource::<Heard>();
+        world.add_observer(|sfx: On<PlaySfx>, mut heard: ResMut<Heard>| {
+            heard.0.push(sfx.event().clone());
+        });
+        let source = Vec3::new(-10.0, 0.0, 0.0);
+        let mut commands = world.commands();
+        play_positional_handle(
+            &mut commands,
+            Handle::default(),
+            1.0,
+            source,
+            Some(Vec3::ZERO),
+        );
+        play_positional_handle(&mut commands, Handle::default(), 1.0, source, None);
+        world.flush();
+
+        let heard = &world.resource::<Heard>().0;
+        assert_eq!(heard.len(), 2);
+        assert_eq!(heard[0].position, Some(source));
+        assert_eq!(heard[0].bus, AudioBus::Sfx);
+        assert_eq!(heard[1].position, None, "no listener plays centred");
+    }
+
     #[test]
     fn listener_position_uses_the_marked_camera_not_any_camera3d() {
         use bevy::ecs::system::SystemState;
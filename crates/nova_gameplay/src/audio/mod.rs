@@ -3,26 +3,38 @@
 //!
 //! [`SfxPlugin`] spawns a self-despawning audio entity for every [`PlaySfx`],
 //! [`SoundBank`] is a keyed registry of loaded handles, and the private
-//! `mixing` submodule owns the listener, the distance rolloff and the
-//! per-source throttle. Nothing here knows what a ship is - the mapping from
+//! `mixing` submodule owns the listener, the distance rolloff, the buses and
+//! the per-source throttle. Nothing here knows what a ship is - the mapping from
 //! gameplay events to sounds is `nova_ship`'s `ship_audio`, and the split is kept
 //! so the reusable half stays extractable once the game is done.
 //!
-//! Positional cues are **distance-attenuated**: their volume is scaled by how
-//! far the event is from the listener (the camera carrying
+//! Positional cues are **distance-attenuated and panned**: their volume is
+//! scaled by how far the event is from the listener (the camera carrying
 //! [`SfxListenerMarker`], i.e. the gameplay camera), so a distant explosion is
-//! quieter than one next to you. This is a volume-only rolloff for the
-//! cinematic feel, not true spatialization - stereo panning would need bevy
-//! spatial audio (`SpatialListener` + `spatial: true`) and is a future step.
+//! quieter than one next to you, and they play through bevy spatial sinks
+//! against the listener's [`SpatialListener`](bevy::audio::SpatialListener),
+//! so an explosion off the port side is heard on the left. The spatial sinks
+//! only pan - [`SFX_SPATIAL_SCALE`] keeps bevy's own falloff out of the way of
+//! [`distance_attenuation`].
+//!
+//! Every cue plays on an [`AudioBus`] - SFX, UI, NOVA OS, comms or music - each
+//! with its own slider in [`BusVolumes`](crate::settings::BusVolumes) under the
+//! master volume. Loop sinks that set their own volume every frame take
+//! [`bus_output_gain`], which folds in the master gain and the harness mute.
 //!
 //! The [`SoundBank<UiSfx>`] resource is inserted by `nova_assets` once assets
 //! load; every consumer degrades gracefully (does nothing) until the resources
 //! it needs exist. World sounds carry no bank at all - each cue resolves its
 //! target's authored `AssetRef` (authored-or-silent).
+//!
+//! Music is the private `music` submodule: layered stems of a [`GameMusic`]
+//! track crossfading on the [`MusicCues`] their owners raise, on their own
+//! [`AudioBus::Music`] bus.
 
 use bevy::prelude::*;
 
 mod mixing;
+mod music;
 mod registry;
 mod sfx;
 
@@ -37,20 +49,32 @@ mod sfx;
 /// defined here while the cues themselves fire from `nova_os_ui`.
 pub mod prelude {
     pub use super::{
-        sounds_loaded, NovaAudioPlugin, PlaySfx, SfxAudioMarker, SfxCommandsExt, SfxListenerMarker,
-        SfxMasterVolume, SfxPlugin, SoundBank, UiSfx, MENU_SELECT_VOLUME, NOVA_OS_BACK_VOLUME,
+        bus_output_gain, sounds_loaded, AudioBus, GameMusic, MusicCue, MusicCues, MusicDirector,
+        MusicStem, MusicStemConfig, MusicTrackConfig, NovaAudioPlugin, PlayMusicStinger, PlaySfx,
+        SfxAudioMarker, SfxCommandsExt, SfxListenerMarker, SfxMasterVolume, SfxPlugin, SoundBank,
+        UiSfx, MENU_SELECT_VOLUME, MUSIC_CROSSFADE_SECS, MUSIC_STINGER_DUCK, NOVA_OS_BACK_VOLUME,
         NOVA_OS_BED_VOLUME, NOVA_OS_COIL_VOLUME, NOVA_OS_ENTER_VOLUME, NOVA_OS_ERROR_VOLUME,
         NOVA_OS_KEY_MIN_INTERVAL, NOVA_OS_KEY_VOLUME, NOVA_OS_OK_VOLUME, NOVA_OS_POWER_VOLUME,
         NOVA_OS_TICK_VOLUME, SALVAGE_PICKUP_VOLUME, UI_SFX_FILES, UI_TOGGLE_VOLUME,
     };
 }
 
-use self::mixing::prune_sfx_throttle;
+use self::{
+    mixing::prune_sfx_throttle,
+    music::{
+        apply_music_volume, fade_music_stems, on_play_music_stinger, sync_music_track, MusicDuck,
+    },
+};
 pub use self::{
     mixing::{
-        area_cell, distance_attenuation, listener_position, play_positional_handle,
-        SfxListenerMarker, SfxThrottle, ThrottleKey, SFX_AREA_CELL, SFX_FAR_DISTANCE,
-        SFX_NEAR_DISTANCE,
+        area_cell, bus_output_gain, distance_attenuation, listener_position,
+        play_positional_handle, sfx_spatial_scale, AudioBus, SfxListenerMarker, SfxThrottle,
+        ThrottleKey, SFX_AREA_CELL, SFX_EAR_GAP, SFX_FAR_DISTANCE, SFX_NEAR_DISTANCE,
+        SFX_SPATIAL_SCALE,
+    },
+    music::{
+        GameMusic, MusicCue, MusicCues, MusicDirector, MusicStem, MusicStemConfig,
+        MusicTrackConfig, PlayMusicStinger, MUSIC_CROSSFADE_SECS, MUSIC_STINGER_DUCK,
     },
     registry::{sounds_loaded, SoundBank},
     sfx::{PlaySfx, SfxAudioMarker, SfxCommandsExt, SfxMasterVolume, SfxPlugin},
@@ -160,8 +184,8 @@ pub const NOVA_OS_TICK_VOLUME: f32 = 0.12;
 pub const NOVA_OS_COIL_VOLUME: f32 = 0.26;
 /// The power-up / power-down sweep.
 pub const NOVA_OS_POWER_VOLUME: f32 = 0.30;
-/// Base volume of the ambient bed loop, before
-/// [`MasterVolume`](crate::settings::MasterVolume). The bed WAV is
+/// Base volume of the ambient bed loop, before the [`AudioBus::NovaOs`] bus
+/// and [`MasterVolume`](crate::settings::MasterVolume). The bed WAV is
 /// authored quiet (~-35 dBFS), so this sits it as a soft under-hum.
 pub const NOVA_OS_BED_VOLUME: f32 = 0.7;
 /// Minimum real seconds between successive typing clicks, so OS key-repeat on a
@@ -169,8 +193,9 @@ pub const NOVA_OS_BED_VOLUME: f32 = 0.7;
 /// precedent, applied inline with a `Local` since this is one global stream).
 pub const NOVA_OS_KEY_MIN_INTERVAL: f32 = 0.03;
 
-/// Plugin for the reusable audio engine: fire-and-forget [`PlaySfx`] playback
-/// and the per-source throttle every positional cue mixes through. The cues
+/// Plugin for the reusable audio engine: fire-and-forget [`PlaySfx`] playback,
+/// the per-source throttle every positional cue mixes through, and the layered
+/// music player. The cues
 /// themselves are added by their own subsystems - the ship's by
 /// `nova_ship`'s `ShipAudioPlugin`.
 #[derive(Default)]
@@ -190,6 +215,26 @@ impl Plugin for NovaAudioPlugin {
 
         // Pure map cleanup; harmless to run always and keeps memory bounded.
         app.add_systems(Update, prune_sfx_throttle);
+
+        // Music: the catalog is filled by the mod merge, the director by the
+        // `SetMusic` action, the cues by whoever senses them. Ungated by pause
+        // or state - the score keeps playing under the pause overlay.
+        app.init_resource::<GameMusic>();
+        app.init_resource::<MusicDirector>();
+        app.init_resource::<MusicCues>();
+        app.init_resource::<MusicDuck>();
+        app.register_type::<MusicDirector>();
+        app.add_observer(on_play_music_stinger);
+        app.add_systems(
+            Update,
+            (
+                sync_music_track
+                    .run_if(resource_changed::<MusicDirector>.or(resource_changed::<GameMusic>)),
+                fade_music_stems,
+                apply_music_volume,
+            )
+                .chain(),
+        );
     }
 }
 
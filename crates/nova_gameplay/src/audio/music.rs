@@ -0,0 +1,440 @@
+//! Layered adaptive music: a TRACK is a set of looping stems started together,
+//! each one gated by a [`MusicCue`], so the arrangement thickens and thins with
+//! what the player is doing instead of switching songs.
+//!
+//! Every stem of the playing track runs for the whole track at once (they stay
+//! in lockstep because they start on the same frame); a stem whose cue is off
+//! simply holds volume 0. A cue turning on or off crossfades that stem, and a
+//! track change ([`MusicDirector`]) fades the old stems out while the new ones
+//! fade in. Nothing here knows what a ship or a scenario is: the cue flags in
+//! [`MusicCues`] are written by their owners - `nova_hud`'s situation sensing
+//! for the flight/combat cues, `nova_scenario` for the scenario variables - and
+//! tracks themselves are mod content registered into [`GameMusic`].
+//!
+//! Music plays on its own bus, [`AudioBus::Music`], under [`MasterVolume`].
+//! Stems set their own sink volume every frame, so (like the thruster loop)
+//! they apply [`bus_output_gain`] themselves rather than going through bevy's
+//! `GlobalVolume`.
+
+use std::collections::HashSet;
+
+use bevy::{audio::Volume, prelude::*};
+
+use super::{
+    mixing::{bus_output_gain, AudioBus},
+    sfx::PlaySfx,
+};
+use crate::{
+    asset_ref::AssetRef,
+    settings::{BusVolumes, HarnessMute, MasterVolume},
+};
+
+/// Seconds a stem takes to fade fully in or out when a track does not author
+/// its own `crossfade`. Long enough that a cue flickering (a trigger tap) does
+/// not pump the mix, short enough that a lock reads as the music reacting.
+pub const MUSIC_CROSSFADE_SECS: f32 = 1.5;
+
+/// Linear factor the stems are ducked to while a stinger plays over them.
+pub const MUSIC_STINGER_DUCK: f32 = 0.35;
+
+/// What turns a stem on. A stem with no cue authored plays for the whole track.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MusicCue {
+    /// Always audible - the bed the other stems layer over.
+    #[default]
+    Always,
+    /// The autopilot is flying a maneuver (a GOTO burn, an orbit, a stop).
+    Maneuver,
+    /// The player holds a combat lock.
+    CombatLock,
+    /// The player's weapons safety is off.
+    WeaponsHot,
+    /// A player weapon trigger is down.
+    Firing,
+    /// Some player weapon group is at or below its low-ammo mark.
+    LowAmmo,
+    /// The named scenario variable is truthy (`true`, a non-zero number or a
+    /// non-empty string) - the hook a scenario uses to score its own beats.
+    Variable(String),
+}
+
+/// One stem of a [`MusicTrackConfig`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicStemConfig {
+    /// The looping audio, authored as a path - `self://music/drums.ogg` from a
+    /// mod - or a live handle in code-built configs.
+    pub sound: AssetRef<AudioSource>,
+    /// What turns this stem on. Omit for a stem that always plays.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cue: MusicCue,
+    /// Linear mix level of this stem (`0.0..=1.0`). `None` is full level.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub volume: Option<f32>,
+}
+
+impl MusicStemConfig {
+    /// The clamped mix level this stem fades up to when its cue is on.
+    pub fn gain(&self) -> f32 {
+        self.volume.unwrap_or(1.0).clamp(0.0, 1.0)
+    }
+}
+
+/// A music track: its id (what `SetMusic` names) and its layered stems.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicTrackConfig {
+    /// The track id, unique across the merged content.
+    pub id: String,
+    /// The stems, started together and kept looping for the whole track.
+    pub stems: Vec<MusicStemConfig>,
+    /// Seconds a stem takes to fade fully in or out. `None` is
+    /// [`MUSIC_CROSSFADE_SECS`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub crossfade: Option<f32>,
+}
+
+impl MusicTrackConfig {
+    /// The crossfade time in seconds, floored so a zero never divides.
+    pub fn crossfade_secs(&self) -> f32 {
+        self.crossfade
+            .unwrap_or(MUSIC_CROSSFADE_SECS)
+            .max(f32::EPSILON)
+    }
+}
+
+/// The loaded catalog of music tracks, filled by the mod merge exactly as the
+/// style catalog is. Look one up by id with [`get_track`](GameMusic::get_track).
+#[derive(Resource, Clone, Debug, Deref, DerefMut, Default)]
+pub struct GameMusic(pub Vec<MusicTrackConfig>);
+
+impl GameMusic {
+    /// The track with this id, or `None` if nothing authored it.
+    pub fn get_track(&self, id: &str) -> Option<&MusicTrackConfig> {
+        self.0.iter().find(|track| track.id == id)
+    }
+}
+
+/// Which track should be playing. Written by the `SetMusic` scenario action
+/// (and reset to silence at scenario teardown); the music systems converge the
+/// live stems on it.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct MusicDirector {
+    /// The wanted track id; `None` fades the music out.
+    pub track: Option<String>,
+}
+
+/// The live cue flags the stems are gated on. Owned by nobody in this crate:
+/// each field is written by the system that senses it, and an app without that
+/// system simply never raises the cue.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MusicCues {
+    /// See [`MusicCue::Maneuver`].
+    pub maneuver: bool,
+    /// See [`MusicCue::CombatLock`].
+    pub combat_lock: bool,
+    /// See [`MusicCue::WeaponsHot`].
+    pub weapons_hot: bool,
+    /// See [`MusicCue::Firing`].
+    pub firing: bool,
+    /// See [`MusicCue::LowAmmo`].
+    pub low_ammo: bool,
+    /// The scenario variables that are currently truthy.
+    pub variables: HashSet<String>,
+}
+
+impl MusicCues {
+    /// Whether `cue` is on right now.
+    pub fn is_active(&self, cue: &MusicCue) -> bool {
+        match cue {
+            MusicCue::Always => true,
+            MusicCue::Maneuver => self.maneuver,
+            MusicCue::CombatLock => self.combat_lock,
+            MusicCue::WeaponsHot => self.weapons_hot,
+            MusicCue::Firing => self.firing,
+            MusicCue::LowAmmo => self.low_ammo,
+            MusicCue::Variable(name) => self.variables.contains(name),
+        }
+    }
+}
+
+/// Request to play a one-shot stinger over the music: on the music bus (it is
+/// music, not SFX) and ducking the stems for `duck`
+/// seconds so it cuts through.
+#[derive(Event, Clone, Debug)]
+pub struct PlayMusicStinger {
+    /// The stinger clip.
+    pub handle: Handle<AudioSource>,
+    /// Per-stinger linear volume, before the music bus.
+    pub volume: f32,
+    /// Seconds to hold the stems at [`MUSIC_STINGER_DUCK`]; 0 ducks nothing.
+    pub duck: f32,
+}
+
+/// Seconds of stinger duck left, counted down on the real clock.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub(crate) struct MusicDuck(f32);
+
+/// One live stem: the looping audio entity and the level it is fading through.
+/// Spawned silent by [`sync_music_track`]; [`fade_music_stems`] moves `level`
+/// toward the cue's target and despawns a retired stem once it reaches 0.
+#[derive(Component, Clone, Debug)]
+pub struct MusicStem {
+    /// The id of the track this stem belongs to.
+    pub track: String,
+    /// The cue that turns this stem on.
+    pub cue: MusicCue,
+    /// The stem's authored mix level.
+    pub gain: f32,
+    /// Seconds a full fade takes (the track's crossfade).
+    pub fade_secs: f32,
+    /// The current level, `0.0..=gain`, before the volume channels.
+    pub level: f32,
+    /// The track moved on: fade to 0 and despawn.
+    pub retiring: bool,
+}
+
+/// Converge the live stems on [`MusicDirector`]: retire the stems of any other
+/// track and spawn the wanted track's stems silent. Gated on a director or
+/// catalog change, so an unknown id warns once per request instead of every
+/// frame; the same track re-requested keeps playing from where it is.
+pub(crate) fn sync_music_track(
+    mut commands: Commands,
+    director: Res<MusicDirector>,
+    music: Res<GameMusic>,
+    asset_server: Res<AssetServer>,
+    mut q_stems: Query<&mut MusicStem>,
+) {
+    let wanted = director.track.as_deref();
+    let playing = q_stems
+        .iter()
+        .find(|stem| !stem.retiring)
+        .map(|stem| stem.track.clone());
+    if playing.as_deref() == wanted {
+        return;
+    }
+    for mut stem in &mut q_stems {
+        stem.retiring = true;
+    }
+    let Some(id) = wanted else {
+        return;
+    };
+    let Some(track) = music.get_track(id) else {
+        warn!("music: no track '{id}' is registered; the music stays silent");
+        return;
+    };
+    debug!("music: starting '{id}' ({} stem(s))", track.stems.len());
+    for stem in &track.stems {
+        commands.spawn((
+            Name::new(format!("Music Stem ({id})")),
+            MusicStem {
+                track: id.to_string(),
+                cue: stem.cue.clone(),
+                gain: stem.gain(),
+                fade_secs: track.crossfade_secs(),
+                level: 0.0,
+                retiring: false,
+            },
+            AudioPlayer(stem.sound.resolve(&asset_server)),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+        ));
+    }
+}
+
+/// Fade every stem toward its target - its gain while its cue is on (ducked
+/// under a stinger), 0 otherwise or once retired - at a constant rate of one
+/// full level per crossfade, and despawn retired stems that reached silence.
+/// Real time, not virtual: the sinks play on the wall clock, so a pause must
+/// not freeze a fade half-way.
+pub(crate) fn fade_music_stems(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    cues: Res<MusicCues>,
+    mut duck: ResMut<MusicDuck>,
+    mut q_stems: Query<(Entity, &mut MusicStem)>,
+) {
+    let dt = time.delta_secs();
+    duck.0 = (duck.0 - dt).max(0.0);
+    let duck_factor = if duck.0 > 0.0 {
+        MUSIC_STINGER_DUCK
+    } else {
+        1.0
+    };
+    for (entity, mut stem) in &mut q_stems {
+        let target = if !stem.retiring && cues.is_active(&stem.cue) {
+            stem.gain * duck_factor
+        } else {
+            0.0
+        };
+        let step = dt / stem.fade_secs;
+        stem.level += (target - stem.level).clamp(-step, step);
+        if stem.retiring && stem.level <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Copy each stem's level onto its sink through the music bus and master
+/// gain. The `AudioSink` appears a frame or two after the stem spawns, so this
+/// no-ops until then. The volume resources are `Option` so audio-only rigs
+/// without the settings plugin play at full volume instead of panicking.
+pub(crate) fn apply_music_volume(
+    master: Option<Res<MasterVolume>>,
+    buses: Option<Res<BusVolumes>>,
+    mute: Option<Res<HarnessMute>>,
+    mut q_sink: Query<(&mut AudioSink, &MusicStem)>,
+) {
+    let gain = bus_output_gain(
+        AudioBus::Music,
+        master.as_deref(),
+        buses.as_deref(),
+        mute.as_deref(),
+    );
+    for (mut sink, stem) in &mut q_sink {
+        sink.set_volume(Volume::Linear(stem.level * gain));
+    }
+}
+
+/// Play a [`PlayMusicStinger`] as a one-shot on the music bus and arm the
+/// duck. The one-shot itself goes through [`PlaySfx`], so it picks up the
+/// master gain (and the scenario scoping) like every other cue.
+pub(crate) fn on_play_music_stinger(
+    event: On<PlayMusicStinger>,
+    mut commands: Commands,
+    mut duck: ResMut<MusicDuck>,
+) {
+    commands.trigger(
+        PlaySfx::new(event.handle.clone())
+            .with_volume(event.volume)
+            .with_bus(AudioBus::Music),
+    );
+    duck.0 = duck.0.max(event.duck);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    fn music_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+        app.init_asset::<AudioSource>();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.init_resource::<MusicDirector>();
+        app.init_resource::<MusicCues>();
+        app.init_resource::<MusicDuck>();
+        app.insert_resource(GameMusic(vec![
+            track("calm", MusicCue::Always),
+            track("fight", MusicCue::CombatLock),
+        ]));
+        app.add_systems(Update, (sync_music_track, fade_music_stems).chain());
+        app
+    }
+
+    fn track(id: &str, cue: MusicCue) -> MusicTrackConfig {
+        MusicTrackConfig {
+            id: id.to_string(),
+            stems: vec![
+                MusicStemConfig {
+                    sound: AssetRef::Handle(Handle::default()),
+                    cue: MusicCue::Always,
+                    volume: None,
+                },
+                MusicStemConfig {
+                    sound: AssetRef::Handle(Handle::default()),
+                    cue,
+                    volume: Some(0.5),
+                },
+            ],
+            crossfade: Some(1.0),
+        }
+    }
+
+    fn stems(app: &mut App) -> Vec<MusicStem> {
+        let mut query = app.world_mut().query::<&MusicStem>();
+        query.iter(app.world()).cloned().collect()
+    }
+
+    fn run(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    /// A cued stem sits silent beside the bed until its cue comes on, then
+    /// crossfades up to its authored level - and back down when it clears.
+    #[test]
+    fn a_cued_stem_fades_with_its_cue() {
+        let mut app = music_app();
+        app.world_mut().resource_mut::<MusicDirector>().track = Some("fight".into());
+        run(&mut app, 8);
+        let levels: Vec<f32> = stems(&mut app).iter().map(|s| s.level).collect();
+        assert_eq!(levels.len(), 2, "both stems start together");
+        assert!(levels.contains(&1.0), "the bed is up: {levels:?}");
+        assert!(levels.contains(&0.0), "the lock stem waits: {levels:?}");
+
+        app.world_mut().resource_mut::<MusicCues>().combat_lock = true;
+        run(&mut app, 8);
+        assert!(
+            stems(&mut app).iter().all(|s| s.level == s.gain),
+            "a lock brings the combat stem up to its mix level"
+        );
+
+        app.world_mut().resource_mut::<MusicCues>().combat_lock = false;
+        run(&mut app, 8);
+        assert!(stems(&mut app)
+            .iter()
+            .any(|s| s.cue == MusicCue::CombatLock && s.level == 0.0));
+    }
+
+    /// A track change fades the old stems out and despawns them, while the
+    /// new track's stems fade in; silence retires everything.
+    #[test]
+    fn a_track_change_retires_the_old_stems() {
+        let mut app = music_app();
+        app.world_mut().resource_mut::<MusicDirector>().track = Some("calm".into());
+        run(&mut app, 8);
+        app.world_mut().resource_mut::<MusicDirector>().track = Some("fight".into());
+        app.update();
+        let live = stems(&mut app);
+        assert_eq!(live.len(), 4, "old stems linger while they fade");
+        assert!(live
+            .iter()
+            .filter(|s| s.track == "calm")
+            .all(|s| s.retiring));
+
+        run(&mut app, 8);
+        assert!(
+            stems(&mut app).iter().all(|s| s.track == "fight"),
+            "the faded-out track is gone"
+        );
+
+        app.world_mut().resource_mut::<MusicDirector>().track = None;
+        run(&mut app, 8);
+        assert!(stems(&mut app).is_empty(), "silence retires every stem");
+    }
+
+    #[test]
+    fn variable_cues_read_the_truthy_set() {
+        let mut cues = MusicCues::default();
+        let cue = MusicCue::Variable("boss_phase".into());
+        assert!(!cues.is_active(&cue));
+        cues.variables.insert("boss_phase".into());
+        assert!(cues.is_active(&cue));
+        assert!(cues.is_active(&MusicCue::Always));
+    }
+}
@@ -8,8 +8,10 @@
 //! mute toggle.
 //!
 //! This is deliberately just one concern: transient, non-looping SFX. It is
-//! not a music player or a mixer; for looping background music spawn an
-//! `AudioPlayer` with `PlaybackSettings::LOOP` directly.
+//! not a music player; for looping background music spawn an `AudioPlayer`
+//! with `PlaybackSettings::LOOP` directly. A request does name its mixer
+//! [`AudioBus`] and, for a world cue, the position it pans from - the mixing
+//! layer's policy, applied here because this is where the sink is spawned.
 //!
 //! ```rust
 //! # use bevy::prelude::*;
@@ -25,11 +27,13 @@
 //!
 //! Nova owns this because every cue in the game - the ship's combat
 //! one-shots, the HUD clicks and the menu blips - is fired through [`PlaySfx`],
-//! and the
-//! master volume it scales by is the slider in nova's settings screen.
+//! and the bus volumes it scales by are the sliders in nova's settings screen.
 
 use bevy::{audio::Volume, prelude::*};
 
+use super::mixing::{sfx_spatial_scale, AudioBus};
+use crate::settings::BusVolumes;
+
 /// Request to play a one-shot sound effect.
 ///
 /// Trigger it with `commands.trigger(PlaySfx::new(handle))`; [`SfxPlugin`]
@@ -47,6 +51,14 @@ pub struct PlaySfx {
     /// Playback speed, which also shifts pitch (1.0 is normal). Handy for
     /// adding variation, e.g. nudging the pitch up as a combo grows.
     pub speed: f32,
+
+    /// The mixer bus the sound plays on, scaled by its [`BusVolumes`] slider.
+    pub bus: AudioBus,
+
+    /// The world position a positional cue pans from, relative to the
+    /// listener camera. `None` plays centred (interface sounds, and any cue
+    /// fired with no listener to pan against).
+    pub position: Option<Vec3>,
 }
 
 impl PlaySfx {
@@ -56,6 +68,8 @@ impl PlaySfx {
             handle,
             volume: 1.0,
             speed: 1.0,
+            bus: AudioBus::default(),
+            position: None,
         }
     }
 
@@ -70,6 +84,18 @@ impl PlaySfx {
         self.speed = speed;
         self
     }
+
+    /// Route the sound through `bus`.
+    pub fn with_bus(mut self, bus: AudioBus) -> Self {
+        self.bus = bus;
+        self
+    }
+
+    /// Pan the sound from world position `position`.
+    pub fn at(mut self, position: Vec3) -> Self {
+        self.position = Some(position);
+        self
+    }
 }
 
 /// Marks the audio entity behind one [`PlaySfx`] one-shot.
@@ -102,6 +128,9 @@ pub trait SfxCommandsExt {
 
     /// Play `handle` once with a per-shot volume multiplier.
     fn play_sfx_volume(&mut self, handle: Handle<AudioSource>, volume: f32);
+
+    /// Play `handle` once with a per-shot volume multiplier on `bus`.
+    fn play_sfx_on(&mut self, bus: AudioBus, handle: Handle<AudioSource>, volume: f32);
 }
 
 impl SfxCommandsExt for Commands<'_, '_> {
@@ -112,6 +141,10 @@ impl SfxCommandsExt for Commands<'_, '_> {
     fn play_sfx_volume(&mut self, handle: Handle<AudioSource>, volume: f32) {
         self.trigger(PlaySfx::new(handle).with_volume(volume));
     }
+
+    fn play_sfx_on(&mut self, bus: AudioBus, handle: Handle<AudioSource>, volume: f32) {
+        self.trigger(PlaySfx::new(handle).with_volume(volume).with_bus(bus));
+    }
 }
 
 /// Plugin that enables fire-and-forget SFX playback via [`PlaySfx`].
@@ -130,20 +163,40 @@ impl Plugin for SfxPlugin {
 }
 
 /// Spawn a self-despawning [`AudioPlayer`] for each [`PlaySfx`], scaled by the
-/// master volume. `PlaybackSettings::DESPAWN` retires the entity once the clip
-/// finishes, so callers never have to clean it up.
-fn on_play_sfx(event: On<PlaySfx>, mut commands: Commands, master: Res<SfxMasterVolume>) {
-    let volume = (event.volume * master.0).max(0.0);
+/// master volume and its bus. `PlaybackSettings::DESPAWN` retires the entity
+/// once the clip finishes, so callers never have to clean it up. A positional
+/// request spawns a spatial sink at its position, which bevy pans against the
+/// listener's ears; `buses` is `Option` for rigs without the settings plugin.
+fn on_play_sfx(
+    event: On<PlaySfx>,
+    mut commands: Commands,
+    master: Res<SfxMasterVolume>,
+    buses: Option<Res<BusVolumes>>,
+) {
+    let bus = buses.map_or(1.0, |b| b.factor(event.bus));
+    let volume = (event.volume * master.0 * bus).max(0.0);
     // NOTE: rodio does not accept a non-positive playback rate.
     let speed = event.speed.max(f32::MIN_POSITIVE);
-    trace!("on_play_sfx: volume {volume}, speed {speed}");
-
-    commands.spawn((
+    trace!(
+        "on_play_sfx: volume {volume}, speed {speed}, bus {:?}",
+        event.bus
+    );
+
+    let settings = PlaybackSettings::DESPAWN
+        .with_volume(Volume::Linear(volume))
+        .with_speed(speed);
+    let mut sfx = commands.spawn((
         Name::new("Sfx"),
         SfxAudioMarker,
         AudioPlayer(event.handle.clone()),
-        PlaybackSettings::DESPAWN
-            .with_volume(Volume::Linear(volume))
-            .with_speed(speed),
     ));
+    match event.position {
+        Some(position) => sfx.insert((
+            settings
+                .with_spatial(true)
+                .with_spatial_scale(sfx_spatial_scale()),
+            Transform::from_translation(position),
+        )),
+        None => sfx.insert(settings),
+    };
 }
@@ -4,7 +4,8 @@
 //! [`crate::integrity::health`] owns the HP store: one `on_damage` observer
 //! subtracts `HealthApplyDamage.amount`, marks the node at zero, and propagates
 //! up `ChildOf`. Damage reaching it is final - [`apply_damage`] is the single
-//! place a weapon enters that store.
+//! place a weapon enters that store, and the place the difficulty's
+//! incoming-damage factor is applied, before the event exists.
 //!
 //! A damage TYPE is not a set of multipliers. It is a way of travelling, which
 //! is the thing a player can watch happen: a Kinetic slug punches (high per-hit,
@@ -27,7 +28,8 @@ use crate::{
         carve::prelude::{record_blast_marks, record_damage_mark},
         health::prelude::{Health, HealthApplyDamage, HealthZeroMarker},
     },
-    markers::SectionMarker,
+    markers::{PlayerSpaceshipMarker, SectionMarker},
+    settings::DifficultyPolicy,
 };
 
 /// The damage types and colours, blast spawning, the closing-speed curves, the
@@ -95,17 +97,32 @@ pub struct ProjectileDamage {
     pub layers: u32,
     /// Which travel rule and which speed curve this projectile uses.
     pub kind: DamageType,
+    /// What one hit carves out of a ROCK - a body with no health pool, which
+    /// pays for a hit in material - as a multiple of `amount`. 1.0 for a combat
+    /// round; a mining round carries more, so it cuts rock fast while a ship
+    /// still only takes `amount`.
+    pub rock_multiplier: f32,
 }
 
 impl ProjectileDamage {
     /// A freshly fired round: full [`PIERCE_BASE_POWER`] and
-    /// [`MAX_PIERCE_LAYERS`], which only a Pierce round reads.
+    /// [`MAX_PIERCE_LAYERS`], which only a Pierce round reads, and no mining
+    /// bonus.
     pub fn new(amount: f32, kind: DamageType) -> Self {
         Self {
             amount,
             power: PIERCE_BASE_POWER,
             layers: MAX_PIERCE_LAYERS,
             kind,
+            rock_multiplier: 1.0,
+        }
+    }
+
+    /// The same round with a [`rock_multiplier`](Self::rock_multiplier).
+    pub fn with_rock_multiplier(self, rock_multiplier: f32) -> Self {
+        Self {
+            rock_multiplier,
+            ..self
         }
     }
 }
@@ -262,9 +279,10 @@ pub fn damage_type_color(kind: DamageType) -> Color {
 /// `at` in WORLD space.
 ///
 /// The single point at which a weapon enters the health store, so every
-/// weapon - turret, torpedo blast, ram - lands identically. It is a plain
-/// trigger: damage is still one number, and nothing between the weapon and
-/// [`on_damage`](crate::integrity::health) reinterprets it.
+/// weapon - turret, torpedo blast, ram - lands identically. Damage is still
+/// one number, and the only thing that touches it on the way is the
+/// difficulty's incoming-damage factor on the player's ship (see
+/// [`trigger_hit`]); nothing after the trigger reinterprets it.
 ///
 /// `at` goes to a different store, and that split is the point: health
 /// remembers how MUCH was spent, [`DamageMarks`] remembers WHERE, and a body
@@ -288,11 +306,7 @@ pub fn apply_damage(
     if let Some(at) = at {
         record_damage_mark(commands, target, at, amount, kind);
     }
-    commands.trigger(HealthApplyDamage {
-        entity: target,
-        source,
-        amount,
-    });
+    trigger_hit(commands, target, source, amount);
 }
 
 /// Deal a blast's pressure to everything it reached: health PER COLLIDER, one
@@ -322,12 +336,48 @@ pub fn apply_blast_damage(
 ) {
     record_blast_marks(commands, at, max_radius, hits.to_vec(), kind);
     for &(target, amount) in hits {
-        commands.trigger(HealthApplyDamage {
+        trigger_hit(commands, target, source, amount);
+    }
+}
+
+/// Trigger one hit on the health store, scaled first by the difficulty's
+/// [`incoming_damage`](DifficultyPolicy::incoming_damage) when `target` is the
+/// player's ship or hangs beneath it.
+///
+/// The factor goes on before the event is triggered, never inside an
+/// observer of it: observers of one event run in no defined order, so one
+/// that rescaled `amount` would leave its siblings - the hit feedback, the
+/// threat model - reading the scaled number or the raw one depending on who
+/// ran first. The carve keeps the weapon's own number; a tier changes what a
+/// hit costs, not the shape it leaves.
+fn trigger_hit(commands: &mut Commands, target: Entity, source: Option<Entity>, amount: f32) {
+    commands.queue(move |world: &mut World| {
+        let amount = amount * incoming_damage_factor(world, target);
+        world.trigger(HealthApplyDamage {
             entity: target,
             source,
             amount,
         });
+    });
+}
+
+/// The incoming-damage factor for a hit on `target`: the policy's on the
+/// player's ship, 1 anywhere else or with no policy.
+fn incoming_damage_factor(world: &World, target: Entity) -> f32 {
+    let factor = world
+        .get_resource::<DifficultyPolicy>()
+        .map_or(1.0, |policy| policy.incoming_damage);
+    if factor == 1.0 {
+        return 1.0;
+    }
+    let mut node = Some(target);
+    while let Some(entity) = node {
+        if world.get::<PlayerSpaceshipMarker>(entity).is_some() {
+            return factor;
+        }
+        node = world.get::<ChildOf>(entity).map(ChildOf::parent);
     }
+    1.0
 }
 
 /// The damage ONE hit delivers, before the health store clamps it to what the
@@ -1121,6 +1171,53 @@ mod tests {
         );
     }
 
+    /// The difficulty scales a hit on the player's ship once, before the event
+    /// exists; the root it bubbles into is charged the same scaled number, and
+    /// nobody else's ship is touched.
+    #[test]
+    fn incoming_damage_scales_only_the_players_ship_and_only_once() {
+        /// The amount each hit's observers saw at its original target.
+        #[derive(Resource, Default)]
+        struct Seen(Vec<f32>);
+
+        let mut app = App::new();
+        app.add_plugins(crate::integrity::health::NovaHealthPlugin);
+        app.insert_resource(DifficultyPolicy {
+            incoming_damage: 2.0,
+            ..Default::default()
+        });
+        let player = app
+            .world_mut()
+            .spawn((Health::new(200.0), PlayerSpaceshipMarker))
+            .id();
+        let section = app
+            .world_mut()
+            .spawn((Health::new(100.0), ChildOf(player)))
+            .id();
+        let enemy = app.world_mut().spawn(Health::new(100.0)).id();
+        app.init_resource::<Seen>();
+        app.add_observer(|hit: On<HealthApplyDamage>, mut seen: ResMut<Seen>| {
+            if hit.entity == hit.original_event_target() {
+                seen.0.push(hit.amount);
+            }
+        });
+
+        let mut commands = app.world_mut().commands();
+        for entity in [section, enemy] {
+            apply_damage(&mut commands, entity, None, 10.0, DamageType::Kinetic, None);
+        }
+        app.world_mut().flush();
+
+        assert_eq!(health(&app, section), 80.0);
+        assert_eq!(health(&app, player), 180.0);
+        assert_eq!(health(&app, enemy), 90.0);
+        assert_eq!(
+            app.world().resource::<Seen>().0,
+            vec![20.0, 10.0],
+            "every observer reads the scaled hit, whatever order it runs in"
+        );
+    }
+
     #[test]
     fn the_health_store_subtracts_exactly_what_the_weapon_decided() {
         // The one-application-point contract end to end: a weapon triggers
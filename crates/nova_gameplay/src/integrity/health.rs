@@ -14,6 +14,12 @@
 //!
 //! [`HealthIsolated`] opts a node out of that bubbling entirely, for pools that
 //! stand IN FRONT of the thing they hang off rather than being part of it.
+//!
+//! A reader that wants the number a hit SETTLED at - after the clamp, not
+//! before it - observes [`HealthDamageLanded`], which `on_damage` triggers once
+//! it has spent the hit. Reading `amount` off [`HealthApplyDamage`] from a
+//! sibling observer gets the raw number or the clamped one depending on which
+//! of them ran first.
 
 use bevy::prelude::*;
 
@@ -21,8 +27,8 @@ use bevy::prelude::*;
 /// `NovaHealthPlugin`.
 pub mod prelude {
     pub use super::{
-        destructible_body, Health, HealthApplyDamage, HealthIsolated, HealthZeroMarker,
-        NovaHealthPlugin,
+        destructible_body, Health, HealthApplyDamage, HealthDamageLanded, HealthIsolated,
+        HealthZeroMarker, NovaHealthPlugin,
     };
 }
 
@@ -67,10 +73,11 @@ pub struct HealthIsolated;
 
 /// Spend `amount` hit points on `entity`.
 ///
-/// `amount` is the FINAL number: the weapon decided it (see
-/// [`apply_damage`](crate::damage::apply_damage), the one path in) and nothing
-/// between there and here reinterprets it. Trigger this directly only where
-/// there is no weapon behind the hit (a test rig, a scripted scenario beat).
+/// `amount` is the FINAL number: the weapon decided it and
+/// [`apply_damage`](crate::damage::apply_damage), the one path in, applied the
+/// difficulty before triggering it, so nothing here reinterprets it. Trigger
+/// this directly only where there is no weapon behind the hit (a test rig, a
+/// scripted scenario beat).
 ///
 /// Propagates up `ChildOf` carrying only what landed - see the module docs.
 #[derive(EntityEvent, Clone, Debug)]
@@ -85,6 +92,23 @@ pub struct HealthApplyDamage {
     pub amount: f32,
 }
 
+/// What a [`HealthApplyDamage`] actually spent at the node it was aimed at,
+/// triggered by `on_damage` once the hit is settled.
+///
+/// One per hit, at the original target and never at the ancestors it bubbled
+/// through; `amount` is what that node's pool lost, so a killing blow carries
+/// the health it took and not its overkill. A hit on a pool already at zero,
+/// or on a node with no pool at all, lands nothing and triggers nothing.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct HealthDamageLanded {
+    /// The node the hit was aimed at.
+    pub entity: Entity,
+    /// The hit's source, as [`HealthApplyDamage::source`] carried it.
+    pub source: Option<Entity>,
+    /// Hit points the node's pool lost.
+    pub amount: f32,
+}
+
 /// Registers the health store: the `on_damage` observer and the reflected types.
 #[derive(Default)]
 pub struct NovaHealthPlugin;
@@ -101,7 +125,7 @@ impl Plugin for NovaHealthPlugin {
 }
 
 /// Subtract a hit from a node's pool, mark it at zero, and propagate only what
-/// landed.
+/// landed. At the original target, announce that as [`HealthDamageLanded`].
 ///
 /// The propagated amount is clamped to the node's remaining health, which is
 /// what stops overkill on a child from teleporting into a parent aggregate.
@@ -145,6 +169,13 @@ fn on_damage(
     let applied = damage.amount.min(health.current);
     health.current -= applied;
     damage.amount = applied;
+    if entity == damage.original_event_target() && applied > 0.0 {
+        commands.trigger(HealthDamageLanded {
+            entity,
+            source: damage.source,
+            amount: applied,
+        });
+    }
     if health.current <= 0.0 {
         health.current = 0.0;
         commands.entity(entity).insert(HealthZeroMarker);
@@ -331,4 +362,39 @@ mod tests {
         assert!(app.world().get::<HealthZeroMarker>(child).is_some());
         assert!(app.world().get::<HealthZeroMarker>(parent).is_some());
     }
+
+    /// A hit lands once, at the node it was aimed at, carrying what that pool
+    /// lost: a killing blow reports the health it took, never its overkill,
+    /// and the parent it bubbled through reports nothing.
+    #[test]
+    fn a_hit_lands_once_with_what_its_target_lost() {
+        #[derive(Resource, Default)]
+        struct Landed(Vec<(Entity, f32)>);
+
+        let mut app = health_app();
+        app.init_resource::<Landed>();
+        app.add_observer(|landed: On<HealthDamageLanded>, mut seen: ResMut<Landed>| {
+            seen.0.push((landed.entity, landed.amount));
+        });
+        let parent = app.world_mut().spawn(Health::new(200.0)).id();
+        let child = app
+            .world_mut()
+            .spawn((Health::new(30.0), ChildOf(parent)))
+            .id();
+
+        for amount in [20.0, 1000.0, 50.0] {
+            app.world_mut().trigger(HealthApplyDamage {
+                entity: child,
+                source: None,
+                amount,
+            });
+            app.world_mut().flush();
+        }
+
+        assert_eq!(
+            app.world().resource::<Landed>().0,
+            vec![(child, 20.0), (child, 10.0)],
+            "the third hit found a corpse and landed nothing"
+        );
+    }
 }
@@ -202,8 +202,12 @@ impl FlashSettings {
 #[derive(Resource, Clone, Debug, Reflect)]
 #[reflect(Resource)]
 pub struct JuiceSettings {
-    /// Kill switch for all juice at once (a settings-menu "reduce motion" toggle).
+    /// Kill switch for all juice at once (the Low graphics preset).
     pub master_enabled: bool,
+    /// Multiplier over every kick and flash, `0.0..=1.0`. Zero under the
+    /// reduced-motion accessibility setting, which scales the juice away
+    /// without touching the toggles the graphics preset owns.
+    pub motion_scale: f32,
     /// Camera-shake tunables.
     pub shake: ShakeSettings,
     /// Flash-FX tunables.
@@ -218,6 +222,7 @@ impl Default for JuiceSettings {
     fn default() -> Self {
         Self {
             master_enabled: true,
+            motion_scale: 1.0,
             shake: ShakeSettings::default(),
             flash: FlashSettings::default(),
             // Only a near, in-your-face event shakes at full strength; the camera
@@ -474,9 +479,10 @@ fn emit_juice(
             settings.near_distance,
             settings.far_distance,
         )
-    });
+    }) * settings.motion_scale.clamp(0.0, 1.0);
     // Fully attenuated events do nothing at all - no kick, no ring, no throttle
-    // stamp - so a far-off skirmish stays quiet even before throttling.
+    // stamp - so a far-off skirmish (or any event under reduced motion) stays
+    // quiet even before throttling.
     if falloff <= 0.0 {
         return;
     }
@@ -1061,4 +1067,24 @@ mod tests {
         assert_eq!(trauma_of(&app, sink), 0.0);
         assert_eq!(flash_count(&app), 0);
     }
+
+    #[test]
+    fn a_zero_motion_scale_suppresses_both_effects() {
+        let mut app = juice_test_app();
+        app.world_mut().resource_mut::<JuiceSettings>().motion_scale = 0.0;
+        let sink = spawn_shake_sink(&mut app);
+        let target = spawn_at(&mut app, Vec3::ZERO);
+
+        app.world_mut().trigger(HealthApplyDamage {
+            entity: target,
+            source: None,
+            amount: 10.0,
+        });
+        app.world_mut()
+            .entity_mut(target)
+            .insert(IntegrityDestroyMarker);
+
+        assert_eq!(trauma_of(&app, sink), 0.0);
+        assert_eq!(flash_count(&app), 0);
+    }
 }
@@ -8,7 +8,8 @@
 //! objective list and its conveyance tags), `mesh` and `transform` (the mesh
 //! toolkit and the rotation/orbit rigs), `markers` and `projectile_hooks` (the
 //! entity vocabulary the layers above tag with), `lifetime` and `cooldown`
-//! (transient entities and the countdowns that gate actions), `math`,
+//! (transient entities and the countdowns that gate actions), `lockstep` (the
+//! fixed-step mode a recorded run is flown in), `math`,
 //! `relations`, `beacon`, `asset_ref` and `settings` (volume + graphics
 //! presets). Nova owns all of it, engine layers included: health, damage and
 //! destruction (`integrity`), the transform rigs, the mesh toolkit and SFX
@@ -30,6 +31,7 @@ pub mod gravity;
 pub mod integrity;
 pub mod juice;
 pub mod lifetime;
+pub mod lockstep;
 pub mod markers;
 pub mod math;
 pub mod mesh;
@@ -94,10 +96,11 @@ pub mod prelude {
     pub use super::{
         asset_ref::prelude::*, audio::prelude::*, beacon::prelude::*, cooldown::prelude::*,
         damage::prelude::*, gravity::prelude::*, integrity::prelude::*, juice::prelude::*,
-        lifetime::prelude::*, markers::prelude::*, math::prelude::*, mesh::prelude::*,
-        objectives::prelude::*, plugin::prelude::*, projectile_hooks::prelude::*,
+        lifetime::prelude::*, lockstep::prelude::*, markers::prelude::*, math::prelude::*,
+        mesh::prelude::*, objectives::prelude::*, plugin::prelude::*, projectile_hooks::prelude::*,
         relations::prelude::*, rounds::prelude::*, settings::prelude::*, shake::prelude::*,
         transform::prelude::*, EscapeOwner, GameMode, GameStates, PauseStates,
+        PlayerInputSuspended,
     };
 }
 
@@ -127,10 +130,14 @@ pub enum GameStates {
 /// entering any frozen variant. Init'd by `AppBuilder` next to [`GameStates`].
 /// Only meaningful inside `GameStates::Playing`; leaving Playing must reset it.
 ///
-/// Both frozen variants ([`PauseStates::Paused`] and [`PauseStates::NovaOs`])
-/// are entered ONLY from [`PauseStates::Unpaused`] and exit back to it - never
-/// one directly into the other - so the freeze/cursor hooks never
-/// double-fire.
+/// [`PauseStates::Paused`] and [`PauseStates::NovaOs`] are entered ONLY from
+/// [`PauseStates::Unpaused`] and exit back to it - never one directly into the
+/// other - so the freeze/cursor hooks never double-fire.
+/// [`PauseStates::Photo`] is the one exception: it is a page of the pause menu,
+/// entered from and exited to `Paused`, and both of its hooks re-assert the
+/// freeze `Paused`'s exit hook just lifted. [`PauseStates::Refit`] follows the
+/// `NovaOs` rule: a refit asked for from the NOVA OS waits for the computer to
+/// close before the bay opens.
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
 pub enum PauseStates {
     #[default]
@@ -142,6 +149,12 @@ pub enum PauseStates {
     /// stopped and the cursor is freed, exactly like [`PauseStates::Paused`]
     /// but without the pause menu.
     NovaOs,
+    /// Gameplay is frozen for the pause menu's photo mode: the clocks are
+    /// stopped and the scenario camera flies free under the photo controls.
+    Photo,
+    /// Gameplay is frozen for a docked ship's refit bay: the clocks are stopped,
+    /// the cursor is freed and the editor's parts gallery owns the screen.
+    Refit,
 }
 
 /// Whether a scene-local surface owns Escape right now, so the pause menu must
@@ -163,6 +176,21 @@ pub enum PauseStates {
 #[reflect(Resource)]
 pub struct EscapeOwner(pub bool);
 
+/// Whether the human's input is cut off from the player ship because something
+/// else is flying it - a replay playing back a recorded run.
+///
+/// Every player-input observer already drops its event while
+/// [`PauseStates::is_frozen`]; it drops it while this is set too, and the
+/// per-frame writers of the player's aim and stance stand down, so the only
+/// intents the ship sees are the ones written for it. Unlike a freeze the
+/// clocks keep running: the simulation goes on, just not at the player's hand.
+///
+/// Whoever sets it clears it - the replay player does so when playback stops
+/// and when it leaves `Playing`.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct PlayerInputSuspended(pub bool);
+
 impl PauseStates {
     /// True when gameplay is frozen (any
     /// non-[`Unpaused`](PauseStates::Unpaused) variant): the clocks are stopped
@@ -0,0 +1,133 @@
+//! Lockstep: the mode a recorded run is flown in, where everything that can
+//! change the outcome steps on the fixed clock.
+//!
+//! Outside it, the AI pilots and the scenario clock run once per rendered
+//! frame, on virtual frame time and against eased poses. That is right for
+//! play, since they decide against the frame being drawn, but it makes a run a
+//! function of the host's frame rate: the fixed loop runs a varying number of
+//! ticks per frame, and a per-frame decision lands on whichever tick follows
+//! it. A recording has to be re-flown to the same end, so while one is being
+//! recorded or played back [`SimulationLockstep`] is set, and those systems
+//! register a second time in `FixedUpdate` behind [`simulation_lockstep`], with
+//! their `Update` copies standing down.
+//!
+//! A fixed step reads poses differently from a frame: the eased `Transform` is
+//! whatever the last frame left it at, and `GlobalTransform` is propagated only
+//! in `PostUpdate`. So in lockstep each fixed step first puts every
+//! interpolated body's `Transform` back on its raw physics pose and propagates,
+//! and the decisions read a pose that depends on the tick alone.
+
+use avian3d::prelude::*;
+use bevy::{
+    prelude::*,
+    transform::systems::{mark_dirty_trees, propagate_parent_transforms, sync_simple_transforms},
+};
+
+/// Glob-import surface: `use nova_gameplay::lockstep::prelude::*`.
+pub mod prelude {
+    pub use super::{simulation_lockstep, NovaLockstepPlugin, SimulationLockstep};
+}
+
+/// Whether the simulation is in lockstep: the AI pilots and the scenario
+/// clock step on the fixed clock instead of the rendered frame.
+///
+/// Whoever sets it clears it - the replay recorder and player do so when their
+/// run ends and when they leave `Playing`.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct SimulationLockstep(pub bool);
+
+/// Run condition: the simulation is (`on = true`) or is not in lockstep. An
+/// app without the resource is never in lockstep, so the slim test rigs keep
+/// their per-frame registrations.
+pub fn simulation_lockstep(
+    on: bool,
+) -> impl FnMut(Option<Res<SimulationLockstep>>) -> bool + Clone {
+    move |lockstep: Option<Res<SimulationLockstep>>| {
+        lockstep.is_some_and(|lockstep| lockstep.0) == on
+    }
+}
+
+/// Owns [`SimulationLockstep`] and settles the poses a lockstep fixed step
+/// reads. Added by [`NovaGameplayPlugin`](crate::plugin::NovaGameplayPlugin).
+pub struct NovaLockstepPlugin;
+
+impl Plugin for NovaLockstepPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimulationLockstep>();
+        app.register_type::<SimulationLockstep>();
+
+        app.add_systems(
+            FixedPreUpdate,
+            (
+                settle_lockstep_poses,
+                mark_dirty_trees,
+                propagate_parent_transforms,
+                sync_simple_transforms,
+            )
+                .chain()
+                .run_if(simulation_lockstep(true)),
+        );
+    }
+}
+
+/// Put every interpolated body back on its raw physics pose, undoing the
+/// easing the last frame applied. Child colliders carry only their local
+/// offset, so the propagation after this rebuilds them from the settled root.
+#[allow(clippy::type_complexity)]
+fn settle_lockstep_poses(
+    mut q_bodies: Query<
+        (&Position, &Rotation, &mut Transform),
+        (With<TransformInterpolation>, Without<ChildOf>),
+    >,
+) {
+    for (position, rotation, mut transform) in &mut q_bodies {
+        let settled = Transform {
+            translation: position.0,
+            rotation: rotation.0,
+            scale: transform.scale,
+        };
+        transform.set_if_neq(settled);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    /// In lockstep a fixed step reads the raw pose, not the eased one the last
+    /// frame drew; out of it the eased pose is left alone.
+    #[test]
+    fn a_lockstep_step_reads_the_raw_pose() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(NovaLockstepPlugin);
+        let step = app.world().resource::<Time<Fixed>>().timestep();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(
+            step + Duration::from_millis(1),
+        ));
+        let body = app
+            .world_mut()
+            .spawn((
+                Position(Vec3::new(4.0, 0.0, 0.0)),
+                Rotation::default(),
+                Transform::from_xyz(3.5, 0.0, 0.0),
+                TransformInterpolation,
+            ))
+            .id();
+        let x = |app: &App| app.world().get::<Transform>(body).unwrap().translation.x;
+
+        // The first update lands a zero delta, so it runs no fixed step.
+        app.update();
+        app.update();
+        assert_eq!(x(&app), 3.5, "out of lockstep the eased pose is left alone");
+
+        app.world_mut().resource_mut::<SimulationLockstep>().0 = true;
+        app.update();
+        assert_eq!(x(&app), 4.0);
+    }
+}
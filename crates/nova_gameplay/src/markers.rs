@@ -1,7 +1,7 @@
 //! The ship-structure vocabulary: what a ship root, a section and a fired
 //! projectile ARE, with none of the behavior that builds or flies them.
 //!
-//! These are the ten markers that both sides of the ship seam read. The ship
+//! These are the eleven markers that both sides of the ship seam read. The ship
 //! crate spawns them; `integrity` and `gravity` here classify entities by them
 //! (is this a ship root? a live section? a torpedo?) without depending on the
 //! sections that define one. They are plain unit components with no systems and
@@ -24,9 +24,9 @@ use crate::{
 /// The whole module - every marker is part of the shared vocabulary.
 pub mod prelude {
     pub use super::{
-        ControllerSectionMarker, PlayerSpaceshipMarker, SectionInactiveMarker, SectionMarker,
-        SpaceshipRootMarker, ThrusterSectionMarker, TorpedoProjectileMarker, TorpedoSectionMarker,
-        TurretBulletProjectileMarker, TurretSectionMarker,
+        ControllerSectionMarker, GhostHullMarker, PlayerSpaceshipMarker, SectionInactiveMarker,
+        SectionMarker, SpaceshipRootMarker, ThrusterSectionMarker, TorpedoProjectileMarker,
+        TorpedoSectionMarker, TurretBulletProjectileMarker, TurretSectionMarker,
     };
 }
 
@@ -88,3 +88,8 @@ pub struct TorpedoProjectileMarker;
 /// Marker for turret bullet projectiles.
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct TurretBulletProjectileMarker;
+
+/// Marks a ghost hull: a replay of the player's own best run, drawn with no
+/// body. Never a target, a contact or an area occupant.
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct GhostHullMarker;
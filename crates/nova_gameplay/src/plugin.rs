@@ -1,10 +1,11 @@
 //! The gameplay composition root: [`NovaGameplayPlugin`] adds every shared
 //! gameplay plugin (integrity, damage, gravity, relations, audio, juice,
-//! transform, lifetime, mesh, settings) plus the third-party plugins the whole
-//! game builds on (avian3d physics with [`ProjectileHooks`] collision hooks,
-//! `bevy_hanabi` particles, and `bevy_rand` entropy). Every gameplay layer it
-//! composes is nova's own. It also declares the top-level [`SpaceshipSystems`]
-//! brackets; `nova_ship` chains its per-subsystem sets inside them.
+//! transform, lifetime, lockstep, mesh, settings) plus the third-party plugins
+//! the whole game builds on (avian3d physics with [`ProjectileHooks`] collision
+//! hooks, `bevy_hanabi` particles, and `bevy_rand` entropy). Every gameplay
+//! layer it composes is nova's own. It also declares the top-level
+//! [`SpaceshipSystems`] brackets; `nova_ship` chains its per-subsystem sets
+//! inside them.
 //!
 //! See the architecture wiki for how this crate sits between `nova_core`
 //! (wiring) and its neighbors.
@@ -65,6 +66,11 @@ impl Plugin for NovaGameplayPlugin {
         app.init_resource::<crate::EscapeOwner>();
         app.register_type::<crate::EscapeOwner>();
 
+        // A replay flying the player ship cuts the human out (see
+        // `PlayerInputSuspended`).
+        app.init_resource::<crate::PlayerInputSuspended>();
+        app.register_type::<crate::PlayerInputSuspended>();
+
         // Mission state, not HUD state: the scenario loader writes it whether or
         // not anything renders it, so it is owned here rather than by the
         // render-gated HUD.
@@ -105,6 +111,7 @@ impl Plugin for NovaGameplayPlugin {
         app.add_plugins(crate::rounds::NovaRoundPlugin);
         app.add_plugins(crate::audio::NovaAudioPlugin);
         app.add_plugins(crate::juice::NovaJuicePlugin);
+        app.add_plugins(crate::lockstep::NovaLockstepPlugin);
         app.add_plugins(crate::settings::NovaSettingsPlugin);
 
         // Diagnostics
@@ -179,6 +179,7 @@ fn advance_rounds(
     q_sensors: Query<(), With<Sensor>>,
     q_collider_of: Query<&ColliderOf>,
     q_health: Query<&Health>,
+    q_rock: Query<(), (With<DamageMarks>, Without<Health>)>,
     q_velocity: Query<&LinearVelocity>,
 ) {
     let dt = time.delta_secs();
@@ -303,12 +304,23 @@ fn advance_rounds(
                 closing,
                 at
             );
+            // A rock pays for a hit in material and nothing else, so it is the
+            // one target a mining round's multiplier reaches. It is also a wall,
+            // so the inflated bite can never fly on into what is behind it.
+            let dealt = if q_rock.contains(candidate.entity) {
+                ProjectileDamage {
+                    amount: damage.amount * damage.rock_multiplier,
+                    ..*damage
+                }
+            } else {
+                *damage
+            };
             match spend_piercing_damage(
                 &mut commands,
                 candidate.entity,
                 Some(entity),
                 health,
-                *damage,
+                dealt,
                 closing,
                 Some(at),
             ) {
@@ -688,6 +700,67 @@ mod tests {
         }
     }
 
+    /// A mining round's multiplier reaches ROCK and nothing else: into a body
+    /// with no pool it carves the multiplied bite, into a section it deals its
+    /// authored amount exactly like a combat round.
+    #[test]
+    fn a_mining_round_carves_rock_harder_than_it_hits_a_ship() {
+        fn carved(rock_multiplier: f32) -> f32 {
+            let mut app = round_app();
+            let body = app
+                .world_mut()
+                .spawn((RigidBody::Static, Transform::default()))
+                .id();
+            let rock = app
+                .world_mut()
+                .spawn((
+                    ChildOf(body),
+                    Transform::default(),
+                    Collider::sphere(2.0),
+                    DamageMarks::default(),
+                ))
+                .id();
+            settle(&mut app);
+            let round = spawn_round(&mut app, 5.0, 4.0);
+            app.world_mut().entity_mut(round).insert(
+                ProjectileDamage::new(4.0, DamageType::Kinetic)
+                    .with_rock_multiplier(rock_multiplier),
+            );
+            for _ in 0..15 {
+                app.update();
+            }
+            let marks = app.world().get::<DamageMarks>(rock).expect("rock exists");
+            marks.0.iter().map(|mark| mark.radius).fold(0.0, f32::max)
+        }
+
+        let plain = carved(1.0);
+        let mined = carved(8.0);
+        assert!(
+            (plain - mark_radius(4.0)).abs() < 1e-3,
+            "delivery guard: a combat round carves its own bite, carved {plain}"
+        );
+        assert!(
+            (mined - mark_radius(32.0)).abs() < 1e-3,
+            "a x8 mining round carves eight times the material, carved {mined}"
+        );
+
+        let mut app = round_app();
+        let plate = spawn_plate(&mut app, 0.0, 100.0);
+        settle(&mut app);
+        let round = spawn_round(&mut app, 8.0, 4.0);
+        app.world_mut()
+            .entity_mut(round)
+            .insert(ProjectileDamage::new(4.0, DamageType::Kinetic).with_rock_multiplier(8.0));
+        for _ in 0..15 {
+            app.update();
+        }
+        let dealt = 100.0 - plate_health(&app, plate);
+        assert!(
+            (dealt - 4.0).abs() < 0.05,
+            "a section takes the authored amount, not the rock bite: dealt {dealt}"
+        );
+    }
+
     /// The two blind spots review R1.1/R1.2 caught when rounds became sensors,
     /// re-asserted against the sweep. A round crossing a pure trigger volume (a
     /// beacon sphere) must SURVIVE, or the pirate goes un-hittable while
@@ -11,6 +11,11 @@
 //!   [`MasterVolume::output_gain`], which [`HarnessMute`] masks to silence in
 //!   scripted runs (probe sweeps, screenshot captures) - the SETTING
 //!   stays untouched, so persistence and the menu never see the mute.
+//! - [`BusVolumes`] are the per-bus channels under it: SFX, UI, NOVA OS, comms
+//!   and music, one slider each. A one-shot picks its bus gain up at spawn (the
+//!   [`PlaySfx`](crate::audio::PlaySfx) `bus`); a loop sink multiplies it in
+//!   with the master output gain every frame. The master gain is the only
+//!   place [`HarnessMute`] is applied, so a muted run is silent on every bus.
 //! - [`GraphicsQuality`] is a three-tier preset. It maps onto two things through
 //!   the single `apply_graphics_quality` seam: the combat juice
 //!   ([`crate::juice::JuiceSettings`]) and the derived [`GraphicsBudget`] gate
@@ -25,6 +30,20 @@
 //!   baseline validates as a real combat cost. Scatter/object counts are
 //!   deliberately NOT a preset lever: asteroids, rocks and debris are gameplay
 //!   content, so no quality tier thins them.
+//! - [`AccessibilitySettings`] holds the colour-blind palette, the UI text
+//!   scale, reduced motion and the comms dwell multiplier. The single
+//!   `apply_accessibility` seam pushes the palette and text scale onto
+//!   `nova_ui`'s [`Palette`] and [`TextScale`] and the motion scale onto the
+//!   juice; the NOVA OS CRT and the comms panel read the resource directly.
+//! - [`Difficulty`] is a three-tier choice made on the Scenarios picker. It
+//!   maps onto the derived [`DifficultyPolicy`] through the single
+//!   `apply_difficulty` seam, the same way the graphics preset maps onto its
+//!   budget: the AI's lead and burst hold, the arrival grace, the damage the
+//!   player's ship takes, and its magazine and repair-part allowances all read
+//!   the policy, never the tier. `Normal` is the authored game - every policy
+//!   factor is 1.0 - so content is balanced against it. A
+//!   [`DifficultyOverride`] stands in for the choice without changing it
+//!   (a replay flies the tier it was recorded at).
 //!
 //! Persistence (native RON + web localStorage) lives in `nova_menu`, which owns
 //! the load-at-startup and save-on-change wiring; this module only defines the
@@ -32,12 +51,14 @@
 //! sane defaults with no disk I/O.
 
 use bevy::prelude::*;
+use nova_ui::{text_scale::TextScale, theme::semantic::Palette};
 
-use crate::juice::prelude::JuiceSettings;
+use crate::{audio::AudioBus, juice::prelude::JuiceSettings};
 
 /// Glob-import surface: `use nova_gameplay::settings::prelude::*`.
 pub mod prelude {
     pub use super::{
+        AccessibilitySettings, BusVolumes, Difficulty, DifficultyOverride, DifficultyPolicy,
         GraphicsBudget, GraphicsQuality, HarnessMute, MasterVolume, NovaSettingsPlugin,
         HARNESS_ENVS, MUTE_ENV,
     };
@@ -82,6 +103,66 @@ impl MasterVolume {
     }
 }
 
+/// The per-bus volumes, each linear `0.0..=1.0` and applied on top of
+/// [`MasterVolume`] to the cues routed through that [`AudioBus`]. Default full.
+#[derive(Resource, Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BusVolumes {
+    /// World and ship sound effects: weapons, impacts, engines.
+    pub sfx: f32,
+    /// Interface chrome: menu clicks, objective chimes.
+    pub ui: f32,
+    /// The NOVA OS terminal's cues and its ambient bed.
+    pub nova_os: f32,
+    /// The comms panel's voice lines.
+    pub comms: f32,
+    /// The music stems and stingers.
+    pub music: f32,
+}
+
+impl Default for BusVolumes {
+    fn default() -> Self {
+        Self {
+            sfx: 1.0,
+            ui: 1.0,
+            nova_os: 1.0,
+            comms: 1.0,
+            music: 1.0,
+        }
+    }
+}
+
+impl BusVolumes {
+    /// The raw setting for `bus`, as persisted and shown on its slider.
+    pub fn get(&self, bus: AudioBus) -> f32 {
+        match bus {
+            AudioBus::Sfx => self.sfx,
+            AudioBus::Ui => self.ui,
+            AudioBus::NovaOs => self.nova_os,
+            AudioBus::Comms => self.comms,
+            AudioBus::Music => self.music,
+        }
+    }
+
+    /// Mutable access to the setting for `bus`, for the slider that owns it.
+    pub fn get_mut(&mut self, bus: AudioBus) -> &mut f32 {
+        match bus {
+            AudioBus::Sfx => &mut self.sfx,
+            AudioBus::Ui => &mut self.ui,
+            AudioBus::NovaOs => &mut self.nova_os,
+            AudioBus::Comms => &mut self.comms,
+            AudioBus::Music => &mut self.music,
+        }
+    }
+
+    /// The clamped linear factor for `bus`, like [`MasterVolume::factor`]. The
+    /// harness mute is applied once, through the master gain, not here.
+    pub fn factor(&self, bus: AudioBus) -> f32 {
+        self.get(bus).clamp(0.0, 1.0)
+    }
+}
+
 /// Environment variable that turns the AUDIO output off.
 ///
 /// One half of the outputs-off pair: this silences the speakers, and
@@ -202,6 +283,13 @@ pub struct GraphicsBudget {
     /// than the particle toggle. Only `Low` drops it; `Medium`/`High` stay at
     /// native resolution.
     pub render_scale: f32,
+    /// Whether the screen-space extras of a post-processing profile draw:
+    /// chromatic aberration (a multi-sample pass over every pixel) and the
+    /// vignette. Off on `Low`, like particles. The tonemapper, the colour
+    /// grade, bloom and a colour wash stay on every tier - the grade rides the
+    /// tonemapping pass that runs regardless, and a wash is how a scenario
+    /// tells the player something.
+    pub post_effects: bool,
 }
 
 impl GraphicsBudget {
@@ -210,15 +298,18 @@ impl GraphicsBudget {
     /// low-end mode) and renders at a reduced `render_scale`. Particles and
     /// render-scale are the per-frame costs the preset gates - scatter/object
     /// counts are gameplay content and are never thinned by a quality tier.
+    /// The post-processing extras follow the particles: on until `Low`.
     pub fn for_quality(quality: GraphicsQuality) -> Self {
         match quality {
             GraphicsQuality::High => Self {
                 particles: true,
                 render_scale: 1.0,
+                post_effects: true,
             },
             GraphicsQuality::Medium => Self {
                 particles: true,
                 render_scale: 1.0,
+                post_effects: true,
             },
             GraphicsQuality::Low => Self {
                 particles: false,
@@ -231,6 +322,7 @@ impl GraphicsBudget {
                 // phones) that the available rig cannot stand in for. Retune
                 // with the `render_scale` perf override if such a rig appears.
                 render_scale: 0.7,
+                post_effects: false,
             },
         }
     }
@@ -268,6 +360,200 @@ impl Default for GraphicsBudget {
     }
 }
 
+/// The accessibility options: a colour-blind palette, a UI text scale, a
+/// reduced-motion mode and a comms dwell multiplier. One resource because the
+/// settings page edits them side by side and persistence stores them as one
+/// block; `apply_accessibility` fans the choice out to the systems that read
+/// the derived values.
+#[derive(Resource, Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AccessibilitySettings {
+    /// Which hue set the HUD's allegiance, lock and objective accents use.
+    pub palette: Palette,
+    /// Multiplier over every UI and HUD font size (see [`TextScale`]).
+    pub text_scale: f32,
+    /// Scale camera shake, hit flashes and the NOVA OS CRT wobble to zero.
+    pub reduced_motion: bool,
+    /// Multiplier over how long a comms line stays on screen.
+    pub comms_dwell: f32,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            palette: Palette::default(),
+            text_scale: 1.0,
+            reduced_motion: false,
+            comms_dwell: 1.0,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// The shortest comms dwell multiplier a setting may take.
+    pub const COMMS_DWELL_MIN: f32 = 0.5;
+    /// The longest comms dwell multiplier a setting may take.
+    pub const COMMS_DWELL_MAX: f32 = 3.0;
+
+    /// The clamped text scale, as [`TextScale::factor`] clamps it.
+    pub fn text_scale_factor(self) -> f32 {
+        TextScale(self.text_scale).factor()
+    }
+
+    /// The multiplier motion effects apply: `0.0` under reduced motion,
+    /// `1.0` otherwise.
+    pub fn motion_scale(self) -> f32 {
+        if self.reduced_motion {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    /// The clamped comms dwell multiplier, so a corrupt persisted value can
+    /// neither flash a line past nor pin it on screen.
+    pub fn comms_dwell_factor(self) -> f32 {
+        self.comms_dwell
+            .clamp(Self::COMMS_DWELL_MIN, Self::COMMS_DWELL_MAX)
+    }
+}
+
+/// How hard the fight is. Chosen on the Scenarios picker before a scenario
+/// starts and persisted with the settings; scenarios read it through the
+/// `Difficulty` scenario query.
+///
+/// `Resource`-only for the same reason as [`GraphicsQuality`]: the segmented
+/// buttons drive it through `button_on_setting::<Difficulty>`.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Difficulty {
+    /// Forgiving: enemies lead poorly, hold longer between bursts and arrive
+    /// slower; the player's ship takes less damage and carries more.
+    Easy,
+    /// The authored game: every policy factor is 1.0.
+    #[default]
+    Normal,
+    /// Punishing: enemies fire sooner and engage faster; the player's ship
+    /// takes more damage and carries less.
+    Hard,
+}
+
+impl Difficulty {
+    /// Short display label for the segmented button, and the value the
+    /// `Difficulty` scenario query returns.
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// The tiers in menu order (easiest -> hardest).
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+}
+
+/// A tier that stands in for the player's [`Difficulty`] while it is set,
+/// without touching the setting or its save: a replay pins the tier it was
+/// recorded at and clears it when it ends.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default, Reflect)]
+#[reflect(Resource)]
+pub struct DifficultyOverride(pub Option<Difficulty>);
+
+impl DifficultyOverride {
+    /// The tier in force: this one when set, else the player's `setting`.
+    pub fn tier(&self, setting: Difficulty) -> Difficulty {
+        self.0.unwrap_or(setting)
+    }
+}
+
+/// What a [`Difficulty`] tier does to the fight, as factors over the authored
+/// numbers. `Difficulty` is the player's choice; this is the policy the AI,
+/// the health store and the ship spawn read, so the tier -> effect mapping
+/// lives only in [`DifficultyPolicy::for_difficulty`]. An app without the
+/// settings plugin reads it through `Option` and falls back to the default,
+/// which is `Normal`: the authored game.
+#[derive(Resource, Clone, Copy, PartialEq, Debug, Reflect)]
+#[reflect(Resource)]
+pub struct DifficultyPolicy {
+    /// Fraction of the target's velocity an AI turret leads by. Below 1.0 the
+    /// rounds trail a moving target; a still one is hit regardless.
+    pub ai_lead: f32,
+    /// Factor over the hold between an AI ship's bursts: how long it takes to
+    /// come back on target.
+    pub ai_burst_hold: f32,
+    /// Factor over an AI ship's authored arrival grace (`engage_delay`).
+    pub ai_engage_grace: f32,
+    /// Factor over every hit the player's ship takes.
+    pub incoming_damage: f32,
+    /// Factor over the player's authored magazine sizes.
+    pub ammo: f32,
+    /// Factor over the player's authored repair parts.
+    pub repair_parts: f32,
+}
+
+impl DifficultyPolicy {
+    /// The one place the tier -> effect policy lives.
+    pub fn for_difficulty(difficulty: Difficulty) -> Self {
+        match difficulty {
+            Difficulty::Easy => Self {
+                ai_lead: 0.5,
+                ai_burst_hold: 1.75,
+                ai_engage_grace: 1.5,
+                incoming_damage: 0.6,
+                ammo: 1.5,
+                repair_parts: 1.5,
+            },
+            Difficulty::Normal => Self {
+                ai_lead: 1.0,
+                ai_burst_hold: 1.0,
+                ai_engage_grace: 1.0,
+                incoming_damage: 1.0,
+                ammo: 1.0,
+                repair_parts: 1.0,
+            },
+            Difficulty::Hard => Self {
+                ai_lead: 1.0,
+                ai_burst_hold: 0.6,
+                ai_engage_grace: 0.5,
+                incoming_damage: 1.35,
+                ammo: 0.75,
+                repair_parts: 0.5,
+            },
+        }
+    }
+
+    /// An authored magazine of `capacity` rounds at this tier. Never scales a
+    /// stocked magazine to empty.
+    pub fn ammo_allowance(self, capacity: u32) -> u32 {
+        scaled_allowance(capacity, self.ammo)
+    }
+
+    /// An authored stock of `parts` repair parts at this tier. Never scales a
+    /// stocked station to empty.
+    pub fn repair_allowance(self, parts: u32) -> u32 {
+        scaled_allowance(parts, self.repair_parts)
+    }
+}
+
+impl Default for DifficultyPolicy {
+    /// The authored game, matching [`Difficulty::default`].
+    fn default() -> Self {
+        Self::for_difficulty(Difficulty::default())
+    }
+}
+
+/// `count` scaled by `factor` and rounded, keeping a non-zero count non-zero.
+fn scaled_allowance(count: u32, factor: f32) -> u32 {
+    if count == 0 {
+        return 0;
+    }
+    ((count as f32 * factor.max(0.0)).round() as u32).max(1)
+}
+
 /// Registers the settings resources and the systems that apply them live.
 /// Added by [`crate::plugin::NovaGameplayPlugin`] so every app (menu or not)
 /// has the resources and the apply wiring; the menu adds persistence on top.
@@ -276,12 +562,22 @@ pub struct NovaSettingsPlugin;
 impl Plugin for NovaSettingsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MasterVolume>();
+        app.init_resource::<BusVolumes>();
         app.insert_resource(HarnessMute::from_env());
         app.init_resource::<GraphicsQuality>();
         app.init_resource::<GraphicsBudget>();
+        app.init_resource::<AccessibilitySettings>();
+        app.init_resource::<Difficulty>();
+        app.init_resource::<DifficultyOverride>();
+        app.init_resource::<DifficultyPolicy>();
         app.register_type::<MasterVolume>();
+        app.register_type::<BusVolumes>();
         app.register_type::<GraphicsQuality>();
         app.register_type::<GraphicsBudget>();
+        app.register_type::<AccessibilitySettings>();
+        app.register_type::<Difficulty>();
+        app.register_type::<DifficultyOverride>();
+        app.register_type::<DifficultyPolicy>();
 
         // Apply on change only. `resource_changed` is true on the first frame
         // too (a freshly-inserted resource counts as changed), so the defaults
@@ -292,6 +588,10 @@ impl Plugin for NovaSettingsPlugin {
             (
                 apply_master_volume.run_if(resource_changed::<MasterVolume>),
                 apply_graphics_quality.run_if(resource_changed::<GraphicsQuality>),
+                apply_accessibility.run_if(resource_changed::<AccessibilitySettings>),
+                apply_difficulty.run_if(
+                    resource_changed::<Difficulty>.or(resource_changed::<DifficultyOverride>),
+                ),
             ),
         );
         // Startup, not build: the game binary's `--mute` inserts the resource
@@ -364,6 +664,40 @@ fn apply_graphics_quality(
     }
 }
 
+/// Fan [`AccessibilitySettings`] out to the values other crates read: the
+/// [`Palette`] and [`TextScale`] resources `nova_ui` applies, and the juice
+/// motion scale. Each target is `Option`-guarded for slim rigs, and written
+/// only when it differs so an unrelated edit does not restyle the HUD.
+fn apply_accessibility(
+    settings: Res<AccessibilitySettings>,
+    palette: Option<ResMut<Palette>>,
+    text_scale: Option<ResMut<TextScale>>,
+    juice: Option<ResMut<JuiceSettings>>,
+) {
+    if let Some(mut palette) = palette {
+        palette.set_if_neq(settings.palette);
+    }
+    if let Some(mut text_scale) = text_scale {
+        text_scale.set_if_neq(TextScale(settings.text_scale_factor()));
+    }
+    if let Some(mut juice) = juice {
+        let motion_scale = settings.motion_scale();
+        if juice.motion_scale != motion_scale {
+            juice.motion_scale = motion_scale;
+        }
+    }
+}
+
+/// Map the [`Difficulty`] in force onto the [`DifficultyPolicy`] its readers
+/// apply. The single seam, like `apply_graphics_quality` beside it.
+fn apply_difficulty(
+    difficulty: Res<Difficulty>,
+    pinned: Res<DifficultyOverride>,
+    mut policy: ResMut<DifficultyPolicy>,
+) {
+    policy.set_if_neq(DifficultyPolicy::for_difficulty(pinned.tier(*difficulty)));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -482,6 +816,52 @@ mod tests {
         assert!(!j.master_enabled, "Low: juice master switch off");
     }
 
+    #[test]
+    fn accessibility_fans_out_to_the_palette_text_scale_and_juice() {
+        let mut app = app();
+        app.init_resource::<Palette>();
+        app.init_resource::<TextScale>();
+        app.insert_resource(AccessibilitySettings {
+            palette: Palette::Deuteranopia,
+            text_scale: 9.0,
+            reduced_motion: true,
+            comms_dwell: 1.5,
+        });
+        app.update();
+
+        assert_eq!(*app.world().resource::<Palette>(), Palette::Deuteranopia);
+        assert_eq!(
+            app.world().resource::<TextScale>().0,
+            TextScale::MAX,
+            "a corrupt scale is clamped before it reaches the UI"
+        );
+        assert_eq!(app.world().resource::<JuiceSettings>().motion_scale, 0.0);
+
+        app.world_mut()
+            .resource_mut::<AccessibilitySettings>()
+            .reduced_motion = false;
+        app.update();
+        assert_eq!(app.world().resource::<JuiceSettings>().motion_scale, 1.0);
+    }
+
+    #[test]
+    fn reduced_motion_leaves_the_graphics_toggles_alone() {
+        let mut app = app();
+        app.insert_resource(GraphicsQuality::Medium);
+        app.insert_resource(AccessibilitySettings {
+            reduced_motion: true,
+            ..default()
+        });
+        app.update();
+        app.insert_resource(AccessibilitySettings::default());
+        app.update();
+        let j = app.world().resource::<JuiceSettings>();
+        assert!(
+            !j.shake.enabled && j.flash.enabled,
+            "leaving reduced motion restores Medium, not High"
+        );
+    }
+
     #[test]
     fn graphics_budget_gates_particles_only_by_tier() {
         // The tier->cost policy is a pure function, so assert it directly
@@ -505,6 +885,10 @@ mod tests {
             !low.particles && low.render_scale < 1.0,
             "Low: spawn-less (no particles) and sub-native resolution"
         );
+        assert!(
+            high.post_effects && medium.post_effects && !low.post_effects,
+            "the post-processing extras follow the particles"
+        );
 
         // Only Low leaves native resolution - the render-scale lever is aimed at
         // the over-budget web target and Medium/High keep the crisp look.
@@ -589,4 +973,46 @@ mod tests {
             "selecting High pushes the High budget back on"
         );
     }
+
+    #[test]
+    fn difficulty_drives_the_policy_and_normal_is_the_authored_game() {
+        let mut app = app();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<DifficultyPolicy>(),
+            DifficultyPolicy::for_difficulty(Difficulty::Normal)
+        );
+        let normal = DifficultyPolicy::default();
+        assert_eq!(
+            (normal.ammo_allowance(40), normal.repair_allowance(6)),
+            (40, 6),
+            "Normal leaves every authored number alone"
+        );
+
+        *app.world_mut().resource_mut::<Difficulty>() = Difficulty::Hard;
+        app.update();
+        let hard = *app.world().resource::<DifficultyPolicy>();
+        assert!(hard.incoming_damage > 1.0 && hard.ai_burst_hold < 1.0);
+        assert_eq!(hard.repair_allowance(6), 3);
+        assert_eq!(
+            hard.repair_allowance(1),
+            1,
+            "a stocked station never empties"
+        );
+        assert_eq!(hard.ammo_allowance(0), 0);
+
+        let easy = DifficultyPolicy::for_difficulty(Difficulty::Easy);
+        assert!(easy.incoming_damage < 1.0 && easy.ai_lead < 1.0);
+        assert_eq!(easy.ammo_allowance(40), 60);
+
+        // An override stands in for the setting, and clearing it hands the
+        // policy back to the setting.
+        app.world_mut().resource_mut::<DifficultyOverride>().0 = Some(Difficulty::Easy);
+        app.update();
+        assert_eq!(*app.world().resource::<DifficultyPolicy>(), easy);
+        assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Hard);
+        app.world_mut().resource_mut::<DifficultyOverride>().0 = None;
+        app.update();
+        assert_eq!(*app.world().resource::<DifficultyPolicy>(), hard);
+    }
 }
@@ -45,6 +45,7 @@
 
 use bevy::prelude::*;
 use nova_gameplay::prelude::*;
+use nova_ui::theme::semantic::Palette;
 
 use super::{screen_indicator::prelude::*, HudTier};
 
@@ -57,11 +58,8 @@ pub mod prelude {
     };
 }
 
-/// Ally green: the player's side (the player's own AI wingmen included).
-const ALLY_GREEN: Color = nova_ui::theme::semantic::ALLY;
-/// Threat red: hostile ships.
-const THREAT_RED: Color = nova_ui::theme::semantic::THREAT;
-/// Neutral grey: bystanders and ships with no allegiance at all.
+/// Neutral grey: bystanders and ships with no allegiance at all. The spawn
+/// colour, the same in every [`Palette`].
 const NEUTRAL_GREY: Color = nova_ui::theme::semantic::NEUTRAL;
 
 /// Half the triangle's base width (px); the coloured left/right borders.
@@ -86,14 +84,15 @@ const WRECK_STROKE_ANGLE_DEG: f32 = 52.1;
 /// playtest wants it higher off the hull.
 const MARKER_OFFSET: Vec2 = Vec2::new(0.0, -40.0);
 
-/// The allegiance tint for a ship's marker. `None` (a ship carrying no
-/// [`Allegiance`] at all) reads as neutral, same as an explicit
-/// `Allegiance::Neutral` - a bystander either way.
-pub fn allegiance_color(allegiance: Option<&Allegiance>) -> Color {
+/// The allegiance tint for a ship's marker in `palette`: ally for the
+/// player's side (their own AI wingmen included), threat for hostiles. `None`
+/// (a ship carrying no [`Allegiance`] at all) reads as neutral, same as an
+/// explicit `Allegiance::Neutral` - a bystander either way.
+pub fn allegiance_color(palette: Palette, allegiance: Option<&Allegiance>) -> Color {
     match allegiance {
-        Some(Allegiance::Player) => ALLY_GREEN,
-        Some(Allegiance::Enemy) => THREAT_RED,
-        Some(Allegiance::Neutral) | None => NEUTRAL_GREY,
+        Some(Allegiance::Player) => palette.ally(),
+        Some(Allegiance::Enemy) => palette.threat(),
+        Some(Allegiance::Neutral) | None => palette.neutral(),
     }
 }
 
@@ -318,9 +317,11 @@ fn despawn_player_allegiance_markers(
 /// fires when the requirement-default allegiance lands at spawn-settle (so
 /// the grey spawn default becomes the real colour) AND on a runtime
 /// `SetAllegiance` flip (a neutral-until-provoked hauler turning red). A
-/// ship that never carries an `Allegiance` keeps the grey spawn default.
+/// [`Palette`] change recolours every ship. A ship that never carries an
+/// `Allegiance` keeps the grey spawn default.
 fn recolor_allegiance_markers(
-    q_ships: Query<(Entity, &Allegiance), (Changed<Allegiance>, With<SpaceshipRootMarker>)>,
+    palette: Option<Res<Palette>>,
+    q_ships: Query<(Entity, Ref<Allegiance>), With<SpaceshipRootMarker>>,
     mut q_triangles: Query<
         (&AllegianceMarkerTargetEntity, &mut BorderColor),
         With<AllegianceMarkerTriangleMarker>,
@@ -330,8 +331,13 @@ fn recolor_allegiance_markers(
         With<AllegianceMarkerWreckStrokeMarker>,
     >,
 ) {
+    let repaint_all = palette.as_ref().is_some_and(|palette| palette.is_changed());
+    let palette = palette.map(|palette| *palette).unwrap_or_default();
     for (ship, allegiance) in &q_ships {
-        let color = allegiance_color(Some(allegiance));
+        if !repaint_all && !allegiance.is_changed() {
+            continue;
+        }
+        let color = allegiance_color(palette, Some(&allegiance));
         for (target, mut border) in &mut q_triangles {
             if **target == ship && border.top != color {
                 border.top = color;
@@ -390,11 +396,20 @@ mod tests {
 
     #[test]
     fn allegiance_color_maps_every_side() {
-        assert_eq!(allegiance_color(Some(&Allegiance::Player)), ALLY_GREEN);
-        assert_eq!(allegiance_color(Some(&Allegiance::Enemy)), THREAT_RED);
-        assert_eq!(allegiance_color(Some(&Allegiance::Neutral)), NEUTRAL_GREY);
+        assert_eq!(
+            allegiance_color(Palette::Standard, Some(&Allegiance::Player)),
+            Palette::Standard.ally()
+        );
+        assert_eq!(
+            allegiance_color(Palette::Standard, Some(&Allegiance::Enemy)),
+            Palette::Standard.threat()
+        );
+        assert_eq!(
+            allegiance_color(Palette::Standard, Some(&Allegiance::Neutral)),
+            NEUTRAL_GREY
+        );
         // A ship with no Allegiance at all reads neutral, like a bystander.
-        assert_eq!(allegiance_color(None), NEUTRAL_GREY);
+        assert_eq!(allegiance_color(Palette::Standard, None), NEUTRAL_GREY);
     }
 
     /// The triangle is a zero-CONTENT `ContentBox` node with a coloured top
@@ -414,8 +429,8 @@ mod tests {
 
         // Only the top border is painted; the sides stay transparent so the
         // mitered shader leaves a single downward triangle.
-        let border = allegiance_triangle_border(THREAT_RED);
-        assert_eq!(border.top, THREAT_RED);
+        let border = allegiance_triangle_border(Palette::Standard.threat());
+        assert_eq!(border.top, Palette::Standard.threat());
         assert_eq!(border.left, Color::NONE);
         assert_eq!(border.right, Color::NONE);
         assert_eq!(border.bottom, Color::NONE);
@@ -532,9 +547,18 @@ mod tests {
 
         // Correct colour per allegiance, including the AI hostile whose
         // Enemy allegiance came from the marker requirement.
-        assert_eq!(triangle_color(&mut app, ai_enemy), Some(THREAT_RED));
-        assert_eq!(triangle_color(&mut app, enemy), Some(THREAT_RED));
-        assert_eq!(triangle_color(&mut app, friendly), Some(ALLY_GREEN));
+        assert_eq!(
+            triangle_color(&mut app, ai_enemy),
+            Some(Palette::Standard.threat())
+        );
+        assert_eq!(
+            triangle_color(&mut app, enemy),
+            Some(Palette::Standard.threat())
+        );
+        assert_eq!(
+            triangle_color(&mut app, friendly),
+            Some(Palette::Standard.ally())
+        );
         assert_eq!(triangle_color(&mut app, neutral), Some(NEUTRAL_GREY));
         assert_eq!(
             triangle_color(&mut app, bystander),
@@ -550,7 +574,7 @@ mod tests {
         app.update();
         assert_eq!(
             triangle_color(&mut app, neutral),
-            Some(THREAT_RED),
+            Some(Palette::Standard.threat()),
             "a provoked ship recolours to threat red"
         );
 
@@ -570,10 +594,22 @@ mod tests {
         );
         assert_eq!(
             wreck_stroke_colors(&mut app, ai_enemy),
-            vec![THREAT_RED, THREAT_RED],
+            vec![Palette::Standard.threat(), Palette::Standard.threat()],
             "both hollow-chevron strokes retain enemy red"
         );
 
+        // A palette switch repaints every marker, not only changed ships.
+        app.insert_resource(Palette::Deuteranopia);
+        app.update();
+        assert_eq!(
+            triangle_color(&mut app, friendly),
+            Some(Palette::Deuteranopia.ally())
+        );
+        assert_eq!(
+            triangle_color(&mut app, enemy),
+            Some(Palette::Deuteranopia.threat())
+        );
+
         // A despawned ship takes its marker with it (the death / unload path).
         app.world_mut().entity_mut(enemy).despawn();
         app.update();
@@ -0,0 +1,167 @@
+//! The canopy the HUD is projected onto in the cockpit view: the frame of the
+//! windscreen - the bow across the top, two pillars and the instrument sill -
+//! with a faint NAV-tinted glass wash between them.
+//!
+//! Nothing here moves the instruments. The screen-space HUD already projects
+//! through the player camera, which in the cockpit view IS the pilot's eye, so
+//! every chip and reticle lands on the glass for free; the frame is what makes
+//! it read as a projection instead of an overlay. Drawn under every other HUD
+//! layer ([`GlobalZIndex`] below zero) and click-through.
+//!
+//! CONTEXTUAL: the layer carries a [`HudContextGate`] that `sync_canopy_gate`
+//! opens exactly while [`SpaceshipCameraView::Cockpit`] holds, so the chase
+//! view never shows a windscreen and the Cinematic level still clears it.
+
+use bevy::prelude::*;
+use nova_ship::prelude::*;
+
+use super::NAV_CYAN;
+use crate::prelude::*;
+
+/// `CanopyHudPlugin`, the layer bundle and its marker.
+pub mod prelude {
+    pub use super::{canopy_hud, CanopyHudMarker, CanopyHudPlugin};
+}
+
+/// The frame's structural colour: near-black, nearly opaque, a touch of blue
+/// so it reads as painted metal against the starfield rather than a hole.
+const CANOPY_FRAME: Color = Color::srgba(0.02, 0.03, 0.045, 0.94);
+
+/// Glass wash alpha over the NAV hue. Barely there - it must never dim a
+/// target the pilot is trying to spot.
+const CANOPY_GLASS_ALPHA: f32 = 0.035;
+
+/// Frame member sizes, as a share of the screen.
+const BOW_HEIGHT_PCT: f32 = 6.0;
+const PILLAR_WIDTH_PCT: f32 = 3.5;
+const SILL_HEIGHT_PCT: f32 = 9.0;
+
+/// Marker for the canopy layer root.
+#[derive(Component, Debug, Clone)]
+pub struct CanopyHudMarker;
+
+/// UI bundle for the canopy layer: glass, bow, pillars and sill, shut until
+/// the cockpit view opens its gate.
+pub fn canopy_hud() -> impl Bundle {
+    (
+        Name::new("CanopyHUD"),
+        CanopyHudMarker,
+        HudContextGate(false),
+        GlobalZIndex(-1),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(NAV_CYAN.with_alpha(CANOPY_GLASS_ALPHA)),
+        Pickable::IGNORE,
+        children![
+            canopy_member(
+                "Canopy Bow",
+                Node {
+                    top: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(BOW_HEIGHT_PCT),
+                    border: UiRect::bottom(Val::Px(1.0)),
+                    ..default()
+                },
+            ),
+            canopy_member(
+                "Canopy Pillar Left",
+                Node {
+                    left: Val::Px(0.0),
+                    width: Val::Percent(PILLAR_WIDTH_PCT),
+                    height: Val::Percent(100.0),
+                    border: UiRect::right(Val::Px(1.0)),
+                    ..default()
+                },
+            ),
+            canopy_member(
+                "Canopy Pillar Right",
+                Node {
+                    right: Val::Px(0.0),
+                    width: Val::Percent(PILLAR_WIDTH_PCT),
+                    height: Val::Percent(100.0),
+                    border: UiRect::left(Val::Px(1.0)),
+                    ..default()
+                },
+            ),
+            canopy_member(
+                "Canopy Sill",
+                Node {
+                    bottom: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(SILL_HEIGHT_PCT),
+                    border: UiRect::top(Val::Px(1.0)),
+                    ..default()
+                },
+            ),
+        ],
+    )
+}
+
+/// One frame member: an absolute dark strip with a dim NAV hairline on its
+/// glass-side edge, where the projection catches the metal.
+fn canopy_member(name: &'static str, mut node: Node) -> impl Bundle {
+    node.position_type = PositionType::Absolute;
+    (
+        Name::new(name),
+        node,
+        BackgroundColor(CANOPY_FRAME),
+        BorderColor::all(NAV_CYAN.with_alpha(0.25)),
+        Pickable::IGNORE,
+    )
+}
+
+/// Open the canopy's gate while the cockpit view holds. The view is optional
+/// so a HUD-only rig without the camera plugin keeps the gate shut.
+fn sync_canopy_gate(
+    view: Option<Res<SpaceshipCameraView>>,
+    mut q_layer: Query<&mut HudContextGate, With<CanopyHudMarker>>,
+) {
+    let cockpit = view.is_some_and(|view| *view == SpaceshipCameraView::Cockpit);
+    for mut gate in &mut q_layer {
+        gate.set_if_neq(HudContextGate(cockpit));
+    }
+}
+
+/// Drives the canopy layer's gate from the camera view. The layer itself is
+/// spawned with the player ship by [`super::NovaHudPlugin`].
+#[derive(Default)]
+pub struct CanopyHudPlugin;
+
+impl Plugin for CanopyHudPlugin {
+    fn build(&self, app: &mut App) {
+        trace!("CanopyHudPlugin: build");
+
+        app.add_systems(Update, sync_canopy_gate.in_set(super::NovaHudSystems));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The windscreen is a cockpit-only fixture: the gate follows the view
+    /// both ways.
+    #[test]
+    fn the_canopy_gate_follows_the_cockpit_view() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<SpaceshipCameraView>();
+        app.add_systems(Update, sync_canopy_gate);
+        let layer = app.world_mut().spawn(canopy_hud()).id();
+
+        app.update();
+        assert!(!app.world().get::<HudContextGate>(layer).unwrap().0);
+
+        *app.world_mut().resource_mut::<SpaceshipCameraView>() = SpaceshipCameraView::Cockpit;
+        app.update();
+        assert!(app.world().get::<HudContextGate>(layer).unwrap().0);
+
+        *app.world_mut().resource_mut::<SpaceshipCameraView>() = SpaceshipCameraView::Chase;
+        app.update();
+        assert!(!app.world().get::<HudContextGate>(layer).unwrap().0);
+    }
+}
@@ -7,8 +7,10 @@
 //! several lines can be visible at once, newest at the bottom, older cards
 //! pushed up and fading. Per-line dwell still defaults
 //! to [`COMMS_DWELL_SECS`] and clamps to
-//! [`COMMS_DWELL_MIN_SECS`]..[`COMMS_DWELL_MAX_SECS`]. Pending overflow drops
-//! oldest, but the full transcript stays in [`StoryFeed`] for the NOVA OS log.
+//! [`COMMS_DWELL_MIN_SECS`]..[`COMMS_DWELL_MAX_SECS`], then the player's comms
+//! dwell multiplier ([`AccessibilitySettings::comms_dwell_factor`]) stretches
+//! it. Pending overflow drops oldest, but the full transcript stays in
+//! [`StoryFeed`] for the NOVA OS log.
 //!
 //! Scenario teardown clears the event world, the sync writes an empty feed,
 //! and the panel resets instantly - queue dropped, fades cancelled, hidden -
@@ -20,7 +22,8 @@ use std::collections::VecDeque;
 use bevy::prelude::*;
 use nova_gameplay::{
     asset_ref::AssetRef,
-    audio::{SfxCommandsExt, SoundBank, UiSfx},
+    audio::{AudioBus, SfxCommandsExt, SoundBank, UiSfx},
+    settings::prelude::AccessibilitySettings,
 };
 use nova_ui::{hud::ChipTone, theme};
 
@@ -140,29 +143,41 @@ struct VisibleCommsLine {
 }
 
 impl VisibleCommsLine {
-    fn dwell_secs(&self) -> f32 {
+    /// The hold before the fade: the authored (clamped) or default dwell,
+    /// times the player's `scale`. The scale applies after the clamp, so the
+    /// authoring range stays the author's and the multiplier stays the
+    /// player's. The scenario pacing layer times its beats off the unscaled
+    /// dwell, so a stretched line can still be up when the next objective
+    /// posts - the reader asked for the extra time.
+    fn dwell_secs(&self, scale: f32) -> f32 {
         self.line
             .dwell
             .map(|secs| secs.clamp(COMMS_DWELL_MIN_SECS, COMMS_DWELL_MAX_SECS))
             .unwrap_or(COMMS_DWELL_SECS)
+            * scale
     }
 
-    fn alpha(&self) -> f32 {
+    fn alpha(&self, scale: f32) -> f32 {
         if self.age_secs < COMMS_FADE_IN_SECS {
             return (self.age_secs / COMMS_FADE_IN_SECS).clamp(0.0, 1.0);
         }
-        let fade_start = self.dwell_secs();
+        let fade_start = self.dwell_secs(scale);
         if self.age_secs <= fade_start {
             return 1.0;
         }
         (1.0 - (self.age_secs - fade_start) / COMMS_FADE_OUT_SECS).clamp(0.0, 1.0)
     }
 
-    fn expired(&self) -> bool {
-        self.age_secs >= self.dwell_secs() + COMMS_FADE_OUT_SECS
+    fn expired(&self, scale: f32) -> bool {
+        self.age_secs >= self.dwell_secs(scale) + COMMS_FADE_OUT_SECS
     }
 }
 
+/// The player's comms dwell multiplier; 1.0 in rigs without settings.
+fn dwell_scale(accessibility: Option<&AccessibilitySettings>) -> f32 {
+    accessibility.map_or(1.0, |a11y| a11y.comms_dwell_factor())
+}
+
 /// The display queue between [`StoryFeed`] (the log) and the visible stack.
 #[derive(Resource, Default)]
 struct CommsQueue {
@@ -261,16 +276,18 @@ fn drive_comms_stack(
     mut commands: Commands,
     keys: Res<ButtonInput<KeyCode>>,
     bank: Option<Res<SoundBank<UiSfx>>>,
+    accessibility: Option<Res<AccessibilitySettings>>,
     panel: Query<Entity, With<CommsPanelMarker>>,
 ) {
     if panel.single().is_err() {
         return;
     };
 
+    let scale = dwell_scale(accessibility.as_deref());
     for visible in &mut queue.visible {
         visible.age_secs += time.delta_secs();
     }
-    queue.visible.retain(|visible| !visible.expired());
+    queue.visible.retain(|visible| !visible.expired(scale));
 
     if keys.just_pressed(KeyCode::KeyV) {
         queue.visible.pop_front();
@@ -291,7 +308,11 @@ fn drive_comms_stack(
             age_secs: 0.0,
         });
         if let Some(bank) = &bank {
-            commands.play_sfx_volume(bank.get(UiSfx::CommsLine), COMMS_BLIP_VOLUME);
+            commands.play_sfx_on(
+                AudioBus::Comms,
+                bank.get(UiSfx::CommsLine),
+                COMMS_BLIP_VOLUME,
+            );
         }
     }
 }
@@ -299,6 +320,7 @@ fn drive_comms_stack(
 fn sync_comms_cards(
     queue: Res<CommsQueue>,
     asset_server: Option<Res<AssetServer>>,
+    accessibility: Option<Res<AccessibilitySettings>>,
     mut commands: Commands,
     mut panel: Query<(Entity, &mut Visibility), With<CommsPanelMarker>>,
 ) {
@@ -311,15 +333,20 @@ fn sync_comms_cards(
         return;
     }
     *visibility = Visibility::Inherited;
+    let scale = dwell_scale(accessibility.as_deref());
     commands.entity(entity).with_children(|parent| {
         for visible in &queue.visible {
-            parent.spawn(comms_card(visible, asset_server.as_deref()));
+            parent.spawn(comms_card(visible, scale, asset_server.as_deref()));
         }
     });
 }
 
-fn comms_card(line: &VisibleCommsLine, asset_server: Option<&AssetServer>) -> impl Bundle {
-    let alpha = line.alpha();
+fn comms_card(
+    line: &VisibleCommsLine,
+    dwell_scale: f32,
+    asset_server: Option<&AssetServer>,
+) -> impl Bundle {
+    let alpha = line.alpha(dwell_scale);
     (
         CommsCardMarker,
         // Arrival emphasis: a fresh transmission grows
@@ -660,6 +687,34 @@ mod tests {
         );
     }
 
+    /// The player's dwell multiplier stretches the clamped hold: the same
+    /// 3s line that is gone by ~5s above is still up at 2x.
+    #[test]
+    fn the_dwell_multiplier_stretches_the_hold() {
+        let mut app = comms_app();
+        app.insert_resource(AccessibilitySettings {
+            comms_dwell: 2.0,
+            ..default()
+        });
+        app.update();
+        push_line(&mut app, "Alpha", "Slow down.", Some(0.5));
+        app.update();
+        app.update();
+
+        for _ in 0..20 {
+            app.update();
+        }
+        assert_eq!(
+            panel_visibility(&mut app),
+            Visibility::Inherited,
+            "3s at 2x holds for 6s"
+        );
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(panel_visibility(&mut app), Visibility::Hidden);
+    }
+
     /// Pending lines beyond the cap drop OLDEST first: after a 6-line
     /// dump, the first displayed line is the one showing, and the queue
     /// kept only the newest four of the rest.
@@ -13,6 +13,7 @@
 use bevy::prelude::*;
 use nova_gameplay::prelude::*;
 use nova_ship::prelude::*;
+use nova_ui::theme::semantic::Palette;
 
 use crate::prelude::*;
 
@@ -43,7 +44,8 @@ const CANDIDATE_COLOR: Color = Color::srgba(1.0, 0.25, 0.25, 0.45);
 
 /// The combat-lock arrow follows the reticle's slot color (torpedo_target.rs):
 /// always combat-red - red = combat lock, white = travel lock, everywhere.
-/// (The relation tint it used to mirror is retired.)
+/// (The relation tint it used to mirror is retired.) Standard-palette value;
+/// [`arrow_color`] swaps it for the active palette's threat hue.
 const LOCK_COLOR: Color = nova_ui::theme::semantic::THREAT;
 
 /// The `edge_indicators_hud` spawner, the indicator kind and marker components, and
@@ -242,12 +244,18 @@ fn tracked_entities(
     wanted
 }
 
-/// The arrow tint for a tracked entity.
-fn arrow_color(kind: EdgeIndicatorKind) -> Color {
-    match kind {
+/// The arrow tint for a tracked entity. The standard palette keeps the
+/// authored reds; a colour-blind palette carries its threat hue at the same
+/// alphas, so the three kinds still read by presence.
+fn arrow_color(kind: EdgeIndicatorKind, palette: Palette) -> Color {
+    let standard = match kind {
         EdgeIndicatorKind::Lock => LOCK_COLOR,
         EdgeIndicatorKind::Torpedo => TORPEDO_COLOR,
         EdgeIndicatorKind::Candidate => CANDIDATE_COLOR,
+    };
+    match palette {
+        Palette::Standard => standard,
+        _ => palette.threat().with_alpha(standard.alpha()),
     }
 }
 
@@ -257,13 +265,15 @@ fn arrow_color(kind: EdgeIndicatorKind) -> Color {
 /// candidates come and go), one idempotent pass covers every ordering. A
 /// kind change (a candidate becomes the lock) respawns the indicator - it
 /// is a different pointer, and lock switches are rare enough that the churn
-/// is irrelevant.
+/// is irrelevant. A [`Palette`] change respawns every indicator in the new
+/// tint the same way.
 #[expect(
     clippy::type_complexity,
     reason = "one query per indicated entity kind"
 )]
 fn sync_edge_indicators(
     mut commands: Commands,
+    palette: Option<Res<Palette>>,
     q_layer: Query<Entity, With<EdgeIndicatorsHudMarker>>,
     q_player: Query<
         (&Allegiance, Option<&CombatLock>, Option<&ThreatContacts>),
@@ -286,6 +296,8 @@ fn sync_edge_indicators(
         Ok((allegiance, lock, threats)) => (Some(allegiance), lock, threats),
         Err(_) => (None, None, None),
     };
+    let repaint = palette.as_ref().is_some_and(|palette| palette.is_changed());
+    let palette = palette.map(|palette| *palette).unwrap_or_default();
     let empty = Vec::new();
     let wanted = tracked_entities(
         lock.and_then(|lock| lock.0),
@@ -300,7 +312,7 @@ fn sync_edge_indicators(
         let keep = wanted
             .iter()
             .any(|&(entity, want)| entity == **target && want == *kind);
-        if !keep {
+        if !keep || repaint {
             commands.entity(indicator).despawn();
         }
     }
@@ -315,7 +327,7 @@ fn sync_edge_indicators(
         }
         commands
             .entity(layer)
-            .with_child(edge_indicator(entity, kind, arrow_color(kind)));
+            .with_child(edge_indicator(entity, kind, arrow_color(kind, palette)));
     }
 }
 
@@ -501,9 +513,26 @@ mod tests {
     fn the_kinds_have_their_tints_and_the_lock_arrow_is_combat_red() {
         // Slot-colored lock language: the lock arrow is always combat-red,
         // relation-independent, matching the reticle.
-        assert_eq!(arrow_color(EdgeIndicatorKind::Lock), LOCK_COLOR);
-        assert_eq!(arrow_color(EdgeIndicatorKind::Torpedo), TORPEDO_COLOR);
-        assert_eq!(arrow_color(EdgeIndicatorKind::Candidate), CANDIDATE_COLOR);
+        let standard = Palette::Standard;
+        assert_eq!(arrow_color(EdgeIndicatorKind::Lock, standard), LOCK_COLOR);
+        assert_eq!(
+            arrow_color(EdgeIndicatorKind::Torpedo, standard),
+            TORPEDO_COLOR
+        );
+        assert_eq!(
+            arrow_color(EdgeIndicatorKind::Candidate, standard),
+            CANDIDATE_COLOR
+        );
+
+        let tritan = Palette::Tritanopia;
+        assert_eq!(
+            arrow_color(EdgeIndicatorKind::Lock, tritan),
+            tritan.threat()
+        );
+        assert_eq!(
+            arrow_color(EdgeIndicatorKind::Candidate, tritan).alpha(),
+            CANDIDATE_COLOR.alpha()
+        );
     }
 
     #[test]
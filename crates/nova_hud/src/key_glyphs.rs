@@ -22,9 +22,10 @@
 //!
 //! Unmapped labels resolve to `None`, and every consumer falls back to a TEXT
 //! chip - a rebind to an unmapped key degrades to the old look instead of
-//! rendering an empty box. TODO(20260710-231927): the remapping/gamepad
-//! follow-up may `server.load` a glyph for a runtime-rebound key - that is
-//! dynamic content and cannot sit behind a one-shot preload collection.
+//! rendering an empty box. The Controls page rebinds at runtime, so the table
+//! preloads the keys a remap commonly lands on rather than `server.load`ing a
+//! glyph per rebind - dynamic content cannot sit behind a one-shot preload
+//! collection, and a ~60-cap preload is cheap.
 
 use bevy::{platform::collections::HashMap, prelude::*};
 
@@ -41,9 +42,10 @@ pub const KEY_GLYPH_DIR: &str = "input-prompts/keyboard/Alt";
 
 /// The mapping: display label -> keycap file stem under [`KEY_GLYPH_DIR`].
 ///
-/// Covers every key the flight rig binds (`flight_rig_reserved_sources`) plus
-/// the HUD's own chrome keys (Tab for the NOVA OS, the backquote HUD-level
-/// cycle) and the fixed gesture pseudo-labels. Two upstream filenames are
+/// Covers every key the default keymap binds (`Keymap::default`) plus the
+/// HUD's own chrome keys (Tab for the NOVA OS, the backquote HUD-level cycle),
+/// the fixed gesture pseudo-labels, and the keys a remap commonly lands on
+/// (letters, digits, arrows, the F row, the editing block). Two upstream filenames are
 /// misspelled/abbreviated and are pinned here so a rename is caught by
 /// `every_bound_key_maps_to_an_existing_glyph_asset`: `T_Crtl_Key_Alt` (the
 /// upstream typo for Ctrl) and `T_Brackets_L/R_Key_Alt`.
@@ -72,6 +74,71 @@ pub const KEY_GLYPH_FILES: &[(&str, &str)] = &[
     ("Tab", "T_Tab_Key_Alt"),
     ("TAB", "T_Tab_Key_Alt"),
     ("Backquote", "T_Tilde_Key_Alt"),
+    // Remap targets: the Controls page can move any action onto these, and the
+    // hints label a rebound key like any verb key. Upstream ships no `4` cap,
+    // so a rebind there keeps the text chip.
+    ("A", "T_A_Key_Alt"),
+    ("B", "T_B_Key_Alt"),
+    ("C", "T_C_Key_Alt"),
+    ("D", "T_D_Key_Alt"),
+    ("E", "T_E_Key_Alt"),
+    ("F", "T_F_Key_Alt"),
+    ("H", "T_H_Key_Alt"),
+    ("I", "T_I_Key_Alt"),
+    ("J", "T_J_Key_Alt"),
+    ("K", "T_K_Key_Alt"),
+    ("L", "T_L_Key_Alt"),
+    ("M", "T_M_Key_Alt"),
+    ("N", "T_N_Key_Alt"),
+    ("P", "T_P_Key_Alt"),
+    ("Q", "T_Q_Key_Alt"),
+    ("R", "T_R_Key_Alt"),
+    ("S", "T_S_Key_Alt"),
+    ("T", "T_T_Key_Alt"),
+    ("U", "T_U_Key_Alt"),
+    ("V", "T_V_Key_Alt"),
+    ("Y", "T_Y_Key_Alt"),
+    ("0", "T_0_Key_Alt"),
+    ("1", "T_1_Key_Alt"),
+    ("2", "T_2_Key_Alt"),
+    ("3", "T_3_Key_Alt"),
+    ("5", "T_5_Key_Alt"),
+    ("6", "T_6_Key_Alt"),
+    ("7", "T_7_Key_Alt"),
+    ("8", "T_8_Key_Alt"),
+    ("9", "T_9_Key_Alt"),
+    ("AltLeft", "T_Alt_Key_Alt"),
+    ("AltRight", "T_Alt_Key_Alt"),
+    ("ArrowUp", "T_Up_Key_Alt"),
+    ("ArrowDown", "T_Down_Key_Alt"),
+    ("ArrowLeft", "T_Left_Key_Alt"),
+    ("ArrowRight", "T_Right_Key_Alt"),
+    ("Enter", "T_Enter_Key_Alt"),
+    ("Backspace", "T_BackSpace_Key_Alt"),
+    ("CapsLock", "T_CapsLock_Key_Alt"),
+    ("Delete", "T_Del_Key_Alt"),
+    ("End", "T_End_Key_Alt"),
+    ("Home", "T_Home_Key_Alt"),
+    ("Insert", "T_Ins_Key_Alt"),
+    ("PageUp", "T_PageUp_Key_Alt"),
+    ("PageDown", "T_PageDown_Key_Alt"),
+    ("Minus", "T_Minus_Key_Alt"),
+    ("Semicolon", "T_Semicolon_Key_Alt"),
+    ("Slash", "T_Slash_Key_Alt"),
+    ("Quote", "T_Quotation_Key_Alt"),
+    ("Escape", "T_Esc_Key_Alt"),
+    ("F1", "T_F1_Key_Alt"),
+    ("F2", "T_F2_Key_Alt"),
+    ("F3", "T_F3_Key_Alt"),
+    ("F4", "T_F4_Key_Alt"),
+    ("F5", "T_F5_Key_Alt"),
+    ("F6", "T_F6_Key_Alt"),
+    ("F7", "T_F7_Key_Alt"),
+    ("F8", "T_F8_Key_Alt"),
+    ("F9", "T_F9_Key_Alt"),
+    ("F10", "T_F10_Key_Alt"),
+    ("F11", "T_F11_Key_Alt"),
+    ("F12", "T_F12_Key_Alt"),
 ];
 
 /// The keycap file stem for `label`, or `None` when the key has no art (the
@@ -312,6 +379,35 @@ mod tests {
         }
     }
 
+    /// Every keyboard key the DEFAULT keymap binds has a keycap, labelled the
+    /// way the hints label it - the shipped layout never shows a text chip.
+    #[test]
+    fn every_default_keymap_key_has_a_glyph() {
+        use nova_ship::input::{
+            keymap::{Keymap, KeymapAction},
+            player::InputSource,
+        };
+
+        let keymap = Keymap::default();
+        let keys: Vec<KeyCode> = KeymapAction::ALL
+            .iter()
+            .flat_map(|action| keymap.get(*action).keyboard)
+            .flatten()
+            .filter_map(|source| match source {
+                InputSource::Keyboard(key) => Some(key),
+                _ => None,
+            })
+            .collect();
+        assert!(!keys.is_empty(), "delivery guard: the keymap binds keys");
+        for key in keys {
+            let label = nova_ship::input::player::keyboard_label(key);
+            assert!(
+                key_glyph_stem(&label).is_some(),
+                "no keycap glyph for the default binding '{label}'"
+            );
+        }
+    }
+
     /// The path list the collection preloads is the DISTINCT set of mapped
     /// files - shared keycaps (both Control keys, both Shift keys) must not
     /// load twice.
@@ -337,6 +433,7 @@ mod tests {
     #[test]
     fn unmapped_keys_fall_back_instead_of_resolving() {
         assert_eq!(key_glyph_stem("F13"), None);
+        assert_eq!(key_glyph_stem("4"), None, "upstream ships no 4 keycap");
         assert_eq!(key_glyph_stem(""), None);
     }
 }
@@ -1,9 +1,9 @@
 //! The player's heads-up display: the diegetic instruments and overlays drawn
 //! for the player ship (velocity/flight status, lock crosshairs and dwell rings,
-//! turret lead and torpedo target reticles, ammo readouts, edge/threat
-//! indicators, objective markers, the comms panel and the keybind dock). Each
-//! widget lives in its own submodule and is a [`HudTier`] layer spawned and
-//! despawned with the player ship.
+//! turret lead and torpedo target reticles, ammo and repair readouts, edge/threat
+//! indicators, objective markers, the comms panel, the race timer, the
+//! keybind dock and the cockpit canopy). Each widget lives in its own submodule
+//! and is a [`HudTier`] layer spawned and despawned with the player ship.
 //!
 //! Touch this crate (or add a module) to change what the player sees.
 //! [`NovaHudPlugin`] adds every widget; the HUD reads gameplay state (locks,
@@ -31,6 +31,7 @@ use crate::prelude::*;
 pub mod allegiance_markers;
 pub mod ammo_readout;
 pub mod beacon_chips;
+pub mod canopy;
 pub mod comms_panel;
 pub mod component_lock;
 pub mod edge_indicators;
@@ -46,7 +47,9 @@ pub mod maneuver_instruments;
 pub mod objective_feedback;
 pub mod objective_markers;
 pub mod objective_stack;
+pub mod race_timer;
 pub mod readout;
+pub mod repair_readout;
 pub mod screen_indicator;
 pub mod situation;
 pub mod target_inset;
@@ -63,16 +66,17 @@ mod chip_layout_rig;
 pub mod prelude {
     pub use super::{
         allegiance_markers::prelude::*, ammo_readout::prelude::*, beacon_chips::prelude::*,
-        comms_panel::prelude::*, component_lock::prelude::*, edge_indicators::prelude::*,
-        emphasis::prelude::*, flight_status::prelude::*, holo_instruments::prelude::*,
-        item_highlights::prelude::*, key_glyphs::prelude::*, keybind_dock::prelude::*,
-        lock_crosshairs::prelude::*, lock_dwell_ring::prelude::*, maneuver_instruments::prelude::*,
-        objective_feedback::prelude::*, objective_markers::prelude::*, objective_stack::prelude::*,
-        readout::prelude::*, screen_indicator::prelude::*, situation::prelude::*,
-        target_inset::prelude::*, torpedo_target::prelude::*, turret_lead::prelude::*,
-        velocity::prelude::*, HudContextGate, HudNovaOsExempt, HudSelfDrivenVisibility,
-        HudSituationSensingSystems, HudTier, HudVisibility, NovaHudAssets, NovaHudPlugin,
-        NovaHudSystems,
+        canopy::prelude::*, comms_panel::prelude::*, component_lock::prelude::*,
+        edge_indicators::prelude::*, emphasis::prelude::*, flight_status::prelude::*,
+        holo_instruments::prelude::*, item_highlights::prelude::*, key_glyphs::prelude::*,
+        keybind_dock::prelude::*, lock_crosshairs::prelude::*, lock_dwell_ring::prelude::*,
+        maneuver_instruments::prelude::*, objective_feedback::prelude::*,
+        objective_markers::prelude::*, objective_stack::prelude::*, race_timer::prelude::*,
+        readout::prelude::*, repair_readout::prelude::*, screen_indicator::prelude::*,
+        situation::prelude::*, target_inset::prelude::*, torpedo_target::prelude::*,
+        turret_lead::prelude::*, velocity::prelude::*, HudContextGate, HudNovaOsExempt,
+        HudSelfDrivenVisibility, HudSituationSensingSystems, HudTier, HudVisibility, NovaHudAssets,
+        NovaHudPlugin, NovaHudSystems,
     };
 }
 
@@ -284,6 +288,14 @@ impl Plugin for NovaHudPlugin {
                 .in_set(HudSituationSensingSystems)
                 .before(NovaHudSystems),
         );
+        // The adaptive music follows the same situations; optional so a
+        // HUD-only rig without the audio plugin runs.
+        app.add_systems(
+            Update,
+            situation::feed_music_cues
+                .after(situation::sense_hud_situations)
+                .run_if(resource_exists::<MusicCues>),
+        );
         app.add_systems(
             PostUpdate,
             emphasis::drive_hud_emphasis.before(bevy::ui::UiSystems::Layout),
@@ -316,10 +328,12 @@ impl Plugin for NovaHudPlugin {
         app.add_plugins(holo_instruments::HoloInstrumentsPlugin);
         app.add_plugins(comms_panel::CommsPanelPlugin);
         app.add_plugins(readout::HudReadoutPlugin);
+        app.add_plugins(race_timer::RaceTimerPlugin);
         app.add_plugins(screen_indicator::ScreenIndicatorPlugin);
         app.add_plugins(torpedo_target::TorpedoTargetHudPlugin);
         app.add_plugins(turret_lead::TurretLeadPlugin);
         app.add_plugins(ammo_readout::AmmoReadoutPlugin);
+        app.add_plugins(repair_readout::RepairReadoutPlugin);
         app.add_plugins(component_lock::ComponentLockHudPlugin);
         app.add_plugins(lock_dwell_ring::LockDwellRingHudPlugin);
         app.add_plugins(lock_crosshairs::LockCrosshairsHudPlugin);
@@ -327,6 +341,7 @@ impl Plugin for NovaHudPlugin {
         app.add_plugins(edge_indicators::EdgeIndicatorsHudPlugin);
         app.add_plugins(beacon_chips::BeaconChipsHudPlugin);
         app.add_plugins(allegiance_markers::AllegianceMarkerHudPlugin);
+        app.add_plugins(canopy::CanopyHudPlugin);
         app.add_plugins(objective_markers::ObjectiveMarkersHudPlugin);
         app.add_plugins(item_highlights::ItemHighlightsHudPlugin);
         app.add_plugins(objective_feedback::ObjectiveFeedbackPlugin);
@@ -350,8 +365,10 @@ impl Plugin for NovaHudPlugin {
         // `despawn_player_hud` for the teardown.
         add_player_hud::<TurretLeadHudMarker, _>(app, HudTier::Instrument, turret_lead_hud);
         add_player_hud::<AmmoReadoutHudMarker, _>(app, HudTier::Instrument, ammo_readout_hud);
+        add_player_hud::<RepairReadoutHudMarker, _>(app, HudTier::Instrument, repair_readout_hud);
         add_player_hud::<ComponentLockHudMarker, _>(app, HudTier::Chrome, component_lock_hud);
         add_player_hud::<EdgeIndicatorsHudMarker, _>(app, HudTier::Chrome, edge_indicators_hud);
+        add_player_hud::<CanopyHudMarker, _>(app, HudTier::Instrument, canopy_hud);
 
         app.add_observer(setup_hud_lock_dwell_ring);
         app.add_observer(despawn_player_hud::<LockDwellRingHudMarker>);
@@ -443,10 +460,13 @@ fn apply_hud_visibility(
 ) {
     // While the Tab NOVA OS is open the flight HUD hides so it does not fight the
     // NOVA OS monitor; only diagnostic/status widgets carrying `HudNovaOsExempt`
-    // stay. The restore branch fires on a pause change too, so CLOSING the
-    // NOVA OS un-hides in the same frame - not just on a grave/tilde level
-    // change.
-    let nova_os_open = *pause.get() == nova_gameplay::PauseStates::NovaOs;
+    // stay. The docked refit bay covers the screen the same way. The restore
+    // branch fires on a pause change too, so CLOSING the NOVA OS un-hides in the
+    // same frame - not just on a grave/tilde level change.
+    let nova_os_open = matches!(
+        pause.get(),
+        nova_gameplay::PauseStates::NovaOs | nova_gameplay::PauseStates::Refit
+    );
     let level_restore = level.is_changed() || pause.is_changed();
     for (gate, mut visibility, self_driven, exempt) in &mut q_roots {
         let open = gate.as_ref().is_none_or(|gate| gate.0);
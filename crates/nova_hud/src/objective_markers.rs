@@ -11,7 +11,10 @@
 
 use bevy::prelude::*;
 use nova_gameplay::prelude::*;
-use nova_ui::hud::{chip_node, chip_paint, ChipTone};
+use nova_ui::{
+    hud::{chip_node, chip_paint, ChipTone},
+    theme::semantic::Palette,
+};
 
 use super::{screen_indicator::prelude::*, HudTier, OBJECTIVE_GOLD};
 
@@ -324,20 +327,27 @@ fn breath_alpha(elapsed_secs: f32) -> f32 {
 /// strokes - with the shared wave. The label text deliberately does NOT
 /// breathe: thinning 12 px text to 0.7 alpha broke readability over
 /// bright scene content; the
-/// glyphs carry all the motion.
+/// glyphs carry all the motion. The gold is the active [`Palette`]'s
+/// objective hue, which the label follows too (unbreathed).
 fn breathe_objective_markers(
     time: Res<Time>,
+    palette: Option<Res<Palette>>,
     mut q_border: Query<&mut BorderColor, With<ObjectiveMarkerBreathMarker>>,
     mut q_background: Query<&mut BackgroundColor, With<ObjectiveMarkerBreathMarker>>,
+    mut q_labels: Query<&mut TextColor, With<ObjectiveMarkerChipTextMarker>>,
 ) {
+    let gold = palette.map_or(OBJECTIVE_GOLD, |palette| palette.objective());
     let alpha = breath_alpha(time.elapsed_secs());
-    let breathed = OBJECTIVE_GOLD.with_alpha(OBJECTIVE_GOLD.alpha() * alpha);
+    let breathed = gold.with_alpha(gold.alpha() * alpha);
     for mut border in &mut q_border {
         *border = BorderColor::all(breathed);
     }
     for mut background in &mut q_background {
         background.0 = breathed;
     }
+    for mut label in &mut q_labels {
+        label.set_if_neq(TextColor(gold));
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,373 @@
+//! The race timer: the HUD face of a scenario race course.
+//!
+//! A course (nova_scenario) tracks the player's run - which gate is next, which
+//! lap, the clock since the start gate, each split and how it compares with the
+//! stored personal best - and its sync writes the run into [`RaceClock`] here
+//! every frame. The panel shows up to four chips under the readout strip:
+//!
+//! ```text
+//! LAP 2/3  GATE 4/6
+//! TIME 01:12.4
+//! SPLIT 00:58.1 -0.6
+//! GHOST +1.3
+//! ```
+//!
+//! Before the start gate the first chip reads `TO START`; after the finish it
+//! reads `FINISH`. The split chip only appears once a gate past the start has
+//! been flown, and its delta only when there is a best to compare with; a
+//! split behind the best reads amber. The ghost chip only appears while a ghost
+//! of the best run is being raced, and reads amber behind it too. The clock is the scenario clock, so it freezes on pause and
+//! behind the outcome overlay with no extra machinery, like the readout strip.
+//!
+//! [`RaceClock`] is `None` whenever no course is loaded - teardown despawns the
+//! course, the sync writes `None`, and the panel hides - so a race cannot leak
+//! into the next scenario or the menu.
+
+use bevy::prelude::*;
+use nova_ui::hud::{chip_node, chip_paint, ChipTone};
+
+use super::{readout::prelude::HudReadoutFormat, HudTier};
+
+/// Glob-import surface: `use nova_hud::race_timer::prelude::*`
+/// re-exports the public API of this module.
+pub mod prelude {
+    pub use super::{format_race_delta, RaceClock, RaceClockState, RaceSplit};
+}
+
+/// The last gate's split: the time since the start gate, and how far ahead
+/// (negative) or behind (positive) of the best run's split at the same gate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RaceSplit {
+    /// Seconds from the start gate to this gate.
+    pub time: f64,
+    /// Seconds against the best run at the same gate; `None` with no best.
+    pub delta: Option<f64>,
+}
+
+/// One course's run, as the panel shows it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaceClockState {
+    /// The lap being flown, from 1; holds at `laps` once finished.
+    pub lap: u32,
+    /// Laps in the race (1 for a sprint).
+    pub laps: u32,
+    /// The gate being flown to, from 1 within the lap; 0 until the start gate
+    /// is flown.
+    pub gate: u32,
+    /// Gates in one lap.
+    pub gates: u32,
+    /// Seconds since the start gate; 0 before it, the final time after the
+    /// finish.
+    pub elapsed: f64,
+    /// The last split, once a gate past the start has been flown.
+    pub split: Option<RaceSplit>,
+    /// Whether the finish gate has been flown.
+    pub finished: bool,
+    /// Seconds behind (positive) or ahead (negative) of the ghost of the best
+    /// run; `None` with no ghost being raced.
+    pub ghost: Option<f64>,
+}
+
+/// The race in progress, if the loaded scenario has a course. Written by
+/// nova_scenario's course sync every frame (`None` with no course, so teardown
+/// clears it); the race-timer panel renders it. Lives here for the same reason
+/// as [`HudReadouts`](super::readout::HudReadouts): the HUD cannot depend on
+/// nova_scenario.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct RaceClock(pub Option<RaceClockState>);
+
+/// A split delta as the panel and the results screen print it: signed, one
+/// decimal, `+1.2` behind and `-0.6` ahead.
+pub fn format_race_delta(delta: f64) -> String {
+    if delta < 0.0 {
+        format!("-{:.1}", -delta)
+    } else {
+        format!("+{delta:.1}")
+    }
+}
+
+/// Race-timer font size (px): the readout strip's, so the two read as one
+/// family.
+const RACE_TIMER_FONT_SIZE_PX: f32 = 18.0;
+
+/// The panel container (top-center, under the readout strip).
+#[derive(Component)]
+struct RaceTimerPanelMarker;
+
+/// Which of the panel's four chips a text entity is.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+enum RaceTimerLine {
+    Progress,
+    Time,
+    Split,
+    Ghost,
+}
+
+/// Drives the race timer: inits [`RaceClock`], spawns the hidden panel in
+/// Startup, and rewrites its chips each frame within
+/// [`super::NovaHudSystems`].
+pub struct RaceTimerPlugin;
+
+impl Plugin for RaceTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RaceClock>();
+        app.add_systems(Startup, spawn_race_timer_panel);
+        app.add_systems(Update, sync_race_timer.in_set(super::NovaHudSystems));
+    }
+}
+
+/// The panel: four chips in a top-center column, hidden until a course is
+/// loaded. Spawned once, like the readout strip, and only rewritten after.
+fn spawn_race_timer_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("RaceTimerPanel"),
+            RaceTimerPanelMarker,
+            HudTier::Instrument,
+            Node {
+                position_type: PositionType::Absolute,
+                // Below the readout strip, which holds a scenario's own clock
+                // or counter if it authors one.
+                top: Val::Px(72.0),
+                left: Val::Percent(50.0),
+                margin: UiRect {
+                    left: Val::Px(-100.0),
+                    ..default()
+                },
+                width: Val::Px(200.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                display: Display::None,
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            for line in [
+                RaceTimerLine::Progress,
+                RaceTimerLine::Time,
+                RaceTimerLine::Split,
+                RaceTimerLine::Ghost,
+            ] {
+                parent.spawn((
+                    Name::new(format!("RaceTimer{line:?}")),
+                    line,
+                    Text::default(),
+                    TextFont::from_font_size(RACE_TIMER_FONT_SIZE_PX),
+                    chip_node(),
+                    chip_paint(ChipTone::Phosphor),
+                    TextColor(ChipTone::Phosphor.text()),
+                ));
+            }
+        });
+}
+
+/// Show or hide the panel with the course, and rewrite each chip's text only
+/// when it changed.
+fn sync_race_timer(
+    clock: Res<RaceClock>,
+    mut q_panel: Query<&mut Node, With<RaceTimerPanelMarker>>,
+    mut q_lines: Query<
+        (&RaceTimerLine, &mut Text, &mut TextColor, &mut Node),
+        Without<RaceTimerPanelMarker>,
+    >,
+) {
+    let Ok(mut panel) = q_panel.single_mut() else {
+        return;
+    };
+    let Some(state) = &clock.0 else {
+        if panel.display != Display::None {
+            panel.display = Display::None;
+        }
+        return;
+    };
+    if panel.display != Display::Flex {
+        panel.display = Display::Flex;
+    }
+
+    for (line, mut text, mut color, mut node) in &mut q_lines {
+        let (shown, tone) = match line {
+            RaceTimerLine::Progress => (Some(progress_text(state)), ChipTone::Phosphor),
+            RaceTimerLine::Time => (
+                Some(format!(
+                    "TIME {}",
+                    HudReadoutFormat::Time.render(state.elapsed)
+                )),
+                ChipTone::Phosphor,
+            ),
+            RaceTimerLine::Split => match state.split {
+                Some(split) => (
+                    Some(split_text(split)),
+                    if split.delta.is_some_and(|delta| delta > 0.0) {
+                        ChipTone::Amber
+                    } else {
+                        ChipTone::Phosphor
+                    },
+                ),
+                None => (None, ChipTone::Phosphor),
+            },
+            RaceTimerLine::Ghost => match state.ghost {
+                Some(delta) => (
+                    Some(format!("GHOST {}", format_race_delta(delta))),
+                    if delta > 0.0 {
+                        ChipTone::Amber
+                    } else {
+                        ChipTone::Phosphor
+                    },
+                ),
+                None => (None, ChipTone::Phosphor),
+            },
+        };
+        let display = if shown.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+        if let Some(shown) = shown {
+            if text.0 != shown {
+                text.0 = shown;
+            }
+        }
+        if color.0 != tone.text() {
+            color.0 = tone.text();
+        }
+    }
+}
+
+/// `LAP 2/3  GATE 4/6`, `TO START` before the start gate, or `FINISH` once
+/// the last gate is flown. A sprint has no lap count to show.
+fn progress_text(state: &RaceClockState) -> String {
+    if state.finished {
+        return "FINISH".to_string();
+    }
+    if state.gate == 0 {
+        return "TO START".to_string();
+    }
+    let gate = format!("GATE {}/{}", state.gate, state.gates);
+    if state.laps > 1 {
+        format!("LAP {}/{}  {gate}", state.lap, state.laps)
+    } else {
+        gate
+    }
+}
+
+/// `SPLIT 00:58.1 -0.6`, the delta only when there is a best to compare with.
+fn split_text(split: RaceSplit) -> String {
+    let time = HudReadoutFormat::Time.render(split.time);
+    match split.delta {
+        Some(delta) => format!("SPLIT {time} {}", format_race_delta(delta)),
+        None => format!("SPLIT {time}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> RaceClockState {
+        RaceClockState {
+            lap: 2,
+            laps: 3,
+            gate: 4,
+            gates: 6,
+            elapsed: 72.4,
+            split: None,
+            finished: false,
+            ghost: None,
+        }
+    }
+
+    /// The chips read as the module docs show them: laps only on a circuit,
+    /// a signed delta only with a best to compare against.
+    #[test]
+    fn chips_render_as_documented() {
+        assert_eq!(progress_text(&state()), "LAP 2/3  GATE 4/6");
+        let sprint = RaceClockState {
+            lap: 1,
+            laps: 1,
+            ..state()
+        };
+        assert_eq!(progress_text(&sprint), "GATE 4/6");
+        let finished = RaceClockState {
+            finished: true,
+            ..state()
+        };
+        assert_eq!(progress_text(&finished), "FINISH");
+        let waiting = RaceClockState { gate: 0, ..state() };
+        assert_eq!(progress_text(&waiting), "TO START");
+
+        assert_eq!(
+            split_text(RaceSplit {
+                time: 58.1,
+                delta: Some(-0.6),
+            }),
+            "SPLIT 00:58.1 -0.6"
+        );
+        assert_eq!(
+            split_text(RaceSplit {
+                time: 58.1,
+                delta: None,
+            }),
+            "SPLIT 00:58.1"
+        );
+        assert_eq!(format_race_delta(1.25), "+1.2");
+        assert_eq!(format_race_delta(0.0), "+0.0");
+    }
+
+    /// The panel follows the clock: hidden with no course, shown with one -
+    /// its ghost chip only while a ghost is raced - and hidden again when the
+    /// course is torn down.
+    #[test]
+    fn the_panel_follows_the_race_clock() {
+        let mut app = App::new();
+        app.init_resource::<RaceClock>();
+        app.add_systems(Startup, spawn_race_timer_panel);
+        app.add_systems(Update, sync_race_timer);
+        app.update();
+
+        let panel_display = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&Node, With<RaceTimerPanelMarker>>()
+                .single(app.world())
+                .unwrap()
+                .display
+        };
+        assert_eq!(panel_display(&mut app), Display::None);
+
+        app.world_mut().resource_mut::<RaceClock>().0 = Some(state());
+        app.update();
+        assert_eq!(panel_display(&mut app), Display::Flex);
+        let time = app
+            .world_mut()
+            .query::<(&RaceTimerLine, &Text)>()
+            .iter(app.world())
+            .find(|(line, _)| **line == RaceTimerLine::Time)
+            .map(|(_, text)| text.0.clone());
+        assert_eq!(time.as_deref(), Some("TIME 01:12.4"));
+        let ghost_display = |app: &mut App| {
+            app.world_mut()
+                .query::<(&RaceTimerLine, &Text, &Node)>()
+                .iter(app.world())
+                .find(|(line, _, _)| **line == RaceTimerLine::Ghost)
+                .map(|(_, text, node)| (node.display, text.0.clone()))
+                .unwrap()
+        };
+        assert_eq!(ghost_display(&mut app).0, Display::None);
+        app.world_mut().resource_mut::<RaceClock>().0 = Some(RaceClockState {
+            ghost: Some(1.3),
+            ..state()
+        });
+        app.update();
+        assert_eq!(
+            ghost_display(&mut app),
+            (Display::Flex, "GHOST +1.3".to_string())
+        );
+
+        app.world_mut().resource_mut::<RaceClock>().0 = None;
+        app.update();
+        assert_eq!(panel_display(&mut app), Display::None);
+    }
+}
@@ -0,0 +1,297 @@
+//! Damage-control progress bars: a thin bar drawn ON each player section with
+//! a running [`SectionRepairJob`], and one on the ship itself while a
+//! [`SectionRebuildJob`] brings a lost section back, so the player can watch
+//! the patch land without opening the ship computer.
+//!
+//! A thin consumer of the [`screen_indicator`](mod@super::screen_indicator)
+//! widget with `Entity` anchors, on the same reconcile-then-drive shape as the
+//! ammo readout: `sync_repair_readouts` keeps one bar per job (the job
+//! component IS the lifecycle, so a hit that cancels a repair takes its bar
+//! the same frame), and `drive_repair_readouts` sizes each fill from the job's
+//! progress. The bars only exist while work is underway, so the layer needs no
+//! contextual gate of its own.
+
+use bevy::prelude::*;
+use nova_gameplay::prelude::*;
+use nova_ship::prelude::*;
+
+use crate::prelude::*;
+
+/// The repair readout markers, its layer bundle and `RepairReadoutPlugin`.
+pub mod prelude {
+    pub use super::{
+        repair_readout_hud, RepairReadoutFill, RepairReadoutHudMarker, RepairReadoutMarker,
+        RepairReadoutPlugin, RepairReadoutTarget,
+    };
+}
+
+/// A section bar's size (px): wide enough to read a fraction, thin enough to
+/// sit under a section without hiding it.
+const SECTION_BAR: Vec2 = Vec2::new(34.0, 4.0);
+
+/// The ship-wide rebuild bar (px): longer, because it spans a 15 s job.
+const REBUILD_BAR: Vec2 = Vec2::new(64.0, 5.0);
+
+/// Fill tint: the damage-control green, distinct from the amber ammo gauge.
+const FILL_COLOR: Color = Color::srgba(0.35, 1.0, 0.55, 0.9);
+
+/// The bar's unfilled track.
+const TRACK_COLOR: Color = Color::srgba(0.35, 1.0, 0.55, 0.18);
+
+/// Marker for the full-screen bar layer (the root the HUD setup spawns).
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RepairReadoutHudMarker;
+
+/// Marker for one job's bar node.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RepairReadoutMarker;
+
+/// What a bar tracks: a section under repair, or the ship root rebuilding.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum RepairReadoutTarget {
+    /// A section with a [`SectionRepairJob`].
+    Repair(Entity),
+    /// A ship root with a [`SectionRebuildJob`].
+    Rebuild(Entity),
+}
+
+impl RepairReadoutTarget {
+    fn entity(self) -> Entity {
+        match self {
+            Self::Repair(entity) | Self::Rebuild(entity) => entity,
+        }
+    }
+}
+
+/// The fill child of a bar, whose width is the job's progress.
+#[derive(Component, Debug, Clone, Reflect)]
+pub struct RepairReadoutFill;
+
+/// UI bundle for the bar layer. Bars are spawned under it by
+/// `sync_repair_readouts`, one per running job on the player ship.
+pub fn repair_readout_hud() -> impl Bundle {
+    (
+        Name::new("RepairReadoutHUD"),
+        RepairReadoutHudMarker,
+        screen_indicator_layer(),
+    )
+}
+
+/// Spawn one bar under `layer` for `target`.
+fn spawn_repair_readout(commands: &mut Commands, layer: Entity, target: RepairReadoutTarget) {
+    let (size, offset) = match target {
+        // Just under the section, clear of the ammo gauge's up-right corner.
+        RepairReadoutTarget::Repair(_) => (SECTION_BAR, Vec2::new(0.0, 14.0)),
+        // Under the ship's centre, below the section bars.
+        RepairReadoutTarget::Rebuild(_) => (REBUILD_BAR, Vec2::new(0.0, 28.0)),
+    };
+    commands.entity(layer).with_children(|layer_children| {
+        layer_children
+            .spawn((
+                Name::new("RepairReadout"),
+                RepairReadoutMarker,
+                target,
+                screen_indicator(ScreenIndicatorConfig {
+                    anchor: Some(ScreenIndicatorAnchorKind::Entity(target.entity())),
+                    size: ScreenIndicatorSize::Fixed(size),
+                    offset,
+                    offscreen: ScreenIndicatorOffscreen::Hide,
+                }),
+                BackgroundColor(TRACK_COLOR),
+            ))
+            .with_children(|bar| {
+                bar.spawn((
+                    RepairReadoutFill,
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(FILL_COLOR),
+                    Pickable::IGNORE,
+                ));
+            });
+    });
+}
+
+/// Reconcile the bars against the player ship's running jobs: spawn one for
+/// each new job, despawn any whose job finished, was cancelled, or whose
+/// section left the ship.
+fn sync_repair_readouts(
+    mut commands: Commands,
+    q_layer: Query<Entity, With<RepairReadoutHudMarker>>,
+    q_player: Query<
+        (Entity, Has<SectionRebuildJob>),
+        (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
+    >,
+    q_repairs: Query<(Entity, &ChildOf), With<SectionRepairJob>>,
+    q_readouts: Query<(Entity, &RepairReadoutTarget), With<RepairReadoutMarker>>,
+) {
+    let Ok(layer) = q_layer.single() else {
+        return;
+    };
+    let Ok((player, rebuilding)) = q_player.single() else {
+        return;
+    };
+
+    let mut wanted: Vec<RepairReadoutTarget> = q_repairs
+        .iter()
+        .filter(|(_, ChildOf(parent))| *parent == player)
+        .map(|(section, _)| RepairReadoutTarget::Repair(section))
+        .collect();
+    if rebuilding {
+        wanted.push(RepairReadoutTarget::Rebuild(player));
+    }
+
+    for (readout, target) in &q_readouts {
+        if !wanted.contains(target) {
+            commands.entity(readout).despawn();
+        }
+    }
+    for target in wanted {
+        if !q_readouts.iter().any(|(_, have)| *have == target) {
+            spawn_repair_readout(&mut commands, layer, target);
+        }
+    }
+}
+
+/// Size each bar's fill to its job's progress.
+fn drive_repair_readouts(
+    q_readouts: Query<(&RepairReadoutTarget, &Children), With<RepairReadoutMarker>>,
+    q_repairs: Query<(&SectionRepairJob, &Health)>,
+    q_rebuilds: Query<&SectionRebuildJob>,
+    mut q_fill: Query<&mut Node, With<RepairReadoutFill>>,
+) {
+    for (target, children) in &q_readouts {
+        let progress = match *target {
+            RepairReadoutTarget::Repair(section) => q_repairs
+                .get(section)
+                .map(|(job, health)| job.progress(health)),
+            RepairReadoutTarget::Rebuild(ship) => q_rebuilds.get(ship).map(|job| job.progress()),
+        };
+        let Ok(progress) = progress else {
+            continue;
+        };
+        let width = Val::Percent(progress * 100.0);
+        for child in children.iter() {
+            if let Ok(mut node) = q_fill.get_mut(child) {
+                if node.width != width {
+                    node.width = width;
+                }
+            }
+        }
+    }
+}
+
+/// Draws a progress bar on every player section under repair and on the ship
+/// while it rebuilds a lost section. Reconciles then drives the bars in
+/// PostUpdate, before the indicator projection places them.
+#[derive(Default)]
+pub struct RepairReadoutPlugin;
+
+impl Plugin for RepairReadoutPlugin {
+    fn build(&self, app: &mut App) {
+        trace!("RepairReadoutPlugin: build");
+
+        app.register_type::<RepairReadoutHudMarker>();
+        app.register_type::<RepairReadoutMarker>();
+        app.register_type::<RepairReadoutTarget>();
+        app.register_type::<RepairReadoutFill>();
+
+        app.add_systems(
+            PostUpdate,
+            (sync_repair_readouts, drive_repair_readouts)
+                .chain()
+                .before(ScreenIndicatorSystems),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn readout_targets(world: &mut World) -> Vec<RepairReadoutTarget> {
+        world
+            .query_filtered::<&RepairReadoutTarget, With<RepairReadoutMarker>>()
+            .iter(world)
+            .copied()
+            .collect()
+    }
+
+    /// One bar per running job on the player ship; the bar goes when the job
+    /// does, and a job on another ship never gets one.
+    #[test]
+    fn bars_follow_the_player_ships_jobs() {
+        let mut world = World::new();
+        world.spawn(repair_readout_hud());
+        let player = world
+            .spawn((SpaceshipRootMarker, PlayerSpaceshipMarker))
+            .id();
+        let other = world.spawn(SpaceshipRootMarker).id();
+        let health = Health {
+            current: 40.0,
+            max: 100.0,
+        };
+        let patched = world
+            .spawn((ChildOf(player), health, SectionRepairJob { from: 40.0 }))
+            .id();
+        world.spawn((ChildOf(other), health, SectionRepairJob { from: 40.0 }));
+        world.entity_mut(player).insert(SectionRebuildJob {
+            section: "wing".to_string(),
+            elapsed: 0.0,
+        });
+
+        world.run_system_once(sync_repair_readouts).unwrap();
+        let targets = readout_targets(&mut world);
+        assert_eq!(targets.len(), 2, "{targets:?}");
+        assert!(targets.contains(&RepairReadoutTarget::Repair(patched)));
+        assert!(targets.contains(&RepairReadoutTarget::Rebuild(player)));
+
+        // A hit cancels the repair: its bar goes, the rebuild's stays.
+        world.entity_mut(patched).remove::<SectionRepairJob>();
+        world.run_system_once(sync_repair_readouts).unwrap();
+        assert_eq!(
+            readout_targets(&mut world),
+            vec![RepairReadoutTarget::Rebuild(player)]
+        );
+    }
+
+    /// The fill's width is the job's progress.
+    #[test]
+    fn the_fill_tracks_progress() {
+        let mut world = World::new();
+        world.spawn(repair_readout_hud());
+        let player = world
+            .spawn((
+                SpaceshipRootMarker,
+                PlayerSpaceshipMarker,
+                SectionRebuildJob {
+                    section: "wing".to_string(),
+                    elapsed: REBUILD_SECONDS * 0.5,
+                },
+            ))
+            .id();
+        world.spawn((
+            ChildOf(player),
+            Health {
+                current: 70.0,
+                max: 100.0,
+            },
+            SectionRepairJob { from: 40.0 },
+        ));
+
+        world.run_system_once(sync_repair_readouts).unwrap();
+        world.run_system_once(drive_repair_readouts).unwrap();
+
+        // Both jobs are half done: the repair has climbed 30 of its 60 HP.
+        let widths: Vec<Val> = world
+            .query_filtered::<&Node, With<RepairReadoutFill>>()
+            .iter(&world)
+            .map(|node| node.width)
+            .collect();
+        assert_eq!(widths, vec![Val::Percent(50.0), Val::Percent(50.0)]);
+    }
+}
@@ -12,6 +12,10 @@
 //! No new gameplay state: every field is read from components the HUD already
 //! consumed ([`Autopilot`], [`CombatLock`], [`WeaponsHot`], [`SectionAmmo`],
 //! [`TurretSectionInput`]).
+//!
+//! The adaptive music reacts to the same situations, so [`feed_music_cues`]
+//! forwards them into nova_gameplay's [`MusicCues`] rather than letting the
+//! music re-sense the ship on its own.
 
 use bevy::prelude::*;
 use nova_gameplay::prelude::*;
@@ -117,6 +121,35 @@ pub fn sense_hud_situations(
     }
 }
 
+/// Forward the sensed situations into the music stems' cue flags. Only the
+/// situation fields are written - the scenario-variable set belongs to
+/// `nova_scenario` - and only on a real change, so an idle cruise does not
+/// dirty the cues every frame.
+pub fn feed_music_cues(situations: Res<HudSituations>, mut cues: ResMut<MusicCues>) {
+    let next = [
+        situations.maneuver.is_some(),
+        situations.combat_lock,
+        situations.weapons_hot,
+        situations.firing,
+        situations.low_ammo,
+    ];
+    let current = [
+        cues.maneuver,
+        cues.combat_lock,
+        cues.weapons_hot,
+        cues.firing,
+        cues.low_ammo,
+    ];
+    if next != current {
+        let [maneuver, combat_lock, weapons_hot, firing, low_ammo] = next;
+        cues.maneuver = maneuver;
+        cues.combat_lock = combat_lock;
+        cues.weapons_hot = weapons_hot;
+        cues.firing = firing;
+        cues.low_ammo = low_ammo;
+    }
+}
+
 /// The flight verb an engaged maneuver corresponds to, so the dock can light
 /// the chip whose key produced it.
 fn maneuver_verb(autopilot: &Autopilot) -> FlightVerb {
@@ -230,6 +263,25 @@ mod tests {
         assert!(situations(&app).low_ammo, "my own group ran dry");
     }
 
+    /// The music hears the same situations the HUD does, and the scenario's
+    /// variable set rides through untouched.
+    #[test]
+    fn situations_reach_the_music_cues() {
+        let mut app = sense_app();
+        app.init_resource::<MusicCues>();
+        app.add_systems(Update, feed_music_cues.after(sense_hud_situations));
+        app.world_mut()
+            .resource_mut::<MusicCues>()
+            .variables
+            .insert("alarm".into());
+        app.world_mut()
+            .spawn((PlayerSpaceshipMarker, CombatLock(Some(Entity::PLACEHOLDER))));
+        app.update();
+        let cues = app.world().resource::<MusicCues>();
+        assert!(cues.combat_lock && !cues.weapons_hot);
+        assert!(cues.variables.contains("alarm"));
+    }
+
     #[test]
     fn reload_reads_the_players_incomplete_magazine() {
         let mut app = sense_app();
@@ -34,6 +34,7 @@ use bevy::{
 };
 use nova_gameplay::prelude::*;
 use nova_ship::prelude::*;
+use nova_ui::theme::semantic::Palette;
 
 use super::screen_indicator::target_world_aabb;
 use crate::prelude::*;
@@ -92,10 +93,9 @@ const INSET_BORDER_SAFE_COLOR: Color = Color::srgba(0.65, 0.7, 0.75, 0.8);
 const INSET_TICK_LEN_PX: f32 = 16.0;
 const INSET_TICK_THICK_PX: f32 = 4.0;
 
-/// Faction-line colors: the relation palette the retired reticle tint used,
-/// now living on the inset's rich surface.
-const FACTION_HOSTILE_COLOR: Color = nova_ui::theme::semantic::THREAT;
-const FACTION_OWN_COLOR: Color = nova_ui::theme::semantic::ALLY;
+/// Faction-line spawn color. The live caption takes its relation color from
+/// the active [`Palette`] - the relation palette the retired reticle tint
+/// used, now living on the inset's rich surface.
 const FACTION_NEUTRAL_COLOR: Color = nova_ui::theme::semantic::NEUTRAL;
 
 /// Locked-target defeat confirmation. It lives inside the target inset instead
@@ -796,6 +796,7 @@ fn drive_inset_camera(
     reason = "disjoint frame/tick queries need explicit Without filters"
 )]
 fn drive_inset_frame_state(
+    palette: Option<Res<Palette>>,
     q_player: Query<
         (Option<&Allegiance>, &WeaponsHot, &CombatLock),
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
@@ -837,6 +838,7 @@ fn drive_inset_frame_state(
         visibility.set_if_neq(tick_visibility);
     }
 
+    let palette = palette.map(|palette| *palette).unwrap_or_default();
     let (caption, caption_color) = match lock.0 {
         Some(target) => {
             let name = q_names
@@ -847,10 +849,10 @@ fn drive_inset_frame_state(
                 .get(target)
                 .map(|allegiance| relation(player_allegiance, allegiance))
             {
-                Ok(Relation::Hostile) => ("HOSTILE", FACTION_HOSTILE_COLOR),
-                Ok(Relation::Own) => ("OWN", FACTION_OWN_COLOR),
+                Ok(Relation::Hostile) => ("HOSTILE", palette.threat()),
+                Ok(Relation::Own) => ("OWN", palette.ally()),
                 // A lock can outlive its entity by a frame; read as neutral.
-                Ok(Relation::Neutral) | Err(_) => ("NEUTRAL", FACTION_NEUTRAL_COLOR),
+                Ok(Relation::Neutral) | Err(_) => ("NEUTRAL", palette.neutral()),
             };
             let tag = if q_neutralized.contains(target) {
                 "NEUTRALIZED"
@@ -859,7 +861,7 @@ fn drive_inset_frame_state(
             };
             (format!("{name} - {tag}"), color)
         }
-        None => (String::new(), FACTION_NEUTRAL_COLOR),
+        None => (String::new(), palette.neutral()),
     };
     for (mut text, mut color) in &mut q_caption {
         if text.0 != caption {
@@ -1591,7 +1593,7 @@ mod tests {
         );
         assert_eq!(
             world.entity(caption).get::<TextColor>().unwrap().0,
-            FACTION_HOSTILE_COLOR,
+            Palette::Standard.threat(),
             "colored by relation"
         );
 
@@ -1605,7 +1607,7 @@ mod tests {
         );
         assert_eq!(
             world.entity(caption).get::<TextColor>().unwrap().0,
-            FACTION_HOSTILE_COLOR,
+            Palette::Standard.threat(),
             "neutralization is combat state, not allegiance"
         );
         assert_eq!(
@@ -15,7 +15,10 @@ use avian3d::prelude::*;
 use bevy::prelude::*;
 use nova_gameplay::prelude::*;
 use nova_ship::prelude::*;
-use nova_ui::hud::{self as chip, chip_paint, ChipTone};
+use nova_ui::{
+    hud::{self as chip, chip_paint, ChipTone},
+    theme::semantic::Palette,
+};
 
 use crate::prelude::*;
 
@@ -384,8 +387,10 @@ fn wind_down_alpha(idle_secs: f32) -> f32 {
 /// Drive the combat reticle's alpha from the player's [`CombatDecay`] clock,
 /// so an idling lock is SEEN winding down instead of vanishing.
 /// No lock, or a clock held at zero by combat activity,
-/// renders the reticle at full strength.
+/// renders the reticle at full strength. The hue is the active [`Palette`]'s
+/// threat color, so a palette switch lands on the next frame.
 fn wind_down_reticle_on_decay(
+    palette: Option<Res<Palette>>,
     q_player: Query<(&CombatLock, &CombatDecay), With<PlayerSpaceshipMarker>>,
     mut q_reticle: Query<&mut ImageNode, With<TorpedoTargetReticleMarker>>,
 ) {
@@ -395,8 +400,9 @@ fn wind_down_reticle_on_decay(
         .filter(|(lock, _)| lock.0.is_some())
         .map_or(0.0, |(_, decay)| decay.0);
     let alpha = wind_down_alpha(idle_secs);
+    let hue = palette.map_or(RETICLE_COMBAT_COLOR, |palette| palette.threat());
     for mut image in &mut q_reticle {
-        let wanted = RETICLE_COMBAT_COLOR.with_alpha(alpha);
+        let wanted = hue.with_alpha(alpha);
         if image.color != wanted {
             image.color = wanted;
         }
@@ -0,0 +1,189 @@
+//! The Settings panel's Accessibility section: the colour palette, reduced
+//! motion, the UI text size and the comms dwell multiplier.
+//!
+//! Every control writes [`AccessibilitySettings`], never the derived
+//! resources: nova_gameplay's `apply_accessibility` fans the choice out to the
+//! theme palette, the text scale and the juice, and the settings store
+//! persists the one struct. So the palette row cannot use the app-global
+//! `button_on_setting::<Palette>` (that would write the derived `Palette`
+//! directly and the choice would never reach the store); it has its own
+//! observer with the same `Selected` handling.
+
+use bevy::{
+    prelude::*,
+    ui_widgets::{Activate, SliderRange, SliderValue, ValueChange},
+};
+use nova_gameplay::prelude::*;
+use nova_ui::{
+    prelude::{TextScale, UiSkin},
+    theme::semantic::Palette,
+    widget::{panel_header, segmented_container, segmented_option, ButtonValue, Selected},
+};
+
+use crate::settings::spawn_slider_row;
+
+/// One of the section's two sliders.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum AccessibilityKnob {
+    TextScale,
+    CommsDwell,
+}
+
+impl AccessibilityKnob {
+    /// The readout beside the slider: a percent for the text size, a
+    /// multiplier for the dwell.
+    pub(crate) fn label(self, value: f32) -> String {
+        match self {
+            AccessibilityKnob::TextScale => format!("{}%", (value * 100.0).round() as i32),
+            AccessibilityKnob::CommsDwell => format!("{value:.2}x"),
+        }
+    }
+}
+
+/// An accessibility [`Slider`](bevy::ui_widgets::Slider) entity, so the
+/// change observer and the readout sync can find it.
+#[derive(Component)]
+pub(crate) struct AccessibilitySlider(pub(crate) AccessibilityKnob);
+
+/// The readout beside an accessibility slider.
+#[derive(Component)]
+pub(crate) struct AccessibilityLabel(pub(crate) AccessibilityKnob);
+
+/// The value a reduced-motion option represents (Off / On).
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct ReducedMotionOption(pub(crate) bool);
+
+/// Build the Accessibility section under `list`, seeded from `settings`.
+pub(crate) fn build_accessibility_body(
+    list: &mut ChildSpawnerCommands,
+    settings: AccessibilitySettings,
+    skin: UiSkin,
+) {
+    list.spawn(panel_header("Accessibility"));
+
+    // PALETTE - the hue set for allegiances, locks and objectives.
+    list.spawn((Name::new("Palette Row"), segmented_container(skin)))
+        .with_children(|row| {
+            for palette in Palette::ALL {
+                let mut button = row.spawn((
+                    Name::new(format!("Palette {}", palette.label())),
+                    segmented_option(palette.label()),
+                    ButtonValue(palette),
+                ));
+                if palette == settings.palette {
+                    button.insert(Selected);
+                }
+            }
+        });
+
+    // REDUCED MOTION - shake, flash and the CRT degauss to zero.
+    list.spawn((Name::new("Reduced Motion Row"), segmented_container(skin)))
+        .with_children(|row| {
+            for (label, on) in [("Full motion", false), ("Reduced motion", true)] {
+                let mut button = row.spawn((
+                    Name::new(label),
+                    segmented_option(label),
+                    ReducedMotionOption(on),
+                ));
+                if on == settings.reduced_motion {
+                    button.insert(Selected);
+                }
+            }
+        });
+
+    spawn_slider_row(
+        list,
+        "Text size",
+        settings.text_scale_factor(),
+        SliderRange::new(TextScale::MIN, TextScale::MAX),
+        0.05,
+        AccessibilityKnob::TextScale.label(settings.text_scale_factor()),
+        skin,
+        AccessibilitySlider(AccessibilityKnob::TextScale),
+        AccessibilityLabel(AccessibilityKnob::TextScale),
+    );
+    spawn_slider_row(
+        list,
+        "Comms dwell",
+        settings.comms_dwell_factor(),
+        SliderRange::new(
+            AccessibilitySettings::COMMS_DWELL_MIN,
+            AccessibilitySettings::COMMS_DWELL_MAX,
+        ),
+        0.25,
+        AccessibilityKnob::CommsDwell.label(settings.comms_dwell_factor()),
+        skin,
+        AccessibilitySlider(AccessibilityKnob::CommsDwell),
+        AccessibilityLabel(AccessibilityKnob::CommsDwell),
+    );
+}
+
+/// Write a pressed palette or motion option into [`AccessibilitySettings`]
+/// and move the row's `Selected` to it. `Activate` (release over the button),
+/// like `button_on_setting`.
+pub(crate) fn on_accessibility_option(
+    activate: On<Activate>,
+    mut commands: Commands,
+    q_palette: Query<(Entity, &ButtonValue<Palette>, Has<Selected>)>,
+    q_motion: Query<(Entity, &ReducedMotionOption, Has<Selected>)>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    if let Ok((_, pressed, _)) = q_palette.get(activate.entity) {
+        let palette = pressed.0;
+        for (entity, value, selected) in &q_palette {
+            move_selected(&mut commands, entity, value.0 == palette, selected);
+        }
+        if settings.palette != palette {
+            settings.palette = palette;
+        }
+    } else if let Ok((_, pressed, _)) = q_motion.get(activate.entity) {
+        let on = pressed.0;
+        for (entity, value, selected) in &q_motion {
+            move_selected(&mut commands, entity, value.0 == on, selected);
+        }
+        if settings.reduced_motion != on {
+            settings.reduced_motion = on;
+        }
+    }
+}
+
+fn move_selected(commands: &mut Commands, entity: Entity, wanted: bool, selected: bool) {
+    if wanted && !selected {
+        commands.entity(entity).insert(Selected);
+    } else if !wanted && selected {
+        commands.entity(entity).remove::<Selected>();
+    }
+}
+
+/// Mirror the accessibility sliders onto [`AccessibilitySettings`] as they
+/// are dragged - the accessibility twin of `on_volume_slider_change`.
+pub(crate) fn on_accessibility_slider_change(
+    change: On<ValueChange<f32>>,
+    sliders: Query<&AccessibilitySlider>,
+    mut settings: ResMut<AccessibilitySettings>,
+) {
+    let Ok(AccessibilitySlider(knob)) = sliders.get(change.source) else {
+        return;
+    };
+    match knob {
+        AccessibilityKnob::TextScale => settings.text_scale = change.value,
+        AccessibilityKnob::CommsDwell => settings.comms_dwell = change.value,
+    }
+}
+
+/// Keep each accessibility slider's readout in sync with its value.
+pub(crate) fn sync_accessibility_sliders(
+    sliders: Query<(&SliderValue, &AccessibilitySlider)>,
+    mut labels: Query<(&mut Text, &AccessibilityLabel)>,
+) {
+    for (value, AccessibilitySlider(knob)) in &sliders {
+        for (mut text, label) in &mut labels {
+            if label.0 == *knob {
+                let wanted = knob.label(value.0);
+                if text.0 != wanted {
+                    text.0 = wanted;
+                }
+            }
+        }
+    }
+}
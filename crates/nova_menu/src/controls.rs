@@ -0,0 +1,385 @@
+//! The Controls section of the Settings panel: one row per remappable action
+//! (the live [`Keymap`]) with a keyboard/mouse chip and a gamepad chip, then the
+//! fixed controls the keymap does not own as read-only rows.
+//!
+//! Clicking a chip arms a [`KeymapCapture`]; the next key or mouse button (or
+//! pad button, for the gamepad chip) becomes that half of the binding. Escape
+//! cancels, and the system controls in [`FIXED_SOURCES`] are refused so a remap
+//! can never shadow pause or the HUD toggle. A source two actions share is
+//! allowed but FLAGGED - every rig action runs with `consume_input: false`, so
+//! both would fire - and "Reset to Defaults" restores the shipped map. The
+//! keymap itself is persisted by the settings store like every other setting.
+
+use bevy::{prelude::*, ui_widgets::Activate};
+use nova_ship::prelude::*;
+use nova_ui::{
+    theme,
+    widget::{ButtonLabel, ButtonSpec, UiText},
+};
+
+use crate::{settings::spawn_keybind_row, widgets::MenuSfxButton};
+
+/// A binding chip: clicking it rebinds `device`'s half of `action`.
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct KeymapChip {
+    pub(crate) action: KeymapAction,
+    pub(crate) device: KeymapDevice,
+}
+
+/// The line under an action row naming what else shares its binding; empty
+/// (and collapsed) while the row is conflict-free.
+#[derive(Component, Clone, Copy, Debug)]
+pub(crate) struct KeymapConflictNote(pub(crate) KeymapAction);
+
+/// The status line above the rows: the capture prompt, or why a press was
+/// refused.
+#[derive(Component)]
+pub(crate) struct KeymapStatusNote;
+
+/// Marker for the "Reset to Defaults" button.
+#[derive(Component)]
+pub(crate) struct KeymapResetButton;
+
+/// The chip waiting for a press, if any.
+///
+/// Armed by a chip's [`Activate`]. Like the editor's section rebind, a capture
+/// armed by a click (or a pad confirm) first waits for every button to be
+/// released, so the arming press is not itself captured.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub(crate) struct KeymapCapture {
+    pub(crate) target: Option<(KeymapAction, KeymapDevice)>,
+    pub(crate) awaiting_release: bool,
+    /// Why the last press was refused, shown until the next capture.
+    pub(crate) note: Option<String>,
+}
+
+impl KeymapCapture {
+    /// Whether a chip is waiting for a press - the pause toggle stands down
+    /// while it is, so the Escape that cancels does not also close the menu.
+    pub(crate) fn is_armed(&self) -> bool {
+        self.target.is_some()
+    }
+}
+
+/// The section header style the Controls rows share with the fixed reference.
+fn section_label(section: &str) -> impl Bundle {
+    (
+        Name::new(format!("Controls Section: {section}")),
+        UiText,
+        Text::new(section.to_string()),
+        TextFont {
+            font_size: FontSize::Px(11.0),
+            ..default()
+        },
+        TextColor(theme::PHOSPHOR_MUTED),
+        Node {
+            margin: UiRect::top(px(6)),
+            ..default()
+        },
+    )
+}
+
+/// A compact binding chip: the shared themed button at a row's sizing.
+fn chip(keymap: &Keymap, action: KeymapAction, device: KeymapDevice) -> impl Bundle {
+    let binding = keymap.get(action);
+    let label = match device {
+        KeymapDevice::Keyboard => binding.keyboard_label(),
+        KeymapDevice::Gamepad => binding.gamepad_label(),
+    };
+    let mut spec = ButtonSpec::new(label);
+    spec.min_height = 24.0;
+    spec.font_size = 12.0;
+    (
+        Name::new(format!("Keymap Chip: {} {device:?}", action.label())),
+        nova_ui::widget::button(spec),
+        KeymapChip { action, device },
+        MenuSfxButton,
+    )
+}
+
+/// The fixed-width cell a chip fills, so both columns line up row to row.
+fn chip_cell() -> Node {
+    Node {
+        width: px(130),
+        flex_shrink: 0.0,
+        ..default()
+    }
+}
+
+/// Build the Controls section under `list`: the capture status line, one row
+/// per keymap action grouped by section, "Reset to Defaults", then the fixed
+/// reference rows under one FIXED header.
+pub(crate) fn build_controls_body(list: &mut ChildSpawnerCommands, keymap: &Keymap) {
+    list.spawn((
+        Name::new("Keymap Status"),
+        KeymapStatusNote,
+        UiText,
+        Text::new(""),
+        TextFont {
+            font_size: FontSize::Px(12.0),
+            ..default()
+        },
+        TextColor(theme::AMBER_NOVA),
+    ));
+
+    let mut current_section = "";
+    for action in KeymapAction::ALL {
+        if action.section() != current_section {
+            current_section = action.section();
+            list.spawn(section_label(current_section));
+        }
+        list.spawn((
+            Name::new(format!("Keymap: {}", action.label())),
+            Node {
+                width: percent(100),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::axes(px(2), px(2)),
+                ..default()
+            },
+        ))
+        .with_children(|row| {
+            row.spawn(Node {
+                width: percent(100),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                column_gap: px(8),
+                ..default()
+            })
+            .with_children(|line| {
+                line.spawn((
+                    UiText,
+                    Text::new(action.label()),
+                    TextFont {
+                        font_size: FontSize::Px(13.0),
+                        ..default()
+                    },
+                    TextColor(theme::SCREEN_TEXT),
+                    Node {
+                        flex_grow: 1.0,
+                        ..default()
+                    },
+                ));
+                line.spawn(chip_cell())
+                    .with_child(chip(keymap, action, KeymapDevice::Keyboard));
+                line.spawn(chip_cell())
+                    .with_child(chip(keymap, action, KeymapDevice::Gamepad));
+            });
+            row.spawn((
+                KeymapConflictNote(action),
+                UiText,
+                Text::new(""),
+                TextFont {
+                    font_size: FontSize::Px(11.0),
+                    ..default()
+                },
+                TextColor(theme::RED),
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+            ));
+        });
+    }
+
+    let mut reset = ButtonSpec::new("Reset to Defaults").ghost();
+    reset.min_height = 28.0;
+    reset.font_size = 13.0;
+    list.spawn((
+        Name::new("Keymap Reset Button"),
+        nova_ui::widget::button(reset),
+        KeymapResetButton,
+        MenuSfxButton,
+    ));
+
+    // One header over the fixed rows: their own sections (FLIGHT, TARGETING)
+    // would repeat the keymap's headers above and read as a second list of
+    // the same controls.
+    list.spawn(section_label("FIXED"));
+    for entry in keybind_reference() {
+        spawn_keybind_row(list, entry);
+    }
+}
+
+/// Arm a capture for the activated chip. A second click on the armed chip
+/// disarms it.
+pub(crate) fn on_keymap_chip(
+    activate: On<Activate>,
+    q_chip: Query<&KeymapChip>,
+    mut capture: ResMut<KeymapCapture>,
+) {
+    let Ok(chip) = q_chip.get(activate.entity) else {
+        return;
+    };
+    let target = (chip.action, chip.device);
+    capture.note = None;
+    if capture.target == Some(target) {
+        capture.target = None;
+        capture.awaiting_release = false;
+        return;
+    }
+    capture.target = Some(target);
+    capture.awaiting_release = true;
+}
+
+/// Drop an armed capture when its panel goes away - leaving the menu or the
+/// pause overlay by any path - so a hidden chip cannot keep holding Escape.
+pub(crate) fn disarm_keymap_capture(mut capture: ResMut<KeymapCapture>) {
+    capture.set_if_neq(KeymapCapture::default());
+}
+
+/// Put every binding back on the shipped defaults.
+pub(crate) fn on_keymap_reset(
+    activate: On<Activate>,
+    q_reset: Query<(), With<KeymapResetButton>>,
+    mut keymap: ResMut<Keymap>,
+    mut capture: ResMut<KeymapCapture>,
+) {
+    if !q_reset.contains(activate.entity) {
+        return;
+    }
+    *capture = KeymapCapture::default();
+    if !keymap.is_default() {
+        *keymap = Keymap::default();
+    }
+}
+
+/// Feed the next press into the armed chip. The keyboard chip takes a key or
+/// mouse button, the gamepad chip a pad button; Escape cancels either. A fixed
+/// system control is refused and the chip stays armed, like the editor's
+/// section rebind. Gamepad and mouse input are optional so headless rigs run
+/// this without the input plugins.
+pub(crate) fn capture_keymap_binding(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    gamepad: Option<Res<ButtonInput<GamepadButton>>>,
+    mut capture: ResMut<KeymapCapture>,
+    mut keymap: ResMut<Keymap>,
+) {
+    let Some((action, device)) = capture.target else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::Escape) {
+        capture.target = None;
+        capture.awaiting_release = false;
+        return;
+    }
+    if capture.awaiting_release {
+        let held = keys.get_pressed().next().is_some()
+            || mouse
+                .as_ref()
+                .is_some_and(|m| m.get_pressed().next().is_some())
+            || gamepad
+                .as_ref()
+                .is_some_and(|g| g.get_pressed().next().is_some());
+        if !held {
+            capture.awaiting_release = false;
+        }
+        return;
+    }
+
+    let source = match device {
+        KeymapDevice::Keyboard => keys
+            .get_just_pressed()
+            .next()
+            .map(|key| InputSource::Keyboard(*key))
+            .or_else(|| {
+                mouse
+                    .as_ref()
+                    .and_then(|m| m.get_just_pressed().next().copied())
+                    .map(InputSource::Mouse)
+            }),
+        KeymapDevice::Gamepad => gamepad
+            .as_ref()
+            .and_then(|g| g.get_just_pressed().next().copied())
+            .map(InputSource::Gamepad),
+    };
+    let Some(source) = source else {
+        return;
+    };
+    if let Some((_, what)) = FIXED_SOURCES.iter().find(|(fixed, _)| *fixed == source) {
+        capture.note = Some(format!(
+            "{} is reserved for {what} - press another",
+            source_display_label(&source)
+        ));
+        return;
+    }
+    keymap.rebind(action, device, source);
+    capture.target = None;
+    capture.note = None;
+}
+
+/// Keep the chips, the conflict lines and the status line in step with the
+/// keymap and the capture. Change-gated, so a quiet frame touches nothing.
+#[allow(clippy::type_complexity)]
+pub(crate) fn sync_keymap_chips(
+    keymap: Res<Keymap>,
+    capture: Res<KeymapCapture>,
+    q_chips: Query<(&KeymapChip, &Children)>,
+    mut q_labels: Query<&mut Text, With<ButtonLabel>>,
+    mut q_notes: Query<
+        (&KeymapConflictNote, &mut Text, &mut Node),
+        (Without<ButtonLabel>, Without<KeymapStatusNote>),
+    >,
+    mut q_status: Query<&mut Text, (With<KeymapStatusNote>, Without<ButtonLabel>)>,
+    q_added: Query<(), Added<KeymapChip>>,
+) {
+    if !keymap.is_changed() && !capture.is_changed() && q_added.is_empty() {
+        return;
+    }
+    for (chip, children) in &q_chips {
+        let binding = keymap.get(chip.action);
+        let label = if capture.target == Some((chip.action, chip.device)) {
+            "press a key...".to_string()
+        } else {
+            match chip.device {
+                KeymapDevice::Keyboard => binding.keyboard_label(),
+                KeymapDevice::Gamepad => binding.gamepad_label(),
+            }
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = q_labels.get_mut(child) {
+                if text.0 != label {
+                    text.0 = label.clone();
+                }
+            }
+        }
+    }
+    for (note, mut text, mut node) in &mut q_notes {
+        let others = keymap.conflicts_of(note.0);
+        let line = if others.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<&str> = others.iter().map(|other| other.label()).collect();
+            format!("shared with {}", names.join(", "))
+        };
+        let display = if line.is_empty() {
+            Display::None
+        } else {
+            Display::Flex
+        };
+        if text.0 != line {
+            text.0 = line;
+        }
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    let status = match (&capture.note, capture.target) {
+        (Some(note), _) => note.clone(),
+        (None, Some((action, device))) => format!(
+            "Rebinding {} ({}) - Esc cancels",
+            action.label(),
+            match device {
+                KeymapDevice::Keyboard => "keyboard / mouse",
+                KeymapDevice::Gamepad => "gamepad",
+            }
+        ),
+        (None, None) => String::new(),
+    };
+    for mut text in &mut q_status {
+        if text.0 != status {
+            text.0 = status.clone();
+        }
+    }
+}
@@ -17,10 +17,12 @@
 #![warn(missing_docs)]
 
 use bevy::{prelude::*, ui_widgets::slider_self_update};
+use nova_assets::prelude::ModProfiles;
 use nova_gameplay::prelude::*;
 use nova_hud::prelude::HudVisibility;
 use nova_os_ui::prelude::NovaOsMonitorSettings;
-use nova_scenario::prelude::{CurrentOutcome, ScenarioStartFailure};
+use nova_scenario::prelude::{CurrentOutcome, DebriefSystems, ScenarioStartFailure};
+use nova_ship::prelude::CameraAuthoritySystems;
 use nova_ui::{prelude::UiSkin, widget::button_on_setting};
 
 /// Glob-import surface: `use nova_menu::prelude::*` brings [`NovaMenuPlugin`]
@@ -29,28 +31,41 @@ pub mod prelude {
     pub use super::{ambience::MENU_BACKDROP_ENV, NewGameScenario, NovaMenuPlugin};
 }
 
+mod accessibility;
 mod ambience;
+mod controls;
 mod menu_ui;
 mod mods;
 mod outcome;
 mod pause;
+mod photo;
 mod portal;
+mod race_bests;
+pub mod replay;
 mod scenarios;
 mod settings;
 mod settings_store;
+mod survival_scores;
 mod widgets;
 
 #[cfg(test)]
 mod tests;
 
+use accessibility::{
+    on_accessibility_option, on_accessibility_slider_change, sync_accessibility_sliders,
+};
 use ambience::{
     hide_hud_chrome, load_menu_ambience, restore_hud_chrome, stage_menu_camera,
     unload_menu_ambience,
 };
+use controls::{
+    capture_keymap_binding, disarm_keymap_capture, on_keymap_chip, on_keymap_reset,
+    sync_keymap_chips, KeymapCapture,
+};
 use menu_ui::{setup_menu_ui, start_new_game_scenario};
 use mods::{
     mod_details_dirty, mods_list_dirty, refresh_mod_details, refresh_mods_list,
-    sync_mod_checkboxes, ModsActiveTab, SelectedModId,
+    sync_mod_checkboxes, sync_mod_profile_label, ModsActiveTab, SelectedModId,
 };
 use outcome::{
     auto_advance_outcome, clear_start_failure, regrab_cursor_on_player_spawn, sync_outcome_cursor,
@@ -60,17 +75,24 @@ use pause::{
     force_unpause, keep_frozen_cursor_released, pause_clocks, release_cursor, restore_cursor,
     setup_pause_ui, toggle_pause, unpause_clocks,
 };
+use photo::{
+    apply_photo_roll, apply_photo_settings, begin_photo_session, drive_photo_capture,
+    end_photo_session, on_photo_option, on_photo_slider_change, photo_input, setup_photo_ui,
+    sync_photo_overlay, sync_photo_sliders, PhotoSettings,
+};
 use portal::{drive_update_choreography, UpdateRequested};
+use race_bests::{load_persisted_race_bests, persist_race_bests_on_change};
 pub use scenarios::NewGameScenario;
 use scenarios::{
-    poll_scenario_thumbnail, refresh_scenario_details, refresh_scenarios_list,
-    scenario_details_dirty, scenarios_list_dirty, CollapsedCampaigns, PendingScenarioThumbnail,
-    SelectedScenarioId,
+    draw_survival_seed, poll_scenario_thumbnail, refresh_scenario_details, refresh_scenarios_list,
+    refresh_survival_scores, scenario_details_dirty, scenarios_list_dirty, CollapsedCampaigns,
+    PendingScenarioThumbnail, SelectedScenarioId,
 };
 use settings::{
     flush_settings_on_exit, load_persisted_settings, on_volume_slider_change,
     persist_settings_on_change, sync_volume_slider, PendingSettingsSave,
 };
+use survival_scores::{load_persisted_survival_scores, persist_survival_scores_on_change};
 use widgets::on_menu_button_activate;
 
 /// The main-menu plugin: owns [`GameStates::MainMenu`] and the settings/mods/
@@ -108,20 +130,63 @@ impl Plugin for NovaMenuPlugin {
         }
 
         app.init_resource::<MasterVolume>();
+        app.init_resource::<BusVolumes>();
         app.init_resource::<GraphicsQuality>();
+        // NOTE: owned by NovaSettingsPlugin; repeated so the Scenarios picker
+        // and the settings load have it in the slim menu rigs.
+        app.init_resource::<Difficulty>();
         // NOTE: `NovaUiPlugin` above inits `UiSkin` transitively; repeat it here
         // so the invariant survives a future reorder.
         app.init_resource::<UiSkin>();
         app.init_resource::<NovaOsMonitorSettings>();
+        // NOTE: owned by NovaSettingsPlugin; repeated so the Accessibility
+        // section and the settings load have it in the slim menu rigs.
+        app.init_resource::<AccessibilitySettings>();
+        // NOTE: owned by SpaceshipInputPlugin; repeated so the Controls page and
+        // the settings load have a keymap in the slim menu rigs.
+        app.init_resource::<nova_ship::input::keymap::Keymap>();
+        app.init_resource::<KeymapCapture>();
+        app.add_observer(on_keymap_chip);
+        app.add_observer(on_keymap_reset);
+        // NOTE: the pause toggle reads the capture BEFORE it disarms, so the
+        // Escape that cancels a rebind does not also close the overlay.
+        app.add_systems(
+            Update,
+            (
+                capture_keymap_binding.after(toggle_pause),
+                sync_keymap_chips,
+            )
+                .chain(),
+        );
+        app.add_systems(OnExit(GameStates::MainMenu), disarm_keymap_capture);
+        app.add_systems(OnExit(PauseStates::Paused), disarm_keymap_capture);
+        // NOTE: owned by GameAssetsPlugin; repeated for the slim menu rigs.
+        app.init_resource::<ModProfiles>();
         app.add_observer(slider_self_update);
         app.add_observer(on_volume_slider_change);
         app.add_observer(button_on_setting::<GraphicsQuality>);
+        app.add_observer(button_on_setting::<Difficulty>);
         app.add_observer(button_on_setting::<UiSkin>);
         app.add_systems(Update, sync_volume_slider);
+        app.add_observer(on_accessibility_option);
+        app.add_observer(on_accessibility_slider_change);
+        app.add_systems(Update, sync_accessibility_sliders);
         app.add_systems(Startup, load_persisted_settings);
         app.init_resource::<PendingSettingsSave>();
         app.add_systems(Update, persist_settings_on_change);
         app.add_systems(Last, flush_settings_on_exit);
+        // NOTE: owned by nova_scenario's CoursePlugin; repeated so the load has
+        // somewhere to land in the slim menu rigs.
+        app.init_resource::<nova_scenario::prelude::RaceBests>();
+        app.add_systems(Startup, load_persisted_race_bests);
+        app.add_systems(Update, persist_race_bests_on_change);
+        // NOTE: owned by nova_scenario's SurvivalPlugin, repeated for the same
+        // reason; the picker draws and rerolls the seed.
+        app.init_resource::<nova_scenario::prelude::SurvivalScores>();
+        app.init_resource::<nova_scenario::prelude::SurvivalSeed>();
+        app.add_systems(Startup, load_persisted_survival_scores);
+        app.add_systems(Update, persist_survival_scores_on_change);
+        app.add_plugins(replay::ReplayPlugin);
 
         app.add_systems(
             OnEnter(GameStates::MainMenu),
@@ -141,7 +206,12 @@ impl Plugin for NovaMenuPlugin {
         );
         app.add_systems(
             Update,
-            (stage_menu_camera, sync_mod_checkboxes).run_if(in_state(GameStates::MainMenu)),
+            (
+                stage_menu_camera,
+                sync_mod_checkboxes,
+                sync_mod_profile_label.run_if(resource_changed::<ModProfiles>),
+            )
+                .run_if(in_state(GameStates::MainMenu)),
         );
         // NOTE: chained so a default selection made while rebuilding the list is
         // rendered by the details refresh in the SAME frame.
@@ -159,8 +229,10 @@ impl Plugin for NovaMenuPlugin {
             Update,
             (
                 poll_scenario_thumbnail,
+                draw_survival_seed,
                 refresh_scenarios_list.run_if(scenarios_list_dirty),
                 refresh_scenario_details.run_if(scenario_details_dirty),
+                refresh_survival_scores.run_if(resource_changed::<Difficulty>),
             )
                 .chain()
                 .run_if(in_state(GameStates::MainMenu)),
@@ -192,6 +264,48 @@ impl Plugin for NovaMenuPlugin {
             OnExit(PauseStates::NovaOs),
             (unpause_clocks, restore_cursor),
         );
+        // NOTE: the docked refit bay is a fourth variant on the same axis; the
+        // editor draws it and borrows the camera, this only freezes.
+        app.add_systems(OnEnter(PauseStates::Refit), (pause_clocks, release_cursor));
+        app.add_systems(OnExit(PauseStates::Refit), (unpause_clocks, restore_cursor));
+        // NOTE: photo mode is a page of the pause menu - entered from and exited
+        // to `Paused` - so its hooks re-assert the freeze and the free cursor
+        // that `Paused`'s exit hooks just lifted, in the same transition.
+        app.init_resource::<PhotoSettings>();
+        app.add_systems(
+            OnEnter(PauseStates::Photo),
+            (
+                pause_clocks,
+                release_cursor,
+                begin_photo_session,
+                setup_photo_ui,
+            ),
+        );
+        app.add_systems(
+            OnExit(PauseStates::Photo),
+            (unpause_clocks, restore_cursor, end_photo_session),
+        );
+        app.add_observer(on_photo_slider_change);
+        app.add_observer(on_photo_option);
+        app.add_systems(
+            Update,
+            (
+                photo_input,
+                sync_photo_sliders,
+                apply_photo_settings,
+                drive_photo_capture,
+                sync_photo_overlay,
+            )
+                .chain()
+                .run_if(in_state(PauseStates::Photo)),
+        );
+        // NOTE: additive, after the WASD rig has solved the pose this frame.
+        app.add_systems(
+            PostUpdate,
+            apply_photo_roll
+                .in_set(CameraAuthoritySystems::Additive)
+                .run_if(in_state(PauseStates::Photo)),
+        );
         app.add_systems(OnExit(GameStates::Playing), force_unpause);
         app.add_systems(
             PostUpdate,
@@ -203,7 +317,7 @@ impl Plugin for NovaMenuPlugin {
         app.add_systems(
             Update,
             (
-                sync_outcome_overlay,
+                sync_outcome_overlay.after(DebriefSystems),
                 sync_outcome_cursor,
                 sync_outcome_pause,
                 auto_advance_outcome,
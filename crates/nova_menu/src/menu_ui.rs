@@ -5,8 +5,10 @@ use bevy::{
     prelude::*,
     ui_widgets::{observe, Activate},
 };
+use nova_assets::prelude::ModProfiles;
 use nova_gameplay::prelude::*;
 use nova_scenario::prelude::*;
+use nova_ship::input::keymap::Keymap;
 use nova_ui::{
     prelude::UiSkin,
     screen::{
@@ -19,8 +21,9 @@ use nova_ui::{
 
 use crate::{
     mods::{
-        on_mods, on_mods_back, on_mods_tab, ModDetailsPanel, ModsActiveTab, ModsList, ModsPanel,
-        ModsTab, ModsTabKind, SelectedModId,
+        mod_profile_label, on_mod_profile_cycle, on_mod_profile_new, on_mods, on_mods_back,
+        on_mods_tab, ModDetailsPanel, ModProfileLabel, ModsActiveTab, ModsList, ModsPanel, ModsTab,
+        ModsTabKind, SelectedModId,
     },
     scenarios::{
         listed_scenarios, on_scenarios, on_scenarios_back, NewGameScenario, ScenarioDetailsPanel,
@@ -39,8 +42,12 @@ pub(crate) fn setup_menu_ui(
     mut selected: ResMut<SelectedModId>,
     mut selected_scenario: ResMut<SelectedScenarioId>,
     volume: Res<MasterVolume>,
+    buses: Res<BusVolumes>,
     quality: Res<GraphicsQuality>,
     skin: Res<UiSkin>,
+    keymap: Res<Keymap>,
+    accessibility: Res<AccessibilitySettings>,
+    profiles: Res<ModProfiles>,
 ) {
     commands
         .spawn((
@@ -181,7 +188,15 @@ pub(crate) fn setup_menu_ui(
                             ..default()
                         },
                     ));
-                    build_settings_body(parent, *volume, *quality, *skin);
+                    build_settings_body(
+                        parent,
+                        *volume,
+                        *buses,
+                        *quality,
+                        *skin,
+                        &keymap,
+                        *accessibility,
+                    );
                     parent.spawn((
                         Name::new("Settings Back Button"),
                         button("Back"),
@@ -237,6 +252,59 @@ pub(crate) fn setup_menu_ui(
                         },
                         TextColor(theme::PHOSPHOR_MUTED),
                     ));
+                    // The profile switcher: which named enable-set is live, a
+                    // cycle through the saved ones, and save-as-new.
+                    parent.spawn((
+                        Name::new("Mods Profile Row"),
+                        Node {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: px(8),
+                            margin: UiRect::vertical(px(8)),
+                            ..default()
+                        },
+                        children![
+                            (
+                                Name::new("Mods Profile Label"),
+                                ModProfileLabel,
+                                UiText,
+                                Text::new(mod_profile_label(&profiles)),
+                                TextFont {
+                                    font_size: FontSize::Px(14.0),
+                                    ..default()
+                                },
+                                TextColor(theme::SCREEN_TEXT),
+                                Node {
+                                    min_width: px(220),
+                                    ..default()
+                                },
+                            ),
+                            (
+                                Name::new("Mods Profile Switch Slot"),
+                                Node {
+                                    width: px(160),
+                                    ..default()
+                                },
+                                children![(
+                                    Name::new("Mods Profile Switch Button"),
+                                    themed_button("Switch profile"),
+                                    observe(on_mod_profile_cycle),
+                                )],
+                            ),
+                            (
+                                Name::new("Mods Profile New Slot"),
+                                Node {
+                                    width: px(160),
+                                    ..default()
+                                },
+                                children![(
+                                    Name::new("Mods Profile New Button"),
+                                    themed_button("New profile"),
+                                    observe(on_mod_profile_new),
+                                )],
+                            ),
+                        ],
+                    ));
 
                     parent.spawn((
                         Name::new("Mods Content"),
@@ -1,14 +1,19 @@
 //! The Mods screen's Installed tab: the panel, its two-pane list/details
-//! surface, and the enable/disable toggles.
+//! surface, the enable/disable toggles, and the mod-profile switcher with its
+//! per-profile load order.
 
 use bevy::{
     picking::hover::Hovered,
     prelude::*,
     ui_widgets::{observe, Activate, Button},
 };
-use nova_assets::prelude::{
-    DownloadedMods, EnabledMods, FetchPortalCatalog, InstallJobs, ModCatalog, ModInfo, ModMeta,
-    PortalEntry, RemoteCatalog, RemoteCatalogState,
+use nova_assets::{
+    activate_mod_profile,
+    mod_profiles::apply_profile_order,
+    prelude::{
+        DownloadedMods, EnabledMods, FetchPortalCatalog, InstallJobs, ModCatalog, ModInfo, ModMeta,
+        ModProfiles, PortalEntry, RemoteCatalog, RemoteCatalogState,
+    },
 };
 use nova_mod_format::BASE_MOD_ID;
 use nova_ui::{
@@ -96,6 +101,29 @@ pub(crate) struct ModToggle {
     pub(crate) base: bool,
 }
 
+/// The profile line above the mod list; `sync_mod_profile_label` repaints its
+/// text when [`ModProfiles`] changes.
+#[derive(Component)]
+pub(crate) struct ModProfileLabel;
+
+/// A details-pane load-order button: moves `id` one slot earlier (`-1`) or
+/// later (`+1`) in the active profile's order. Only spawned while a profile is
+/// active - the global set has no order of its own.
+#[derive(Component)]
+pub(crate) struct ModLoadOrder {
+    pub(crate) id: String,
+    pub(crate) step: isize,
+}
+
+/// The profile line's text: the active profile's name, or `global` when the
+/// game runs on the profile-less enabled set.
+pub(crate) fn mod_profile_label(profiles: &ModProfiles) -> String {
+    format!(
+        "Profile: {}",
+        profiles.active.as_deref().unwrap_or("global")
+    )
+}
+
 /// The muted "v0.2.0 - by Author" line under a mod's name (row and details
 /// pane); empty meta fields drop out, both empty yields an empty string (the
 /// caller skips spawning it).
@@ -295,8 +323,9 @@ pub(crate) fn mods_list_dirty(
 /// `refresh_mod_details` runs when the tab, the selection, the catalogs
 /// (installed meta upgrade / remote transition), the enabled set
 /// (Enable/Disable label), the job table (progress/Failed/Dismiss), the
-/// downloaded set (Install vs Uninstall/Update actions) or the update
-/// requests ("Updating..." rendering) changed.
+/// downloaded set (Install vs Uninstall/Update actions), the update
+/// requests ("Updating..." rendering) or the profiles (load-order buttons)
+/// changed.
 pub(crate) fn mod_details_dirty(
     active: Res<ModsActiveTab>,
     selected: Res<SelectedModId>,
@@ -306,11 +335,13 @@ pub(crate) fn mod_details_dirty(
     jobs: Option<Res<InstallJobs>>,
     downloaded: Option<Res<DownloadedMods>>,
     updates: Res<UpdateRequested>,
+    profiles: Option<Res<ModProfiles>>,
 ) -> bool {
     active.is_changed()
         || selected.is_changed()
         || catalog.is_some_and(|c| c.is_changed())
         || enabled.is_some_and(|e| e.is_changed())
+        || profiles.is_some_and(|p| p.is_changed())
         || remote.is_some_and(|r| r.is_changed())
         || jobs.is_some_and(|j| j.is_changed())
         || downloaded.is_some_and(|d| d.is_changed())
@@ -585,7 +616,8 @@ pub(crate) fn spawn_details_meta(
 /// line, description, dependencies, then the action area
 /// ([`ModDetailsActions`]). Installed tab: the Enable/Disable button (base: a
 /// locked tag), plus Uninstall for DOWNLOADED mods (managing installs must
-/// not require the Explore tab). Explore tab: the selection keys into the
+/// not require the Explore tab), plus Load earlier/later while a profile is
+/// active and the mod is enabled. Explore tab: the selection keys into the
 /// visible remote entries and the action area follows the install state
 /// ([`spawn_portal_actions`]). The action container is spawned even with
 /// nothing selected, so the marker contract holds in every state.
@@ -599,6 +631,7 @@ pub(crate) fn refresh_mod_details(
     jobs: Option<Res<InstallJobs>>,
     downloaded: Option<Res<DownloadedMods>>,
     updates: Res<UpdateRequested>,
+    profiles: Option<Res<ModProfiles>>,
     panels: Query<Entity, With<ModDetailsPanel>>,
 ) {
     let Ok(panel) = panels.single() else {
@@ -622,6 +655,9 @@ pub(crate) fn refresh_mod_details(
             let is_enabled = info
                 .as_ref()
                 .is_some_and(|m| enabled.as_ref().is_some_and(|e| e.0.contains(&m.id)));
+            let profile_active = profiles
+                .as_ref()
+                .is_some_and(|p| p.active_profile().is_some());
             commands.entity(panel).with_children(|details| {
                 let Some(m) = info else {
                     spawn_details_empty(details);
@@ -695,6 +731,29 @@ pub(crate) fn refresh_mod_details(
                                     PortalActionKind::Uninstall,
                                 );
                             }
+                            if profile_active && is_enabled {
+                                for (label, step) in [("Load earlier", -1), ("Load later", 1)] {
+                                    actions
+                                        .spawn((
+                                            Name::new(format!("Mod Details {label} Slot")),
+                                            Node {
+                                                width: px(140),
+                                                ..default()
+                                            },
+                                        ))
+                                        .with_children(|slot| {
+                                            slot.spawn((
+                                                Name::new(format!("Mod Details {label} Button")),
+                                                themed_button(label),
+                                                ModLoadOrder {
+                                                    id: m.id.clone(),
+                                                    step,
+                                                },
+                                                observe(on_mod_load_order),
+                                            ));
+                                        });
+                                }
+                            }
                         }
                     });
             });
@@ -882,3 +941,80 @@ pub(crate) fn sync_mod_checkboxes(
         }
     }
 }
+
+/// Cycle the active profile: the global set, then each saved profile, then back
+/// ([`ModProfiles::next_active`]). The switch reloads [`EnabledMods`] from the
+/// new profile, which re-runs the merge live like any toggle.
+pub(crate) fn on_mod_profile_cycle(
+    _activate: On<Activate>,
+    mut profiles: ResMut<ModProfiles>,
+    mut enabled: ResMut<EnabledMods>,
+) {
+    let next = profiles.next_active();
+    activate_mod_profile(next, &mut profiles, &mut enabled);
+}
+
+/// Save the current enabled set as a NEW profile and switch to it, so "tweak
+/// the set, keep it under a name" is one click. Renaming is a file edit
+/// (`mod_profiles.ron`); the menu has no text entry for it.
+pub(crate) fn on_mod_profile_new(
+    _activate: On<Activate>,
+    mut profiles: ResMut<ModProfiles>,
+    mut enabled: ResMut<EnabledMods>,
+) {
+    let name = profiles.create_from(&enabled.0);
+    activate_mod_profile(Some(name), &mut profiles, &mut enabled);
+}
+
+/// Move a mod one slot in the active profile's load order.
+///
+/// The order edited is the EFFECTIVE one - the enabled non-base rows in
+/// catalog order, permuted by the profile's saved order - and the whole list is
+/// written back, so after one click the profile pins every enabled mod's slot.
+/// A step past either end is a no-op. Dependencies still win at the merge: a
+/// move that would put a mod before its dependency reorders nothing there.
+pub(crate) fn on_mod_load_order(
+    activate: On<Activate>,
+    buttons: Query<&ModLoadOrder>,
+    catalog: Option<Res<ModCatalog>>,
+    enabled: Res<EnabledMods>,
+    mut profiles: ResMut<ModProfiles>,
+) {
+    let Ok(button) = buttons.get(activate.entity) else {
+        return;
+    };
+    let ids: Vec<String> = catalog
+        .iter()
+        .flat_map(|c| c.0.iter())
+        .filter(|m| !m.base && enabled.0.contains(&m.id))
+        .map(|m| m.id.clone())
+        .collect();
+    let mut order = apply_profile_order(&ids, profiles.active_order());
+    let Some(at) = order.iter().position(|id| *id == button.id) else {
+        return;
+    };
+    let Some(to) = at
+        .checked_add_signed(button.step)
+        .filter(|to| *to < order.len())
+    else {
+        return;
+    };
+    order.swap(at, to);
+    if let Some(profile) = profiles.active_profile_mut() {
+        profile.order = order;
+    }
+}
+
+/// Repaint the profile line when [`ModProfiles`] changes (a switch, a new
+/// profile, or the startup load).
+pub(crate) fn sync_mod_profile_label(
+    profiles: Res<ModProfiles>,
+    mut labels: Query<&mut Text, With<ModProfileLabel>>,
+) {
+    let label = mod_profile_label(&profiles);
+    for mut text in &mut labels {
+        if text.0 != label {
+            text.0 = label.clone();
+        }
+    }
+}
@@ -7,10 +7,15 @@ use bevy::{
     window::{CursorGrabMode, CursorOptions, PrimaryWindow},
 };
 use nova_gameplay::prelude::*;
+use nova_hud::prelude::{format_race_delta, HudReadoutFormat};
 use nova_scenario::prelude::*;
 use nova_ui::{prelude::UiSkin, theme, widget::panel};
 
-use crate::{pause::on_back_to_menu, widgets::button};
+use crate::{
+    pause::on_back_to_menu,
+    replay::{on_watch_replay, prelude::LastReplay},
+    widgets::button,
+};
 
 /// Marker for the outcome overlay root (see `sync_outcome_overlay`). Carries
 /// the queued-switch snapshot the overlay was built against, so the sync can
@@ -27,13 +32,24 @@ pub(crate) struct OutcomeOverlay {
 /// stale - an outcome flips at most once per scenario, so there is nothing
 /// worth diffing. The overlay dies with the outcome (scenario teardown
 /// clears the resource) and with the Playing state (`DespawnOnExit`),
-/// whichever comes first.
+/// whichever comes first. A scenario whose race course was finished adds the
+/// run's time and its delta to the personal best under the message, the
+/// debrief's statistics follow once it has stamped the outcome (it runs
+/// first), and a recorded run offers Watch Replay (the recorder closes the run
+/// before this builds).
+#[expect(
+    clippy::too_many_arguments,
+    reason = "one param per thing the overlay shows"
+)]
 pub(crate) fn sync_outcome_overlay(
     mut commands: Commands,
     skin: Res<UiSkin>,
     outcome: Res<CurrentOutcome>,
+    last_replay: Res<LastReplay>,
+    debrief: Option<Res<ScenarioDebrief>>,
     world: Option<Res<NovaEventWorld>>,
     q_existing: Query<(Entity, &OutcomeOverlay)>,
+    q_races: Query<&CourseResult>,
 ) {
     // What Continue means is whatever the scenario queued: a Victory pairs it
     // with the next chapter, a Defeat with a retry of itself. Nothing queued
@@ -63,6 +79,12 @@ pub(crate) fn sync_outcome_overlay(
         ScenarioOutcomeKind::Defeat => "Retry",
     });
     let message = config.message.clone();
+    let race = q_races.iter().next().map(race_result_lines);
+    let debrief = debrief
+        .filter(|debrief| debrief.time.is_some())
+        .map(|debrief| debrief_lines(&debrief))
+        .unwrap_or_default();
+    let replay = last_replay.0.is_some();
 
     commands
         .spawn((
@@ -129,6 +151,45 @@ pub(crate) fn sync_outcome_overlay(
                             },
                         ));
                     }
+                    if let Some((time, best)) = race {
+                        parent.spawn((
+                            Name::new("Outcome Race Time"),
+                            Text::new(time),
+                            TextFont {
+                                font_size: FontSize::Px(20.0),
+                                ..default()
+                            },
+                            TextColor(theme::SCREEN_TEXT),
+                            Node {
+                                margin: UiRect::top(px(12)),
+                                ..default()
+                            },
+                        ));
+                        parent.spawn((
+                            Name::new("Outcome Race Best"),
+                            Text::new(best),
+                            TextFont {
+                                font_size: FontSize::Px(14.0),
+                                ..default()
+                            },
+                            TextColor(accent),
+                        ));
+                    }
+                    for (index, line) in debrief.into_iter().enumerate() {
+                        parent.spawn((
+                            Name::new("Outcome Debrief"),
+                            Text::new(line),
+                            TextFont {
+                                font_size: FontSize::Px(13.0),
+                                ..default()
+                            },
+                            TextColor(theme::PHOSPHOR_MUTED),
+                            Node {
+                                margin: UiRect::top(px(if index == 0 { 12 } else { 2 })),
+                                ..default()
+                            },
+                        ));
+                    }
                     if let Some(primary) = primary {
                         parent.spawn((
                             Name::new("Outcome Primary Button"),
@@ -136,6 +197,13 @@ pub(crate) fn sync_outcome_overlay(
                             observe(on_outcome_advance),
                         ));
                     }
+                    if replay {
+                        parent.spawn((
+                            Name::new("Outcome Replay Button"),
+                            button("Watch Replay"),
+                            observe(on_watch_replay),
+                        ));
+                    }
                     parent.spawn((
                         Name::new("Outcome Menu Button"),
                         button("Main Menu"),
@@ -164,6 +232,72 @@ pub(crate) fn sync_outcome_overlay(
         });
 }
 
+/// The outcome overlay's race results: the run's time, and either the delta
+/// to the personal best it was flown against or the news that it is the new
+/// one.
+fn race_result_lines(result: &CourseResult) -> (String, String) {
+    let time = format!("TIME {}", HudReadoutFormat::Time.render(result.time));
+    let best = match result.previous_best {
+        Some(best) if !result.is_best() => format!(
+            "BEST {}  {}",
+            HudReadoutFormat::Time.render(best),
+            format_race_delta(result.time - best)
+        ),
+        Some(best) => format!(
+            "NEW PERSONAL BEST  {}",
+            format_race_delta(result.time - best)
+        ),
+        None => "NEW PERSONAL BEST".to_string(),
+    };
+    (time, best)
+}
+
+/// The outcome overlay's debrief: the mission time, each weapon's hits and
+/// accuracy, damage both ways (in total, then by section class), the combat
+/// tallies and the objectives done.
+fn debrief_lines(debrief: &ScenarioDebrief) -> Vec<String> {
+    let by_class = |label: &str, damage: &std::collections::BTreeMap<String, f32>| {
+        let classes = damage
+            .iter()
+            .map(|(class, amount)| format!("{} {amount:.0}", class.to_uppercase()))
+            .collect::<Vec<_>>();
+        (!classes.is_empty()).then(|| format!("{label} {}", classes.join("  ")))
+    };
+    let mut lines = Vec::new();
+    if let Some(time) = debrief.time {
+        lines.push(format!(
+            "MISSION TIME {}",
+            HudReadoutFormat::Time.render(time)
+        ));
+    }
+    for (weapon, tally) in &debrief.weapons {
+        if let Some(accuracy) = tally.accuracy() {
+            lines.push(format!(
+                "{} {}/{} HIT {:.0}%",
+                weapon.to_uppercase(),
+                tally.hit,
+                tally.fired,
+                accuracy * 100.0
+            ));
+        }
+    }
+    lines.push(format!(
+        "DAMAGE DEALT {:.0}  TAKEN {:.0}",
+        debrief.total_dealt(),
+        debrief.total_taken()
+    ));
+    lines.extend(by_class("DEALT", &debrief.damage_dealt));
+    lines.extend(by_class("TAKEN", &debrief.damage_taken));
+    lines.push(format!(
+        "KILLS {}  INTERCEPTS {}  LOCKS {}",
+        debrief.kills, debrief.intercepts, debrief.locks
+    ));
+    if !debrief.objectives.is_empty() {
+        lines.push(format!("OBJECTIVES {}", debrief.objectives.len()));
+    }
+    lines
+}
+
 /// The outcome overlay's Continue/Retry button: release the lingering
 /// `NextScenario` the scenario queued next to its `Outcome` action - the
 /// same mechanism the Enter key drives through the loader.
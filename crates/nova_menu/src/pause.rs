@@ -1,5 +1,6 @@
 //! The pause overlay: ESC freezes the sim and raises a modal panel with
-//! Resume / Retry / Settings / Back to Main Menu / Exit.
+//! Resume / Retry / Photo Mode / Settings / Back to Main Menu / Exit. Photo
+//! Mode is a page of its own ([`crate::photo`]) that ESC steps back out of.
 
 use avian3d::prelude::{Physics, PhysicsTime};
 use bevy::{
@@ -9,6 +10,7 @@ use bevy::{
 };
 use nova_gameplay::prelude::*;
 use nova_scenario::prelude::*;
+use nova_ship::input::keymap::Keymap;
 use nova_ui::{
     prelude::UiSkin,
     theme,
@@ -18,6 +20,8 @@ use nova_ui::{
 #[cfg(not(target_arch = "wasm32"))]
 use crate::menu_ui::on_exit;
 use crate::{
+    controls::KeymapCapture,
+    photo::on_photo_mode,
     settings::{build_settings_body, PauseSettingsPanel},
     widgets::{button, button_variant},
 };
@@ -29,6 +33,7 @@ pub(crate) fn toggle_pause(
     keys: Res<ButtonInput<KeyCode>>,
     gamepad: Option<Res<ButtonInput<GamepadButton>>>,
     escape_owner: Option<Res<EscapeOwner>>,
+    capture: Option<Res<KeymapCapture>>,
     current: Res<State<PauseStates>>,
     mut next: ResMut<NextState<PauseStates>>,
     bank: Option<Res<SoundBank<UiSfx>>>,
@@ -42,6 +47,11 @@ pub(crate) fn toggle_pause(
     if escape_owner.is_some_and(|owner| owner.0) {
         return;
     }
+    // A Controls chip waiting for a press owns Escape (cancel) and Start (a
+    // refused fixed control) until it disarms.
+    if capture.is_some_and(|capture| capture.is_armed()) {
+        return;
+    }
     // A shown outcome frame is its own paused modal (`sync_outcome_pause` holds the app
     // in `Paused` while `CurrentOutcome` is set), with its own Continue/Retry/Main Menu
     // buttons: ESC/Start must not toggle here, or it would either resume the sim behind
@@ -60,6 +70,11 @@ pub(crate) fn toggle_pause(
             // is active is handled by nova_gameplay so clocks stay paused until
             // the NOVA OS has slid fully off screen.
             PauseStates::NovaOs => PauseStates::NovaOs,
+            // Photo mode is a page of the pause menu: ESC goes back to it.
+            PauseStates::Photo => PauseStates::Paused,
+            // The refit bay is the editor's gallery, which backs out of itself
+            // one Escape at a time and leaves the bay on the last one.
+            PauseStates::Refit => PauseStates::Refit,
         };
         if destination == *current.get() {
             return;
@@ -69,7 +84,7 @@ pub(crate) fn toggle_pause(
         // Resume/Exit buttons close it with their own MenuSelect click, so only the
         // ESC/pad toggle needs this.
         if let Some(bank) = bank {
-            commands.play_sfx_volume(bank.get(UiSfx::UiToggle), UI_TOGGLE_VOLUME);
+            commands.play_sfx_on(AudioBus::Ui, bank.get(UiSfx::UiToggle), UI_TOGGLE_VOLUME);
         }
     }
 }
@@ -165,8 +180,11 @@ pub(crate) fn setup_pause_ui(
     mut commands: Commands,
     current: Option<Res<CurrentScenario>>,
     volume: Res<MasterVolume>,
+    buses: Res<BusVolumes>,
     quality: Res<GraphicsQuality>,
     skin: Res<UiSkin>,
+    keymap: Res<Keymap>,
+    accessibility: Res<AccessibilitySettings>,
     outcome: Option<Res<CurrentOutcome>>,
 ) {
     // The outcome frame also enters `Paused` (`sync_outcome_pause`) to freeze the sim,
@@ -239,6 +257,13 @@ pub(crate) fn setup_pause_ui(
                             button("Retry"),
                             observe(on_retry),
                         ));
+                        // Photo mode borrows the scenario camera, so it too
+                        // needs a live scenario.
+                        parent.spawn((
+                            Name::new("Pause Photo Button"),
+                            button("Photo Mode"),
+                            observe(on_photo_mode),
+                        ));
                     }
                     parent.spawn((
                         Name::new("Pause Settings Button"),
@@ -316,7 +341,15 @@ pub(crate) fn setup_pause_ui(
                             ..default()
                         },
                     ));
-                    build_settings_body(parent, *volume, *quality, *skin);
+                    build_settings_body(
+                        parent,
+                        *volume,
+                        *buses,
+                        *quality,
+                        *skin,
+                        &keymap,
+                        *accessibility,
+                    );
                     parent.spawn((
                         Name::new("Pause Settings Back Button"),
                         button("Back"),
@@ -330,19 +363,23 @@ pub(crate) fn setup_pause_ui(
 pub(crate) fn on_pause_settings(
     _activate: On<Activate>,
     mut panel: Single<&mut Visibility, With<PauseSettingsPanel>>,
+    mut capture: ResMut<KeymapCapture>,
 ) {
     **panel = match **panel {
         Visibility::Hidden => Visibility::Visible,
         _ => Visibility::Hidden,
     };
+    capture.set_if_neq(KeymapCapture::default());
 }
 
 /// Close the pause Settings modal, back to the pause overlay.
 pub(crate) fn on_pause_settings_back(
     _activate: On<Activate>,
     mut panel: Single<&mut Visibility, With<PauseSettingsPanel>>,
+    mut capture: ResMut<KeymapCapture>,
 ) {
     **panel = Visibility::Hidden;
+    capture.set_if_neq(KeymapCapture::default());
 }
 
 pub(crate) fn on_resume(_activate: On<Activate>, mut next: ResMut<NextState<PauseStates>>) {
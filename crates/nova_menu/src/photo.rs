@@ -0,0 +1,812 @@
+//! Photo mode: a page of the pause menu that keeps the sim frozen and hands the
+//! scenario camera to the free-fly rig, with framing tools and a capture.
+//!
+//! The pause menu's Photo Mode button enters [`PauseStates::Photo`]. The clocks
+//! stay stopped, the HUD drops to [`HudVisibility::Cinematic`], and the scenario
+//! camera trades its chase rig (or scripted pose) for a [`WASDCameraController`]
+//! starting where the shot was. A panel on the right edge drives the lens:
+//!
+//! | Control | Does |
+//! |---------|------|
+//! | FOV | the perspective field of view |
+//! | Roll | tilt about the view axis, on top of the free-fly pose |
+//! | Exposure | EV compensation against the camera's own exposure |
+//! | Focus / Aperture | depth of field, while Depth of field is on |
+//! | Thirds | a rule-of-thirds grid over the frame |
+//! | Scale | capture at 1x, 2x or 4x the window resolution |
+//!
+//! | Key | Does |
+//! |-----|------|
+//! | `W` `A` `S` `D` / `Space` / `Shift` | fly the camera |
+//! | right mouse | look |
+//! | `H` | fold the panel away / back |
+//! | `Enter` | capture |
+//! | `Esc` | back to the pause menu |
+//!
+//! A capture goes through the `Screenshot` action's path
+//! ([`save_screenshot`]): the panel and the grid hide for the frames it takes,
+//! and a scale above 1x renders the world into a supersampled offscreen target
+//! ([`RenderScaleSupersample`]) and saves that instead of the window. Leaving
+//! the page puts every borrowed piece of the camera back as it was.
+
+use bevy::{
+    camera::Exposure,
+    post_process::dof::{DepthOfField, DepthOfFieldMode},
+    prelude::*,
+    render::view::screenshot::Screenshot,
+    ui_widgets::{observe, Activate, SliderRange, SliderValue, ValueChange},
+};
+use nova_gameplay::prelude::*;
+use nova_hud::prelude::HudVisibility;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+use nova_ui::{
+    prelude::UiSkin,
+    theme,
+    widget::{panel, segmented_container, segmented_option, ButtonVariant, Selected, UiText},
+};
+
+use crate::{
+    settings::spawn_slider_row,
+    widgets::{button, button_variant},
+};
+
+/// The directory captures land in, resolved under `NOVA_CAPTURE_DIR` like any
+/// `Screenshot` action path.
+pub(crate) const PHOTO_DIR: &str = "photos";
+
+/// Frames a capture waits for the hidden panel (and a supersampled target) to
+/// reach the screen before it shoots, and again before it puts them back.
+const PHOTO_SETTLE_FRAMES: u8 = 2;
+
+/// The capture scales the Scale row offers.
+const PHOTO_SCALES: [u8; 3] = [1, 2, 4];
+
+/// Photo panel width (px).
+const PHOTO_PANEL_W_PX: f32 = 320.0;
+
+/// The lens and framing the photo panel edits. Kept between visits so a series
+/// of shots shares a look; the field of view is re-read from the camera on
+/// every entry.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub(crate) struct PhotoSettings {
+    /// Vertical field of view, in degrees.
+    pub(crate) fov_deg: f32,
+    /// Roll about the view axis, in degrees.
+    pub(crate) roll_deg: f32,
+    /// EV compensation: positive brightens.
+    pub(crate) exposure_ev: f32,
+    /// Whether depth of field is on.
+    pub(crate) depth_of_field: bool,
+    /// Distance to the plane in focus, in world units.
+    pub(crate) focus: f32,
+    /// Aperture in f-stops: lower blurs more.
+    pub(crate) aperture: f32,
+    /// Whether the rule-of-thirds grid is drawn.
+    pub(crate) thirds: bool,
+    /// Capture scale against the window resolution.
+    pub(crate) scale: u8,
+    /// Whether the panel is folded away (`H`).
+    pub(crate) panel_hidden: bool,
+}
+
+impl Default for PhotoSettings {
+    fn default() -> Self {
+        Self {
+            fov_deg: 60.0,
+            roll_deg: 0.0,
+            exposure_ev: 0.0,
+            depth_of_field: false,
+            focus: 50.0,
+            aperture: 2.8,
+            thirds: false,
+            scale: 1,
+            panel_hidden: false,
+        }
+    }
+}
+
+/// One of the panel's sliders.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum PhotoKnob {
+    Fov,
+    Roll,
+    Exposure,
+    Focus,
+    Aperture,
+}
+
+impl PhotoKnob {
+    const ALL: [PhotoKnob; 5] = [
+        PhotoKnob::Fov,
+        PhotoKnob::Roll,
+        PhotoKnob::Exposure,
+        PhotoKnob::Focus,
+        PhotoKnob::Aperture,
+    ];
+
+    fn caption(self) -> &'static str {
+        match self {
+            PhotoKnob::Fov => "FOV",
+            PhotoKnob::Roll => "Roll",
+            PhotoKnob::Exposure => "Exposure",
+            PhotoKnob::Focus => "Focus",
+            PhotoKnob::Aperture => "Aperture",
+        }
+    }
+
+    /// The slider's range and step.
+    fn range(self) -> (SliderRange, f32) {
+        match self {
+            PhotoKnob::Fov => (SliderRange::new(20.0, 110.0), 1.0),
+            PhotoKnob::Roll => (SliderRange::new(-180.0, 180.0), 1.0),
+            PhotoKnob::Exposure => (SliderRange::new(-3.0, 3.0), 0.25),
+            PhotoKnob::Focus => (SliderRange::new(1.0, 1000.0), 1.0),
+            PhotoKnob::Aperture => (SliderRange::new(0.5, 22.0), 0.5),
+        }
+    }
+
+    fn get(self, settings: &PhotoSettings) -> f32 {
+        match self {
+            PhotoKnob::Fov => settings.fov_deg,
+            PhotoKnob::Roll => settings.roll_deg,
+            PhotoKnob::Exposure => settings.exposure_ev,
+            PhotoKnob::Focus => settings.focus,
+            PhotoKnob::Aperture => settings.aperture,
+        }
+    }
+
+    fn set(self, settings: &mut PhotoSettings, value: f32) {
+        match self {
+            PhotoKnob::Fov => settings.fov_deg = value,
+            PhotoKnob::Roll => settings.roll_deg = value,
+            PhotoKnob::Exposure => settings.exposure_ev = value,
+            PhotoKnob::Focus => settings.focus = value,
+            PhotoKnob::Aperture => settings.aperture = value,
+        }
+    }
+
+    /// The readout beside the slider.
+    pub(crate) fn label(self, value: f32) -> String {
+        match self {
+            PhotoKnob::Fov | PhotoKnob::Roll => format!("{}°", value.round() as i32),
+            PhotoKnob::Exposure => format!("{value:+.2} EV"),
+            PhotoKnob::Focus => format!("{} m", value.round() as i32),
+            PhotoKnob::Aperture => format!("f/{value:.1}"),
+        }
+    }
+}
+
+/// A photo [`Slider`](bevy::ui_widgets::Slider) entity.
+#[derive(Component)]
+pub(crate) struct PhotoSlider(pub(crate) PhotoKnob);
+
+/// The readout beside a photo slider.
+#[derive(Component)]
+pub(crate) struct PhotoLabel(pub(crate) PhotoKnob);
+
+/// The value a segmented photo option represents.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum PhotoOption {
+    DepthOfField(bool),
+    Thirds(bool),
+    Scale(u8),
+}
+
+/// The photo panel (folded away by `H` and during a capture).
+#[derive(Component)]
+pub(crate) struct PhotoPanel;
+
+/// The rule-of-thirds grid.
+#[derive(Component)]
+pub(crate) struct PhotoThirds;
+
+/// The panel's status line: where the last capture went.
+#[derive(Component)]
+pub(crate) struct PhotoStatus;
+
+/// Everything photo mode borrowed from the scenario camera, to hand back on
+/// the way out. Present exactly while the page is up over a camera.
+#[derive(Resource, Clone, Debug)]
+pub(crate) struct PhotoSession {
+    camera: Entity,
+    transform: Transform,
+    projection: Option<Projection>,
+    exposure: Option<Exposure>,
+    depth_of_field: Option<DepthOfField>,
+    pose: Option<ScriptedCameraPose>,
+    chase: bool,
+    free: bool,
+    hud: HudVisibility,
+}
+
+impl PhotoSession {
+    /// The exposure the compensation is measured from.
+    fn base_ev100(&self) -> f32 {
+        self.exposure
+            .as_ref()
+            .map_or(Exposure::default().ev100, |exposure| exposure.ev100)
+    }
+}
+
+/// A capture in flight: waiting for the hidden panel to clear the frame, then
+/// for the shot to land.
+#[derive(Resource, Clone, Debug)]
+pub(crate) struct PhotoCapture {
+    path: String,
+    frames: u8,
+    shot: bool,
+}
+
+/// The pause menu's Photo Mode button.
+pub(crate) fn on_photo_mode(_activate: On<Activate>, mut next: ResMut<NextState<PauseStates>>) {
+    next.set(PauseStates::Photo);
+}
+
+/// The panel's Back button: the same step back as `Esc`.
+fn on_photo_back(_activate: On<Activate>, mut next: ResMut<NextState<PauseStates>>) {
+    next.set(PauseStates::Paused);
+}
+
+/// Borrow the scenario camera: record what it was, swap its rig for the
+/// free-fly one where it stands, and clear the HUD. A rig without a scenario
+/// camera (the editor's build mode) still gets the page, with nothing to fly.
+pub(crate) fn begin_photo_session(
+    mut commands: Commands,
+    mut settings: ResMut<PhotoSettings>,
+    mut hud: ResMut<HudVisibility>,
+    camera: Option<
+        Single<
+            (
+                Entity,
+                &Transform,
+                Option<&Projection>,
+                Option<&Exposure>,
+                Option<&DepthOfField>,
+                Option<&ScriptedCameraPose>,
+                Has<SpaceshipCameraController>,
+                Has<WASDCameraController>,
+            ),
+            With<ScenarioCameraMarker>,
+        >,
+    >,
+) {
+    let Some(camera) = camera else {
+        return;
+    };
+    let (camera, transform, projection, exposure, depth_of_field, pose, chase, free) =
+        camera.into_inner();
+    let session = PhotoSession {
+        camera,
+        transform: *transform,
+        projection: projection.cloned(),
+        exposure: exposure.cloned(),
+        depth_of_field: depth_of_field.cloned(),
+        pose: pose.copied(),
+        chase,
+        free,
+        hud: *hud,
+    };
+    if let Some(Projection::Perspective(perspective)) = projection {
+        settings.fov_deg = perspective.fov.to_degrees().round();
+    }
+    settings.panel_hidden = false;
+    *hud = HudVisibility::Cinematic;
+
+    // NOTE: the free-fly rig seeds itself from the Transform it finds on insert,
+    // so the first frame of photo mode is the frame the pause froze. A camera
+    // that was already flying free is re-seeded the same way.
+    let mut entity = commands.entity(camera);
+    entity
+        .remove::<(
+            SpaceshipCameraController,
+            ScriptedCameraPose,
+            WASDCameraController,
+        )>()
+        .insert(WASDCameraController);
+    commands.insert_resource(session);
+}
+
+/// Hand the camera back exactly as [`begin_photo_session`] found it. Runs on
+/// every exit from the page, including the one leaving `Playing` - where the
+/// camera may already be gone, so every write is `try_`.
+pub(crate) fn end_photo_session(
+    mut commands: Commands,
+    session: Option<Res<PhotoSession>>,
+    mut hud: ResMut<HudVisibility>,
+) {
+    commands.remove_resource::<PhotoCapture>();
+    commands.remove_resource::<RenderScaleSupersample>();
+    let Some(session) = session else {
+        return;
+    };
+    commands.remove_resource::<PhotoSession>();
+    *hud = session.hud;
+
+    let Ok(mut entity) = commands.get_entity(session.camera) else {
+        return;
+    };
+    entity
+        .try_insert(session.transform)
+        .try_remove::<(WASDCameraController, DepthOfField, Exposure)>();
+    if let Some(projection) = session.projection.clone() {
+        entity.try_insert(projection);
+    }
+    if let Some(exposure) = session.exposure.clone() {
+        entity.try_insert(exposure);
+    }
+    if let Some(depth_of_field) = session.depth_of_field.clone() {
+        entity.try_insert(depth_of_field);
+    }
+    if session.free {
+        entity.try_insert(WASDCameraController);
+    }
+    if session.chase {
+        entity.try_insert(SpaceshipCameraController);
+    }
+    if let Some(pose) = session.pose {
+        entity.try_insert(pose);
+    }
+}
+
+/// The page: the panel on the right edge, the thirds grid over the frame.
+pub(crate) fn setup_photo_ui(
+    mut commands: Commands,
+    settings: Res<PhotoSettings>,
+    skin: Res<UiSkin>,
+) {
+    let settings = *settings;
+    let skin = *skin;
+
+    commands
+        .spawn((
+            DespawnOnExit(PauseStates::Photo),
+            Name::new("Photo Thirds"),
+            PhotoThirds,
+            Pickable::IGNORE,
+            Node {
+                position_type: PositionType::Absolute,
+                width: percent(100),
+                height: percent(100),
+                ..default()
+            },
+            Visibility::Hidden,
+            GlobalZIndex(9),
+        ))
+        .with_children(|grid| {
+            for third in [1.0 / 3.0, 2.0 / 3.0] {
+                let at = percent(third * 100.0);
+                grid.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: at,
+                        width: px(1),
+                        height: percent(100),
+                        ..default()
+                    },
+                    BackgroundColor(theme::SCREEN_TEXT.with_alpha(0.5)),
+                ));
+                grid.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: at,
+                        height: px(1),
+                        width: percent(100),
+                        ..default()
+                    },
+                    BackgroundColor(theme::SCREEN_TEXT.with_alpha(0.5)),
+                ));
+            }
+        });
+
+    commands
+        .spawn((
+            DespawnOnExit(PauseStates::Photo),
+            Name::new("Photo Panel"),
+            PhotoPanel,
+            // Clicks on the panel must not fall through to the scene, but the
+            // rest of the screen stays free for the camera.
+            Pickable {
+                should_block_lower: true,
+                is_hoverable: false,
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                top: px(20),
+                right: px(20),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Stretch,
+                width: px(PHOTO_PANEL_W_PX),
+                padding: UiRect::all(px(16)),
+                border: UiRect::all(px(theme::BORDER_W)),
+                border_radius: BorderRadius::all(px(theme::RADIUS)),
+                ..default()
+            },
+            panel(skin),
+            GlobalZIndex(10),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Name::new("Photo Title"),
+                UiText,
+                Text::new("Photo Mode"),
+                TextFont {
+                    font_size: FontSize::Px(20.0),
+                    ..default()
+                },
+                TextColor(theme::SCREEN_TEXT),
+            ));
+            for knob in PhotoKnob::ALL {
+                let (range, step) = knob.range();
+                let value = knob.get(&settings);
+                spawn_slider_row(
+                    parent,
+                    knob.caption(),
+                    value,
+                    range,
+                    step,
+                    knob.label(value),
+                    skin,
+                    PhotoSlider(knob),
+                    PhotoLabel(knob),
+                );
+            }
+            spawn_option_row(
+                parent,
+                "Depth Of Field",
+                &[
+                    ("Focus off", PhotoOption::DepthOfField(false)),
+                    ("Focus on", PhotoOption::DepthOfField(true)),
+                ],
+                PhotoOption::DepthOfField(settings.depth_of_field),
+                skin,
+            );
+            spawn_option_row(
+                parent,
+                "Thirds",
+                &[
+                    ("No grid", PhotoOption::Thirds(false)),
+                    ("Thirds", PhotoOption::Thirds(true)),
+                ],
+                PhotoOption::Thirds(settings.thirds),
+                skin,
+            );
+            let scales = PHOTO_SCALES.map(|scale| (scale_label(scale), PhotoOption::Scale(scale)));
+            let scales: Vec<(&str, PhotoOption)> = scales
+                .iter()
+                .map(|(label, option)| (label.as_str(), *option))
+                .collect();
+            spawn_option_row(
+                parent,
+                "Scale",
+                &scales,
+                PhotoOption::Scale(settings.scale),
+                skin,
+            );
+            // No file to write on wasm; the browser owns saving the canvas
+            // (same rule as the main menu's Exit).
+            #[cfg(not(target_arch = "wasm32"))]
+            parent.spawn((
+                Name::new("Photo Capture Button"),
+                button_variant("Capture", ButtonVariant::Primary, Some("Enter")),
+                observe(on_photo_capture),
+            ));
+            parent.spawn((
+                Name::new("Photo Status"),
+                PhotoStatus,
+                UiText,
+                Text::new("[WASD] MOVE  [RMB] LOOK  [H] HIDE"),
+                TextFont {
+                    font_size: FontSize::Px(12.0),
+                    ..default()
+                },
+                TextColor(theme::PHOSPHOR_DIM),
+                Node {
+                    margin: UiRect::vertical(px(6)),
+                    ..default()
+                },
+            ));
+            parent.spawn((
+                Name::new("Photo Back Button"),
+                button("Back"),
+                observe(on_photo_back),
+            ));
+        });
+}
+
+/// `2x`.
+fn scale_label(scale: u8) -> String {
+    format!("{scale}x")
+}
+
+/// One segmented row of [`PhotoOption`]s, `active` selected.
+fn spawn_option_row(
+    parent: &mut ChildSpawnerCommands,
+    name: &str,
+    options: &[(&str, PhotoOption)],
+    active: PhotoOption,
+    skin: UiSkin,
+) {
+    parent
+        .spawn((
+            Name::new(format!("Photo {name} Row")),
+            segmented_container(skin),
+        ))
+        .with_children(|row| {
+            for (label, option) in options {
+                let mut button = row.spawn((
+                    Name::new(format!("Photo {label}")),
+                    segmented_option(label),
+                    *option,
+                ));
+                if *option == active {
+                    button.insert(Selected);
+                }
+            }
+        });
+}
+
+/// Mirror a dragged photo slider into [`PhotoSettings`].
+pub(crate) fn on_photo_slider_change(
+    change: On<ValueChange<f32>>,
+    sliders: Query<&PhotoSlider>,
+    mut settings: ResMut<PhotoSettings>,
+) {
+    let Ok(PhotoSlider(knob)) = sliders.get(change.source) else {
+        return;
+    };
+    knob.set(&mut settings, change.value);
+}
+
+/// Keep each photo slider's readout in sync with its value.
+pub(crate) fn sync_photo_sliders(
+    sliders: Query<(&SliderValue, &PhotoSlider)>,
+    mut labels: Query<(&mut Text, &PhotoLabel)>,
+) {
+    for (value, PhotoSlider(knob)) in &sliders {
+        for (mut text, label) in &mut labels {
+            if label.0 == *knob {
+                let wanted = knob.label(value.0);
+                if text.0 != wanted {
+                    text.0 = wanted;
+                }
+            }
+        }
+    }
+}
+
+/// Write a pressed segmented option into [`PhotoSettings`] and move its row's
+/// `Selected` to it.
+pub(crate) fn on_photo_option(
+    activate: On<Activate>,
+    mut commands: Commands,
+    q_options: Query<(Entity, &PhotoOption, Has<Selected>)>,
+    mut settings: ResMut<PhotoSettings>,
+) {
+    let Ok((_, pressed, _)) = q_options.get(activate.entity) else {
+        return;
+    };
+    let pressed = *pressed;
+    let same_row =
+        |option: &PhotoOption| std::mem::discriminant(option) == std::mem::discriminant(&pressed);
+    for (entity, option, selected) in &q_options {
+        if !same_row(option) {
+            continue;
+        }
+        if *option == pressed && !selected {
+            commands.entity(entity).insert(Selected);
+        } else if *option != pressed && selected {
+            commands.entity(entity).remove::<Selected>();
+        }
+    }
+    match pressed {
+        PhotoOption::DepthOfField(on) => settings.depth_of_field = on,
+        PhotoOption::Thirds(on) => settings.thirds = on,
+        PhotoOption::Scale(scale) => settings.scale = scale,
+    }
+}
+
+/// Put the lens on the borrowed camera whenever the settings move (and once
+/// as the session starts): field of view, exposure, and depth of field.
+pub(crate) fn apply_photo_settings(
+    mut commands: Commands,
+    settings: Res<PhotoSettings>,
+    session: Option<Res<PhotoSession>>,
+    mut q_projection: Query<&mut Projection>,
+) {
+    let Some(session) = session else {
+        return;
+    };
+    if !settings.is_changed() && !session.is_added() {
+        return;
+    }
+    if let Ok(mut projection) = q_projection.get_mut(session.camera) {
+        if let Projection::Perspective(perspective) = &mut *projection {
+            perspective.fov = settings.fov_deg.to_radians();
+        }
+    }
+    let Ok(mut entity) = commands.get_entity(session.camera) else {
+        return;
+    };
+    entity.try_insert(Exposure {
+        ev100: session.base_ev100() - settings.exposure_ev,
+    });
+    if settings.depth_of_field {
+        // NOTE: Gaussian rather than the default bokeh, which WebGL2 cannot run.
+        entity.try_insert(DepthOfField {
+            mode: DepthOfFieldMode::Gaussian,
+            focal_distance: settings.focus,
+            aperture_f_stops: settings.aperture,
+            ..default()
+        });
+    } else {
+        entity.try_remove::<DepthOfField>();
+    }
+}
+
+/// Roll the free-fly pose about the view axis. In the authority chain's
+/// additive phase, on top of the pose the WASD rig solved this frame: the rig
+/// rewrites the whole Transform every frame, so the roll never accumulates.
+pub(crate) fn apply_photo_roll(
+    settings: Res<PhotoSettings>,
+    session: Option<Res<PhotoSession>>,
+    mut q_transform: Query<&mut Transform>,
+) {
+    let Some(session) = session else {
+        return;
+    };
+    if settings.roll_deg == 0.0 {
+        return;
+    }
+    if let Ok(mut transform) = q_transform.get_mut(session.camera) {
+        transform.rotate_local_z(settings.roll_deg.to_radians());
+    }
+}
+
+/// Show the panel and the grid as the settings ask, and neither while a
+/// capture is in flight.
+pub(crate) fn sync_photo_overlay(
+    settings: Res<PhotoSettings>,
+    capture: Option<Res<PhotoCapture>>,
+    mut q_panel: Query<&mut Visibility, (With<PhotoPanel>, Without<PhotoThirds>)>,
+    mut q_thirds: Query<&mut Visibility, (With<PhotoThirds>, Without<PhotoPanel>)>,
+) {
+    let capturing = capture.is_some();
+    let shown = |on: bool| {
+        if on && !capturing {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        }
+    };
+    for mut visibility in &mut q_panel {
+        visibility.set_if_neq(shown(!settings.panel_hidden));
+    }
+    for mut visibility in &mut q_thirds {
+        visibility.set_if_neq(shown(settings.thirds));
+    }
+}
+
+/// The page's keys: `H` folds the panel, `Enter` captures.
+pub(crate) fn photo_input(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut settings: ResMut<PhotoSettings>,
+    capture: Option<Res<PhotoCapture>>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    if keys.just_pressed(KeyCode::KeyH) {
+        settings.panel_hidden = !settings.panel_hidden;
+    }
+    if keys.just_pressed(KeyCode::Enter) && capture.is_none() {
+        start_photo_capture(&mut commands, &settings);
+    }
+}
+
+/// The panel's Capture button.
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+fn on_photo_capture(
+    _activate: On<Activate>,
+    mut commands: Commands,
+    settings: Res<PhotoSettings>,
+    capture: Option<Res<PhotoCapture>>,
+) {
+    if capture.is_none() {
+        start_photo_capture(&mut commands, &settings);
+    }
+}
+
+/// Start a capture: hide the overlay, raise the supersample when the scale
+/// asks for one, and let [`drive_photo_capture`] shoot once both have landed.
+fn start_photo_capture(commands: &mut Commands, settings: &PhotoSettings) {
+    if settings.scale > 1 {
+        commands.insert_resource(RenderScaleSupersample(f32::from(settings.scale)));
+    }
+    commands.insert_resource(PhotoCapture {
+        path: photo_path(),
+        frames: PHOTO_SETTLE_FRAMES,
+        shot: false,
+    });
+}
+
+/// `photos/nova_<unix-millis>.png` - millis so a burst never collides and the
+/// folder sorts by time.
+#[cfg(not(target_arch = "wasm32"))]
+fn photo_path() -> String {
+    let since_epoch = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default();
+    photo_file_name(since_epoch.as_millis())
+}
+
+/// The wasm build has no wall clock (`SystemTime::now` is a panicking stub)
+/// and never shows the Capture button; `Enter` still names its shot.
+#[cfg(target_arch = "wasm32")]
+fn photo_path() -> String {
+    photo_file_name(0)
+}
+
+fn photo_file_name(millis: u128) -> String {
+    format!("{PHOTO_DIR}/nova_{millis}.png")
+}
+
+/// Step a capture: wait for the overlay to clear, shoot - the supersampled
+/// target when one is up, else the window - then wait again and put the
+/// overlay and the resolution back.
+pub(crate) fn drive_photo_capture(
+    mut commands: Commands,
+    capture: Option<ResMut<PhotoCapture>>,
+    settings: Res<PhotoSettings>,
+    render_scale: Option<Res<RenderScaleState>>,
+    mut q_status: Query<&mut Text, With<PhotoStatus>>,
+) {
+    let Some(mut capture) = capture else {
+        return;
+    };
+    if capture.frames > 0 {
+        capture.frames -= 1;
+        return;
+    }
+    if !capture.shot {
+        let target = render_scale
+            .as_deref()
+            .and_then(RenderScaleState::target)
+            .filter(|_| settings.scale > 1)
+            .cloned();
+        let screenshot = match target {
+            Some(image) => Screenshot::image(image),
+            None => Screenshot::primary_window(),
+        };
+        let path = capture.path.clone();
+        commands.queue(move |world: &mut World| save_screenshot(world, screenshot, &path));
+        capture.shot = true;
+        capture.frames = PHOTO_SETTLE_FRAMES;
+        return;
+    }
+    for mut text in &mut q_status {
+        text.0 = format!("SAVED {}", capture.path);
+    }
+    commands.remove_resource::<PhotoCapture>();
+    commands.remove_resource::<RenderScaleSupersample>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The readouts and the capture name read as the wiki shows them.
+    #[test]
+    fn readouts_and_file_names_read_as_documented() {
+        assert_eq!(PhotoKnob::Fov.label(59.6), "60°");
+        assert_eq!(PhotoKnob::Roll.label(-15.0), "-15°");
+        assert_eq!(PhotoKnob::Exposure.label(0.5), "+0.50 EV");
+        assert_eq!(PhotoKnob::Focus.label(120.4), "120 m");
+        assert_eq!(PhotoKnob::Aperture.label(2.8), "f/2.8");
+        assert_eq!(
+            photo_file_name(1_737_027_685_123),
+            "photos/nova_1737027685123.png"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! The persisted form of the race personal bests.
+//!
+//! A race course (nova_scenario) keeps the best run of every course the player
+//! has finished in [`RaceBests`]; this module snapshots that into one
+//! versionable blob, names the store key, and loads it at startup and saves it
+//! whenever a finish sets a new best. Storage, and its best-effort semantics,
+//! belong to [`nova_assets::persist`].
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use nova_assets::persist;
+use nova_scenario::prelude::{thin_ghost_track, GhostSample, RaceBests, RaceRecord};
+use serde::{Deserialize, Serialize};
+
+/// The persisted form of the bests: plain, versionable data decoupled from the
+/// live resource.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct PersistedRaceBests {
+    /// Every course's best run, keyed `<scenario id>/<course id>`.
+    #[serde(default)]
+    pub courses: BTreeMap<String, PersistedRaceRecord>,
+}
+
+/// One course's best run.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PersistedRaceRecord {
+    /// Seconds from the start gate to the finish.
+    pub time: f64,
+    /// The split at every gate after the start.
+    #[serde(default)]
+    pub splits: Vec<f64>,
+    /// The ghost track: `(t, position, rotation)` per sample, thinned for
+    /// storage from the run's every fixed step (10 Hz, capped). Absent from
+    /// bests saved before ghosts were recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ghost: Vec<(f32, [f32; 3], [f32; 4])>,
+}
+
+impl PersistedRaceBests {
+    /// Snapshot the live bests.
+    pub fn from_resource(bests: &RaceBests) -> Self {
+        Self {
+            courses: bests
+                .0
+                .iter()
+                .map(|(key, record)| {
+                    (
+                        key.clone(),
+                        PersistedRaceRecord {
+                            time: record.time,
+                            splits: record.splits.clone(),
+                            ghost: thin_ghost_track(&record.ghost)
+                                .iter()
+                                .map(|sample| {
+                                    (
+                                        sample.t,
+                                        sample.position.to_array(),
+                                        sample.rotation.to_array(),
+                                    )
+                                })
+                                .collect(),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// The live bests this snapshot restores. A track saved before tracks
+    /// were thinned is thinned here.
+    pub fn race_bests(&self) -> RaceBests {
+        RaceBests(
+            self.courses
+                .iter()
+                .map(|(key, record)| {
+                    (
+                        key.clone(),
+                        RaceRecord {
+                            time: record.time,
+                            splits: record.splits.clone(),
+                            ghost: thin_ghost_track(
+                                &record
+                                    .ghost
+                                    .iter()
+                                    .map(|&(t, position, rotation)| GhostSample {
+                                        t,
+                                        position: Vec3::from_array(position),
+                                        rotation: Quat::from_array(rotation),
+                                    })
+                                    .collect::<Vec<_>>(),
+                            ),
+                        },
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The store key: `<config_dir>/nova-protocol/race_bests.ron` on native,
+/// `nova_protocol.race_bests` in localStorage on the web.
+pub(crate) const KEY: &str = "race_bests";
+
+/// The saved bests, or `None` if nothing has been saved yet (or the store is
+/// unreadable/corrupt).
+pub fn load_race_bests() -> Option<PersistedRaceBests> {
+    persist::load(KEY)
+}
+
+/// Persist the bests. Best-effort - failures are logged, not returned.
+pub fn save_race_bests(bests: &PersistedRaceBests) {
+    persist::save(KEY, bests);
+}
+
+/// Startup: restore the saved bests, if any.
+pub(crate) fn load_persisted_race_bests(mut bests: ResMut<RaceBests>) {
+    if let Some(saved) = load_race_bests() {
+        *bests = saved.race_bests();
+    }
+}
+
+/// Save the bests whenever they change. A course only writes them when a
+/// finish beats the stored run, so this is one save per new best - no
+/// debounce needed. The startup load is skipped: it changes nothing on disk.
+pub(crate) fn persist_race_bests_on_change(bests: Res<RaceBests>) {
+    if bests.is_changed() && !bests.is_added() {
+        save_race_bests(&PersistedRaceBests::from_resource(&bests));
+    }
+}
+
+// As with the settings, the storage backends are tested in `nova_assets`; what
+// is left to pin here is that the value round-trips.
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use nova_assets::{
+        persist::{load_from, save_to},
+        storage::NativeStorage,
+    };
+
+    use super::*;
+
+    #[test]
+    fn race_bests_round_trip() {
+        let store = NativeStorage::at(std::env::temp_dir().join("nova_race_bests_round_trip"));
+        let _ = std::fs::remove_dir_all(store.path(KEY).parent().unwrap());
+
+        let bests = RaceBests(BTreeMap::from([(
+            RaceBests::key("gauntlet", "course"),
+            RaceRecord {
+                time: 83.4,
+                splits: vec![12.5, 40.0, 83.4],
+                ghost: vec![
+                    GhostSample {
+                        t: 0.0,
+                        position: Vec3::new(0.0, 5.0, -20.0),
+                        rotation: Quat::IDENTITY,
+                    },
+                    GhostSample {
+                        t: 83.4,
+                        position: Vec3::new(310.0, 5.0, 1200.0),
+                        rotation: Quat::from_rotation_y(0.5),
+                    },
+                ],
+            },
+        )]));
+        save_to(&store, KEY, &PersistedRaceBests::from_resource(&bests));
+
+        let loaded = load_from::<PersistedRaceBests>(&store, KEY).expect("saved bests load");
+        assert_eq!(loaded.race_bests(), bests);
+    }
+
+    /// A track sampled every fixed step - how a run records it, and how bests
+    /// were saved before - is stored at 10 Hz, both ways through the file.
+    #[test]
+    fn a_ghost_is_stored_thinned() {
+        let ghost: Vec<(f32, [f32; 3], [f32; 4])> = (0..=640)
+            .map(|index| {
+                (
+                    index as f32 / 64.0,
+                    [0.0, 0.0, index as f32],
+                    [0.0, 0.0, 0.0, 1.0],
+                )
+            })
+            .collect();
+        let saved = PersistedRaceBests {
+            courses: BTreeMap::from([(
+                RaceBests::key("gauntlet", "course"),
+                PersistedRaceRecord {
+                    time: 10.0,
+                    splits: vec![10.0],
+                    ghost,
+                },
+            )]),
+        };
+        let bests = saved.race_bests();
+        let track = &bests.0[&RaceBests::key("gauntlet", "course")].ghost;
+        assert!(track.len() <= 102, "{} samples in 10s", track.len());
+        assert_eq!(track.last().map(|sample| sample.t), Some(10.0));
+        assert_eq!(
+            PersistedRaceBests::from_resource(&bests).courses
+                [&RaceBests::key("gauntlet", "course")]
+                .ghost
+                .len(),
+            track.len()
+        );
+
+        // A best set this session is raced at the fixed step, and thinned only
+        // as it is written.
+        let fine = RaceBests(BTreeMap::from([(
+            RaceBests::key("gauntlet", "course"),
+            RaceRecord {
+                time: 10.0,
+                splits: vec![10.0],
+                ghost: (0..=640)
+                    .map(|index| GhostSample {
+                        t: index as f32 / 64.0,
+                        position: Vec3::Z * index as f32,
+                        rotation: Quat::IDENTITY,
+                    })
+                    .collect(),
+            },
+        )]));
+        let written = PersistedRaceBests::from_resource(&fine);
+        assert_eq!(
+            written.courses[&RaceBests::key("gauntlet", "course")]
+                .ghost
+                .len(),
+            track.len()
+        );
+    }
+}
@@ -0,0 +1,312 @@
+//! The replay file: what a recorded run is, on disk and in memory.
+//!
+//! A replay is everything needed to fly a scenario again and nothing the
+//! simulation can re-derive: the scenario id, the mods in merge order, the
+//! difficulty tier, the RNG seed, the player's intent as change-only keyframes on the fixed tick, and
+//! the NOVA OS commands with the tick they were typed on. Vectors are stored
+//! as plain float arrays so the file does not depend on glam's serde feature,
+//! and the whole thing is written as compact RON through
+//! [`nova_assets::persist`].
+
+use bevy::prelude::*;
+use nova_assets::persist;
+use nova_gameplay::prelude::Difficulty;
+use serde::{Deserialize, Serialize};
+
+/// Bumped whenever a field changes meaning; a replay of another schema is
+/// refused rather than played wrong.
+pub const REPLAY_SCHEMA: u32 = 2;
+
+/// One recorded run.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Replay {
+    /// [`REPLAY_SCHEMA`] at record time.
+    pub schema: u32,
+    /// The scenario that was flown.
+    pub scenario: String,
+    /// The enabled mod ids, in the order they were merged.
+    pub mods: Vec<String>,
+    /// The `GlobalRng` seed the run was reseeded with on load.
+    pub seed: u64,
+    /// Fixed ticks from the load to the end of the recording.
+    pub end_tick: u64,
+    /// The player's intent, one entry per tick on which it changed. The first
+    /// entry is at tick 0.
+    #[serde(default)]
+    pub keyframes: Vec<ReplayKeyframe>,
+    /// Every gameplay NOVA OS command, in the order it was typed.
+    #[serde(default)]
+    pub commands: Vec<ReplayCommand>,
+    /// The world as the run ended, for the probe's replay check: the JSON the
+    /// snapshot capability writes, header stamps stripped. `None` unless the
+    /// probe was armed when the run was recorded.
+    #[serde(default)]
+    pub snapshot: Option<String>,
+    /// The seed a survival scenario's waves were built from, so playback
+    /// rebuilds the same waves. `None` for any other scenario.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub survival_seed: Option<u64>,
+    /// The tier the run was flown at; playback pins it whatever the setting
+    /// says now.
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// The intent in force from `tick` until the next keyframe.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ReplayKeyframe {
+    /// The fixed tick the intent was first read on.
+    pub tick: u64,
+    /// What the pilot was asking for.
+    pub intent: ReplayIntent,
+}
+
+/// Every intent component the player input layer writes, at one tick.
+/// Entities are named by their scenario [`EntityId`](nova_events::prelude::EntityId)
+/// (or their `Name`), never numbered: a reload renumbers them.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct ReplayIntent {
+    /// The main-drive burn, `0..1`.
+    pub burn: f32,
+    /// The ship-local RCS command while the RCS modifier is held.
+    #[serde(default)]
+    pub rcs: Option<[f32; 3]>,
+    /// The commanded heading (the controller's rotation input), as `x, y, z, w`.
+    #[serde(default)]
+    pub heading: Option<[f32; 4]>,
+    /// Whether the weapons were raised.
+    #[serde(default)]
+    pub raised: bool,
+    /// The engaged autopilot verb.
+    #[serde(default)]
+    pub autopilot: Option<ReplayAutopilot>,
+    /// The combat lock's target.
+    #[serde(default)]
+    pub lock: Option<String>,
+    /// The sections whose trigger was held, by their ship-local id, sorted.
+    #[serde(default)]
+    pub fire: Vec<String>,
+    /// The key-bound thrusters whose key was held, by their ship-local id,
+    /// sorted.
+    #[serde(default)]
+    pub thrust: Vec<String>,
+    /// The turret feed.
+    #[serde(default)]
+    pub aim: Option<ReplayAim>,
+}
+
+/// An autopilot verb, with its target named instead of numbered. The orbit
+/// plan is not recorded: the computer recomputes it on its first tick.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum ReplayAutopilot {
+    /// STOP.
+    Stop,
+    /// GOTO an entity.
+    Goto {
+        /// The destination.
+        target: String,
+        /// Whether the trip is a docking approach to `target`, a port.
+        #[serde(default)]
+        dock: bool,
+    },
+    /// GOTO a fixed position.
+    GotoPos {
+        /// The destination, world coordinates.
+        position: [f32; 3],
+    },
+    /// ORBIT a well.
+    Orbit {
+        /// The well being orbited.
+        well: String,
+    },
+}
+
+/// The point, velocity and tracked body the turret feed handed the player's
+/// guns.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ReplayAim {
+    /// The aim point, world coordinates.
+    pub point: [f32; 3],
+    /// The velocity the lead is computed from.
+    pub velocity: [f32; 3],
+    /// The body that velocity belongs to.
+    #[serde(default)]
+    pub target: Option<String>,
+}
+
+/// A NOVA OS gameplay command and the tick it was applied after.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct ReplayCommand {
+    /// Fixed ticks completed when the command was submitted.
+    pub tick: u64,
+    /// The command (`ship repair`, `map goto`, ...).
+    pub name: String,
+    /// Its arguments, as typed.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// Why a replay cannot be played in this game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The file was written by another replay schema.
+    Schema {
+        /// The file's schema.
+        found: u32,
+    },
+    /// The recorded scenario is not registered.
+    UnknownScenario {
+        /// The recorded scenario id.
+        scenario: String,
+    },
+    /// The enabled mods differ from the recorded set, so the content the run
+    /// was flown against is not the content loaded now.
+    ModMismatch {
+        /// The recorded mod ids.
+        recorded: Vec<String>,
+        /// The enabled mod ids.
+        enabled: Vec<String>,
+    },
+}
+
+impl std::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Schema { found } => {
+                write!(
+                    f,
+                    "replay schema {found} (this build plays {REPLAY_SCHEMA})"
+                )
+            }
+            Self::UnknownScenario { scenario } => {
+                write!(f, "scenario '{scenario}' is not installed")
+            }
+            Self::ModMismatch { recorded, enabled } => write!(
+                f,
+                "recorded with mods [{}], enabled are [{}]",
+                recorded.join(", "),
+                enabled.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl Replay {
+    /// The intent in force at `tick`: the last keyframe at or before it.
+    pub fn intent_at(&self, tick: u64) -> Option<&ReplayIntent> {
+        let index = self.keyframes.partition_point(|frame| frame.tick <= tick);
+        index
+            .checked_sub(1)
+            .map(|index| &self.keyframes[index].intent)
+    }
+}
+
+/// The store key: `<config_dir>/nova-protocol/replay.ron` on native,
+/// `nova_protocol.replay` in localStorage on the web. One slot - the last
+/// recorded run.
+pub(crate) const KEY: &str = "replay";
+
+/// The last recorded run, or `None` if nothing has been saved yet (or the
+/// store is unreadable/corrupt).
+pub fn load_replay() -> Option<Replay> {
+    persist::load(KEY)
+}
+
+/// Persist a run. Best-effort - failures are logged, not returned.
+pub fn save_replay(replay: &Replay) {
+    persist::save(KEY, replay);
+}
+
+/// `Vec3` to its stored form.
+pub(crate) fn vec3_array(v: Vec3) -> [f32; 3] {
+    v.to_array()
+}
+
+/// A `Quat` to its stored form.
+pub(crate) fn quat_array(q: Quat) -> [f32; 4] {
+    q.to_array()
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use nova_assets::{
+        persist::{load_from, save_to},
+        storage::NativeStorage,
+    };
+
+    use super::*;
+
+    fn replay() -> Replay {
+        Replay {
+            schema: REPLAY_SCHEMA,
+            scenario: "gauntlet".to_string(),
+            mods: vec!["base".to_string()],
+            seed: 0x5eed_1234_abcd_0001,
+            end_tick: 640,
+            keyframes: vec![
+                ReplayKeyframe {
+                    tick: 0,
+                    intent: ReplayIntent::default(),
+                },
+                ReplayKeyframe {
+                    tick: 12,
+                    intent: ReplayIntent {
+                        burn: 0.75,
+                        rcs: Some([0.1, 0.0, -0.3]),
+                        heading: Some(quat_array(Quat::from_rotation_y(0.4))),
+                        raised: true,
+                        autopilot: Some(ReplayAutopilot::Goto {
+                            target: "beacon".to_string(),
+                            dock: false,
+                        }),
+                        lock: Some("raider".to_string()),
+                        fire: vec!["gun_left".to_string()],
+                        thrust: vec!["retro".to_string()],
+                        aim: Some(ReplayAim {
+                            point: [1.5, -2.0, 300.25],
+                            velocity: [0.0, 0.0, 4.0],
+                            target: Some("raider".to_string()),
+                        }),
+                    },
+                },
+            ],
+            commands: vec![ReplayCommand {
+                tick: 300,
+                name: "ship repair".to_string(),
+                args: vec!["hull_a".to_string()],
+            }],
+            snapshot: Some("{\"ships\":[]}".to_string()),
+            survival_seed: None,
+            difficulty: Difficulty::Hard,
+        }
+    }
+
+    // The storage backends are tested in `nova_assets`; what is left to pin
+    // here is that a run round-trips exactly, floats included - a replay that
+    // loses a bit of a heading flies a different run.
+    #[test]
+    fn replay_round_trip() {
+        let store = NativeStorage::at(std::env::temp_dir().join("nova_replay_round_trip"));
+        let _ = std::fs::remove_dir_all(store.path(KEY).parent().unwrap());
+
+        save_to(&store, KEY, &replay());
+        let loaded = load_from::<Replay>(&store, KEY).expect("saved replay loads");
+        assert_eq!(loaded, replay());
+    }
+
+    #[test]
+    fn the_intent_in_force_is_the_last_keyframe_at_or_before_the_tick() {
+        let replay = replay();
+        assert_eq!(replay.intent_at(0), Some(&ReplayIntent::default()));
+        assert_eq!(replay.intent_at(11), Some(&ReplayIntent::default()));
+        assert_eq!(replay.intent_at(12).map(|intent| intent.burn), Some(0.75));
+        assert_eq!(replay.intent_at(9000).map(|intent| intent.burn), Some(0.75));
+        let empty = Replay {
+            keyframes: Vec::new(),
+            ..replay
+        };
+        assert_eq!(empty.intent_at(5), None);
+    }
+}
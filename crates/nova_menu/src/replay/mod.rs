@@ -0,0 +1,221 @@
+//! Replays: every run is recorded, and the last one can be watched again.
+//!
+//! A run is a scenario, the mods it was flown with, a `GlobalRng` seed and
+//! the player's input - nothing else, because everything else is the
+//! simulation's to re-derive. The [recorder](record) reseeds the RNG on load,
+//! samples the player's intent components at the top of every fixed tick and
+//! keeps the ticks on which they changed, and notes each NOVA OS gameplay
+//! command with its tick. The [player](playback) loads the same scenario under
+//! the same seed, suspends the human's input
+//! ([`PlayerInputSuspended`](nova_gameplay::PlayerInputSuspended)) and writes
+//! the recorded intent back into the same components on the same ticks, so
+//! the ship is flown by exactly the code paths that flew it the first time.
+//! The [spectator](spectator) is what the freed keyboard does meanwhile: a
+//! free camera and seeking by re-simulation, which lands exactly where
+//! playback would because a playback steps one fixed tick per frame. The
+//! [file](format) is compact RON in the one `replay` store slot.
+//!
+//! ## How deterministic it is
+//!
+//! Exactly. While a run is recorded or played back the simulation is in
+//! [lockstep](nova_gameplay::lockstep): the AI pilots and the scenario clock,
+//! which otherwise step once per rendered frame on frame time, step on the
+//! fixed clock with everything else - flight, autopilot, weapons, physics -
+//! and read the raw physics pose rather than the eased one. Every input to the
+//! outcome is then a function of the seed, the recorded intent and the tick,
+//! so a playback reaches every tick in the state the recording did however
+//! many ticks each of the recorded frames ran. The probe holds it to that:
+//! with the probe armed, a finished recording carries a world snapshot, and a
+//! playback that does not reproduce it is a `replay_divergence` that fails the
+//! run.
+
+mod format;
+mod playback;
+mod record;
+mod spectator;
+
+/// Glob-import surface: `use nova_menu::replay::prelude::*` re-exports the
+/// public API of this module.
+pub mod prelude {
+    pub use super::{
+        format::{
+            load_replay, save_replay, Replay, ReplayAim, ReplayAutopilot, ReplayCommand,
+            ReplayError, ReplayIntent, ReplayKeyframe, REPLAY_SCHEMA,
+        },
+        playback::{
+            replay_scenario, watch_replay, ReplayPhase, ReplayPlayback, ReplayPlaybackEnded,
+        },
+        record::{LastReplay, ReplayRecorded, ReplayRecorder},
+        spectator::REPLAY_SEEK_SECS,
+        ReplayPlugin, ReplayTick,
+    };
+}
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use nova_events::prelude::EntityId;
+use nova_gameplay::prelude::*;
+use nova_os_ui::{map::NovaOsMapSystems, ship::NovaOsShipSystems, terminal::NovaOsSystems};
+use nova_scenario::prelude::CurrentOutcome;
+pub(crate) use playback::on_watch_replay;
+use playback::{
+    apply_replay_intent, begin_playback, drive_replay_clock, finish_playback,
+    replay_nova_os_commands, settle_seek, stop_playback_on_exit, ReplayClock, ReplayPlayback,
+};
+use record::{
+    finish_recording_on_exit, finish_recording_on_outcome, finish_recording_on_unload,
+    load_persisted_replay, persist_last_replay, record_nova_os_commands, record_player_intent,
+    start_recording, LastReplay, ReplayRecorder,
+};
+use spectator::{spectator_input, sync_replay_bar};
+
+use crate::outcome::sync_outcome_overlay;
+
+/// Fixed ticks since the recorded (or replayed) scenario loaded: the clock
+/// both the keyframes and the commands are stamped with. Rewound on every load
+/// a recording or playback starts from, and advanced after each fixed tick
+/// while one is running.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ReplayTick(pub u64);
+
+/// Records every run in play and plays the last one back. Added by
+/// [`NovaMenuPlugin`](crate::NovaMenuPlugin).
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReplayTick>();
+        app.init_resource::<ReplayRecorder>();
+        app.init_resource::<ReplayPlayback>();
+        app.init_resource::<ReplayClock>();
+        app.init_resource::<LastReplay>();
+        // NOTE: owned by nova_gameplay's plugin; repeated for the slim menu
+        // rigs.
+        app.init_resource::<PlayerInputSuspended>();
+        app.init_resource::<SimulationLockstep>();
+
+        app.add_observer(start_recording);
+        app.add_observer(begin_playback);
+        app.add_observer(finish_recording_on_unload);
+        // The recorder reads what this tick's consumers are about to read, and
+        // the player writes it back in the same slot.
+        app.add_systems(
+            FixedPreUpdate,
+            (record_player_intent, apply_replay_intent).run_if(in_state(GameStates::Playing)),
+        );
+        app.add_systems(FixedPostUpdate, advance_replay_tick.run_if(replay_running));
+        // Between the terminal's input and the apps that consume the pending
+        // invocation: a typed command is seen before it is taken, a replayed
+        // one is queued before the app looks.
+        app.add_systems(
+            Update,
+            (record_nova_os_commands, replay_nova_os_commands)
+                .after(NovaOsSystems::Input)
+                .before(NovaOsMapSystems)
+                .before(NovaOsShipSystems)
+                .run_if(in_state(GameStates::Playing)),
+        );
+        // NOTE: resource_exists-gated like the outcome overlay - headless rigs
+        // without the scenario loader have no CurrentOutcome. Before it, so
+        // its Watch Replay offers the run that just ended.
+        app.add_systems(
+            Update,
+            finish_recording_on_outcome
+                .before(sync_outcome_overlay)
+                .run_if(in_state(GameStates::Playing))
+                .run_if(resource_exists::<CurrentOutcome>),
+        );
+        app.add_systems(
+            Update,
+            (
+                spectator_input,
+                settle_seek,
+                finish_playback,
+                sync_replay_bar,
+            )
+                .chain()
+                .run_if(in_state(GameStates::Playing)),
+        );
+        // Ungated: the clock goes back after play is left too.
+        app.add_systems(Last, (drive_replay_clock, release_lockstep));
+        app.add_systems(Startup, load_persisted_replay);
+        app.add_systems(Update, persist_last_replay);
+        app.add_systems(
+            OnExit(GameStates::Playing),
+            (finish_recording_on_exit, stop_playback_on_exit),
+        );
+    }
+}
+
+/// Run condition: a run is being recorded or played.
+fn replay_running(recorder: Res<ReplayRecorder>, playback: Res<ReplayPlayback>) -> bool {
+    recorder.is_recording() || playback.is_active()
+}
+
+/// Take the simulation out of lockstep once nothing is recorded or played.
+/// Going in is the recorder's and the player's to do as their run starts, so
+/// no frame of it runs on the free clock; coming out covers every way a run
+/// ends.
+fn release_lockstep(
+    recorder: Res<ReplayRecorder>,
+    playback: Res<ReplayPlayback>,
+    mut lockstep: ResMut<SimulationLockstep>,
+) {
+    if lockstep.0 && !recorder.is_recording() && !playback.is_active() {
+        lockstep.0 = false;
+    }
+}
+
+/// Count the fixed tick that just ran.
+fn advance_replay_tick(mut tick: ResMut<ReplayTick>) {
+    tick.0 += 1;
+}
+
+/// Names entities the way a replay stores them, and finds them again. An
+/// entity is its scenario [`EntityId`], else its `Name` - prefixed with its
+/// parent's label when it has one, so the `DockingPort0` of two stations stay
+/// apart. Section ids are ship-local and never name a target, so sections are
+/// left out.
+#[derive(SystemParam)]
+pub(crate) struct ReplayLabels<'w, 's> {
+    q_named: Query<
+        'w,
+        's,
+        (
+            Entity,
+            Option<&'static EntityId>,
+            Option<&'static Name>,
+            Option<&'static ChildOf>,
+        ),
+        Without<SectionMarker>,
+    >,
+}
+
+impl ReplayLabels<'_, '_> {
+    /// How a replay names `entity`; `None` when it has neither id nor name.
+    pub(crate) fn label(&self, entity: Entity) -> Option<String> {
+        let (_, id, name, parent) = self.q_named.get(entity).ok()?;
+        if let Some(id) = id {
+            return Some(id.0.clone());
+        }
+        let name = name?.to_string();
+        match parent.and_then(|parent| self.label(parent.parent())) {
+            Some(parent) => Some(format!("{parent}/{name}")),
+            None => Some(name),
+        }
+    }
+
+    /// The entity a replay's `label` names. Look-alikes can share a label
+    /// (two same-named objects with no id); the lowest entity wins, a tie an
+    /// author breaks by giving the target an id.
+    pub(crate) fn resolve(&self, label: &str) -> Option<Entity> {
+        let leaf = label.rsplit('/').next().unwrap_or(label);
+        self.q_named
+            .iter()
+            .filter(|(_, id, name, _)| {
+                id.is_some_and(|id| id.0 == label) || name.is_some_and(|name| name.as_str() == leaf)
+            })
+            .map(|(entity, ..)| entity)
+            .filter(|&entity| self.label(entity).as_deref() == Some(label))
+            .min()
+    }
+}
@@ -0,0 +1,601 @@
+//! The player: flies a recorded run again through the same intent components
+//! the human writes, with the human's input suspended.
+//!
+//! Watch Replay validates the run against this game (scenario installed, same
+//! mods), suspends the player's input, pins the recorded difficulty tier
+//! ([`DifficultyOverride`]) and loads the scenario; the load reseeds
+//! `GlobalRng` with the recorded seed and starts the tick at 0. Every fixed
+//! tick then writes the keyframe in force, and every recorded NOVA OS command
+//! is re-issued on the first frame its tick has passed. Playback ends when the
+//! run's outcome comes round again or its last tick is reached, and hands the
+//! ship back.
+//!
+//! A playback runs in lockstep: the app clock advances exactly one fixed
+//! tick per frame ([`TimeUpdateStrategy::FixedTimesteps`]), so every frame of
+//! it is the same frame, per-frame systems included. On native it is held to
+//! real time by sleeping off what is left of each tick; on the web the
+//! browser's frame rate paces it.
+//!
+//! Seeking is re-simulation: a seek forward runs those same frames unpaced
+//! and without vsync until the target tick, a seek back reloads the scenario
+//! and does the same from tick 0. Nothing is skipped, so a seek lands on the
+//! target tick in the state straight playback reaches it in.
+
+use bevy::{
+    prelude::*,
+    time::TimeUpdateStrategy,
+    ui_widgets::Activate,
+    window::{PresentMode, PrimaryWindow},
+};
+use bevy_rand::prelude::*;
+use nova_assets::mod_set::MergedMods;
+use nova_events::prelude::EntityId;
+use nova_gameplay::prelude::*;
+use nova_os_ui::nova_os::prelude::NovaOsTerminal;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+use rand::SeedableRng as _;
+
+use super::{
+    format::{Replay, ReplayAutopilot, ReplayError, ReplayIntent, REPLAY_SCHEMA},
+    record::LastReplay,
+    ReplayLabels, ReplayTick,
+};
+
+/// Where the player is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReplayPhase {
+    /// No replay.
+    #[default]
+    Idle,
+    /// The scenario is loading for a playback (or a seek back).
+    Loading,
+    /// Flying the run.
+    Playing,
+}
+
+/// The run being played back, if any.
+#[derive(Resource, Default, Debug)]
+pub struct ReplayPlayback {
+    replay: Option<Replay>,
+    phase: ReplayPhase,
+    /// The keyframe last written, by index.
+    applied: Option<usize>,
+    /// The next command to re-issue, by index.
+    next_command: usize,
+    /// The tick a seek is running to.
+    seek_to: Option<u64>,
+}
+
+impl ReplayPlayback {
+    /// Whether a replay is loading or flying.
+    pub fn is_active(&self) -> bool {
+        self.phase != ReplayPhase::Idle
+    }
+
+    /// Where the player is.
+    pub fn phase(&self) -> ReplayPhase {
+        self.phase
+    }
+
+    /// The run being played.
+    pub fn replay(&self) -> Option<&Replay> {
+        self.replay.as_ref()
+    }
+
+    /// Whether a seek is running.
+    pub fn is_seeking(&self) -> bool {
+        self.seek_to.is_some()
+    }
+}
+
+/// What a playback borrowed from the app, to hand back when it ends: the
+/// clock's update strategy while it flies, the primary window's present mode
+/// while it seeks.
+#[derive(Resource, Default)]
+pub(crate) struct ReplayClock {
+    strategy: Option<TimeUpdateStrategy>,
+    present_mode: Option<PresentMode>,
+    /// When the last paced frame ended.
+    #[cfg(not(target_arch = "wasm32"))]
+    paced: Option<std::time::Instant>,
+}
+
+/// Playback stopped: the run reached its outcome or its last tick. The probe
+/// compares the world with the recorded snapshot here.
+#[derive(Event, Clone, Debug)]
+pub struct ReplayPlaybackEnded {
+    /// The scenario the run was flown in.
+    pub scenario: String,
+    /// The tick playback stopped on.
+    pub tick: u64,
+}
+
+/// Check `replay` can be played in this game and return the scenario to load.
+/// The mods must be the recorded ones in the recorded merge order: the same
+/// set merged in another order can overlay differently.
+pub fn replay_scenario(
+    replay: &Replay,
+    scenarios: &GameScenarios,
+    mods: Option<&MergedMods>,
+) -> Result<ScenarioConfig, ReplayError> {
+    if replay.schema != REPLAY_SCHEMA {
+        return Err(ReplayError::Schema {
+            found: replay.schema,
+        });
+    }
+    let Some(config) = scenarios.get(&replay.scenario) else {
+        return Err(ReplayError::UnknownScenario {
+            scenario: replay.scenario.clone(),
+        });
+    };
+    let enabled = mods.map(|mods| mods.0.clone()).unwrap_or_default();
+    if enabled != replay.mods {
+        return Err(ReplayError::ModMismatch {
+            recorded: replay.mods.clone(),
+            enabled,
+        });
+    }
+    let mut config = config.clone();
+    // Pinned on the template, so the load expands the recorded waves whatever
+    // seed the picker holds now.
+    if let (Some(survival), Some(seed)) = (config.survival.as_mut(), replay.survival_seed) {
+        survival.seed = Some(seed);
+    }
+    Ok(config)
+}
+
+/// The outcome overlay's Watch Replay button: play the last recorded run.
+pub(crate) fn on_watch_replay(
+    _activate: On<Activate>,
+    mut commands: Commands,
+    last: Res<LastReplay>,
+) {
+    let Some(replay) = last.0.clone() else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        if let Err(err) = watch_replay(world, replay) {
+            warn!("on_watch_replay: cannot play the replay: {err}");
+        }
+    });
+}
+
+/// Play `replay` from its first tick: validate it against this game, suspend
+/// the player's input, pin its tier, put the clock and the simulation in
+/// lockstep and load its scenario. What Watch Replay does with the last run,
+/// for a caller holding a run of its own (the probe's fuzzer playing back a
+/// trace).
+pub fn watch_replay(world: &mut World, replay: Replay) -> Result<(), ReplayError> {
+    let config = replay_scenario(
+        &replay,
+        world.resource::<GameScenarios>(),
+        world.get_resource::<MergedMods>(),
+    )?;
+    // Before the load, so the ships spawn under the recorded tier and no frame
+    // of the playback runs on the free clock; `drive_replay_clock` hands both
+    // back.
+    if let Some(mut pinned) = world.get_resource_mut::<DifficultyOverride>() {
+        pinned.0 = Some(replay.difficulty);
+    }
+    if let Some(mut policy) = world.get_resource_mut::<DifficultyPolicy>() {
+        policy.set_if_neq(DifficultyPolicy::for_difficulty(replay.difficulty));
+    }
+    if world.resource::<ReplayClock>().strategy.is_none() {
+        if let Some(mut strategy) = world.get_resource_mut::<TimeUpdateStrategy>() {
+            let saved = std::mem::replace(&mut *strategy, TimeUpdateStrategy::FixedTimesteps(1));
+            world.resource_mut::<ReplayClock>().strategy = Some(saved);
+        }
+    }
+    *world.resource_mut::<ReplayPlayback>() = ReplayPlayback {
+        replay: Some(replay),
+        phase: ReplayPhase::Loading,
+        ..default()
+    };
+    world.resource_mut::<PlayerInputSuspended>().0 = true;
+    world.resource_mut::<SimulationLockstep>().0 = true;
+    world.trigger(LoadScenario(config));
+    Ok(())
+}
+
+/// The load a playback asked for: reseed, rewind the tick, start flying. Any
+/// other load while flying means the world left the run (a scenario chained
+/// into another), so playback stops there.
+pub(crate) fn begin_playback(
+    loaded: On<ScenarioLoaded>,
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    mut suspended: ResMut<PlayerInputSuspended>,
+    mut rng: Single<&mut WyRand, With<GlobalRng>>,
+    mut tick: ResMut<ReplayTick>,
+) {
+    let Some(replay) = playback.replay.as_ref() else {
+        return;
+    };
+    match playback.phase {
+        ReplayPhase::Loading if loaded.scenario_id == replay.scenario => {
+            **rng = WyRand::seed_from_u64(replay.seed);
+            tick.0 = 0;
+            playback.applied = None;
+            playback.next_command = 0;
+            playback.phase = ReplayPhase::Playing;
+            debug!(
+                "begin_playback: playing '{}' ({} ticks)",
+                replay.scenario, replay.end_tick
+            );
+        }
+        ReplayPhase::Loading | ReplayPhase::Playing => {
+            let scenario = replay.scenario.clone();
+            playback.phase = ReplayPhase::Idle;
+            playback.seek_to = None;
+            suspended.0 = false;
+            commands.trigger(ReplayPlaybackEnded {
+                scenario,
+                tick: tick.0,
+            });
+        }
+        ReplayPhase::Idle => {}
+    }
+}
+
+/// Write the keyframe in force at this tick, once, at the top of the fixed
+/// tick - where the recorder read it.
+pub(crate) fn apply_replay_intent(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    tick: Res<ReplayTick>,
+    mut q_ship: Query<
+        (
+            Entity,
+            Option<&mut FlightIntent>,
+            Option<&mut RcsIntent>,
+            Option<&mut WeaponsRaised>,
+            Option<&mut CombatLock>,
+        ),
+        (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
+    >,
+    mut q_sections: Query<
+        (
+            &ChildOf,
+            Option<&EntityId>,
+            Option<&mut ControllerSectionRotationInput>,
+            Option<&mut TurretSectionInput>,
+            Option<&mut TorpedoSectionInput>,
+            Option<&mut ThrusterSectionInput>,
+            Has<SpaceshipThrusterInputBinding>,
+        ),
+        With<SectionMarker>,
+    >,
+    mut q_turrets: Query<
+        (
+            &ChildOf,
+            &mut TurretSectionTargetInput,
+            &mut TurretSectionTargetVelocity,
+            &mut TurretSectionTargetEntity,
+            Option<&PointDefenseMount>,
+            Option<&TurretDefenseTarget>,
+        ),
+        With<TurretSectionMarker>,
+    >,
+    labels: ReplayLabels,
+) {
+    if playback.phase != ReplayPhase::Playing {
+        return;
+    }
+    let Some(replay) = playback.replay.as_ref() else {
+        return;
+    };
+    let Some(index) = replay
+        .keyframes
+        .partition_point(|frame| frame.tick <= tick.0)
+        .checked_sub(1)
+    else {
+        return;
+    };
+    if playback.applied == Some(index) {
+        return;
+    }
+    let intent = &replay.keyframes[index].intent;
+    let previous = playback
+        .applied
+        .map(|applied| &replay.keyframes[applied].intent);
+    let Ok((ship, flight, rcs, raised, lock)) = q_ship.single_mut() else {
+        // Nothing to fly yet; try again next tick.
+        return;
+    };
+
+    if let Some(mut flight) = flight {
+        flight.burn = intent.burn;
+    }
+    match intent.rcs {
+        Some(command) => {
+            commands.entity(ship).insert(RcsActive);
+            if let Some(mut rcs) = rcs {
+                rcs.0 = Vec3::from_array(command);
+            }
+        }
+        None => {
+            commands.entity(ship).remove::<RcsActive>();
+            // Leaving RCS zeroes the held command, as the key release does.
+            if previous.is_some_and(|previous| previous.rcs.is_some()) {
+                if let Some(mut rcs) = rcs {
+                    rcs.0 = Vec3::ZERO;
+                }
+            }
+        }
+    }
+    if let Some(mut raised) = raised {
+        raised.set_if_neq(WeaponsRaised(intent.raised));
+    }
+    if let Some(mut lock) = lock {
+        lock.0 = intent
+            .lock
+            .as_deref()
+            .and_then(|label| labels.resolve(label));
+    }
+    // Re-engage only on a verb change: engaging resets the maneuver's phase,
+    // which the live one has moved on from.
+    if previous.map(|previous| &previous.autopilot) != Some(&intent.autopilot) {
+        engage_autopilot(&mut commands, ship, intent, &labels);
+    }
+
+    for (child_of, id, heading, turret, torpedo, thruster, bound) in &mut q_sections {
+        if child_of.parent() != ship {
+            continue;
+        }
+        if let (Some(mut heading), Some(recorded)) = (heading, intent.heading) {
+            heading.0 = Quat::from_array(recorded);
+        }
+        let held = id.is_some_and(|id| intent.fire.contains(&id.0));
+        if let Some(mut turret) = turret {
+            turret.set_if_neq(TurretSectionInput(held));
+        }
+        if let Some(mut torpedo) = torpedo {
+            torpedo.set_if_neq(TorpedoSectionInput(held));
+        }
+        // The key sets full thrust on press and clears it on release, and
+        // leaves it to whatever else writes it in between.
+        if let (Some(mut thruster), true, Some(id)) = (thruster, bound, id) {
+            if intent.thrust.contains(&id.0) {
+                thruster.0 = 1.0;
+            } else if previous.is_some_and(|previous| previous.thrust.contains(&id.0)) {
+                thruster.0 = 0.0;
+            }
+        }
+    }
+    if let Some(aim) = &intent.aim {
+        let target = aim
+            .target
+            .as_deref()
+            .and_then(|label| labels.resolve(label));
+        for (child_of, mut point, mut velocity, mut tracked, mount, assignment) in &mut q_turrets {
+            if child_of.parent() != ship || flight_computer_works(mount, assignment) {
+                continue;
+            }
+            point.0 = Some(Vec3::from_array(aim.point));
+            velocity.0 = Vec3::from_array(aim.velocity);
+            tracked.0 = target;
+        }
+    }
+    playback.applied = Some(index);
+}
+
+/// Put the recorded verb on the ship, or take the autopilot off.
+fn engage_autopilot(
+    commands: &mut Commands,
+    ship: Entity,
+    intent: &ReplayIntent,
+    labels: &ReplayLabels,
+) {
+    let Some(verb) = &intent.autopilot else {
+        commands.entity(ship).remove::<Autopilot>();
+        return;
+    };
+    let resolve = |label: &str| {
+        let entity = labels.resolve(label);
+        if entity.is_none() {
+            warn!("engage_autopilot: no '{label}' in the world to fly to");
+        }
+        entity
+    };
+    match verb {
+        ReplayAutopilot::Stop => {
+            commands
+                .entity(ship)
+                .insert(Autopilot::engage(AutopilotAction::Stop));
+        }
+        ReplayAutopilot::Goto { target, dock } => {
+            if let Some(target) = resolve(target) {
+                let mut ship = commands.entity(ship);
+                ship.insert(Autopilot::engage(AutopilotAction::Goto { target }));
+                if *dock {
+                    ship.insert(DockApproach { port: target });
+                }
+            }
+        }
+        ReplayAutopilot::GotoPos { position } => {
+            commands
+                .entity(ship)
+                .insert(Autopilot::engage(AutopilotAction::GotoPos {
+                    position: Vec3::from_array(*position),
+                }));
+        }
+        ReplayAutopilot::Orbit { well } => {
+            if let Some(well) = resolve(well) {
+                commands
+                    .entity(ship)
+                    .insert(Autopilot::engage(AutopilotAction::Orbit {
+                        well,
+                        plan: None,
+                    }));
+            }
+        }
+    }
+}
+
+/// Re-issue the recorded NOVA OS commands whose tick has passed, through the
+/// terminal's pending slot so the app that owns each one applies it exactly as
+/// it did when typed. The slot holds one; a busy slot waits a frame.
+pub(crate) fn replay_nova_os_commands(
+    mut playback: ResMut<ReplayPlayback>,
+    tick: Res<ReplayTick>,
+    terminal: Option<ResMut<NovaOsTerminal>>,
+) {
+    if playback.phase != ReplayPhase::Playing {
+        return;
+    }
+    let Some(mut terminal) = terminal else {
+        return;
+    };
+    let Some(replay) = playback.replay.as_ref() else {
+        return;
+    };
+    let Some(command) = replay.commands.get(playback.next_command) else {
+        return;
+    };
+    if command.tick > tick.0 {
+        return;
+    }
+    if terminal.replay_invocation(&command.name, command.args.clone()) {
+        playback.next_command += 1;
+    } else if terminal.peek_pending_invocation().is_none() {
+        warn!(
+            "replay_nova_os_commands: '{}' is not a gameplay command in this build; skipped",
+            command.name
+        );
+        playback.next_command += 1;
+    }
+}
+
+/// Stop when the run's outcome comes round again or its last tick is reached:
+/// hand the ship back and announce the end.
+pub(crate) fn finish_playback(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    mut suspended: ResMut<PlayerInputSuspended>,
+    outcome: Option<Res<CurrentOutcome>>,
+    tick: Res<ReplayTick>,
+) {
+    if playback.phase != ReplayPhase::Playing {
+        return;
+    }
+    let Some(replay) = playback.replay.as_ref() else {
+        return;
+    };
+    let decided = outcome.is_some_and(|outcome| outcome.0.is_some());
+    if !decided && tick.0 < replay.end_tick {
+        return;
+    }
+    let scenario = replay.scenario.clone();
+    debug!("finish_playback: '{scenario}' ended at tick {}", tick.0);
+    playback.phase = ReplayPhase::Idle;
+    playback.seek_to = None;
+    suspended.0 = false;
+    commands.trigger(ReplayPlaybackEnded {
+        scenario,
+        tick: tick.0,
+    });
+}
+
+/// Leaving play ends any playback without a verdict and returns the controls.
+pub(crate) fn stop_playback_on_exit(
+    mut playback: ResMut<ReplayPlayback>,
+    mut suspended: ResMut<PlayerInputSuspended>,
+) {
+    *playback = ReplayPlayback::default();
+    suspended.0 = false;
+}
+
+/// Run the playback to `target`: forward from here, back by reloading the
+/// scenario - through [`replay_scenario`], so the recorded survival seed is
+/// pinned again - and running from tick 0.
+pub(crate) fn seek_replay(
+    commands: &mut Commands,
+    playback: &mut ReplayPlayback,
+    scenarios: &GameScenarios,
+    mods: Option<&MergedMods>,
+    tick: u64,
+    target: u64,
+) {
+    let Some(replay) = playback.replay.as_ref() else {
+        return;
+    };
+    let target = target.min(replay.end_tick);
+    if target < tick {
+        let config = match replay_scenario(replay, scenarios, mods) {
+            Ok(config) => config,
+            Err(err) => {
+                warn!("seek_replay: cannot reload the replay: {err}");
+                return;
+            }
+        };
+        playback.phase = ReplayPhase::Loading;
+        commands.trigger(LoadScenario(config));
+    }
+    playback.seek_to = (target > 0).then_some(target);
+}
+
+/// End a seek on the frame its tick is reached.
+pub(crate) fn settle_seek(mut playback: ResMut<ReplayPlayback>, tick: Res<ReplayTick>) {
+    if playback.phase == ReplayPhase::Playing
+        && playback.seek_to.is_some_and(|target| tick.0 >= target)
+    {
+        playback.seek_to = None;
+    }
+}
+
+/// Last thing in the frame: turn vsync off for a seek and back on after it,
+/// pace a playback to real time, and hand the clock and the player's tier
+/// back once it has ended. A windowless app (tests, headless runs) is neither
+/// paced nor presented.
+pub(crate) fn drive_replay_clock(
+    playback: Res<ReplayPlayback>,
+    mut clock: ResMut<ReplayClock>,
+    strategy: Option<ResMut<TimeUpdateStrategy>>,
+    pinned: Option<ResMut<DifficultyOverride>>,
+    #[cfg(not(target_arch = "wasm32"))] fixed: Option<Res<Time<Fixed>>>,
+    window: Option<Single<&mut Window, With<PrimaryWindow>>>,
+) {
+    if !playback.is_active() {
+        if let (Some(saved), Some(mut strategy)) = (clock.strategy.take(), strategy) {
+            *strategy = saved;
+        }
+        if let Some(mut pinned) = pinned.filter(|pinned| pinned.0.is_some()) {
+            pinned.0 = None;
+        }
+    }
+    let Some(mut window) = window else {
+        return;
+    };
+    match (playback.is_seeking(), clock.present_mode) {
+        (true, None) => {
+            clock.present_mode = Some(std::mem::replace(
+                &mut window.present_mode,
+                PresentMode::AutoNoVsync,
+            ));
+        }
+        (false, Some(present_mode)) => {
+            window.present_mode = present_mode;
+            clock.present_mode = None;
+        }
+        _ => {}
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if playback.is_active() && !playback.is_seeking() {
+        pace_frame(&mut clock, fixed.map(|fixed| fixed.timestep()));
+    } else {
+        clock.paced = None;
+    }
+}
+
+/// Sleep off what is left of one fixed step since the last paced frame.
+#[cfg(not(target_arch = "wasm32"))]
+fn pace_frame(clock: &mut ReplayClock, step: Option<std::time::Duration>) {
+    let step = step.unwrap_or(std::time::Duration::from_micros(15_625));
+    if let Some(paced) = clock.paced {
+        if let Some(rest) = step.checked_sub(paced.elapsed()) {
+            std::thread::sleep(rest);
+        }
+    }
+    clock.paced = Some(std::time::Instant::now());
+}
@@ -0,0 +1,333 @@
+//! The recorder: samples the player's intent every fixed tick and keeps the
+//! ticks on which it changed.
+//!
+//! A recording starts when a scenario finishes loading in play (not during a
+//! replay - the player is not flying then) and ends when the outcome is
+//! declared, the scenario is unloaded or replaced, or play is left. Only the
+//! outcome ending is a FINISHED run: it is the one that triggers
+//! [`ReplayRecorded`], because only there is the world held still long enough
+//! to be compared with a playback of the same run.
+
+use bevy::prelude::*;
+use bevy_rand::prelude::*;
+use nova_assets::mod_set::MergedMods;
+use nova_events::prelude::EntityId;
+use nova_gameplay::prelude::*;
+use nova_os_ui::nova_os::prelude::NovaOsTerminal;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+use rand::{Rng as _, SeedableRng as _};
+
+use super::{
+    format::{
+        load_replay, quat_array, save_replay, vec3_array, Replay, ReplayAim, ReplayAutopilot,
+        ReplayCommand, ReplayIntent, ReplayKeyframe, REPLAY_SCHEMA,
+    },
+    playback::ReplayPlayback,
+    ReplayLabels, ReplayTick,
+};
+
+/// The run being recorded, if any.
+#[derive(Resource, Default, Debug)]
+pub struct ReplayRecorder(pub(crate) Option<Replay>);
+
+impl ReplayRecorder {
+    /// Whether a run is being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// The run recorded so far. Its `end_tick` is stamped only when the
+    /// recording closes, so it reads 0 here.
+    pub fn replay(&self) -> Option<&Replay> {
+        self.0.as_ref()
+    }
+}
+
+/// The last finished recording, kept in memory for the outcome overlay's
+/// Watch Replay and saved whenever it changes.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct LastReplay(pub Option<Replay>);
+
+/// A run was recorded to its outcome; [`LastReplay`] holds it. The probe
+/// stamps the world snapshot into it here.
+#[derive(Event, Clone, Debug)]
+pub struct ReplayRecorded;
+
+/// Start recording on every load in play, reseeding `GlobalRng` with a seed
+/// drawn from it so the run's randomness is the recorded seed's, and putting
+/// the simulation in [lockstep](nova_gameplay::lockstep) so the run can be
+/// flown again on the same ticks. A load that replaces a scenario
+/// mid-recording closes the old run first.
+pub(crate) fn start_recording(
+    loaded: On<ScenarioLoaded>,
+    game_state: Res<State<GameStates>>,
+    playback: Res<ReplayPlayback>,
+    mods: Option<Res<MergedMods>>,
+    difficulty: Option<Res<Difficulty>>,
+    mut rng: Single<&mut WyRand, With<GlobalRng>>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last: ResMut<LastReplay>,
+    mut tick: ResMut<ReplayTick>,
+    mut lockstep: ResMut<SimulationLockstep>,
+) {
+    if *game_state.get() != GameStates::Playing || playback.is_active() {
+        return;
+    }
+    close_recording(&mut recorder, &mut last, tick.0);
+    lockstep.0 = true;
+
+    let seed = rng.next_u64();
+    **rng = WyRand::seed_from_u64(seed);
+    let mods = mods.map(|mods| mods.0.clone()).unwrap_or_default();
+    tick.0 = 0;
+    recorder.0 = Some(Replay {
+        schema: REPLAY_SCHEMA,
+        scenario: loaded.scenario_id.clone(),
+        mods,
+        seed,
+        end_tick: 0,
+        keyframes: Vec::new(),
+        commands: Vec::new(),
+        snapshot: None,
+        survival_seed: loaded.survival_seed,
+        difficulty: difficulty.map(|difficulty| *difficulty).unwrap_or_default(),
+    });
+    debug!(
+        "start_recording: recording '{}' with seed {seed:#x}",
+        loaded.scenario_id
+    );
+}
+
+/// Sample the player's intent at the top of the fixed tick - what this tick's
+/// consumers are about to read - and keep it when it differs from the intent
+/// in force.
+pub(crate) fn record_player_intent(
+    mut recorder: ResMut<ReplayRecorder>,
+    tick: Res<ReplayTick>,
+    q_ship: Query<
+        (
+            Entity,
+            Option<&FlightIntent>,
+            Option<&RcsIntent>,
+            Has<RcsActive>,
+            Option<&WeaponsRaised>,
+            Option<&Autopilot>,
+            Option<&DockApproach>,
+            Option<&CombatLock>,
+        ),
+        (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
+    >,
+    q_sections: Query<
+        (
+            &ChildOf,
+            Option<&EntityId>,
+            Option<&ControllerSectionRotationInput>,
+            Option<&TurretSectionInput>,
+            Option<&TorpedoSectionInput>,
+            Option<&ThrusterSectionInput>,
+            Has<SpaceshipThrusterInputBinding>,
+        ),
+        With<SectionMarker>,
+    >,
+    q_turrets: Query<
+        (
+            &ChildOf,
+            &TurretSectionTargetInput,
+            &TurretSectionTargetVelocity,
+            &TurretSectionTargetEntity,
+            Option<&PointDefenseMount>,
+            Option<&TurretDefenseTarget>,
+        ),
+        With<TurretSectionMarker>,
+    >,
+    labels: ReplayLabels,
+) {
+    let Some(replay) = recorder.0.as_mut() else {
+        return;
+    };
+    let intent = match q_ship.single() {
+        Ok((ship, flight, rcs, rcs_active, raised, autopilot, approach, lock)) => {
+            let mut fire: Vec<String> = q_sections
+                .iter()
+                .filter(|(child_of, ..)| child_of.parent() == ship)
+                .filter(|(_, _, _, turret, torpedo, ..)| {
+                    turret.is_some_and(|turret| turret.0)
+                        || torpedo.is_some_and(|torpedo| torpedo.0)
+                })
+                .filter_map(|(_, id, ..)| id.map(|id| id.0.clone()))
+                .collect();
+            fire.sort();
+            // Only a key-bound thruster's input is the pilot's; any other is
+            // written by code that runs again on playback.
+            let mut thrust: Vec<String> = q_sections
+                .iter()
+                .filter(|(child_of, ..)| child_of.parent() == ship)
+                .filter(|(.., thruster, bound)| {
+                    *bound && thruster.is_some_and(|input| input.0 > 0.0)
+                })
+                .filter_map(|(_, id, ..)| id.map(|id| id.0.clone()))
+                .collect();
+            thrust.sort();
+            let heading = q_sections
+                .iter()
+                .filter(|(child_of, ..)| child_of.parent() == ship)
+                .find_map(|(_, _, heading, ..)| heading.map(|heading| quat_array(heading.0)));
+            let aim = q_turrets
+                .iter()
+                .filter(|(&ChildOf(parent), ..)| parent == ship)
+                .filter(|(.., mount, assignment)| !flight_computer_works(*mount, *assignment))
+                .find_map(|(_, point, velocity, tracked, ..)| {
+                    point.0.map(|point| ReplayAim {
+                        point: vec3_array(point),
+                        velocity: vec3_array(velocity.0),
+                        target: tracked.0.and_then(|tracked| labels.label(tracked)),
+                    })
+                });
+            ReplayIntent {
+                burn: flight.map_or(0.0, |flight| flight.burn),
+                rcs: rcs_active.then(|| vec3_array(rcs.map_or(Vec3::ZERO, |rcs| rcs.0))),
+                heading,
+                raised: raised.is_some_and(|raised| raised.0),
+                autopilot: autopilot
+                    .and_then(|autopilot| autopilot_verb(autopilot.action, approach, &labels)),
+                lock: lock
+                    .and_then(|lock| lock.0)
+                    .and_then(|lock| labels.label(lock)),
+                fire,
+                thrust,
+                aim,
+            }
+        }
+        // No ship (not spawned yet, or destroyed): nothing is being flown.
+        Err(_) => ReplayIntent::default(),
+    };
+    if replay
+        .keyframes
+        .last()
+        .is_none_or(|last| last.intent != intent)
+    {
+        replay.keyframes.push(ReplayKeyframe {
+            tick: tick.0,
+            intent,
+        });
+    }
+}
+
+/// An engaged verb, named. `None` when its target has no name to find it by
+/// again - a replay of that verb would fly somewhere else.
+fn autopilot_verb(
+    action: AutopilotAction,
+    approach: Option<&DockApproach>,
+    labels: &ReplayLabels,
+) -> Option<ReplayAutopilot> {
+    match action {
+        AutopilotAction::Stop => Some(ReplayAutopilot::Stop),
+        AutopilotAction::Goto { target } => {
+            labels.label(target).map(|label| ReplayAutopilot::Goto {
+                target: label,
+                dock: approach.is_some_and(|approach| approach.port == target),
+            })
+        }
+        AutopilotAction::GotoPos { position } => Some(ReplayAutopilot::GotoPos {
+            position: vec3_array(position),
+        }),
+        AutopilotAction::Orbit { well, .. } => labels
+            .label(well)
+            .map(|label| ReplayAutopilot::Orbit { well: label }),
+    }
+}
+
+/// Record each NOVA OS gameplay command as it is submitted: peeked between the
+/// terminal's input and the apps that consume it, the same frame it was typed.
+/// Commands are typed with the clocks frozen, so the tick is exact.
+pub(crate) fn record_nova_os_commands(
+    mut recorder: ResMut<ReplayRecorder>,
+    tick: Res<ReplayTick>,
+    terminal: Option<Res<NovaOsTerminal>>,
+    mut was_pending: Local<bool>,
+) {
+    let pending = terminal
+        .as_ref()
+        .and_then(|terminal| terminal.peek_pending_invocation());
+    // A pending invocation nobody takes lingers; record it once.
+    let fresh = pending.is_some() && !*was_pending;
+    *was_pending = pending.is_some();
+    let (Some(replay), Some(invocation), true) = (recorder.0.as_mut(), pending, fresh) else {
+        return;
+    };
+    replay.commands.push(ReplayCommand {
+        tick: tick.0,
+        name: invocation.name.to_string(),
+        args: invocation.args.clone(),
+    });
+}
+
+/// A declared outcome finishes the run: keep it, save it and announce it.
+pub(crate) fn finish_recording_on_outcome(
+    mut commands: Commands,
+    outcome: Res<CurrentOutcome>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last: ResMut<LastReplay>,
+    tick: Res<ReplayTick>,
+) {
+    if !outcome.is_changed() || outcome.0.is_none() || !recorder.is_recording() {
+        return;
+    }
+    close_recording(&mut recorder, &mut last, tick.0);
+    commands.trigger(ReplayRecorded);
+}
+
+/// An unload closes the run where it stands.
+pub(crate) fn finish_recording_on_unload(
+    _: On<UnloadScenario>,
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last: ResMut<LastReplay>,
+    tick: Res<ReplayTick>,
+) {
+    close_recording(&mut recorder, &mut last, tick.0);
+}
+
+/// Leaving play closes the run where it stands.
+pub(crate) fn finish_recording_on_exit(
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last: ResMut<LastReplay>,
+    tick: Res<ReplayTick>,
+) {
+    close_recording(&mut recorder, &mut last, tick.0);
+}
+
+/// Stamp the end tick on the run in progress, if any, and make it the last
+/// replay.
+fn close_recording(recorder: &mut ReplayRecorder, last: &mut LastReplay, tick: u64) {
+    let Some(mut replay) = recorder.0.take() else {
+        return;
+    };
+    replay.end_tick = tick;
+    debug!(
+        "close_recording: '{}' recorded, {} tick(s), {} keyframe(s), {} command(s)",
+        replay.scenario,
+        replay.end_tick,
+        replay.keyframes.len(),
+        replay.commands.len()
+    );
+    last.0 = Some(replay);
+}
+
+/// Startup: restore the last saved run, if any.
+pub(crate) fn load_persisted_replay(mut last: ResMut<LastReplay>) {
+    if let Some(saved) = load_replay() {
+        last.0 = Some(saved);
+    }
+}
+
+/// Save the last replay whenever it changes: a closed recording, or the probe
+/// stamping its snapshot in. The startup default is skipped - it is empty.
+pub(crate) fn persist_last_replay(last: Res<LastReplay>) {
+    if !last.is_changed() || last.is_added() {
+        return;
+    }
+    if let Some(replay) = &last.0 {
+        save_replay(replay);
+    }
+}
@@ -0,0 +1,237 @@
+//! The spectator: how a human watches a playback.
+//!
+//! While a replay flies the ship the keyboard is free, so it drives the view
+//! and the timeline instead:
+//!
+//! | Key | Does |
+//! |-----|------|
+//! | `C` | swap the scenario camera between the chase rig and the free camera |
+//! | `,` / `.` | seek back / forward [`REPLAY_SEEK_SECS`] |
+//! | `Home` | restart from the first tick |
+//!
+//! A chip bar along the bottom edge shows where the playback is:
+//!
+//! ```text
+//! REPLAY 01:12.4 / 03:40.0
+//! [C] CAMERA  [,/.] -/+10s  [HOME] RESTART
+//! ```
+//!
+//! The position chip reads amber while a seek is running.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use nova_assets::mod_set::MergedMods;
+use nova_gameplay::prelude::*;
+use nova_hud::prelude::HudReadoutFormat;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+use nova_ui::hud::{chip_node, chip_paint, ChipTone};
+
+use super::{
+    playback::{seek_replay, ReplayPlayback},
+    ReplayTick,
+};
+
+/// How far one `,` / `.` press seeks, in seconds.
+pub const REPLAY_SEEK_SECS: f32 = 10.0;
+
+/// The fixed step the tick counts when the app has no fixed clock to ask
+/// (the engine default, 64 Hz).
+const DEFAULT_TICK: Duration = Duration::from_micros(15_625);
+
+/// Spectator bar font size (px), the race timer's.
+const REPLAY_BAR_FONT_SIZE_PX: f32 = 18.0;
+
+/// The bar's container (bottom-center).
+#[derive(Component)]
+pub(crate) struct ReplayBarMarker;
+
+/// The bar's position chip.
+#[derive(Component)]
+struct ReplayBarPosition;
+
+/// The keys a playback answers, read while it is loading or flying.
+pub(crate) fn spectator_input(
+    mut commands: Commands,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    pause: Res<State<PauseStates>>,
+    mut playback: ResMut<ReplayPlayback>,
+    fixed: Option<Res<Time<Fixed>>>,
+    scenarios: Option<Res<GameScenarios>>,
+    mods: Option<Res<MergedMods>>,
+    tick: Res<ReplayTick>,
+    camera: Option<Single<(Entity, Has<SpaceshipCameraController>), With<ScenarioCameraMarker>>>,
+    q_player: Query<(), With<PlayerSpaceshipMarker>>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+    // The NOVA OS owns the keyboard while it is open.
+    if !playback.is_active() || *pause.get() == PauseStates::NovaOs {
+        return;
+    }
+    if keys.just_pressed(KeyCode::KeyC) {
+        if let Some((camera, chasing)) = camera.map(|camera| camera.into_inner()) {
+            if chasing {
+                commands
+                    .entity(camera)
+                    .remove::<SpaceshipCameraController>()
+                    .insert(WASDCameraController);
+            } else if !q_player.is_empty() {
+                commands
+                    .entity(camera)
+                    .remove::<WASDCameraController>()
+                    .insert(SpaceshipCameraController);
+            }
+        }
+    }
+    let step = seek_ticks(fixed.as_deref());
+    let target = if keys.just_pressed(KeyCode::Home) {
+        Some(0)
+    } else if keys.just_pressed(KeyCode::Comma) {
+        Some(tick.0.saturating_sub(step))
+    } else if keys.just_pressed(KeyCode::Period) {
+        Some(tick.0 + step)
+    } else {
+        None
+    };
+    if let (Some(target), Some(scenarios)) = (target, scenarios) {
+        seek_replay(
+            &mut commands,
+            &mut playback,
+            &scenarios,
+            mods.as_deref(),
+            tick.0,
+            target,
+        );
+    }
+}
+
+/// Ticks in one [`REPLAY_SEEK_SECS`] seek.
+fn seek_ticks(fixed: Option<&Time<Fixed>>) -> u64 {
+    let step = fixed.map_or(DEFAULT_TICK, Time::<Fixed>::timestep);
+    (REPLAY_SEEK_SECS / step.as_secs_f32()).round() as u64
+}
+
+/// Seconds at `tick`.
+fn tick_secs(tick: u64, fixed: Option<&Time<Fixed>>) -> f64 {
+    tick as f64
+        * fixed
+            .map_or(DEFAULT_TICK, Time::<Fixed>::timestep)
+            .as_secs_f64()
+}
+
+/// `REPLAY 01:12.4 / 03:40.0`.
+fn position_text(secs: f64, end_secs: f64) -> String {
+    format!(
+        "REPLAY {} / {}",
+        HudReadoutFormat::Time.render(secs),
+        HudReadoutFormat::Time.render(end_secs)
+    )
+}
+
+/// Spawn the bar when a playback starts, keep its position current, and
+/// despawn it when playback stops.
+pub(crate) fn sync_replay_bar(
+    mut commands: Commands,
+    playback: Res<ReplayPlayback>,
+    tick: Res<ReplayTick>,
+    fixed: Option<Res<Time<Fixed>>>,
+    q_bar: Query<Entity, With<ReplayBarMarker>>,
+    mut q_position: Query<
+        (&mut Text, &mut TextColor, &mut BackgroundColor),
+        With<ReplayBarPosition>,
+    >,
+) {
+    let replay = playback.replay().filter(|_| playback.is_active());
+    let Some(replay) = replay else {
+        for bar in &q_bar {
+            commands.entity(bar).despawn();
+        }
+        return;
+    };
+    let fixed = fixed.as_deref();
+    let shown = position_text(
+        tick_secs(tick.0.min(replay.end_tick), fixed),
+        tick_secs(replay.end_tick, fixed),
+    );
+    let tone = if playback.is_seeking() {
+        ChipTone::Amber
+    } else {
+        ChipTone::Phosphor
+    };
+    if q_bar.is_empty() {
+        spawn_replay_bar(&mut commands, shown);
+        return;
+    }
+    for (mut text, mut color, mut fill) in &mut q_position {
+        if text.0 != shown {
+            text.0.clone_from(&shown);
+        }
+        if color.0 != tone.text() {
+            color.0 = tone.text();
+            fill.0 = tone.fill();
+        }
+    }
+}
+
+/// The bar: the position chip over the key hints.
+fn spawn_replay_bar(commands: &mut Commands, position: String) {
+    commands
+        .spawn((
+            Name::new("Replay Bar"),
+            ReplayBarMarker,
+            DespawnOnExit(GameStates::Playing),
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(24.0),
+                left: Val::Percent(50.0),
+                margin: UiRect {
+                    left: Val::Px(-200.0),
+                    ..default()
+                },
+                width: Val::Px(400.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Name::new("Replay Position"),
+                ReplayBarPosition,
+                Text::new(position),
+                TextFont::from_font_size(REPLAY_BAR_FONT_SIZE_PX),
+                chip_node(),
+                chip_paint(ChipTone::Phosphor),
+                TextColor(ChipTone::Phosphor.text()),
+            ));
+            parent.spawn((
+                Name::new("Replay Keys"),
+                Text::new("[C] CAMERA  [,/.] -/+10s  [HOME] RESTART"),
+                TextFont::from_font_size(REPLAY_BAR_FONT_SIZE_PX * 0.7),
+                chip_node(),
+                chip_paint(ChipTone::Phosphor),
+                TextColor(ChipTone::Phosphor.text()),
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The bar reads as the module docs show it, on the engine's 64 Hz step.
+    #[test]
+    fn the_position_reads_as_documented() {
+        let fixed = Time::<Fixed>::from_hz(64.0);
+        assert_eq!(seek_ticks(Some(&fixed)), 640);
+        assert_eq!(seek_ticks(None), 640);
+        assert_eq!(
+            position_text(tick_secs(4634, Some(&fixed)), tick_secs(14080, None)),
+            "REPLAY 01:12.4 / 03:40.0"
+        );
+    }
+}
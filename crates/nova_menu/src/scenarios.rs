@@ -7,13 +7,19 @@ use bevy::{
     prelude::*,
     ui_widgets::{observe, Activate, Button},
 };
+use bevy_rand::prelude::*;
 use nova_gameplay::prelude::*;
+use nova_hud::prelude::HudReadoutFormat;
 use nova_scenario::prelude::*;
 use nova_ui::{
     prelude::UiSkin,
     theme,
-    widget::{list_row, separator, themed_button, ListRow, Selected, ThemedButton, UiText},
+    widget::{
+        list_row, segmented_container, segmented_option, separator, themed_button, ButtonValue,
+        ListRow, Selected, ThemedButton, UiText,
+    },
 };
+use rand::Rng as _;
 
 /// Marker for the Scenarios panel root, toggled by the Scenarios button.
 #[derive(Component)]
@@ -83,6 +89,11 @@ pub(crate) struct CampaignHeader {
 #[derive(Component)]
 pub(crate) struct ScenarioDetailsPanel;
 
+/// The survival high-score table in the details pane: the scenario it lists.
+/// `refresh_survival_scores` swaps its rows when the tier changes.
+#[derive(Component)]
+pub(crate) struct SurvivalScoresTable(ScenarioId);
+
 /// The scenario details pane's action area (holds the Play button). Kept as a
 /// stable marker so the container exists in every state.
 #[derive(Component)]
@@ -130,8 +141,13 @@ pub(crate) fn scenarios_list_dirty(
 pub(crate) fn scenario_details_dirty(
     scenarios: Option<Res<GameScenarios>>,
     selected: Res<SelectedScenarioId>,
+    seed: Res<SurvivalSeed>,
+    scores: Res<SurvivalScores>,
 ) -> bool {
-    scenarios.is_some_and(|s| s.is_changed()) || selected.is_changed()
+    scenarios.is_some_and(|s| s.is_changed())
+        || selected.is_changed()
+        || seed.is_changed()
+        || scores.is_changed()
 }
 
 /// Campaigns in a stable display order: by display name, then id.
@@ -409,6 +425,10 @@ pub(crate) fn refresh_scenario_details(
     images: Option<Res<Assets<Image>>>,
     mut pending_thumb: ResMut<PendingScenarioThumbnail>,
     panels: Query<Entity, With<ScenarioDetailsPanel>>,
+    skin: Res<UiSkin>,
+    difficulty: Res<Difficulty>,
+    seed: Res<SurvivalSeed>,
+    scores: Res<SurvivalScores>,
 ) {
     let Ok(panel) = panels.single() else {
         return;
@@ -511,6 +531,30 @@ pub(crate) fn refresh_scenario_details(
                 },
             ));
         }
+        // The difficulty is chosen here, at scenario start, and sticks for the
+        // next one: a segmented row wired through `ButtonValue<Difficulty>` and
+        // the app-global `button_on_setting::<Difficulty>`, like the Graphics
+        // row on the Settings page. Persisted with the settings.
+        details
+            .spawn((
+                Name::new("Scenario Difficulty Row"),
+                segmented_container(*skin),
+            ))
+            .with_children(|row| {
+                for tier in Difficulty::ALL {
+                    let mut button = row.spawn((
+                        Name::new(format!("Difficulty {}", tier.label())),
+                        segmented_option(tier.label()),
+                        ButtonValue(tier),
+                    ));
+                    if tier == *difficulty {
+                        button.insert(Selected);
+                    }
+                }
+            });
+        if let Some(survival) = &scenario.survival {
+            survival_details(details, &scenario.id, survival, &seed, &scores, *difficulty);
+        }
         details
             .spawn((
                 Name::new("Scenario Details Actions"),
@@ -540,6 +584,143 @@ pub(crate) fn refresh_scenario_details(
     });
 }
 
+/// The survival rows of the details pane: the seed the waves will be built
+/// from, with its Reroll, and the scenario's high-score table at the picked
+/// tier. An authored seed pins every run, so it shows without a Reroll.
+fn survival_details(
+    details: &mut ChildSpawnerCommands,
+    scenario: &str,
+    survival: &SurvivalConfig,
+    seed: &SurvivalSeed,
+    scores: &SurvivalScores,
+    difficulty: Difficulty,
+) {
+    details
+        .spawn((
+            Name::new("Survival Seed Row"),
+            Node {
+                column_gap: px(12),
+                align_items: AlignItems::Center,
+                margin: UiRect::vertical(px(8)),
+                ..default()
+            },
+        ))
+        .with_children(|row| {
+            let shown = survival.seed.or(seed.0);
+            row.spawn((
+                Name::new("Survival Seed"),
+                survival_line(match shown {
+                    Some(seed) => format!("SEED {seed:016X}"),
+                    None => "SEED -".to_string(),
+                }),
+            ));
+            if survival.seed.is_none() {
+                row.spawn((
+                    Name::new("Survival Reroll Button"),
+                    themed_button("Reroll"),
+                    observe(on_survival_reroll),
+                ));
+            }
+        });
+    details.spawn((
+        Name::new("Survival Scores Heading"),
+        survival_line(format!("BEST RUNS - {} WAVES", survival.waves)),
+    ));
+    details
+        .spawn((
+            Name::new("Survival Scores"),
+            SurvivalScoresTable(scenario.to_string()),
+            Node {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+        ))
+        .with_children(|rows| survival_score_rows(rows, &scores.table(scenario, difficulty)));
+}
+
+/// Re-list the survival table at the newly picked tier. The difficulty row
+/// moves its highlight in place, so only the table is rebuilt, not the pane.
+pub(crate) fn refresh_survival_scores(
+    mut commands: Commands,
+    tables: Query<(Entity, &SurvivalScoresTable)>,
+    difficulty: Res<Difficulty>,
+    scores: Res<SurvivalScores>,
+) {
+    for (entity, table) in &tables {
+        commands
+            .entity(entity)
+            .despawn_related::<Children>()
+            .with_children(|rows| {
+                survival_score_rows(rows, &scores.table(&table.0, *difficulty));
+            });
+    }
+}
+
+/// The rows of one high-score table, or a placeholder when it is empty.
+fn survival_score_rows(rows: &mut ChildSpawnerCommands, table: &[&SurvivalScore]) {
+    if table.is_empty() {
+        rows.spawn((
+            Name::new("Survival Scores Empty"),
+            Text::new("No runs yet."),
+            TextFont {
+                font_size: FontSize::Px(14.0),
+                ..default()
+            },
+            TextColor(theme::PHOSPHOR_MUTED),
+        ));
+    }
+    for (place, score) in table.iter().enumerate() {
+        rows.spawn((
+            Name::new(format!("Survival Score {}", place + 1)),
+            survival_line(survival_score_line(place, score)),
+        ));
+    }
+}
+
+/// One line of the survival rows.
+fn survival_line(text: String) -> impl Bundle {
+    (
+        Text::new(text),
+        TextFont {
+            font_size: FontSize::Px(14.0),
+            ..default()
+        },
+        TextColor(theme::SCREEN_TEXT),
+    )
+}
+
+/// One high-score row: place, waves, time, tier and seed.
+fn survival_score_line(place: usize, score: &SurvivalScore) -> String {
+    format!(
+        "{}. {} WAVES  {}  {}  {:016X}",
+        place + 1,
+        score.waves,
+        HudReadoutFormat::Time.render(score.time),
+        score.difficulty.label().to_uppercase(),
+        score.seed
+    )
+}
+
+/// Draw the survival seed the picker offers, the first time there is none.
+/// Drawn from `GlobalRng`, so a session's seeds follow the app's RNG.
+pub(crate) fn draw_survival_seed(
+    mut seed: ResMut<SurvivalSeed>,
+    mut rng: Single<&mut WyRand, With<GlobalRng>>,
+) {
+    if seed.0.is_none() {
+        seed.0 = Some(rng.next_u64());
+    }
+}
+
+/// The Reroll button: draw a fresh seed, which re-arms the details refresh.
+pub(crate) fn on_survival_reroll(
+    _activate: On<Activate>,
+    mut seed: ResMut<SurvivalSeed>,
+    mut rng: Single<&mut WyRand, With<GlobalRng>>,
+) {
+    seed.0 = Some(rng.next_u64());
+}
+
 /// While the selected scenario's thumbnail is still loading (parked in
 /// [`PendingScenarioThumbnail`] by `refresh_scenario_details`), re-arm that
 /// refresh the moment the image finishes loading, so it can validate the
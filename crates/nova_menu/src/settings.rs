@@ -17,7 +17,11 @@ use nova_ui::{
     },
 };
 
-use crate::settings_store::{load_settings, save_settings, PersistedSettings};
+use crate::{
+    accessibility::build_accessibility_body,
+    controls::{build_controls_body, KeymapCapture},
+    settings_store::{load_settings, save_settings, PersistedSettings},
+};
 
 /// Marker for the main-menu Settings panel, toggled by the Settings button.
 #[derive(Component)]
@@ -31,18 +35,22 @@ pub(crate) struct PauseSettingsPanel;
 pub(crate) fn on_settings(
     _activate: On<Activate>,
     mut panel: Single<&mut Visibility, With<SettingsPanel>>,
+    mut capture: ResMut<KeymapCapture>,
 ) {
     **panel = match **panel {
         Visibility::Hidden => Visibility::Visible,
         _ => Visibility::Hidden,
     };
+    capture.set_if_neq(KeymapCapture::default());
 }
 
 pub(crate) fn on_settings_back(
     _activate: On<Activate>,
     mut panel: Single<&mut Visibility, With<SettingsPanel>>,
+    mut capture: ResMut<KeymapCapture>,
 ) {
     **panel = Visibility::Hidden;
+    capture.set_if_neq(KeymapCapture::default());
 }
 
 /// The master-volume [`Slider`] entity (bevy's headless slider widget), so the
@@ -54,6 +62,14 @@ pub(crate) struct VolumeSlider;
 #[derive(Component)]
 pub(crate) struct VolumeLabel;
 
+/// A per-bus volume [`Slider`] entity, the bus twin of [`VolumeSlider`].
+#[derive(Component)]
+pub(crate) struct BusVolumeSlider(pub(crate) AudioBus);
+
+/// The readout beside a per-bus volume slider.
+#[derive(Component)]
+pub(crate) struct BusVolumeLabel(pub(crate) AudioBus);
+
 /// Format a linear volume factor as a whole-percent label.
 pub(crate) fn volume_label(value: f32) -> String {
     format!("{}%", (value.clamp(0.0, 1.0) * 100.0).round() as i32)
@@ -63,8 +79,8 @@ pub(crate) fn volume_label(value: f32) -> String {
 // `segmented_option` (the same helpers the widget_zoo uses); the caller adds the
 // `ButtonValue<T>` + `Selected` that `button_on_setting` drives.
 
-/// Build the shared settings body (audio volume, graphics preset, read-only
-/// keybind reference) under `list`. Used by BOTH the main-menu Settings overlay
+/// Build the shared settings body (audio volume, graphics preset, the
+/// Controls keymap, the UI skin, accessibility) under `list`. Used by BOTH the main-menu Settings overlay
 /// and the pause-menu Settings overlay so the two entry points stay one modal
 /// (user note 2026-07-16). Selection highlights are seeded from the current
 /// resource values; presses are handled by the app-global
@@ -72,16 +88,131 @@ pub(crate) fn volume_label(value: f32) -> String {
 pub(crate) fn build_settings_body(
     list: &mut ChildSpawnerCommands,
     volume: MasterVolume,
+    buses: BusVolumes,
     quality: GraphicsQuality,
     skin: UiSkin,
+    keymap: &Keymap,
+    accessibility: AccessibilitySettings,
 ) {
-    // AUDIO - master volume as a draggable slider (bevy's headless `Slider`;
-    // drag handling comes from `UiWidgetsPlugins` in DefaultPlugins, the value
-    // is committed by `slider_self_update` and mirrored to `MasterVolume` by
-    // `on_volume_slider_change`, both registered in the plugin).
+    // AUDIO - master volume and one slider per mixer bus (bevy's headless
+    // `Slider`; drag handling comes from `UiWidgetsPlugins` in DefaultPlugins,
+    // the value is committed by `slider_self_update` and mirrored to
+    // `MasterVolume` / `BusVolumes` by `on_volume_slider_change`, both
+    // registered in the plugin).
     list.spawn(panel_header("Audio"));
+    spawn_volume_row(
+        list,
+        "Volume",
+        volume.factor(),
+        skin,
+        VolumeSlider,
+        VolumeLabel,
+    );
+    for bus in AudioBus::ALL {
+        spawn_volume_row(
+            list,
+            bus.label(),
+            buses.factor(bus),
+            skin,
+            BusVolumeSlider(bus),
+            BusVolumeLabel(bus),
+        );
+    }
+
+    list.spawn(separator());
+
+    // GRAPHICS - the quality preset. Each tier drives the combat juice today; the low-
+    // end mode extends what Low/Medium skip.
+    list.spawn(panel_header("Graphics"));
+    list.spawn((Name::new("Graphics Row"), segmented_container(skin)))
+        .with_children(|row| {
+            for tier in GraphicsQuality::ALL {
+                let mut button = row.spawn((
+                    Name::new(format!("Graphics {}", tier.label())),
+                    segmented_option(tier.label()),
+                    ButtonValue(tier),
+                ));
+                if tier == quality {
+                    button.insert(Selected);
+                }
+            }
+        });
+
+    list.spawn(separator());
+
+    // CONTROLS - the remappable keymap, then the fixed controls (controls.rs).
+    list.spawn(panel_header("Controls"));
+    build_controls_body(list, keymap);
+
+    list.spawn(separator());
+
+    // INTERFACE - the UI skin choice. A segmented Phosphor|Hardware control
+    // wired through `ButtonValue<UiSkin>` + the app-global
+    // `button_on_setting::<UiSkin>` observer, exactly like GRAPHICS above.
+    list.spawn(panel_header("Interface"));
+    list.spawn((Name::new("UI Skin Row"), segmented_container(skin)))
+        .with_children(|row| {
+            for option in [UiSkin::Phosphor, UiSkin::Hardware] {
+                let label = match option {
+                    UiSkin::Phosphor => "Phosphor",
+                    UiSkin::Hardware => "Hardware",
+                };
+                let mut button = row.spawn((
+                    Name::new(format!("UI Skin {label}")),
+                    segmented_option(label),
+                    ButtonValue(option),
+                ));
+                if option == skin {
+                    button.insert(Selected);
+                }
+            }
+        });
+
+    list.spawn(separator());
+
+    // ACCESSIBILITY - palette, motion, text size, comms dwell (accessibility.rs).
+    build_accessibility_body(list, accessibility, skin);
+}
+
+/// One labelled volume slider row over `0..=1`, read out as a percent.
+fn spawn_volume_row(
+    list: &mut ChildSpawnerCommands,
+    caption: &str,
+    value: f32,
+    skin: UiSkin,
+    slider: impl Component,
+    label: impl Component,
+) {
+    spawn_slider_row(
+        list,
+        caption,
+        value,
+        SliderRange::new(0.0, 1.0),
+        0.05,
+        volume_label(value),
+        skin,
+        slider,
+        label,
+    );
+}
+
+/// One labelled slider row: the caption, the slider over `range` in `step`s
+/// (tagged `slider`) and its `readout` (tagged `label`, kept current by the
+/// owning sync system).
+pub(crate) fn spawn_slider_row(
+    list: &mut ChildSpawnerCommands,
+    caption: &str,
+    value: f32,
+    range: SliderRange,
+    step: f32,
+    readout: String,
+    skin: UiSkin,
+    slider: impl Component,
+    label: impl Component,
+) {
+    let fraction = range.thumb_position(value);
     list.spawn((
-        Name::new("Volume Row"),
+        Name::new(format!("{caption} Row")),
         Node {
             width: percent(100),
             flex_direction: FlexDirection::Row,
@@ -93,9 +224,9 @@ pub(crate) fn build_settings_body(
     ))
     .with_children(|row| {
         row.spawn((
-            Name::new("Volume Slider"),
+            Name::new(format!("{caption} Slider")),
             UiText,
-            Text::new("Volume"),
+            Text::new(caption.to_string()),
             TextFont {
                 font_size: FontSize::Px(13.0),
                 ..default()
@@ -117,23 +248,23 @@ pub(crate) fn build_settings_body(
         })
         .with_children(|cell| {
             cell.spawn((
-                Name::new("Volume Slider Track"),
-                VolumeSlider,
+                Name::new(format!("{caption} Slider Track")),
+                slider,
                 Slider {
                     track_click: TrackClick::Snap,
                     ..default()
                 },
-                SliderValue(volume.factor()),
-                SliderRange::new(0.0, 1.0),
-                SliderStep(0.05),
-                slider_track(volume.factor(), skin),
+                SliderValue(value),
+                range,
+                SliderStep(step),
+                slider_track(fraction, skin),
             ));
         });
         row.spawn((
-            Name::new("Volume Label"),
-            VolumeLabel,
+            Name::new(format!("{caption} Label")),
+            label,
             UiText,
-            Text::new(volume_label(volume.factor())),
+            Text::new(readout),
             TextFont {
                 font_size: FontSize::Px(13.0),
                 ..default()
@@ -145,75 +276,6 @@ pub(crate) fn build_settings_body(
             },
         ));
     });
-
-    list.spawn(separator());
-
-    // GRAPHICS - the quality preset. Each tier drives the combat juice today; the low-
-    // end mode extends what Low/Medium skip.
-    list.spawn(panel_header("Graphics"));
-    list.spawn((Name::new("Graphics Row"), segmented_container(skin)))
-        .with_children(|row| {
-            for tier in GraphicsQuality::ALL {
-                let mut button = row.spawn((
-                    Name::new(format!("Graphics {}", tier.label())),
-                    segmented_option(tier.label()),
-                    ButtonValue(tier),
-                ));
-                if tier == quality {
-                    button.insert(Selected);
-                }
-            }
-        });
-
-    list.spawn(separator());
-
-    // CONTROLS - a read-only reference of the current bindings.
-    list.spawn(panel_header("Controls"));
-    let mut current_section = "";
-    for entry in keybind_reference() {
-        if entry.section != current_section {
-            current_section = entry.section;
-            list.spawn((
-                Name::new(format!("Controls Section: {}", entry.section)),
-                UiText,
-                Text::new(entry.section),
-                TextFont {
-                    font_size: FontSize::Px(11.0),
-                    ..default()
-                },
-                TextColor(theme::PHOSPHOR_MUTED),
-                Node {
-                    margin: UiRect::top(px(6)),
-                    ..default()
-                },
-            ));
-        }
-        spawn_keybind_row(list, entry);
-    }
-
-    list.spawn(separator());
-
-    // INTERFACE - the UI skin choice. A segmented Phosphor|Hardware control
-    // wired through `ButtonValue<UiSkin>` + the app-global
-    // `button_on_setting::<UiSkin>` observer, exactly like GRAPHICS above.
-    list.spawn(panel_header("Interface"));
-    list.spawn((Name::new("UI Skin Row"), segmented_container(skin)))
-        .with_children(|row| {
-            for option in [UiSkin::Phosphor, UiSkin::Hardware] {
-                let label = match option {
-                    UiSkin::Phosphor => "Phosphor",
-                    UiSkin::Hardware => "Hardware",
-                };
-                let mut button = row.spawn((
-                    Name::new(format!("UI Skin {label}")),
-                    segmented_option(label),
-                    ButtonValue(option),
-                ));
-                if option == skin {
-                    button.insert(Selected);
-                }
-            }
-        });
 }
 
 /// Load the persisted settings once at startup and write them into the live
@@ -223,17 +285,30 @@ pub(crate) fn build_settings_body(
 /// first frame.
 pub(crate) fn load_persisted_settings(
     mut volume: ResMut<MasterVolume>,
+    mut buses: ResMut<BusVolumes>,
     mut quality: ResMut<GraphicsQuality>,
     mut skin: ResMut<UiSkin>,
     mut monitor: ResMut<NovaOsMonitorSettings>,
+    mut keymap: ResMut<Keymap>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+    mut difficulty: ResMut<Difficulty>,
 ) {
     let Some(saved) = load_settings() else {
         return;
     };
     *volume = MasterVolume(saved.master_volume.clamp(0.0, 1.0));
+    *buses = saved.bus_volumes();
     *quality = saved.graphics_quality;
     *skin = saved.ui_skin;
     *monitor = saved.nova_os_monitor();
+    *accessibility = saved.accessibility;
+    *difficulty = saved.difficulty;
+    // Only written when it differs: an untouched keymap would otherwise mark
+    // the resource changed and respawn the input rigs for nothing.
+    let saved_keymap = saved.keymap();
+    if *keymap != saved_keymap {
+        *keymap = saved_keymap;
+    }
 }
 
 /// Idle frames a settings value must hold steady before it is written to disk.
@@ -251,15 +326,23 @@ pub(crate) const SETTINGS_SAVE_DEBOUNCE_FRAMES: u32 = 15;
 /// countdown: `None` = nothing pending, `Some(n)` = `n` idle frames so far.
 pub(crate) fn persist_settings_on_change(
     volume: Res<MasterVolume>,
+    buses: Res<BusVolumes>,
     quality: Res<GraphicsQuality>,
     skin: Res<UiSkin>,
     monitor: Res<NovaOsMonitorSettings>,
+    keymap: Res<Keymap>,
+    accessibility: Res<AccessibilitySettings>,
+    difficulty: Res<Difficulty>,
     mut pending: ResMut<PendingSettingsSave>,
 ) {
     let edited = (volume.is_changed() && !volume.is_added())
+        || (buses.is_changed() && !buses.is_added())
         || (quality.is_changed() && !quality.is_added())
         || (skin.is_changed() && !skin.is_added())
-        || (monitor.is_changed() && !monitor.is_added());
+        || (monitor.is_changed() && !monitor.is_added())
+        || (keymap.is_changed() && !keymap.is_added())
+        || (accessibility.is_changed() && !accessibility.is_added())
+        || (difficulty.is_changed() && !difficulty.is_added());
     if edited {
         // A fresh edit: (re)start the debounce, coalescing a drag's per-frame
         // changes into one pending save.
@@ -269,7 +352,14 @@ pub(crate) fn persist_settings_on_change(
     if let Some(frames) = pending.idle_frames {
         if frames + 1 >= SETTINGS_SAVE_DEBOUNCE_FRAMES {
             save_settings(&PersistedSettings::from_resources(
-                *volume, *quality, *skin, *monitor,
+                *volume,
+                *buses,
+                *quality,
+                *skin,
+                *monitor,
+                &keymap,
+                *accessibility,
+                *difficulty,
             ));
             pending.idle_frames = None;
         } else {
@@ -295,9 +385,13 @@ pub(crate) struct PendingSettingsSave {
 pub(crate) fn flush_settings_on_exit(
     mut exits: MessageReader<AppExit>,
     volume: Res<MasterVolume>,
+    buses: Res<BusVolumes>,
     quality: Res<GraphicsQuality>,
     skin: Res<UiSkin>,
     monitor: Res<NovaOsMonitorSettings>,
+    keymap: Res<Keymap>,
+    accessibility: Res<AccessibilitySettings>,
+    difficulty: Res<Difficulty>,
     mut pending: ResMut<PendingSettingsSave>,
 ) {
     if exits.is_empty() || pending.idle_frames.is_none() {
@@ -305,41 +399,62 @@ pub(crate) fn flush_settings_on_exit(
     }
     exits.clear();
     save_settings(&PersistedSettings::from_resources(
-        *volume, *quality, *skin, *monitor,
+        *volume,
+        *buses,
+        *quality,
+        *skin,
+        *monitor,
+        &keymap,
+        *accessibility,
+        *difficulty,
     ));
     pending.idle_frames = None;
 }
 
-/// Mirror the volume slider's value onto [`MasterVolume`] as it is dragged.
-/// bevy's `slider_self_update` (registered alongside this) commits the value
-/// onto the slider's own `SliderValue`; this copies it to the resource, whose
-/// change then drives the audio (`GlobalVolume` + the thruster loop) and the
-/// save-on-change persistence. Guarded on [`VolumeSlider`] so it ignores any
-/// other slider.
+/// Mirror the volume sliders' values onto [`MasterVolume`] and
+/// [`BusVolumes`] as they are dragged. bevy's `slider_self_update`
+/// (registered alongside this) commits the value onto the slider's own
+/// `SliderValue`; this copies it to the resource, whose change then drives the
+/// audio (`GlobalVolume`, each bus's cues and loops) and the save-on-change
+/// persistence. Guarded on [`VolumeSlider`] / [`BusVolumeSlider`] so it
+/// ignores any other slider.
 pub(crate) fn on_volume_slider_change(
     change: On<ValueChange<f32>>,
     is_volume: Query<(), With<VolumeSlider>>,
+    bus_sliders: Query<&BusVolumeSlider>,
     mut volume: ResMut<MasterVolume>,
+    mut buses: ResMut<BusVolumes>,
 ) {
     if is_volume.contains(change.source) {
         *volume = MasterVolume(change.value.clamp(0.0, 1.0));
+    } else if let Ok(BusVolumeSlider(bus)) = bus_sliders.get(change.source) {
+        *buses.get_mut(*bus) = change.value.clamp(0.0, 1.0);
     }
 }
 
-/// Keep the volume slider's percent label in sync with its value. The bar fill
-/// is the shared `slider_track`, shown by nova_ui's `sync_slider_tracks` in
-/// either skin - so this only owns the `NN%` text. Runs every frame;
-/// there is at most one slider (main-menu or pause), and none while no settings
-/// panel is open.
+/// Keep the volume sliders' percent labels in sync with their values. The bar
+/// fill is the shared `slider_track`, shown by nova_ui's `sync_slider_tracks`
+/// in either skin - so this only owns the `NN%` text. Runs every frame;
+/// there is at most one slider of each kind (main-menu or pause), and none
+/// while no settings panel is open.
 pub(crate) fn sync_volume_slider(
     sliders: Query<&SliderValue, With<VolumeSlider>>,
-    mut labels: Query<&mut Text, With<VolumeLabel>>,
+    bus_sliders: Query<(&SliderValue, &BusVolumeSlider)>,
+    mut labels: Query<&mut Text, (With<VolumeLabel>, Without<BusVolumeLabel>)>,
+    mut bus_labels: Query<(&mut Text, &BusVolumeLabel)>,
 ) {
     if let Ok(value) = sliders.single() {
         for mut text in &mut labels {
             text.0 = volume_label(value.0);
         }
     }
+    for (value, BusVolumeSlider(bus)) in &bus_sliders {
+        for (mut text, label) in &mut bus_labels {
+            if label.0 == *bus {
+                text.0 = volume_label(value.0);
+            }
+        }
+    }
 }
 
 /// One read-only keybind row: the action on the left, the keyboard and gamepad
@@ -1,23 +1,45 @@
 //! The persisted form of the player settings.
 //!
-//! The settings menu writes four Bevy resources; this module snapshots them into
-//! one versionable blob and names the store key. Storage, and its best-effort
+//! The settings menu writes eight Bevy resources (the master volume, the bus
+//! volumes, the graphics preset, the UI skin, the NOVA OS monitor, the keymap,
+//! the accessibility settings and the difficulty the Scenarios picker sets); this module snapshots them into one versionable blob and names the
+//! store key. Storage, and its best-effort
 //! semantics, belong to [`nova_assets::persist`].
 
+use std::collections::BTreeMap;
+
 use nova_assets::persist;
-use nova_gameplay::prelude::{GraphicsQuality, MasterVolume};
+use nova_gameplay::prelude::{
+    AccessibilitySettings, AudioBus, BusVolumes, Difficulty, GraphicsQuality, MasterVolume,
+};
 use nova_os_ui::prelude::NovaOsMonitorSettings;
+use nova_ship::input::keymap::{Keymap, KeymapAction, KeymapBinding};
 use nova_ui::prelude::UiSkin;
 use serde::{Deserialize, Serialize};
 
 /// The persisted form of the settings: plain, versionable data decoupled from
 /// the live resources. Missing/extra fields are tolerated by serde defaults so
 /// an older or newer file still loads.
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct PersistedSettings {
     /// Linear master volume `0.0..=1.0`.
     #[serde(default = "default_volume")]
     pub master_volume: f32,
+    /// Linear SFX-bus volume `0.0..=1.0`, on top of the master volume.
+    #[serde(default = "default_bus_volume")]
+    pub sfx_volume: f32,
+    /// Linear UI-bus volume `0.0..=1.0`, on top of the master volume.
+    #[serde(default = "default_bus_volume")]
+    pub ui_volume: f32,
+    /// Linear NOVA OS-bus volume `0.0..=1.0`, on top of the master volume.
+    #[serde(default = "default_bus_volume")]
+    pub nova_os_volume: f32,
+    /// Linear comms-bus volume `0.0..=1.0`, on top of the master volume.
+    #[serde(default = "default_bus_volume")]
+    pub comms_volume: f32,
+    /// Linear music-bus volume `0.0..=1.0`, on top of the master volume.
+    #[serde(default = "default_bus_volume")]
+    pub music_volume: f32,
     /// The graphics-quality preset.
     #[serde(default)]
     pub graphics_quality: GraphicsQuality,
@@ -33,12 +55,29 @@ pub struct PersistedSettings {
     /// NOVA OS SND speaker toggle (default ON).
     #[serde(default = "default_sound_enabled")]
     pub nova_os_sound_enabled: bool,
+    /// The keymap rows the player changed, keyed by [`KeymapAction::id`].
+    /// Only overrides are kept, so a later default change still reaches an
+    /// untouched row, and an id this build does not know is skipped on load.
+    #[serde(default)]
+    pub keybinds: BTreeMap<String, KeymapBinding>,
+    /// Palette, text size, reduced motion and comms dwell. Stored raw; the
+    /// readers clamp.
+    #[serde(default)]
+    pub accessibility: AccessibilitySettings,
+    /// The difficulty tier last picked on the Scenarios picker.
+    #[serde(default)]
+    pub difficulty: Difficulty,
 }
 
 fn default_volume() -> f32 {
     MasterVolume::default().0
 }
 
+fn default_bus_volume() -> f32 {
+    // Every bus defaults alike; the music bus stands in for all five.
+    BusVolumes::default().music
+}
+
 fn default_bright_detent() -> usize {
     NovaOsMonitorSettings::default().bright_detent
 }
@@ -53,15 +92,16 @@ fn default_sound_enabled() -> bool {
 
 impl Default for PersistedSettings {
     fn default() -> Self {
-        let monitor = NovaOsMonitorSettings::default();
-        Self {
-            master_volume: MasterVolume::default().0,
-            graphics_quality: GraphicsQuality::default(),
-            ui_skin: UiSkin::default(),
-            nova_os_bright_detent: monitor.bright_detent,
-            nova_os_scan_detent: monitor.scan_detent,
-            nova_os_sound_enabled: monitor.sound_enabled,
-        }
+        Self::from_resources(
+            MasterVolume::default(),
+            BusVolumes::default(),
+            GraphicsQuality::default(),
+            UiSkin::default(),
+            NovaOsMonitorSettings::default(),
+            &Keymap::default(),
+            AccessibilitySettings::default(),
+            Difficulty::default(),
+        )
     }
 }
 
@@ -69,17 +109,45 @@ impl PersistedSettings {
     /// Snapshot the live resources into a persistable value.
     pub fn from_resources(
         volume: MasterVolume,
+        buses: BusVolumes,
         quality: GraphicsQuality,
         skin: UiSkin,
         monitor: NovaOsMonitorSettings,
+        keymap: &Keymap,
+        accessibility: AccessibilitySettings,
+        difficulty: Difficulty,
     ) -> Self {
         Self {
             master_volume: volume.factor(),
+            sfx_volume: buses.factor(AudioBus::Sfx),
+            ui_volume: buses.factor(AudioBus::Ui),
+            nova_os_volume: buses.factor(AudioBus::NovaOs),
+            comms_volume: buses.factor(AudioBus::Comms),
+            music_volume: buses.factor(AudioBus::Music),
             graphics_quality: quality,
             ui_skin: skin,
             nova_os_bright_detent: monitor.bright_detent,
             nova_os_scan_detent: monitor.scan_detent,
             nova_os_sound_enabled: monitor.sound_enabled,
+            keybinds: keymap
+                .overrides()
+                .into_iter()
+                .map(|(action, binding)| (action.id().to_string(), binding))
+                .collect(),
+            accessibility,
+            difficulty,
+        }
+    }
+
+    /// The persisted bus volumes as the live resource, each clamped like the
+    /// master volume beside them.
+    pub fn bus_volumes(&self) -> BusVolumes {
+        BusVolumes {
+            sfx: self.sfx_volume.clamp(0.0, 1.0),
+            ui: self.ui_volume.clamp(0.0, 1.0),
+            nova_os: self.nova_os_volume.clamp(0.0, 1.0),
+            comms: self.comms_volume.clamp(0.0, 1.0),
+            music: self.music_volume.clamp(0.0, 1.0),
         }
     }
 
@@ -95,6 +163,15 @@ impl PersistedSettings {
         monitor.clamp_detents();
         monitor
     }
+
+    /// The persisted keymap: the saved overrides over the shipped defaults.
+    pub fn keymap(&self) -> Keymap {
+        Keymap::from_overrides(
+            self.keybinds.iter().filter_map(|(id, binding)| {
+                KeymapAction::from_id(id).map(|action| (action, *binding))
+            }),
+        )
+    }
 }
 
 /// The store key: `<config_dir>/nova-protocol/settings.ron` on native,
@@ -118,13 +195,18 @@ pub fn save_settings(settings: &PersistedSettings) {
 // setting never invalidates a player's saved store.
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
+    use bevy::prelude::{GamepadButton, KeyCode};
     use nova_assets::{
         persist::{load_from, save_to},
         storage::NativeStorage,
     };
-    use nova_gameplay::prelude::GraphicsQuality;
+    use nova_gameplay::prelude::{AccessibilitySettings, BusVolumes, GraphicsQuality};
     use nova_os_ui::prelude::NovaOsMonitorSettings;
-    use nova_ui::prelude::UiSkin;
+    use nova_ship::input::{
+        keymap::{Keymap, KeymapAction, KeymapDevice},
+        player::InputSource,
+    };
+    use nova_ui::{prelude::UiSkin, theme::semantic::Palette};
 
     use super::{PersistedSettings, KEY};
 
@@ -149,15 +231,51 @@ mod tests {
         clear(&store);
 
         // Non-default monitor detents + SND off, so the round-trip proves the NOVA OS
-        // chin fields persist.
+        // chin fields persist; one remapped key and one remapped pad button for
+        // the keymap.
+        let mut keymap = Keymap::default();
+        keymap.rebind(
+            KeymapAction::AutopilotStop,
+            KeymapDevice::Keyboard,
+            InputSource::Keyboard(KeyCode::KeyK),
+        );
+        keymap.rebind(
+            KeymapAction::Radar,
+            KeymapDevice::Gamepad,
+            InputSource::Gamepad(GamepadButton::RightTrigger2),
+        );
         let settings = PersistedSettings {
             master_volume: 0.4,
+            sfx_volume: 0.9,
+            ui_volume: 0.3,
+            nova_os_volume: 0.2,
+            comms_volume: 0.7,
+            music_volume: 0.6,
             graphics_quality: GraphicsQuality::Low,
             ui_skin: UiSkin::Hardware,
             nova_os_bright_detent: 3,
             nova_os_scan_detent: 0,
             nova_os_sound_enabled: false,
+            keybinds: PersistedSettings::from_resources(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                &keymap,
+                Default::default(),
+                Default::default(),
+            )
+            .keybinds,
+            accessibility: AccessibilitySettings {
+                palette: Palette::Tritanopia,
+                text_scale: 1.5,
+                reduced_motion: true,
+                comms_dwell: 2.0,
+            },
+            difficulty: Difficulty::Hard,
         };
+        assert_eq!(settings.keybinds.len(), 2, "only the overrides are kept");
         save_to(&store, KEY, &settings);
         assert!(
             store.path(KEY).exists(),
@@ -165,9 +283,16 @@ mod tests {
         );
         assert_eq!(
             load_from::<PersistedSettings>(&store, KEY),
-            Some(settings),
+            Some(settings.clone()),
             "settings round-trip through RON"
         );
+        assert_eq!(
+            load_from::<PersistedSettings>(&store, KEY)
+                .unwrap()
+                .keymap(),
+            keymap,
+            "the persisted overrides rebuild the live keymap"
+        );
         assert_eq!(
             load_from::<PersistedSettings>(&store, KEY)
                 .unwrap()
@@ -218,17 +343,41 @@ mod tests {
             load_from::<PersistedSettings>(&store, KEY),
             Some(PersistedSettings {
                 master_volume: 0.5,
+                sfx_volume: BusVolumes::default().sfx,
+                ui_volume: BusVolumes::default().ui,
+                nova_os_volume: BusVolumes::default().nova_os,
+                comms_volume: BusVolumes::default().comms,
+                music_volume: BusVolumes::default().music,
                 graphics_quality: GraphicsQuality::default(),
                 ui_skin: UiSkin::default(),
                 nova_os_bright_detent: NovaOsMonitorSettings::default().bright_detent,
                 nova_os_scan_detent: NovaOsMonitorSettings::default().scan_detent,
                 nova_os_sound_enabled: NovaOsMonitorSettings::default().sound_enabled,
+                keybinds: Default::default(),
+                accessibility: AccessibilitySettings::default(),
+                difficulty: Difficulty::Normal,
             }),
             "a missing field falls back to its serde default"
         );
         clear(&store);
     }
 
+    /// A keybind id this build does not know (a renamed or retired action) is
+    /// skipped, not fatal: the rest of the keymap still loads.
+    #[test]
+    fn unknown_keybind_ids_are_ignored() {
+        let settings = PersistedSettings {
+            keybinds: [(
+                "retired_action".to_string(),
+                KeymapAction::Radar.default_binding(),
+            )]
+            .into_iter()
+            .collect(),
+            ..PersistedSettings::default()
+        };
+        assert_eq!(settings.keymap(), Keymap::default());
+    }
+
     /// The UI skin choice survives a save/load round-trip (DoD 2). Default is Phosphor,
     /// so a Hardware choice is the non-default proof; and an older store lacking the
     /// field defaults to Phosphor rather than failing to load.
@@ -0,0 +1,159 @@
+//! The persisted form of the survival high scores.
+//!
+//! Survival mode (nova_scenario) keeps the best runs of every survival
+//! scenario, per tier, in [`SurvivalScores`]; this module snapshots that into one
+//! versionable blob, names the store key, and loads it at startup and saves it
+//! whenever a run makes a table. Storage, and its best-effort semantics,
+//! belong to [`nova_assets::persist`].
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use nova_assets::persist;
+use nova_gameplay::prelude::Difficulty;
+use nova_scenario::prelude::{SurvivalScore, SurvivalScores};
+use serde::{Deserialize, Serialize};
+
+/// The persisted form of the scores: plain, versionable data decoupled from
+/// the live resource.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct PersistedSurvivalScores {
+    /// Every survival scenario's table, best first, keyed by scenario id.
+    #[serde(default)]
+    pub scenarios: BTreeMap<String, Vec<PersistedSurvivalScore>>,
+}
+
+/// One finished run.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PersistedSurvivalScore {
+    /// Waves cleared.
+    pub waves: u32,
+    /// Scenario seconds from the start to the outcome.
+    pub time: f64,
+    /// The seed the waves were built from.
+    pub seed: u64,
+    /// The tier the run was flown at.
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+impl PersistedSurvivalScores {
+    /// Snapshot the live scores.
+    pub fn from_resource(scores: &SurvivalScores) -> Self {
+        Self {
+            scenarios: scores
+                .0
+                .iter()
+                .map(|(scenario, table)| {
+                    (
+                        scenario.clone(),
+                        table
+                            .iter()
+                            .map(|score| PersistedSurvivalScore {
+                                waves: score.waves,
+                                time: score.time,
+                                seed: score.seed,
+                                difficulty: score.difficulty,
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// The live scores this snapshot restores.
+    pub fn survival_scores(&self) -> SurvivalScores {
+        SurvivalScores(
+            self.scenarios
+                .iter()
+                .map(|(scenario, table)| {
+                    (
+                        scenario.clone(),
+                        table
+                            .iter()
+                            .map(|score| SurvivalScore {
+                                waves: score.waves,
+                                time: score.time,
+                                seed: score.seed,
+                                difficulty: score.difficulty,
+                            })
+                            .collect(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The store key: `<config_dir>/nova-protocol/survival_scores.ron` on native,
+/// `nova_protocol.survival_scores` in localStorage on the web.
+pub(crate) const KEY: &str = "survival_scores";
+
+/// The saved scores, or `None` if nothing has been saved yet (or the store is
+/// unreadable/corrupt).
+pub fn load_survival_scores() -> Option<PersistedSurvivalScores> {
+    persist::load(KEY)
+}
+
+/// Persist the scores. Best-effort - failures are logged, not returned.
+pub fn save_survival_scores(scores: &PersistedSurvivalScores) {
+    persist::save(KEY, scores);
+}
+
+/// Startup: restore the saved scores, if any.
+pub(crate) fn load_persisted_survival_scores(mut scores: ResMut<SurvivalScores>) {
+    if let Some(saved) = load_survival_scores() {
+        *scores = saved.survival_scores();
+    }
+}
+
+/// Save the scores whenever they change: once per finished survival run. The
+/// startup load is skipped: it changes nothing on disk.
+pub(crate) fn persist_survival_scores_on_change(scores: Res<SurvivalScores>) {
+    if scores.is_changed() && !scores.is_added() {
+        save_survival_scores(&PersistedSurvivalScores::from_resource(&scores));
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use nova_assets::{
+        persist::{load_from, save_to},
+        storage::NativeStorage,
+    };
+
+    use super::*;
+
+    #[test]
+    fn survival_scores_round_trip() {
+        let store = NativeStorage::at(std::env::temp_dir().join("nova_survival_scores_round_trip"));
+        let _ = std::fs::remove_dir_all(store.path(KEY).parent().unwrap());
+
+        let scores = SurvivalScores(BTreeMap::from([(
+            "holdout".to_string(),
+            vec![
+                SurvivalScore {
+                    waves: 6,
+                    time: 412.5,
+                    seed: 0xdead_beef,
+                    difficulty: Difficulty::Hard,
+                },
+                SurvivalScore {
+                    waves: 3,
+                    time: 190.0,
+                    seed: 7,
+                    difficulty: Difficulty::Normal,
+                },
+            ],
+        )]));
+        save_to(
+            &store,
+            KEY,
+            &PersistedSurvivalScores::from_resource(&scores),
+        );
+
+        let loaded = load_from::<PersistedSurvivalScores>(&store, KEY).expect("saved scores load");
+        assert_eq!(loaded.survival_scores(), scores);
+    }
+}
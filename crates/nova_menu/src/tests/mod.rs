@@ -6,7 +6,9 @@ mod menu;
 mod mods;
 mod outcome;
 mod pause;
+mod photo;
 mod portal;
+mod replay;
 mod scenarios;
 mod settings;
 mod support;
@@ -7,7 +7,8 @@ use bevy::{
     ui_widgets::{observe, Activate},
 };
 use nova_assets::prelude::{
-    EnabledMods, InstallJobs, ModCatalog, ModInfo, ModMeta, PendingRemovals,
+    EnabledMods, InstallJobs, ModCatalog, ModInfo, ModMeta, ModProfile, ModProfiles,
+    PendingRemovals,
 };
 use nova_ui::widget::Selected;
 
@@ -15,7 +16,10 @@ use super::support::{
     all_texts, app, checkbox_of, downloaded_set, entity_by_name, label_of, mod_row, mods_app,
     observe_portal_events, selected_mod, PortalCaptures,
 };
-use crate::mods::{dep_status, on_mod_toggle, DepStatus, ModEnableCheckbox, ModToggle};
+use crate::mods::{
+    dep_status, on_mod_load_order, on_mod_toggle, DepStatus, ModEnableCheckbox, ModLoadOrder,
+    ModToggle,
+};
 
 /// Clicking a non-base mod's toggle flips its id in `EnabledMods` (the set the
 /// nova_assets re-merge watches). Driven via `trigger(Activate)` like the other
@@ -463,3 +467,82 @@ fn dep_status_classifies_enabled_installed_and_missing() {
         "not in the catalog"
     );
 }
+
+// --- Mod profiles -----------------------------------------------------------
+
+/// "New profile" saves the live set under a fresh name and switches to it; the
+/// label repaints and the profile carries the set it was made from.
+#[test]
+fn new_profile_saves_the_live_set_and_becomes_active() {
+    let mut app = mods_app();
+    app.world_mut()
+        .resource_mut::<EnabledMods>()
+        .0
+        .insert("demo".to_string());
+    app.update();
+
+    let button = entity_by_name(&mut app, "Mods Profile New Button").expect("new-profile button");
+    app.world_mut().trigger(Activate { entity: button });
+    app.update();
+
+    let profiles = app.world().resource::<ModProfiles>();
+    assert_eq!(profiles.active.as_deref(), Some("profile-1"));
+    assert_eq!(
+        profiles.active_profile().map(|p| p.enabled.clone()),
+        Some(vec!["base".to_string(), "demo".to_string()]),
+        "the new profile holds the set it was made from"
+    );
+    assert!(
+        all_texts(&mut app).contains(&"Profile: profile-1".to_string()),
+        "the profile line repaints for the switch"
+    );
+}
+
+/// "Load later" moves a mod one slot down the ACTIVE profile's order and
+/// pins the whole effective order; a step past the end is a no-op.
+#[test]
+fn load_order_buttons_edit_the_active_profile_order() {
+    let mut app = app();
+    app.insert_resource(dep_catalog());
+    app.insert_resource(EnabledMods(
+        ["base", "lib", "cool"]
+            .map(String::from)
+            .into_iter()
+            .collect(),
+    ));
+    app.insert_resource(ModProfiles {
+        active: Some("story".to_string()),
+        profiles: vec![ModProfile {
+            name: "story".to_string(),
+            enabled: vec!["base".into(), "cool".into(), "lib".into()],
+            order: Vec::new(),
+        }],
+    });
+    let later = app
+        .world_mut()
+        .spawn((
+            ModLoadOrder {
+                id: "lib".to_string(),
+                step: 1,
+            },
+            observe(on_mod_load_order),
+        ))
+        .id();
+    app.update();
+
+    app.world_mut().trigger(Activate { entity: later });
+    let order = |app: &App| {
+        app.world()
+            .resource::<ModProfiles>()
+            .active_order()
+            .to_vec()
+    };
+    assert_eq!(order(&app), vec!["cool".to_string(), "lib".to_string()]);
+
+    app.world_mut().trigger(Activate { entity: later });
+    assert_eq!(
+        order(&app),
+        vec!["cool".to_string(), "lib".to_string()],
+        "lib is already last - nothing moves"
+    );
+}
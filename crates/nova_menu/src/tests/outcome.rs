@@ -1,6 +1,7 @@
 //! The victory/defeat overlay: which entries it offers, that it freezes the sim
 //! and frees the cursor the way the pause menu does, that ESC cannot raise the
-//! pause overlay over it, and that it rebuilds when a switch is queued late.
+//! pause overlay over it, that it rebuilds when a switch is queued late, and
+//! that it carries the debrief.
 
 use bevy::{
     prelude::*,
@@ -179,6 +180,93 @@ fn victory_overlay_without_a_queued_next_offers_only_the_menu() {
     );
 }
 
+/// A finished race course adds its result under the banner: the time, and
+/// the delta to the best it was flown against - or, faster, the news.
+#[test]
+fn victory_overlay_shows_a_finished_race_against_the_best() {
+    let mut app = app_with_outcome();
+    enter_playing(&mut app);
+
+    let course = app
+        .world_mut()
+        .spawn(CourseResult {
+            time: 83.4,
+            splits: vec![40.0, 83.4],
+            previous_best: Some(81.2),
+        })
+        .id();
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = Some(OutcomeActionConfig {
+        outcome: ScenarioOutcomeKind::Victory,
+        message: None,
+        auto_advance_secs: None,
+    });
+    app.update();
+
+    let texts = all_text(&mut app);
+    assert!(texts.iter().any(|t| t == "TIME 01:23.4"), "time: {texts:?}");
+    assert!(
+        texts.iter().any(|t| t == "BEST 01:21.2  +2.2"),
+        "delta to the best: {texts:?}"
+    );
+
+    // A faster run is the new best; rebuild the overlay for it.
+    app.world_mut().entity_mut(course).insert(CourseResult {
+        time: 80.0,
+        splits: vec![39.0, 80.0],
+        previous_best: Some(81.2),
+    });
+    app.world_mut()
+        .resource_mut::<CurrentOutcome>()
+        .set_changed();
+    app.update();
+    let texts = all_text(&mut app);
+    assert!(
+        texts.iter().any(|t| t == "NEW PERSONAL BEST  -1.2"),
+        "new best: {texts:?}"
+    );
+}
+
+/// A stamped debrief lists the run's statistics under the banner.
+#[test]
+fn the_outcome_overlay_shows_the_debrief() {
+    let mut app = app_with_outcome();
+    enter_playing(&mut app);
+
+    let mut debrief = ScenarioDebrief {
+        kills: 2,
+        intercepts: 3,
+        locks: 4,
+        objectives: vec![("reach".to_string(), 30.0)],
+        time: Some(252.5),
+        victory: true,
+        ..default()
+    };
+    debrief
+        .weapons
+        .insert("Turret".to_string(), WeaponTally { fired: 40, hit: 10 });
+    debrief.damage_dealt.insert("Hull".to_string(), 120.0);
+    debrief.damage_taken.insert("Thruster".to_string(), 35.0);
+    app.insert_resource(debrief);
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = Some(OutcomeActionConfig::new(
+        ScenarioOutcomeKind::Victory,
+        "Done.",
+    ));
+    app.update();
+
+    let texts = all_text(&mut app);
+    for line in [
+        "MISSION TIME 04:12.5",
+        "TURRET 10/40 HIT 25%",
+        "DAMAGE DEALT 120  TAKEN 35",
+        "DEALT HULL 120",
+        "TAKEN THRUSTER 35",
+        "KILLS 2  INTERCEPTS 3  LOCKS 4",
+        "OBJECTIVES 1",
+    ] {
+        assert!(texts.iter().any(|t| t == line), "{line}: {texts:?}");
+    }
+}
+
 /// The overlay's Main Menu button rides the same exit as the pause
 /// overlay's Back button: lands in MainMenu (which is what tears the
 /// scenario down and, with it, the outcome).
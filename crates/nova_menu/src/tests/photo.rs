@@ -0,0 +1,174 @@
+//! Photo mode: the pause menu's page that borrows the scenario camera, and
+//! hands every piece of it back on the way out.
+
+use bevy::{camera::Exposure, post_process::dof::DepthOfField, prelude::*, ui_widgets::Activate};
+use nova_gameplay::prelude::*;
+use nova_hud::prelude::HudVisibility;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+
+use super::support::{
+    app, clocks_paused, dummy_scenario, dummy_scenarios, enter_playing, find_named, pause_state,
+    press_escape,
+};
+use crate::photo::PhotoSettings;
+
+/// A live scenario under the pause menu, with a chase-rigged scenario camera
+/// at a known pose and a 50° lens.
+fn photo_app() -> (App, Entity) {
+    let mut app = app();
+    app.insert_resource(dummy_scenarios());
+    app.insert_resource(CurrentScenario(Some(dummy_scenario("live_run").1)));
+    enter_playing(&mut app);
+    let camera = app
+        .world_mut()
+        .spawn((
+            ScenarioCameraMarker,
+            SpaceshipCameraController,
+            Transform::from_xyz(0.0, 5.0, 20.0).looking_at(Vec3::ZERO, Vec3::Y),
+            Projection::Perspective(PerspectiveProjection {
+                fov: 50f32.to_radians(),
+                ..default()
+            }),
+        ))
+        .id();
+    press_escape(&mut app);
+    (app, camera)
+}
+
+fn enter_photo(app: &mut App) {
+    let button = find_named(app, "Pause Photo Button").expect("photo mode button");
+    app.world_mut().trigger(Activate { entity: button });
+    app.update();
+    app.update();
+}
+
+fn fov_deg(app: &App, camera: Entity) -> f32 {
+    match app.world().get::<Projection>(camera) {
+        Some(Projection::Perspective(perspective)) => perspective.fov.to_degrees(),
+        other => panic!("expected a perspective projection, got {other:?}"),
+    }
+}
+
+/// The Photo Mode button keeps the sim frozen, swaps the chase rig for the
+/// free-fly one, clears the HUD and the pause panel; Esc steps back to the
+/// pause menu with the rig, the lens and the HUD as they were.
+#[test]
+fn photo_mode_borrows_the_camera_and_esc_hands_it_back() {
+    let (mut app, camera) = photo_app();
+    let posed = *app.world().get::<Transform>(camera).unwrap();
+
+    enter_photo(&mut app);
+    assert_eq!(pause_state(&app), PauseStates::Photo);
+    assert_eq!(clocks_paused(&app), (true, true), "photo mode stays frozen");
+    assert!(app.world().get::<WASDCameraController>(camera).is_some());
+    assert!(app
+        .world()
+        .get::<SpaceshipCameraController>(camera)
+        .is_none());
+    assert_eq!(
+        *app.world().resource::<HudVisibility>(),
+        HudVisibility::Cinematic
+    );
+    assert!(find_named(&mut app, "Photo Panel").is_some());
+    assert!(
+        find_named(&mut app, "Pause Overlay").is_none(),
+        "the pause panel gives the frame up to the photo page"
+    );
+    assert_eq!(
+        app.world().resource::<PhotoSettings>().fov_deg.round(),
+        50.0,
+        "the FOV slider starts from the camera's own lens"
+    );
+
+    // Fly off and widen the lens, then back out.
+    app.world_mut()
+        .get_mut::<Transform>(camera)
+        .unwrap()
+        .translation = Vec3::new(100.0, 0.0, 0.0);
+    app.world_mut().resource_mut::<PhotoSettings>().fov_deg = 90.0;
+    app.update();
+    assert_eq!(fov_deg(&app, camera).round(), 90.0);
+
+    press_escape(&mut app);
+    assert_eq!(pause_state(&app), PauseStates::Paused);
+    assert_eq!(clocks_paused(&app), (true, true), "still paused underneath");
+    assert!(find_named(&mut app, "Pause Overlay").is_some());
+    assert!(find_named(&mut app, "Photo Panel").is_none());
+    assert!(app
+        .world()
+        .get::<SpaceshipCameraController>(camera)
+        .is_some());
+    assert!(app.world().get::<WASDCameraController>(camera).is_none());
+    assert_eq!(*app.world().get::<Transform>(camera).unwrap(), posed);
+    assert_eq!(fov_deg(&app, camera).round(), 50.0);
+    assert!(app.world().get::<Exposure>(camera).is_none());
+    assert_eq!(*app.world().resource::<HudVisibility>(), HudVisibility::On);
+}
+
+/// The panel's lens settings land on the borrowed camera: exposure as
+/// compensation against the camera's base, and depth of field only while it
+/// is switched on.
+#[test]
+fn photo_settings_drive_exposure_and_depth_of_field() {
+    let (mut app, camera) = photo_app();
+    enter_photo(&mut app);
+
+    {
+        let mut settings = app.world_mut().resource_mut::<PhotoSettings>();
+        settings.exposure_ev = 1.0;
+        settings.depth_of_field = true;
+        settings.focus = 120.0;
+        settings.aperture = 4.0;
+    }
+    app.update();
+
+    let exposure = app.world().get::<Exposure>(camera).expect("exposure");
+    assert_eq!(
+        exposure.ev100,
+        Exposure::default().ev100 - 1.0,
+        "+1 EV brightens"
+    );
+    let dof = app
+        .world()
+        .get::<DepthOfField>(camera)
+        .expect("depth of field");
+    assert_eq!(dof.focal_distance, 120.0);
+    assert_eq!(dof.aperture_f_stops, 4.0);
+
+    app.world_mut()
+        .resource_mut::<PhotoSettings>()
+        .depth_of_field = false;
+    app.update();
+    assert!(app.world().get::<DepthOfField>(camera).is_none());
+}
+
+/// `H` folds the panel away and back; the rig keeps flying either way.
+#[test]
+fn h_folds_the_photo_panel() {
+    let (mut app, _) = photo_app();
+    enter_photo(&mut app);
+    let panel = find_named(&mut app, "Photo Panel").unwrap();
+
+    let tap_h = |app: &mut App| {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyH);
+        app.update();
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::KeyH);
+        keys.clear();
+        app.update();
+    };
+
+    tap_h(&mut app);
+    assert_eq!(
+        app.world().get::<Visibility>(panel),
+        Some(&Visibility::Hidden)
+    );
+    tap_h(&mut app);
+    assert_eq!(
+        app.world().get::<Visibility>(panel),
+        Some(&Visibility::Inherited)
+    );
+}
@@ -0,0 +1,375 @@
+//! Replays: a run loaded in play is recorded and offered on its outcome, Watch
+//! Replay loads it with the player's input suspended, a seek lands where
+//! straight playback does and keeps the survival seed, and a replay this game
+//! cannot play is refused before anything loads.
+
+use std::time::Duration;
+
+use bevy::{prelude::*, time::TimePlugin, ui_widgets::Activate};
+use nova_assets::prelude::MergedMods;
+use nova_gameplay::prelude::*;
+use nova_scenario::prelude::*;
+use nova_ship::prelude::*;
+
+use super::support::{
+    app_with_outcome, dummy_scenarios, enter_playing, find_named, observe_load_scenario,
+    LoadedScenario, TEST_START_ID,
+};
+use crate::replay::prelude::*;
+
+fn replay_of(scenario: &str) -> Replay {
+    Replay {
+        schema: REPLAY_SCHEMA,
+        scenario: scenario.to_string(),
+        mods: Vec::new(),
+        seed: 7,
+        end_tick: 640,
+        keyframes: Vec::new(),
+        commands: Vec::new(),
+        snapshot: None,
+        survival_seed: None,
+        difficulty: Difficulty::Normal,
+    }
+}
+
+/// What the loader triggers once `scenario_id` is in the world.
+fn scenario_loaded(app: &mut App, scenario_id: &str) {
+    app.world_mut().trigger(ScenarioLoaded {
+        scenario_id: scenario_id.to_string(),
+        handler_count: 0,
+        object_count: 0,
+        survival_seed: None,
+    });
+}
+
+#[test]
+fn a_replay_for_another_game_is_refused() {
+    let scenarios = dummy_scenarios();
+    assert!(replay_scenario(&replay_of(TEST_START_ID), &scenarios, None).is_ok());
+
+    let old = Replay {
+        schema: REPLAY_SCHEMA + 1,
+        ..replay_of(TEST_START_ID)
+    };
+    assert_eq!(
+        replay_scenario(&old, &scenarios, None).err(),
+        Some(ReplayError::Schema {
+            found: REPLAY_SCHEMA + 1
+        })
+    );
+    assert_eq!(
+        replay_scenario(&replay_of("gone"), &scenarios, None).err(),
+        Some(ReplayError::UnknownScenario {
+            scenario: "gone".to_string()
+        })
+    );
+    let modded = Replay {
+        mods: vec!["alpha".to_string(), "zeta".to_string()],
+        ..replay_of(TEST_START_ID)
+    };
+    let merged = MergedMods(modded.mods.clone());
+    assert!(replay_scenario(&modded, &scenarios, Some(&merged)).is_ok());
+    let reordered = MergedMods(vec!["zeta".to_string(), "alpha".to_string()]);
+    assert_eq!(
+        replay_scenario(&modded, &scenarios, Some(&reordered)).err(),
+        Some(ReplayError::ModMismatch {
+            recorded: modded.mods.clone(),
+            enabled: reordered.0.clone(),
+        }),
+        "the same mods merged in another order are another game"
+    );
+}
+
+/// What the seek test compares: frames and frame time seen since the load,
+/// and the burn integrated over the fixed ticks.
+#[derive(Resource, Default, Clone, Debug, PartialEq)]
+struct Flown {
+    frames: u32,
+    clocked: Duration,
+    travelled: f32,
+}
+
+/// A menu rig with the real clock, a player ship for the keyframes to fly and
+/// a [`Flown`] the load resets, playing a run that changes its burn as it goes.
+fn playback_rig() -> App {
+    let mut app = app_with_outcome();
+    app.add_plugins(TimePlugin);
+    observe_load_scenario(&mut app);
+    app.init_resource::<Flown>();
+    app.add_observer(|_: On<ScenarioLoaded>, mut flown: ResMut<Flown>| {
+        *flown = Flown::default();
+    });
+    app.add_systems(
+        FixedUpdate,
+        |q_ship: Query<&FlightIntent>, mut flown: ResMut<Flown>| {
+            flown.travelled += q_ship.iter().map(|intent| intent.burn).sum::<f32>();
+        },
+    );
+    app.add_systems(Update, |time: Res<Time>, mut flown: ResMut<Flown>| {
+        flown.frames += 1;
+        flown.clocked += time.delta();
+    });
+    app.world_mut().spawn((
+        SpaceshipRootMarker,
+        PlayerSpaceshipMarker,
+        FlightIntent::default(),
+    ));
+    enter_playing(&mut app);
+
+    let burns = [(0, 0.25), (90, 1.0), (400, 0.5), (700, 0.0), (1000, 0.75)];
+    let replay = Replay {
+        end_tick: 4000,
+        keyframes: burns
+            .into_iter()
+            .map(|(tick, burn)| ReplayKeyframe {
+                tick,
+                intent: ReplayIntent { burn, ..default() },
+            })
+            .collect(),
+        ..replay_of(TEST_START_ID)
+    };
+    watch_replay(app.world_mut(), replay).expect("the replay is playable");
+    scenario_loaded(&mut app, TEST_START_ID);
+    app
+}
+
+fn tick(app: &App) -> u64 {
+    app.world().resource::<ReplayTick>().0
+}
+
+fn press(app: &mut App, key: KeyCode) {
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(key);
+    keys.clear();
+}
+
+/// Run until the seek in progress has landed.
+fn settle(app: &mut App) {
+    for _ in 0..10_000 {
+        if !app.world().resource::<ReplayPlayback>().is_seeking() {
+            return;
+        }
+        app.update();
+    }
+    panic!("the seek never landed");
+}
+
+/// Straight playback to `target`, for comparison.
+fn played_to(target: u64) -> Flown {
+    let mut app = playback_rig();
+    while tick(&app) < target {
+        app.update();
+    }
+    assert_eq!(tick(&app), target, "playback steps one tick per frame");
+    app.world().resource::<Flown>().clone()
+}
+
+/// A seek re-simulates the same frames playback runs, one tick each, so it
+/// stops exactly on its tick with the world playback has there - forward from
+/// where it stands, and back through a reload from tick 0.
+#[test]
+fn a_seek_lands_on_the_state_playback_reaches() {
+    let mut app = playback_rig();
+    for _ in 0..30 {
+        app.update();
+    }
+    press(&mut app, KeyCode::Period);
+    settle(&mut app);
+    assert_eq!(tick(&app), 31 + 640, "a seek forward stops on its tick");
+    assert_eq!(*app.world().resource::<Flown>(), played_to(31 + 640));
+
+    for _ in 0..200 {
+        app.update();
+    }
+    press(&mut app, KeyCode::Comma);
+    assert_eq!(
+        app.world().resource::<ReplayPlayback>().phase(),
+        ReplayPhase::Loading
+    );
+    scenario_loaded(&mut app, TEST_START_ID);
+    settle(&mut app);
+    assert_eq!(tick(&app), 872 - 640, "a seek back stops on its tick");
+    assert_eq!(*app.world().resource::<Flown>(), played_to(872 - 640));
+}
+
+/// A seek back reloads through the same door Watch Replay does, so a survival
+/// run is rebuilt from its recorded seed both times.
+#[test]
+fn a_seek_back_rebuilds_the_recorded_survival_waves() {
+    #[derive(Resource, Default)]
+    struct Loads(Vec<Option<u64>>);
+
+    let mut app = app_with_outcome();
+    app.add_plugins(TimePlugin);
+    app.init_resource::<Loads>();
+    app.add_observer(|load: On<LoadScenario>, mut loads: ResMut<Loads>| {
+        loads
+            .0
+            .push(load.0.survival.as_ref().and_then(|survival| survival.seed));
+    });
+    app.world_mut()
+        .resource_mut::<GameScenarios>()
+        .0
+        .get_mut(TEST_START_ID)
+        .expect("the start scenario")
+        .survival = Some(SurvivalConfig::default());
+    enter_playing(&mut app);
+
+    let replay = Replay {
+        end_tick: 4000,
+        survival_seed: Some(0xfeed),
+        ..replay_of(TEST_START_ID)
+    };
+    watch_replay(app.world_mut(), replay).expect("the replay is playable");
+    scenario_loaded(&mut app, TEST_START_ID);
+    for _ in 0..30 {
+        app.update();
+    }
+    press(&mut app, KeyCode::Comma);
+    assert_eq!(
+        app.world().resource::<ReplayPlayback>().phase(),
+        ReplayPhase::Loading
+    );
+    assert_eq!(app.world().resource::<Loads>().0, vec![Some(0xfeed); 2]);
+}
+
+/// The whole loop in one rig: a load in play starts the recording, the outcome
+/// closes it and offers it, and Watch Replay loads the same scenario with the
+/// controls taken away - without recording the replay over itself.
+#[test]
+fn a_finished_run_is_offered_and_watch_replay_plays_it() {
+    let mut app = app_with_outcome();
+    observe_load_scenario(&mut app);
+    enter_playing(&mut app);
+
+    scenario_loaded(&mut app, TEST_START_ID);
+    app.update();
+    assert!(app.world().resource::<ReplayRecorder>().is_recording());
+    assert!(
+        app.world().resource::<SimulationLockstep>().0,
+        "a recorded run steps on the fixed clock"
+    );
+
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = Some(OutcomeActionConfig::new(
+        ScenarioOutcomeKind::Victory,
+        "Done.",
+    ));
+    app.update();
+
+    assert!(!app.world().resource::<ReplayRecorder>().is_recording());
+    assert!(!app.world().resource::<SimulationLockstep>().0);
+    let recorded = app.world().resource::<LastReplay>().0.clone();
+    assert_eq!(
+        recorded.as_ref().map(|replay| replay.scenario.as_str()),
+        Some(TEST_START_ID)
+    );
+    let watch = find_named(&mut app, "Outcome Replay Button").expect("watch replay button");
+    app.world_mut().trigger(Activate { entity: watch });
+    app.update();
+
+    assert_eq!(
+        app.world().resource::<LoadedScenario>().0.as_deref(),
+        Some(TEST_START_ID)
+    );
+    assert!(app.world().resource::<PlayerInputSuspended>().0);
+    assert!(app.world().resource::<SimulationLockstep>().0);
+    assert_eq!(
+        app.world().resource::<ReplayPlayback>().phase(),
+        ReplayPhase::Loading
+    );
+
+    // The loader's teardown clears the outcome before the load lands.
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = None;
+    scenario_loaded(&mut app, TEST_START_ID);
+
+    assert_eq!(
+        app.world().resource::<ReplayPlayback>().phase(),
+        ReplayPhase::Playing
+    );
+    assert!(
+        !app.world().resource::<ReplayRecorder>().is_recording(),
+        "a playback is not recorded"
+    );
+
+    // The recorded run never ticked, so it is over on the first frame: the
+    // controls come back and the run is still the one on offer.
+    app.update();
+    assert!(!app.world().resource::<ReplayPlayback>().is_active());
+    assert!(!app.world().resource::<PlayerInputSuspended>().0);
+    assert!(!app.world().resource::<SimulationLockstep>().0);
+    assert_eq!(app.world().resource::<LastReplay>().0, recorded);
+}
+
+/// A run is flown again at the tier it was recorded at, whatever the setting
+/// says now, and the setting is neither changed nor left overridden.
+#[test]
+fn a_replay_plays_back_at_its_recorded_tier() {
+    let mut app = app_with_outcome();
+    observe_load_scenario(&mut app);
+    app.insert_resource(Difficulty::Easy);
+    app.init_resource::<DifficultyOverride>();
+    app.init_resource::<DifficultyPolicy>();
+    enter_playing(&mut app);
+    scenario_loaded(&mut app, TEST_START_ID);
+    app.update();
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = Some(OutcomeActionConfig::new(
+        ScenarioOutcomeKind::Victory,
+        "Done.",
+    ));
+    app.update();
+    let recorded = app.world().resource::<LastReplay>().0.clone().unwrap();
+    assert_eq!(recorded.difficulty, Difficulty::Easy);
+
+    // The player has since moved to Hard.
+    app.insert_resource(Difficulty::Hard);
+    app.insert_resource(DifficultyPolicy::for_difficulty(Difficulty::Hard));
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = None;
+    watch_replay(app.world_mut(), recorded).expect("the replay is playable");
+    assert_eq!(
+        app.world().resource::<DifficultyOverride>().0,
+        Some(Difficulty::Easy)
+    );
+    assert_eq!(
+        *app.world().resource::<DifficultyPolicy>(),
+        DifficultyPolicy::for_difficulty(Difficulty::Easy),
+        "the ships spawn under the recorded tier"
+    );
+    assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Hard);
+
+    scenario_loaded(&mut app, TEST_START_ID);
+    app.update();
+    assert!(!app.world().resource::<ReplayPlayback>().is_active());
+    assert_eq!(app.world().resource::<DifficultyOverride>().0, None);
+    assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Hard);
+}
+
+/// Leaving play mid-playback gives the controls back.
+#[test]
+fn leaving_play_ends_the_playback_and_returns_the_controls() {
+    let mut app = app_with_outcome();
+    observe_load_scenario(&mut app);
+    enter_playing(&mut app);
+    scenario_loaded(&mut app, TEST_START_ID);
+    app.update();
+    app.world_mut().resource_mut::<CurrentOutcome>().0 = Some(OutcomeActionConfig::new(
+        ScenarioOutcomeKind::Defeat,
+        "Lost.",
+    ));
+    app.update();
+    let watch = find_named(&mut app, "Outcome Replay Button").expect("watch replay button");
+    app.world_mut().trigger(Activate { entity: watch });
+    app.update();
+    assert!(app.world().resource::<PlayerInputSuspended>().0);
+
+    app.world_mut()
+        .resource_mut::<NextState<GameStates>>()
+        .set(GameStates::MainMenu);
+    app.update();
+
+    assert!(!app.world().resource::<PlayerInputSuspended>().0);
+    assert!(!app.world().resource::<ReplayPlayback>().is_active());
+}
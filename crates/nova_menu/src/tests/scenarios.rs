@@ -11,8 +11,8 @@ use nova_ui::{
 };
 
 use super::support::{
-    app, entity_by_name, label_of, observe_load_scenario, LoadedScenario, TEST_BACKDROP_ID,
-    TEST_START_ID,
+    all_texts, app, entity_by_name, label_of, observe_load_scenario, LoadedScenario,
+    TEST_BACKDROP_ID, TEST_START_ID,
 };
 use crate::scenarios::{
     CampaignHeader, NewGameScenario, ScenarioRow, ScenariosList, SelectedScenarioId,
@@ -510,6 +510,99 @@ fn play_button_hands_off_and_loads_the_chosen_scenario() {
     );
 }
 
+/// The details pane carries the difficulty row above Play: the current tier
+/// is highlighted, and a click on another moves the resource and the
+/// highlight, so the next Play starts at it.
+#[test]
+fn the_difficulty_row_picks_the_tier_a_scenario_starts_at() {
+    let mut app = scenarios_app();
+    let normal = entity_by_name(&mut app, "Difficulty Normal").expect("difficulty row");
+    assert!(app.world().entity(normal).contains::<Selected>());
+
+    let hard = entity_by_name(&mut app, "Difficulty Hard").expect("hard option");
+    app.world_mut().trigger(Activate { entity: hard });
+    app.update();
+
+    assert_eq!(*app.world().resource::<Difficulty>(), Difficulty::Hard);
+    assert!(app.world().entity(hard).contains::<Selected>());
+    assert!(!app.world().entity(normal).contains::<Selected>());
+}
+
+/// A survival scenario's details carry the seed the waves will be built from
+/// - drawn on first show, redrawn by Reroll - and its high-score table at the
+/// picked tier.
+#[test]
+fn a_survival_scenario_shows_its_seed_reroll_and_best_runs() {
+    let mut app = app();
+    let (id, mut holdout) = picker_scenario("holdout", "Holdout", false);
+    holdout.survival = Some(SurvivalConfig::default());
+    app.insert_resource(GameScenarios(bevy::platform::collections::HashMap::from([
+        (id, holdout),
+        picker_scenario(TEST_START_ID, "Shakedown Run", false),
+        picker_scenario(TEST_BACKDROP_ID, "Menu Ambience", true),
+    ])));
+    let mut scores = app.world_mut().resource_mut::<SurvivalScores>();
+    scores.record(
+        "holdout",
+        SurvivalScore {
+            waves: 4,
+            time: 83.44,
+            seed: 0xbeef,
+            difficulty: Difficulty::Hard,
+        },
+    );
+    scores.record(
+        "holdout",
+        SurvivalScore {
+            waves: 6,
+            time: 120.0,
+            seed: 0x1,
+            difficulty: Difficulty::Normal,
+        },
+    );
+    app.world_mut()
+        .resource_mut::<NextState<GameStates>>()
+        .set(GameStates::MainMenu);
+    app.update();
+    app.update();
+
+    assert_eq!(selected_scenario(&app).as_deref(), Some("holdout"));
+    let drawn = app
+        .world()
+        .resource::<SurvivalSeed>()
+        .0
+        .expect("a seed is drawn");
+    let seed_text = |app: &mut App| {
+        let label = entity_by_name(app, "Survival Seed").expect("seed row");
+        app.world().get::<Text>(label).unwrap().0.clone()
+    };
+    assert_eq!(seed_text(&mut app), format!("SEED {drawn:016X}"));
+    let hard_run = "1. 4 WAVES  01:23.4  HARD  000000000000BEEF";
+    let texts = all_texts(&mut app);
+    assert!(texts
+        .iter()
+        .any(|text| text == "1. 6 WAVES  02:00.0  NORMAL  0000000000000001"));
+    assert!(!texts.iter().any(|text| text == hard_run));
+
+    let hard = entity_by_name(&mut app, "Difficulty Hard").expect("hard option");
+    app.world_mut().trigger(Activate { entity: hard });
+    app.update();
+    let texts = all_texts(&mut app);
+    assert!(texts.iter().any(|text| text == hard_run));
+    assert!(!texts.iter().any(|text| text.contains("NORMAL")));
+    assert!(
+        app.world().get_entity(hard).is_ok(),
+        "only the table is rebuilt"
+    );
+
+    let reroll = entity_by_name(&mut app, "Survival Reroll Button").expect("reroll");
+    app.world_mut().trigger(Activate { entity: reroll });
+    app.update();
+    let rerolled = app.world().resource::<SurvivalSeed>().0.unwrap();
+    assert_ne!(rerolled, drawn);
+    assert_eq!(seed_text(&mut app), format!("SEED {rerolled:016X}"));
+}
+
 /// The flat baseline (the interim campaign grouping is superseded by the collapsible-
 /// header UI): the picker lists every `!hidden` scenario sorted by display name, and a
 /// hidden backdrop does not render. Reads the ACTUAL spawned row Text in child order
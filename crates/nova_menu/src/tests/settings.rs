@@ -1,22 +1,31 @@
 //! The settings screen: that the controls build, that the skin buttons and the
-//! volume slider write through to their resources and reskin live, and that an
-//! edit made just before quitting is still persisted.
+//! volume slider write through to their resources and reskin live, that the
+//! Controls chips rebind the keymap, and that an edit made just before quitting
+//! is still persisted.
 
 use bevy::{
     prelude::*,
     ui_widgets::{SliderValue, ValueChange},
 };
 use nova_gameplay::prelude::*;
+use nova_ship::input::{
+    keymap::{Keymap, KeymapAction},
+    player::InputSource,
+};
 use nova_ui::{
     prelude::UiSkin,
+    theme::semantic::Palette,
     widget::{
-        button_on_setting, segmented_option, ButtonValue, Selected, SliderBlock, SliderFill,
-        SLIDER_SEGMENTS,
+        button_on_setting, segmented_option, ButtonLabel, ButtonValue, Selected, SliderBlock,
+        SliderFill, SLIDER_SEGMENTS,
     },
 };
 
 use super::support::{all_texts, entity_by_name, mods_app};
-use crate::settings::{VolumeLabel, VolumeSlider};
+use crate::{
+    controls::{KeymapCapture, KeymapConflictNote},
+    settings::{VolumeLabel, VolumeSlider},
+};
 
 /// DoD 2: pressing a `UI skin` segmented button (a `ThemedButton` carrying
 /// `ButtonValue<UiSkin>`) drives the shared `UiSkin` resource + moves `Selected`,
@@ -55,7 +64,7 @@ fn ui_skin_button_sets_resource() {
 }
 
 /// The Settings panel is no longer a stub: the shared body builds the audio
-/// volume control, the graphics preset, and the read-only keybind reference.
+/// volume control, the graphics preset, and the Controls keymap rows.
 /// Structural (the panel is hidden until toggled, but its entities exist), so
 /// it pins that the controls are actually wired - not an empty placeholder.
 /// Assertions are disk-independent: the loaded preset can be any saved value,
@@ -67,7 +76,7 @@ fn settings_panel_builds_its_controls() {
     // The section headers and at least one keybind reference row render
     // (panel_header uppercases). "AUDIO"/"GRAPHICS"/"CONTROLS" + a control.
     let texts = all_texts(&mut app);
-    for header in ["AUDIO", "GRAPHICS", "CONTROLS"] {
+    for header in ["AUDIO", "GRAPHICS", "CONTROLS", "ACCESSIBILITY"] {
         assert!(
             texts.iter().any(|t| t == header),
             "the settings body is missing the {header} section"
@@ -75,7 +84,7 @@ fn settings_panel_builds_its_controls() {
     }
     assert!(
         texts.iter().any(|t| t == "Main Drive"),
-        "the keybind reference rows are missing (no Main Drive row)"
+        "the Controls keymap rows are missing (no Main Drive row)"
     );
 
     // Exactly one volume slider, seeded to the current level, with a thumb
@@ -146,6 +155,71 @@ fn dragging_the_volume_slider_sets_master_volume() {
     );
 }
 
+/// The Accessibility section writes the ONE persisted struct, not the derived
+/// palette: a palette press lands in `AccessibilitySettings` and moves the
+/// row's selection, and the text-size slider sets the scale the same way.
+#[test]
+fn accessibility_controls_write_the_settings() {
+    let mut app = mods_app();
+    let deuteran = entity_by_name(&mut app, "Palette Deuteran").expect("palette row exists");
+    app.world_mut()
+        .trigger(bevy::ui_widgets::Activate { entity: deuteran });
+    app.update();
+    assert_eq!(
+        app.world().resource::<AccessibilitySettings>().palette,
+        Palette::Deuteranopia
+    );
+    let selected: Vec<Palette> = {
+        let mut q = app
+            .world_mut()
+            .query_filtered::<&ButtonValue<Palette>, With<Selected>>();
+        q.iter(app.world()).map(|value| value.0).collect()
+    };
+    assert_eq!(selected, vec![Palette::Deuteranopia], "the selection moved");
+
+    let slider = entity_by_name(&mut app, "Text size Slider Track").expect("text size slider");
+    app.world_mut().trigger(ValueChange::<f32> {
+        source: slider,
+        value: 1.25,
+        is_final: true,
+    });
+    app.update();
+    assert_eq!(
+        app.world().resource::<AccessibilitySettings>().text_scale,
+        1.25
+    );
+}
+
+/// Each bus slider is its own channel: dragging the comms slider moves only
+/// the comms bus, leaving the other buses and the master volume where they
+/// were.
+#[test]
+fn dragging_a_bus_slider_sets_only_that_bus() {
+    let mut app = mods_app();
+    let slider = entity_by_name(&mut app, "Comms Slider Track").expect("comms slider exists");
+    app.world_mut().trigger(ValueChange::<f32> {
+        source: slider,
+        value: 0.4,
+        is_final: true,
+    });
+    app.update();
+    let buses = *app.world().resource::<BusVolumes>();
+    assert!((buses.comms - 0.4).abs() < 1e-6);
+    assert_eq!(
+        BusVolumes {
+            comms: BusVolumes::default().comms,
+            ..buses
+        },
+        BusVolumes::default(),
+        "the other buses are untouched"
+    );
+    assert_eq!(
+        app.world().resource::<MasterVolume>().0,
+        MasterVolume::default().0,
+        "the master channel is untouched"
+    );
+}
+
 /// DoD at the CALLER, not just the widget: the SHIPPED settings volume slider - the one
 /// the owner played with - re-skins live and shows its value in the new skin. A widget-
 /// level test proves the factory; this proves the wiring (lesson
@@ -271,3 +345,119 @@ fn a_setting_edited_just_before_quitting_is_still_saved() {
     unsafe { std::env::remove_var(nova_assets::storage::CONFIG_ROOT_ENV) };
     let _ = std::fs::remove_dir_all(&store);
 }
+
+/// The menu app on the SHIPPED keymap, whatever the developer's own store
+/// loaded at startup.
+fn default_keymap_app() -> App {
+    let mut app = mods_app();
+    app.world_mut().insert_resource(Keymap::default());
+    app.update();
+    app
+}
+
+/// The text on a Controls chip's label span.
+fn chip_label(app: &mut App, chip: Entity) -> String {
+    let children: Vec<Entity> = app
+        .world()
+        .entity(chip)
+        .get::<Children>()
+        .expect("a chip has children")
+        .iter()
+        .collect();
+    let mut q = app.world_mut().query_filtered::<&Text, With<ButtonLabel>>();
+    children
+        .into_iter()
+        .find_map(|child| q.get(app.world(), child).ok().map(|t| t.0.clone()))
+        .expect("a chip has a label span")
+}
+
+/// Arm `chip`, let the arming click release, then press `key` for one frame.
+fn rebind_with(app: &mut App, chip: Entity, key: KeyCode) {
+    app.world_mut()
+        .trigger(bevy::ui_widgets::Activate { entity: chip });
+    app.update();
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(key);
+    app.update();
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(key);
+    keys.clear();
+}
+
+/// Clicking the Autopilot: Stop keyboard chip and pressing K rebinds the
+/// action in the live keymap, and the chip relabels to the new key.
+#[test]
+fn a_keymap_chip_captures_the_next_key() {
+    let mut app = default_keymap_app();
+    let chip = entity_by_name(&mut app, "Keymap Chip: Autopilot: Stop Keyboard")
+        .expect("the Stop row has a keyboard chip");
+    assert_eq!(chip_label(&mut app, chip), "X");
+
+    rebind_with(&mut app, chip, KeyCode::KeyK);
+
+    assert_eq!(
+        app.world()
+            .resource::<Keymap>()
+            .get(KeymapAction::AutopilotStop)
+            .keyboard,
+        [Some(InputSource::Keyboard(KeyCode::KeyK)), None]
+    );
+    assert_eq!(chip_label(&mut app, chip), "K");
+    assert!(!app.world().resource::<KeymapCapture>().is_armed());
+}
+
+/// A fixed system control is refused: the keymap keeps its binding and the
+/// chip stays armed for another press.
+#[test]
+fn a_fixed_control_cannot_be_captured() {
+    let mut app = default_keymap_app();
+    let chip = entity_by_name(&mut app, "Keymap Chip: Autopilot: Stop Keyboard")
+        .expect("the Stop row has a keyboard chip");
+
+    rebind_with(&mut app, chip, KeyCode::Backquote);
+
+    assert!(app.world().resource::<Keymap>().is_default());
+    let capture = app.world().resource::<KeymapCapture>();
+    assert!(capture.is_armed(), "the chip keeps waiting");
+    assert!(capture
+        .note
+        .as_ref()
+        .is_some_and(|note| note.contains("HUD level")));
+}
+
+/// Moving Stop onto G (Go To's key) flags BOTH rows, and Reset to Defaults
+/// clears the keymap and the flags.
+#[test]
+fn a_shared_key_is_flagged_until_reset() {
+    let mut app = default_keymap_app();
+    let chip = entity_by_name(&mut app, "Keymap Chip: Autopilot: Stop Keyboard")
+        .expect("the Stop row has a keyboard chip");
+    rebind_with(&mut app, chip, KeyCode::KeyG);
+    app.update();
+
+    let shown = |app: &mut App| -> Vec<(KeymapAction, String)> {
+        let mut q = app
+            .world_mut()
+            .query::<(&KeymapConflictNote, &Text, &Node)>();
+        q.iter(app.world())
+            .filter(|(_, _, node)| node.display != Display::None)
+            .map(|(note, text, _)| (note.0, text.0.clone()))
+            .collect()
+    };
+    let flagged = shown(&mut app);
+    assert_eq!(flagged.len(), 2, "both rows sharing G are flagged");
+    assert!(flagged.contains(&(
+        KeymapAction::AutopilotStop,
+        "shared with Autopilot: Go To".to_string()
+    )));
+
+    let reset = entity_by_name(&mut app, "Keymap Reset Button").expect("a reset button");
+    app.world_mut()
+        .trigger(bevy::ui_widgets::Activate { entity: reset });
+    app.update();
+
+    assert!(app.world().resource::<Keymap>().is_default());
+    assert!(shown(&mut app).is_empty(), "the flags clear with the reset");
+    assert_eq!(chip_label(&mut app, chip), "X");
+}
@@ -13,6 +13,8 @@
 //! - [`Content::Style`] - a [`ShipStyleConfig`], the look a ship's derived skin
 //!   wears: materials per surface role plus the decoration it scatters, and
 //! - [`Content::Ship`] - a [`ShipConfig`], a whole hull a scenario spawns by id.
+//! - [`Content::Music`] - a [`MusicTrackConfig`], a layered music track a
+//!   scenario's `SetMusic` plays by id.
 //!
 //! The kind lives IN the RON structure (an externally-tagged enum), so ONE
 //! loader reads any content file and a downstream router (`nova_assets`'s
@@ -42,6 +44,7 @@ use bevy::{
     prelude::*,
     reflect::TypePath,
 };
+use nova_gameplay::prelude::MusicTrackConfig;
 // NOTE: the pure serde format types live in the engine-free `nova_mod_format`
 // crate so the portal generator builds without bevy; re-exported here so game
 // code keeps importing them from nova_modding.
@@ -89,6 +92,10 @@ pub enum Content {
     /// authored once and spawned by id, so a scenario names a corvette instead
     /// of carrying a copy of one.
     Ship(ShipConfig),
+    /// A [`MusicTrackConfig`] - registers into `GameMusic` keyed by its id.
+    /// Its stems are `self://` resources like any other mod audio, so a mod
+    /// ships a score and a scenario plays it with `SetMusic`.
+    Music(MusicTrackConfig),
 }
 
 /// The content of one `*.content.ron` file: a thin [`Asset`] wrapper around a
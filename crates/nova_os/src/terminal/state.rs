@@ -355,6 +355,29 @@ impl NovaOsTerminal {
         self.pending_invocation.as_ref()
     }
 
+    /// Queue a gameplay invocation as if its line had just been submitted, for
+    /// a replay re-issuing a recorded command or an app button standing in for
+    /// its verb. `name` is matched against the
+    /// mirrored command set and must be a
+    /// [`CommandDispatch::Gameplay`](crate::shell::CommandDispatch::Gameplay)
+    /// command; `false` (and nothing queued) when it is not one, or when an
+    /// invocation is already waiting to be applied.
+    pub fn replay_invocation(&mut self, name: &str, args: Vec<String>) -> bool {
+        if self.pending_invocation.is_some() {
+            return false;
+        }
+        let Some(spec) = self.commands.iter().find(|spec| {
+            spec.name == name && spec.dispatch == crate::shell::CommandDispatch::Gameplay
+        }) else {
+            return false;
+        };
+        self.pending_invocation = Some(NovaOsCommandInvocation {
+            name: spec.name,
+            args,
+        });
+        true
+    }
+
     /// Whether the `exit` command has requested an animated close, clearing the
     /// request as it is read.
     pub fn take_pending_close(&mut self) -> bool {
@@ -363,6 +386,12 @@ impl NovaOsTerminal {
         pending
     }
 
+    /// Ask for the same animated close the `exit` command does, for a gameplay
+    /// verb that hands the screen to something else.
+    pub fn request_close(&mut self) {
+        self.pending_close = true;
+    }
+
     /// How many flight-log entries had been seen the last time the computer
     /// closed (for the boot banner's unread count).
     pub fn seen_events(&self) -> usize {
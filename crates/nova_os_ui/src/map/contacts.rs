@@ -11,6 +11,7 @@ use nova_events::prelude::{EntityId, EntityTypeName, ASTEROID_TYPE_NAME};
 use nova_gameplay::prelude::*;
 use nova_hud::allegiance_markers::allegiance_color;
 use nova_os::prelude::*;
+use nova_ui::theme::semantic::Palette;
 
 use crate::terminal::{NOVA_OS_AMBER, NOVA_OS_PHOSPHOR};
 
@@ -35,14 +36,15 @@ impl MapContactKind {
         }
     }
 
-    /// Blip / readout tint, consistent with the allegiance-marker palette.
-    pub(crate) fn color(self) -> Color {
+    /// Blip / readout tint in `palette`, consistent with the allegiance
+    /// markers.
+    pub(crate) fn color(self, palette: Palette) -> Color {
         match self {
             MapContactKind::OwnShip => NOVA_OS_PHOSPHOR,
-            MapContactKind::Ally => allegiance_color(Some(&Allegiance::Player)),
-            MapContactKind::Hostile => allegiance_color(Some(&Allegiance::Enemy)),
+            MapContactKind::Ally => allegiance_color(palette, Some(&Allegiance::Player)),
+            MapContactKind::Hostile => allegiance_color(palette, Some(&Allegiance::Enemy)),
             MapContactKind::Objective => NOVA_OS_AMBER,
-            MapContactKind::Terrain => allegiance_color(Some(&Allegiance::Neutral)),
+            MapContactKind::Terrain => allegiance_color(palette, Some(&Allegiance::Neutral)),
         }
     }
 
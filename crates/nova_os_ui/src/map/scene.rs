@@ -17,7 +17,7 @@ use bevy::{
 use nova_gameplay::prelude::*;
 use nova_os::prelude::*;
 use nova_ship::prelude::*;
-use nova_ui::font::UiFont;
+use nova_ui::{font::UiFont, theme::semantic::Palette};
 
 use super::{app::*, contacts::*, *};
 use crate::terminal::{
@@ -389,6 +389,7 @@ pub(crate) fn project_map_blips(
     mut commands: Commands,
     mut runtime: ResMut<MapRuntime>,
     ui_font: Option<Res<UiFont>>,
+    palette: Option<Res<Palette>>,
     contacts: MapContacts,
     time: Res<Time>,
     q_camera: Query<(&Camera, &GlobalTransform), With<MapCameraMarker>>,
@@ -411,6 +412,7 @@ pub(crate) fn project_map_blips(
     let list = contacts.collect();
     let font = nova_os_font(ui_font.as_deref());
     let pulse = 0.6 + 0.4 * (time.elapsed_secs() * 4.0).sin().abs();
+    let palette = palette.map(|palette| *palette).unwrap_or_default();
 
     let mut seen = bevy::platform::collections::HashSet::new();
     for contact in &list {
@@ -420,7 +422,7 @@ pub(crate) fn project_map_blips(
             .ok()
             .filter(|p| p.x >= 0.0 && p.y >= 0.0 && p.x <= size.x && p.y <= size.y);
         let selected = runtime.selected == Some(contact.entity);
-        let mut base = contact.kind.color();
+        let mut base = contact.kind.color(palette);
         if contact.kind == MapContactKind::Hostile {
             base = base.with_alpha(pulse);
         }
@@ -428,7 +430,7 @@ pub(crate) fn project_map_blips(
         let blip = if let Some(&blip) = runtime.blips.get(&contact.entity) {
             blip
         } else {
-            let id = spawn_blip(&mut commands, viewport, contact, font.clone());
+            let id = spawn_blip(&mut commands, viewport, contact, palette, font.clone());
             runtime.blips.insert(contact.entity, id);
             id
         };
@@ -478,9 +480,10 @@ pub(crate) fn spawn_blip(
     commands: &mut Commands,
     viewport: Entity,
     contact: &MapContact,
+    palette: Palette,
     font: Handle<Font>,
 ) -> Entity {
-    let color = contact.kind.color();
+    let color = contact.kind.color(palette);
     let id = commands
         .spawn((
             MapBlip {
@@ -8,6 +8,7 @@ use bevy::{
 };
 use nova_events::prelude::{EntityTypeName, ASTEROID_TYPE_NAME};
 use nova_ship::prelude::*;
+use nova_ui::theme::semantic::Palette;
 
 use super::{app::*, contacts::*, scene::*, *};
 use crate::pointer_rig::{
@@ -732,7 +733,13 @@ fn rig_place_blip(
         .run_system_once_with(
             |input: In<(Entity, MapContact)>, mut commands: Commands| {
                 let (viewport, contact) = input.0;
-                spawn_blip(&mut commands, viewport, &contact, Handle::default())
+                spawn_blip(
+                    &mut commands,
+                    viewport,
+                    &contact,
+                    Palette::Standard,
+                    Handle::default(),
+                )
             },
             (viewport, contact.clone()),
         )
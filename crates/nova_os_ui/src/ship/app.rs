@@ -61,8 +61,8 @@ impl NovaOsAppRuntime for ShipApp {
 /// Fixed width of the inspector panel column, in px.
 pub(crate) const SHIP_PANEL_PX: f32 = 232.0;
 
-/// Build the inspector-panel subtree (title, live detail, action row, note) as a
-/// bordered CRT column. The three info text nodes carry a [`ShipPanelField`] so
+/// Build the inspector-panel subtree (title, live detail, action row, note,
+/// damage control, cargo) as a bordered CRT column. The info text nodes carry a [`ShipPanelField`] so
 /// one system can refresh them; the two buttons carry a [`ShipPanelButton`] and
 /// route through the [`ShipSectionCommand`] seam via `Activate` observers.
 pub(crate) fn spawn_ship_panel(parent: &mut ChildSpawnerCommands, font: Handle<Font>) {
@@ -116,17 +116,50 @@ pub(crate) fn spawn_ship_panel(parent: &mut ChildSpawnerCommands, font: Handle<F
                         });
                 });
             panel
-                .spawn(panel_button_bundle(ShipPanelButton::Rebind))
-                .observe(on_ship_rebind_button)
-                .with_children(|button| {
-                    button.spawn(panel_button_label("B Rebind", font.clone()));
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(6.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn(panel_button_bundle(ShipPanelButton::Rebind))
+                        .observe(on_ship_rebind_button)
+                        .with_children(|button| {
+                            button.spawn(panel_button_label("B Rebind", font.clone()));
+                        });
+                    // Lit only while docked at a station that refits.
+                    row.spawn(panel_button_bundle(ShipPanelButton::Refit))
+                        .observe(on_ship_refit_button)
+                        .with_children(|button| {
+                            button.spawn(panel_button_label("Refit", font.clone()));
+                        });
                 });
             panel.spawn((
                 ShipPanelField::Note,
                 Text::new(String::new()),
-                nova_os_text_font(DRAWER_LINE_FONT_PX - 4.0, font),
+                nova_os_text_font(DRAWER_LINE_FONT_PX - 4.0, font.clone()),
                 TextColor(NOVA_OS_PHOSPHOR_MUTED),
             ));
+            panel.spawn((
+                ShipPanelField::DamageControl,
+                Text::new(String::new()),
+                nova_os_text_font(DRAWER_LINE_FONT_PX - 3.0, font.clone()),
+                TextColor(NOVA_OS_TEXT),
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
+            panel.spawn((
+                ShipPanelField::Cargo,
+                Text::new(String::new()),
+                nova_os_text_font(DRAWER_LINE_FONT_PX - 3.0, font),
+                TextColor(NOVA_OS_TEXT),
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+            ));
         });
 }
 
@@ -155,23 +188,32 @@ pub(crate) fn panel_button_label(label: &str, font: Handle<Font>) -> impl Bundle
     )
 }
 /// Keep the terminal's arg-completion set in sync with the live section codes, so
-/// `ship repair <TAB>` offers them. Only writes on a real change.
+/// `ship repair <TAB>` (and `dock refit <TAB>`) offers them, and `ship rebuild
+/// <TAB>` the lost section ids. Only writes on a real change.
 pub(crate) fn sync_ship_arg_completions(
     sections: ShipSections,
+    q_blueprint: Query<&ShipBlueprint, With<PlayerSpaceshipMarker>>,
     mut runtime: ResMut<ShipRuntime>,
     mut terminal: ResMut<NovaOsTerminal>,
 ) {
     let codes = sections.codes();
-    if codes == runtime.completion_codes {
+    let lost: Vec<String> = q_blueprint
+        .iter()
+        .next()
+        .map(|blueprint| blueprint.lost().map(|entry| entry.id.clone()).collect())
+        .unwrap_or_default();
+    if codes == runtime.completion_codes && lost == runtime.completion_lost {
         return;
     }
     runtime.completion_codes = codes.clone();
+    runtime.completion_lost = lost.clone();
     // Merge (not replace) so the `map goto` completions the map app owns survive;
     // the `!=` gate above already ensured this set changed.
     terminal.merge_arg_completions(
-        ["ship section", "ship reload", "ship repair"]
+        ["ship section", "ship reload", "ship repair", "dock refit"]
             .into_iter()
-            .map(|verb| (verb, codes.clone())),
+            .map(|verb| (verb, codes.clone()))
+            .chain(std::iter::once(("ship rebuild", lost))),
     );
 }
 
@@ -187,6 +229,7 @@ pub(crate) fn sync_ship_arg_completions(
     reason = "one query term per section field the CLI prints"
 )]
 pub(crate) fn apply_ship_cli_commands(
+    mut commands: Commands,
     mut terminal: ResMut<NovaOsTerminal>,
     q_player: Query<Entity, (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>)>,
     q_sections: Query<
@@ -196,12 +239,17 @@ pub(crate) fn apply_ship_cli_commands(
             &SectionCode,
             Option<&Name>,
             SectionKindQuery,
-            (Has<SectionInactiveMarker>, Has<HealthZeroMarker>),
+            (
+                Has<SectionInactiveMarker>,
+                Has<HealthZeroMarker>,
+                Option<&SectionRepairJob>,
+            ),
         ),
         With<SectionMarker>,
     >,
-    mut q_health: Query<&mut Health>,
+    q_health: Query<&Health>,
     mut q_ammo: Query<&mut SectionAmmo>,
+    mut q_control: Query<&mut DamageControl>,
 ) {
     // Peek first: the single pending slot is shared with the `map` gameplay verbs,
     // so only consume an invocation this handler owns (leave `map ...` for its own
@@ -234,14 +282,22 @@ pub(crate) fn apply_ship_cli_commands(
     // the not-found listing.
     let wanted = code.to_ascii_uppercase();
     let mut codes: Vec<String> = Vec::new();
-    let mut target: Option<(Entity, String, String, SectionClass, bool, bool)> = None;
+    let mut target: Option<(
+        Entity,
+        String,
+        String,
+        SectionClass,
+        bool,
+        bool,
+        Option<SectionRepairJob>,
+    )> = None;
     for (
         entity,
         child,
         section_code,
         name,
         (class, hull, controller, thruster, turret, torpedo),
-        (inactive, zero),
+        (inactive, zero, repair),
     ) in &q_sections
     {
         if child.0 != ship {
@@ -260,12 +316,13 @@ pub(crate) fn apply_ship_cli_commands(
                     kind,
                     inactive,
                     zero,
+                    repair.copied(),
                 ));
             }
         }
     }
     codes.sort();
-    let Some((entity, code_str, name, kind, inactive, zero)) = target else {
+    let Some((entity, code_str, name, kind, inactive, zero, repair)) = target else {
         terminal.extend_scrollback(unknown_code_rows(code, &codes));
         return;
     };
@@ -273,7 +330,8 @@ pub(crate) fn apply_ship_cli_commands(
 
     let rows = match invocation.name {
         "ship section" => {
-            // Read integrity/ammo back through the mutable queries (read-only get).
+            // Read ammo back through the mutable query (read-only get).
+            let health = q_health.get(entity).ok();
             let view = ShipSectionView {
                 entity,
                 code: code_str,
@@ -282,11 +340,12 @@ pub(crate) fn apply_ship_cli_commands(
                 local: Transform::default(),
                 half_extents: Vec3::ONE,
                 link_points: Vec::new(),
-                health: q_health.get(entity).ok().cloned(),
+                health: health.cloned(),
                 ammo: q_ammo.get(entity).ok().copied(),
                 bindings: None,
                 inactive,
                 zero_health: zero,
+                repair: repair.zip(health).map(|(job, health)| job.progress(health)),
             };
             section_detail_rows(&view)
         }
@@ -296,16 +355,22 @@ pub(crate) fn apply_ship_cli_commands(
             } else {
                 ShipAction::Repair
             };
-            let mut health = q_health.get_mut(entity).ok();
             let mut ammo = q_ammo.get_mut(entity).ok();
-            vec![apply_action_to_section(
+            let mut control = q_control.get_mut(ship).ok();
+            let (row, job) = apply_action_to_section(
                 action,
                 &code_str,
                 kind,
                 is_weapon,
-                health.as_deref_mut(),
+                q_health.get(entity).ok(),
                 ammo.as_deref_mut(),
-            )]
+                control.as_deref_mut(),
+                repair.is_some(),
+            );
+            if let Some(job) = job {
+                commands.entity(entity).insert(job);
+            }
+            vec![row]
         }
         _ => vec![TerminalRow {
             kind: TerminalRowKind::Error,
@@ -318,6 +383,7 @@ pub(crate) fn apply_ship_cli_commands(
 /// Apply in-app [`ShipSectionCommand`] messages (the `L`/`P` action keys and the
 /// panel buttons), and flash the result on the panel note line.
 pub(crate) fn apply_ship_section_commands(
+    mut commands: Commands,
     mut messages: MessageReader<ShipSectionCommand>,
     mut runtime: ResMut<ShipRuntime>,
     q_view: Query<(
@@ -328,12 +394,14 @@ pub(crate) fn apply_ship_section_commands(
         Has<ThrusterSectionMarker>,
         Has<TurretSectionMarker>,
         Has<TorpedoSectionMarker>,
+        (Option<&ChildOf>, Has<SectionRepairJob>),
     )>,
-    mut q_health: Query<&mut Health>,
+    q_health: Query<&Health>,
     mut q_ammo: Query<&mut SectionAmmo>,
+    mut q_control: Query<&mut DamageControl>,
 ) {
     for command in messages.read() {
-        let Ok((code, class, hull, controller, thruster, turret, torpedo)) =
+        let Ok((code, class, hull, controller, thruster, turret, torpedo, (parent, repairing))) =
             q_view.get(command.target)
         else {
             continue;
@@ -344,20 +412,143 @@ pub(crate) fn apply_ship_section_commands(
             continue;
         };
         let is_weapon = matches!(kind, SectionClass::Turret | SectionClass::Torpedo);
-        let mut health = q_health.get_mut(command.target).ok();
         let mut ammo = q_ammo.get_mut(command.target).ok();
-        let row = apply_action_to_section(
+        let mut control = parent.and_then(|parent| q_control.get_mut(parent.parent()).ok());
+        let (row, job) = apply_action_to_section(
             command.action,
             &code.0,
             kind,
             is_weapon,
-            health.as_deref_mut(),
+            q_health.get(command.target).ok(),
             ammo.as_deref_mut(),
+            control.as_deref_mut(),
+            repairing,
         );
+        if let Some(job) = job {
+            commands.entity(command.target).insert(job);
+        }
         runtime.note = Some((row.text, 2.5));
     }
 }
 
+/// Drain a queued `ship rebuild [id]`. Bare, it lists the player ship's lost
+/// sections with what damage control can do about them; with an id, it starts
+/// rebuilding that section through [`DamageControl::start_rebuild`].
+pub(crate) fn apply_ship_rebuild_command(
+    mut commands: Commands,
+    mut terminal: ResMut<NovaOsTerminal>,
+    mut q_player: Query<
+        (
+            Entity,
+            Option<&mut DamageControl>,
+            &ShipBlueprint,
+            Option<&SectionRebuildJob>,
+            Has<Docked>,
+        ),
+        (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
+    >,
+) {
+    let owns = terminal
+        .peek_pending_invocation()
+        .is_some_and(|inv| inv.name == "ship rebuild");
+    if !owns {
+        return;
+    }
+    let Some(invocation) = terminal.take_pending_invocation() else {
+        return;
+    };
+    let Ok((ship, control, blueprint, rebuild, docked)) = q_player.single_mut() else {
+        terminal.extend_scrollback([TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: "no live player ship".to_string(),
+        }]);
+        return;
+    };
+    let Some(mut control) = control else {
+        terminal.extend_scrollback([TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: "rebuild: no damage control aboard".to_string(),
+        }]);
+        return;
+    };
+
+    let Some(id) = invocation.args.first() else {
+        terminal.extend_scrollback(rebuild_listing_rows(&control, blueprint, rebuild));
+        return;
+    };
+    let row = match control.start_rebuild(blueprint, id, rebuild.is_some(), docked) {
+        Ok(job) => {
+            let row = TerminalRow {
+                kind: TerminalRowKind::Info,
+                text: format!(
+                    "rebuilding {}: {:.0}s, {} parts left",
+                    job.section, REBUILD_SECONDS, control.parts
+                ),
+            };
+            commands.entity(ship).insert(job);
+            row
+        }
+        Err(refusal) => TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: format!("rebuild {id}: {refusal}"),
+        },
+    };
+    terminal.extend_scrollback([row]);
+}
+
+/// The bare `ship rebuild` listing: parts and combat state, the running
+/// rebuild, then each lost section and whether it can be rebuilt now.
+pub(crate) fn rebuild_listing_rows(
+    control: &DamageControl,
+    blueprint: &ShipBlueprint,
+    rebuild: Option<&SectionRebuildJob>,
+) -> Vec<TerminalRow> {
+    let mut rows = vec![TerminalRow {
+        kind: TerminalRowKind::Output,
+        text: format!(
+            "damage control: {} parts, {}",
+            control.parts,
+            if control.in_combat() {
+                "in combat"
+            } else {
+                "stood down"
+            }
+        ),
+    }];
+    if let Some(rebuild) = rebuild {
+        rows.push(TerminalRow {
+            kind: TerminalRowKind::Info,
+            text: format!(
+                "rebuilding {} {}",
+                rebuild.section,
+                ascii_meter(rebuild.progress())
+            ),
+        });
+    }
+    let lost: Vec<&BlueprintSection> = blueprint
+        .lost()
+        .filter(|entry| rebuild.is_none_or(|rebuild| rebuild.section != entry.id))
+        .collect();
+    if lost.is_empty() && rebuild.is_none() {
+        rows.push(TerminalRow {
+            kind: TerminalRowKind::Dim,
+            text: "no lost sections".to_string(),
+        });
+    }
+    for entry in lost {
+        let state = if entry.attachable {
+            format!("{REBUILD_PART_COST} parts, {REBUILD_SECONDS:.0}s")
+        } else {
+            "no standing neighbour".to_string()
+        };
+        rows.push(TerminalRow {
+            kind: TerminalRowKind::Output,
+            text: format!("  {}  {}  {state}", entry.id, entry.name),
+        });
+    }
+    rows
+}
+
 // ---------------------------------------------------------------------------
 // Scene systems
 // ---------------------------------------------------------------------------
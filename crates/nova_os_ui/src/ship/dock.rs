@@ -0,0 +1,321 @@
+//! The `dock` CLI verbs: what a station does for the player ship while it is
+//! docked there.
+//!
+//! `dock` prints the station and what it offers; `dock rearm` fills every
+//! magazine; `dock repair` restores every standing section at once and
+//! restocks damage control to the ship's own allowance (its authored parts as
+//! the difficulty scaled them); bare `dock refit` lists what each section
+//! could be swapped for and opens the refit bay ([`OpenRefitBay`]) over the
+//! closing computer, while `dock refit [section] [part]` lists one section or
+//! swaps it for another catalog part of the same kind through
+//! [`RefitSection`]. Every verb refuses unless the ship is [`Docked`] at a
+//! station offering that service.
+
+use bevy::prelude::*;
+use nova_events::prelude::{EntityId, EntityTypeName};
+use nova_gameplay::prelude::*;
+use nova_os::prelude::*;
+use nova_ship::prelude::*;
+
+use super::SectionCode;
+
+/// The `dock` command tree: bare `dock` prints the station, the subcommands ask
+/// it for a service.
+pub(crate) fn dock_command_tree() -> TerminalCommand {
+    TerminalCommand::gameplay(
+        "dock",
+        "Show the station you are docked at",
+        CommandArity::None,
+    )
+    .with_subcommand(TerminalCommand::gameplay(
+        "dock rearm",
+        "Fill every magazine",
+        CommandArity::None,
+    ))
+    .with_subcommand(TerminalCommand::gameplay(
+        "dock repair",
+        "Restore every section and restock parts",
+        CommandArity::None,
+    ))
+    .with_subcommand(
+        TerminalCommand::gameplay(
+            "dock refit",
+            "Open the refit bay, or swap a section's part",
+            CommandArity::UpTo(2),
+        )
+        .with_arg_hint("[section] [part]"),
+    )
+}
+
+/// What a station without [`StationServices`] offers: nothing.
+const NO_SERVICES: StationServices = StationServices {
+    rearm: false,
+    repair: false,
+    refit: false,
+};
+
+/// Drain a queued `dock` verb and apply it against the player ship and the
+/// station it is docked at.
+#[expect(
+    clippy::type_complexity,
+    reason = "one query term per section field the verbs read or restore"
+)]
+pub(crate) fn apply_dock_commands(
+    mut commands: Commands,
+    mut terminal: ResMut<NovaOsTerminal>,
+    game_sections: Res<GameSections>,
+    mut q_player: Query<
+        (Entity, Option<&Docked>, Option<&mut DamageControl>),
+        (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
+    >,
+    q_station: Query<(Option<&EntityId>, Option<&StationServices>)>,
+    mut q_sections: Query<
+        (
+            Entity,
+            &ChildOf,
+            &SectionCode,
+            (Option<&EntityId>, Option<&EntityTypeName>),
+            Option<&mut Health>,
+            Option<&mut SectionAmmo>,
+            Has<HealthZeroMarker>,
+        ),
+        With<SectionMarker>,
+    >,
+) {
+    // Peek first: the pending slot is shared with the `ship` and `map` verbs.
+    let owns = terminal.peek_pending_invocation().is_some_and(|inv| {
+        matches!(
+            inv.name,
+            "dock" | "dock rearm" | "dock repair" | "dock refit"
+        )
+    });
+    if !owns {
+        return;
+    }
+    let Some(invocation) = terminal.take_pending_invocation() else {
+        return;
+    };
+    let Ok((ship, docked, control)) = q_player.single_mut() else {
+        terminal.extend_scrollback([TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: "no live player ship".to_string(),
+        }]);
+        return;
+    };
+    let Some(docked) = docked else {
+        terminal.extend_scrollback([
+            TerminalRow {
+                kind: TerminalRowKind::Error,
+                text: format!("{}: not docked", invocation.name),
+            },
+            TerminalRow {
+                kind: TerminalRowKind::Dim,
+                text: "travel-lock a station and press G to dock".to_string(),
+            },
+        ]);
+        return;
+    };
+    let (station, services) = match q_station.get(docked.station) {
+        Ok((id, services)) => (
+            id.map_or_else(|| "station".to_string(), |id| id.0.clone()),
+            services.copied().unwrap_or(NO_SERVICES),
+        ),
+        Err(_) => ("station".to_string(), NO_SERVICES),
+    };
+    let refused = |service: &str| {
+        vec![TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: format!("{service}: {station} offers no {service}"),
+        }]
+    };
+
+    let rows = match invocation.name {
+        "dock" => {
+            let offered: Vec<&str> = [
+                (services.rearm, "rearm"),
+                (services.repair, "repair"),
+                (services.refit, "refit"),
+            ]
+            .into_iter()
+            .filter_map(|(offered, name)| offered.then_some(name))
+            .collect();
+            vec![
+                TerminalRow {
+                    kind: TerminalRowKind::Info,
+                    text: format!("docked at {station}"),
+                },
+                TerminalRow {
+                    kind: TerminalRowKind::Output,
+                    text: if offered.is_empty() {
+                        "services: none".to_string()
+                    } else {
+                        format!("services: {}", offered.join(", "))
+                    },
+                },
+                TerminalRow {
+                    kind: TerminalRowKind::Dim,
+                    text: "burn or engage a verb to undock".to_string(),
+                },
+            ]
+        }
+        "dock rearm" if !services.rearm => refused("rearm"),
+        "dock rearm" => {
+            let mut filled = 0;
+            for (_, child, _, _, _, ammo, _) in &mut q_sections {
+                let Some(mut ammo) = ammo.filter(|_| child.0 == ship) else {
+                    continue;
+                };
+                if ammo.rounds < ammo.capacity {
+                    ammo.rounds = ammo.capacity;
+                    filled += 1;
+                }
+            }
+            vec![if filled == 0 {
+                TerminalRow {
+                    kind: TerminalRowKind::Dim,
+                    text: "rearm: every magazine is full".to_string(),
+                }
+            } else {
+                TerminalRow {
+                    kind: TerminalRowKind::Info,
+                    text: format!("rearmed {filled} magazine(s) at {station}"),
+                }
+            }]
+        }
+        "dock repair" if !services.repair => refused("repair"),
+        "dock repair" => {
+            // Lost sections are a rebuild, not a repair: only standing ones.
+            let mut restored = 0;
+            for (section, child, _, _, health, _, zero) in &mut q_sections {
+                if child.0 != ship || zero {
+                    continue;
+                }
+                if let Some(mut health) = health {
+                    if health.current < health.max {
+                        health.current = health.max;
+                        restored += 1;
+                    }
+                }
+                commands.entity(section).remove::<SectionRepairJob>();
+            }
+            let mut rows = vec![if restored == 0 {
+                TerminalRow {
+                    kind: TerminalRowKind::Dim,
+                    text: "repair: every section is whole".to_string(),
+                }
+            } else {
+                TerminalRow {
+                    kind: TerminalRowKind::Info,
+                    text: format!("repaired {restored} section(s) at {station}"),
+                }
+            }];
+            if let Some(mut control) = control {
+                if control.parts < control.allowance {
+                    control.parts = control.allowance;
+                    rows.push(TerminalRow {
+                        kind: TerminalRowKind::Info,
+                        text: format!("damage control restocked to {} parts", control.parts),
+                    });
+                }
+            }
+            rows
+        }
+        "dock refit" if !services.refit => refused("refit"),
+        _ => {
+            // (code, scenario id, fitted part) for every standing section.
+            let mut fitted: Vec<(String, String, String)> = q_sections
+                .iter()
+                .filter(|(_, child, .., zero)| child.0 == ship && !zero)
+                .filter_map(|(_, _, code, (id, part), ..)| {
+                    Some((code.0.clone(), id?.0.clone(), part?.0.clone()))
+                })
+                .collect();
+            fitted.sort();
+            let mut rows = refit_rows(
+                &mut commands,
+                ship,
+                &game_sections,
+                &fitted,
+                &invocation.args,
+            );
+            // Bare, the verb is the bay's door: the listing stays in the
+            // scrollback for the next time the computer opens.
+            if invocation.args.is_empty() {
+                commands.trigger(OpenRefitBay { entity: ship });
+                terminal.request_close();
+                rows.push(TerminalRow {
+                    kind: TerminalRowKind::Info,
+                    text: format!("opening the refit bay at {station}"),
+                });
+            }
+            rows
+        }
+    };
+    terminal.extend_scrollback(rows);
+}
+
+/// The `dock refit` answer: bare, every standing section with its part and
+/// what it could become; with a code, that one section; with a code and a
+/// part, the swap itself.
+fn refit_rows(
+    commands: &mut Commands,
+    ship: Entity,
+    game_sections: &GameSections,
+    fitted: &[(String, String, String)],
+    args: &[String],
+) -> Vec<TerminalRow> {
+    let options = |part: &str| -> Vec<String> {
+        refit_parts(game_sections, part)
+            .into_iter()
+            .map(|config| config.base.id.clone())
+            .collect()
+    };
+    let listing = |(code, _, part): &(String, String, String)| {
+        let options = options(part);
+        TerminalRow {
+            kind: TerminalRowKind::Output,
+            text: if options.is_empty() {
+                format!("{code}  {part}  (no other part of this kind)")
+            } else {
+                format!("{code}  {part}  -> {}", options.join(", "))
+            },
+        }
+    };
+
+    let Some(code) = args.first() else {
+        let mut rows = vec![TerminalRow {
+            kind: TerminalRowKind::Dim,
+            text: "section  part  -> refit options".to_string(),
+        }];
+        rows.extend(fitted.iter().map(listing));
+        return rows;
+    };
+    let Some(entry) = fitted
+        .iter()
+        .find(|(fitted_code, ..)| fitted_code.eq_ignore_ascii_case(code))
+    else {
+        return vec![TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: format!("refit: no standing section {code}"),
+        }];
+    };
+    let Some(part) = args.get(1) else {
+        return vec![listing(entry)];
+    };
+    let (code, id, current) = entry;
+    if !options(current).iter().any(|option| option == part) {
+        return vec![TerminalRow {
+            kind: TerminalRowKind::Error,
+            text: format!("refit {code}: {part} cannot replace {current}"),
+        }];
+    }
+    commands.trigger(RefitSection {
+        entity: ship,
+        section: id.clone(),
+        part: part.clone(),
+    });
+    vec![TerminalRow {
+        kind: TerminalRowKind::Info,
+        text: format!("refitting {code}: {current} -> {part}"),
+    }]
+}
@@ -3,7 +3,8 @@
 //!
 //! Bare `ship` launches this app; `ship view` prints the status summary (built in
 //! `nova_os.rs`); `ship section <id>` prints one section's detail; `ship reload
-//! <id>` / `ship repair <id>` act on a section. Every section is addressed by a
+//! <id>` / `ship repair <id>` act on a section; `ship rebuild [id]` lists or
+//! rebuilds the sections the ship has lost. Every section is addressed by a
 //! short [`SectionCode`] (`HULL-3`, `PDC-1`, `TRB-1`), assigned stably per session
 //! from the section kind + a stable index - the real ships use auto grid-coord
 //! `EntityId`s (`engine_port`, `fuselage`) that can be long, so the code is the
@@ -24,10 +25,16 @@
 //! `[`/`]` cycle the selection; `G` toggles structural mates; `L` reloads, `P`
 //! repairs, and `B` replaces the selected bindable section's input.
 //!
-//! Actions are instant and free for now, but they route through a single
-//! `ShipSectionCommand` seam (CLI verb -> [`NovaOsCommandInvocation`], in-app key
-//! -> message) so a future queued/over-time, resource-costed model can replace the
-//! handler without touching the callers.
+//! Actions route through a single `ShipSectionCommand` seam (CLI verb ->
+//! [`NovaOsCommandInvocation`], in-app key -> message). A reload is instant; a
+//! repair or rebuild only STARTS a damage-control job, paid in parts, that
+//! `nova_ship` then runs over time. The panel and the section detail show the
+//! job's progress, and the panel's damage-control block shows parts, combat
+//! state and the lost sections.
+//!
+//! The `dock` verbs sit beside the `ship` tree: while the ship is docked at a
+//! station they rearm, repair or refit it at once, for free, as far as the
+//! station offers (see `dock`).
 //!
 //! # Module layout
 //!
@@ -35,9 +42,11 @@
 //! | --- | --- |
 //! | `sections` | Section codes, the live section view and the action seam. |
 //! | `app` | The `ship` CLI verbs, the app runtime and its side panel. |
+//! | `dock` | The `dock` CLI verbs: station rearm, repair and refit. |
 //! | `scene` | The schematic 3D scene, its camera and the projected blips. |
 
 mod app;
+mod dock;
 mod rebind;
 mod scene;
 mod sections;
@@ -50,7 +59,7 @@ use nova_gameplay::prelude::*;
 use nova_os::prelude::*;
 
 pub use self::sections::SectionCode;
-pub(crate) use self::{app::*, rebind::*, scene::*, sections::*};
+pub(crate) use self::{app::*, dock::*, rebind::*, scene::*, sections::*};
 
 /// Glob-import surface: `use nova_os_ui::ship::prelude::*`.
 pub mod prelude {
@@ -103,6 +112,8 @@ pub(crate) struct NovaOsShipPlugin;
 impl Plugin for NovaOsShipPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ShipRuntime>();
+        // The refit catalog; filled by the section content when it loads.
+        app.init_resource::<nova_ship::prelude::GameSections>();
         app.add_message::<ShipSectionCommand>();
 
         // Register the `ship` command tree: bare `ship` launches the app; `ship
@@ -111,6 +122,10 @@ impl Plugin for NovaOsShipPlugin {
         app.world_mut()
             .resource_mut::<NovaOsCommandRegistry>()
             .register(ship_command_tree());
+        // The `dock` verbs: station services for a docked player ship.
+        app.world_mut()
+            .resource_mut::<NovaOsCommandRegistry>()
+            .register(dock_command_tree());
 
         // Where `NovaOsShipSystems` sits in the frame is decided by
         // `crate::MonitorFrame`, which is above both apps and the terminal.
@@ -121,6 +136,8 @@ impl Plugin for NovaOsShipPlugin {
                 sync_ship_arg_completions,
                 apply_ship_cli_commands,
                 apply_ship_section_commands,
+                apply_ship_rebuild_command,
+                apply_dock_commands,
                 manage_ship_scene,
                 reconcile_ship_target,
                 apply_ship_rebind,
@@ -137,7 +154,8 @@ impl Plugin for NovaOsShipPlugin {
 }
 
 /// The `ship` command tree: the app launch word, the `ship view` snapshot
-/// subcommand, and the arg-bearing `section`/`reload`/`repair` gameplay verbs.
+/// subcommand, and the arg-bearing `section`/`reload`/`repair`/`rebuild`
+/// gameplay verbs.
 /// Shared by the plugin registration and the tests.
 fn ship_command_tree() -> TerminalCommand {
     TerminalCommand::app(SHIP_APP_ID, "Open the ship computer", ShipApp)
@@ -166,6 +184,14 @@ fn ship_command_tree() -> TerminalCommand {
             TerminalCommand::gameplay("ship repair", "Repair a section", CommandArity::UpTo(1))
                 .with_arg_hint("<section>"),
         )
+        .with_subcommand(
+            TerminalCommand::gameplay(
+                "ship rebuild",
+                "List or rebuild lost sections",
+                CommandArity::UpTo(1),
+            )
+            .with_arg_hint("[section]"),
+        )
 }
 
 /// System set for the ship app's per-frame work.
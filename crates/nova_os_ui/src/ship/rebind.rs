@@ -5,11 +5,22 @@ use nova_ship::prelude::*;
 
 use super::ShipRuntime;
 
-fn reserved_conflict(source: InputSource) -> Option<String> {
-    flight_rig_reserved_sources()
-        .into_iter()
-        .find(|(reserved, _)| *reserved == source)
-        .map(|(_, verb)| format!("flight control: {verb}"))
+/// The player control already on `source`, read from the LIVE keymap so a
+/// remapped flight key frees its old default for a section trigger. Without a
+/// keymap resource (slim rigs) the shipped defaults stand in.
+fn reserved_conflict(keymap: Option<&Keymap>, source: InputSource) -> Option<String> {
+    let fallback;
+    let keymap = match keymap {
+        Some(keymap) => keymap,
+        None => {
+            fallback = Keymap::default();
+            &fallback
+        }
+    };
+    keymap
+        .actions_on(source)
+        .first()
+        .map(|action| format!("player control: {}", action.label()))
 }
 
 #[allow(clippy::type_complexity)]
@@ -18,6 +29,7 @@ pub(crate) fn apply_ship_rebind(
     mouse: Res<ButtonInput<MouseButton>>,
     mut runtime: ResMut<ShipRuntime>,
     mut commands: Commands,
+    keymap: Option<Res<Keymap>>,
     targets: Query<(
         &ChildOf,
         &EntityId,
@@ -61,7 +73,7 @@ pub(crate) fn apply_ship_rebind(
         return;
     };
     let ship = parent.parent();
-    if let Some(conflict) = reserved_conflict(source) {
+    if let Some(conflict) = reserved_conflict(keymap.as_deref(), source) {
         runtime.note = Some((
             format!("{} is already used by {conflict}", source.label()),
             2.5,
@@ -168,7 +180,42 @@ mod tests {
             .resource::<ShipRuntime>()
             .note
             .as_ref()
-            .is_some_and(|(note, _)| note.contains("flight control")));
+            .is_some_and(|(note, _)| note.contains("player control")));
+    }
+
+    /// The check reads the live keymap: X moved off STOP is free for a
+    /// section, and the key STOP moved to is now the refused one.
+    #[test]
+    fn a_remapped_flight_key_frees_its_old_default() {
+        let (mut world, target) = rebind_world();
+        let mut keymap = Keymap::default();
+        keymap.rebind(
+            KeymapAction::AutopilotStop,
+            KeymapDevice::Keyboard,
+            InputSource::Keyboard(KeyCode::KeyK),
+        );
+        world.insert_resource(keymap);
+
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyK);
+        world.run_system_once(apply_ship_rebind).unwrap();
+        assert_eq!(
+            world.resource::<ShipRuntime>().rebinding,
+            Some(target),
+            "K now drives STOP"
+        );
+
+        world.resource_mut::<ButtonInput<KeyCode>>().clear();
+        world
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyX);
+        world.run_system_once(apply_ship_rebind).unwrap();
+        let binding = &world.get::<SpaceshipTurretInputBinding>(target).unwrap().0;
+        assert_eq!(
+            binding_source(&binding[0]),
+            Some(InputSource::Keyboard(KeyCode::KeyX))
+        );
     }
 
     #[test]
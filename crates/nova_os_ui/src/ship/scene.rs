@@ -18,7 +18,9 @@ use bevy::{
 };
 use nova_gameplay::prelude::*;
 use nova_os::prelude::*;
-use nova_ship::prelude::{derive_link_point_graph, PlacedSectionLinkPoints};
+use nova_ship::prelude::{
+    derive_link_point_graph, CargoHold, Docked, PlacedSectionLinkPoints, StationServices,
+};
 use nova_ui::font::UiFont;
 
 use super::{sections::*, *};
@@ -35,12 +37,16 @@ pub(crate) struct ShipViewportMarker;
 /// The inspector-panel container.
 #[derive(Component)]
 pub(crate) struct ShipPanelMarker;
-/// Which live text line of the panel a node is, so one system refreshes all three.
+/// Which live text line of the panel a node is, so one system refreshes them all.
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum ShipPanelField {
     Title,
     Detail,
     Note,
+    /// The player ship's cargo hold - ship-wide, shown whatever is selected.
+    Cargo,
+    /// The player ship's damage control: parts, combat state, lost sections.
+    DamageControl,
 }
 /// Which action a panel button raises, for its per-frame enabled styling.
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
@@ -48,6 +54,7 @@ pub(crate) enum ShipPanelButton {
     Repair,
     Reload,
     Rebind,
+    Refit,
 }
 #[derive(Component)]
 pub(crate) struct ShipCameraMarker;
@@ -129,6 +136,8 @@ pub(crate) struct ShipRuntime {
     /// The codes last pushed as arg-completions, so the terminal is only marked
     /// changed when the set changes.
     pub(crate) completion_codes: Vec<String>,
+    /// The lost section ids last pushed as `ship rebuild` completions.
+    pub(crate) completion_lost: Vec<String>,
     /// A transient note (e.g. an in-app action result) shown in the panel.
     pub(crate) note: Option<(String, f32)>,
     /// Whether Repair / Reload are valid for the current selection, cached by
@@ -137,6 +146,9 @@ pub(crate) struct ShipRuntime {
     pub(crate) panel_repair_enabled: bool,
     pub(crate) panel_reload_enabled: bool,
     pub(crate) panel_rebind_enabled: bool,
+    /// Whether the ship is docked at a station that refits, which is what the
+    /// Refit button opens the bay for.
+    pub(crate) panel_refit_enabled: bool,
     /// Section waiting for a replacement keyboard or mouse binding.
     pub(crate) rebinding: Option<Entity>,
     /// Skips the key or click that armed the capture.
@@ -743,6 +755,20 @@ pub(crate) fn on_ship_rebind_button(_activate: On<Activate>, mut runtime: ResMut
     runtime.note = None;
 }
 
+/// Open the refit bay when the panel button is clicked, unless the ship is not
+/// docked at a station that refits. Queued as the bare `dock refit` verb, so
+/// the button and the terminal open the bay through the same answer.
+pub(crate) fn on_ship_refit_button(
+    _activate: On<Activate>,
+    runtime: Res<ShipRuntime>,
+    mut terminal: ResMut<NovaOsTerminal>,
+) {
+    if !runtime.panel_refit_enabled {
+        return;
+    }
+    terminal.replay_invocation("dock refit", Vec::new());
+}
+
 pub(crate) fn on_ship_reload_button(
     _activate: On<Activate>,
     runtime: Res<ShipRuntime>,
@@ -762,9 +788,17 @@ pub(crate) fn on_ship_reload_button(
 /// Refresh the inspector panel from the current selection: title, live detail,
 /// button enabled-state, and the note line (a transient action result, or the
 /// reason a button is disabled). Caches the enabled flags for the observers.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn update_ship_panel(
     mut runtime: ResMut<ShipRuntime>,
     sections: ShipSections,
+    q_hold: Query<&CargoHold, With<PlayerSpaceshipMarker>>,
+    q_control: Query<
+        (&DamageControl, &ShipBlueprint, Option<&SectionRebuildJob>),
+        With<PlayerSpaceshipMarker>,
+    >,
+    q_docked: Query<&Docked, With<PlayerSpaceshipMarker>>,
+    q_services: Query<&StationServices>,
     mut q_text: Query<(&ShipPanelField, &mut Text, &mut TextColor)>,
     mut q_button: Query<(&ShipPanelButton, &mut BorderColor, &mut BackgroundColor)>,
 ) {
@@ -799,6 +833,11 @@ pub(crate) fn update_ship_panel(
     runtime.panel_rebind_enabled = selected
         .as_ref()
         .is_some_and(|view| view.bindings.is_some());
+    runtime.panel_refit_enabled = q_docked.iter().next().is_some_and(|docked| {
+        q_services
+            .get(docked.station)
+            .is_ok_and(|services| services.refit)
+    });
 
     // Note line: a transient action result wins; else the disabled reason; else a
     // key hint when a section is selected.
@@ -820,11 +859,16 @@ pub(crate) fn update_ship_panel(
         (String::new(), NOVA_OS_PHOSPHOR_MUTED)
     };
 
+    let cargo = cargo_panel_text(q_hold.iter().next());
+    let damage_control = damage_control_panel_text(q_control.iter().next());
+
     for (field, mut text, mut color) in &mut q_text {
         let (value, tint) = match field {
             ShipPanelField::Title => (&title, NOVA_OS_PHOSPHOR),
             ShipPanelField::Detail => (&detail, detail_color),
             ShipPanelField::Note => (&note, note_color),
+            ShipPanelField::Cargo => (&cargo, NOVA_OS_TEXT),
+            ShipPanelField::DamageControl => (&damage_control, NOVA_OS_TEXT),
         };
         if text.0 != *value {
             text.0 = value.clone();
@@ -837,6 +881,7 @@ pub(crate) fn update_ship_panel(
             ShipPanelButton::Repair => actions.repair_enabled,
             ShipPanelButton::Reload => actions.reload_enabled,
             ShipPanelButton::Rebind => runtime.panel_rebind_enabled,
+            ShipPanelButton::Refit => runtime.panel_refit_enabled,
         };
         let (border_color, background_color) = if enabled {
             (NOVA_OS_PHOSPHOR, NOVA_OS_PHOSPHOR.with_alpha(0.14))
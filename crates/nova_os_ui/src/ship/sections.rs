@@ -172,6 +172,8 @@ pub(crate) struct ShipSectionView {
     pub(crate) bindings: Option<Vec<Binding>>,
     pub(crate) inactive: bool,
     pub(crate) zero_health: bool,
+    /// Progress of a running damage-control repair, `0..=1`.
+    pub(crate) repair: Option<f32>,
 }
 
 impl ShipSectionView {
@@ -241,12 +243,18 @@ impl ShipSectionView {
 
     /// A short ASCII integrity meter, `[####------]`.
     pub(crate) fn meter(&self) -> String {
-        let filled = (self.integrity().unwrap_or(0.0) * 10.0).round() as usize;
-        let filled = filled.min(10);
-        format!("[{}{}]", "#".repeat(filled), "-".repeat(10 - filled))
+        ascii_meter(self.integrity().unwrap_or(0.0))
     }
 }
 
+/// A ten-cell ASCII bar for a `0..=1` fraction, `[####------]`. The integrity
+/// meter and the damage-control progress bars share it.
+pub(crate) fn ascii_meter(fraction: f32) -> String {
+    let filled = (fraction.clamp(0.0, 1.0) * 10.0).round() as usize;
+    let filled = filled.min(10);
+    format!("[{}{}]", "#".repeat(filled), "-".repeat(10 - filled))
+}
+
 /// System-param that enumerates the live player-ship sections into
 /// [`ShipSectionView`]s. Shared by the CLI verbs (`ship section/reload/repair`),
 /// the arg-completion sync, the scene builder and the interaction systems.
@@ -274,7 +282,11 @@ pub struct ShipSections<'w, 's> {
             // Nested so the whole row stays under the 15-item query-tuple cap.
             SectionKindQuery,
             SectionBindingQuery,
-            (Has<SectionInactiveMarker>, Has<HealthZeroMarker>),
+            (
+                Has<SectionInactiveMarker>,
+                Has<HealthZeroMarker>,
+                Option<&'static SectionRepairJob>,
+            ),
         ),
         With<SectionMarker>,
     >,
@@ -327,7 +339,7 @@ impl ShipSections<'_, '_> {
                     ammo,
                     (class, hull, controller, thruster, turret, torpedo),
                     (thruster_bindings, turret_bindings, torpedo_bindings),
-                    (inactive, zero_health),
+                    (inactive, zero_health, repair),
                 )| {
                     let kind = section_kind_from_markers(
                         class, hull, controller, thruster, turret, torpedo,
@@ -344,6 +356,7 @@ impl ShipSections<'_, '_> {
                         link_points: link_points
                             .map(|points| points.0.clone())
                             .unwrap_or_default(),
+                        repair: repair.zip(health).map(|(job, health)| job.progress(health)),
                         health: health.cloned(),
                         ammo: ammo.copied(),
                         bindings: thruster_bindings
@@ -411,9 +424,24 @@ pub(crate) fn section_detail_rows(view: &ShipSectionView) -> Vec<TerminalRow> {
             text: format!("ammo: {}/{}", ammo.rounds, ammo.capacity),
         });
     }
+    if let Some(progress) = view.repair {
+        rows.push(TerminalRow {
+            kind: TerminalRowKind::Info,
+            text: repair_progress_text(progress),
+        });
+    }
     rows
 }
 
+/// The `repairing 40% [####------]` line for a section under repair.
+pub(crate) fn repair_progress_text(progress: f32) -> String {
+    format!(
+        "repairing {:.0}% {}",
+        progress * 100.0,
+        ascii_meter(progress)
+    )
+}
+
 pub(crate) fn status_row_kind(status: &str) -> TerminalRowKind {
     match status {
         "neutralized" => TerminalRowKind::Error,
@@ -440,13 +468,74 @@ pub(crate) fn panel_detail_text(view: &ShipSectionView) -> String {
     if let Some(bindings) = view.binding_text() {
         text.push_str(&format!("\nbindings: {bindings}"));
     }
+    if let Some(progress) = view.repair {
+        text.push_str(&format!("\n{}", repair_progress_text(progress)));
+    }
+    text
+}
+
+/// The panel's ship-wide damage-control block: parts and combat state, the
+/// running rebuild as a bar, and the lost sections with whether each can be
+/// rebuilt. `None` is a ship with no damage control.
+pub(crate) fn damage_control_panel_text(
+    control: Option<(&DamageControl, &ShipBlueprint, Option<&SectionRebuildJob>)>,
+) -> String {
+    let Some((control, blueprint, rebuild)) = control else {
+        return "damage control: none".to_string();
+    };
+    let mut text = format!(
+        "damage control: {} parts, {}",
+        control.parts,
+        if control.in_combat() {
+            "in combat"
+        } else {
+            "stood down"
+        }
+    );
+    if let Some(rebuild) = rebuild {
+        text.push_str(&format!(
+            "\n  rebuilding {} {}",
+            rebuild.section,
+            ascii_meter(rebuild.progress())
+        ));
+    }
+    for lost in blueprint.lost() {
+        if rebuild.is_some_and(|rebuild| rebuild.section == lost.id) {
+            continue;
+        }
+        let state = if lost.attachable {
+            "rebuildable"
+        } else {
+            "no mount"
+        };
+        text.push_str(&format!("\n  lost {} ({state})", lost.id));
+    }
+    text
+}
+
+/// The panel's ship-wide cargo line: fill against capacity, then one row per
+/// stack. `None` is a ship with no cargo-bearing hull.
+pub(crate) fn cargo_panel_text(hold: Option<&CargoHold>) -> String {
+    let Some(hold) = hold else {
+        return "cargo: no hold".to_string();
+    };
+    let mut text = format!("cargo: {}/{}", hold.used(), hold.capacity);
+    if hold.used() == 0 {
+        text.push_str(" empty");
+    }
+    for (item, count) in hold.stacks() {
+        text.push_str(&format!("\n  {item} x{count}"));
+    }
     text
 }
 
 /// Whether Repair / Reload are valid for a section, plus a reason for a disabled
-/// action. Derived from the SAME conditions [`apply_action_to_section`] enforces
-/// (Reload = a `Turret`/`Torpedo` with an ammo feed; Repair = `Health` with a
-/// positive max), so the panel buttons never disagree with the handler.
+/// action. Derived from the SAME per-section conditions
+/// [`apply_action_to_section`] enforces (Reload = a `Turret`/`Torpedo` with an
+/// ammo feed; Repair = a damaged, living section with no repair already
+/// running), so the panel buttons never disagree with the handler. Running out
+/// of parts is ship-wide, not a property of the section: the button stays lit
+/// and the handler's note says why.
 pub(crate) struct PanelActions {
     pub(crate) repair_enabled: bool,
     pub(crate) reload_enabled: bool,
@@ -466,7 +555,12 @@ impl PanelActions {
 
 pub(crate) fn panel_action_state(view: &ShipSectionView) -> PanelActions {
     let is_weapon = matches!(view.kind, SectionClass::Turret | SectionClass::Torpedo);
-    let repair_enabled = view.health.as_ref().map(|h| h.max > 0.0).unwrap_or(false);
+    let repairable = view.health.as_ref().map(|h| h.max > 0.0).unwrap_or(false);
+    let damaged = view
+        .health
+        .as_ref()
+        .is_some_and(|h| h.current > 0.0 && h.current < h.max);
+    let repair_enabled = repairable && damaged && view.repair.is_none();
     let reload_enabled = is_weapon && view.ammo.is_some();
 
     // Surface why a disabled action is unavailable, mirroring the handler's text.
@@ -478,8 +572,12 @@ pub(crate) fn panel_action_state(view: &ShipSectionView) -> PanelActions {
         ))
     } else if view.ammo.is_none() {
         Some(format!("reload: {} has unlimited ammo", view.code))
-    } else if !repair_enabled {
+    } else if !repairable {
         Some(format!("repair: {} has no integrity to restore", view.code))
+    } else if view.repair.is_some() {
+        Some(format!("repair: {} is under repair", view.code))
+    } else if !damaged {
+        Some(format!("repair: {} is at full integrity", view.code))
     } else {
         None
     };
@@ -509,13 +607,14 @@ pub(crate) fn unknown_code_rows(code: &str, codes: &[String]) -> Vec<TerminalRow
 // ---------------------------------------------------------------------------
 // Action seam (CLI verb + in-app key -> one handler)
 // ---------------------------------------------------------------------------
-/// A mutating action on a section. Instant/free today; the [`ShipSectionCommand`]
-/// seam is where a future queued, resource-costed job model plugs in.
+/// A mutating action on a section. A reload is instant; a repair starts a
+/// damage-control job that takes time and a part (see `nova_ship`'s
+/// `damage_control`).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ShipAction {
     /// Refill a weapon section's ammo to capacity.
     Reload,
-    /// Restore a section's integrity to full.
+    /// Start restoring a section's integrity to full.
     Repair,
 }
 
@@ -530,27 +629,35 @@ pub struct ShipSectionCommand {
     pub action: ShipAction,
 }
 
-/// Apply an action to a section's live `Health` / `SectionAmmo`, returning the
-/// result row. This is the single mutation point (arcade-instant today); a queued
-/// model would enqueue a job here instead of mutating in place.
+/// Apply an action to a section, returning the result row and, for a repair
+/// that started, the job the caller inserts on the section. This is the single
+/// mutation point: a reload refills `SectionAmmo` in place, a repair pays the
+/// ship's [`DamageControl`] and hands back a [`SectionRepairJob`] that the
+/// damage-control tick then runs. `repairing` is whether the section already
+/// has one.
 pub(crate) fn apply_action_to_section(
     action: ShipAction,
     code: &str,
     kind: SectionClass,
     is_weapon: bool,
-    health: Option<&mut Health>,
+    health: Option<&Health>,
     ammo: Option<&mut SectionAmmo>,
-) -> TerminalRow {
-    match action {
+    control: Option<&mut DamageControl>,
+    repairing: bool,
+) -> (TerminalRow, Option<SectionRepairJob>) {
+    let row = match action {
         ShipAction::Reload => {
             if !is_weapon {
-                return TerminalRow {
-                    kind: TerminalRowKind::Error,
-                    text: format!(
-                        "reload: {code} is a {} section, no ammo feed",
-                        section_kind_label(kind).to_lowercase()
-                    ),
-                };
+                return (
+                    TerminalRow {
+                        kind: TerminalRowKind::Error,
+                        text: format!(
+                            "reload: {code} is a {} section, no ammo feed",
+                            section_kind_label(kind).to_lowercase()
+                        ),
+                    },
+                    None,
+                );
             }
             match ammo {
                 Some(ammo) => {
@@ -566,23 +673,47 @@ pub(crate) fn apply_action_to_section(
                 },
             }
         }
-        ShipAction::Repair => match health {
-            Some(health) if health.max > 0.0 => {
-                health.current = health.max;
-                TerminalRow {
-                    kind: TerminalRowKind::Info,
-                    text: format!(
-                        "repaired {code}: integrity restored to {:.0} HP",
-                        health.max
-                    ),
-                }
-            }
-            _ => TerminalRow {
-                kind: TerminalRowKind::Error,
-                text: format!("repair: {code} has no integrity to restore"),
-            },
-        },
-    }
+        ShipAction::Repair => {
+            let Some(health) = health.filter(|health| health.max > 0.0) else {
+                return (
+                    TerminalRow {
+                        kind: TerminalRowKind::Error,
+                        text: format!("repair: {code} has no integrity to restore"),
+                    },
+                    None,
+                );
+            };
+            let Some(control) = control else {
+                return (
+                    TerminalRow {
+                        kind: TerminalRowKind::Error,
+                        text: format!("repair: {code}: no damage control aboard"),
+                    },
+                    None,
+                );
+            };
+            return match control.start_repair(health, repairing) {
+                Ok(job) => (
+                    TerminalRow {
+                        kind: TerminalRowKind::Info,
+                        text: format!(
+                            "repairing {code}: {:.0}/{:.0} HP, {} parts left",
+                            health.current, health.max, control.parts
+                        ),
+                    },
+                    Some(job),
+                ),
+                Err(refusal) => (
+                    TerminalRow {
+                        kind: TerminalRowKind::Error,
+                        text: format!("repair: {code}: {refusal}"),
+                    },
+                    None,
+                ),
+            };
+        }
+    };
+    (row, None)
 }
 
 // ---------------------------------------------------------------------------
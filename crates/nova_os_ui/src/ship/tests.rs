@@ -12,7 +12,7 @@ use bevy::{
 use nova_events::prelude::EntityId;
 use nova_ship::prelude::*;
 
-use super::{app::*, scene::*, sections::*, *};
+use super::{app::*, dock::*, scene::*, sections::*, *};
 use crate::{
     pointer_rig::{
         click_at, glass_px, glass_uv_showing, image_px_shown_at, nova_os_pointer_rig,
@@ -51,13 +51,15 @@ fn ship_verb_help_names_the_section_argument() {
 }
 
 /// Spawn a scripted player ship: a hull, a turret (with ammo, critically
-/// damaged) and a healthy thruster. Sections carry NO `SectionCode` yet, so
-/// `assign_section_codes` mints them (HULL-1 / PDC-1 / THR-1).
+/// damaged) and a healthy thruster, with two repair parts aboard. Sections
+/// carry NO `SectionCode` yet, so `assign_section_codes` mints them (HULL-1 /
+/// PDC-1 / THR-1).
 fn spawn_scripted_ship(world: &mut World) -> (Entity, Entity, Entity, Entity) {
     let ship = world
         .spawn((
             SpaceshipRootMarker,
             PlayerSpaceshipMarker,
+            DamageControl::new(2),
             GlobalTransform::from(Transform::from_xyz(0.0, 0.0, 0.0)),
             Name::new("NOVA"),
         ))
@@ -165,18 +167,40 @@ fn ship_reload_and_repair_apply_through_command_handler() {
     let mut app = App::new();
     app.add_plugins(MinimalPlugins);
     app.insert_resource(ship_terminal());
-    let (_ship, hull, turret, _thruster) = spawn_scripted_ship(app.world_mut());
+    let (ship, hull, turret, thruster) = spawn_scripted_ship(app.world_mut());
     app.world_mut()
         .run_system_once(assign_section_codes)
         .unwrap();
 
-    // `ship repair HULL-1` restores integrity through the handler.
+    // `ship repair HULL-1` starts a paid damage-control job through the
+    // handler; the health itself climbs later, under `tick_damage_control`.
     submit(&mut app, "ship repair HULL-1");
     app.world_mut()
         .run_system_once(apply_ship_cli_commands)
         .unwrap();
-    assert_eq!(app.world().get::<Health>(hull).unwrap().current, 100.0);
-    assert!(scrollback_text(&app).contains("repaired HULL-1"));
+    assert_eq!(
+        app.world().get::<SectionRepairJob>(hull),
+        Some(&SectionRepairJob { from: 80.0 })
+    );
+    assert_eq!(app.world().get::<Health>(hull).unwrap().current, 80.0);
+    assert_eq!(app.world().get::<DamageControl>(ship).unwrap().parts, 1);
+    assert!(scrollback_text(&app).contains("repairing HULL-1"));
+
+    // A second order on the same section is refused without spending a part,
+    // and so is a section already at full integrity.
+    submit(&mut app, "ship repair HULL-1");
+    app.world_mut()
+        .run_system_once(apply_ship_cli_commands)
+        .unwrap();
+    submit(&mut app, "ship repair THR-1");
+    app.world_mut()
+        .run_system_once(apply_ship_cli_commands)
+        .unwrap();
+    let text = scrollback_text(&app);
+    assert!(text.contains("repair HULL-1: already underway"), "{text}");
+    assert!(text.contains("repair THR-1: nothing to repair"), "{text}");
+    assert!(app.world().get::<SectionRepairJob>(thruster).is_none());
+    assert_eq!(app.world().get::<DamageControl>(ship).unwrap().parts, 1);
 
     // `ship reload PDC-1` refills ammo through the same seam (lowercase id).
     submit(&mut app, "ship reload pdc-1");
@@ -253,12 +277,13 @@ fn ship_action_keys_mutate_through_message_handler() {
     app.add_plugins(MinimalPlugins);
     app.init_resource::<ShipRuntime>();
     app.add_message::<ShipSectionCommand>();
-    let (_ship, hull, turret, _thruster) = spawn_scripted_ship(app.world_mut());
+    let (ship, hull, turret, _thruster) = spawn_scripted_ship(app.world_mut());
     app.world_mut()
         .run_system_once(assign_section_codes)
         .unwrap();
 
-    // Repair the hull through the message handler.
+    // Repair the hull through the message handler: the job lands on the
+    // section and the part comes off the ship root it hangs from.
     app.world_mut().write_message(ShipSectionCommand {
         target: hull,
         action: ShipAction::Repair,
@@ -266,10 +291,11 @@ fn ship_action_keys_mutate_through_message_handler() {
     app.world_mut()
         .run_system_once(apply_ship_section_commands)
         .unwrap();
-    assert_eq!(app.world().get::<Health>(hull).unwrap().current, 100.0);
+    assert!(app.world().get::<SectionRepairJob>(hull).is_some());
+    assert_eq!(app.world().get::<DamageControl>(ship).unwrap().parts, 1);
     let note = app.world().resource::<ShipRuntime>().note.clone();
     assert!(
-        note.map(|(text, _)| text.contains("repaired HULL-1"))
+        note.map(|(text, _)| text.contains("repairing HULL-1"))
             .unwrap_or(false),
         "the handler flashes the result on the panel note line",
     );
@@ -285,6 +311,204 @@ fn ship_action_keys_mutate_through_message_handler() {
     assert_eq!(app.world().get::<SectionAmmo>(turret).unwrap().rounds, 6);
 }
 
+#[test]
+fn ship_rebuild_lists_lost_sections_and_starts_a_rebuild() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.insert_resource(ship_terminal());
+    let (ship, ..) = spawn_scripted_ship(app.world_mut());
+    let lost = |id: &str, attachable: bool| BlueprintSection {
+        id: id.to_string(),
+        name: id.to_string(),
+        transform: Transform::default(),
+        link_points: Vec::new(),
+        standing: false,
+        attachable,
+    };
+    app.world_mut().entity_mut(ship).insert((
+        DamageControl::new(4),
+        ShipBlueprint(vec![lost("wing", true), lost("tail", false)]),
+    ));
+
+    // Bare `ship rebuild` lists each lost section with what it would take.
+    submit(&mut app, "ship rebuild");
+    app.world_mut()
+        .run_system_once(apply_ship_rebuild_command)
+        .unwrap();
+    let text = scrollback_text(&app);
+    assert!(
+        text.contains("damage control: 4 parts, stood down"),
+        "{text}"
+    );
+    assert!(text.contains("wing  wing  3 parts, 15s"), "{text}");
+    assert!(text.contains("tail  tail  no standing neighbour"), "{text}");
+
+    // An orphaned section is refused; an attachable one starts and is paid for.
+    submit(&mut app, "ship rebuild tail");
+    app.world_mut()
+        .run_system_once(apply_ship_rebuild_command)
+        .unwrap();
+    submit(&mut app, "ship rebuild wing");
+    app.world_mut()
+        .run_system_once(apply_ship_rebuild_command)
+        .unwrap();
+    let text = scrollback_text(&app);
+    assert!(
+        text.contains("rebuild tail: nothing standing to build onto"),
+        "{text}"
+    );
+    assert!(text.contains("rebuilding wing"), "{text}");
+    assert_eq!(
+        app.world()
+            .get::<SectionRebuildJob>(ship)
+            .map(|job| job.section.as_str()),
+        Some("wing")
+    );
+    assert_eq!(app.world().get::<DamageControl>(ship).unwrap().parts, 1);
+}
+
+/// The `dock` verbs refuse until the ship is docked, then rearm and repair it
+/// at once and for free, as far as the station offers. Parts restock to the
+/// ship's own allowance, not the default.
+#[test]
+fn dock_services_apply_only_while_docked() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<GameSections>();
+    let mut registry = NovaOsCommandRegistry::default();
+    registry.register(dock_command_tree());
+    let mut terminal = NovaOsTerminal::default();
+    terminal.set_commands(registry.specs());
+    app.insert_resource(terminal);
+    let (ship, hull, turret, _thruster) = spawn_scripted_ship(app.world_mut());
+    app.world_mut()
+        .run_system_once(assign_section_codes)
+        .unwrap();
+    app.world_mut()
+        .entity_mut(hull)
+        .insert(SectionRepairJob { from: 80.0 });
+    let allowance = DEFAULT_REPAIR_PARTS + 3;
+    app.world_mut().entity_mut(ship).insert(DamageControl {
+        parts: 1,
+        ..DamageControl::new(allowance)
+    });
+
+    submit(&mut app, "dock rearm");
+    app.world_mut()
+        .run_system_once(apply_dock_commands)
+        .unwrap();
+    assert!(scrollback_text(&app).contains("dock rearm: not docked"));
+    assert_eq!(app.world().get::<SectionAmmo>(turret).unwrap().rounds, 2);
+
+    let station = app
+        .world_mut()
+        .spawn((
+            EntityId::new("waystation"),
+            StationServices {
+                refit: false,
+                ..default()
+            },
+        ))
+        .id();
+    let port = app.world_mut().spawn(ChildOf(station)).id();
+    app.world_mut()
+        .entity_mut(ship)
+        .insert(Docked { port, station });
+
+    for line in ["dock rearm", "dock repair", "dock refit"] {
+        submit(&mut app, line);
+        app.world_mut()
+            .run_system_once(apply_dock_commands)
+            .unwrap();
+    }
+    let text = scrollback_text(&app);
+    assert!(
+        text.contains("rearmed 1 magazine(s) at waystation"),
+        "{text}"
+    );
+    assert!(
+        text.contains("repaired 2 section(s) at waystation"),
+        "{text}"
+    );
+    assert!(text.contains("refit: waystation offers no refit"), "{text}");
+    assert_eq!(app.world().get::<SectionAmmo>(turret).unwrap().rounds, 6);
+    assert_eq!(app.world().get::<Health>(hull).unwrap().current, 100.0);
+    assert!(app.world().get::<SectionRepairJob>(hull).is_none());
+    assert_eq!(
+        app.world().get::<DamageControl>(ship).unwrap().parts,
+        allowance
+    );
+    assert!(text.contains(&format!("damage control restocked to {allowance} parts")));
+}
+
+/// Bare `dock refit` at a station that refits is the refit bay's door: it
+/// asks the bay to open for the player ship and closes the computer over it,
+/// and the Refit button queues the same verb.
+#[test]
+fn bare_dock_refit_opens_the_refit_bay() {
+    #[derive(Resource, Default)]
+    struct Opened(Vec<Entity>);
+
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins);
+    app.init_resource::<GameSections>();
+    app.init_resource::<Opened>();
+    app.add_observer(|open: On<OpenRefitBay>, mut opened: ResMut<Opened>| {
+        opened.0.push(open.entity);
+    });
+    let mut registry = NovaOsCommandRegistry::default();
+    registry.register(dock_command_tree());
+    let mut terminal = NovaOsTerminal::default();
+    terminal.set_commands(registry.specs());
+    app.insert_resource(terminal);
+    let (ship, ..) = spawn_scripted_ship(app.world_mut());
+    app.world_mut()
+        .run_system_once(assign_section_codes)
+        .unwrap();
+    let station = app
+        .world_mut()
+        .spawn((EntityId::new("yard"), StationServices::default()))
+        .id();
+    let port = app.world_mut().spawn(ChildOf(station)).id();
+    app.world_mut()
+        .entity_mut(ship)
+        .insert(Docked { port, station });
+
+    // With a section named, the verb only lists: no bay.
+    submit(&mut app, "dock refit HULL-1");
+    app.world_mut()
+        .run_system_once(apply_dock_commands)
+        .unwrap();
+    assert!(app.world().resource::<Opened>().0.is_empty());
+    assert!(!app
+        .world_mut()
+        .resource_mut::<NovaOsTerminal>()
+        .take_pending_close());
+
+    // The Refit button stands in for the bare verb.
+    app.insert_resource(ShipRuntime {
+        panel_refit_enabled: true,
+        ..default()
+    });
+    let refit = app
+        .world_mut()
+        .spawn(panel_button_bundle(ShipPanelButton::Refit))
+        .observe(on_ship_refit_button)
+        .id();
+    app.world_mut().trigger(Activate { entity: refit });
+    app.world_mut()
+        .run_system_once(apply_dock_commands)
+        .unwrap();
+    assert_eq!(app.world().resource::<Opened>().0, vec![ship]);
+    assert!(
+        app.world_mut()
+            .resource_mut::<NovaOsTerminal>()
+            .take_pending_close(),
+        "the computer closes so the bay can open"
+    );
+    assert!(scrollback_text(&app).contains("opening the refit bay at yard"));
+}
+
 #[test]
 fn scene_blocks_use_local_space_when_ship_off_origin() {
     // Regression: the schematic scene is anchored at the origin and blocks sit
@@ -789,6 +1013,7 @@ fn view_fixture(
         bindings: None,
         inactive: false,
         zero_health: false,
+        repair: None,
     }
 }
 
@@ -1042,6 +1267,22 @@ fn panel_action_state_gates_repair_and_reload() {
         "{:?}",
         a.reason
     );
+
+    // A hull already under repair, or one at full integrity, has nothing for
+    // the button to start.
+    let mut patching = hull.clone();
+    patching.repair = Some(0.4);
+    let a = panel_action_state(&patching);
+    assert!(!a.repair_enabled);
+    assert!(a.reason.as_deref().unwrap().contains("under repair"));
+    let mut whole = hull;
+    whole.health = Some(Health {
+        current: 100.0,
+        max: 100.0,
+    });
+    let a = panel_action_state(&whole);
+    assert!(!a.repair_enabled);
+    assert!(a.reason.as_deref().unwrap().contains("full integrity"));
 }
 
 #[test]
@@ -1070,6 +1311,20 @@ fn panel_detail_text_covers_live_fields() {
     assert!(text.contains("bindings: K / LMB"), "{text}");
 }
 
+#[test]
+fn cargo_panel_text_lists_the_hold() {
+    assert_eq!(cargo_panel_text(None), "cargo: no hold");
+    let mut hold = CargoHold::new(12);
+    assert_eq!(cargo_panel_text(Some(&hold)), "cargo: 0/12 empty");
+    hold.give("ore", 4);
+    hold.give("ice", 3);
+    assert_eq!(
+        cargo_panel_text(Some(&hold)),
+        "cargo: 7/12\n  ice x3\n  ore x4",
+        "stacks list in item order"
+    );
+}
+
 #[test]
 fn panel_buttons_raise_ship_section_command() {
     // Each button's `Activate` observer routes a ShipSectionCommand for the
@@ -1260,6 +1515,7 @@ fn rig_section_view(entity: Entity, code: &str) -> ShipSectionView {
         bindings: None,
         inactive: false,
         zero_health: false,
+        repair: None,
     }
 }
 
@@ -0,0 +1,105 @@
+//! The NOVA OS as a real screen in the cockpit: while the player flies from
+//! the seat ([`SpaceshipCameraView::Cockpit`]), the terminal's offscreen image
+//! is also shown on a small panel mounted ahead of the pilot, low and to the
+//! right, so the computer is a thing in the ship and not only a Tab overlay.
+//!
+//! The panel samples the SAME image the Tab monitor's CRT surface does
+//! ([`NovaOsRtt`]) - one terminal, two displays - which is why
+//! `reconcile_nova_os_target` keeps the offscreen pass alive while a panel
+//! exists, not only while the monitor is open. It is parented to the player
+//! root, so it rides the hull with no per-frame follow, and is placed from the
+//! same seat as the eye ([`cockpit_seat`] / [`COCKPIT_EYE_OFFSET`]). Headless,
+//! with no render target, there is nothing to show and no panel spawns.
+
+use bevy::{light::NotShadowCaster, prelude::*};
+use nova_gameplay::prelude::*;
+use nova_ship::prelude::*;
+
+use super::crt::NovaOsRtt;
+
+/// Panel centre from the pilot's eye, in the root frame: below the sightline
+/// and right of it, close enough to read without hiding the nose.
+pub(crate) const COCKPIT_SCREEN_OFFSET: Vec3 = Vec3::new(0.28, -0.22, -0.6);
+
+/// Panel size, world units - a 16:10 glass at arm's length.
+const COCKPIT_SCREEN_SIZE: Vec2 = Vec2::new(0.32, 0.2);
+
+/// Marker for the in-cockpit NOVA OS panel.
+#[derive(Component, Debug, Clone)]
+pub(crate) struct NovaOsCockpitScreenMarker;
+
+/// The panel's pose under the player root for a seat section at `seat`:
+/// offset from the eye, turned to face it.
+pub(crate) fn cockpit_screen_transform(seat: &Transform) -> Transform {
+    let eye = seat.translation + COCKPIT_EYE_OFFSET;
+    let centre = eye + COCKPIT_SCREEN_OFFSET;
+    // A `Rectangle` faces +Z; pointing -Z away from the eye turns the glass
+    // toward it.
+    Transform::from_translation(centre).looking_to(centre - eye, Vec3::Y)
+}
+
+/// Keep exactly one panel while the cockpit view holds and the terminal has
+/// an image to show, and none otherwise. Re-placed every frame it exists: the
+/// seat is the first working computer, and losing one to damage can move it.
+pub(crate) fn sync_nova_os_cockpit_screen(
+    mut commands: Commands,
+    view: Option<Res<SpaceshipCameraView>>,
+    rtt: Option<Res<NovaOsRtt>>,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<StandardMaterial>>>,
+    q_ship: Query<Entity, (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>)>,
+    q_controller: Query<
+        (&ChildOf, &Transform),
+        (
+            With<ControllerSectionMarker>,
+            Without<SectionInactiveMarker>,
+            Without<NovaOsCockpitScreenMarker>,
+        ),
+    >,
+    mut q_screen: Query<(Entity, &ChildOf, &mut Transform), With<NovaOsCockpitScreenMarker>>,
+) {
+    let cockpit = view.is_some_and(|view| *view == SpaceshipCameraView::Cockpit);
+    let seat = match (cockpit, q_ship.single()) {
+        (true, Ok(root)) => cockpit_seat(root, &q_controller).map(|seat| (root, seat)),
+        _ => None,
+    };
+    let (Some((root, seat)), Some(rtt), Some(mut meshes), Some(mut materials)) =
+        (seat, rtt, meshes, materials)
+    else {
+        for (screen, _, _) in &q_screen {
+            commands.entity(screen).despawn();
+        }
+        return;
+    };
+
+    let pose = cockpit_screen_transform(&seat);
+    let mut placed = false;
+    for (screen, &ChildOf(parent), mut transform) in &mut q_screen {
+        // A panel left on a previous hull (a respawn inside one cockpit
+        // session) goes with it rather than floating in space.
+        if parent != root || placed {
+            commands.entity(screen).despawn();
+            continue;
+        }
+        transform.set_if_neq(pose);
+        placed = true;
+    }
+    if placed {
+        return;
+    }
+
+    commands.entity(root).with_child((
+        Name::new("NOVA OS Cockpit Screen"),
+        NovaOsCockpitScreenMarker,
+        Mesh3d(meshes.add(Rectangle::from_size(COCKPIT_SCREEN_SIZE))),
+        // Unlit: the terminal is emissive phosphor, and a cabin light must
+        // not wash out the image.
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color_texture: Some(rtt.image.clone()),
+            unlit: true,
+            ..default()
+        })),
+        NotShadowCaster,
+        pose,
+    ));
+}
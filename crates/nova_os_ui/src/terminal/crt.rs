@@ -23,8 +23,9 @@ use bevy::{
     shader::ShaderRef,
     ui_render::prelude::{MaterialNode, UiMaterial},
 };
+use nova_gameplay::prelude::AccessibilitySettings;
 
-use super::{components::*, style::*};
+use super::{cockpit_screen::NovaOsCockpitScreenMarker, components::*, style::*};
 
 #[derive(Asset, AsBindGroup, TypePath, Clone, Debug)]
 pub(crate) struct NovaOsCrtMaterial {
@@ -194,6 +195,7 @@ pub(crate) fn reconcile_nova_os_target(
     q_openness: Query<&NovaOsOpenness, With<NovaOsRootMarker>>,
     mut q_camera: Query<(&mut Camera, &mut Projection), With<NovaOsImageCameraMarker>>,
     mut q_root: Query<(&mut Node, &mut Visibility), With<NovaOsImageContentRootMarker>>,
+    q_cockpit_screen: Query<(), With<NovaOsCockpitScreenMarker>>,
 ) {
     let Some(rtt) = rtt else {
         return;
@@ -207,6 +209,9 @@ pub(crate) fn reconcile_nova_os_target(
     // up with the sampled surface.
     let desired = computed.size().round().as_uvec2().max(UVec2::ONE);
     let open = q_openness.iter().next().map(|o| o.0).unwrap_or(0.0);
+    // The cockpit panel samples the same image, so it needs the offscreen
+    // pass (and the content it draws) while the monitor is closed too.
+    let showing = open > f32::EPSILON || !q_cockpit_screen.is_empty();
 
     let needs_resize = images
         .get(&rtt.image)
@@ -228,8 +233,8 @@ pub(crate) fn reconcile_nova_os_target(
     }
 
     if let Ok((mut cam, _)) = q_camera.get_mut(camera) {
-        // No point rendering the offscreen pass when the NOVA OS is fully closed.
-        cam.is_active = open > f32::EPSILON;
+        // No point rendering the offscreen pass when nothing samples it.
+        cam.is_active = showing;
     }
     if let Ok((mut node, mut vis)) = q_root.single_mut() {
         // This system's only gate is `resource_exists::<NovaOsRtt>`, which holds
@@ -241,7 +246,7 @@ pub(crate) fn reconcile_nova_os_target(
             node.width = width;
             node.height = height;
         }
-        vis.set_if_neq(if open > f32::EPSILON {
+        vis.set_if_neq(if showing {
             Visibility::Inherited
         } else {
             Visibility::Hidden
@@ -639,6 +644,7 @@ pub(crate) fn mirror_nova_os_hover(
 pub(crate) fn animate_nova_os_crt(
     time: Res<Time<Real>>,
     settings: Res<NovaOsMonitorSettings>,
+    accessibility: Option<Res<AccessibilitySettings>>,
     mut degauss: ResMut<NovaOsDegauss>,
     mut materials: ResMut<Assets<NovaOsCrtMaterial>>,
     q_openness: Query<&NovaOsOpenness, With<NovaOsRootMarker>>,
@@ -653,7 +659,10 @@ pub(crate) fn animate_nova_os_crt(
     if degauss.remaining > 0.0 {
         degauss.remaining = (degauss.remaining - time.delta_secs()).max(0.0);
     }
-    let degauss_env = degauss.envelope();
+    // Reduced motion scales the wobble+flash away; the pulse itself still
+    // runs, so turning the option off mid-pulse picks it up where it is.
+    let motion_scale = accessibility.map_or(1.0, |a11y| a11y.motion_scale());
+    let degauss_env = degauss.envelope() * motion_scale;
     // Feed the eased openness in as the CRT power level: the shader blooms the
     // raster on from a line and collapses it to a dying dot on close.
     let power = q_openness.iter().next().map(|o| o.0).unwrap_or(1.0);
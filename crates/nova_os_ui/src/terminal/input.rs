@@ -33,12 +33,12 @@ use nova_ui::screen::{max_scroll_y, page_step};
 use super::{components::*, content::*, sound::*, style::*};
 use crate::ship::prelude::SectionCode;
 
-/// Tab opens the shared freeze axis and becomes autocomplete while open. The
-/// gamepad right-stick click still toggles `Unpaused <-> NovaOs`; both inputs are
-/// inert while the pause menu owns the freeze (`Paused`) - which is also how a
-/// live outcome (it forces `Paused`) blocks the NOVA OS without a cross-crate
-/// dependency. The pad button is `RightThumb`, the one free button, mirroring
-/// `nova_menu`'s optional-gamepad guard.
+/// The keymap's NOVA OS key (Tab by default) opens the shared freeze axis; Tab
+/// becomes autocomplete while open. The keymap's pad button (right-stick click
+/// by default) still toggles `Unpaused <-> NovaOs`; both inputs are inert while
+/// the pause menu owns the freeze (`Paused`) - which is also how a live outcome
+/// (it forces `Paused`) blocks the NOVA OS without a cross-crate dependency.
+/// The gamepad and mouse inputs are optional, mirroring `nova_menu`'s guard.
 ///
 /// OPENING also needs a ship to be the computer OF. `Playing` covers the
 /// editor's build mode as well as flight, and there Tab used to arm the freeze
@@ -47,16 +47,17 @@ use crate::ship::prelude::SectionCode;
 /// for. Closing stays ungated - a computer that opened must always be closable.
 pub(crate) fn toggle_nova_os(
     keys: Res<ButtonInput<KeyCode>>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
     gamepad: Option<Res<ButtonInput<GamepadButton>>>,
+    keymap: Res<Keymap>,
     player: Query<(), With<PlayerSpaceshipMarker>>,
     current: Res<State<PauseStates>>,
     mut next: ResMut<NextState<PauseStates>>,
     mut close: ResMut<NovaOsCloseTransition>,
 ) {
-    let pad = gamepad
-        .map(|g| g.just_pressed(GamepadButton::RightThumb))
-        .unwrap_or(false);
-    let tab = keys.just_pressed(KeyCode::Tab);
+    let binding = keymap.get(KeymapAction::NovaOs);
+    let pad = binding.gamepad_just_pressed(gamepad.as_deref());
+    let tab = binding.keyboard_just_pressed(&keys, mouse.as_deref());
     if !tab && !pad {
         return;
     }
@@ -69,7 +70,7 @@ pub(crate) fn toggle_nova_os(
         PauseStates::NovaOs if pad && !tab => {
             close.closing = true;
         }
-        PauseStates::NovaOs | PauseStates::Paused => {}
+        PauseStates::NovaOs | PauseStates::Paused | PauseStates::Photo | PauseStates::Refit => {}
     }
 }
 /// Whether either Control key is down. Three NOVA OS keyboard handlers ask this
@@ -42,8 +42,10 @@
 //! | `flight_log` | The flight-log model and the live objective announcements. |
 //! | `spawn` | Shell setup and teardown, and the header/main/footer regions. |
 //! | `casing` | The physical monitor: casing, bezel, glass and chin controls. |
+//! | `cockpit_screen` | The terminal image on a panel in the cockpit view. |
 
 mod casing;
+mod cockpit_screen;
 mod components;
 mod content;
 mod crt;
@@ -71,11 +73,8 @@ pub mod prelude {
 use nova_gameplay::{objectives::prelude::GameObjectives, GameStates, PauseStates};
 use nova_hud::prelude::StoryFeed;
 
-pub use self::{
-    components::NovaOsMonitorSettings,
-    crt::{nova_os_openness, nova_os_pointer_id, nova_os_window_px_showing},
-};
 use self::{
+    cockpit_screen::sync_nova_os_cockpit_screen,
     components::{NovaOsCloseTransition, NovaOsDegauss, NovaOsFlightLog},
     crt::{animate_nova_os_crt, mirror_nova_os_hover, reconcile_nova_os_target, NovaOsCrtMaterial},
     flight_log::{announce_objectives_in_terminal, sync_nova_os_logs},
@@ -96,6 +95,10 @@ use self::{
     },
     spawn::{remove_nova_os, setup_nova_os},
 };
+pub use self::{
+    components::NovaOsMonitorSettings,
+    crt::{nova_os_openness, nova_os_pointer_id, nova_os_window_px_showing},
+};
 pub(crate) use self::{
     content::{section_kind_from_markers, section_kind_label},
     crt::{forward_nova_os_pointer, NovaOsRtt},
@@ -150,6 +153,9 @@ impl Plugin for NovaOsPlugin {
         app.init_resource::<NovaOsCloseTransition>();
         app.init_resource::<NovaOsMonitorSettings>();
         app.init_resource::<NovaOsDegauss>();
+        // NOTE: owned by nova_ship's input plugin; repeated so the NOVA OS
+        // toggle's keymap read survives slim rigs.
+        app.init_resource::<nova_ship::input::keymap::Keymap>();
         app.register_type::<NovaOsMonitorSettings>();
         app.add_plugins(UiMaterialPlugin::<NovaOsCrtMaterial>::default());
 
@@ -280,6 +286,14 @@ impl Plugin for NovaOsPlugin {
                 .run_if(resource_exists::<NovaOsRtt>)
                 .in_set(NovaOsSystems::Simulate),
         );
+        // The cockpit view's panel shows the same image; it spawns before the
+        // reconcile so the offscreen pass is live on the panel's first frame.
+        app.add_systems(
+            Update,
+            sync_nova_os_cockpit_screen
+                .before(reconcile_nova_os_target)
+                .in_set(NovaOsSystems::Simulate),
+        );
         app.add_systems(
             Update,
             (forward_nova_os_pointer, mirror_nova_os_hover)
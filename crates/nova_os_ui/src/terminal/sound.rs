@@ -1,15 +1,19 @@
 //! The NOVA OS audio: keypress and control cues, the continuous power-on bed,
 //! and the power-down sting.
 //!
-//! The bed is a single entity so its gain can track the sound toggle and the
-//! master volume live rather than being restarted.
+//! Every cue plays on the NOVA OS bus. The bed is a single entity so its gain
+//! can track the sound toggle and the volume sliders live rather than being
+//! restarted.
 //!
 //! Touch this module when adding a sound the monitor makes.
 
 use bevy::{audio::Volume, prelude::*};
 use nova_gameplay::{
-    audio::prelude::{SfxCommandsExt, SoundBank, UiSfx, NOVA_OS_BED_VOLUME, NOVA_OS_POWER_VOLUME},
-    settings::prelude::{HarnessMute, MasterVolume},
+    audio::prelude::{
+        bus_output_gain, AudioBus, SfxCommandsExt, SoundBank, UiSfx, NOVA_OS_BED_VOLUME,
+        NOVA_OS_POWER_VOLUME,
+    },
+    settings::prelude::{BusVolumes, HarnessMute, MasterVolume},
 };
 
 use super::components::*;
@@ -24,8 +28,8 @@ use super::components::*;
 pub(crate) struct NovaOsBedSfx;
 
 /// Fire a one-shot NOVA OS terminal cue, honoring the SND toggle
-/// ([`NovaOsMonitorSettings::sound_enabled`]). Master volume is applied
-/// downstream by the SFX plugin's `SfxMasterVolume` path, like every other cue.
+/// ([`NovaOsMonitorSettings::sound_enabled`]), on the NOVA OS bus. Master and
+/// bus volume are applied downstream by the SFX plugin, like every other cue.
 pub(crate) fn play_nova_os_cue(
     commands: &mut Commands,
     bank: &SoundBank<UiSfx>,
@@ -36,7 +40,7 @@ pub(crate) fn play_nova_os_cue(
     if !settings.sound_enabled {
         return;
     }
-    commands.play_sfx_volume(bank.get(cue), volume);
+    commands.play_sfx_on(AudioBus::NovaOs, bank.get(cue), volume);
 }
 
 /// Power-up sweep + start the ambient bed when the computer opens
@@ -98,29 +102,35 @@ pub(crate) fn play_nova_os_power_down(
     *was_closing = close.closing;
 }
 
-/// Drive the ambient bed sink volume from [`MasterVolume`] and the SND toggle, so
-/// muting SND (or the master) silences the hum live without despawning the loop.
-/// Uses `output_gain(mute)` like the thruster/RCS loop sinks, so a `HarnessMute`d
-/// smoke/probe run silences the bed too (a per-frame sink write bypasses the
-/// `GlobalVolume` path that mute otherwise masks).
+/// Drive the ambient bed sink volume from [`MasterVolume`], the NOVA OS bus and
+/// the SND toggle, so muting SND (or either slider) silences the hum live
+/// without despawning the loop. Uses [`bus_output_gain`] like the thruster/RCS
+/// loop sinks, so a `HarnessMute`d smoke/probe run silences the bed too (a
+/// per-frame sink write bypasses the `GlobalVolume` path that mute otherwise
+/// masks).
 pub(crate) fn apply_nova_os_bed_volume(
     settings: Res<NovaOsMonitorSettings>,
     master: Option<Res<MasterVolume>>,
+    buses: Option<Res<BusVolumes>>,
     mute: Option<Res<HarnessMute>>,
     mut q_bed: Query<&mut AudioSink, With<NovaOsBedSfx>>,
 ) {
-    let mute = mute.map(|m| *m).unwrap_or_default();
-    let master = master.map(|m| m.output_gain(mute)).unwrap_or(1.0);
+    let master = bus_output_gain(
+        AudioBus::NovaOs,
+        master.as_deref(),
+        buses.as_deref(),
+        mute.as_deref(),
+    );
     let target = nova_os_bed_gain(settings.sound_enabled, master);
     for mut sink in &mut q_bed {
         sink.set_volume(Volume::Linear(target));
     }
 }
 
-/// The ambient bed's target sink gain: the base volume scaled by the master
+/// The ambient bed's target sink gain: the base volume scaled by the bus
 /// output gain, or ZERO when SND is muted. Pure so the SND-off / master / mute
 /// silence logic is testable without an `AudioSink` (which needs an audio
-/// device). `master` is already the `output_gain(mute)`, so a harness-muted run
+/// device). `master` is already the [`bus_output_gain`], so a harness-muted run
 /// (master 0) silences the bed too.
 pub(crate) fn nova_os_bed_gain(sound_enabled: bool, master: f32) -> f32 {
     if sound_enabled {
@@ -244,6 +244,47 @@ fn nova_os_app_mode_change_pulses_and_decays_the_degauss_uniform() {
     );
 }
 
+#[test]
+fn reduced_motion_holds_the_degauss_uniform_at_zero() {
+    use bevy::ecs::system::RunSystemOnce;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<NovaOsCrtMaterial>();
+    app.init_resource::<Time<Real>>();
+    app.init_resource::<NovaOsMonitorSettings>();
+    app.init_resource::<NovaOsDegauss>();
+    app.insert_resource(AccessibilitySettings {
+        reduced_motion: true,
+        ..default()
+    });
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<NovaOsCrtMaterial>>()
+        .add(NovaOsCrtMaterial::default());
+    app.world_mut().spawn((
+        NovaOsSamplingSurfaceMarker,
+        MaterialNode(handle.clone()),
+        ComputedNode::default(),
+    ));
+
+    app.world_mut().resource_mut::<NovaOsDegauss>().pulse();
+    app.world_mut()
+        .run_system_once(animate_nova_os_crt)
+        .unwrap();
+
+    let material = app
+        .world()
+        .resource::<Assets<NovaOsCrtMaterial>>()
+        .get(&handle)
+        .expect("CRT material still present");
+    assert_eq!(material.data.degauss, 0.0, "no wobble under reduced motion");
+    assert!(
+        app.world().resource::<NovaOsDegauss>().remaining > 0.0,
+        "the pulse still runs, only its effect is scaled away"
+    );
+}
+
 #[test]
 fn mirror_hover_serves_content_but_never_clobbers_window_ui() {
     // `mirror_nova_os_hover` must feed `Hovered` for the forwarded pointer
@@ -757,3 +798,25 @@ fn the_glass_rect_is_measured_in_logical_pixels() {
         "a cursor past the logical rect is off the glass"
     );
 }
+
+/// The in-cockpit panel sits ahead of, below and right of the pilot's eye,
+/// and faces it: its +Z normal points back at the seat.
+#[test]
+fn the_cockpit_screen_faces_the_pilot() {
+    use super::super::cockpit_screen::cockpit_screen_transform;
+
+    let seat = Transform::from_xyz(0.0, 0.0, 2.0);
+    let pose = cockpit_screen_transform(&seat);
+    let eye = seat.translation + COCKPIT_EYE_OFFSET;
+
+    let to_screen = pose.translation - eye;
+    assert!(to_screen.z < 0.0, "ahead of the eye: {to_screen}");
+    assert!(to_screen.y < 0.0, "below the sightline: {to_screen}");
+    assert!(to_screen.x > 0.0, "right of the nose: {to_screen}");
+
+    let normal = pose.rotation * Vec3::Z;
+    assert!(
+        normal.dot((eye - pose.translation).normalize()) > 0.999,
+        "the glass must face the eye, got normal {normal}"
+    );
+}
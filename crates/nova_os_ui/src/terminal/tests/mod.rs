@@ -53,6 +53,7 @@ fn toggle_app() -> App {
     app.init_state::<PauseStates>();
     app.init_resource::<ButtonInput<KeyCode>>();
     app.init_resource::<NovaOsCloseTransition>();
+    app.init_resource::<Keymap>();
     // The computer belongs to a ship: with none on the field the toggle is
     // inert (see `toggle_nova_os`), so the rig flies one.
     app.world_mut().spawn(PlayerSpaceshipMarker);
@@ -20,6 +20,35 @@ fn tab_toggles_nova_os_state() {
     );
 }
 
+/// The toggle reads the player's keymap: moved to F2, the NOVA OS opens on F2
+/// and a plain Tab in flight no longer does.
+#[test]
+fn a_remapped_nova_os_key_opens_the_computer() {
+    let mut app = toggle_app();
+    app.world_mut().resource_mut::<Keymap>().rebind(
+        KeymapAction::NovaOs,
+        KeymapDevice::Keyboard,
+        InputSource::Keyboard(KeyCode::F2),
+    );
+
+    press_tab(&mut app);
+    assert_eq!(
+        pause_state(&app),
+        PauseStates::Unpaused,
+        "Tab is no longer the NOVA OS key"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::F2);
+    app.update();
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.release(KeyCode::F2);
+    keys.clear();
+    app.update();
+    assert_eq!(pause_state(&app), PauseStates::NovaOs);
+}
+
 /// Tab is inert with no ship on the field. `Playing` also covers the editor's
 /// build mode, and a Tab there used to arm the freeze axis over a scene the
 /// monitor cannot draw for: the press looked like it did nothing, then Play
@@ -0,0 +1,766 @@
+//! The scenario fuzzer: flies the player's ship on random input drawn from a
+//! seed, and keeps what it did as a trace that flies the same run again - the
+//! in-game half of `probe fuzz`.
+//!
+//! One env-gated plugin, [`nova_fuzz`]: inert unless `NOVA_PROBE_FUZZ` names
+//! a seed (native only - the trace is a file). Armed, it suspends the player's
+//! input ([`PlayerInputSuspended`]) and, every [`FUZZ_DECISION_TICKS`] fixed
+//! ticks, rolls a new intent from its OWN seeded RNG - never `GlobalRng`,
+//! whose draws belong to the simulation:
+//!
+//! - flight: burn, heading, RCS, weapons raised, and the trigger on every
+//!   weapon section the ship names;
+//! - the combat lock, on a ship the scenario names;
+//! - an autopilot verb - STOP, GOTO an entity (a docking approach when it is a
+//!   port), GOTO a point, ORBIT a well - or none. A verb the ship's computer
+//!   withholds ([`WithheldVerbs`]) is never rolled, so the run exercises the
+//!   content rather than the capability gate;
+//! - now and then, a NOVA OS gameplay command, with an argument drawn from the
+//!   terminal's own completions.
+//!
+//! Only entities with a scenario [`EntityId`] are ever chosen, and candidates
+//! are ordered by it: the replay stores targets by id, and a seed must roll
+//! the same choices on every host.
+//!
+//! ## The trace is the replay
+//!
+//! The fuzzer writes the same intent components the player input layer does,
+//! in `FixedFirst` - ahead of the replay recorder's sample in
+//! `FixedPreUpdate` - so the [recorder](nova_core::nova_menu::replay) keeps the
+//! run like any other, and THAT recording is the trace. `NOVA_PROBE_FUZZ_TRACE`
+//! names where it goes, as JSON like the rest of the probe's output. It is
+//! rewritten after every decision with `end_tick` at the budget, so a run
+//! that panics still leaves a trace that plays up to the panic and past it.
+//! The run ends at its outcome or after `NOVA_PROBE_FUZZ_SECS` of fixed time,
+//! whichever comes first, and the trace is then stamped with the tick it
+//! really ended on.
+//!
+//! `NOVA_PROBE_FUZZ_REPLAY` plays a trace instead of rolling one: once the
+//! scenario has loaded it is handed to the replay player, and the run ends
+//! when playback does. That is how the host flies a failure again, and every
+//! candidate its shrinker cuts.
+//!
+//! Nothing here decides what a FAILURE is. Panics, invariant violations and
+//! stalls are read off the run's log and timeline by the host - the same
+//! evidence a clean pass leaves.
+
+/// Glob-import surface for the scenario fuzzer.
+pub mod prelude {
+    pub use super::{
+        nova_fuzz, FuzzPlugin, DEFAULT_FUZZ_SECS, FUZZ_DECISION_TICKS, FUZZ_PARAM,
+        FUZZ_REPLAY_PARAM, FUZZ_SECS_PARAM, FUZZ_TRACE_PARAM,
+    };
+}
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use bevy::prelude::*;
+use nova_autopilot::completion::{self, HarnessCompletion};
+use nova_core::{
+    nova_menu::replay::prelude::{
+        watch_replay, LastReplay, Replay, ReplayPlaybackEnded, ReplayRecorded, ReplayRecorder,
+        ReplayTick,
+    },
+    nova_os_ui::nova_os::{
+        prelude::{CommandArity, NovaOsTerminal},
+        shell::CommandDispatch,
+    },
+};
+use nova_events::prelude::EntityId;
+use nova_gameplay::prelude::*;
+use nova_scenario::loader::ScenarioLoaded;
+use nova_ship::prelude::*;
+use rand::{rngs::StdRng, RngExt, SeedableRng};
+
+use super::frametime::probe_param;
+
+/// Env value (via [`probe_param`], so `NOVA_PROBE_FUZZ` on native): the seed
+/// that arms the fuzzer.
+pub const FUZZ_PARAM: &str = "fuzz";
+
+/// Env value naming the JSON file the run's trace is written to.
+pub const FUZZ_TRACE_PARAM: &str = "fuzz_trace";
+
+/// Env value naming a trace to play back instead of rolling input. Wins over
+/// [`FUZZ_PARAM`].
+pub const FUZZ_REPLAY_PARAM: &str = "fuzz_replay";
+
+/// Env value: the run's budget, in seconds of fixed time.
+pub const FUZZ_SECS_PARAM: &str = "fuzz_secs";
+
+/// The budget when [`FUZZ_SECS_PARAM`] is unset.
+pub const DEFAULT_FUZZ_SECS: f64 = 60.0;
+
+/// Fixed ticks between two decisions: a quarter second at bevy's 64 Hz, long
+/// enough for a burn or a turn to show, short enough to reach most of what a
+/// scenario can do in a minute.
+pub const FUZZ_DECISION_TICKS: u64 = 16;
+
+/// The completion collector the fuzzer holds the exit open under.
+const FUZZ_COLLECTOR: &str = "fuzz";
+
+/// How far from the ship a random GOTO point may land.
+const GOTO_RADIUS: f32 = 2_000.0;
+
+/// Env-gated fuzzer preset. Inert unless `NOVA_PROBE_FUZZ` or
+/// `NOVA_PROBE_FUZZ_REPLAY` is set. See the module docs.
+pub fn nova_fuzz() -> FuzzPlugin {
+    FuzzPlugin
+}
+
+/// Plugin returned by [`nova_fuzz`]. Construct it through that preset.
+pub struct FuzzPlugin;
+
+impl Plugin for FuzzPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(mode) = FuzzMode::from_params() else {
+            return;
+        };
+        let secs = probe_param(FUZZ_SECS_PARAM)
+            .and_then(|secs| secs.parse::<f64>().ok())
+            .filter(|secs| secs.is_finite() && *secs > 0.0)
+            .unwrap_or(DEFAULT_FUZZ_SECS);
+        let trace = probe_param(FUZZ_TRACE_PARAM).map(PathBuf::from);
+        info!(
+            "nova probe: fuzz armed ({}, budget={secs}s, trace={trace:?})",
+            mode.describe()
+        );
+        completion::register(app, FUZZ_COLLECTOR);
+        app.insert_resource(FuzzRun {
+            mode,
+            secs,
+            trace,
+            checkpoint: 0,
+            finished: false,
+        });
+        app.add_observer(start_fuzz_replay);
+        app.add_observer(finish_fuzz_on_outcome);
+        app.add_observer(finish_fuzz_replay);
+        app.add_systems(
+            FixedFirst,
+            fuzz_player.run_if(in_state(GameStates::Playing)),
+        );
+        app.add_systems(
+            Update,
+            checkpoint_fuzz_trace.run_if(in_state(GameStates::Playing)),
+        );
+    }
+}
+
+/// Where the run's input comes from.
+enum FuzzMode {
+    /// Rolled from this seed.
+    Generate { seed: u64, rng: StdRng },
+    /// Played from a trace; `None` once it is handed to the replay player.
+    Replay(Option<Replay>),
+}
+
+impl FuzzMode {
+    /// The armed mode, or `None` when nothing asked for one. A trace or a seed
+    /// that does not parse is logged at ERROR - it was asked for, and a run
+    /// that silently flies nothing would read as a clean one.
+    fn from_params() -> Option<Self> {
+        if let Some(path) = probe_param(FUZZ_REPLAY_PARAM) {
+            let trace = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| {
+                    serde_json::from_str::<Replay>(&contents).map_err(|e| e.to_string())
+                });
+            return match trace {
+                Ok(trace) => Some(Self::Replay(Some(trace))),
+                Err(error) => {
+                    error!("nova probe: fuzz trace {path} is unreadable: {error}");
+                    None
+                }
+            };
+        }
+        let seed = probe_param(FUZZ_PARAM)?;
+        match seed.parse::<u64>() {
+            Ok(seed) => Some(Self::Generate {
+                seed,
+                rng: StdRng::seed_from_u64(seed),
+            }),
+            Err(error) => {
+                error!("nova probe: fuzz seed '{seed}' is not a u64: {error}");
+                None
+            }
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Generate { seed, .. } => format!("seed={seed}"),
+            Self::Replay(_) => "replaying a trace".into(),
+        }
+    }
+}
+
+/// The armed fuzzer's state.
+#[derive(Resource)]
+struct FuzzRun {
+    mode: FuzzMode,
+    /// The budget, in seconds of fixed time.
+    secs: f64,
+    /// Where the trace is written, if anywhere.
+    trace: Option<PathBuf>,
+    /// The tick the trace was last written at.
+    checkpoint: u64,
+    /// Reported done; nothing more is written.
+    finished: bool,
+}
+
+impl FuzzRun {
+    /// The budget in fixed ticks of `timestep`.
+    fn budget_ticks(&self, timestep: Duration) -> u64 {
+        (self.secs / timestep.as_secs_f64()).ceil() as u64
+    }
+
+    /// Write the trace as it ends, and release the exit.
+    fn finish(&mut self, replay: &Replay, end_tick: u64, completion: &mut HarnessCompletion) {
+        if self.finished {
+            return;
+        }
+        self.finished = true;
+        if let Some(path) = &self.trace {
+            write_trace(path, replay, end_tick);
+        }
+        if let FuzzMode::Generate { seed, .. } = self.mode {
+            info!(
+                "nova probe: fuzz seed {seed} ran {end_tick} tick(s), {} keyframe(s), {} command(s)",
+                replay.keyframes.len(),
+                replay.commands.len()
+            );
+        }
+        completion.done(FUZZ_COLLECTOR);
+    }
+}
+
+/// Write `replay` to `path` with `end_tick`, through a temporary file so a
+/// panic mid-write leaves the previous trace rather than half of this one.
+fn write_trace(path: &Path, replay: &Replay, end_tick: u64) {
+    let mut trace = replay.clone();
+    trace.end_tick = end_tick;
+    // The snapshot is the replay check's, and a trace the shrinker cuts down
+    // ends on another world.
+    trace.snapshot = None;
+    let written = serde_json::to_string(&trace)
+        .map_err(std::io::Error::other)
+        .and_then(|json| {
+            let staging = path.with_extension("json.partial");
+            std::fs::write(&staging, json)?;
+            std::fs::rename(&staging, path)
+        });
+    if let Err(error) = written {
+        error!(
+            "nova probe: could not write the fuzz trace {}: {error}",
+            path.display()
+        );
+    }
+}
+
+/// What one decision may choose from: the live world, read once, every list
+/// ordered by the id the replay stores it under.
+#[derive(Debug, Default)]
+struct FuzzMenu {
+    /// The flight verbs the ship's computer grants.
+    verbs: Vec<FlightVerb>,
+    /// Other ships, for the combat lock.
+    ships: Vec<Entity>,
+    /// Anything GOTO can fly to, and whether it is a docking port.
+    destinations: Vec<(Entity, bool)>,
+    /// Wells ORBIT can circle.
+    wells: Vec<Entity>,
+    /// How many weapon sections the ship names.
+    weapons: usize,
+    /// The NOVA OS gameplay commands and the arguments each completes.
+    commands: Vec<(&'static str, Vec<String>)>,
+    /// Where the ship is, for a GOTO to a point.
+    origin: Vec3,
+}
+
+/// An autopilot decision.
+#[derive(Debug, Clone, PartialEq)]
+enum FuzzVerb {
+    /// Take the autopilot off.
+    Off,
+    Stop,
+    Goto {
+        target: Entity,
+        dock: bool,
+    },
+    GotoPos(Vec3),
+    Orbit(Entity),
+}
+
+/// One decision.
+#[derive(Debug, Clone, PartialEq)]
+struct FuzzRoll {
+    burn: f32,
+    /// Held RCS, or released.
+    rcs: Option<Vec3>,
+    heading: Quat,
+    raised: bool,
+    /// The trigger on each of the menu's weapons, in order.
+    fire: Vec<bool>,
+    /// The new lock; `None` leaves it alone (the computer withholds LOCK).
+    lock: Option<Option<Entity>>,
+    /// The new verb; `None` leaves the one engaged.
+    autopilot: Option<FuzzVerb>,
+    /// A command to queue.
+    command: Option<(&'static str, Vec<String>)>,
+}
+
+/// One of `items`, or `None` when there are none.
+fn pick<'a, T>(rng: &mut StdRng, items: &'a [T]) -> Option<&'a T> {
+    (!items.is_empty()).then(|| &items[rng.random_range(0..items.len())])
+}
+
+/// Roll one decision off `menu`. Pure, so a seed is a sequence of decisions
+/// whatever the world does with them.
+fn roll(rng: &mut StdRng, menu: &FuzzMenu) -> FuzzRoll {
+    let granted = |verb| menu.verbs.contains(&verb);
+    let burn = if rng.random_bool(0.3) {
+        0.0
+    } else {
+        rng.random_range(0.0..=1.0)
+    };
+    let rcs = (granted(FlightVerb::Rcs) && rng.random_bool(0.2)).then(|| {
+        Vec3::new(
+            rng.random_range(-1.0..=1.0),
+            rng.random_range(-1.0..=1.0),
+            rng.random_range(-1.0..=1.0),
+        )
+    });
+    let heading = Quat::from_euler(
+        EulerRot::YXZ,
+        rng.random_range(-std::f32::consts::PI..std::f32::consts::PI),
+        rng.random_range(-1.2..1.2),
+        rng.random_range(-0.5..0.5),
+    );
+    let raised = rng.random_bool(0.5);
+    let fire = (0..menu.weapons)
+        .map(|_| raised && rng.random_bool(0.4))
+        .collect();
+    let lock = granted(FlightVerb::Lock).then(|| {
+        rng.random_bool(0.6)
+            .then(|| pick(rng, &menu.ships).copied())
+            .flatten()
+    });
+    let autopilot = if rng.random_bool(0.1) {
+        Some(FuzzVerb::Off)
+    } else if rng.random_bool(0.2) {
+        let mut verbs = Vec::new();
+        if granted(FlightVerb::Stop) {
+            verbs.push(FlightVerb::Stop);
+        }
+        if granted(FlightVerb::Goto) {
+            verbs.push(FlightVerb::Goto);
+        }
+        if granted(FlightVerb::Orbit) && !menu.wells.is_empty() {
+            verbs.push(FlightVerb::Orbit);
+        }
+        match pick(rng, &verbs) {
+            Some(FlightVerb::Stop) => Some(FuzzVerb::Stop),
+            Some(FlightVerb::Goto) => match pick(rng, &menu.destinations) {
+                Some(&(target, port)) if rng.random_bool(0.7) => {
+                    Some(FuzzVerb::Goto { target, dock: port })
+                }
+                _ => {
+                    let offset = Vec3::new(
+                        rng.random_range(-1.0..=1.0),
+                        rng.random_range(-1.0..=1.0),
+                        rng.random_range(-1.0..=1.0),
+                    );
+                    Some(FuzzVerb::GotoPos(menu.origin + offset * GOTO_RADIUS))
+                }
+            },
+            Some(FlightVerb::Orbit) => pick(rng, &menu.wells).map(|&well| FuzzVerb::Orbit(well)),
+            _ => None,
+        }
+    } else {
+        None
+    };
+    let command = rng
+        .random_bool(0.05)
+        .then(|| pick(rng, &menu.commands).cloned())
+        .flatten()
+        .map(|(name, candidates)| {
+            let args = pick(rng, &candidates).cloned().into_iter().collect();
+            (name, args)
+        });
+    FuzzRoll {
+        burn,
+        rcs,
+        heading,
+        raised,
+        fire,
+        lock,
+        autopilot,
+        command,
+    }
+}
+
+/// Every [`FUZZ_DECISION_TICKS`] ticks of a recorded run: roll a decision and
+/// write it into the player's intent components, where the recorder reads
+/// them next.
+fn fuzz_player(
+    mut commands: Commands,
+    mut run: ResMut<FuzzRun>,
+    recorder: Res<ReplayRecorder>,
+    tick: Res<ReplayTick>,
+    mut suspended: ResMut<PlayerInputSuspended>,
+    mut terminal: Option<ResMut<NovaOsTerminal>>,
+    mut q_ship: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&mut FlightIntent>,
+            Option<&mut RcsIntent>,
+            Option<&mut WeaponsRaised>,
+            Option<&mut CombatLock>,
+        ),
+        (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
+    >,
+    mut q_sections: Query<
+        (
+            &ChildOf,
+            Option<&EntityId>,
+            Option<&mut ControllerSectionRotationInput>,
+            Option<&mut TurretSectionInput>,
+            Option<&mut TorpedoSectionInput>,
+        ),
+        With<SectionMarker>,
+    >,
+    // Mirrors the flight rig's grant check: any live computer on the ship
+    // that does not withhold the verb.
+    q_computers: Query<
+        (&ChildOf, Option<&WithheldVerbs>),
+        (
+            With<ControllerSectionMarker>,
+            With<PDController>,
+            Without<SectionInactiveMarker>,
+        ),
+    >,
+    q_targets: Query<
+        (
+            Entity,
+            &EntityId,
+            Has<SpaceshipRootMarker>,
+            Has<GravityWell>,
+            Has<DockingPort>,
+        ),
+        Without<SectionMarker>,
+    >,
+) {
+    let FuzzRun {
+        mode: FuzzMode::Generate { rng, .. },
+        finished: false,
+        ..
+    } = &mut *run
+    else {
+        return;
+    };
+    // Only a run the recorder is keeping: input it does not see is input no
+    // trace can play back.
+    if !recorder.is_recording() || !tick.0.is_multiple_of(FUZZ_DECISION_TICKS) {
+        return;
+    }
+    if !suspended.0 {
+        suspended.0 = true;
+    }
+    let Ok((ship, transform, flight, rcs, raised, lock)) = q_ship.single_mut() else {
+        return;
+    };
+
+    let mut menu = FuzzMenu {
+        origin: transform.translation,
+        ..default()
+    };
+    menu.verbs = [
+        FlightVerb::Stop,
+        FlightVerb::Goto,
+        FlightVerb::Orbit,
+        FlightVerb::Lock,
+        FlightVerb::Rcs,
+    ]
+    .into_iter()
+    .filter(|&verb| {
+        q_computers.iter().any(|(&ChildOf(parent), withheld)| {
+            parent == ship && withheld.is_none_or(|withheld| withheld.granted(verb))
+        })
+    })
+    .collect();
+    let mut targets: Vec<_> = q_targets
+        .iter()
+        .filter(|(entity, ..)| *entity != ship)
+        .collect();
+    targets.sort_by(|a, b| a.1 .0.cmp(&b.1 .0));
+    for (entity, _, is_ship, is_well, is_port) in targets {
+        if is_ship {
+            menu.ships.push(entity);
+        }
+        if is_well {
+            menu.wells.push(entity);
+        }
+        menu.destinations.push((entity, is_port));
+    }
+    let mut weapons: Vec<String> = q_sections
+        .iter()
+        .filter(|(child_of, ..)| child_of.parent() == ship)
+        .filter(|(_, _, _, turret, torpedo)| turret.is_some() || torpedo.is_some())
+        .filter_map(|(_, id, ..)| id.map(|id| id.0.clone()))
+        .collect();
+    weapons.sort();
+    menu.weapons = weapons.len();
+    if let Some(terminal) = terminal.as_deref() {
+        menu.commands = terminal
+            .command_specs()
+            .iter()
+            .filter(|spec| spec.dispatch == CommandDispatch::Gameplay)
+            .map(|spec| {
+                let candidates = match spec.arity {
+                    CommandArity::None => Vec::new(),
+                    CommandArity::UpTo(_) => terminal
+                        .arg_completions()
+                        .get(spec.name)
+                        .cloned()
+                        .unwrap_or_default(),
+                };
+                (spec.name, candidates)
+            })
+            .collect();
+    }
+
+    let decision = roll(rng, &menu);
+
+    if let Some(mut flight) = flight {
+        flight.burn = decision.burn;
+    }
+    match decision.rcs {
+        Some(command) => {
+            commands.entity(ship).insert(RcsActive);
+            if let Some(mut rcs) = rcs {
+                rcs.0 = command;
+            }
+        }
+        None => {
+            commands.entity(ship).remove::<RcsActive>();
+            if let Some(mut rcs) = rcs {
+                rcs.0 = Vec3::ZERO;
+            }
+        }
+    }
+    if let Some(mut raised) = raised {
+        raised.set_if_neq(WeaponsRaised(decision.raised));
+    }
+    if let (Some(mut lock), Some(target)) = (lock, decision.lock) {
+        lock.0 = target;
+    }
+    // Engaged the way the replay player engages a recorded verb, so the trace
+    // flies the run the way this system flew it.
+    match decision.autopilot {
+        None => {}
+        Some(FuzzVerb::Off) => {
+            commands.entity(ship).remove::<Autopilot>();
+        }
+        Some(FuzzVerb::Stop) => {
+            commands
+                .entity(ship)
+                .insert(Autopilot::engage(AutopilotAction::Stop));
+        }
+        Some(FuzzVerb::Goto { target, dock }) => {
+            let mut ship = commands.entity(ship);
+            ship.insert(Autopilot::engage(AutopilotAction::Goto { target }));
+            if dock {
+                ship.insert(DockApproach { port: target });
+            }
+        }
+        Some(FuzzVerb::GotoPos(position)) => {
+            commands
+                .entity(ship)
+                .insert(Autopilot::engage(AutopilotAction::GotoPos { position }));
+        }
+        Some(FuzzVerb::Orbit(well)) => {
+            commands
+                .entity(ship)
+                .insert(Autopilot::engage(AutopilotAction::Orbit {
+                    well,
+                    plan: None,
+                }));
+        }
+    }
+    for (child_of, id, heading, turret, torpedo) in &mut q_sections {
+        if child_of.parent() != ship {
+            continue;
+        }
+        if let Some(mut heading) = heading {
+            heading.0 = decision.heading;
+        }
+        let held = id
+            .and_then(|id| weapons.iter().position(|weapon| *weapon == id.0))
+            .is_some_and(|index| decision.fire[index]);
+        if let Some(mut turret) = turret {
+            turret.set_if_neq(TurretSectionInput(held));
+        }
+        if let Some(mut torpedo) = torpedo {
+            torpedo.set_if_neq(TorpedoSectionInput(held));
+        }
+    }
+    // Through the pending slot, as a replay re-issues one: the recorder
+    // notes it there, and a busy slot just means no command this time.
+    if let (Some(terminal), Some((name, args))) = (terminal.as_deref_mut(), decision.command) {
+        terminal.replay_invocation(name, args);
+    }
+}
+
+/// Keep the trace on disk current, and end the run at its budget.
+fn checkpoint_fuzz_trace(
+    mut run: ResMut<FuzzRun>,
+    recorder: Res<ReplayRecorder>,
+    tick: Res<ReplayTick>,
+    fixed: Res<Time<Fixed>>,
+    mut completion: ResMut<HarnessCompletion>,
+) {
+    if run.finished || !matches!(run.mode, FuzzMode::Generate { .. }) {
+        return;
+    }
+    let Some(replay) = recorder.replay() else {
+        return;
+    };
+    let budget = run.budget_ticks(fixed.timestep());
+    if tick.0 >= budget {
+        run.finish(replay, tick.0, &mut completion);
+    } else if tick.0 >= run.checkpoint + FUZZ_DECISION_TICKS {
+        run.checkpoint = tick.0;
+        if let Some(path) = &run.trace {
+            write_trace(path, replay, budget);
+        }
+    }
+}
+
+/// The outcome closed the recording: that is the whole run.
+fn finish_fuzz_on_outcome(
+    _: On<ReplayRecorded>,
+    mut run: ResMut<FuzzRun>,
+    last: Res<LastReplay>,
+    mut completion: ResMut<HarnessCompletion>,
+) {
+    if !matches!(run.mode, FuzzMode::Generate { .. }) {
+        return;
+    }
+    if let Some(replay) = &last.0 {
+        run.finish(replay, replay.end_tick, &mut completion);
+    }
+}
+
+/// Hand the trace to the replay player once the scenario it boots into has
+/// loaded in play.
+fn start_fuzz_replay(
+    _: On<ScenarioLoaded>,
+    mut commands: Commands,
+    mut run: ResMut<FuzzRun>,
+    game_state: Res<State<GameStates>>,
+) {
+    if *game_state.get() != GameStates::Playing {
+        return;
+    }
+    let FuzzMode::Replay(trace) = &mut run.mode else {
+        return;
+    };
+    let Some(trace) = trace.take() else {
+        return;
+    };
+    commands.queue(move |world: &mut World| {
+        if let Err(error) = watch_replay(world, trace) {
+            // ERROR: the host asked for this playback, and a run that flies
+            // nothing must not read as one that flew clean.
+            error!("nova probe: cannot play the fuzz trace: {error}");
+            world.resource_mut::<FuzzRun>().finished = true;
+            world
+                .resource_mut::<HarnessCompletion>()
+                .done(FUZZ_COLLECTOR);
+        }
+    });
+}
+
+/// The trace has been flown.
+fn finish_fuzz_replay(
+    _: On<ReplayPlaybackEnded>,
+    mut run: ResMut<FuzzRun>,
+    mut completion: ResMut<HarnessCompletion>,
+) {
+    if run.finished || !matches!(run.mode, FuzzMode::Replay(None)) {
+        return;
+    }
+    run.finished = true;
+    completion.done(FUZZ_COLLECTOR);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn menu() -> FuzzMenu {
+        let mut world = World::new();
+        let [ship, port, well] = [(); 3].map(|()| world.spawn_empty().id());
+        FuzzMenu {
+            verbs: vec![FlightVerb::Stop, FlightVerb::Goto, FlightVerb::Lock],
+            ships: vec![ship],
+            destinations: vec![(port, true)],
+            wells: vec![well],
+            weapons: 2,
+            commands: vec![("ship repair", vec!["hull".into()])],
+            origin: Vec3::ZERO,
+        }
+    }
+
+    #[test]
+    fn a_seed_rolls_the_same_decisions_and_never_a_withheld_verb() {
+        let menu = menu();
+        let rolls = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..400).map(|_| roll(&mut rng, &menu)).collect::<Vec<_>>()
+        };
+        let first = rolls(42);
+        assert_eq!(first, rolls(42), "a seed is a sequence of decisions");
+        assert_ne!(first, rolls(43));
+
+        for decision in &first {
+            // ORBIT and RCS are withheld, so neither is ever asked for, even
+            // with a well on the menu.
+            assert!(!matches!(decision.autopilot, Some(FuzzVerb::Orbit(_))));
+            assert!(decision.rcs.is_none());
+            assert_eq!(decision.fire.len(), 2);
+            assert!(decision.raised || decision.fire.iter().all(|held| !held));
+            assert!((0.0..=1.0).contains(&decision.burn));
+        }
+        // Over 400 decisions every granted verb comes up.
+        assert!(first
+            .iter()
+            .any(|d| matches!(d.autopilot, Some(FuzzVerb::Stop))));
+        assert!(first
+            .iter()
+            .any(|d| matches!(d.autopilot, Some(FuzzVerb::Goto { dock: true, .. }))));
+        assert!(first.iter().any(|d| d.lock == Some(Some(menu.ships[0]))));
+        assert!(first
+            .iter()
+            .any(|d| d.command == Some(("ship repair", vec!["hull".to_string()]))));
+    }
+
+    #[test]
+    fn a_computer_that_withholds_lock_leaves_the_lock_alone() {
+        let menu = FuzzMenu {
+            verbs: Vec::new(),
+            ..menu()
+        };
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let decision = roll(&mut rng, &menu);
+            assert_eq!(decision.lock, None);
+            assert!(matches!(decision.autopilot, None | Some(FuzzVerb::Off)));
+        }
+    }
+}
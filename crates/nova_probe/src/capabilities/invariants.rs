@@ -32,6 +32,24 @@
 //!   [`monotonic`](InvariantsPlugin::monotonic) - never inferred.
 //! - **Entity-count sanity**: the world's total entity count stays under
 //!   [`ENTITY_SANITY_CAP`] (a leak detector; no gameplay cap exists).
+//! - **Scenario-declared invariants**: every invariant the loaded scenario
+//!   declares in its RON (`invariants: [..]`, see
+//!   [`nova_scenario::invariants`]) holds while its `when` guard does. The
+//!   one row here the engine does not guarantee: these are the content's own
+//!   promises, declared by the author like `monotonic`. A broken one is a
+//!   `scenario_invariant` violation when it BREAKS, not again every frame it
+//!   stays broken: the rule is usually a state, and a thousand identical
+//!   entries name nothing new. One it cannot evaluate this frame (a variable
+//!   not set yet) is neither held nor broken - `content lint` is what catches a
+//!   name that never resolves.
+//! - **Replay reproduction**: a run recorded to its outcome is stamped with
+//!   the world [snapshot](super::snapshot) it ended on, header stamps
+//!   stripped; a playback of it that ends on a different world is a
+//!   `replay_divergence`, naming the snapshot sections that differ. Fires at
+//!   the playback's end, not per frame, and is silent for a replay recorded
+//!   without the probe (it carries no snapshot). The one violation that
+//!   PANICS in record+warn mode too: a recorded run is flown in lockstep and
+//!   must come back exactly, so a divergence is never a state to fly through.
 //!
 //! Deliberately NOT in v1: the ship-root-equals-section-sum health aggregate
 //! (mid-despawn frames make it schedule-flaky) and projectile lifetimes
@@ -49,12 +67,21 @@ use std::collections::HashMap;
 
 use avian3d::prelude::LinearVelocity;
 use bevy::{diagnostic::FrameCount, prelude::*};
+use nova_core::nova_menu::replay::prelude::{
+    LastReplay, ReplayPlayback, ReplayPlaybackEnded, ReplayRecorded,
+};
 use nova_gameplay::prelude::Health;
-use nova_scenario::{loader::ScenarioLoaded, variables::VariableLiteral, world::NovaEventWorld};
+use nova_scenario::{
+    invariants::InvariantCheck,
+    loader::{CurrentScenario, ScenarioLoaded},
+    variables::VariableLiteral,
+    world::NovaEventWorld,
+};
 use nova_ship::flight::prelude::FlightSpeedCap;
 
 use super::{
     frametime::probe_param,
+    snapshot::capture_snapshot,
     timeline::{stamp, ProbeTimeline, TimelineEvent},
 };
 
@@ -139,6 +166,9 @@ impl Plugin for InvariantsPlugin {
             violations: 0,
             health_subjects: 0,
             velocity_subjects: 0,
+            scenario_subjects: 0,
+            scenario_broken: Vec::new(),
+            pending: Vec::new(),
         });
         // In Last, BEFORE the recorder's variable-diff + run_end chain, so
         // the exit frame's violations land on the timeline before the
@@ -156,11 +186,104 @@ impl Plugin for InvariantsPlugin {
                 .in_set(crate::capabilities::timeline::ProbeRecorderSystems::RunEnd)
                 .before(crate::capabilities::timeline::record_variable_changes),
         );
-        app.add_observer(forget_monotonic_on_load);
+        app.add_observer(forget_scenario_state_on_load);
+        app.add_observer(stamp_replay_snapshot);
+        app.add_observer(check_replay_snapshot);
+    }
+}
+
+/// Violations that fail the run whether or not the checks are strict. A
+/// replay is a promise the simulation keeps outright, so one that diverges is
+/// a broken lockstep, not a state to note and fly on through.
+const ALWAYS_FATAL: [&str; 1] = ["replay_divergence"];
+
+/// The snapshot header keys that say WHEN a snapshot was taken rather than
+/// what the world was, and the settings the player may change between a run
+/// and its replay: none of them can tell two runs apart.
+const REPLAY_UNCOMPARED_KEYS: [&str; 5] = ["reason", "frame", "elapsed", "t_real", "accessibility"];
+
+/// The world as a replay check compares it.
+fn replay_snapshot(world: &mut World) -> serde_json::Value {
+    let mut snapshot = capture_snapshot(world, "replay");
+    if let Some(object) = snapshot.as_object_mut() {
+        for key in REPLAY_UNCOMPARED_KEYS {
+            object.remove(key);
+        }
+    }
+    snapshot
+}
+
+/// A run was recorded to its outcome: stamp the world it ended on into the
+/// replay, which saves it with the run.
+fn stamp_replay_snapshot(_: On<ReplayRecorded>, mut commands: Commands) {
+    commands.queue(|world: &mut World| {
+        let snapshot = replay_snapshot(world).to_string();
+        if let Some(replay) = world
+            .get_resource_mut::<LastReplay>()
+            .and_then(|last| last.into_inner().0.as_mut())
+        {
+            replay.snapshot = Some(snapshot);
+        }
+    });
+}
+
+/// A playback ended: the world must be the one the recording ended on. The
+/// violation is queued for the next check pass, which delivers it.
+fn check_replay_snapshot(ended: On<ReplayPlaybackEnded>, mut commands: Commands) {
+    let (scenario, tick) = (ended.scenario.clone(), ended.tick);
+    commands.queue(move |world: &mut World| {
+        let Some(recorded) = world
+            .get_resource::<ReplayPlayback>()
+            .and_then(ReplayPlayback::replay)
+            .and_then(|replay| replay.snapshot.clone())
+        else {
+            return;
+        };
+        let replayed = replay_snapshot(world);
+        if let Some(violation) = replay_divergence(&scenario, tick, &recorded, &replayed) {
+            world
+                .resource_mut::<InvariantState>()
+                .pending
+                .push(violation);
+        }
+    });
+}
+
+/// The `replay_divergence` violation for a playback that ended on `replayed`
+/// against the `recorded` snapshot, or `None` when they match.
+fn replay_divergence(
+    scenario: &str,
+    tick: u64,
+    recorded: &str,
+    replayed: &serde_json::Value,
+) -> Option<Violation> {
+    let recorded: serde_json::Value = serde_json::from_str(recorded).unwrap_or_default();
+    if &recorded == replayed {
+        return None;
     }
+    let empty = serde_json::Map::new();
+    let before = recorded.as_object().unwrap_or(&empty);
+    let after = replayed.as_object().unwrap_or(&empty);
+    let mut differs: Vec<&str> = before
+        .keys()
+        .chain(after.keys())
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(String::as_str)
+        .collect();
+    differs.sort_unstable();
+    differs.dedup();
+    Some(Violation {
+        name: "replay_divergence",
+        data: serde_json::json!({
+            "scenario": scenario,
+            "tick": tick,
+            "differs": differs,
+        }),
+    })
 }
 
-/// A scenario load starts a fresh monotonic life.
+/// A scenario load starts a fresh monotonic life, and a fresh set of declared
+/// invariants with nothing broken yet.
 ///
 /// The vanished-key reset in [`check_invariants`] only fires when a registered
 /// key is ABSENT for a checked frame, which a reload never produces: the
@@ -169,8 +292,9 @@ impl Plugin for InvariantsPlugin {
 /// with no gap. Examples that replay a scenario (the round loop in
 /// `system_player_path`) would otherwise take a false `monotonic_regression`
 /// on every round boundary.
-fn forget_monotonic_on_load(_: On<ScenarioLoaded>, mut state: ResMut<InvariantState>) {
+fn forget_scenario_state_on_load(_: On<ScenarioLoaded>, mut state: ResMut<InvariantState>) {
     state.monotonic_last.clear();
+    state.scenario_broken.clear();
 }
 
 /// On the first `AppExit`, write one `invariant_summary` timeline entry
@@ -203,6 +327,7 @@ fn record_invariant_summary(
             "violations": state.violations,
             "health_subjects": state.health_subjects,
             "velocity_subjects": state.velocity_subjects,
+            "scenario_subjects": state.scenario_subjects,
         }),
     });
 }
@@ -231,6 +356,16 @@ pub struct InvariantState {
     /// Most entities the velocity-sanity query examined in a single frame. Same
     /// guard, same reason.
     pub velocity_subjects: u64,
+    /// Most scenario-declared invariants in force AND evaluated in a single
+    /// frame. Same guard: an invariant whose guard never opens, or whose
+    /// variable is never set, holds vacuously, and this is how that shows.
+    pub scenario_subjects: u64,
+    /// The declared invariants broken as of the last check, by name, so a
+    /// broken one is reported once per breach rather than once per frame.
+    scenario_broken: Vec<String>,
+    /// Violations found outside the check pass (the replay check), delivered
+    /// by the next one.
+    pending: Vec<Violation>,
 }
 
 /// One violation, on its way to the log/timeline/panic.
@@ -242,7 +377,8 @@ struct Violation {
 /// The whole v1 check pass, one exclusive system so it reads a settled world
 /// (Last: physics, damage, scenario writes for the frame are done).
 fn check_invariants(world: &mut World) {
-    let mut violations: Vec<Violation> = Vec::new();
+    let mut violations: Vec<Violation> =
+        std::mem::take(&mut world.resource_mut::<InvariantState>().pending);
 
     // (a) Health bounds: finite, 0 <= current <= max.
     {
@@ -364,6 +500,44 @@ fn check_invariants(world: &mut World) {
         }
     }
 
+    // (e) Scenario-declared invariants, edge-triggered per breach.
+    {
+        let mut evaluated: u64 = 0;
+        let mut broken: Vec<String> = Vec::new();
+        let mut breaches: Vec<Violation> = Vec::new();
+        if let (Some(current), Some(scenario)) = (
+            world.get_resource::<CurrentScenario>(),
+            world.get_resource::<NovaEventWorld>(),
+        ) {
+            let state = world.resource::<InvariantState>();
+            for config in current.0.iter() {
+                for invariant in &config.invariants {
+                    match invariant.check(scenario) {
+                        InvariantCheck::Held => evaluated += 1,
+                        InvariantCheck::Broken => {
+                            evaluated += 1;
+                            if !state.scenario_broken.contains(&invariant.name) {
+                                breaches.push(Violation {
+                                    name: "scenario_invariant",
+                                    data: serde_json::json!({
+                                        "scenario": config.id,
+                                        "invariant": invariant.name,
+                                    }),
+                                });
+                            }
+                            broken.push(invariant.name.clone());
+                        }
+                        InvariantCheck::NotInForce | InvariantCheck::Unevaluable(_) => {}
+                    }
+                }
+            }
+        }
+        violations.extend(breaches);
+        let mut state = world.resource_mut::<InvariantState>();
+        state.scenario_subjects = state.scenario_subjects.max(evaluated);
+        state.scenario_broken = broken;
+    }
+
     // Deliver: count, warn, timeline, then (strict) panic - in that order,
     // so even a strict run flushes the evidence before dying.
     world.resource_mut::<InvariantState>().checks += 1;
@@ -404,12 +578,23 @@ fn check_invariants(world: &mut World) {
             violations[0].data
         );
     }
+    if let Some(fatal) = violations
+        .iter()
+        .find(|violation| ALWAYS_FATAL.contains(&violation.name))
+    {
+        panic!(
+            "nova probe: {} is fatal in every armed mode: {}",
+            fatal.name, fatal.data
+        );
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::sync::atomic::{AtomicU32, Ordering};
 
+    use nova_core::nova_menu::replay::prelude::{Replay, REPLAY_SCHEMA};
+
     use super::*;
 
     fn temp_timeline() -> std::path::PathBuf {
@@ -605,6 +790,7 @@ mod tests {
             scenario_id: "replay".to_string(),
             handler_count: 0,
             object_count: 0,
+            survival_seed: None,
         });
         set(&mut app, 0.0);
         app.update();
@@ -616,6 +802,63 @@ mod tests {
         assert_eq!(violations(&app), 1, "live regression still fires");
     }
 
+    /// A declared invariant is reported when it BREAKS - once per breach,
+    /// not once per frame it stays broken - and reads as a checked subject
+    /// only while its guard is open.
+    #[test]
+    fn a_declared_invariant_reports_each_breach_once() {
+        use nova_gameplay::prelude::AssetRef;
+        use nova_scenario::prelude::*;
+
+        let term = |factor| VariableExpressionNode::new_term(VariableTermNode::new_factor(factor));
+        let mut app = rig();
+        app.init_resource::<NovaEventWorld>();
+        app.insert_resource(CurrentScenario(Some(ScenarioConfig {
+            invariants: vec![ScenarioInvariantConfig {
+                name: "hauler_intact".to_string(),
+                rule: InvariantRule::Never(VariableConditionNode::new_less_than(
+                    term(VariableFactorNode::new_name("hauler_hull")),
+                    term(VariableFactorNode::new_literal(VariableLiteral::Number(
+                        0.0,
+                    ))),
+                )),
+                when: None,
+            }],
+            ..ScenarioConfig::new("convoy", "Convoy", AssetRef::default())
+        })));
+        let hull = |app: &mut App, v: f64| {
+            app.world_mut()
+                .resource_mut::<NovaEventWorld>()
+                .insert_variable("hauler_hull".to_string(), VariableLiteral::Number(v));
+        };
+
+        app.update();
+        let state = app.world().resource::<InvariantState>();
+        assert_eq!(
+            state.scenario_subjects, 0,
+            "an unset variable is not checked"
+        );
+
+        hull(&mut app, 10.0);
+        app.update();
+        assert_eq!(violations(&app), 0);
+        assert_eq!(
+            app.world().resource::<InvariantState>().scenario_subjects,
+            1
+        );
+
+        hull(&mut app, -1.0);
+        app.update();
+        app.update();
+        assert_eq!(violations(&app), 1, "one breach, however long it lasts");
+
+        hull(&mut app, 5.0);
+        app.update();
+        hull(&mut app, -2.0);
+        app.update();
+        assert_eq!(violations(&app), 2, "holding again re-arms the report");
+    }
+
     #[test]
     fn nan_scenario_variable_violates() {
         let mut app = App::new();
@@ -701,6 +944,73 @@ mod tests {
         app.update();
     }
 
+    /// A diverged replay fails the run even when the checks only record.
+    #[test]
+    #[should_panic(expected = "replay_divergence is fatal in every armed mode")]
+    fn a_diverged_replay_panics_outside_strict_mode() {
+        let mut app = rig();
+        let recorded = serde_json::json!({ "ships": [] }).to_string();
+        let violation = replay_divergence("gauntlet", 640, &recorded, &serde_json::json!({}))
+            .expect("an emptied world diverges");
+        app.world_mut()
+            .resource_mut::<InvariantState>()
+            .pending
+            .push(violation);
+        app.update();
+    }
+
+    #[test]
+    fn a_replay_that_ends_elsewhere_diverges_by_section() {
+        let recorded = serde_json::json!({
+            "scenario": "gauntlet",
+            "ships": [{ "id": "player", "position": [0.0, 0.0, 10.0] }],
+            "ordnance": [],
+        });
+        assert!(replay_divergence("gauntlet", 640, &recorded.to_string(), &recorded).is_none());
+
+        let replayed = serde_json::json!({
+            "scenario": "gauntlet",
+            "ships": [{ "id": "player", "position": [0.0, 0.0, 12.5] }],
+            "ordnance": [],
+        });
+        let violation = replay_divergence("gauntlet", 640, &recorded.to_string(), &replayed)
+            .expect("a moved ship diverges");
+        assert_eq!(violation.name, "replay_divergence");
+        assert_eq!(violation.data["differs"], serde_json::json!(["ships"]));
+        assert_eq!(violation.data["tick"], 640);
+    }
+
+    /// A finished recording carries the world it ended on, without the stamps
+    /// that differ on every run.
+    #[test]
+    fn a_finished_recording_is_stamped_with_its_snapshot() {
+        let mut app = rig();
+        app.insert_resource(LastReplay(Some(Replay {
+            schema: REPLAY_SCHEMA,
+            scenario: "gauntlet".to_string(),
+            mods: Vec::new(),
+            seed: 7,
+            end_tick: 640,
+            keyframes: Vec::new(),
+            commands: Vec::new(),
+            snapshot: None,
+            survival_seed: None,
+            difficulty: nova_gameplay::prelude::Difficulty::Normal,
+        })));
+        app.world_mut().trigger(ReplayRecorded);
+        app.update();
+
+        let last = app.world().resource::<LastReplay>();
+        let snapshot = last
+            .0
+            .as_ref()
+            .and_then(|replay| replay.snapshot.as_deref());
+        let snapshot: serde_json::Value =
+            serde_json::from_str(snapshot.expect("stamped")).expect("snapshot parses");
+        assert!(snapshot.get("ships").is_some());
+        assert!(snapshot.get("frame").is_none(), "stamps are stripped");
+    }
+
     #[test]
     fn unarmed_plugin_is_a_no_op() {
         let mut app = App::new();
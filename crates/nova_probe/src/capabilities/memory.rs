@@ -0,0 +1,520 @@
+//! Memory over a run: process RSS and what the asset stores hold, sampled on a
+//! wall-clock interval -> `memory.csv`.
+//!
+//! Change this module when a leak hypothesis needs a number the census cannot
+//! give. The census counts the world ONCE; a leak is a slope, and only shows
+//! as the same column climbing sample after sample through a long fight -
+//! carved asteroid remeshes, debris chunks and particle effects each mint
+//! assets as they go, and nothing else in the probe watches them get freed.
+//!
+//! Every byte column is an ESTIMATE of what the asset occupies once uploaded,
+//! computed from its shape rather than measured from an allocator: a mesh is
+//! its vertex stride times its vertices plus its index buffer, an image its
+//! texels across every mip at the format's block size, a material its struct,
+//! a sound its encoded bytes. That is what makes two runs comparable on any
+//! host, and why RSS - the one measured figure - rides beside them rather than
+//! being derived from them.
+
+/// Glob-import surface for the memory capability.
+pub mod prelude {
+    pub use super::{
+        nova_memory, parse_memory_csv, MemoryPlugin, MemorySample, DEFAULT_MEMORY_INTERVAL_SECS,
+        MEMORY_INTERVAL_PARAM, MEMORY_PARAM,
+    };
+}
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+
+use bevy::{audio::AudioSource, diagnostic::FrameCount, mesh::Indices, prelude::*};
+use bevy_hanabi::prelude::{EffectAsset, ParticleEffect};
+use nova_ship::prelude::{SectionCracksMaterial, ThrusterPlumeMaterial};
+
+use crate::{
+    capabilities::{frametime::prelude::*, timeline::ProbeRecorderSystems},
+    contract::{declare, Capability},
+};
+
+/// Logical parameter naming the CSV the samples are written to
+/// (`NOVA_PROBE_MEMORY` natively). Unset, the plugin declares and adds
+/// nothing else.
+pub const MEMORY_PARAM: &str = "memory";
+
+/// Logical parameter overriding the seconds between two samples
+/// (`NOVA_PROBE_MEMORY_INTERVAL` natively).
+pub const MEMORY_INTERVAL_PARAM: &str = "memory_interval";
+
+/// Wall-clock seconds between two samples when none is named. A leak worth
+/// finding climbs over minutes; once a second costs nothing a frame can feel
+/// and still resolves a climb inside one wave of a fight.
+pub const DEFAULT_MEMORY_INTERVAL_SECS: f64 = 1.0;
+
+/// The CSV header, and the order [`MemorySample::to_csv_row`] writes in.
+const HEADER: &str = "t_real,frame,rss_bytes,meshes,mesh_vertices,mesh_bytes,images,image_bytes,\
+                      materials,material_bytes,audio,audio_bytes,effects,particle_effects";
+
+/// Process memory and asset residency, sampled to the CSV named by
+/// [`MEMORY_PARAM`].
+///
+/// Declares [`Capability::Memory`] whether or not the run arms it, like every
+/// probe plugin; an ordinary run pays nothing else.
+pub fn nova_memory() -> MemoryPlugin {
+    MemoryPlugin { out: None }
+}
+
+/// Samples memory every [`DEFAULT_MEMORY_INTERVAL_SECS`] of real time, once
+/// more as the run exits, and logs the growth from the first sample to the
+/// last.
+#[derive(Clone, Default)]
+pub struct MemoryPlugin {
+    out: Option<PathBuf>,
+}
+
+impl MemoryPlugin {
+    /// Write the samples here instead of to the path [`MEMORY_PARAM`] names.
+    pub fn out(mut self, path: impl Into<PathBuf>) -> Self {
+        self.out = Some(path.into());
+        self
+    }
+}
+
+impl Plugin for MemoryPlugin {
+    fn build(&self, app: &mut App) {
+        declare(app, Capability::Memory);
+        let Some(path) = self
+            .out
+            .clone()
+            .or_else(|| probe_param(MEMORY_PARAM).map(PathBuf::from))
+        else {
+            return;
+        };
+        let interval = probe_param(MEMORY_INTERVAL_PARAM)
+            .and_then(|value| value.trim().parse().ok())
+            .filter(|secs: &f64| secs.is_finite() && *secs > 0.0)
+            .unwrap_or(DEFAULT_MEMORY_INTERVAL_SECS);
+        let sink = match MemorySink::create(path, interval) {
+            Ok(sink) => sink,
+            Err(error) => {
+                // ERROR for the timeline sink's reason: the CSV was asked
+                // for, and a run that silently samples nothing reads as a run
+                // that held steady.
+                error!("nova probe: memory sampling disabled: {error}");
+                return;
+            }
+        };
+        info!(
+            "nova probe: memory sampling armed -> {:?} (every {interval}s)",
+            sink.path
+        );
+        app.insert_resource(sink);
+        app.add_systems(Last, sample_memory);
+        // The last sample is a RUN-END row, ordered behind the exit write so
+        // it is taken at all.
+        crate::capabilities::timeline::order_run_end(app);
+        app.add_systems(Last, close_memory.in_set(ProbeRecorderSystems::RunEnd));
+    }
+}
+
+/// One sample: RSS, and each asset store's count and estimated bytes.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemorySample {
+    /// Wall-clock seconds since app start (`Time<Real>`).
+    pub t_real: f64,
+    /// Render frame number.
+    pub frame: u32,
+    /// Resident set size. `None` where the host does not say (non-Linux).
+    pub rss_bytes: Option<u64>,
+    pub meshes: u64,
+    pub mesh_vertices: u64,
+    pub mesh_bytes: u64,
+    pub images: u64,
+    pub image_bytes: u64,
+    /// Standard, section-cracks and thruster-plume materials together.
+    pub materials: u64,
+    pub material_bytes: u64,
+    pub audio: u64,
+    pub audio_bytes: u64,
+    /// Particle effect ASSETS, and the live `ParticleEffect` instances drawing
+    /// them - the instances are the ones a detonation mints.
+    pub effects: u64,
+    pub particle_effects: u64,
+}
+
+impl MemorySample {
+    /// The columns a run is held to a baseline on, by name: everything a leak
+    /// grows. Counts that only say how many (meshes, images) are left out -
+    /// their bytes carry the same story with its weight.
+    pub fn gauges(&self) -> [(&'static str, Option<u64>); 7] {
+        [
+            ("rss_bytes", self.rss_bytes),
+            ("mesh_bytes", Some(self.mesh_bytes)),
+            ("mesh_vertices", Some(self.mesh_vertices)),
+            ("image_bytes", Some(self.image_bytes)),
+            ("material_bytes", Some(self.material_bytes)),
+            ("audio_bytes", Some(self.audio_bytes)),
+            ("particle_effects", Some(self.particle_effects)),
+        ]
+    }
+
+    /// One `memory.csv` row, in the header's column order.
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{:.3},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            self.t_real,
+            self.frame,
+            self.rss_bytes
+                .map_or_else(String::new, |rss| rss.to_string()),
+            self.meshes,
+            self.mesh_vertices,
+            self.mesh_bytes,
+            self.images,
+            self.image_bytes,
+            self.materials,
+            self.material_bytes,
+            self.audio,
+            self.audio_bytes,
+            self.effects,
+            self.particle_effects,
+        )
+    }
+}
+
+/// Parse a whole `memory.csv` back into samples, in order. A header that is
+/// not this module's, or a malformed row, is an error naming its line: a
+/// misread column would compare the wrong bytes against the baseline.
+pub fn parse_memory_csv(contents: &str) -> Result<Vec<MemorySample>, String> {
+    let mut lines = contents.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == HEADER => {}
+        _ => return Err("memory.csv: unexpected header".into()),
+    }
+    lines
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            parse_row(line).ok_or_else(|| format!("memory.csv: malformed line {}: {line:?}", i + 1))
+        })
+        .collect()
+}
+
+fn parse_row(line: &str) -> Option<MemorySample> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+    if fields.len() != HEADER.split(',').count() {
+        return None;
+    }
+    let int = |i: usize| fields[i].parse::<u64>().ok();
+    Some(MemorySample {
+        t_real: fields[0].parse().ok()?,
+        frame: fields[1].parse().ok()?,
+        rss_bytes: if fields[2].is_empty() {
+            None
+        } else {
+            Some(int(2)?)
+        },
+        meshes: int(3)?,
+        mesh_vertices: int(4)?,
+        mesh_bytes: int(5)?,
+        images: int(6)?,
+        image_bytes: int(7)?,
+        materials: int(8)?,
+        material_bytes: int(9)?,
+        audio: int(10)?,
+        audio_bytes: int(11)?,
+        effects: int(12)?,
+        particle_effects: int(13)?,
+    })
+}
+
+/// The open CSV, the sampling clock and the first sample, for the summary.
+#[derive(Resource)]
+struct MemorySink {
+    sink: BufWriter<File>,
+    path: PathBuf,
+    interval: f64,
+    next_at: f64,
+    first: Option<MemorySample>,
+    closed: bool,
+}
+
+impl MemorySink {
+    fn create(path: PathBuf, interval: f64) -> Result<Self, String> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        // Truncated after opening, as the step diagnostics' CSV is: a plain
+        // create splices a second writer's stream into an earlier one's.
+        let file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(|e| format!("could not create {}: {e}", path.display()))?;
+        file.set_len(0)
+            .map_err(|e| format!("could not truncate {}: {e}", path.display()))?;
+        let mut sink = BufWriter::new(file);
+        writeln!(sink, "{HEADER}").map_err(|e| format!("could not write the header: {e}"))?;
+        Ok(Self {
+            sink,
+            path,
+            interval,
+            next_at: 0.0,
+            first: None,
+            closed: false,
+        })
+    }
+
+    /// Append one row, flushed: a run that dies mid-fight keeps every sample
+    /// up to the crash, which is exactly the stretch a leak report needs.
+    fn write(&mut self, sample: &MemorySample) {
+        if let Err(error) =
+            writeln!(self.sink, "{}", sample.to_csv_row()).and_then(|()| self.sink.flush())
+        {
+            warn!("nova probe: memory sample write failed: {error}");
+        }
+        if self.first.is_none() {
+            self.first = Some(sample.clone());
+        }
+    }
+}
+
+/// The resident set size `/proc/self/status` reports, in bytes.
+fn parse_vm_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    let kib: u64 = line
+        .trim_start_matches("VmRSS:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kib * 1024)
+}
+
+fn resident_bytes() -> Option<u64> {
+    std::fs::read_to_string("/proc/self/status")
+        .ok()
+        .as_deref()
+        .and_then(parse_vm_rss)
+}
+
+/// Bytes a texture occupies across `mips` levels of `layers` layers, at
+/// `block_bytes` per `block` of texels (1x1 for an uncompressed format).
+fn texture_bytes(
+    width: u32,
+    height: u32,
+    layers: u32,
+    mips: u32,
+    block: (u32, u32),
+    block_bytes: u32,
+) -> u64 {
+    (0..mips.max(1))
+        .map(|level| {
+            let w = (width >> level).max(1).div_ceil(block.0.max(1));
+            let h = (height >> level).max(1).div_ceil(block.1.max(1));
+            u64::from(w) * u64::from(h) * u64::from(block_bytes)
+        })
+        .sum::<u64>()
+        * u64::from(layers.max(1))
+}
+
+fn image_bytes(image: &Image) -> u64 {
+    let descriptor = &image.texture_descriptor;
+    let format = descriptor.format;
+    texture_bytes(
+        descriptor.size.width,
+        descriptor.size.height,
+        descriptor.size.depth_or_array_layers,
+        descriptor.mip_level_count,
+        format.block_dimensions(),
+        format.block_copy_size(None).unwrap_or(4),
+    )
+}
+
+fn mesh_bytes(mesh: &Mesh) -> (u64, u64) {
+    let vertices = mesh.count_vertices() as u64;
+    let indices = mesh.indices().map_or(0, |indices| match indices {
+        Indices::U16(values) => values.len() as u64 * 2,
+        Indices::U32(values) => values.len() as u64 * 4,
+    });
+    (vertices, vertices * mesh.get_vertex_size() + indices)
+}
+
+/// `(count, bytes)` of a material store, a material counted at its struct.
+fn material_store<M: Asset>(world: &World) -> (u64, u64) {
+    let count = world
+        .get_resource::<Assets<M>>()
+        .map_or(0, |assets| assets.len()) as u64;
+    (count, count * std::mem::size_of::<M>() as u64)
+}
+
+fn take_sample(world: &mut World) -> MemorySample {
+    let mut sample = MemorySample {
+        t_real: world
+            .get_resource::<Time<Real>>()
+            .map_or(0.0, |time| time.elapsed_secs_f64()),
+        frame: world
+            .get_resource::<FrameCount>()
+            .map_or(0, |frame| frame.0),
+        rss_bytes: resident_bytes(),
+        ..default()
+    };
+    if let Some(meshes) = world.get_resource::<Assets<Mesh>>() {
+        for (_, mesh) in meshes.iter() {
+            let (vertices, bytes) = mesh_bytes(mesh);
+            sample.meshes += 1;
+            sample.mesh_vertices += vertices;
+            sample.mesh_bytes += bytes;
+        }
+    }
+    if let Some(images) = world.get_resource::<Assets<Image>>() {
+        sample.images = images.len() as u64;
+        sample.image_bytes = images.iter().map(|(_, image)| image_bytes(image)).sum();
+    }
+    for (count, bytes) in [
+        material_store::<StandardMaterial>(world),
+        material_store::<SectionCracksMaterial>(world),
+        material_store::<ThrusterPlumeMaterial>(world),
+    ] {
+        sample.materials += count;
+        sample.material_bytes += bytes;
+    }
+    if let Some(audio) = world.get_resource::<Assets<AudioSource>>() {
+        sample.audio = audio.len() as u64;
+        sample.audio_bytes = audio
+            .iter()
+            .map(|(_, source)| source.bytes.len() as u64)
+            .sum();
+    }
+    sample.effects = world
+        .get_resource::<Assets<EffectAsset>>()
+        .map_or(0, |assets| assets.len()) as u64;
+    sample.particle_effects = world
+        .query_filtered::<(), With<ParticleEffect>>()
+        .iter(world)
+        .count() as u64;
+    sample
+}
+
+fn sample_memory(world: &mut World) {
+    let now = world
+        .get_resource::<Time<Real>>()
+        .map_or(0.0, |time| time.elapsed_secs_f64());
+    {
+        let Some(mut sink) = world.get_resource_mut::<MemorySink>() else {
+            return;
+        };
+        if sink.closed || now < sink.next_at {
+            return;
+        }
+        sink.next_at = now + sink.interval;
+    }
+    let sample = take_sample(world);
+    world.resource_mut::<MemorySink>().write(&sample);
+}
+
+/// Queue the run-end sample as the run exits.
+fn close_memory(mut exits: MessageReader<AppExit>, mut commands: Commands) {
+    if exits.read().next().is_some() {
+        commands.queue(write_last_sample);
+    }
+}
+
+/// The run-end sample, and one line of growth from the first sample to it.
+fn write_last_sample(world: &mut World) {
+    if world
+        .get_resource::<MemorySink>()
+        .is_none_or(|sink| sink.closed)
+    {
+        return;
+    }
+    let last = take_sample(world);
+    let mut sink = world.resource_mut::<MemorySink>();
+    sink.write(&last);
+    sink.closed = true;
+    let first = sink.first.clone().unwrap_or_default();
+    let mib = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+    let grew = |from: u64, to: u64| to as i64 - from as i64;
+    info!(
+        "nova probe: memory {:?}: rss={} mesh_bytes={:.1}MiB ({:+}) image_bytes={:.1}MiB ({:+}) \
+         materials={} ({:+}) audio_bytes={:.1}MiB particle_effects={} ({:+}) over {:.0}s",
+        sink.path,
+        last.rss_bytes
+            .map_or_else(|| "n/a".into(), |rss| format!("{:.1}MiB", mib(rss))),
+        mib(last.mesh_bytes),
+        grew(first.mesh_bytes, last.mesh_bytes),
+        mib(last.image_bytes),
+        grew(first.image_bytes, last.image_bytes),
+        last.materials,
+        grew(first.materials, last.materials),
+        mib(last.audio_bytes),
+        last.particle_effects,
+        grew(first.particle_effects, last.particle_effects),
+        last.t_real - first.t_real,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_sample_round_trips_through_its_csv_row() {
+        let sample = MemorySample {
+            t_real: 12.5,
+            frame: 740,
+            rss_bytes: Some(512 * 1024 * 1024),
+            meshes: 1_204,
+            mesh_vertices: 3_000_000,
+            mesh_bytes: 96_000_000,
+            images: 80,
+            image_bytes: 64_000_000,
+            materials: 300,
+            material_bytes: 240_000,
+            audio: 12,
+            audio_bytes: 4_000_000,
+            effects: 6,
+            particle_effects: 40,
+        };
+        let csv = format!("{HEADER}\n{}\n", sample.to_csv_row());
+        assert_eq!(parse_memory_csv(&csv).unwrap(), [sample.clone()]);
+        assert_eq!(
+            HEADER.split(',').count(),
+            sample.to_csv_row().split(',').count(),
+            "one column per written field"
+        );
+
+        // A host that does not report RSS leaves the column empty, not zero.
+        let unknown = MemorySample {
+            rss_bytes: None,
+            ..sample
+        };
+        let csv = format!("{HEADER}\n{}\n", unknown.to_csv_row());
+        assert_eq!(parse_memory_csv(&csv).unwrap()[0].rss_bytes, None);
+
+        assert!(
+            parse_memory_csv("t,frame\n1,2\n").is_err(),
+            "not our header"
+        );
+        assert!(parse_memory_csv(&format!("{HEADER}\n1,2,3\n")).is_err());
+    }
+
+    #[test]
+    fn rss_reads_the_kernel_status_line() {
+        let status = "Name:\tnova-protocol\nVmPeak:\t  900000 kB\nVmRSS:\t  524288 kB\n";
+        assert_eq!(parse_vm_rss(status), Some(512 * 1024 * 1024));
+        assert_eq!(parse_vm_rss("Name:\tnova-protocol\n"), None);
+    }
+
+    #[test]
+    fn a_texture_counts_every_mip_and_layer_at_its_block_size() {
+        // 4x4 RGBA8, full chain: 16 + 4 + 1 texels at 4 bytes.
+        assert_eq!(texture_bytes(4, 4, 1, 3, (1, 1), 4), 84);
+        // A six-face cube map is six of the same.
+        assert_eq!(texture_bytes(4, 4, 6, 1, (1, 1), 4), 6 * 64);
+        // BC7: 16 bytes per 4x4 block, and a 2x2 mip still takes a block.
+        assert_eq!(texture_bytes(8, 8, 1, 3, (4, 4), 16), 4 * 16 + 16 + 16);
+    }
+}
@@ -14,11 +14,16 @@
 //!   -> `timeline.jsonl`.
 //! - [`invariants`] - engine-guaranteed bounds asserted every frame, riding
 //!   the timeline sink.
+//! - `memory` - process RSS and what the asset stores hold, sampled through
+//!   the run -> `memory.csv`. The census counts once; this watches the slope.
 //! - [`snapshot`] - the whole world's state on demand (ships, sections,
 //!   fixtures, weapons, ordnance) -> `snapshot.jsonl`. The timeline says what
 //!   happened; this says what the world LOOKS like.
 //! - `stepdiag` - what one FIXED STEP costs, by avian phase, with the
 //!   body-count regime the summary is taken over.
+//! - `fuzz` - not evidence but a DRIVER: flies the player's ship on seeded
+//!   random input and keeps the run as a replayable trace, so the other
+//!   capabilities have something to catch.
 //!
 //! [`NovaProbePlugin`] bundles them all. It does not replace their
 //! per-example configuration: an example that needs a driver or a custom
@@ -36,11 +41,19 @@ pub mod census;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod framecost;
 pub mod frametime;
+// The fuzzer's trace is a file and its seed an env var, so it is native-only;
+// nothing wasm-side drives a scenario this way.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod fuzz;
 // Continuous invariant checks ride the recorder's timeline sink, so they are
 // native-only with it (nothing wasm-side references them - the examples that
 // wire them never build for wasm).
 #[cfg(not(target_arch = "wasm32"))]
 pub mod invariants;
+// Memory sampling reads /proc and writes a CSV, and a browser tab's memory is
+// the browser's: native-only like the other file-writing capabilities.
+#[cfg(not(target_arch = "wasm32"))]
+pub mod memory;
 // The world-state snapshot writes a JSONL file, so it is native-only with the
 // recorder and wasm gets the same shape of no-op stub.
 #[cfg(not(target_arch = "wasm32"))]
@@ -135,8 +148,12 @@ pub mod prelude {
     #[cfg(not(target_arch = "wasm32"))]
     pub use super::framecost::prelude::*;
     #[cfg(not(target_arch = "wasm32"))]
+    pub use super::fuzz::prelude::*;
+    #[cfg(not(target_arch = "wasm32"))]
     pub use super::invariants::prelude::*;
     #[cfg(not(target_arch = "wasm32"))]
+    pub use super::memory::prelude::*;
+    #[cfg(not(target_arch = "wasm32"))]
     pub use super::stepdiag::prelude::*;
     pub use super::{
         frametime::prelude::*, snapshot::prelude::*, timeline::prelude::*, NovaProbePlugin,
@@ -253,7 +270,13 @@ impl Plugin for NovaProbePlugin {
         }
         app.add_plugins(nova_timeline());
         app.add_plugins(nova_snapshot());
+        // Outside the correctness gate: the sampler rides the CLEAN pass, the
+        // long one a leak has time to show in, and arms on its own parameter.
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_plugins(nova_memory());
         #[cfg(not(target_arch = "wasm32"))]
         app.add_plugins(self.invariants.clone());
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_plugins(nova_fuzz());
     }
 }
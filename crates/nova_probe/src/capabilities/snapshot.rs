@@ -13,6 +13,13 @@
 //! A header (`schema`, `scenario`, `frame`, `elapsed`, `t_real`, `game_state`,
 //! `reason`), then:
 //!
+//! - `accessibility` - the player's [`AccessibilitySettings`]: `palette`,
+//!   `text_scale`, `reduced_motion`, `comms_dwell`. Null when the app carries
+//!   no settings.
+//! - `debrief` - the scenario's [`ScenarioDebrief`] so far, for balance runs:
+//!   shots fired and hit per weapon, damage dealt and taken per section class,
+//!   kills, intercepts, locks, the objectives done with their times, and the
+//!   outcome time once one landed. Null when the app carries no debrief.
 //! - `ships` - every [`SpaceshipRootMarker`]: identity, transform, velocity,
 //!   aggregate health, mass, the collapse/defeat/neutralize flags, weapon
 //!   locks, its `skin`, and its `sections`.
@@ -97,17 +104,17 @@ use bevy::{diagnostic::FrameCount, prelude::*};
 use nova_events::prelude::{EntityId, EntityTypeName};
 use nova_gameplay::{
     prelude::{
-        Allegiance, DefeatedMarker, Health, HealthZeroMarker, IntegrityDisabledMarker,
-        NeutralizedMarker, ProjectileDamage, ProjectileOwner, SectionClass, SectionMarker,
-        SpaceshipRootMarker, TempEntity, TempEntityState, TorpedoProjectileMarker,
-        TurretBulletProjectileMarker,
+        AccessibilitySettings, Allegiance, DefeatedMarker, Health, HealthZeroMarker,
+        IntegrityDisabledMarker, NeutralizedMarker, ProjectileDamage, ProjectileOwner,
+        SectionClass, SectionMarker, SpaceshipRootMarker, TempEntity, TempEntityState,
+        TorpedoProjectileMarker, TurretBulletProjectileMarker,
     },
     GameStates,
 };
 use nova_scenario::{
     prelude::{
-        CurrentScenario, SectionAmmoOverride, SectionHealthOverride, SectionRename,
-        SpaceshipController,
+        CurrentScenario, ScenarioDebrief, SectionAmmoOverride, SectionHealthOverride,
+        SectionRename, SpaceshipController,
     },
     world::NovaEventWorld,
 };
@@ -374,6 +381,11 @@ pub fn capture_snapshot(world: &mut World, reason: &str) -> serde_json::Value {
         .get_resource::<State<GameStates>>()
         .map(|state| format!("{:?}", state.get()));
 
+    let accessibility = world
+        .get_resource::<AccessibilitySettings>()
+        .map(accessibility_record);
+    let debrief = world.get_resource::<ScenarioDebrief>().map(debrief_record);
+
     let mut q_ships = world.query_filtered::<Entity, With<SpaceshipRootMarker>>();
     let ship_entities: Vec<Entity> = q_ships.iter(world).collect();
     let mut q_ordnance = world.query_filtered::<Entity, Or<(
@@ -403,11 +415,66 @@ pub fn capture_snapshot(world: &mut World, reason: &str) -> serde_json::Value {
         "frame": frame,
         "elapsed": elapsed,
         "t_real": t_real,
+        "accessibility": accessibility,
+        "debrief": debrief,
         "ships": ships,
         "ordnance": ordnance,
     })
 }
 
+/// The accessibility settings as the player chose them - the raw values, not
+/// the clamped factors, so a corrupt persisted value shows up as itself.
+fn accessibility_record(settings: &AccessibilitySettings) -> serde_json::Value {
+    serde_json::json!({
+        "palette": format!("{:?}", settings.palette),
+        "text_scale": num(settings.text_scale),
+        "reduced_motion": settings.reduced_motion,
+        "comms_dwell": num(settings.comms_dwell),
+    })
+}
+
+/// The debrief's tallies. Every map is keyed by name, so it serializes in key
+/// order; the times are scenario seconds.
+fn debrief_record(debrief: &ScenarioDebrief) -> serde_json::Value {
+    let damage = |by_class: &std::collections::BTreeMap<String, f32>| {
+        by_class
+            .iter()
+            .map(|(class, amount)| (class.clone(), num(*amount)))
+            .collect::<serde_json::Map<_, _>>()
+    };
+    let weapons = debrief
+        .weapons
+        .iter()
+        .map(|(weapon, tally)| {
+            (
+                weapon.clone(),
+                serde_json::json!({
+                    "fired": tally.fired,
+                    "hit": tally.hit,
+                    "accuracy": tally.accuracy().map(num),
+                }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+    let objectives: Vec<serde_json::Value> = debrief
+        .objectives
+        .iter()
+        .map(|(id, time)| serde_json::json!({ "id": id, "time": num(*time as f32) }))
+        .collect();
+    serde_json::json!({
+        "weapons": weapons,
+        "damage_dealt": damage(&debrief.damage_dealt),
+        "damage_taken": damage(&debrief.damage_taken),
+        "kills": debrief.kills,
+        "intercepts": debrief.intercepts,
+        "locks": debrief.locks,
+        "lock_seconds": num(debrief.lock_seconds as f32),
+        "objectives": objectives,
+        "time": debrief.time.map(|time| num(time as f32)),
+        "victory": debrief.victory,
+    })
+}
+
 /// Sort `records` into a total, value-derived order and drop the keys.
 ///
 /// The key is the record's natural id; its serialized form breaks a tie. So the
@@ -1010,6 +1077,7 @@ mod tests {
     use std::sync::atomic::{AtomicU32, Ordering};
 
     use nova_gameplay::prelude::DamageType;
+    use nova_scenario::prelude::WeaponTally;
     use nova_ship::prelude::{unit_cube_link_points, SectionReloadConfig};
 
     use super::*;
@@ -1098,6 +1166,7 @@ mod tests {
                 power: 1.0,
                 layers: 3,
                 kind: DamageType::Kinetic,
+                rock_multiplier: 1.0,
             },
             TempEntity(3.0),
         ));
@@ -1274,6 +1343,69 @@ mod tests {
         assert_eq!(round["lifetime"]["total"], 3.0);
     }
 
+    #[test]
+    fn the_header_carries_the_accessibility_settings_when_present() {
+        let mut app = rig();
+        app.update();
+        let snapshot = capture_snapshot(app.world_mut(), "test");
+        assert_eq!(snapshot["accessibility"], serde_json::Value::Null);
+
+        app.insert_resource(AccessibilitySettings {
+            text_scale: 1.25,
+            reduced_motion: true,
+            comms_dwell: 2.0,
+            ..default()
+        });
+        let snapshot = capture_snapshot(app.world_mut(), "test");
+        assert_eq!(
+            snapshot["accessibility"],
+            serde_json::json!({
+                "palette": "Standard",
+                "text_scale": 1.25,
+                "reduced_motion": true,
+                "comms_dwell": 2.0,
+            })
+        );
+    }
+
+    #[test]
+    fn the_header_carries_the_debrief_when_present() {
+        let mut app = rig();
+        app.update();
+        let snapshot = capture_snapshot(app.world_mut(), "test");
+        assert_eq!(snapshot["debrief"], serde_json::Value::Null);
+
+        let mut debrief = ScenarioDebrief {
+            kills: 2,
+            intercepts: 1,
+            objectives: vec![("reach".to_string(), 12.5)],
+            time: Some(90.0),
+            victory: true,
+            ..default()
+        };
+        debrief
+            .weapons
+            .insert("Turret".to_string(), WeaponTally { fired: 8, hit: 2 });
+        debrief.damage_dealt.insert("Hull".to_string(), 40.0);
+        app.insert_resource(debrief);
+        let snapshot = capture_snapshot(app.world_mut(), "test");
+        let record = &snapshot["debrief"];
+        assert_eq!(
+            record["weapons"]["Turret"],
+            serde_json::json!({ "fired": 8, "hit": 2, "accuracy": 0.25 })
+        );
+        assert_eq!(record["damage_dealt"]["Hull"], 40.0);
+        assert_eq!(record["damage_taken"], serde_json::json!({}));
+        assert_eq!(record["kills"], 2);
+        assert_eq!(record["intercepts"], 1);
+        assert_eq!(
+            record["objectives"],
+            serde_json::json!([{ "id": "reach", "time": 12.5 }])
+        );
+        assert_eq!(record["time"], 90.0);
+        assert_eq!(record["victory"], true);
+    }
+
     /// The whole point of the artifact: one state, one set of bytes. Two
     /// captures of the SAME frame must not differ, or a diff of two runs is
     /// noise.
@@ -29,6 +29,8 @@
 //!   snapshot diff of [`NovaEventWorld`]'s variables that mirrors the
 //!   engine's own write-on-diff logging (world.rs) and likewise ignores the
 //!   every-frame `scenario_elapsed` clock.
+//! - `objective` - every objective the scenario completes, named by its id, in
+//!   completion order (the world's own completion log, diffed per frame).
 //! - `marker` - beats an autopilot script pushes itself via [`probe_marker`],
 //!   so a run reads "raise -> fire -> kill confirmed -> lowered -> goto" in
 //!   the same stream as the engine's own signals.
@@ -160,13 +162,17 @@ impl Plugin for RunRecorderPlugin {
         // Every fired scenario event, name + payload, straight off the
         // observer - the queue is bcs's to drain, not ours.
         app.add_observer(record_game_event);
-        // Variable diff runs in Last so it sees everything PostUpdate
-        // wrote (the scenario clock, action writes) in the same frame;
-        // run_end runs after it so final changes precede the bracket.
+        // Variable and objective diffs run in Last so they see everything
+        // PostUpdate wrote (the scenario clock, action writes) in the same
+        // frame; run_end runs after them so final changes precede the bracket.
         order_run_end(app);
         app.add_systems(
             Last,
-            (record_variable_changes, record_run_end)
+            (
+                record_variable_changes,
+                record_objective_completions,
+                record_run_end,
+            )
                 .chain()
                 .in_set(ProbeRecorderSystems::RunEnd),
         );
@@ -184,7 +190,7 @@ pub struct TimelineEvent {
     /// The scenario clock (`scenario_elapsed`) when a scenario is live.
     pub scenario_elapsed: Option<f64>,
     /// Entry kind: `run_start`, `state`, `scenario_event`, `variable`,
-    /// `marker`, `run_end`.
+    /// `objective`, `marker`, `run_end`.
     pub kind: String,
     /// The entry's name within its kind (event name, variable key, state
     /// type, marker label).
@@ -246,6 +252,8 @@ pub struct ProbeTimeline {
     /// Variables as of the last diff, as JSON values (VariableLiteral maps
     /// in), excluding the `scenario_elapsed` clock.
     last_vars: HashMap<String, serde_json::Value>,
+    /// How many of the world's completed objectives are already recorded.
+    objectives_recorded: usize,
     entries: u64,
 }
 
@@ -287,6 +295,7 @@ impl ProbeTimeline {
             sink: BufWriter::new(file),
             path,
             last_vars: HashMap::new(),
+            objectives_recorded: 0,
             entries: 0,
         })
     }
@@ -312,7 +321,7 @@ pub(crate) fn stamp(
         property: ScenarioProperty::Elapsed,
     });
     let elapsed = scenario.and_then(|world| match world.query_value(&elapsed_query) {
-        Some(VariableLiteral::Number(n)) => Some(*n),
+        Some(VariableLiteral::Number(n)) => Some(n),
         _ => None,
     });
     (time.elapsed_secs_f64(), frame.0, elapsed)
@@ -494,6 +503,39 @@ pub(crate) fn record_variable_changes(
     timeline.last_vars = current;
 }
 
+/// Record one `objective` entry per objective completed since the last frame,
+/// in the order the scenario completed them. The world's log only grows while
+/// a scenario lives; a shorter one is a teardown, and recording restarts.
+fn record_objective_completions(
+    scenario: Option<Res<NovaEventWorld>>,
+    time: Res<Time<Real>>,
+    frame: Res<FrameCount>,
+    mut timeline: ResMut<ProbeTimeline>,
+) {
+    let Some(world) = scenario.as_deref() else {
+        return;
+    };
+    let completed = world.completed_objectives();
+    if completed.len() < timeline.objectives_recorded {
+        timeline.objectives_recorded = 0;
+    }
+    if completed.len() == timeline.objectives_recorded {
+        return;
+    }
+    let (t_real, frame, scenario_elapsed) = stamp(&time, &frame, Some(world));
+    for (id, at) in &completed[timeline.objectives_recorded..] {
+        timeline.record(TimelineEvent {
+            t_real,
+            frame,
+            scenario_elapsed,
+            kind: "objective".to_string(),
+            name: id.clone(),
+            data: serde_json::json!({ "completed_at": at }),
+        });
+    }
+    timeline.objectives_recorded = completed.len();
+}
+
 /// Close the run bracket on the first `AppExit` message.
 fn record_run_end(
     mut exits: MessageReader<AppExit>,
@@ -529,6 +571,7 @@ mod tests {
 
     use bevy::state::app::StatesPlugin;
     use nova_events::engine::GameEventInfo;
+    use nova_scenario::actions::prelude::ObjectiveActionConfig;
 
     use super::*;
 
@@ -672,6 +715,37 @@ mod tests {
         let _ = std::fs::remove_file(&path);
     }
 
+    #[test]
+    fn objective_completions_are_recorded_once_in_completion_order() {
+        let path = temp_timeline();
+        let mut app = rig(&path);
+        app.update();
+
+        {
+            let mut world = app.world_mut().resource_mut::<NovaEventWorld>();
+            world.push_objective(ObjectiveActionConfig::new("dock", "Dock at the depot"));
+            world.push_objective(ObjectiveActionConfig::new("escort", "Escort the hauler"));
+            world.remove_objective("escort");
+        }
+        app.update();
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .remove_objective("dock");
+        app.update();
+        // Steady frames must not re-log the completions.
+        app.update();
+
+        let entries = read_entries(&path);
+        let objectives: Vec<&str> = entries
+            .iter()
+            .filter(|e| e.kind == "objective")
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(objectives, ["escort", "dock"]);
+
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn run_end_closes_the_bracket_on_app_exit() {
         let path = temp_timeline();
@@ -48,6 +48,9 @@ pub enum Capability {
     FrameTime,
     /// `nova_snapshot()` - the world-state snapshot sink (`snapshot.jsonl`).
     Snapshot,
+    /// `nova_memory()` - process RSS and asset residency over the run
+    /// (`memory.csv`).
+    Memory,
 }
 
 impl Capability {
@@ -58,6 +61,7 @@ impl Capability {
             Capability::Invariants => "invariants",
             Capability::FrameTime => "frametime",
             Capability::Snapshot => "snapshot",
+            Capability::Memory => "memory",
         }
     }
 
@@ -69,6 +73,7 @@ impl Capability {
             "invariants" => Some(Capability::Invariants),
             "frametime" => Some(Capability::FrameTime),
             "snapshot" => Some(Capability::Snapshot),
+            "memory" => Some(Capability::Memory),
             _ => None,
         }
     }
@@ -81,6 +86,7 @@ impl Capability {
             Capability::Invariants => "nova_probe::nova_invariants()",
             Capability::FrameTime => "nova_probe::nova_frametime()",
             Capability::Snapshot => "nova_probe::nova_snapshot()",
+            Capability::Memory => "nova_probe::nova_memory()",
         }
     }
 }
@@ -228,7 +234,7 @@ mod tests {
     }
 
     /// The point of the whole module: an UNARMED plugin still declares. Each
-    /// of these three returns early without env, and the claim survives it.
+    /// of these returns early without env, and the claim survives it.
     #[test]
     fn wiring_a_plugin_declares_even_when_the_run_arms_nothing() {
         let mut app = App::new();
@@ -237,6 +243,7 @@ mod tests {
             crate::nova_invariants(),
             crate::nova_frametime(),
             crate::nova_snapshot(),
+            crate::nova_memory(),
         ));
         let contract = app.world().resource::<ProbeContract>().clone();
         assert_eq!(
@@ -246,6 +253,7 @@ mod tests {
                 Capability::Invariants,
                 Capability::FrameTime,
                 Capability::Snapshot,
+                Capability::Memory,
             ])
         );
     }
@@ -113,6 +113,7 @@ pub fn asteroid(
             invulnerable: false,
             seed: None,
             lock_signature,
+            ore: Default::default(),
         }),
     }
 }
@@ -14,8 +14,10 @@
 //!   world-state serializer: every ship, section, fixture, weapon and round in
 //!   flight as one JSON object, on demand), `capabilities::census` (what the
 //!   world contains while a window runs) and `capabilities::framecost` (where
-//!   the milliseconds in that window went, by name). [`NovaProbePlugin`]
-//!   bundles them all.
+//!   the milliseconds in that window went, by name), `capabilities::memory`
+//!   (process RSS and asset residency sampled through the run), plus
+//!   `capabilities::fuzz` (the seeded random pilot `probe fuzz` drives a
+//!   scenario with). [`NovaProbePlugin`] bundles them all.
 //! - [`contract`] - what an example CLAIMS to collect, declared by the plugins
 //!   it wires.
 //! - [`stats`] - [`FrameStats`], the per-run [`RunMeta`], and the CSV/JSON
@@ -127,6 +129,8 @@
 //! | `NOVA_PROBE_RENDER_DIAG` | (unset) | Asks the renderer for GPU timestamp queries, so the frame-cost report can name each render pass. Costs a resolve pass and a readback per frame - a measurement knob, never a default. |
 //! | `NOVA_PROBE_STEPDIAG` / (n/a) | (unset) | Native only: CSV path for the per-FIXED-STEP physics diagnostics - avian's own broad/narrow/prepare/solve/finalize/spatial timers, contact and constraint counts, the step's wall time, and the live dynamic-body and collider counts. |
 //! | `NOVA_PROBE_STEPDIAG_BODIES` / (n/a) | `0` | Native only: the body-count REGIME floor. Only steps carrying at least this many live dynamic bodies enter the end-of-run summary, so two arms are compared over the same weight of world instead of over a whole run one of them ended early. |
+//! | `NOVA_PROBE_MEMORY` / (n/a) | (unset) | Native only: CSV path for the memory samples - process RSS (Linux), and per asset type the resident count and estimated bytes (meshes plus their vertex total, images, materials, audio), with the live particle-effect count. `probe run` arms it into `memory.csv`. |
+//! | `NOVA_PROBE_MEMORY_INTERVAL` / (n/a) | `1.0` | Native only: seconds of real time between memory samples. |
 //! | `NOVA_PROBE_QUALITY` / `quality` | (app default) | Graphics preset for the run (read by the example/bin); recorded in the run metadata. |
 //! | `NOVA_PROBE_SHA` / `sha`       | `git rev-parse` | Overrides the recorded git SHA (the web build cannot shell out). |
 //! | `NOVA_PROBE_HOST` / `host`     | `/etc/hostname` | Overrides the recorded host tag (`browser` on wasm). |
@@ -1,6 +1,7 @@
 //! Everything a run directory produced, loaded once: the timeline, the
-//! frame-time stats, the trace, the log, and the manifest. Every artifact is
-//! optional - a missing one becomes a SKIPPED check, never a silent omission.
+//! frame-time stats, the memory samples, the trace, the log, the manifest, and the shots held
+//! against their goldens. Every artifact is optional - a missing one becomes a
+//! SKIPPED check, never a silent omission.
 
 /// Glob-import surface for a run's collected artifacts.
 pub mod prelude {
@@ -10,12 +11,15 @@ pub mod prelude {
 use std::path::{Path, PathBuf};
 
 use nova_probe::{
-    capabilities::timeline::{parse_timeline, TimelineEvent},
+    capabilities::{
+        memory::{parse_memory_csv, MemorySample},
+        timeline::{parse_timeline, TimelineEvent},
+    },
     contract::{Capability, ProbeContract},
     stats::{parse_frametime_csv, PerfRun},
 };
 
-use super::manifest::RunManifest;
+use super::{golden::GoldenRun, manifest::RunManifest};
 use crate::evaluation::profile::prelude::*;
 
 /// A present-but-unloadable artifact. Never silently dropped: [`RunArtifacts::load`]
@@ -37,6 +41,8 @@ pub struct RunArtifacts {
     pub timeline: Option<Vec<TimelineEvent>>,
     /// Parsed `frametime.csv`.
     pub runs: Option<Vec<PerfRun>>,
+    /// Parsed `memory.csv`: RSS and asset residency sampled over the run.
+    pub memory: Option<Vec<MemorySample>>,
     /// Aggregated `trace.json` system costs. STREAMED, never held: see
     /// [`aggregate_system_costs`] for why the file is not a `String` first.
     pub costs: Option<TraceProfile>,
@@ -44,12 +50,22 @@ pub struct RunArtifacts {
     pub log: Option<String>,
     /// Parsed baseline `frametime.csv` (from `--baseline`).
     pub baseline: Option<Vec<PerfRun>>,
+    /// Parsed baseline `timeline.jsonl`, when the baseline run recorded one -
+    /// what the report's timeline diff aligns this run against.
+    pub baseline_timeline: Option<Vec<TimelineEvent>>,
+    /// Parsed baseline `memory.csv`, when the baseline run sampled one -
+    /// what `memory_within_baseline` holds this run's peaks against.
+    pub baseline_memory: Option<Vec<MemorySample>>,
     /// Parsed `probe-run.json` (present in probe-produced dirs).
     pub manifest: Option<RunManifest>,
     /// Parsed `probe-contract.json`: what the EXAMPLE claimed, by wiring.
     /// `None` for a dir that predates the contract and for a web run (no
     /// filesystem) - absent is NOT "declares nothing", see [`Input::Unknown`].
     pub contract: Option<ProbeContract>,
+    /// The staged goldens compared against this run's shots (see
+    /// [`super::golden`]). `None` when no goldens were staged - the run was
+    /// held to none, which is not the same as holding to them.
+    pub golden: Option<GoldenRun>,
     /// Reload intervals per run label (from each `<label>.json` sidecar's
     /// `reload_ms`, written by looped captures) - excluded from the frame
     /// stats by the capture, shown as their own line.
@@ -153,15 +169,17 @@ impl RunArtifacts {
         };
         let timeline = loader.load("timeline.jsonl", parse_timeline);
         let runs = loader.load("frametime.csv", parse_frametime_csv);
+        let memory = loader.load("memory.csv", parse_memory_csv);
         let costs = loader.stream("trace.json", aggregate_system_costs);
         // The game's logs: run.log (single run), fps-run.log (the fps pass is
-        // a real game run too; its panics/errors gate), web-run.log (chromium's
+        // a real game run too; its panics/errors gate), shots-run.log (so is
+        // the capture path the goldens are held against), web-run.log (chromium's
         // output AND the game's - stats.rs parses `nova perf:` out of its
         // INFO:CONSOLE lines), plus the NUMBERED logs - run-<n>.log (sweep
         // cells) and fps-run-<n>.log (repeat captures). A repeat that panicked
         // has to reach the log checks like any other run.
         let mut log_parts: Vec<String> = Vec::new();
-        for name in ["run.log", "fps-run.log", "web-run.log"] {
+        for name in ["run.log", "fps-run.log", "shots-run.log", "web-run.log"] {
             if let Some(contents) = loader.read(name) {
                 log_parts.push(contents);
             }
@@ -218,6 +236,8 @@ impl RunArtifacts {
                 }
             }
         }
+        let (golden, golden_failures) = super::golden::load_golden_run(dir);
+        loader.failures.extend(golden_failures);
         let baseline = match baseline_dir {
             None => None,
             Some(base) => {
@@ -227,14 +247,39 @@ impl RunArtifacts {
                 Some(parse_frametime_csv(&contents).map_err(|e| format!("baseline: {e}"))?)
             }
         };
+        // Optional where the CSV is not: a baseline from before the recorder
+        // was armed still compares frame times. A present-but-corrupt one is
+        // the operator's argument gone bad, so it errors like the CSV does.
+        let baseline_timeline = match baseline_dir.map(|base| base.join("timeline.jsonl")) {
+            Some(path) if path.exists() => {
+                let contents = std::fs::read_to_string(&path)
+                    .map_err(|e| format!("baseline {}: {e}", path.display()))?;
+                Some(parse_timeline(&contents).map_err(|e| format!("baseline: {e}"))?)
+            }
+            _ => None,
+        };
+        // Optional for the timeline's reason: a baseline that predates memory
+        // sampling still gates frame times.
+        let baseline_memory = match baseline_dir.map(|base| base.join("memory.csv")) {
+            Some(path) if path.exists() => {
+                let contents = std::fs::read_to_string(&path)
+                    .map_err(|e| format!("baseline {}: {e}", path.display()))?;
+                Some(parse_memory_csv(&contents).map_err(|e| format!("baseline: {e}"))?)
+            }
+            _ => None,
+        };
         Ok(Self {
             timeline,
             runs,
+            memory,
             costs,
             log,
             baseline,
+            baseline_timeline,
+            baseline_memory,
             manifest,
             contract,
+            golden,
             reloads,
             failures: loader.failures,
         })
@@ -278,10 +323,12 @@ impl RunArtifacts {
         !self.failures.is_empty()
             || self.timeline.is_some()
             || self.runs.is_some()
+            || self.memory.is_some()
             || self.costs.is_some()
             || self.log.is_some()
             || self.manifest.is_some()
             || self.contract.is_some()
+            || self.golden.is_some()
     }
 
     /// Whether probe armed `capability` for this run. `None` when there is no
@@ -292,6 +339,7 @@ impl RunArtifacts {
             Capability::Timeline => manifest.armed_timeline,
             Capability::Invariants => manifest.armed_invariants,
             Capability::FrameTime => manifest.armed_fps,
+            Capability::Memory => manifest.armed_memory,
             // `probe run` does not arm snapshots: they are an on-demand
             // debugging artifact nothing grades, so the manifest has nothing
             // to say and "unknown" is the honest answer. A check that reads
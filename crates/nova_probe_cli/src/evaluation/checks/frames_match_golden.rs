@@ -0,0 +1,320 @@
+//! `frames_match_golden`: every committed golden frame was captured again, and
+//! each one is still perceptually the frame it was.
+//!
+//! The screenshot producers' output used to be judged by eye and nothing else.
+//! This row holds it to the frames committed under
+//! `crates/nova_probe_cli/goldens/<example>/<backend>/`, per backend because a
+//! software rasterizer and a GPU do not draw the same pixels - so a lavapipe
+//! run in CI grades against lavapipe goldens and needs no human.
+//!
+//! Graded by the worst shot: one lost frame or one shot under `fail_below`
+//! fails the run; under `warn_below` warns. The thresholds and the masked
+//! regions are the example's own `golden.json` (see [`crate::evaluation::golden`]).
+//!
+//! No goldens at all is not applicable, except for a run whose manifest says
+//! they were required - a stills producer on the software backend. That
+//! one FAILS: a producer nobody blessed is a producer nobody checks.
+
+use super::{Check, CheckStatus, NotApplicable, RunArtifacts};
+use crate::evaluation::golden::prelude::*;
+
+/// The grade of one golden, worst first when ordered.
+fn grade(diff: &GoldenDiff, config: &GoldenConfig) -> CheckStatus {
+    match &diff.outcome {
+        GoldenOutcome::NotCaptured
+        | GoldenOutcome::Unreadable(_)
+        | GoldenOutcome::SizeMismatch { .. } => CheckStatus::Fail,
+        GoldenOutcome::Compared(frame) if frame.ssim < config.fail_below => CheckStatus::Fail,
+        // A frame masked out entirely scores a vacuous 1.0; it holds nothing.
+        GoldenOutcome::Compared(frame) if frame.windows == 0 || frame.ssim < config.warn_below => {
+            CheckStatus::Warn
+        }
+        GoldenOutcome::Compared(_) => CheckStatus::Pass,
+    }
+}
+
+fn severity(status: CheckStatus) -> u8 {
+    match status {
+        CheckStatus::Fail => 2,
+        CheckStatus::Warn => 1,
+        _ => 0,
+    }
+}
+
+/// One sentence on what went wrong with a golden that did not pass.
+fn describe(diff: &GoldenDiff, config: &GoldenConfig) -> String {
+    match &diff.outcome {
+        GoldenOutcome::NotCaptured => format!(
+            "{} was not captured this run - the producer no longer takes that shot, \
+             or the shots pass died before it",
+            diff.shot
+        ),
+        GoldenOutcome::Unreadable(reason) => format!("{}: {reason}", diff.shot),
+        GoldenOutcome::SizeMismatch { golden, captured } => format!(
+            "{} was captured at {}x{} against a {}x{} golden - re-bless after a \
+             resolution change",
+            diff.shot, captured.0, captured.1, golden.0, golden.1
+        ),
+        GoldenOutcome::Compared(frame) if frame.windows == 0 => format!(
+            "{}: the masks cover the whole frame, so nothing was compared",
+            diff.shot
+        ),
+        GoldenOutcome::Compared(frame) => {
+            let worst = frame
+                .heatmap
+                .worst()
+                .map(|(col, row, value)| {
+                    format!(
+                        ", hottest at {:.0}%/{:.0}% across/down (1-SSIM {value:.2})",
+                        (col as f64 + 0.5) / frame.heatmap.cols as f64 * 100.0,
+                        (row as f64 + 0.5) / frame.heatmap.rows as f64 * 100.0,
+                    )
+                })
+                .unwrap_or_default();
+            format!(
+                "{} scored SSIM {:.4} against warn {} / fail {}{worst}",
+                diff.shot, frame.ssim, config.warn_below, config.fail_below
+            )
+        }
+    }
+}
+
+pub(super) fn evaluate(artifacts: &RunArtifacts) -> Check {
+    let row = |status, value: String, threshold: String, detail: String, data| Check {
+        name: "frames_match_golden",
+        status,
+        value,
+        threshold,
+        detail,
+        data,
+    };
+
+    let Some(golden) = &artifacts.golden else {
+        return match &artifacts.manifest {
+            Some(manifest) if manifest.goldens_required => row(
+                CheckStatus::Fail,
+                "no goldens".into(),
+                "every shot held to a golden".into(),
+                format!(
+                    "{} is a stills producer and has no software goldens committed - \
+                     bless them with `probe run {} --render sw --bless` and commit \
+                     crates/nova_probe_cli/goldens/{}/",
+                    manifest.example, manifest.example, manifest.example
+                ),
+                serde_json::Value::Null,
+            ),
+            Some(manifest) => row(
+                CheckStatus::NotApplicable(NotApplicable::InputNotSupplied("golden frames")),
+                "no goldens".into(),
+                "-".into(),
+                format!(
+                    "no golden frames were staged: none are committed for {} and this \
+                     backend, or the run took no shots pass (--correctness-only, \
+                     --norender, a sweep, the web)",
+                    manifest.example
+                ),
+                serde_json::Value::Null,
+            ),
+            None => row(
+                CheckStatus::Skipped,
+                "not staged".into(),
+                "-".into(),
+                "no golden/ dir in this run dir - only `probe run` stages goldens".into(),
+                serde_json::Value::Null,
+            ),
+        };
+    };
+    let config = &golden.config;
+    let threshold = format!(
+        "SSIM >= {} per shot (WARN below {})",
+        config.fail_below, config.warn_below
+    );
+    if golden.diffs.is_empty() {
+        return row(
+            CheckStatus::Skipped,
+            "0 goldens".into(),
+            threshold,
+            "golden/ holds no frames to compare against".into(),
+            serde_json::Value::Null,
+        );
+    }
+
+    let graded: Vec<(&GoldenDiff, CheckStatus)> = golden
+        .diffs
+        .iter()
+        .map(|diff| (diff, grade(diff, config)))
+        .collect();
+    let (worst_diff, status) = graded
+        .iter()
+        .copied()
+        .max_by_key(|(_, status)| severity(*status))
+        .expect("at least one golden");
+    let lowest = golden
+        .diffs
+        .iter()
+        .filter_map(|diff| match &diff.outcome {
+            GoldenOutcome::Compared(frame) if frame.windows > 0 => Some(frame.ssim),
+            _ => None,
+        })
+        .min_by(f64::total_cmp);
+    let uncompared = graded
+        .iter()
+        .filter(|(diff, _)| !matches!(diff.outcome, GoldenOutcome::Compared(_)))
+        .count();
+    let value = match (lowest, uncompared) {
+        (Some(ssim), 0) => format!("worst SSIM {ssim:.4} over {} shot(s)", graded.len()),
+        (Some(ssim), missing) => format!(
+            "worst SSIM {ssim:.4}; {missing} of {} shot(s) not compared",
+            graded.len()
+        ),
+        (None, missing) => format!("{missing} of {} shot(s) not compared", graded.len()),
+    };
+    let detail = match status {
+        CheckStatus::Pass => format!(
+            "every golden was captured and held within warn {}",
+            config.warn_below
+        ),
+        _ => describe(worst_diff, config),
+    };
+
+    row(
+        status,
+        value,
+        threshold,
+        detail,
+        serde_json::json!({
+            "warn_below": config.warn_below,
+            "fail_below": config.fail_below,
+            "shots": graded.iter().map(|(diff, status)| {
+                let mut entry = serde_json::json!({
+                    "shot": diff.shot,
+                    "status": status.as_str(),
+                });
+                match &diff.outcome {
+                    GoldenOutcome::Compared(frame) => {
+                        entry["ssim"] = frame.ssim.into();
+                        entry["windows"] = frame.windows.into();
+                        entry["masked_share"] = frame.masked_share.into();
+                        if let Some((col, row, value)) = frame.heatmap.worst() {
+                            entry["worst_cell"] = serde_json::json!([col, row, value]);
+                        }
+                    }
+                    GoldenOutcome::NotCaptured => entry["outcome"] = "not_captured".into(),
+                    GoldenOutcome::Unreadable(reason) => {
+                        entry["outcome"] = "unreadable".into();
+                        entry["reason"] = reason.as_str().into();
+                    }
+                    GoldenOutcome::SizeMismatch { golden, captured } => {
+                        entry["outcome"] = "size_mismatch".into();
+                        entry["golden"] = serde_json::json!([golden.0, golden.1]);
+                        entry["captured"] = serde_json::json!([captured.0, captured.1]);
+                    }
+                }
+                entry
+            }).collect::<Vec<_>>(),
+            "unblessed": golden.unblessed,
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use image::GrayImage;
+
+    use super::*;
+    use crate::evaluation::{checks::evaluate_checks, fixtures::*, manifest::RunManifest};
+
+    fn frame(block_x: u32) -> GrayImage {
+        GrayImage::from_fn(64, 36, |x, y| {
+            let lit = (block_x..block_x + 16).contains(&x) && (10..26).contains(&y);
+            image::Luma([if lit { 200 } else { 12 }])
+        })
+    }
+
+    fn stage(dir: &Path, subdir: &str, name: &str, image: &GrayImage) {
+        std::fs::create_dir_all(dir.join(subdir)).unwrap();
+        image.save(dir.join(subdir).join(name)).unwrap();
+    }
+
+    fn grade_dir(dir: &Path) -> Check {
+        let artifacts = RunArtifacts::load(dir, None).unwrap();
+        check(&evaluate_checks(&artifacts), "frames_match_golden").clone()
+    }
+
+    #[test]
+    fn a_recaptured_golden_passes_and_a_moved_one_fails() {
+        let dir = scratch_run_dir();
+        stage(&dir, GOLDEN_DIR, "feature-hud.png", &frame(16));
+        stage(&dir, SHOTS_DIR, "feature-hud.png", &frame(16));
+        stage(&dir, SHOTS_DIR, "wiki-hud.png", &frame(16));
+        let c = grade_dir(&dir);
+        assert_eq!(c.status, CheckStatus::Pass, "{c:?}");
+        assert_eq!(c.data["shots"][0]["ssim"], 1.0);
+        assert_eq!(c.data["unblessed"][0], "wiki-hud.png");
+
+        stage(&dir, SHOTS_DIR, "feature-hud.png", &frame(40));
+        let c = grade_dir(&dir);
+        assert_eq!(c.status, CheckStatus::Fail, "{c:?}");
+        assert!(c.detail.contains("feature-hud.png"), "{c:?}");
+        assert!(c.data["shots"][0]["worst_cell"].is_array(), "{c:?}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// A golden the producer stopped taking is a lost frame, never a skip -
+    /// and masking the moved region out turns the same pair back into a pass.
+    #[test]
+    fn a_lost_frame_fails_and_a_mask_forgives_its_region() {
+        let dir = scratch_run_dir();
+        stage(&dir, GOLDEN_DIR, "a.png", &frame(16));
+        let c = grade_dir(&dir);
+        assert_eq!(c.status, CheckStatus::Fail, "{c:?}");
+        assert_eq!(c.data["shots"][0]["outcome"], "not_captured");
+
+        stage(&dir, SHOTS_DIR, "a.png", &frame(40));
+        std::fs::write(
+            dir.join(GOLDEN_DIR).join(GOLDEN_CONFIG),
+            r#"{"masks": [{"shot": "a.png", "rect": [0.2, 0.2, 0.7, 0.6], "why": "the block moves"}]}"#,
+        )
+        .unwrap();
+        let c = grade_dir(&dir);
+        assert_eq!(c.status, CheckStatus::Pass, "{c:?}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn no_staged_goldens_is_not_applicable_with_a_manifest_and_skipped_without() {
+        let artifacts = RunArtifacts {
+            manifest: Some(manifest_ok()),
+            ..Default::default()
+        };
+        let c = check(&evaluate_checks(&artifacts), "frames_match_golden").clone();
+        assert_eq!(
+            c.status,
+            CheckStatus::NotApplicable(NotApplicable::InputNotSupplied("golden frames"))
+        );
+        let c = check(
+            &evaluate_checks(&RunArtifacts::default()),
+            "frames_match_golden",
+        )
+        .clone();
+        assert_eq!(c.status, CheckStatus::Skipped);
+    }
+
+    /// A stills producer with nothing blessed fails the run instead of
+    /// passing it ungraded.
+    #[test]
+    fn a_producer_without_goldens_fails() {
+        let artifacts = RunArtifacts {
+            manifest: Some(RunManifest {
+                goldens_required: true,
+                ..manifest_ok()
+            }),
+            ..Default::default()
+        };
+        let c = check(&evaluate_checks(&artifacts), "frames_match_golden").clone();
+        assert_eq!(c.status, CheckStatus::Fail, "{c:?}");
+        assert!(c.detail.contains("--bless"), "{c:?}");
+    }
+}
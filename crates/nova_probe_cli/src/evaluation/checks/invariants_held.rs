@@ -1,5 +1,5 @@
 //! `invariants_held`: the engine-guaranteed bounds the run asserted every
-//! frame.
+//! frame, and the invariants the scenario declared for itself.
 //!
 //! The summary entry carries the tally; per-name counts ride in detail AND
 //! data - a stuck entity violates every frame, so a raw total says nothing
@@ -117,19 +117,26 @@ pub(super) fn evaluate(artifacts: &RunArtifacts) -> Check {
     let subjects = |key: &str| summary.and_then(|s| s.data[key].as_u64());
     let health_subjects = subjects("health_subjects");
     let velocity_subjects = subjects("velocity_subjects");
+    // Only a scenario that declares invariants has any; named only then.
+    let scenario_subjects = subjects("scenario_subjects");
     let counts = serde_json::json!({
         "violations": violations,
         "checked_frames": checks_run,
         "by_name": by_name,
         "health_subjects": health_subjects,
         "velocity_subjects": velocity_subjects,
+        "scenario_subjects": scenario_subjects,
     });
     let subject_detail = match (health_subjects, velocity_subjects) {
         (None, None) => String::new(),
         (h, v) => format!(
-            "; peak subjects: {} health, {} velocity",
+            "; peak subjects: {} health, {} velocity{}",
             h.unwrap_or(0),
-            v.unwrap_or(0)
+            v.unwrap_or(0),
+            match scenario_subjects {
+                Some(n) if n > 0 => format!(", {n} scenario-declared"),
+                _ => String::new(),
+            }
         ),
     };
 
@@ -185,7 +192,7 @@ mod tests {
         );
         contents = contents.replace(
             "\"data\":{\"checks\":120,\"violations\":0}",
-            "\"data\":{\"checks\":120,\"violations\":2,\"health_subjects\":7,\"velocity_subjects\":9}",
+            "\"data\":{\"checks\":120,\"violations\":2,\"health_subjects\":7,\"velocity_subjects\":9,\"scenario_subjects\":2}",
         );
         std::fs::write(&path, contents).unwrap();
 
@@ -195,7 +202,8 @@ mod tests {
         assert_eq!(c.status, CheckStatus::Fail);
         assert!(c.detail.contains("health_bounds x2"), "{c:?}");
         assert!(
-            c.detail.contains("peak subjects: 7 health, 9 velocity"),
+            c.detail
+                .contains("peak subjects: 7 health, 9 velocity, 2 scenario-declared"),
             "{c:?}"
         );
         assert_eq!(c.data["health_subjects"], 7);
@@ -0,0 +1,280 @@
+//! `memory_within_baseline`: `fps_within_baseline`'s soft gate, held on the
+//! run's memory PEAKS.
+//!
+//! Each gauge compares this run's high-water mark against the baseline's -
+//! the peak, not the last sample, because a leak that a level transition
+//! happens to free before exit still cost the run its headroom. A gauge only
+//! counts when both runs sampled it and the baseline's peak is non-zero: RSS
+//! is absent off Linux, and audio may never load at all.
+
+use nova_probe::prelude::*;
+
+use super::{Check, CheckStatus, NotApplicable, RunArtifacts};
+use crate::evaluation::prelude::*;
+
+/// Soft memory gate: the worst gauge's peak may grow this many percent over
+/// the baseline's before the check turns WARN. Looser than the FPS gate -
+/// allocator slack and asset streaming order move RSS more than a frame
+/// moves its mean - and still a flag for the reviewer, never a failure.
+pub const MEMORY_WARN_THRESHOLD_PCT: f64 = 15.0;
+
+fn threshold() -> String {
+    format!("peak growth <= {MEMORY_WARN_THRESHOLD_PCT}%")
+}
+
+/// The highest value each gauge reached over `samples`, in
+/// [`MemorySample::gauges`] order. `None` for a gauge no sample carried.
+fn peaks(samples: &[MemorySample]) -> Vec<(&'static str, Option<u64>)> {
+    let mut peaks: Vec<(&'static str, Option<u64>)> = Vec::new();
+    for sample in samples {
+        for (i, (name, value)) in sample.gauges().into_iter().enumerate() {
+            match peaks.get_mut(i) {
+                Some((_, peak)) => *peak = (*peak).max(value),
+                None => peaks.push((name, value)),
+            }
+        }
+    }
+    peaks
+}
+
+pub(super) fn evaluate(artifacts: &RunArtifacts) -> Check {
+    let no_input = |status, value: &str, detail: String| Check {
+        name: "memory_within_baseline",
+        status,
+        value: value.into(),
+        threshold: threshold(),
+        detail,
+        data: serde_json::Value::Null,
+    };
+    let samples = match artifacts.resolve(Capability::Memory, artifacts.memory.as_ref()) {
+        Input::Present(samples) => samples,
+        Input::NotDeclared(capability) => {
+            return no_input(
+                CheckStatus::NotApplicable(NotApplicable::NotDeclared(capability)),
+                "not claimed",
+                format!(
+                    "the example wires no {} - it makes no memory assertion, \
+                     so there is nothing to compare",
+                    capability.wiring()
+                ),
+            )
+        }
+        Input::NotArmed(capability) => {
+            return no_input(
+                CheckStatus::NotApplicable(NotApplicable::NotArmed(capability)),
+                "not armed",
+                format!(
+                    "the example wires {} but this run did not arm the sampler",
+                    capability.wiring()
+                ),
+            )
+        }
+        Input::ArmedButAbsent(capability) => {
+            return no_input(
+                CheckStatus::Fail,
+                "armed and silent",
+                format!(
+                    "the example declares {} and probe armed the sampler, but no \
+                     memory.csv was written",
+                    capability.wiring()
+                ),
+            )
+        }
+        Input::Unknown(_) => {
+            return no_input(
+                CheckStatus::Skipped,
+                "no capture",
+                "memory.csv not captured (arm NOVA_PROBE_MEMORY)".into(),
+            )
+        }
+    };
+    // `--baseline` always loads the baseline's frametime.csv, so its absence
+    // is the operator's argument missing - not the baseline's memory.csv.
+    if artifacts.baseline.is_none() {
+        return no_input(
+            CheckStatus::NotApplicable(NotApplicable::InputNotSupplied("--baseline")),
+            "no baseline",
+            "a delta needs a baseline capture: pass --baseline <dir>".into(),
+        );
+    }
+    let Some(baseline) = &artifacts.baseline_memory else {
+        return no_input(
+            CheckStatus::NotApplicable(NotApplicable::InputNotComparable("baseline memory")),
+            "baseline has no memory.csv",
+            "the baseline run predates memory sampling; re-capture it to compare".into(),
+        );
+    };
+
+    let mut worst_regression: Option<(&str, f64)> = None;
+    let mut best_note: Option<(&str, f64)> = None;
+    let mut matched = 0;
+    for ((gauge, peak), (_, base)) in peaks(samples).into_iter().zip(peaks(baseline)) {
+        let (Some(peak), Some(base)) = (peak, base) else {
+            continue;
+        };
+        if base == 0 {
+            continue;
+        }
+        matched += 1;
+        let delta = (peak as f64 - base as f64) / base as f64 * 100.0;
+        if delta > 0.0 {
+            if worst_regression.is_none_or(|(_, w)| delta > w) {
+                worst_regression = Some((gauge, delta));
+            }
+        } else if best_note.is_none_or(|(_, b)| delta < b) {
+            best_note = Some((gauge, delta));
+        }
+    }
+
+    if matched == 0 {
+        return no_input(
+            CheckStatus::NotApplicable(NotApplicable::InputNotComparable("baseline memory")),
+            "no comparable gauges",
+            "no gauge was sampled non-zero by both runs (RSS is Linux-only)".into(),
+        );
+    }
+
+    match worst_regression {
+        Some((gauge, delta)) if delta > MEMORY_WARN_THRESHOLD_PCT => Check {
+            name: "memory_within_baseline",
+            status: CheckStatus::Warn,
+            value: format!("worst {gauge}: +{delta:.1}%"),
+            threshold: threshold(),
+            detail: "soft gate: peaks move with load order and allocator slack; \
+                     reviewer judges (does memory.csv climb, or just peak higher?)"
+                .into(),
+            data: serde_json::json!({ "gauge": gauge, "delta_pct": delta }),
+        },
+        Some((gauge, delta)) => Check {
+            name: "memory_within_baseline",
+            status: CheckStatus::Pass,
+            value: format!("worst {gauge}: +{delta:.1}%"),
+            threshold: threshold(),
+            detail: "worst peak growth within the soft gate".into(),
+            data: serde_json::json!({ "gauge": gauge, "delta_pct": delta }),
+        },
+        None => {
+            let (gauge, delta) = best_note.expect("matched > 0 with no regressions");
+            Check {
+                name: "memory_within_baseline",
+                status: CheckStatus::Pass,
+                value: format!("shrank; best {gauge}: {delta:.1}%"),
+                threshold: threshold(),
+                detail: "no gauge peaked above the baseline".into(),
+                data: serde_json::json!({ "gauge": gauge, "delta_pct": delta }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation::{
+        checks::{evaluate_checks, overall_verdict},
+        fixtures::*,
+    };
+
+    const HEADER: &str = "t_real,frame,rss_bytes,meshes,mesh_vertices,mesh_bytes,images,\
+                          image_bytes,materials,material_bytes,audio,audio_bytes,effects,\
+                          particle_effects";
+
+    /// Two samples whose SECOND holds the peak: `rss` and `mesh_bytes` are
+    /// what the tests move, everything else holds still.
+    fn write_memory(dir: &std::path::Path, rss: u64, mesh_bytes: u64) {
+        let row = |t: f64, rss: u64, mesh: u64| {
+            format!(
+                "{t:.3},{},{rss},4,1000,{mesh},2,4096,3,0,0,0,1,2",
+                (t * 60.0) as u32
+            )
+        };
+        std::fs::write(
+            dir.join("memory.csv"),
+            format!(
+                "{HEADER}\n{}\n{}\n",
+                row(1.0, rss / 2, mesh_bytes / 2),
+                row(2.0, rss, mesh_bytes)
+            ),
+        )
+        .unwrap();
+    }
+
+    /// A run that declared and armed memory sampling, with these peaks.
+    fn armed_run(rss: u64, mesh_bytes: u64) -> std::path::PathBuf {
+        let dir = scratch_run_dir();
+        write_contract(&dir, [Capability::Memory]);
+        let mut manifest = manifest_ok();
+        manifest.armed_memory = true;
+        write_manifest(&dir, &manifest);
+        write_memory(&dir, rss, mesh_bytes);
+        dir
+    }
+
+    #[test]
+    fn declared_memory_without_output_fails() {
+        let dir = armed_run(100, 100);
+        std::fs::remove_file(dir.join("memory.csv")).unwrap();
+
+        let artifacts = RunArtifacts::load(&dir, None).unwrap();
+        let checks = evaluate_checks(&artifacts);
+        let c = check(&checks, "memory_within_baseline");
+        assert_eq!(c.status, CheckStatus::Fail, "{c:?}");
+        assert!(c.detail.contains("no memory.csv"), "{c:?}");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn the_worst_peak_warns_beyond_threshold_and_passes_within() {
+        let base_dir = armed_run(1_000_000, 1000);
+
+        // +10% RSS is within the soft gate.
+        let dir = armed_run(1_100_000, 1000);
+        let artifacts = RunArtifacts::load(&dir, Some(&base_dir)).unwrap();
+        let c = check(&evaluate_checks(&artifacts), "memory_within_baseline").clone();
+        assert_eq!(c.status, CheckStatus::Pass, "{c:?}");
+        assert!(c.value.contains("rss_bytes: +10.0%"), "{c:?}");
+
+        // Mesh bytes +50% warns, and is named over the smaller RSS growth.
+        write_memory(&dir, 1_100_000, 1500);
+        let artifacts = RunArtifacts::load(&dir, Some(&base_dir)).unwrap();
+        let checks = evaluate_checks(&artifacts);
+        let c = check(&checks, "memory_within_baseline");
+        assert_eq!(c.status, CheckStatus::Warn, "{c:?}");
+        assert!(c.value.contains("mesh_bytes: +50.0%"), "{c:?}");
+        assert_eq!(overall_verdict(&checks), "WARN");
+
+        // Shrinking is a PASS with the improvement noted, never a WARN.
+        write_memory(&dir, 500_000, 1000);
+        let artifacts = RunArtifacts::load(&dir, Some(&base_dir)).unwrap();
+        let c = check(&evaluate_checks(&artifacts), "memory_within_baseline").clone();
+        assert_eq!(c.status, CheckStatus::Pass, "{c:?}");
+        assert!(c.value.contains("shrank"), "{c:?}");
+
+        let _ = std::fs::remove_dir_all(&dir);
+        let _ = std::fs::remove_dir_all(&base_dir);
+    }
+
+    /// A baseline from before memory sampling still gates frame times; it
+    /// just has nothing to say about memory.
+    #[test]
+    fn a_baseline_without_memory_is_not_comparable_rather_than_passing() {
+        let base_dir = scratch_run_dir();
+        let dir = armed_run(1_000_000, 1000);
+
+        let artifacts = RunArtifacts::load(&dir, None).unwrap();
+        assert_eq!(
+            check(&evaluate_checks(&artifacts), "memory_within_baseline").status,
+            CheckStatus::NotApplicable(NotApplicable::InputNotSupplied("--baseline"))
+        );
+
+        let artifacts = RunArtifacts::load(&dir, Some(&base_dir)).unwrap();
+        let c = check(&evaluate_checks(&artifacts), "memory_within_baseline").clone();
+        assert_eq!(
+            c.status,
+            CheckStatus::NotApplicable(NotApplicable::InputNotComparable("baseline memory")),
+            "{c:?}"
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+        let _ = std::fs::remove_dir_all(&base_dir);
+    }
+}
@@ -11,20 +11,24 @@ pub mod prelude {
     pub use super::{
         check_names, checks_json, evaluate_checks, measured_count, overall_verdict, print_checks,
         status_class, Check, CheckStatus, NotApplicable, FPS_WARN_THRESHOLD_PCT,
+        MEMORY_WARN_THRESHOLD_PCT,
     };
 }
 
 mod artifacts_loadable;
 mod capture_simulated;
 mod fps_within_baseline;
+mod frames_match_golden;
 mod invariants_held;
 mod log_clean;
+mod memory_within_baseline;
 mod process_exit;
 mod reached_playing;
 mod run_completed;
 
 pub use fps_within_baseline::FPS_WARN_THRESHOLD_PCT;
 pub(crate) use invariants_held::violations_by_name;
+pub use memory_within_baseline::MEMORY_WARN_THRESHOLD_PCT;
 use nova_probe::prelude::*;
 
 use super::{artifacts::RunArtifacts, manifest::RunManifest};
@@ -116,9 +120,9 @@ pub fn status_class(status: &str) -> &'static str {
 }
 
 /// Every check, in report order: its name, the capability it grades (`None`
-/// for the three that need no plugin - probe owns the exit status, the
-/// captured stdio and the artifacts themselves for every run), and its
-/// evaluator. Adding a check is
+/// for the four that need no plugin - probe owns the exit status, the
+/// captured stdio, the artifacts themselves and the goldens it stages for
+/// every run), and its evaluator. Adding a check is
 /// adding a module and a row here - the aggregation holds no per-check
 /// knowledge.
 const CHECKS: &[(&str, Option<Capability>, fn(&RunArtifacts) -> Check)] = &[
@@ -138,6 +142,9 @@ const CHECKS: &[(&str, Option<Capability>, fn(&RunArtifacts) -> Check)] = &[
         Some(Capability::Invariants),
         invariants_held::evaluate,
     ),
+    // Still correctness: a producer that draws the wrong picture did the
+    // wrong thing, however fast it drew it.
+    ("frames_match_golden", None, frames_match_golden::evaluate),
     // Before the comparison, because it decides whether there WAS a window to
     // compare: a capture that met a stopped simulation measured a still
     // picture, and every statistic downstream of it is fiction.
@@ -151,6 +158,11 @@ const CHECKS: &[(&str, Option<Capability>, fn(&RunArtifacts) -> Check)] = &[
         Some(Capability::FrameTime),
         fps_within_baseline::evaluate,
     ),
+    (
+        "memory_within_baseline",
+        Some(Capability::Memory),
+        memory_within_baseline::evaluate,
+    ),
     ("log_clean", None, log_clean::evaluate),
     // Last because it grades the EVIDENCE rather than the run: when it fails,
     // it is the reason the rows above read the way they do.
@@ -422,6 +434,8 @@ mod tests {
             armed_timeline: true,
             armed_invariants: true,
             armed_fps: false,
+            armed_memory: false,
+            goldens_required: false,
             passes: vec![PassRecord {
                 name: "clean".into(),
                 success: true,
@@ -431,7 +445,7 @@ mod tests {
         artifacts.manifest = Some(manifest);
         let json = checks_json(&artifacts, &checks);
         assert_eq!(json["verdict"], "OK");
-        assert_eq!(json["measured"], "6/8");
+        assert_eq!(json["measured"], "6/10");
         assert_eq!(json["reviewer_confirmation_required"], true);
         assert_eq!(json["run"]["example"], "playable");
         assert_eq!(json["run"]["passes"][0]["name"], "clean");
@@ -100,6 +100,8 @@ mod tests {
             armed_timeline: true,
             armed_invariants: true,
             armed_fps: true,
+            armed_memory: true,
+            goldens_required: false,
             passes: vec![
                 PassRecord {
                     name: "clean".into(),
@@ -61,6 +61,8 @@ pub fn manifest_ok() -> RunManifest {
         armed_timeline: true,
         armed_invariants: true,
         armed_fps: false,
+        armed_memory: false,
+        goldens_required: false,
         passes: vec![PassRecord {
             name: "clean".into(),
             success: true,
@@ -0,0 +1,359 @@
+//! `probe fuzz`'s two judgements: whether a fuzzed run FAILED, read off the
+//! log and timeline it left, and which cuts of a failing trace still fail.
+//!
+//! A run's failure is the first of these that holds, in this order:
+//!
+//! - `panic` - a `panicked at` line in the log, named by its location, so a
+//!   replay that panics somewhere else is a different failure;
+//! - `nan_physics` - a `velocity_finite` invariant entry: a body's velocity
+//!   went non-finite, which is the physics exploding;
+//! - `invariant` - any other invariant entry, named by the invariant;
+//! - `stall` - the scenario clock ran [`DEFAULT_STALL_SECS`] (or the
+//!   operator's `--stall-secs`) with no PROGRESS on the timeline: no scenario
+//!   event other than the per-frame update pulse, no variable change, no
+//!   completed objective. The pilot is random, so a scenario that waits on it
+//!   is being poked the whole time; minutes of silence is a script that can
+//!   no longer move, not a slow player;
+//! - `exit` - the run ended non-zero or was killed with none of the above to
+//!   name it (the completion deadline, a crash the log lost).
+//!
+//! Two failures are the SAME when their kind and name match - what the
+//! shrinker holds each cut to. The shrinker itself is [`shrink`]: delta
+//! debugging over the trace's input, removing ever-smaller chunks while the
+//! failure holds, then [`shorten`] bisecting the run's length.
+
+/// Glob-import surface for the fuzz verdicts and the shrinker.
+pub mod prelude {
+    pub use super::{find_failure, shorten, shrink, FailureKind, FuzzFailure, DEFAULT_STALL_SECS};
+}
+
+use nova_probe::prelude::*;
+
+/// Seconds of scenario clock without progress that make a stall.
+pub const DEFAULT_STALL_SECS: f64 = 45.0;
+
+/// The invariant that reports a non-finite velocity.
+const NAN_INVARIANT: &str = "velocity_finite";
+
+/// The scenario's per-frame update pulse: on the timeline, never progress.
+const UPDATE_PULSE: &str = "onupdate";
+
+/// How much of a panic message a failure quotes.
+const DETAIL_CHARS: usize = 160;
+
+/// What went wrong.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    Panic,
+    NanPhysics,
+    Invariant,
+    Stall,
+    Exit,
+}
+
+impl FailureKind {
+    /// The kind's name in `fuzz.json` and on the terminal.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Panic => "panic",
+            Self::NanPhysics => "nan_physics",
+            Self::Invariant => "invariant",
+            Self::Stall => "stall",
+            Self::Exit => "exit",
+        }
+    }
+}
+
+/// A fuzzed run's failure.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzFailure {
+    pub kind: FailureKind,
+    /// What within the kind: the panic's location, the invariant's name.
+    pub name: String,
+    /// One line of context for a human.
+    pub detail: String,
+    /// The scenario clock it was seen at, when there was one.
+    pub at: Option<f64>,
+}
+
+impl FuzzFailure {
+    /// Whether `other` is this failure again.
+    pub fn same_as(&self, other: &FuzzFailure) -> bool {
+        self.kind == other.kind && self.name == other.name
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "kind": self.kind.as_str(),
+            "name": self.name,
+            "detail": self.detail,
+            "at": self.at,
+        })
+    }
+}
+
+impl std::fmt::Display for FuzzFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.kind.as_str(), self.name)?;
+        if let Some(at) = self.at {
+            write!(f, " at {at:.1}s")?;
+        }
+        if !self.detail.is_empty() {
+            write!(f, ": {}", self.detail)?;
+        }
+        Ok(())
+    }
+}
+
+fn clip(line: &str) -> String {
+    line.trim().chars().take(DETAIL_CHARS).collect()
+}
+
+/// The first panic in `log`: its location, and the message line after it.
+fn find_panic(log: &str) -> Option<FuzzFailure> {
+    let mut lines = log.lines();
+    let line = lines.by_ref().find(|line| line.contains("panicked at"))?;
+    let location = line
+        .split_once("panicked at")
+        .map_or("", |(_, rest)| rest)
+        .trim()
+        .trim_end_matches(':');
+    Some(FuzzFailure {
+        kind: FailureKind::Panic,
+        name: location.to_string(),
+        detail: lines
+            .find(|line| !line.trim().is_empty())
+            .map(clip)
+            .unwrap_or_default(),
+        at: None,
+    })
+}
+
+/// Whether an entry moved the scenario along.
+fn is_progress(entry: &TimelineEvent) -> bool {
+    match entry.kind.as_str() {
+        "scenario_event" => entry.name != UPDATE_PULSE,
+        "variable" | "objective" => true,
+        _ => false,
+    }
+}
+
+/// The first stretch of `stall_secs` scenario clock with no progress, as
+/// (from, to). A clock that runs backwards is a reload and starts afresh.
+fn find_stall(timeline: &[TimelineEvent], stall_secs: f64) -> Option<(f64, f64)> {
+    let mut since: Option<f64> = None;
+    let mut last: Option<f64> = None;
+    for entry in timeline {
+        let Some(clock) = entry.scenario_elapsed else {
+            continue;
+        };
+        if last.is_some_and(|last| clock < last) {
+            since = None;
+        }
+        last = Some(clock);
+        let from = *since.get_or_insert(clock);
+        if clock - from >= stall_secs {
+            return Some((from, clock));
+        }
+        if is_progress(entry) {
+            since = Some(clock);
+        }
+    }
+    None
+}
+
+/// Read a fuzzed run's failure off its evidence; `None` when it ran clean.
+/// `exit` is how the process ended when that was not a clean exit (`None`
+/// for exit 0).
+pub fn find_failure(
+    log: Option<&str>,
+    timeline: &[TimelineEvent],
+    exit: Option<&str>,
+    stall_secs: f64,
+) -> Option<FuzzFailure> {
+    if let Some(panic) = log.and_then(find_panic) {
+        return Some(panic);
+    }
+    if let Some(entry) = timeline.iter().find(|e| e.kind == "invariant") {
+        return Some(FuzzFailure {
+            kind: if entry.name == NAN_INVARIANT {
+                FailureKind::NanPhysics
+            } else {
+                FailureKind::Invariant
+            },
+            name: entry.name.clone(),
+            detail: clip(&entry.data.to_string()),
+            at: entry.scenario_elapsed,
+        });
+    }
+    if let Some((from, to)) = find_stall(timeline, stall_secs) {
+        return Some(FuzzFailure {
+            kind: FailureKind::Stall,
+            name: "scenario".into(),
+            detail: format!("no scenario progress from {from:.1}s to {to:.1}s"),
+            at: Some(from),
+        });
+    }
+    exit.map(|exit| FuzzFailure {
+        kind: FailureKind::Exit,
+        name: exit.into(),
+        detail: String::new(),
+        at: None,
+    })
+}
+
+/// Delta debugging (ddmin, complements only): the smallest subset of `units`
+/// found for which `fails` still holds, removing ever-smaller chunks and
+/// keeping every cut that still fails. `units` itself is taken to fail.
+///
+/// `fails` runs at most `budget` times - each call is a whole game run - and
+/// the smallest failing subset so far is what a spent budget returns. Also
+/// returns how many calls were made.
+pub fn shrink<T: Clone>(
+    units: Vec<T>,
+    budget: usize,
+    mut fails: impl FnMut(&[T]) -> bool,
+) -> (Vec<T>, usize) {
+    let mut current = units;
+    let mut granularity = 2;
+    let mut tries = 0;
+    while !current.is_empty() && tries < budget {
+        let chunk = current.len().div_ceil(granularity);
+        let mut cut = false;
+        let mut start = 0;
+        while start < current.len() && tries < budget {
+            let end = (start + chunk).min(current.len());
+            let candidate: Vec<T> = current[..start]
+                .iter()
+                .chain(&current[end..])
+                .cloned()
+                .collect();
+            tries += 1;
+            if fails(&candidate) {
+                current = candidate;
+                granularity = (granularity - 1).max(2);
+                cut = true;
+                break;
+            }
+            start = end;
+        }
+        if !cut {
+            // Every unit on its own is needed: nothing smaller to try.
+            if chunk == 1 {
+                break;
+            }
+            granularity = (granularity * 2).min(current.len());
+        }
+    }
+    (current, tries)
+}
+
+/// Bisect the shortest length in `lo..=hi` at which `fails` still holds,
+/// `hi` being taken to fail, in at most `budget` calls. Assumes a longer run
+/// fails whenever a shorter one does - true of a failure that, once it has
+/// happened, has happened. Also returns how many calls were made.
+pub fn shorten(
+    mut lo: u64,
+    mut hi: u64,
+    budget: usize,
+    mut fails: impl FnMut(u64) -> bool,
+) -> (u64, usize) {
+    let mut tries = 0;
+    while lo < hi && tries < budget {
+        let mid = lo + (hi - lo) / 2;
+        tries += 1;
+        if fails(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    (hi, tries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(clock: f64, kind: &str, name: &str) -> TimelineEvent {
+        TimelineEvent {
+            t_real: clock,
+            frame: 0,
+            scenario_elapsed: Some(clock),
+            kind: kind.into(),
+            name: name.into(),
+            data: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn a_failure_is_the_first_kind_that_holds() {
+        let log = "INFO fine\nthread 'main' panicked at src/flight.rs:12:5:\n\nboom\n";
+        let timeline = [
+            entry(1.0, "invariant", "health_bounds"),
+            entry(2.0, "invariant", NAN_INVARIANT),
+        ];
+        let panic = find_failure(Some(log), &timeline, Some("exit 101"), 45.0).unwrap();
+        assert_eq!(panic.kind, FailureKind::Panic);
+        assert_eq!(panic.name, "src/flight.rs:12:5");
+        assert_eq!(panic.detail, "boom");
+
+        // The first violation names it, and a NaN velocity is its own kind.
+        let invariant = find_failure(Some("INFO fine"), &timeline, None, 45.0).unwrap();
+        assert_eq!(
+            (invariant.kind, invariant.name.as_str()),
+            (FailureKind::Invariant, "health_bounds")
+        );
+        let nan = find_failure(None, &timeline[1..], None, 45.0).unwrap();
+        assert_eq!(nan.kind, FailureKind::NanPhysics);
+        assert!(!nan.same_as(&invariant));
+
+        let exit = find_failure(None, &[], Some("timed out"), 45.0).unwrap();
+        assert_eq!(exit.kind, FailureKind::Exit);
+        assert_eq!(find_failure(Some("INFO fine"), &[], None, 45.0), None);
+    }
+
+    #[test]
+    fn only_a_quiet_scenario_clock_stalls() {
+        // The update pulse ticks the clock along but is not progress.
+        let quiet: Vec<_> = (0..=60)
+            .map(|t| entry(f64::from(t), "scenario_event", UPDATE_PULSE))
+            .collect();
+        let stall = find_failure(None, &quiet, None, 45.0).unwrap();
+        assert_eq!(stall.kind, FailureKind::Stall);
+        assert_eq!(stall.at, Some(0.0));
+        assert_eq!(find_failure(None, &quiet, None, 90.0), None);
+
+        // A variable change every 30s keeps it moving.
+        let mut moving = quiet.clone();
+        moving.insert(31, entry(30.0, "variable", "beat"));
+        assert_eq!(find_failure(None, &moving, None, 45.0), None);
+
+        // A reload rewinds the clock, and the silence starts again.
+        let reloaded: Vec<_> = (0..30)
+            .chain(0..30)
+            .map(|t| entry(f64::from(t), "scenario_event", UPDATE_PULSE))
+            .collect();
+        assert_eq!(find_failure(None, &reloaded, None, 45.0), None);
+    }
+
+    #[test]
+    fn shrinking_keeps_only_the_units_the_failure_needs() {
+        let units: Vec<u32> = (0..16).collect();
+        let needs = |kept: &[u32]| kept.contains(&3) && kept.contains(&11);
+        let (minimal, tries) = shrink(units.clone(), 200, needs);
+        assert_eq!(minimal, [3, 11]);
+        assert!(tries < 200);
+
+        // A failure that needs no input shrinks to none.
+        assert_eq!(shrink(units.clone(), 200, |_| true).0, Vec::<u32>::new());
+
+        // A spent budget returns the smallest failing cut found so far.
+        let (partial, tries) = shrink(units, 1, needs);
+        assert_eq!(tries, 1);
+        assert!(needs(&partial));
+
+        assert_eq!(shorten(0, 1_000, 64, |end| end >= 437), (437, 10));
+        assert_eq!(shorten(0, 1_000, 3, |end| end >= 437).1, 3);
+    }
+}
@@ -0,0 +1,467 @@
+//! Golden frames: the stills a screenshot producer captured this run, held
+//! against the ones committed for it - by perceptual distance, never by bytes.
+//!
+//! Two software renders of the same commit are not byte-identical (dithering,
+//! a particle seeded off the clock, a counter in a corner), so a byte compare
+//! is a test that always fails and gets deleted. The distance here is windowed
+//! SSIM over luma: [`SSIM_WINDOW`]-pixel tiles scored for mean, contrast and
+//! structure, so a shifted hull or a lost material reads as far away and a
+//! dither pattern does not. Regions that are noisy BY DESIGN are masked out in
+//! the example's `golden.json` rather than loosened into the threshold.
+//!
+//! The run dir holds both halves, staged by `probe run` so a report re-renders
+//! from the dir alone:
+//!
+//! - `golden/` - the committed frames for the backend that drew this run,
+//!   plus the example's `golden.json` when it has one;
+//! - `shots/` - what the producer's capture path wrote this run.
+//!
+//! A golden with no matching shot is a failure, not a skip: the producer used
+//! to make that frame and no longer does. A shot with no golden is reported as
+//! unblessed and grades nothing.
+
+/// Glob-import surface for the golden-frame comparison.
+pub mod prelude {
+    pub use super::{
+        compare_frames, FrameDiff, GoldenConfig, GoldenDiff, GoldenMask, GoldenOutcome, GoldenRun,
+        Heatmap, GOLDEN_CONFIG, GOLDEN_DIR, SHOTS_DIR,
+    };
+}
+
+use std::path::Path;
+
+use image::GrayImage;
+use serde::Deserialize;
+
+use super::artifacts::ArtifactFailure;
+
+/// The run-dir subdirectory the committed goldens are staged into.
+pub const GOLDEN_DIR: &str = "golden";
+
+/// The run-dir subdirectory the producer's capture path writes into.
+pub const SHOTS_DIR: &str = "shots";
+
+/// The per-example thresholds-and-masks file, beside the goldens.
+pub const GOLDEN_CONFIG: &str = "golden.json";
+
+/// SSIM tile edge, in pixels. Eight is the classic window: small enough that
+/// a moved edge lands in its own tile, large enough that one noisy pixel
+/// does not.
+pub const SSIM_WINDOW: u32 = 8;
+
+/// Heatmap grid, in cells. 16:9 like every capture, coarse enough to render as
+/// inline SVG and fine enough to point at a section.
+pub const HEATMAP_COLS: usize = 48;
+/// See [`HEATMAP_COLS`].
+pub const HEATMAP_ROWS: usize = 27;
+
+/// The SSIM stabilizers for 8-bit luma (`(0.01 * 255)^2`, `(0.03 * 255)^2`),
+/// which keep flat, dark tiles - most of space - from dividing by nearly zero.
+const C1: f64 = 6.5025;
+const C2: f64 = 58.5225;
+
+/// One region to leave out of a comparison, as a SHARE of the frame so the
+/// mask survives a capture-resolution change.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GoldenMask {
+    /// The shot it applies to (`feature-hud.png`), or `*` for every shot.
+    pub shot: String,
+    /// `[x, y, width, height]`, each a share of the frame from the top left.
+    pub rect: [f32; 4],
+    /// Why the region is noisy. Required: a mask nobody can explain is a
+    /// regression somebody hid.
+    pub why: String,
+}
+
+impl GoldenMask {
+    fn covers(&self, shot: &str, (x, y): (f32, f32)) -> bool {
+        let [left, top, width, height] = self.rect;
+        (self.shot == "*" || self.shot == shot)
+            && (left..left + width).contains(&x)
+            && (top..top + height).contains(&y)
+    }
+}
+
+/// An example's `golden.json`: its thresholds and its noisy regions. Absent,
+/// the defaults hold and nothing is masked.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct GoldenConfig {
+    /// Mean SSIM below this WARNs.
+    pub warn_below: f64,
+    /// Mean SSIM below this FAILs.
+    pub fail_below: f64,
+    /// Regions left out of the comparison.
+    pub masks: Vec<GoldenMask>,
+}
+
+impl Default for GoldenConfig {
+    fn default() -> Self {
+        Self {
+            warn_below: 0.98,
+            fail_below: 0.95,
+            masks: Vec::new(),
+        }
+    }
+}
+
+impl GoldenConfig {
+    /// Parse `golden.json`. Loud on unknown keys: a misspelt `fail_bellow`
+    /// read as the default is a threshold nobody set.
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        let config: Self =
+            serde_json::from_str(contents).map_err(|e| format!("{GOLDEN_CONFIG}: {e}"))?;
+        if !(0.0..=1.0).contains(&config.fail_below) || config.warn_below < config.fail_below {
+            return Err(format!(
+                "{GOLDEN_CONFIG}: thresholds must satisfy 0 <= fail_below <= warn_below"
+            ));
+        }
+        Ok(config)
+    }
+
+    fn masks_for<'a>(&'a self, shot: &'a str) -> impl Iterator<Item = &'a GoldenMask> + 'a {
+        self.masks
+            .iter()
+            .filter(move |mask| mask.shot == "*" || mask.shot == shot)
+    }
+}
+
+/// Where the frame differs, cell by cell: `1 - SSIM` averaged over the tiles
+/// whose centre falls in the cell, clamped to `0..=1`. `None` is a cell that
+/// was masked out entirely.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap {
+    /// Cells across.
+    pub cols: usize,
+    /// Cells down.
+    pub rows: usize,
+    /// Row-major dissimilarity per cell.
+    pub cells: Vec<Option<f64>>,
+}
+
+impl Heatmap {
+    /// The hottest cell as `(col, row, dissimilarity)`.
+    pub fn worst(&self) -> Option<(usize, usize, f64)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cell)| cell.map(|value| (i % self.cols, i / self.cols, value)))
+            .max_by(|a, b| a.2.total_cmp(&b.2))
+    }
+}
+
+/// One shot's perceptual distance from its golden.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameDiff {
+    /// Mean SSIM over the unmasked tiles; 1 is identical.
+    pub ssim: f64,
+    /// How many tiles were compared. Zero means the masks covered the frame
+    /// and the score says nothing.
+    pub windows: u32,
+    /// Share of the tiles the masks left out.
+    pub masked_share: f64,
+    /// Where the distance is.
+    pub heatmap: Heatmap,
+}
+
+/// How one golden fared this run.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoldenOutcome {
+    /// The producer wrote no shot of this name.
+    NotCaptured,
+    /// The golden or the shot would not decode; the reason is also an
+    /// artifact failure.
+    Unreadable(String),
+    /// Both decoded at different sizes - a capture-resolution change, which
+    /// needs a re-bless rather than a score.
+    SizeMismatch {
+        /// The golden's `(width, height)`.
+        golden: (u32, u32),
+        /// The shot's `(width, height)`.
+        captured: (u32, u32),
+    },
+    /// Compared.
+    Compared(FrameDiff),
+}
+
+/// One committed golden and its outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenDiff {
+    /// The frame's file name, the same in `golden/` and `shots/`.
+    pub shot: String,
+    /// What happened to it.
+    pub outcome: GoldenOutcome,
+}
+
+/// Everything the staged goldens yielded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoldenRun {
+    /// The thresholds and masks the diffs were graded with.
+    pub config: GoldenConfig,
+    /// One per committed golden, in file-name order.
+    pub diffs: Vec<GoldenDiff>,
+    /// Shots this run captured that have no golden yet.
+    pub unblessed: Vec<String>,
+}
+
+/// Windowed SSIM of `captured` against `golden` (same size), leaving out the
+/// tiles whose centre falls in one of `masks` for `shot`.
+pub fn compare_frames(
+    shot: &str,
+    golden: &GrayImage,
+    captured: &GrayImage,
+    config: &GoldenConfig,
+) -> FrameDiff {
+    let (width, height) = golden.dimensions();
+    let masks: Vec<&GoldenMask> = config.masks_for(shot).collect();
+    let mut cells = vec![(0.0_f64, 0_u32); HEATMAP_COLS * HEATMAP_ROWS];
+    let (mut total, mut windows, mut masked) = (0.0_f64, 0_u32, 0_u32);
+    for wy in 0..height / SSIM_WINDOW {
+        for wx in 0..width / SSIM_WINDOW {
+            let (x0, y0) = (wx * SSIM_WINDOW, wy * SSIM_WINDOW);
+            let centre = (
+                (x0 + SSIM_WINDOW / 2) as f32 / width as f32,
+                (y0 + SSIM_WINDOW / 2) as f32 / height as f32,
+            );
+            if masks.iter().any(|mask| mask.covers(shot, centre)) {
+                masked += 1;
+                continue;
+            }
+            let score = window_ssim(golden, captured, x0, y0);
+            total += score;
+            windows += 1;
+            let col = ((centre.0 * HEATMAP_COLS as f32) as usize).min(HEATMAP_COLS - 1);
+            let row = ((centre.1 * HEATMAP_ROWS as f32) as usize).min(HEATMAP_ROWS - 1);
+            let cell = &mut cells[row * HEATMAP_COLS + col];
+            cell.0 += (1.0 - score).clamp(0.0, 1.0);
+            cell.1 += 1;
+        }
+    }
+    let tiles = windows + masked;
+    FrameDiff {
+        ssim: if windows == 0 {
+            1.0
+        } else {
+            total / f64::from(windows)
+        },
+        windows,
+        masked_share: if tiles == 0 {
+            0.0
+        } else {
+            f64::from(masked) / f64::from(tiles)
+        },
+        heatmap: Heatmap {
+            cols: HEATMAP_COLS,
+            rows: HEATMAP_ROWS,
+            cells: cells
+                .into_iter()
+                .map(|(sum, count)| (count > 0).then(|| sum / f64::from(count)))
+                .collect(),
+        },
+    }
+}
+
+/// SSIM of one tile, from the two luma planes' means, variances and
+/// covariance.
+fn window_ssim(golden: &GrayImage, captured: &GrayImage, x0: u32, y0: u32) -> f64 {
+    let n = f64::from(SSIM_WINDOW * SSIM_WINDOW);
+    let (mut sa, mut sb, mut saa, mut sbb, mut sab) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for y in y0..y0 + SSIM_WINDOW {
+        for x in x0..x0 + SSIM_WINDOW {
+            let a = f64::from(golden.get_pixel(x, y).0[0]);
+            let b = f64::from(captured.get_pixel(x, y).0[0]);
+            sa += a;
+            sb += b;
+            saa += a * a;
+            sbb += b * b;
+            sab += a * b;
+        }
+    }
+    let (ma, mb) = (sa / n, sb / n);
+    let (va, vb) = (saa / n - ma * ma, sbb / n - mb * mb);
+    let cov = sab / n - ma * mb;
+    ((2.0 * ma * mb + C1) * (2.0 * cov + C2)) / ((ma * ma + mb * mb + C1) * (va + vb + C2))
+}
+
+/// The PNG file names directly under `dir`, sorted. A missing dir is empty.
+fn png_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .filter(|name| name.ends_with(".png"))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// Decode `dir/name` to luma, or record why not.
+fn decode(
+    dir: &Path,
+    subdir: &str,
+    name: &str,
+    failures: &mut Vec<ArtifactFailure>,
+) -> Result<GrayImage, String> {
+    image::open(dir.join(subdir).join(name))
+        .map(|image| image.to_luma8())
+        .map_err(|error| {
+            let reason = format!("could not decode: {error}");
+            failures.push(ArtifactFailure {
+                name: format!("{subdir}/{name}"),
+                reason: reason.clone(),
+            });
+            reason
+        })
+}
+
+/// Compare whatever goldens are staged in run dir `dir` against its shots.
+/// `None` when nothing is staged - the run was held to no goldens. Decode and
+/// parse failures come back beside it, for `artifacts_loadable`.
+pub(crate) fn load_golden_run(dir: &Path) -> (Option<GoldenRun>, Vec<ArtifactFailure>) {
+    let golden_dir = dir.join(GOLDEN_DIR);
+    if !golden_dir.is_dir() {
+        return (None, Vec::new());
+    }
+    let mut failures = Vec::new();
+    // A malformed config still grades, with the defaults and no masks: the
+    // failure row says why the masks are missing, and a silent skip would
+    // hide every frame behind one typo.
+    let config = match std::fs::read_to_string(golden_dir.join(GOLDEN_CONFIG)) {
+        Err(_) => GoldenConfig::default(),
+        Ok(contents) => GoldenConfig::from_json(&contents).unwrap_or_else(|reason| {
+            failures.push(ArtifactFailure {
+                name: format!("{GOLDEN_DIR}/{GOLDEN_CONFIG}"),
+                reason,
+            });
+            GoldenConfig::default()
+        }),
+    };
+    let goldens = png_names(&golden_dir);
+    let shots = png_names(&dir.join(SHOTS_DIR));
+    let diffs = goldens
+        .iter()
+        .map(|shot| {
+            let outcome = if !shots.contains(shot) {
+                GoldenOutcome::NotCaptured
+            } else {
+                match (
+                    decode(dir, GOLDEN_DIR, shot, &mut failures),
+                    decode(dir, SHOTS_DIR, shot, &mut failures),
+                ) {
+                    (Err(reason), _) | (_, Err(reason)) => GoldenOutcome::Unreadable(reason),
+                    (Ok(golden), Ok(captured)) if golden.dimensions() != captured.dimensions() => {
+                        GoldenOutcome::SizeMismatch {
+                            golden: golden.dimensions(),
+                            captured: captured.dimensions(),
+                        }
+                    }
+                    (Ok(golden), Ok(captured)) => {
+                        GoldenOutcome::Compared(compare_frames(shot, &golden, &captured, &config))
+                    }
+                }
+            };
+            GoldenDiff {
+                shot: shot.clone(),
+                outcome,
+            }
+        })
+        .collect();
+    let unblessed = shots
+        .into_iter()
+        .filter(|shot| !goldens.contains(shot))
+        .collect();
+    (
+        Some(GoldenRun {
+            config,
+            diffs,
+            unblessed,
+        }),
+        failures,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 64x36 frame: a dark field with a lit block, the shape of a hull on
+    /// space.
+    fn frame(block_x: u32) -> GrayImage {
+        GrayImage::from_fn(64, 36, |x, y| {
+            let lit = (block_x..block_x + 16).contains(&x) && (10..26).contains(&y);
+            image::Luma([if lit {
+                200
+            } else {
+                12 + ((x * 7 + y * 3) % 5) as u8
+            }])
+        })
+    }
+
+    #[test]
+    fn an_identical_frame_scores_one_and_a_moved_hull_does_not() {
+        let golden = frame(16);
+        let same = compare_frames("a.png", &golden, &golden, &GoldenConfig::default());
+        assert!((same.ssim - 1.0).abs() < 1e-9, "{}", same.ssim);
+        assert_eq!(same.windows, 8 * 4);
+
+        let moved = compare_frames("a.png", &golden, &frame(40), &GoldenConfig::default());
+        assert!(moved.ssim < 0.9, "{}", moved.ssim);
+        let (col, _, hot) = moved.heatmap.worst().unwrap();
+        assert!(hot > 0.5, "{hot}");
+        // The heat sits where the block was or went, not in the empty left.
+        assert!(col >= HEATMAP_COLS / 4, "{col}");
+    }
+
+    #[test]
+    fn a_mask_takes_its_region_out_of_the_score_and_the_heatmap() {
+        let golden = frame(16);
+        let moved = frame(40);
+        let config = GoldenConfig {
+            masks: vec![GoldenMask {
+                shot: "*".into(),
+                rect: [0.0, 0.0, 1.0, 1.0],
+                why: "everything".into(),
+            }],
+            ..Default::default()
+        };
+        let diff = compare_frames("a.png", &golden, &moved, &config);
+        assert_eq!(diff.windows, 0, "nothing left to compare");
+        assert_eq!(diff.masked_share, 1.0);
+        assert!(diff.heatmap.cells.iter().all(Option::is_none));
+
+        // A mask for another shot leaves this one alone.
+        let config = GoldenConfig {
+            masks: vec![GoldenMask {
+                shot: "b.png".into(),
+                ..config.masks[0].clone()
+            }],
+            ..Default::default()
+        };
+        assert!(compare_frames("a.png", &golden, &moved, &config).windows > 0);
+    }
+
+    #[test]
+    fn the_config_is_loud_about_keys_and_thresholds() {
+        assert_eq!(
+            GoldenConfig::from_json("{}").unwrap(),
+            GoldenConfig::default()
+        );
+        let config = GoldenConfig::from_json(
+            r#"{"fail_below": 0.9, "masks": [{"shot": "*", "rect": [0, 0, 0.2, 0.05], "why": "fps"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(config.fail_below, 0.9);
+        assert_eq!(config.masks.len(), 1);
+
+        assert!(GoldenConfig::from_json(r#"{"fail_bellow": 0.9}"#).is_err());
+        assert!(GoldenConfig::from_json(r#"{"warn_below": 0.9, "fail_below": 0.95}"#).is_err());
+        assert!(
+            GoldenConfig::from_json(r#"{"masks": [{"shot": "*", "rect": [0, 0, 1, 1]}]}"#).is_err(),
+            "a mask must say why"
+        );
+    }
+}
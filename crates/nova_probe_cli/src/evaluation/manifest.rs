@@ -34,6 +34,12 @@ pub struct RunManifest {
     pub armed_invariants: bool,
     /// Whether the frame-time capture surface was armed.
     pub armed_fps: bool,
+    /// Whether the memory capture surface was armed.
+    pub armed_memory: bool,
+    /// Whether the example is a stills producer held to committed goldens
+    /// on this run's backend, so a run that staged none FAILS
+    /// `frames_match_golden` instead of skipping it.
+    pub goldens_required: bool,
     /// Per-pass outcomes, in execution order.
     pub passes: Vec<PassRecord>,
 }
@@ -68,7 +74,9 @@ impl RunManifest {
                 "timeline": self.armed_timeline,
                 "invariants": self.armed_invariants,
                 "fps": self.armed_fps,
+                "memory": self.armed_memory,
             },
+            "goldens_required": self.goldens_required,
             "passes": self.passes.iter().map(|p| serde_json::json!({
                 "name": p.name, "success": p.success, "timed_out": p.timed_out,
             })).collect::<Vec<_>>(),
@@ -120,6 +128,11 @@ impl RunManifest {
             armed_timeline: armed("timeline"),
             armed_invariants: armed("invariants"),
             armed_fps: armed("fps"),
+            armed_memory: armed("memory"),
+            goldens_required: v
+                .get("goldens_required")
+                .and_then(|x| x.as_bool())
+                .unwrap_or(false),
             passes,
         })
     }
@@ -140,6 +153,8 @@ mod tests {
             armed_timeline: true,
             armed_invariants: true,
             armed_fps: true,
+            armed_memory: true,
+            goldens_required: true,
             passes: vec![
                 PassRecord {
                     name: "clean".into(),
@@ -179,5 +194,13 @@ mod tests {
         let manifest = RunManifest::from_json(&json.to_string()).expect("legacy manifest loads");
         assert_eq!(manifest.full_git_sha, "abc123");
         assert!(!manifest.armed_fps);
+        assert!(
+            !manifest.armed_memory,
+            "a manifest older than memory capture"
+        );
+        assert!(
+            !manifest.goldens_required,
+            "a manifest older than required goldens holds nothing to them"
+        );
     }
 }
@@ -8,7 +8,14 @@
 //!   events, variables, markers, invariant entries, run bracket);
 //! - `frametime.csv` - the clean pass's FPS stats (schema v1 to v4);
 //! - `trace.json` - the profiled pass's chrome trace;
-//! - `run.log` - the run's captured stdout/stderr.
+//! - `run.log` - the run's captured stdout/stderr;
+//! - `golden/` + `shots/` - the committed frames a screenshot producer is held
+//!   to, and the ones it captured this run ([`golden`]).
+//!
+//! Against a baseline run, [`timeline_diff`] aligns the two timelines for
+//! behavioural triage; like the frame read, it grades nothing. [`fuzz`]
+//! reads a fuzzed run's failure off the same evidence and shrinks the input
+//! that caused it.
 //!
 //! Missing artifacts make their checks SKIPPED and their report sections
 //! say why - the report never silently omits a dimension. The auto checks
@@ -31,15 +38,18 @@ pub mod artifacts;
 pub mod catalog;
 pub mod checks;
 pub mod frames;
+pub mod fuzz;
+pub mod golden;
 pub mod manifest;
 pub mod profile;
+pub mod timeline_diff;
 
 #[cfg(test)]
 pub(crate) mod fixtures;
 
 /// Glob-import surface for the whole evaluation half: the catalog a run was
-/// resolved from, its manifest and artifacts, the trace aggregation and the
-/// checks that grade them.
+/// resolved from, its manifest and artifacts, the trace aggregation, the checks
+/// that grade them, the timeline diff against a baseline and the fuzz verdicts.
 ///
 /// `overall_verdict` is deliberately NOT re-exported at the crate root beside
 /// [`report::aggregate`](crate::report::aggregate)'s function of the same name
@@ -47,7 +57,8 @@ pub(crate) mod fixtures;
 pub mod prelude {
     pub use super::{
         artifacts::prelude::*, catalog::prelude::*, checks::prelude::*, frames::prelude::*,
-        manifest::prelude::*, profile::prelude::*,
+        fuzz::prelude::*, golden::prelude::*, manifest::prelude::*, profile::prelude::*,
+        timeline_diff::prelude::*,
     };
 }
 
@@ -0,0 +1,458 @@
+//! Two runs' timelines, aligned: what the current run did that the baseline
+//! did not, what it stopped doing, what it did in a different order, and what
+//! it did noticeably later or earlier.
+//!
+//! Only the STORY is aligned - state transitions, fired scenario events,
+//! completed objectives and the autopilot's markers. Variables churn too much
+//! to read as a sequence (a counter ticks every few frames), and the per-frame
+//! `onupdate` pulse is noise by construction. The recorder's own rule holds:
+//! ORDER and VALUES are compared, and a time is only compared on the scenario
+//! clock (falling back to seconds since `run_start` when neither run had a
+//! scenario live), and only past a tolerance - wall-clock differs wildly across
+//! hosts and a diff that flags every entry flags nothing.
+//!
+//! A diff grades nothing. It is triage for a behavioural regression a human
+//! already suspects, rendered beside the frame-time comparison when a report
+//! has a baseline and on its own by `probe diff`.
+
+/// Glob-import surface for the timeline diff.
+pub mod prelude {
+    pub use super::{
+        diff_timelines, Divergence, DivergenceKind, TimelineDiff, DEFAULT_SHIFT_TOLERANCE_SECS,
+    };
+}
+
+use std::collections::{HashMap, VecDeque};
+
+use nova_probe::capabilities::timeline::TimelineEvent;
+
+/// How far a matched entry's time may move before it is reported as shifted.
+/// Two seconds of scenario clock is past frame-pacing jitter on a loaded host
+/// and well short of a beat the script waits on.
+pub const DEFAULT_SHIFT_TOLERANCE_SECS: f64 = 2.0;
+
+/// The alignment table is `baseline x current` cells once the shared prefix
+/// and suffix are trimmed. Past this, the runs are too far apart to align
+/// meaningfully and the diff says so instead of allocating gigabytes.
+const MAX_ALIGNMENT_CELLS: usize = 16 * 1024 * 1024;
+
+/// What one divergence says about the current run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DivergenceKind {
+    /// Only the current run has it.
+    Added,
+    /// Only the baseline has it.
+    Missing,
+    /// Both runs have it, at different places in the story - a reordered
+    /// objective, a beat that now lands after a different event.
+    Reordered,
+    /// Aligned, but its time moved past the tolerance.
+    Shifted,
+}
+
+impl DivergenceKind {
+    /// The stable lowercase name `diff.json` and the report use.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DivergenceKind::Added => "added",
+            DivergenceKind::Missing => "missing",
+            DivergenceKind::Reordered => "reordered",
+            DivergenceKind::Shifted => "shifted",
+        }
+    }
+}
+
+/// One way the current run's story differs from the baseline's.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence {
+    pub kind: DivergenceKind,
+    /// The timeline kind of the entry (`state`, `scenario_event`,
+    /// `objective`, `marker`).
+    pub entry_kind: String,
+    /// What the alignment keyed it on: `GameStates: Loading -> Playing`,
+    /// `ondestroyed prey`, an objective id, a marker label.
+    pub label: String,
+    /// Its time in the baseline, when the baseline has it.
+    pub baseline_at: Option<f64>,
+    /// Its time in the current run, when the current run has it.
+    pub current_at: Option<f64>,
+}
+
+/// Two timelines aligned, and everything that did not line up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineDiff {
+    /// Story entries in the baseline and the current run.
+    pub anchors: (usize, usize),
+    /// Entries aligned in place, shifted or not.
+    pub matched: usize,
+    pub tolerance_secs: f64,
+    /// In story order: walking the alignment from the start of both runs.
+    pub divergences: Vec<Divergence>,
+}
+
+impl TimelineDiff {
+    /// How many divergences are of `kind`.
+    pub fn count(&self, kind: DivergenceKind) -> usize {
+        self.divergences.iter().filter(|d| d.kind == kind).count()
+    }
+
+    /// The one-line read: `3 added, 1 missing, 0 reordered, 2 shifted`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} added, {} missing, {} reordered, {} shifted (of {} baseline / {} current \
+             story entries; tolerance {:.1} s)",
+            self.count(DivergenceKind::Added),
+            self.count(DivergenceKind::Missing),
+            self.count(DivergenceKind::Reordered),
+            self.count(DivergenceKind::Shifted),
+            self.anchors.0,
+            self.anchors.1,
+            self.tolerance_secs,
+        )
+    }
+
+    /// The machine-readable mirror `probe diff` writes as `diff.json`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "anchors": { "baseline": self.anchors.0, "current": self.anchors.1 },
+            "matched": self.matched,
+            "tolerance_secs": self.tolerance_secs,
+            "divergences": self.divergences.iter().map(|d| serde_json::json!({
+                "kind": d.kind.as_str(),
+                "entry_kind": d.entry_kind,
+                "label": d.label,
+                "baseline_at": d.baseline_at,
+                "current_at": d.current_at,
+            })).collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Which clock an anchor's time is on. Times on different clocks are never
+/// compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Clock {
+    Scenario,
+    Run,
+}
+
+/// One story entry, reduced to what the alignment compares.
+#[derive(Debug, Clone)]
+struct Anchor {
+    entry_kind: String,
+    key: String,
+    at: f64,
+    clock: Clock,
+}
+
+/// The story entries of `timeline`, in order.
+fn anchors(timeline: &[TimelineEvent]) -> Vec<Anchor> {
+    let start = timeline
+        .iter()
+        .find(|e| e.kind == "run_start")
+        .map_or(0.0, |e| e.t_real);
+    timeline
+        .iter()
+        .filter_map(|entry| {
+            let key = match entry.kind.as_str() {
+                "state" => format!(
+                    "{}: {} -> {}",
+                    entry.name,
+                    entry.data["exited"].as_str().unwrap_or("-"),
+                    entry.data["entered"].as_str().unwrap_or("-"),
+                ),
+                "scenario_event" if entry.name == "onupdate" => return None,
+                // The payload's content id, when it has one, is part of what
+                // happened: the escort dying is not the raider dying.
+                "scenario_event" => match entry.data["id"].as_str() {
+                    Some(id) => format!("{} {id}", entry.name),
+                    None => entry.name.clone(),
+                },
+                "objective" | "marker" => entry.name.clone(),
+                _ => return None,
+            };
+            let (at, clock) = match entry.scenario_elapsed {
+                Some(elapsed) => (elapsed, Clock::Scenario),
+                None => (entry.t_real - start, Clock::Run),
+            };
+            Some(Anchor {
+                entry_kind: entry.kind.clone(),
+                key,
+                at,
+                clock,
+            })
+        })
+        .collect()
+}
+
+/// One step of the alignment walk.
+enum Step {
+    Both(usize, usize),
+    OnlyBaseline(usize),
+    OnlyCurrent(usize),
+}
+
+/// The longest common subsequence of the two keyed sequences, as a walk from
+/// the start of both. The shared prefix and suffix are matched before the
+/// table is built, so two runs that agree except in the middle pay only for
+/// the middle.
+fn align(baseline: &[Anchor], current: &[Anchor]) -> Result<Vec<Step>, String> {
+    let same = |i: usize, j: usize| baseline[i].key == current[j].key;
+    let mut prefix = 0;
+    while prefix < baseline.len() && prefix < current.len() && same(prefix, prefix) {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < baseline.len() - prefix
+        && suffix < current.len() - prefix
+        && same(baseline.len() - 1 - suffix, current.len() - 1 - suffix)
+    {
+        suffix += 1;
+    }
+    let (n, m) = (
+        baseline.len() - prefix - suffix,
+        current.len() - prefix - suffix,
+    );
+    if n.saturating_mul(m) > MAX_ALIGNMENT_CELLS {
+        return Err(format!(
+            "the timelines diverge across {n} baseline and {m} current story entries - \
+             too far apart to align; diff a narrower pair of runs"
+        ));
+    }
+
+    // lengths[i][j]: the LCS of the middles' suffixes from i and j.
+    let width = m + 1;
+    let mut lengths = vec![0u32; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lengths[i * width + j] = if same(prefix + i, prefix + j) {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut steps: Vec<Step> = (0..prefix).map(|k| Step::Both(k, k)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && same(prefix + i, prefix + j) {
+            steps.push(Step::Both(prefix + i, prefix + j));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1]) {
+            steps.push(Step::OnlyBaseline(prefix + i));
+            i += 1;
+        } else {
+            steps.push(Step::OnlyCurrent(prefix + j));
+            j += 1;
+        }
+    }
+    steps.extend((0..suffix).map(|k| Step::Both(prefix + n + k, prefix + m + k)));
+    Ok(steps)
+}
+
+/// Align `current` against `baseline` and report every divergence. An entry
+/// left unaligned on BOTH sides under the same key is one entry that moved,
+/// not one lost and one gained, and is reported once as reordered.
+pub fn diff_timelines(
+    baseline: &[TimelineEvent],
+    current: &[TimelineEvent],
+    tolerance_secs: f64,
+) -> Result<TimelineDiff, String> {
+    let (base, cur) = (anchors(baseline), anchors(current));
+    let steps = align(&base, &cur)?;
+
+    // The current run's unaligned entries, per key, oldest first: what a
+    // baseline-only entry can pair with.
+    let mut unaligned_current: HashMap<&str, VecDeque<usize>> = HashMap::new();
+    for step in &steps {
+        if let Step::OnlyCurrent(j) = step {
+            unaligned_current
+                .entry(cur[*j].key.as_str())
+                .or_default()
+                .push_back(*j);
+        }
+    }
+    let mut paired = vec![false; cur.len()];
+
+    let mut matched = 0;
+    let mut divergences = Vec::new();
+    for step in &steps {
+        match *step {
+            Step::Both(i, j) => {
+                matched += 1;
+                let (a, b) = (&base[i], &cur[j]);
+                if a.clock == b.clock && (b.at - a.at).abs() > tolerance_secs {
+                    divergences.push(Divergence {
+                        kind: DivergenceKind::Shifted,
+                        entry_kind: b.entry_kind.clone(),
+                        label: b.key.clone(),
+                        baseline_at: Some(a.at),
+                        current_at: Some(b.at),
+                    });
+                }
+            }
+            Step::OnlyBaseline(i) => {
+                let a = &base[i];
+                let moved = unaligned_current
+                    .get_mut(a.key.as_str())
+                    .and_then(VecDeque::pop_front);
+                if let Some(j) = moved {
+                    paired[j] = true;
+                }
+                divergences.push(Divergence {
+                    kind: if moved.is_some() {
+                        DivergenceKind::Reordered
+                    } else {
+                        DivergenceKind::Missing
+                    },
+                    entry_kind: a.entry_kind.clone(),
+                    label: a.key.clone(),
+                    baseline_at: Some(a.at),
+                    current_at: moved.map(|j| cur[j].at),
+                });
+            }
+            Step::OnlyCurrent(j) if !paired[j] => {
+                let b = &cur[j];
+                divergences.push(Divergence {
+                    kind: DivergenceKind::Added,
+                    entry_kind: b.entry_kind.clone(),
+                    label: b.key.clone(),
+                    baseline_at: None,
+                    current_at: Some(b.at),
+                });
+            }
+            Step::OnlyCurrent(_) => {}
+        }
+    }
+    Ok(TimelineDiff {
+        anchors: (base.len(), cur.len()),
+        matched,
+        tolerance_secs,
+        divergences,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(kind: &str, name: &str, at: f64, data: serde_json::Value) -> TimelineEvent {
+        TimelineEvent {
+            t_real: at,
+            frame: 0,
+            scenario_elapsed: Some(at),
+            kind: kind.into(),
+            name: name.into(),
+            data,
+        }
+    }
+
+    fn story(entries: &[(&str, &str, f64)]) -> Vec<TimelineEvent> {
+        entries
+            .iter()
+            .map(|&(kind, name, at)| entry(kind, name, at, serde_json::Value::Null))
+            .collect()
+    }
+
+    fn kinds(diff: &TimelineDiff) -> Vec<(&'static str, &str)> {
+        diff.divergences
+            .iter()
+            .map(|d| (d.kind.as_str(), d.label.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn identical_stories_do_not_diverge_and_pulses_and_variables_are_ignored() {
+        let baseline = story(&[
+            ("marker", "beat: launch", 1.0),
+            ("scenario_event", "onupdate", 1.1),
+            ("variable", "leg", 1.2),
+            ("objective", "dock", 5.0),
+        ]);
+        let current = story(&[
+            ("marker", "beat: launch", 1.4),
+            ("variable", "leg", 1.3),
+            ("objective", "dock", 5.5),
+        ]);
+        let diff = diff_timelines(&baseline, &current, DEFAULT_SHIFT_TOLERANCE_SECS).unwrap();
+        assert_eq!(diff.anchors, (2, 2));
+        assert_eq!(diff.matched, 2);
+        assert!(diff.divergences.is_empty(), "{:?}", diff.divergences);
+    }
+
+    #[test]
+    fn new_missing_reordered_and_shifted_entries_are_each_reported_once() {
+        let baseline = story(&[
+            ("marker", "beat: launch", 1.0),
+            ("objective", "escort", 10.0),
+            ("objective", "dock", 20.0),
+            ("marker", "beat: jump", 30.0),
+            ("marker", "beat: land", 40.0),
+        ]);
+        let current = story(&[
+            ("marker", "beat: launch", 1.0),
+            ("objective", "dock", 12.0),
+            ("objective", "escort", 18.0),
+            ("marker", "beat: refuel", 25.0),
+            ("marker", "beat: land", 47.0),
+        ]);
+        let diff = diff_timelines(&baseline, &current, DEFAULT_SHIFT_TOLERANCE_SECS).unwrap();
+        assert_eq!(
+            kinds(&diff),
+            [
+                ("reordered", "escort"),
+                ("shifted", "dock"),
+                ("missing", "beat: jump"),
+                ("added", "beat: refuel"),
+                ("shifted", "beat: land"),
+            ],
+            "{:?}",
+            diff.divergences
+        );
+        let escort = &diff.divergences[0];
+        assert_eq!(
+            (escort.baseline_at, escort.current_at),
+            (Some(10.0), Some(18.0))
+        );
+        assert!(diff
+            .summary()
+            .starts_with("1 added, 1 missing, 1 reordered, 2 shifted"));
+        assert_eq!(diff.to_json()["divergences"][4]["kind"], "shifted");
+    }
+
+    #[test]
+    fn a_scenario_event_keys_on_its_content_id_and_clocks_are_never_mixed() {
+        let baseline = vec![
+            entry(
+                "scenario_event",
+                "ondestroyed",
+                4.0,
+                serde_json::json!({ "id": "raider" }),
+            ),
+            TimelineEvent {
+                scenario_elapsed: None,
+                ..entry("marker", "beat: menu", 2.0, serde_json::Value::Null)
+            },
+        ];
+        let current = vec![
+            entry(
+                "scenario_event",
+                "ondestroyed",
+                4.0,
+                serde_json::json!({ "id": "escort" }),
+            ),
+            entry("marker", "beat: menu", 60.0, serde_json::Value::Null),
+        ];
+        let diff = diff_timelines(&baseline, &current, DEFAULT_SHIFT_TOLERANCE_SECS).unwrap();
+        assert_eq!(
+            kinds(&diff),
+            [
+                ("missing", "ondestroyed raider"),
+                ("added", "ondestroyed escort")
+            ],
+            "the marker moved 58 s but across clocks, so it is not shifted"
+        );
+    }
+}
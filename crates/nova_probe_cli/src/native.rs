@@ -1,14 +1,18 @@
 //! The native probe driver, one module per concern: the command line, spec
 //! resolution, paths, child-run environments, the profile sandbox those
-//! environments point at, child-run supervision, the single-example run, the
-//! web pass, the multi-example sweep, and `report`.
+//! environments point at, child-run supervision, the single-example run, its
+//! golden frames, the web pass, the multi-example sweep, `report`, `diff`, and
+//! `fuzz`.
 
 use std::process::ExitCode;
 
 mod cli;
+mod diff;
 mod env;
 #[cfg(test)]
 mod fixtures;
+mod fuzz;
+mod goldens;
 mod paths;
 pub mod profile_sandbox;
 mod report;
@@ -37,6 +41,8 @@ use cli::{parse, Cmd};
 /// cargo run --features debug probe scenario <id>         # a scenario from the merged registry
 /// cargo run --features debug probe scenario <file.ron>   # a loose content file, catalog or not
 /// cargo run --features debug probe report <run-dir>      # re-render (manifest-gated)
+/// cargo run --features debug probe diff <run-a> <run-b>   # align two runs' timelines
+/// cargo run --features debug probe fuzz <id> --runs 20    # random flights, shrunk failures
 /// ```
 ///
 /// `run` orchestrates natively: pass 1 CLEAN (timeline + invariants + log), a
@@ -80,6 +86,24 @@ pub fn main(args: &[String]) -> ExitCode {
                 ExitCode::FAILURE
             }
         },
+        Ok(Cmd::Diff {
+            baseline,
+            current,
+            tolerance,
+        }) => match diff::diff(&baseline, &current, tolerance) {
+            Ok(code) => code,
+            Err(message) => {
+                eprintln!("probe: {message}");
+                ExitCode::FAILURE
+            }
+        },
+        Ok(Cmd::Fuzz(options)) => match fuzz::fuzz(&options) {
+            Ok(code) => code,
+            Err(message) => {
+                eprintln!("probe: {message}");
+                ExitCode::FAILURE
+            }
+        },
         Ok(Cmd::Report { dirs, baseline }) => {
             match report::report_many(&dirs, baseline.as_deref()) {
                 Ok(code) => code,
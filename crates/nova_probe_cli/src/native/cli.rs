@@ -5,6 +5,9 @@
 use std::path::PathBuf;
 
 use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use nova_probe::DEFAULT_FUZZ_SECS;
+
+use crate::evaluation::{DEFAULT_SHIFT_TOLERANCE_SECS, DEFAULT_STALL_SECS};
 
 /// What `probe scenario` measures. Decided by SUFFIX so parsing stays pure: a
 /// positional ending in `.ron` is a file, anything else an id.
@@ -64,6 +67,17 @@ pub(crate) enum Render {
     Sw,
 }
 
+impl Render {
+    /// The backend's name on the command line, which is also the goldens
+    /// subdir its frames are held to: the two rasterize differently.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Render::Gpu => "gpu",
+            Render::Sw => "sw",
+        }
+    }
+}
+
 /// Where the run executes. Web runs the perf_web wasm build under
 /// headless Chromium and captures the frame line only (the recorder and
 /// invariants are native-only by design).
@@ -84,7 +98,8 @@ pub(crate) struct MeasureArgs {
     /// Record a samply flamegraph in an extra pass.
     #[arg(long)]
     pub samply: bool,
-    /// Run only the clean behavioral pass - no capture, no trace.
+    /// Run only the behavioral passes (clean, and shots where goldens are
+    /// committed) - no frame-time capture, no trace.
     #[arg(long)]
     pub correctness_only: bool,
     /// Storage base to compare frame times against; probe finds the nearest
@@ -119,6 +134,15 @@ pub(crate) struct MeasureArgs {
     #[arg(long, value_name = "N", default_value_t = 1,
           value_parser = clap::value_parser!(u32).range(1..))]
     pub repeat: u32,
+    /// Run the children with this saved mod profile active (its enabled set
+    /// and load order), read from YOUR config store and carried into the
+    /// run's profile sandbox.
+    #[arg(long, value_name = "NAME")]
+    pub mod_profile: Option<String>,
+    /// Record this run's shots as the example's golden frames for the chosen
+    /// backend, replacing the ones committed. Review the pictures first.
+    #[arg(long, conflicts_with = "norender")]
+    pub bless: bool,
 }
 
 /// Parsed `probe run` / `probe scenario` options, resolved out of the clap
@@ -154,6 +178,12 @@ pub(crate) struct RunOptions {
     /// tail moved, so a claim about the worst frame is made over repeats and
     /// the report gates them; see [`crate::evaluation::frames`].
     pub repeat: u32,
+    /// The saved mod profile every child boots with (`--mod-profile`); `None`
+    /// leaves the sandbox's empty profile, i.e. base only.
+    pub mod_profile: Option<String>,
+    /// Replace the committed golden frames with this run's shots
+    /// (`--bless`); see [`crate::evaluation::golden`].
+    pub bless: bool,
 }
 
 impl RunOptions {
@@ -174,10 +204,31 @@ impl RunOptions {
             presets: Vec::new(),
             platform: Platform::Native,
             repeat: measure.repeat,
+            mod_profile: measure.mod_profile,
+            bless: measure.bless,
         }
     }
 }
 
+/// Parsed `probe fuzz` options.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct FuzzOptions {
+    pub target: ScenarioTarget,
+    /// The first seed; `runs` seeds count up from it.
+    pub seed: u64,
+    pub runs: u32,
+    /// Each flight's budget, in seconds of fixed time.
+    pub secs: f64,
+    /// Seconds of scenario clock with no progress that fail a flight.
+    pub stall_secs: f64,
+    /// How many replays the shrinker may spend on one failure.
+    pub shrink_runs: usize,
+    /// Fly this trace instead of seeds, and shrink it if it fails.
+    pub replay: Option<PathBuf>,
+    pub out: Option<PathBuf>,
+    pub release: bool,
+}
+
 /// The dispatch shape the rest of the crate consumes.
 #[derive(Debug, PartialEq)]
 pub(crate) enum Cmd {
@@ -202,6 +253,15 @@ pub(crate) enum Cmd {
         dirs: Vec<PathBuf>,
         baseline: Option<PathBuf>,
     },
+    /// A `probe diff`: align `current`'s timeline against `baseline`'s.
+    Diff {
+        baseline: PathBuf,
+        current: PathBuf,
+        tolerance: f64,
+    },
+    /// A `probe fuzz`: fly a scenario on seeded random input and shrink what
+    /// fails.
+    Fuzz(FuzzOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -213,7 +273,9 @@ pub(crate) enum Cmd {
                   frame time, profiled) and writes a run report plus an aggregate index; \
                   `scenario` takes the same passes to a scenario through the game binary, with \
                   no example between the tool and the data; `report` re-renders a run directory \
-                  probe already produced.",
+                  probe already produced; `diff` aligns two runs' timelines to triage a \
+                  behavioural regression; `fuzz` flies a scenario on seeded random input and \
+                  shrinks what fails to a minimal replay.",
     subcommand_required = true,
     disable_version_flag = true
 )]
@@ -246,6 +308,12 @@ their mean and median and reads the worst frame only across the ones that \
 pass - a single worst frame is ~30% noise on this host and cannot prove \
 anything on its own.
 
+An example with golden frames committed under \
+crates/nova_probe_cli/goldens/<example>/<gpu|sw>/ also takes a SHOTS pass: its \
+capture path runs into <run-dir>/shots/ and each frame is held to its golden by \
+perceptual diff (frames_match_golden), --correctness-only included. --bless \
+records this run's shots as the goldens for the chosen backend instead.
+
 The matrix flags (--scenario/--preset, repeatable) and --platform web \
 (positional = scenario id) are single-example concerns.")]
     Run {
@@ -299,6 +367,82 @@ positional IS the scenario, and there is nothing to expand.")]
         baseline: Option<PathBuf>,
     },
 
+    /// Align two runs' timelines and report where the behaviour diverged.
+    #[command(long_about = "\
+Align two run dirs' timelines - state transitions, scenario events, completed \
+objectives and autopilot markers - and report where the second run diverged from \
+the first: entries it added, entries it lost, entries it reached in a different \
+order, and entries whose time moved by more than --tolerance.
+
+Times compare on the scenario clock where a scenario was live, else on seconds \
+since run start. Wall-clock varies across hosts; keep the tolerance above the \
+host's jitter.
+
+Writes diff.html and diff.json into <RUN-B>. Exits 0 when the runs tell the same \
+story and 1 when they diverge, like diff(1). `probe report --baseline` renders \
+the same read beside the frame-time comparison.")]
+    Diff {
+        /// The baseline run dir (usually the older commit).
+        #[arg(value_name = "RUN-A")]
+        baseline: PathBuf,
+        /// The run dir to triage against it.
+        #[arg(value_name = "RUN-B")]
+        current: PathBuf,
+        /// Seconds a matched entry may move before it is reported as shifted.
+        #[arg(long, value_name = "SECS", default_value_t = DEFAULT_SHIFT_TOLERANCE_SECS)]
+        tolerance: f64,
+    },
+
+    /// Fly a scenario on seeded random input; shrink any failure to a minimal
+    /// replayable trace.
+    #[command(long_about = "\
+Fly a scenario through the game binary, headless, with a seeded random pilot at \
+the controls: burns, turns, weapons, locks, autopilot verbs and NOVA OS commands. \
+Each of --runs seeds (counting up from --seed) flies for --secs of game time.
+
+A flight FAILS on a panic, a non-finite velocity, any other invariant \
+violation, --stall-secs of scenario clock with no progress (no scenario event, \
+variable change or completed objective), or a bad exit. A failing flight is \
+replayed from its input trace to confirm it reproduces, then shrunk: input is \
+cut away, and the run shortened, while the SAME failure still happens, within \
+--shrink-runs replays.
+
+Writes <out|probe-runs/<short-commit>/fuzz-<label>>/seed-<n>/ per seed \
+(run.log, timeline.jsonl, input-trace.json, and minimal-trace.json for a \
+failure) and fuzz.json above them. --replay flies one trace instead of seeds; \
+the same seed on the same commit rolls the same input. Exits 1 when any flight \
+failed.")]
+    Fuzz {
+        /// A scenario id, or a path to a loose `*.content.ron`.
+        #[arg(value_name = "ID|FILE.RON")]
+        target: String,
+        /// The first seed.
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        seed: u64,
+        /// How many seeds to fly.
+        #[arg(long, value_name = "N", default_value_t = 1,
+              value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Each flight's budget, in seconds of game time.
+        #[arg(long, value_name = "SECS", default_value_t = DEFAULT_FUZZ_SECS)]
+        secs: f64,
+        /// Scenario-clock seconds with no progress that count as a stall.
+        #[arg(long, value_name = "SECS", default_value_t = DEFAULT_STALL_SECS)]
+        stall_secs: f64,
+        /// Replays the shrinker may spend on one failure.
+        #[arg(long, value_name = "N", default_value_t = 32)]
+        shrink_runs: usize,
+        /// Fly this input trace instead of seeds.
+        #[arg(long, value_name = "TRACE.JSON", conflicts_with_all = ["seed", "runs"])]
+        replay: Option<PathBuf>,
+        /// Write the fuzz dir here instead of under `probe-runs/<commit>/`.
+        #[arg(long, value_name = "DIR")]
+        out: Option<PathBuf>,
+        /// Build the game in release.
+        #[arg(long)]
+        release: bool,
+    },
+
     /// Retired at the v0.8.0 cut.
     #[command(hide = true)]
     Trace {
@@ -392,6 +536,56 @@ pub(crate) fn parse(args: &[String]) -> Result<Cmd, String> {
             Ok(Cmd::Scenario { base })
         }
         Verb::Report { dirs, baseline } => Ok(Cmd::Report { dirs, baseline }),
+        Verb::Diff {
+            baseline,
+            current,
+            tolerance,
+        } => {
+            // NaN would compare false against every shift and report none.
+            if !(tolerance.is_finite() && tolerance >= 0.0) {
+                return Err(format!(
+                    "--tolerance {tolerance}: a shift tolerance is a finite number of \
+                     seconds, zero or more"
+                ));
+            }
+            Ok(Cmd::Diff {
+                baseline,
+                current,
+                tolerance,
+            })
+        }
+        Verb::Fuzz {
+            target,
+            seed,
+            runs,
+            secs,
+            stall_secs,
+            shrink_runs,
+            replay,
+            out,
+            release,
+        } => {
+            // NaN would never reach the budget, and a stall window of NaN
+            // would never fire: both must be real, positive durations.
+            for (flag, value) in [("--secs", secs), ("--stall-secs", stall_secs)] {
+                if !(value.is_finite() && value > 0.0) {
+                    return Err(format!(
+                        "{flag} {value}: a fuzz duration is a finite number of seconds above zero"
+                    ));
+                }
+            }
+            Ok(Cmd::Fuzz(FuzzOptions {
+                target: ScenarioTarget::parse(&target),
+                seed,
+                runs,
+                secs,
+                stall_secs,
+                shrink_runs,
+                replay,
+                out,
+                release,
+            }))
+        }
         // Retired verbs get a pointed error, not a generic one: the
         // muscle-memory commands should say where they went.
         Verb::Trace { .. } => Err(
@@ -426,6 +620,15 @@ fn gate_measure(base: &RunOptions, matrix: bool) -> Result<(), String> {
                 .into(),
         );
     }
+    // The goldens are the shots pass's, and a sweep takes none: a bless there
+    // would record nothing and say it had.
+    if base.bless && matrix {
+        return Err(
+            "--bless records the shots pass, which a --scenario/--preset sweep \
+             does not take"
+                .into(),
+        );
+    }
     // A sweep REPLACES the frame-time pass with its matrix cells, so there is
     // nothing for --repeat to repeat. Silently doing one capture would read as
     // a completed sweep of repeats.
@@ -458,6 +661,14 @@ fn gate_run(base: &RunOptions) -> Result<(), String> {
     if base.platform == Platform::Web && base.norender {
         return Err("--norender is native only: a wasm run has no process environment".into());
     }
+    // Same reason: the profile reaches the child through its environment and
+    // its config store, and a browser run has neither.
+    if base.platform == Platform::Web && base.mod_profile.is_some() {
+        return Err("--mod-profile is native only: a wasm run reads no Nova profile state".into());
+    }
+    if base.platform == Platform::Web && base.bless {
+        return Err("--bless is native only: a web run takes no shots pass".into());
+    }
     gate_measure(base, matrix)
 }
 
@@ -510,6 +721,25 @@ mod tests {
         assert_eq!(base.display.as_deref(), Some(":0"));
     }
 
+    #[test]
+    fn mod_profile_rides_both_verbs_and_refuses_the_web() {
+        let Ok(Cmd::Scenario { base }) =
+            parse(&s(&["scenario", "duel", "--mod-profile", "sandbox"]))
+        else {
+            panic!("expected a scenario run");
+        };
+        assert_eq!(base.mod_profile.as_deref(), Some("sandbox"));
+        assert!(parse(&s(&[
+            "run",
+            "duel",
+            "--platform",
+            "web",
+            "--mod-profile",
+            "sandbox"
+        ]))
+        .is_err());
+    }
+
     #[test]
     fn parse_run_specs() {
         // A comma list splits into tokens; resolution happens later.
@@ -548,6 +778,7 @@ mod tests {
             s(&["report", "runs/x", "-h"]),
             s(&["scenario", "--help"]),
             s(&["scenario", "some_id", "-h"]),
+            s(&["fuzz", "--help"]),
         ] {
             assert!(help(&args), "{args:?}");
         }
@@ -599,6 +830,29 @@ mod tests {
             "report needs at least one run dir"
         );
 
+        let Ok(Cmd::Diff {
+            baseline,
+            current,
+            tolerance,
+        }) = parse(&s(&["diff", "runs/old", "runs/new"]))
+        else {
+            panic!("diff parses");
+        };
+        assert_eq!(
+            (baseline, current),
+            (PathBuf::from("runs/old"), PathBuf::from("runs/new"))
+        );
+        assert_eq!(tolerance, DEFAULT_SHIFT_TOLERANCE_SECS);
+        assert!(matches!(
+            parse(&s(&["diff", "a", "b", "--tolerance", "0.5"])),
+            Ok(Cmd::Diff { tolerance, .. }) if tolerance == 0.5
+        ));
+        assert!(
+            parse(&s(&["diff", "a"])).is_err(),
+            "diff needs two run dirs"
+        );
+        assert!(parse(&s(&["diff", "a", "b", "--tolerance", "NaN"])).is_err());
+
         let Ok(Cmd::RunSpec { tokens, base, .. }) = parse(&s(&[
             "run",
             "many_things",
@@ -621,6 +875,40 @@ mod tests {
         assert_eq!(base.presets, s(&["high"]));
     }
 
+    #[test]
+    fn fuzz_parses_its_budget_and_refuses_a_meaningless_one() {
+        let Ok(Cmd::Fuzz(options)) = parse(&s(&["fuzz", "some_id", "--seed", "7", "--runs", "3"]))
+        else {
+            panic!("fuzz parses");
+        };
+        assert_eq!(options.target, ScenarioTarget::Id("some_id".into()));
+        assert_eq!((options.seed, options.runs), (7, 3));
+        assert_eq!(options.secs, DEFAULT_FUZZ_SECS);
+        assert_eq!(options.stall_secs, DEFAULT_STALL_SECS);
+        assert_eq!(options.replay, None);
+
+        let Ok(Cmd::Fuzz(options)) =
+            parse(&s(&["fuzz", "loose.content.ron", "--replay", "t.json"]))
+        else {
+            panic!("a replay parses");
+        };
+        assert_eq!(options.replay, Some(PathBuf::from("t.json")));
+        assert!(
+            parse(&s(&["fuzz", "a", "--replay", "t.json", "--seed", "1"])).is_err(),
+            "a replay rolls no seed"
+        );
+        assert!(parse(&s(&["fuzz", "a", "--runs", "0"])).is_err());
+        for flag in ["--secs", "--stall-secs"] {
+            for value in ["0", "-1", "NaN", "inf"] {
+                assert!(
+                    parse(&s(&["fuzz", "a", flag, value])).is_err(),
+                    "{flag} {value}"
+                );
+            }
+        }
+        assert!(parse(&s(&["fuzz"])).is_err(), "fuzz needs a scenario");
+    }
+
     /// `--render` picks a backend and `--norender` says nothing draws, so
     /// asking for both is a contradiction the parser must refuse rather than
     /// silently honour one of.
@@ -791,6 +1079,31 @@ mod tests {
         .is_err());
     }
 
+    #[test]
+    fn bless_parses_and_refuses_the_runs_that_take_no_shots() {
+        let Ok(Cmd::RunSpec { base, .. }) = parse(&s(&["run", "x", "--render", "sw", "--bless"]))
+        else {
+            panic!("--bless parses on run");
+        };
+        assert!(base.bless);
+        assert_eq!(base.render.as_str(), "sw");
+        let Ok(Cmd::RunSpec { base, .. }) =
+            parse(&s(&["run", "x", "--bless", "--correctness-only"]))
+        else {
+            panic!("a correctness-only run takes the shots, so it can bless them");
+        };
+        assert!(base.bless && base.correctness_only);
+        for tail in [
+            vec!["--norender"],
+            vec!["--scenario", "a"],
+            vec!["--platform", "web"],
+        ] {
+            let mut args = s(&["run", "x", "--bless"]);
+            args.extend(tail.iter().map(|t| (*t).to_string()));
+            assert!(parse(&args).is_err(), "{tail:?} combined with --bless");
+        }
+    }
+
     #[test]
     fn retired_verbs_error_with_pointers() {
         // The v0.8.0 cut removed the deprecated aliases and the trace verb:
@@ -818,7 +1131,7 @@ mod tests {
         for alias in ["sweep", "trace", "profile"] {
             assert!(!text.contains(&format!("  {alias}")), "{alias}: {text}");
         }
-        for verb in ["run", "scenario", "report"] {
+        for verb in ["run", "scenario", "report", "diff", "fuzz"] {
             assert!(text.contains(verb), "{verb}: {text}");
         }
     }
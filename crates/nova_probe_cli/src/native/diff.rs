@@ -0,0 +1,143 @@
+//! `probe diff`: align two run dirs' timelines and write the divergences
+//! beside the newer run's report.
+
+use std::{path::Path, process::ExitCode};
+
+use nova_probe::prelude::*;
+
+use crate::{
+    evaluation::{diff_timelines, TimelineDiff},
+    report::{escape, render_timeline_diff, STYLE},
+};
+
+/// The parsed `timeline.jsonl` of a run dir probe produced. Both halves of a
+/// diff are the operator's arguments, so a missing or corrupt one is an error
+/// rather than an empty story that diverges from everything.
+fn load_timeline(dir: &Path) -> Result<Vec<TimelineEvent>, String> {
+    if !dir.join("probe-run.json").exists() {
+        return Err(format!(
+            "{} has no probe-run.json - probe only diffs run dirs it produced",
+            dir.display()
+        ));
+    }
+    let path = dir.join("timeline.jsonl");
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("{}: {e} - the run recorded no timeline", path.display()))?;
+    parse_timeline(&contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn render_page(baseline: &Path, current: &Path, diff: &TimelineDiff) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str("<title>nova timeline diff</title>\n");
+    html.push_str(STYLE);
+    html.push_str("</head>\n<body>\n<h1>Timeline diff</h1>\n");
+    html.push_str(&format!(
+        "<p class=\"meta\">Baseline <code>{}</code> against <code>{}</code></p>\n",
+        escape(&baseline.display().to_string()),
+        escape(&current.display().to_string()),
+    ));
+    html.push_str(&render_timeline_diff(diff));
+    html.push_str(
+        "<footer>Generated by <code>nova_probe diff</code>; machine-readable mirror \
+         in <code>diff.json</code>.</footer>\n",
+    );
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// `probe diff`: write `diff.html` + `diff.json` into `current` and print the
+/// divergences. Exits non-zero when the runs diverge, as `diff` does - the
+/// exit code says whether there is anything to read, not whether it is bad.
+pub(crate) fn diff(baseline: &Path, current: &Path, tolerance: f64) -> Result<ExitCode, String> {
+    let diff = diff_timelines(
+        &load_timeline(baseline)?,
+        &load_timeline(current)?,
+        tolerance,
+    )?;
+    std::fs::write(
+        current.join("diff.html"),
+        render_page(baseline, current, &diff),
+    )
+    .map_err(|e| format!("could not write diff.html: {e}"))?;
+    std::fs::write(current.join("diff.json"), format!("{:#}\n", diff.to_json()))
+        .map_err(|e| format!("could not write diff.json: {e}"))?;
+    println!(
+        "probe: {} - {}",
+        diff.summary(),
+        current.join("diff.html").display()
+    );
+    for divergence in &diff.divergences {
+        let at = |t: Option<f64>| t.map_or_else(|| "-".into(), |t| format!("{t:.2}"));
+        println!(
+            "  {:<9} {:<14} {} ({} -> {})",
+            divergence.kind.as_str(),
+            divergence.entry_kind,
+            divergence.label,
+            at(divergence.baseline_at),
+            at(divergence.current_at),
+        );
+    }
+    Ok(if diff.divergences.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation::DivergenceKind;
+
+    #[test]
+    fn a_diff_writes_both_files_into_the_newer_run_and_exits_on_divergence() {
+        let base = std::env::temp_dir().join(format!("nova_probe_diff_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let (old, new) = (base.join("old"), base.join("new"));
+        for (dir, beats) in [(&old, ["launch", "dock"]), (&new, ["launch", "refuel"])] {
+            std::fs::create_dir_all(dir).unwrap();
+            std::fs::write(dir.join("probe-run.json"), "{}").unwrap();
+            let lines: Vec<String> = beats
+                .iter()
+                .enumerate()
+                .map(|(i, beat)| {
+                    serde_json::json!({
+                        "t_real": i as f64, "frame": i, "scenario_elapsed": i as f64,
+                        "kind": "marker", "name": beat, "data": null,
+                    })
+                    .to_string()
+                })
+                .collect();
+            std::fs::write(dir.join("timeline.jsonl"), lines.join("\n")).unwrap();
+        }
+
+        assert_eq!(diff(&old, &old, 2.0), Ok(ExitCode::SUCCESS));
+        assert_eq!(diff(&old, &new, 2.0), Ok(ExitCode::FAILURE));
+        let json: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(new.join("diff.json")).unwrap()).unwrap();
+        let kinds: Vec<&str> = json["divergences"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["kind"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                DivergenceKind::Missing.as_str(),
+                DivergenceKind::Added.as_str()
+            ]
+        );
+        assert!(std::fs::read_to_string(new.join("diff.html"))
+            .unwrap()
+            .contains("<code>refuel</code>"));
+
+        assert!(
+            diff(&old, &base, 2.0).is_err(),
+            "a dir probe did not produce is refused"
+        );
+        let _ = std::fs::remove_dir_all(&base);
+    }
+}
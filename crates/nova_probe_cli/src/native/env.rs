@@ -3,10 +3,15 @@
 
 use std::path::Path;
 
-use nova_autopilot::{autopilot::AUTOPILOT_ENV, completion::DEADLINE_ENV};
+use nova_autopilot::{
+    autopilot::AUTOPILOT_ENV,
+    capture::{CAPTURE_DIR_ENV, CAPTURE_ENV},
+    completion::DEADLINE_ENV,
+};
 use nova_probe::{
-    probe_env, CONTRACT_PARAM, FRAMES_PARAM, INVARIANTS_PARAM, LABEL_PARAM, OUT_PARAM,
-    QUALITY_PARAM, SCENARIO_PARAM, TIMELINE_PARAM, WARMUP_PARAM,
+    probe_env, CONTRACT_PARAM, FRAMES_PARAM, FUZZ_PARAM, FUZZ_REPLAY_PARAM, FUZZ_SECS_PARAM,
+    FUZZ_TRACE_PARAM, INVARIANTS_PARAM, LABEL_PARAM, MEMORY_PARAM, OUT_PARAM, QUALITY_PARAM,
+    SCENARIO_PARAM, TIMELINE_PARAM, WARMUP_PARAM,
 };
 
 use super::cli::Render;
@@ -126,6 +131,10 @@ pub(crate) fn clean_pass_env(
             probe_env(CONTRACT_PARAM),
             out.join("probe-contract.json").display().to_string(),
         ),
+        (
+            probe_env(MEMORY_PARAM),
+            out.join("memory.csv").display().to_string(),
+        ),
     ]);
     if fps {
         env.push((nova_probe::PROBE_ENV.into(), "1".into()));
@@ -206,6 +215,82 @@ pub(crate) fn samply_pass_env(root: &Path, out: &Path, display: &str) -> Vec<(St
     env
 }
 
+/// Environment for the SHOTS pass: the producer's own capture path, staged
+/// into the run dir's `shots/` so the goldens have something to be held
+/// against. No recorder and no contract - the clean pass owns both, and the
+/// capture path settles each framing longer than a correctness run would.
+/// Profile-sandboxed like every other native child run.
+pub(crate) fn shots_pass_env(root: &Path, out: &Path, display: &str) -> Vec<(String, String)> {
+    let mut env = profile_sandbox::env(out);
+    env.extend(display_env(display));
+    env.extend(vec![
+        (AUTOPILOT_ENV.into(), "1".into()),
+        ("BEVY_ASSET_ROOT".into(), root.display().to_string()),
+        (CAPTURE_ENV.into(), "1".into()),
+        (
+            CAPTURE_DIR_ENV.into(),
+            out.join(crate::evaluation::SHOTS_DIR).display().to_string(),
+        ),
+    ]);
+    env
+}
+
+/// What a fuzzed flight flies: a seed's fresh input, or a trace played back.
+pub(crate) enum FuzzInput<'a> {
+    Seed(u64),
+    Replay(&'a Path),
+}
+
+/// Extra seconds a fuzzed flight's completion deadline gets over its budget,
+/// for the scenario to load and the trace to be written.
+const FUZZ_LOAD_MARGIN_SECS: f64 = 60.0;
+
+/// Environment for ONE fuzzed flight into `flight` (its `run.log` sibling
+/// files: `timeline.jsonl`, and `input-trace.json` when it rolls input):
+/// autopilot, recorder and invariants like the clean pass, headless always -
+/// a fuzz run is judged on its log and timeline, and a minute of random
+/// flight per seed is not worth a frame - plus the fuzzer's own knobs.
+/// Returns the completion deadline too, so the caller can raise the
+/// supervisor timeout above it. No contract: the clean pass owns that, and a
+/// fuzz run is not a pass of anything.
+///
+/// `sandbox` is the profile sandbox every flight of one `probe fuzz` shares.
+pub(crate) fn fuzz_env(
+    root: &Path,
+    sandbox: &Path,
+    flight: &Path,
+    input: FuzzInput<'_>,
+    secs: f64,
+) -> (Vec<(String, String)>, u64) {
+    let deadline = (secs + FUZZ_LOAD_MARGIN_SECS).ceil() as u64;
+    let mut env = profile_sandbox::env(sandbox);
+    env.extend(vec![
+        (AUTOPILOT_ENV.into(), "1".into()),
+        ("BEVY_ASSET_ROOT".into(), root.display().to_string()),
+        (
+            probe_env(TIMELINE_PARAM),
+            flight.join("timeline.jsonl").display().to_string(),
+        ),
+        (probe_env(INVARIANTS_PARAM), "1".into()),
+        (nova_probe::NORENDER_ENV.into(), "1".into()),
+        (probe_env(FUZZ_SECS_PARAM), secs.to_string()),
+        (DEADLINE_ENV.into(), deadline.to_string()),
+    ]);
+    match input {
+        FuzzInput::Seed(seed) => {
+            env.push((probe_env(FUZZ_PARAM), seed.to_string()));
+            env.push((
+                probe_env(FUZZ_TRACE_PARAM),
+                flight.join("input-trace.json").display().to_string(),
+            ));
+        }
+        FuzzInput::Replay(trace) => {
+            env.push((probe_env(FUZZ_REPLAY_PARAM), trace.display().to_string()));
+        }
+    }
+    (env, deadline)
+}
+
 /// The renderer selection, applied to EVERY native pass rather than to the
 /// clean pass alone: the number a `--render` flag exists to produce is written
 /// by the fps pass, so a selection the fps pass did not get is a selection that
@@ -331,6 +416,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn a_fuzz_flight_is_headless_and_replay_names_no_seed() {
+        let (root, sandbox, flight) = (Path::new("/r"), Path::new("/s"), Path::new("/s/seed-7"));
+        let (env, deadline) = fuzz_env(root, sandbox, flight, FuzzInput::Seed(7), 60.0);
+        let get = |env: &[(String, String)], k: &str| {
+            env.iter().find(|(key, _)| key == k).map(|(_, v)| v.clone())
+        };
+        assert_eq!(get(&env, "NOVA_PROBE_FUZZ").as_deref(), Some("7"));
+        assert_eq!(
+            get(&env, "NOVA_PROBE_FUZZ_TRACE").as_deref(),
+            Some("/s/seed-7/input-trace.json")
+        );
+        assert_eq!(get(&env, nova_probe::NORENDER_ENV).as_deref(), Some("1"));
+        assert_eq!(get(&env, DEADLINE_ENV), Some(deadline.to_string()));
+        assert_eq!(deadline, 120);
+
+        let trace = Path::new("/s/seed-7/minimal-trace.json");
+        let (env, _) = fuzz_env(root, sandbox, flight, FuzzInput::Replay(trace), 12.5);
+        assert_eq!(get(&env, "NOVA_PROBE_FUZZ"), None, "a replay rolls nothing");
+        assert_eq!(get(&env, "NOVA_PROBE_FUZZ_TRACE"), None);
+        assert_eq!(
+            get(&env, "NOVA_PROBE_FUZZ_REPLAY").as_deref(),
+            Some("/s/seed-7/minimal-trace.json")
+        );
+    }
+
     #[test]
     fn matrix_cells_cross_scenarios_and_presets() {
         let cells = matrix_cells(&s(&["a", "b"]), &s(&["high", "low"]));
@@ -403,6 +514,7 @@ mod tests {
             clean_pass_env(root, out, "", true),
             trace_pass_env(root, out, ""),
             samply_pass_env(root, out, ""),
+            shots_pass_env(root, out, ""),
         ] {
             assert!(
                 !env.iter().any(|(k, _)| k == "DISPLAY"),
@@ -428,6 +540,10 @@ mod tests {
             Some("/repo/probe-runs/x/timeline.jsonl")
         );
         assert_eq!(get("NOVA_PROBE_INVARIANTS", &env).as_deref(), Some("1"));
+        assert_eq!(
+            get("NOVA_PROBE_MEMORY", &env).as_deref(),
+            Some("/repo/probe-runs/x/memory.csv")
+        );
         assert_eq!(get("NOVA_PROBE", &env), None, "clean pass excludes fps");
 
         let env = clean_pass_env(root, out, ":97", true);
@@ -464,10 +580,11 @@ mod tests {
             profile_sandbox::SANDBOXED_VARS
         );
         // EVERY builder that feeds run_supervised with a native example:
-        // clean (also the sweep + fps passes), profiled, samply.
+        // clean (also the sweep + fps passes), shots, profiled, samply.
         for env in [
             clean_pass_env(root, out, ":97", false),
             clean_pass_env(root, out, ":97", true),
+            shots_pass_env(root, out, ":97"),
             trace_pass_env(root, out, ":97"),
             samply_pass_env(root, out, ":97"),
         ] {
@@ -506,4 +623,21 @@ mod tests {
             "the profiled pass never overwrites the clean pass's timeline"
         );
     }
+
+    /// The shots pass arms the producer's capture path into the run dir and
+    /// nothing the clean pass owns.
+    #[test]
+    fn shots_env_stages_the_capture_into_the_run_dir() {
+        let env = shots_pass_env(Path::new("/repo"), Path::new("/repo/probe-runs/x"), ":97");
+        let get = |k: &str| env.iter().find(|(key, _)| key == k).map(|(_, v)| v.clone());
+        assert_eq!(get("NOVA_CAPTURE").as_deref(), Some("1"));
+        assert_eq!(
+            get("NOVA_CAPTURE_DIR").as_deref(),
+            Some("/repo/probe-runs/x/shots")
+        );
+        assert_eq!(get("NOVA_AUTOPILOT").as_deref(), Some("1"));
+        for owned in ["NOVA_PROBE_TIMELINE", "NOVA_PROBE_CONTRACT", "NOVA_PROBE"] {
+            assert_eq!(get(owned), None, "{owned} belongs to another pass");
+        }
+    }
 }
@@ -0,0 +1,428 @@
+//! `probe fuzz`: fly a scenario on seeded random input, read each flight's
+//! failure, and shrink a failing trace to the least input that still fails.
+//!
+//! Every flight is the game binary, headless, with the in-game fuzzer armed
+//! ([`nova_probe::FUZZ_PARAM`]); the judging is [`crate::evaluation::fuzz`]'s.
+//! A failure is first flown AGAIN from its own trace - a failure the replay
+//! does not reproduce is reported as such and left unshrunk, because every
+//! cut the shrinker makes would be judged against a run it cannot repeat.
+
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use nova_probe::prelude::*;
+
+use super::{
+    cli::FuzzOptions,
+    env::{fuzz_env, FuzzInput},
+    paths::{default_output_root, repo_root},
+    supervise::{build_game, run_supervised, RunOutcome, GAME_BIN},
+};
+use crate::{evaluation::prelude::*, native::profile_sandbox};
+
+/// Bevy's fixed rate, which the game never overrides: a trace's `end_tick`
+/// in seconds.
+const FIXED_HZ: f64 = 64.0;
+
+/// Seconds the supervisor waits past a flight's own completion deadline
+/// before killing it.
+const SUPERVISOR_MARGIN_SECS: u64 = 30;
+
+/// The files one flight leaves in its dir, removed before it flies so a
+/// previous flight's evidence cannot judge this one.
+const FLIGHT_ARTIFACTS: [&str; 3] = ["run.log", "timeline.jsonl", "input-trace.json"];
+
+/// The game binary and what every flight of one `probe fuzz` shares.
+struct Pilot {
+    root: PathBuf,
+    bin: PathBuf,
+    args: Vec<String>,
+    /// The fuzz dir: the profile sandbox every flight boots in.
+    sandbox: PathBuf,
+    stall_secs: f64,
+}
+
+impl Pilot {
+    /// Fly once into `dir` and judge the flight.
+    fn fly(
+        &self,
+        dir: &Path,
+        input: FuzzInput<'_>,
+        secs: f64,
+    ) -> Result<Option<FuzzFailure>, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        for name in FLIGHT_ARTIFACTS {
+            let _ = std::fs::remove_file(dir.join(name));
+        }
+        let (env, deadline) = fuzz_env(&self.root, &self.sandbox, dir, input, secs);
+        let args: Vec<&str> = self.args.iter().map(String::as_str).collect();
+        let log_path = dir.join("run.log");
+        let outcome = run_supervised(
+            &self.bin,
+            &args,
+            &self.root,
+            &env,
+            &log_path,
+            Duration::from_secs(deadline + SUPERVISOR_MARGIN_SECS),
+        )?;
+        let log = std::fs::read_to_string(&log_path).ok();
+        let timeline = read_flight_timeline(&dir.join("timeline.jsonl"))?;
+        let exit = match outcome {
+            RunOutcome::Completed { success: true } => None,
+            RunOutcome::Completed { success: false } => Some("non-zero exit"),
+            RunOutcome::TimedOut => Some("timed out"),
+        };
+        Ok(find_failure(
+            log.as_deref(),
+            &timeline,
+            exit,
+            self.stall_secs,
+        ))
+    }
+
+    /// Fly `trace` back and judge it.
+    fn replay(&self, dir: &Path, trace: &serde_json::Value) -> Result<Option<FuzzFailure>, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        let path = dir.join("candidate-trace.json");
+        write_trace(&path, trace)?;
+        let secs = trace_end_tick(trace) as f64 / FIXED_HZ;
+        self.fly(dir, FuzzInput::Replay(&path), secs.max(1.0))
+    }
+}
+
+/// A flight's timeline, whole lines only: a flight that died mid-write leaves
+/// a torn last line, and that crash is the log's to report. No file is no
+/// entries - the flight died before the recorder armed.
+fn read_flight_timeline(path: &Path) -> Result<Vec<TimelineEvent>, String> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Ok(Vec::new());
+    };
+    let whole = contents.rfind('\n').map_or("", |end| &contents[..=end]);
+    parse_timeline(whole).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn read_trace(path: &Path) -> Result<serde_json::Value, String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    serde_json::from_str(&contents).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn write_trace(path: &Path, trace: &serde_json::Value) -> Result<(), String> {
+    std::fs::write(path, format!("{trace:#}\n"))
+        .map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+fn trace_end_tick(trace: &serde_json::Value) -> u64 {
+    trace["end_tick"].as_u64().unwrap_or(0)
+}
+
+fn trace_len(trace: &serde_json::Value, key: &str) -> usize {
+    trace[key].as_array().map_or(0, Vec::len)
+}
+
+/// One cut-able piece of a trace's input.
+#[derive(Clone)]
+enum Unit {
+    Keyframe(serde_json::Value),
+    Command(serde_json::Value),
+}
+
+/// The trace's input as shrinkable units. The first keyframe - the intent at
+/// load - is not one: it stays, so every cut still starts from a pilot.
+fn trace_units(trace: &serde_json::Value) -> Vec<Unit> {
+    let keyframes = trace["keyframes"].as_array().into_iter().flatten().skip(1);
+    let commands = trace["commands"].as_array().into_iter().flatten();
+    keyframes
+        .cloned()
+        .map(Unit::Keyframe)
+        .chain(commands.cloned().map(Unit::Command))
+        .collect()
+}
+
+/// `trace` with only `units` for input (order kept; keyframes stay sorted).
+fn with_units(trace: &serde_json::Value, units: &[Unit]) -> serde_json::Value {
+    let mut cut = trace.clone();
+    let first = trace["keyframes"]
+        .as_array()
+        .and_then(|k| k.first())
+        .cloned();
+    cut["keyframes"] = first
+        .into_iter()
+        .chain(units.iter().filter_map(|unit| match unit {
+            Unit::Keyframe(keyframe) => Some(keyframe.clone()),
+            Unit::Command(_) => None,
+        }))
+        .collect();
+    cut["commands"] = units
+        .iter()
+        .filter_map(|unit| match unit {
+            Unit::Command(command) => Some(command.clone()),
+            Unit::Keyframe(_) => None,
+        })
+        .collect();
+    cut
+}
+
+/// What became of one failing flight.
+struct Shrunk {
+    reproduced: bool,
+    minimal: Option<serde_json::Value>,
+    replays: usize,
+}
+
+/// Replay `trace` to confirm `failure`, then cut its input and shorten its
+/// run while the SAME failure holds, in at most `budget` replays after the
+/// confirmation. Candidates fly in `dir/shrink/`.
+fn shrink_failure(
+    pilot: &Pilot,
+    dir: &Path,
+    trace: &serde_json::Value,
+    failure: &FuzzFailure,
+    budget: usize,
+) -> Result<Shrunk, String> {
+    let work = dir.join("shrink");
+    eprintln!("probe: fuzz: replaying the trace to confirm {failure}");
+    let again = pilot.replay(&work, trace)?;
+    if !again.as_ref().is_some_and(|again| again.same_as(failure)) {
+        eprintln!(
+            "probe: fuzz: the replay did not reproduce it ({}) - keeping the trace unshrunk",
+            again.map_or_else(|| "clean".into(), |again| again.to_string())
+        );
+        return Ok(Shrunk {
+            reproduced: false,
+            minimal: None,
+            replays: 1,
+        });
+    }
+
+    // An infrastructure error is not "passes": remember it, stop spending
+    // replays, and report it once the shrinker returns.
+    let mut error = None;
+    let mut still_fails = |candidate: &serde_json::Value| {
+        if error.is_some() {
+            return false;
+        }
+        match pilot.replay(&work, candidate) {
+            Ok(seen) => seen.is_some_and(|seen| seen.same_as(failure)),
+            Err(e) => {
+                error = Some(e);
+                false
+            }
+        }
+    };
+    let (units, cut_tries) = shrink(trace_units(trace), budget, |units| {
+        still_fails(&with_units(trace, units))
+    });
+    let mut minimal = with_units(trace, &units);
+    let (end_tick, shorten_tries) = shorten(
+        0,
+        trace_end_tick(&minimal),
+        budget.saturating_sub(cut_tries),
+        |end_tick| {
+            let mut candidate = minimal.clone();
+            candidate["end_tick"] = end_tick.into();
+            still_fails(&candidate)
+        },
+    );
+    if let Some(error) = error {
+        return Err(error);
+    }
+    minimal["end_tick"] = end_tick.into();
+    Ok(Shrunk {
+        reproduced: true,
+        minimal: Some(minimal),
+        replays: 1 + cut_tries + shorten_tries,
+    })
+}
+
+/// Judge one flight already flown into `dir`, shrink it if it failed, and
+/// return its `fuzz.json` row.
+fn settle(
+    pilot: &Pilot,
+    dir: &Path,
+    seed: Option<u64>,
+    trace_path: &Path,
+    failure: Option<FuzzFailure>,
+    shrink_runs: usize,
+) -> Result<serde_json::Value, String> {
+    let mut row = serde_json::json!({
+        "seed": seed,
+        "dir": dir.display().to_string(),
+        "failure": failure.as_ref().map(FuzzFailure::to_json),
+    });
+    let Some(failure) = failure else {
+        eprintln!("probe: fuzz: {} clean", dir.display());
+        return Ok(row);
+    };
+    eprintln!("probe: fuzz: {} FAILED: {failure}", dir.display());
+    let Ok(trace) = read_trace(trace_path) else {
+        eprintln!(
+            "probe: fuzz: no input trace at {} - the flight died before its first decision",
+            trace_path.display()
+        );
+        row["reproduced"] = serde_json::Value::Null;
+        return Ok(row);
+    };
+    let shrunk = shrink_failure(pilot, dir, &trace, &failure, shrink_runs)?;
+    row["reproduced"] = shrunk.reproduced.into();
+    row["replays"] = shrunk.replays.into();
+    if let Some(minimal) = shrunk.minimal {
+        let path = dir.join("minimal-trace.json");
+        write_trace(&path, &minimal)?;
+        let span =
+            |key: &str| serde_json::json!([trace_len(&trace, key), trace_len(&minimal, key)]);
+        row["minimal_trace"] = path.display().to_string().into();
+        row["keyframes"] = span("keyframes");
+        row["commands"] = span("commands");
+        row["end_tick"] = serde_json::json!([trace_end_tick(&trace), trace_end_tick(&minimal)]);
+        eprintln!(
+            "probe: fuzz: shrunk to {} keyframes, {} commands, {} ticks in {} replays -> {}",
+            trace_len(&minimal, "keyframes"),
+            trace_len(&minimal, "commands"),
+            trace_end_tick(&minimal),
+            shrunk.replays,
+            path.display()
+        );
+    }
+    Ok(row)
+}
+
+/// `probe fuzz`: build the game, fly the seeds (or the one trace), shrink
+/// what fails, and write `fuzz.json`. Exits non-zero when any flight failed.
+pub(crate) fn fuzz(opts: &FuzzOptions) -> Result<ExitCode, String> {
+    let root = repo_root();
+    let label = opts.target.label();
+    let out = opts.out.clone().unwrap_or_else(|| {
+        let (git_sha, _) = run_identity();
+        default_output_root(&root, None, &git_sha).join(format!("fuzz-{label}"))
+    });
+    std::fs::create_dir_all(&out).map_err(|e| format!("could not create out dir: {e}"))?;
+    let out = out
+        .canonicalize()
+        .map_err(|e| format!("could not resolve out dir: {e}"))?;
+    // A --replay trace may sit inside the out dir; resolve it before anything
+    // under the dir moves.
+    let replay = match &opts.replay {
+        Some(path) => Some(read_trace(path)?),
+        None => None,
+    };
+    profile_sandbox::prepare(&out);
+
+    eprintln!(
+        "probe: fuzz: building {GAME_BIN}{}",
+        if opts.release { " (release)" } else { "" }
+    );
+    let (profile_dir, cargo_profile) = if opts.release {
+        ("release", Some("release"))
+    } else {
+        ("debug", None)
+    };
+    build_game(&root, "debug", cargo_profile)?;
+    let pilot = Pilot {
+        bin: root.join("target").join(profile_dir).join(GAME_BIN),
+        args: opts.target.args(),
+        root,
+        sandbox: out.clone(),
+        stall_secs: opts.stall_secs,
+    };
+
+    let mut rows = Vec::new();
+    if let Some(trace) = replay {
+        let dir = out.join("replay");
+        eprintln!("probe: fuzz: {label} replaying -> {}", dir.display());
+        let failure = pilot.replay(&dir, &trace)?;
+        let trace_path = dir.join("candidate-trace.json");
+        rows.push(settle(
+            &pilot,
+            &dir,
+            None,
+            &trace_path,
+            failure,
+            opts.shrink_runs,
+        )?);
+    } else {
+        for seed in (opts.seed..).take(opts.runs as usize) {
+            let dir = out.join(format!("seed-{seed}"));
+            eprintln!("probe: fuzz: {label} seed {seed} -> {}", dir.display());
+            let failure = pilot.fly(&dir, FuzzInput::Seed(seed), opts.secs)?;
+            rows.push(settle(
+                &pilot,
+                &dir,
+                Some(seed),
+                &dir.join("input-trace.json"),
+                failure,
+                opts.shrink_runs,
+            )?);
+        }
+    }
+
+    let failed = rows.iter().filter(|row| !row["failure"].is_null()).count();
+    let summary = serde_json::json!({
+        "scenario": label,
+        "secs": opts.secs,
+        "stall_secs": opts.stall_secs,
+        "flights": rows,
+    });
+    std::fs::write(out.join("fuzz.json"), format!("{summary:#}\n"))
+        .map_err(|e| format!("could not write fuzz.json: {e}"))?;
+    println!(
+        "probe: fuzz {label}: {failed} of {} flights failed - {}",
+        rows.len(),
+        out.join("fuzz.json").display()
+    );
+    Ok(if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_cut_keeps_the_opening_intent_and_the_units_it_was_given() {
+        let trace = serde_json::json!({
+            "schema": 1, "scenario": "s", "mods": [], "seed": 3, "end_tick": 640,
+            "keyframes": [{"tick": 0}, {"tick": 16}, {"tick": 32}],
+            "commands": [{"tick": 20, "name": "ship repair", "args": []}],
+        });
+        let units = trace_units(&trace);
+        assert_eq!(units.len(), 3, "two later keyframes and a command");
+
+        let cut = with_units(&trace, &units[1..]);
+        assert_eq!(
+            cut["keyframes"],
+            serde_json::json!([{"tick": 0}, {"tick": 32}])
+        );
+        assert_eq!(trace_len(&cut, "commands"), 1);
+        assert_eq!(trace_end_tick(&cut), 640);
+
+        let bare = with_units(&trace, &[]);
+        assert_eq!(bare["keyframes"], serde_json::json!([{"tick": 0}]));
+        assert_eq!(bare["commands"], serde_json::json!([]));
+        assert_eq!(bare["seed"], 3, "everything but the input is kept");
+    }
+
+    #[test]
+    fn a_torn_timeline_reads_up_to_its_last_whole_line() {
+        let path = std::env::temp_dir().join(format!(
+            "nova_probe_fuzz_timeline_{}.jsonl",
+            std::process::id()
+        ));
+        let line = serde_json::json!({
+            "t_real": 0.0, "frame": 0, "scenario_elapsed": 0.0,
+            "kind": "run_start", "name": "run", "data": null,
+        });
+        std::fs::write(&path, format!("{line}\n{{\"t_real\": 1.")).unwrap();
+        assert_eq!(read_flight_timeline(&path).unwrap().len(), 1);
+        let _ = std::fs::remove_file(&path);
+        assert!(read_flight_timeline(&path).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+//! The host side of the golden frames: whether an example is held to any,
+//! staging them into the run dir beside its shots, and `--bless`.
+//!
+//! A stills producer is held to its software goldens whether or not any are
+//! committed: those examples exist to take stills, so a producer with nothing
+//! blessed fails the sweep instead of passing it unseen. Every other
+//! example, and every GPU run, is held only to what it has committed.
+//!
+//! The run dir gets COPIES. A report is re-rendered from its dir alone, and a
+//! golden re-blessed since must not quietly change what an old report says it
+//! was held to.
+
+use std::path::{Path, PathBuf};
+
+use super::{cli::Render, paths::goldens_dir};
+use crate::{evaluation::golden::prelude::*, CatalogExample};
+
+/// The catalog category of the capture producers.
+pub(crate) const PRODUCER_CATEGORY: &str = "screenshots";
+
+/// The name prefix that marks a producer of STILLS; its `loop_*` siblings
+/// record video, which no golden frame describes. The catalog's naming
+/// contract.
+pub(crate) const STILLS_PREFIX: &str = "screenshot_";
+
+/// The PNGs directly under `dir`, sorted. A missing dir is empty.
+fn pngs(dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "png"))
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+fn copy_into(files: &[PathBuf], dir: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+    for file in files {
+        let Some(name) = file.file_name() else {
+            continue;
+        };
+        std::fs::copy(file, dir.join(name))
+            .map_err(|e| format!("could not copy {}: {e}", file.display()))?;
+    }
+    Ok(())
+}
+
+/// Whether `example` has golden frames committed for `render` - what decides
+/// that a run takes the shots pass at all.
+pub(crate) fn committed(root: &Path, example: &str, render: Render) -> bool {
+    !pngs(&goldens_dir(root, example).join(render.as_str())).is_empty()
+}
+
+/// Whether a `render` run of `example` must be held to goldens even when none
+/// are committed: a stills producer on the software backend. GPU goldens
+/// differ by vendor and driver and stay a local concern.
+pub(crate) fn required(catalog: &[CatalogExample], example: &str, render: Render) -> bool {
+    render == Render::Sw
+        && example.starts_with(STILLS_PREFIX)
+        && catalog
+            .iter()
+            .any(|entry| entry.name == example && entry.category == PRODUCER_CATEGORY)
+}
+
+/// Copy the goldens committed for `render`, and the example's `golden.json`
+/// when it has one, into the run dir's [`GOLDEN_DIR`]. Returns how many frames
+/// were staged; none staged leaves no dir, which the check reads as "held to
+/// no goldens".
+pub(crate) fn stage(
+    root: &Path,
+    example: &str,
+    render: Render,
+    out: &Path,
+) -> Result<usize, String> {
+    let source = goldens_dir(root, example);
+    let frames = pngs(&source.join(render.as_str()));
+    if frames.is_empty() {
+        return Ok(0);
+    }
+    let staged = out.join(GOLDEN_DIR);
+    copy_into(&frames, &staged)?;
+    let config = source.join(GOLDEN_CONFIG);
+    if config.is_file() {
+        copy_into(&[config], &staged)?;
+    }
+    Ok(frames.len())
+}
+
+/// Replace the goldens committed for `render` with this run's shots. Refuses a
+/// run that captured nothing: an empty bless would delete every golden and
+/// call the silence a baseline.
+pub(crate) fn bless(
+    root: &Path,
+    example: &str,
+    render: Render,
+    out: &Path,
+) -> Result<usize, String> {
+    let shots = pngs(&out.join(SHOTS_DIR));
+    if shots.is_empty() {
+        return Err(format!(
+            "--bless: the shots pass wrote no frames to {} - nothing to record",
+            out.join(SHOTS_DIR).display()
+        ));
+    }
+    let target = goldens_dir(root, example).join(render.as_str());
+    // A shot the producer no longer takes must not survive as a golden it
+    // can never match again.
+    for stale in pngs(&target) {
+        std::fs::remove_file(&stale)
+            .map_err(|e| format!("could not remove {}: {e}", stale.display()))?;
+    }
+    copy_into(&shots, &target)?;
+    Ok(shots.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_bless_replaces_the_backend_goldens_and_stages_back_into_the_run_dir() {
+        let base = std::env::temp_dir().join(format!("nova_probe_goldens_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let (root, out) = (base.join("repo"), base.join("run"));
+        let committed_dir = goldens_dir(&root, "screenshot_x");
+        std::fs::create_dir_all(committed_dir.join("sw")).unwrap();
+        std::fs::write(committed_dir.join("sw").join("old.png"), "old").unwrap();
+        std::fs::write(committed_dir.join(GOLDEN_CONFIG), "{}").unwrap();
+        std::fs::create_dir_all(out.join(SHOTS_DIR)).unwrap();
+
+        assert!(committed(&root, "screenshot_x", Render::Sw));
+        assert!(!committed(&root, "screenshot_x", Render::Gpu));
+        assert!(
+            bless(&root, "screenshot_x", Render::Sw, &out).is_err(),
+            "an empty shots dir blesses nothing"
+        );
+
+        std::fs::write(out.join(SHOTS_DIR).join("new.png"), "new").unwrap();
+        assert_eq!(bless(&root, "screenshot_x", Render::Sw, &out), Ok(1));
+        assert!(!committed_dir.join("sw").join("old.png").exists());
+        assert!(committed_dir.join("sw").join("new.png").is_file());
+
+        assert_eq!(stage(&root, "screenshot_x", Render::Sw, &out), Ok(1));
+        assert!(out.join(GOLDEN_DIR).join("new.png").is_file());
+        assert!(out.join(GOLDEN_DIR).join(GOLDEN_CONFIG).is_file());
+        assert_eq!(stage(&root, "screenshot_x", Render::Gpu, &base), Ok(0));
+        assert!(!base.join(GOLDEN_DIR).exists());
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    /// Only a stills producer on the software backend is held to goldens it
+    /// has not committed; a video loop and everything outside the category
+    /// are held only to what they commit.
+    #[test]
+    fn only_a_software_stills_run_requires_goldens() {
+        let catalog: Vec<CatalogExample> = [
+            ("screenshot_x", "screenshots"),
+            ("loop_x", "screenshots"),
+            ("screenshot_misfiled", "gameplay"),
+        ]
+        .into_iter()
+        .map(|(name, category)| CatalogExample {
+            name: name.into(),
+            path: format!("examples/{category}/{name}.rs"),
+            category: category.into(),
+        })
+        .collect();
+        assert!(required(&catalog, "screenshot_x", Render::Sw));
+        assert!(!required(&catalog, "screenshot_x", Render::Gpu));
+        assert!(!required(&catalog, "loop_x", Render::Sw));
+        assert!(!required(&catalog, "screenshot_misfiled", Render::Sw));
+        assert!(!required(&catalog, "screenshot_unlisted", Render::Sw));
+    }
+}
@@ -15,6 +15,13 @@ pub(crate) fn repo_root() -> PathBuf {
         .unwrap_or_else(|_| Path::new(env!("CARGO_MANIFEST_DIR")).join("../.."))
 }
 
+/// Where an example's committed golden frames live: one subdir per backend
+/// (`gpu`, `sw`) holding the PNGs, and an optional `golden.json` of thresholds
+/// and masks beside them that both backends share.
+pub(crate) fn goldens_dir(root: &Path, example: &str) -> PathBuf {
+    root.join("crates/nova_probe_cli/goldens").join(example)
+}
+
 /// Resolve an example's baseline against a baseline root: the old direct
 /// run dir when it holds `frametime.csv`, or the new child run dir
 /// `<root>/<example>` when that holds one. Missing examples skip the
@@ -53,13 +53,30 @@
 //!   requires Xvfb, so Linux is the supported host.
 //! - The web pass (`--platform web`) is out of scope: it runs in a browser
 //!   profile and reads no Nova profile state.
+//!
+//! ## Mod profiles
+//!
+//! `--mod-profile <name>` is the one piece of the operator's state a run asks
+//! for by name. [`seed_mod_profiles`] checks the operator's saved
+//! `mod_profiles.ron` (honouring `NOVA_CONFIG_ROOT`) holds the name and copies
+//! it into the sandbox config before the first pass, and [`mod_profile_env`]
+//! hands each child the name - so the run merges exactly the profile's set and
+//! order while everything else stays sandboxed. A profile enabling downloaded
+//! mods fails the run unless the operator kept their own mod cache, because
+//! the sandboxed one is empty.
 
 /// Glob-import surface for the child run's profile sandbox.
 pub mod prelude {
-    pub use super::{env, env_with, inherited, prepare, root, SANDBOXED_VARS};
+    pub use super::{
+        env, env_with, inherited, mod_profile_env, prepare, root, seed_mod_profiles,
+        MOD_PROFILE_ENV, SANDBOXED_VARS,
+    };
 }
 
-use std::path::{Path, PathBuf};
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
 
 /// The environment variables the sandbox redirects, in the order [`env()`]
 /// emits them.
@@ -74,6 +91,14 @@ pub const SANDBOXED_VARS: [&str; 3] = [
     "XDG_CONFIG_HOME",
 ];
 
+/// The variable a child reads its launch mod profile from. `nova_assets` owns
+/// it; spelled again here for the same reason as the mod-cache name above, and
+/// pinned by the same test.
+pub const MOD_PROFILE_ENV: &str = "NOVA_MOD_PROFILE";
+
+/// The store file the profiles live in, under a config root.
+const MOD_PROFILES_FILE: &str = "nova-protocol/mod_profiles.ron";
+
 /// The sandbox root for a run directory: `<run_dir>/profile`.
 pub fn root(run_dir: &Path) -> PathBuf {
     run_dir.join("profile")
@@ -151,6 +176,159 @@ pub fn prepare(run_dir: &Path) {
     );
 }
 
+/// The env a child needs to boot with the mod profile `name` (nothing for no
+/// profile).
+pub fn mod_profile_env(name: Option<&str>) -> Vec<(String, String)> {
+    name.map(|name| (MOD_PROFILE_ENV.to_string(), name.to_string()))
+        .into_iter()
+        .collect()
+}
+
+/// The settings-store root override (`nova_assets::storage::CONFIG_ROOT_ENV`).
+/// The child inherits it, and it beats the config dir the sandbox moves.
+const CONFIG_ROOT_ENV: &str = "NOVA_CONFIG_ROOT";
+
+/// The shipped mod catalog, relative to the repo root.
+const MOD_CATALOG: &str = "assets/mods.catalog.ron";
+
+/// The slice of `mod_profiles.ron` a run needs: the names and their sets.
+/// Mirrors `nova_assets::mod_profiles`; unknown fields are ignored.
+#[derive(serde::Deserialize, Default)]
+struct ProfileStore {
+    #[serde(default)]
+    profiles: Vec<StoredProfile>,
+}
+
+#[derive(serde::Deserialize)]
+struct StoredProfile {
+    name: String,
+    #[serde(default)]
+    enabled: Vec<String>,
+}
+
+/// The slice of `assets/mods.catalog.ron` a run needs: the shipped ids.
+#[derive(serde::Deserialize)]
+struct Catalog {
+    mods: Vec<CatalogEntry>,
+}
+
+#[derive(serde::Deserialize)]
+struct CatalogEntry {
+    id: String,
+}
+
+/// Where the operator's saved mod profiles live, resolved the way the game's
+/// settings store does: `$NOVA_CONFIG_ROOT`, else the `dirs` config dir on
+/// Linux (`$XDG_CONFIG_HOME`, else `$HOME/.config`) under `nova-protocol`.
+/// The second value is whether a child reads that store itself - true when
+/// the operator kept the variable that points at it.
+fn mod_profiles_source(var: impl Fn(&str) -> Option<OsString>) -> Option<(PathBuf, bool)> {
+    if let Some(root) = var(CONFIG_ROOT_ENV) {
+        return Some((PathBuf::from(root).join("mod_profiles.ron"), true));
+    }
+    if let Some(config) = var("XDG_CONFIG_HOME") {
+        return Some((PathBuf::from(config).join(MOD_PROFILES_FILE), true));
+    }
+    let home = var("HOME")?;
+    Some((
+        PathBuf::from(home).join(".config").join(MOD_PROFILES_FILE),
+        false,
+    ))
+}
+
+/// Check that the store holds a profile called `name` and that the run can
+/// merge it: a profile enabling a DOWNLOADED mod (one the catalog does not
+/// ship) needs the operator's mod cache, which the sandbox swaps for an empty
+/// one. `store` is `None` when no profiles have been saved.
+fn check_mod_profile(
+    name: &str,
+    store: Option<&str>,
+    catalog: &str,
+    cache_inherited: bool,
+) -> Result<(), String> {
+    let store: ProfileStore = match store {
+        Some(text) => ron::from_str(text)
+            .map_err(|e| format!("--mod-profile '{name}': unreadable mod profiles: {e}"))?,
+        None => ProfileStore::default(),
+    };
+    let Some(profile) = store.profiles.iter().find(|p| p.name == name) else {
+        let mut known: Vec<&str> = store.profiles.iter().map(|p| p.name.as_str()).collect();
+        known.sort();
+        return Err(format!(
+            "--mod-profile '{name}': no saved profile by that name (saved: {})",
+            if known.is_empty() {
+                "none".to_string()
+            } else {
+                known.join(", ")
+            }
+        ));
+    };
+    if cache_inherited {
+        return Ok(());
+    }
+    let catalog: Catalog =
+        ron::from_str(catalog).map_err(|e| format!("{MOD_CATALOG}: unreadable: {e}"))?;
+    let downloaded: Vec<&str> = profile
+        .enabled
+        .iter()
+        .filter(|id| !catalog.mods.iter().any(|entry| &entry.id == *id))
+        .map(String::as_str)
+        .collect();
+    if downloaded.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "--mod-profile '{name}' enables downloaded mod(s) the sandboxed cache does not hold: {}. \
+         Point the run at your cache, e.g. {}=~/.local/share/nova-protocol",
+        downloaded.join(", "),
+        SANDBOXED_VARS[0]
+    ))
+}
+
+/// Make the operator's mod profile `name` resolvable by a child run under
+/// `run_dir`, or fail the run before anything is built.
+///
+/// The store is found the way the game finds it (see
+/// [`mod_profiles_source`]) and parsed: an unknown name is an error rather
+/// than a run that silently measures base only, and so is a profile that
+/// needs downloaded mods while the mod cache is sandboxed. When the child
+/// reads the operator's store itself there is nothing to copy; otherwise the
+/// store is copied into the sandbox config.
+pub fn seed_mod_profiles(repo_root: &Path, run_dir: &Path, name: &str) -> Result<(), String> {
+    let Some((source, child_reads_it)) = mod_profiles_source(|var| std::env::var_os(var)) else {
+        return Err(format!(
+            "--mod-profile '{name}': no {CONFIG_ROOT_ENV}, XDG_CONFIG_HOME or HOME to find the \
+             saved mod profiles under"
+        ));
+    };
+    let store = std::fs::read_to_string(&source).ok();
+    let catalog_path = repo_root.join(MOD_CATALOG);
+    let catalog = std::fs::read_to_string(&catalog_path)
+        .map_err(|e| format!("could not read {}: {e}", catalog_path.display()))?;
+    let cache_inherited = SANDBOXED_VARS[..2]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some());
+    check_mod_profile(name, store.as_deref(), &catalog, cache_inherited)?;
+    if !child_reads_it {
+        let target = root(run_dir).join("config").join(MOD_PROFILES_FILE);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        std::fs::copy(&source, &target).map_err(|e| {
+            format!(
+                "--mod-profile '{name}': could not copy {}: {e}",
+                source.display()
+            )
+        })?;
+    }
+    eprintln!(
+        "probe: profile sandbox: mod profile '{name}' from {}",
+        source.display()
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,4 +385,69 @@ mod tests {
         // All three: probe pushes nothing, the run is fully the operator's.
         assert!(env_with(run_dir, |_| true).is_empty());
     }
+
+    #[test]
+    fn mod_profile_env_names_the_profile_only_when_asked() {
+        assert!(mod_profile_env(None).is_empty());
+        assert_eq!(
+            get(&mod_profile_env(Some("sandbox")), MOD_PROFILE_ENV).as_deref(),
+            Some("sandbox")
+        );
+    }
+
+    const CATALOG: &str = r#"(mods: [(id: "base", bundle: "base/base.bundle.ron", base: true), (id: "example", bundle: "mods/example/example.bundle.ron")])"#;
+
+    const STORE: &str = r#"(active: None, profiles: [
+        (name: "shipped", enabled: ["base", "example"], order: []),
+        (name: "downloads", enabled: ["base", "fleet-pack"]),
+    ])"#;
+
+    #[test]
+    fn mod_profiles_are_found_where_the_game_looks() {
+        let env = |set: &'static [(&'static str, &'static str)]| {
+            move |var: &str| {
+                set.iter()
+                    .find(|(k, _)| *k == var)
+                    .map(|(_, v)| OsString::from(v))
+            }
+        };
+        assert_eq!(
+            mod_profiles_source(env(&[("HOME", "/h"), ("NOVA_CONFIG_ROOT", "/cfg")])),
+            Some((PathBuf::from("/cfg/mod_profiles.ron"), true)),
+            "the settings-store override wins, and the child reads it too"
+        );
+        assert_eq!(
+            mod_profiles_source(env(&[("HOME", "/h"), ("XDG_CONFIG_HOME", "/x")])),
+            Some((PathBuf::from("/x/nova-protocol/mod_profiles.ron"), true))
+        );
+        assert_eq!(
+            mod_profiles_source(env(&[("HOME", "/h")])),
+            Some((
+                PathBuf::from("/h/.config/nova-protocol/mod_profiles.ron"),
+                false
+            )),
+            "the desktop default is the one store the sandbox hides"
+        );
+        assert_eq!(mod_profiles_source(env(&[])), None);
+    }
+
+    #[test]
+    fn a_mod_profile_must_exist_and_be_mergeable() {
+        assert_eq!(
+            check_mod_profile("shipped", Some(STORE), CATALOG, false),
+            Ok(())
+        );
+        let unknown = check_mod_profile("sandbox", Some(STORE), CATALOG, false).unwrap_err();
+        assert!(unknown.contains("downloads, shipped"), "{unknown}");
+        let unsaved = check_mod_profile("sandbox", None, CATALOG, false).unwrap_err();
+        assert!(unsaved.contains("saved: none"), "{unsaved}");
+
+        let downloads = check_mod_profile("downloads", Some(STORE), CATALOG, false).unwrap_err();
+        assert!(downloads.contains("fleet-pack"), "{downloads}");
+        assert_eq!(
+            check_mod_profile("downloads", Some(STORE), CATALOG, true),
+            Ok(()),
+            "an inherited cache holds the operator's downloads"
+        );
+    }
 }
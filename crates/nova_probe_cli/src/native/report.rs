@@ -135,6 +135,8 @@ mod tests {
                 armed_timeline: false,
                 armed_invariants: false,
                 armed_fps: false,
+                armed_memory: false,
+                goldens_required: false,
                 passes: vec![PassRecord {
                     name: "clean".into(),
                     success: true,
@@ -1,5 +1,5 @@
-//! One SUBJECT through the harness passes: clean, declared frame time, traced,
-//! optional samply, then the run report.
+//! One SUBJECT through the harness passes: clean, golden shots, declared frame
+//! time, traced, optional samply, then the run report.
 //!
 //! The subject is a cataloged example, or - for `probe scenario` - the game
 //! binary pointed at a scenario. The passes are identical either way; only what
@@ -17,8 +17,9 @@ use super::{
     cli::{Platform, RunOptions},
     env::{
         clean_pass_env, fps_window_and_deadline_env, matrix_cells, render_env, samply_pass_env,
-        sweep_cell_env, trace_pass_env,
+        shots_pass_env, sweep_cell_env, trace_pass_env,
     },
+    goldens,
     paths::{default_output_root, repo_root, resolve_full_git_sha},
     supervise::{build_example, build_game, ensure_display, run_supervised, GAME_BIN},
     web::web_capture,
@@ -31,14 +32,16 @@ use crate::{
 /// at the start of a run so nothing stale (an old trace, a previous
 /// checks.json) can present as this run's evidence. NOTE: never a recursive
 /// wipe - the dir may be user-supplied.
-const RUN_ARTIFACTS: [&str; 12] = [
+const RUN_ARTIFACTS: [&str; 14] = [
     "timeline.jsonl",
     "probe-contract.json",
     "run.log",
+    "shots-run.log",
     "fps-run.log",
     "trace.json",
     "trace-run.log",
     "frametime.csv",
+    "memory.csv",
     "samply-profile.json.gz",
     "samply-run.log",
     "web-run.log",
@@ -68,12 +71,29 @@ fn stale_cell_logs(out: &Path) -> Vec<PathBuf> {
         .collect()
 }
 
+/// The frames under the run dir's `golden/` and `shots/`, and the staged
+/// `golden.json` - a previous run's shots must not grade as this run's.
+fn stale_frames(out: &Path) -> Vec<PathBuf> {
+    let mut paths = vec![out.join(GOLDEN_DIR).join(GOLDEN_CONFIG)];
+    for dir in [GOLDEN_DIR, SHOTS_DIR] {
+        let Ok(entries) = std::fs::read_dir(out.join(dir)) else {
+            continue;
+        };
+        paths.extend(
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "png")),
+        );
+    }
+    paths
+}
+
 fn clean_out_dir(out: &Path) -> Result<(), String> {
     let named = RUN_ARTIFACTS
         .iter()
         .map(|name| out.join(name))
         .chain(std::iter::once(out.join("probe-run.json")));
-    for path in named.chain(stale_cell_logs(out)) {
+    for path in named.chain(stale_cell_logs(out)).chain(stale_frames(out)) {
         if path.exists() {
             std::fs::remove_file(&path)
                 .map_err(|e| format!("could not clear stale {}: {e}", path.display()))?;
@@ -93,6 +113,7 @@ fn declares_frametime(out: &Path) -> bool {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum NativePass {
+    Shots,
     FrameTime,
     Profiled,
     Samply,
@@ -136,11 +157,17 @@ fn post_clean_passes(
     sweeping: bool,
     samply: bool,
     correctness_only: bool,
+    shots: bool,
 ) -> Vec<NativePass> {
+    let mut passes = Vec::with_capacity(4);
+    // The goldens are correctness evidence, so a correctness-only run still
+    // takes the shots; it drops only the measured passes.
+    if shots && !sweeping {
+        passes.push(NativePass::Shots);
+    }
     if correctness_only {
-        return Vec::new();
+        return passes;
     }
-    let mut passes = Vec::with_capacity(3);
     if frametime_declared && !sweeping {
         passes.push(NativePass::FrameTime);
     }
@@ -168,6 +195,12 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
     // itself rides in clean_pass_env and trace_pass_env; this creates the
     // dirs and reports any variable the operator kept for themselves.
     profile_sandbox::prepare(&out);
+    // NOTE: a profile lives in the operator's config store, which the sandbox
+    // just swapped out - carry the saved profiles across so the child can
+    // resolve the name it is handed.
+    if let Some(name) = &opts.mod_profile {
+        profile_sandbox::seed_mod_profiles(&root, &out, name)?;
+    }
     // NOTE: a bad baseline path must fail BEFORE minutes of build+run, and
     // it must actually parse.
     if let Some(baseline) = &opts.baseline {
@@ -202,6 +235,7 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
             passes,
             /*armed_native*/ false,
             /*armed_frametime*/ true,
+            /*goldens_required*/ false,
         );
     }
 
@@ -249,14 +283,21 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
         }
         if sweeping {
             // Sweep cells measure frames, not the recorder surfaces.
-            let (timeline, invariants) = (probe_env(TIMELINE_PARAM), probe_env(INVARIANTS_PARAM));
-            env.retain(|(k, _)| *k != timeline && *k != invariants);
+            let recorders = [
+                probe_env(TIMELINE_PARAM),
+                probe_env(INVARIANTS_PARAM),
+                probe_env(MEMORY_PARAM),
+            ];
+            env.retain(|(k, _)| !recorders.contains(k));
             // The per-example label yields to the sweep convention.
             let label_key = probe_env(LABEL_PARAM);
             env.retain(|(k, _)| *k != label_key);
         }
         env.extend(sweep_cell_env(scenario.as_deref(), preset.as_deref()));
         env.extend(render_env(opts.render, opts.norender));
+        env.extend(profile_sandbox::mod_profile_env(
+            opts.mod_profile.as_deref(),
+        ));
         let outcome = run_supervised(&bin, &args, &root, &env, &out.join(&log_name), timeout)?;
         if !outcome.success() {
             eprintln!("probe: {cell_name} did not succeed; the report will say so");
@@ -269,6 +310,20 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
     }
 
     let frametime_declared = declares_frametime(&out);
+    // A stills producer on the software backend is held to goldens
+    // whether or not any are committed; a catalog that will not load was
+    // already refused by spec resolution.
+    let goldens_required = opts.scenario_target.is_none()
+        && !opts.norender
+        && load_example_catalog(&root)
+            .is_ok_and(|catalog| goldens::required(&catalog, &opts.example, opts.render));
+    // Golden shots: only an example held to goldens on this backend (or one
+    // being blessed) pays for the extra run, and a headless run has no frames
+    // to take.
+    let shots = !opts.norender
+        && (opts.bless
+            || goldens_required
+            || goldens::committed(&root, &opts.example, opts.render));
 
     // Frame-time pass (declared, non-sweep): the dedicated capture-only run -
     // same binary as the clean pass, recorder/invariants OFF the frame
@@ -281,8 +336,40 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
         sweeping,
         opts.samply,
         opts.correctness_only,
+        shots,
     ) {
         let record = match pass {
+            NativePass::Shots => {
+                // Its own run: the capture producers write PNGs on the frame
+                // path, so the frames never ride the clean or measured passes.
+                let log_name = "shots-run.log";
+                eprintln!("probe: shots pass -> {}", out.join(SHOTS_DIR).display());
+                let mut env = shots_pass_env(&root, &out, &display);
+                env.extend(render_env(opts.render, opts.norender));
+                env.extend(profile_sandbox::mod_profile_env(
+                    opts.mod_profile.as_deref(),
+                ));
+                // Encoding frames to disk is slow under software rendering.
+                let outcome =
+                    run_supervised(&bin, &args, &root, &env, &out.join(log_name), timeout * 2)?;
+                if !outcome.success() {
+                    eprintln!("probe: shots pass did not succeed; the report will say so");
+                }
+                if opts.bless {
+                    let blessed = goldens::bless(&root, &opts.example, opts.render, &out)?;
+                    eprintln!(
+                        "probe: blessed {blessed} frame(s) as the {} goldens for {}",
+                        opts.render.as_str(),
+                        opts.example
+                    );
+                }
+                goldens::stage(&root, &opts.example, opts.render, &out)?;
+                PassRecord {
+                    name: "shots".into(),
+                    success: outcome.success(),
+                    timed_out: outcome.timed_out(),
+                }
+            }
             NativePass::FrameTime => {
                 // One capture, or a repeat set. Each repeat is a whole
                 // separate process: a within-process repeat would share the
@@ -315,6 +402,7 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
                         probe_env(TIMELINE_PARAM),
                         probe_env(INVARIANTS_PARAM),
                         probe_env(CONTRACT_PARAM),
+                        probe_env(MEMORY_PARAM),
                     ];
                     env.retain(|(k, _)| !clean_only.contains(k));
                     if let Some(label) = label {
@@ -331,6 +419,9 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
                     // AFTER the window env: the sw arm's short window is a
                     // renderer property and must win over the baseline one.
                     env.extend(render_env(opts.render, opts.norender));
+                    env.extend(profile_sandbox::mod_profile_env(
+                        opts.mod_profile.as_deref(),
+                    ));
                     // The supervisor timeout MUST exceed the in-process deadline,
                     // or probe kills the child before the deadline can complete or
                     // report; keep the operator's --timeout if it is larger.
@@ -377,6 +468,9 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
                         let trace_bin = subject_bin(&root, opts, "debug");
                         let mut env = trace_pass_env(&root, &out, &display);
                         env.extend(render_env(opts.render, opts.norender));
+                        env.extend(profile_sandbox::mod_profile_env(
+                            opts.mod_profile.as_deref(),
+                        ));
                         eprintln!("probe: traced run -> {}", out.join("trace.json").display());
                         // Tracing throttles the run hard; give it double time.
                         let outcome = run_supervised(
@@ -417,6 +511,9 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
                         let sbin = subject_bin(&root, opts, "profiling");
                         let mut samply_env = samply_pass_env(&root, &out, &display);
                         samply_env.extend(render_env(opts.render, opts.norender));
+                        samply_env.extend(profile_sandbox::mod_profile_env(
+                            opts.mod_profile.as_deref(),
+                        ));
                         let samply = Path::new("samply");
                         let profile_out = out.join("samply-profile.json.gz");
                         let mut samply_args = vec![
@@ -467,6 +564,7 @@ pub(crate) fn run(opts: &RunOptions) -> Result<ExitCode, String> {
         passes,
         /*armed_native*/ !sweeping,
         /*armed_frametime*/ frametime_declared,
+        goldens_required,
     )
 }
 
@@ -478,6 +576,7 @@ fn finish_report(
     passes: Vec<PassRecord>,
     armed_native: bool,
     armed_frametime: bool,
+    goldens_required: bool,
 ) -> Result<ExitCode, String> {
     let (git_sha, host) = run_identity();
     let full_git_sha = resolve_full_git_sha(&repo_root());
@@ -490,6 +589,8 @@ fn finish_report(
         armed_timeline: armed_native,
         armed_invariants: armed_native,
         armed_fps: armed_frametime,
+        armed_memory: armed_native,
+        goldens_required,
         passes,
     };
     std::fs::write(
@@ -540,8 +641,13 @@ mod tests {
             std::fs::write(out.join(name), "stale\n").unwrap();
         }
         std::fs::write(out.join("notes.txt"), "the operator's own file\n").unwrap();
+        std::fs::create_dir_all(out.join(SHOTS_DIR)).unwrap();
+        std::fs::write(out.join(SHOTS_DIR).join("hud.png"), "stale").unwrap();
+        std::fs::write(out.join(SHOTS_DIR).join("notes.txt"), "kept").unwrap();
 
         clean_out_dir(&out).unwrap();
+        assert!(!out.join(SHOTS_DIR).join("hud.png").exists());
+        assert!(out.join(SHOTS_DIR).join("notes.txt").is_file());
 
         for name in ["run-1.log", "run-12.log", "checks.json", "run.log"] {
             assert!(!out.join(name).exists(), "{name} survived the clean");
@@ -556,26 +662,33 @@ mod tests {
     #[test]
     fn default_passes_follow_the_runtime_contract() {
         assert_eq!(
-            post_clean_passes(false, false, false, false),
+            post_clean_passes(false, false, false, false, false),
             vec![NativePass::Profiled]
         );
         assert_eq!(
-            post_clean_passes(true, false, false, false),
+            post_clean_passes(true, false, false, false, false),
             vec![NativePass::FrameTime, NativePass::Profiled]
         );
         assert_eq!(
-            post_clean_passes(true, true, false, false),
-            vec![NativePass::Profiled]
+            post_clean_passes(true, true, false, false, true),
+            vec![NativePass::Profiled],
+            "a sweep takes no shots"
         );
         assert_eq!(
-            post_clean_passes(true, false, true, false),
+            post_clean_passes(true, false, true, false, true),
             vec![
+                NativePass::Shots,
                 NativePass::FrameTime,
                 NativePass::Profiled,
                 NativePass::Samply,
             ]
         );
-        assert!(post_clean_passes(true, false, false, true).is_empty());
+        assert_eq!(
+            post_clean_passes(true, false, false, true, true),
+            vec![NativePass::Shots],
+            "a correctness-only run still holds its frames to the goldens"
+        );
+        assert!(post_clean_passes(true, false, false, true, false).is_empty());
     }
 
     #[test]
@@ -11,11 +11,12 @@ use std::path::Path;
 use nova_probe::prelude::*;
 
 use super::{
-    escape, render_chart, render_fixed_steps, render_frame_read, render_refused_captures,
-    render_repeat_gate, render_table, STYLE,
+    escape, render_chart, render_fixed_steps, render_frame_read, render_golden_frames,
+    render_refused_captures, render_repeat_gate, render_table, render_timeline_diff, STYLE,
 };
 use crate::evaluation::{
-    checks::violations_by_name, measured_count, overall_verdict, Check, RunArtifacts,
+    checks::violations_by_name, diff_timelines, measured_count, overall_verdict, Check,
+    RunArtifacts, DEFAULT_SHIFT_TOLERANCE_SECS,
 };
 
 fn meaningful(timeline: &[TimelineEvent]) -> Vec<&TimelineEvent> {
@@ -182,7 +183,11 @@ pub fn render_run_report(dir: &Path, artifacts: &RunArtifacts, checks: &[Check])
         }
     }
 
-    // 4. Performance (the absorbed perf_report as a section).
+    // 4. Frames: the shots against their goldens, side by side.
+    html.push_str("<h2>Frames</h2>\n");
+    html.push_str(&render_golden_frames(artifacts.golden.as_ref()));
+
+    // 5. Performance (the absorbed perf_report as a section).
     html.push_str("<h2>Performance</h2>\n");
     // A capture the example never claimed is not a missing one. The contract
     // says which, so a reader does not chase a number this example was never
@@ -252,7 +257,31 @@ pub fn render_run_report(dir: &Path, artifacts: &RunArtifacts, checks: &[Check])
         }
     }
 
-    // 5. Profile (top-N; ranking only - see the module docs).
+    // The behavioural half of the baseline comparison, beside the frame-time
+    // half: a run that got faster by skipping an act is not an improvement.
+    if artifacts.baseline.is_some() || artifacts.baseline_timeline.is_some() {
+        html.push_str("<h3>Timeline against the baseline</h3>\n");
+        match (&artifacts.baseline_timeline, &artifacts.timeline) {
+            (Some(baseline), Some(timeline)) => {
+                match diff_timelines(baseline, timeline, DEFAULT_SHIFT_TOLERANCE_SECS) {
+                    Ok(diff) => html.push_str(&render_timeline_diff(&diff)),
+                    Err(reason) => {
+                        html.push_str(&format!("<p class=\"note\">{}</p>\n", escape(&reason)))
+                    }
+                }
+            }
+            (None, _) => html.push_str(
+                "<p class=\"note\">The baseline run recorded no timeline, so only its \
+                 frame times compare.</p>\n",
+            ),
+            (Some(_), None) => html.push_str(
+                "<p class=\"note\">This run recorded no timeline to align against the \
+                 baseline's.</p>\n",
+            ),
+        }
+    }
+
+    // 6. Profile (top-N; ranking only - see the module docs).
     html.push_str("<h2>Profile</h2>\n");
     match &artifacts.costs {
         None => html.push_str(
@@ -307,7 +336,7 @@ pub fn render_run_report(dir: &Path, artifacts: &RunArtifacts, checks: &[Check])
         }
     }
 
-    // 6. Log tail (collapsible).
+    // 7. Log tail (collapsible).
     html.push_str("<h2>Log</h2>\n");
     match &artifacts.log {
         None => html.push_str("<p>No run.log captured.</p>\n"),
@@ -323,7 +352,7 @@ pub fn render_run_report(dir: &Path, artifacts: &RunArtifacts, checks: &[Check])
         }
     }
 
-    // 7. Reviewer checklist.
+    // 8. Reviewer checklist.
     html.push_str(
         "<h2>What to check (reviewer)</h2>\n<ol class=\"checklist\">\n\
          <li>Does the verdict banner match your reading of the rows? A SKIPPED \
@@ -337,10 +366,19 @@ pub fn render_run_report(dir: &Path, artifacts: &RunArtifacts, checks: &[Check])
          baseline).</li>\n\
          <li>If <code>fps_within_baseline</code> is WARN: was the host quiet? Is the \
          delta consistent across labels, or one noisy row?</li>\n\
+         <li>If <code>memory_within_baseline</code> is WARN: open memory.csv. A \
+         gauge that climbs sample after sample is a leak; one that peaks higher \
+         and settles is load order.</li>\n\
          <li>Scan the timeline: do the script beats and scenario events tell the \
-         story this run was supposed to tell? Anything unexpected between them?</li>\n\
+         story this run was supposed to tell? Anything unexpected between them? \
+         Against a baseline, read every MISSING and REORDERED row in the timeline \
+         diff as a behaviour change until the commit range explains it.</li>\n\
          <li>If invariants fired: which name, which frame - open timeline.jsonl \
          at that frame for the surrounding events.</li>\n\
+         <li>If <code>frames_match_golden</code> is WARN or FAIL: look where the \
+         heatmap is hot. A counter or a particle burst is noise to mask in \
+         <code>golden.json</code>; a hull, a panel or a missing ship is the \
+         regression. Re-bless only once the new picture is the right one.</li>\n\
          <li>If a system jumped in the profile table: open trace.json in Perfetto \
          (and the samply profile if captured) before concluding.</li>\n\
          </ol>\n\
@@ -373,6 +411,8 @@ mod tests {
             "<h2>Run summary</h2>",
             "<h2>Correctness</h2>",
             "onupdate pulses collapsed",
+            "<h2>Frames</h2>",
+            "No golden frames were staged",
             "<h2>Performance</h2>",
             // The frame read leads the section, and says it grades nothing.
             "FPS worst frame",
@@ -390,6 +430,40 @@ mod tests {
         assert!(!html.contains("src=\"http"));
     }
 
+    #[test]
+    fn a_baseline_timeline_renders_its_divergences_beside_the_frame_times() {
+        let beat = |name: &str, at: f64| TimelineEvent {
+            t_real: at,
+            frame: 0,
+            scenario_elapsed: Some(at),
+            kind: "marker".into(),
+            name: name.into(),
+            data: serde_json::Value::Null,
+        };
+        let artifacts = RunArtifacts {
+            timeline: Some(vec![beat("beat: launch", 1.0), beat("beat: <dock>", 9.0)]),
+            baseline_timeline: Some(vec![beat("beat: launch", 1.0), beat("beat: jump", 5.0)]),
+            manifest: Some(manifest_ok()),
+            ..Default::default()
+        };
+        let checks = evaluate_checks(&artifacts);
+        let html = render_run_report(Path::new("probe-runs/editor"), &artifacts, &checks);
+        let section = html
+            .split("<h3>Timeline against the baseline</h3>")
+            .nth(1)
+            .expect("the diff renders when a baseline timeline is loaded");
+        assert!(
+            section.find("<h2>Profile</h2>") > section.find("missing"),
+            "the diff sits in Performance, before Profile"
+        );
+        assert!(section.contains("<code>beat: jump</code>"));
+        assert!(
+            section.contains("<code>beat: &lt;dock&gt;</code>"),
+            "labels are escaped"
+        );
+        assert!(section.contains("1 added, 1 missing, 0 reordered, 0 shifted"));
+    }
+
     #[test]
     fn an_unclaimed_frame_time_renders_an_honest_note_not_a_missing_capture() {
         // No frametime.csv (runs = None) AND a contract that declares no
@@ -1,7 +1,8 @@
 //! The last stage of the pipeline: what [`crate::evaluation`] decided, made
 //! readable. One self-contained file per run ([`html`]) plus the index over
 //! many runs ([`aggregate`]) - inline CSS and inline SVG, no external assets,
-//! so a report opens offline.
+//! so a report opens offline. The one exception is the golden frames, which
+//! are PNGs staged in the run dir beside the report and linked relatively.
 //!
 //! This module root holds the pieces both renderers share (styles, the
 //! frame-time chart and table) over parsed [`PerfRun`]s; the standalone FPS
@@ -19,7 +20,7 @@ use std::collections::HashMap;
 
 use nova_probe::prelude::*;
 
-use crate::evaluation::frames::prelude::*;
+use crate::evaluation::{frames::prelude::*, golden::prelude::*, timeline_diff::prelude::*};
 
 /// Glob-import surface for both renderers.
 pub mod prelude {
@@ -298,6 +299,161 @@ pub(crate) fn render_fixed_steps(log: &str) -> String {
     html
 }
 
+/// The GOLDEN read: each shot beside its committed frame, and the captured
+/// frame again under its dissimilarity heatmap, so a failing row points at
+/// the part of the picture that moved. The images are the run dir's own
+/// staged copies (`golden/`, `shots/`), referenced relatively, so the report
+/// still opens offline from the dir it was written into.
+pub(crate) fn render_golden_frames(golden: Option<&GoldenRun>) -> String {
+    let Some(golden) = golden else {
+        return "<p>No golden frames were staged for this run. A <code>screenshot_*</code> \
+                producer on the software backend fails without them; \
+                <code>probe run &lt;example&gt; --render sw --bless</code> records \
+                them for the software floor CI renders on.</p>\n"
+            .into();
+    };
+    let mut html = format!(
+        "<p>Each shot beside its golden, then the shot under its heatmap: red is \
+         1 - SSIM per region (scaled x{HEATMAP_GAIN} so a faint change still shows), \
+         grey is masked by <code>golden.json</code>. FAIL below {}, WARN below {}.</p>\n",
+        golden.config.fail_below, golden.config.warn_below,
+    );
+    for diff in &golden.diffs {
+        let shot = escape(&diff.shot);
+        html.push_str(&format!("<h3><code>{shot}</code></h3>\n"));
+        let frame = match &diff.outcome {
+            GoldenOutcome::NotCaptured => {
+                html.push_str(&format!(
+                    "<p class=\"note\">Not captured: this run wrote no \
+                     <code>shots/{shot}</code>.</p>\n"
+                ));
+                continue;
+            }
+            GoldenOutcome::Unreadable(reason) => {
+                html.push_str(&format!(
+                    "<p class=\"note\">Unreadable: {}</p>\n",
+                    escape(reason)
+                ));
+                continue;
+            }
+            GoldenOutcome::SizeMismatch { golden, captured } => {
+                html.push_str(&format!(
+                    "<p class=\"note\">Captured at {}x{} against a {}x{} golden; \
+                     not compared.</p>\n",
+                    captured.0, captured.1, golden.0, golden.1
+                ));
+                continue;
+            }
+            GoldenOutcome::Compared(frame) => frame,
+        };
+        html.push_str(&format!(
+            "<p class=\"meta\">SSIM {:.4} over {} tile(s); {:.0}% of the frame masked.</p>\n\
+             <div class=\"golden\">\
+             <figure><img src=\"golden/{shot}\" alt=\"golden {shot}\"><figcaption>golden</figcaption></figure>\
+             <figure><img src=\"shots/{shot}\" alt=\"captured {shot}\"><figcaption>this run</figcaption></figure>\
+             <figure><div class=\"overlay\"><img src=\"shots/{shot}\" alt=\"\">{}</div>\
+             <figcaption>heatmap</figcaption></figure>\
+             </div>\n",
+            frame.ssim,
+            frame.windows,
+            frame.masked_share * 100.0,
+            render_heatmap(&frame.heatmap),
+        ));
+    }
+    if !golden.unblessed.is_empty() {
+        let names: Vec<String> = golden
+            .unblessed
+            .iter()
+            .map(|name| format!("<code>{}</code>", escape(name)))
+            .collect();
+        html.push_str(&format!(
+            "<p class=\"note\">Captured with no golden, so graded against nothing: {}. \
+             <code>--bless</code> records them.</p>\n",
+            names.join(", ")
+        ));
+    }
+    html
+}
+
+/// The TIMELINE read against a baseline: every divergence the alignment found,
+/// in story order, with its time on both sides. Rendered beside the frame-time
+/// comparison in a run report and on its own by `probe diff`; like the frame
+/// read it grades nothing - a new event is a question for the reviewer, not a
+/// failed check.
+pub(crate) fn render_timeline_diff(diff: &TimelineDiff) -> String {
+    let mut html = format!(
+        "<p class=\"meta\">{} - {} entries aligned.</p>\n",
+        escape(&diff.summary()),
+        diff.matched,
+    );
+    if diff.divergences.is_empty() {
+        html.push_str(
+            "<p>The two runs tell the same story: every state transition, scenario \
+             event, objective and script beat lines up, in order and within tolerance.</p>\n",
+        );
+        return html;
+    }
+    html.push_str(
+        "<table>\n<thead><tr><th>divergence</th><th>kind</th><th>entry</th>\
+         <th>baseline t</th><th>this run t</th></tr></thead>\n<tbody>\n",
+    );
+    let at = |t: Option<f64>| t.map_or_else(|| "-".into(), |t| format!("{t:.2}"));
+    for divergence in &diff.divergences {
+        let class = match divergence.kind {
+            DivergenceKind::Missing => "fail",
+            DivergenceKind::Added | DivergenceKind::Reordered => "warn",
+            DivergenceKind::Shifted => "unknown",
+        };
+        html.push_str(&format!(
+            "<tr><td class=\"status-{class}\">{}</td><td>{}</td><td><code>{}</code></td>\
+             <td class=\"num\">{}</td><td class=\"num\">{}</td></tr>\n",
+            divergence.kind.as_str(),
+            escape(&divergence.entry_kind),
+            escape(&divergence.label),
+            at(divergence.baseline_at),
+            at(divergence.current_at),
+        ));
+    }
+    html.push_str(
+        "</tbody>\n</table>\n<p class=\"note\">Times are scenario clock where a \
+         scenario was live, else seconds since run start; a time on one clock is never \
+         compared with the other. REORDERED is one entry at a different place in the \
+         story, not one lost and one gained. Reported, not graded.</p>\n",
+    );
+    html
+}
+
+/// How much the heatmap amplifies dissimilarity before it saturates: a 0.05
+/// drop in SSIM is already a visible regression and should not render as a
+/// faint tint.
+const HEATMAP_GAIN: f64 = 5.0;
+
+/// The heatmap as inline SVG in cell units, stretched over the frame it
+/// describes. Cells under the noise floor draw nothing.
+fn render_heatmap(heatmap: &Heatmap) -> String {
+    let mut svg = format!(
+        "<svg class=\"heatmap\" viewBox=\"0 0 {} {}\" preserveAspectRatio=\"none\" \
+         role=\"img\" aria-label=\"dissimilarity heatmap\">",
+        heatmap.cols, heatmap.rows
+    );
+    for (i, cell) in heatmap.cells.iter().enumerate() {
+        let (x, y) = (i % heatmap.cols, i / heatmap.cols);
+        match cell {
+            None => svg.push_str(&format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"1\" height=\"1\" class=\"masked\"/>"
+            )),
+            Some(value) if value * HEATMAP_GAIN >= 0.02 => svg.push_str(&format!(
+                "<rect x=\"{x}\" y=\"{y}\" width=\"1\" height=\"1\" class=\"hot\" \
+                 fill-opacity=\"{:.2}\"/>",
+                (value * HEATMAP_GAIN).min(1.0)
+            )),
+            Some(_) => {}
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
 /// Horizontal bar chart: one row per run, bar length = mean frame time, a tick
 /// at p99, all runs on one common scale (the largest p99/max across runs), plus
 /// a dashed 16.6 ms budget line. Pure inline SVG - no script, no external lib.
@@ -524,6 +680,14 @@ td.status-unknown { color: #999; }
 .profile { font-size: 0.75em; padding: 0.05rem 0.3rem; border-radius: 3px; }
 .profile.dev { background: #fff3d6; color: #7a5b00; }
 .profile.release { background: #e3f4e6; color: #0b6623; }
+.golden { display: grid; grid-template-columns: repeat(3, 1fr); gap: 0.5rem; }
+.golden figure { margin: 0; }
+.golden img { width: 100%; height: auto; display: block; border-radius: 3px; }
+.golden figcaption { font-size: 0.8rem; color: #666; }
+.golden .overlay { position: relative; }
+.golden .heatmap { position: absolute; inset: 0; width: 100%; height: 100%; }
+.golden .heatmap .hot { fill: #ff1a3c; }
+.golden .heatmap .masked { fill: #888; fill-opacity: 0.45; }
 details { margin: 0.6rem 0; }
 details summary { cursor: pointer; color: #555; }
 .checklist li { margin: 0.3rem 0; }
@@ -717,4 +881,43 @@ reason=simulation_stopped phase=capture frame=345 warmup=60 frames=360 - stopped
         assert!(delta_cell(None, 90.0).contains("&mdash;"));
         assert!(delta_cell(Some(0.0), 90.0).contains("&mdash;"));
     }
+
+    /// A compared shot renders side by side with its heatmap, linking the run
+    /// dir's own staged copies; a masked cell and a hot one draw differently.
+    #[test]
+    fn golden_frames_render_side_by_side_with_a_heatmap() {
+        let mut cells = vec![Some(0.0); 4];
+        cells[1] = None;
+        cells[2] = Some(0.5);
+        let golden = GoldenRun {
+            config: GoldenConfig::default(),
+            diffs: vec![
+                GoldenDiff {
+                    shot: "wiki-hud.png".into(),
+                    outcome: GoldenOutcome::Compared(FrameDiff {
+                        ssim: 0.9,
+                        windows: 3,
+                        masked_share: 0.25,
+                        heatmap: Heatmap {
+                            cols: 2,
+                            rows: 2,
+                            cells,
+                        },
+                    }),
+                },
+                GoldenDiff {
+                    shot: "gone.png".into(),
+                    outcome: GoldenOutcome::NotCaptured,
+                },
+            ],
+            unblessed: vec!["new.png".into()],
+        };
+        let html = render_golden_frames(Some(&golden));
+        assert!(html.contains("src=\"golden/wiki-hud.png\""), "{html}");
+        assert!(html.contains("src=\"shots/wiki-hud.png\""), "{html}");
+        assert_eq!(html.matches("class=\"masked\"").count(), 1, "{html}");
+        assert_eq!(html.matches("class=\"hot\"").count(), 1, "{html}");
+        assert!(html.contains("Not captured"), "{html}");
+        assert!(html.contains("<code>new.png</code>"), "{html}");
+    }
 }
@@ -30,7 +30,7 @@ use nova_scenario::prelude::*;
 
 /// Every non-`OnUpdate` event kind, so the synthetic scenario can pad itself
 /// with handlers that the `OnUpdate` frame must scan past but never name-match.
-const OTHER_EVENTS: [EventConfig; 15] = [
+const OTHER_EVENTS: [EventConfig; 20] = [
     EventConfig::OnStart,
     EventConfig::OnDefeated,
     EventConfig::OnDestroyed,
@@ -38,6 +38,7 @@ const OTHER_EVENTS: [EventConfig; 15] = [
     EventConfig::OnTimerEnd,
     EventConfig::OnEnter,
     EventConfig::OnExit,
+    EventConfig::OnMined,
     EventConfig::OnOrbitStart,
     EventConfig::OnOrbitStable,
     EventConfig::OnOrbitUnstable,
@@ -46,6 +47,10 @@ const OTHER_EVENTS: [EventConfig; 15] = [
     EventConfig::OnTravelLockEnd,
     EventConfig::OnCombatLockStart,
     EventConfig::OnCombatLockEnd,
+    EventConfig::OnDocked,
+    EventConfig::OnUndocked,
+    EventConfig::OnCourseFinished,
+    EventConfig::OnCameraTrackEnd,
 ];
 
 /// A representative per-frame expression filter: `progress > 0.5`. This is the
@@ -0,0 +1,781 @@
+//! Cinematic camera tracks: authored, keyframed camera moves a beat plays on
+//! the scenario camera - intros, finales, fly-bys - eased off the player's
+//! camera and back onto it, framed by letterbox bars and skippable with the
+//! scenario-advance key.
+//!
+//! A track is a list of keys, each a time, a position, a look-at point and an
+//! optional field of view. Position and look-at run on Catmull-Rom splines
+//! through their keys, so a handful of keys reads as one continuous move, and
+//! either can ride a scenario object by id. The track owns the frame through
+//! [`CameraAuthoritySystems::Override`]: the chase rig keeps solving
+//! underneath (order, don't disable), which is what the blends ease from and
+//! back onto with the `camera::handback` curve.
+//!
+//! `OnCameraTrackEnd` fires once per track - when its keys run out or the
+//! player skips it - so the next beat chains off it while the camera is still
+//! easing home.
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use nova_events::prelude::*;
+use nova_gameplay::prelude::*;
+use nova_ship::prelude::*;
+
+use crate::prelude::*;
+
+/// Where a track key puts the camera or its aim.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CameraTrackPointConfig {
+    /// A fixed world-space point.
+    World(Vec3),
+    /// A point riding the scenario object whose id matches, re-read every
+    /// frame so the shot follows it. An object that is gone holds the last
+    /// place it was seen.
+    Entity {
+        /// Scenario id of the object to follow.
+        id: String,
+        /// Offset from the object's origin, on the WORLD axes (the shot does
+        /// not roll with a tumbling hull).
+        #[cfg_attr(feature = "serde", serde(default))]
+        offset: Vec3,
+    },
+}
+
+/// One key of a camera track.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraTrackKeyConfig {
+    /// Seconds from the start of the track.
+    pub time: f32,
+    /// Where the camera is.
+    pub position: CameraTrackPointConfig,
+    /// What it looks at (up is +Y).
+    pub look_at: CameraTrackPointConfig,
+    /// Vertical field of view, degrees. Omit to keep the camera's own.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fov: Option<f32>,
+}
+
+/// Play a keyframed camera track on the scenario camera (the
+/// [`ScenarioCameraMarker`] entity). The camera eases from whatever was
+/// driving it onto the track over `blend_in`, follows the splined keys, and
+/// once the last key's time passes fires `OnCameraTrackEnd` with this `id`
+/// and eases back over `blend_out`. The scenario-advance key skips a
+/// `skippable` track: the end event fires (`skipped: true`) and the camera
+/// eases home from where it was. The skip spends the press, so a queued
+/// `NextScenario` waits for the next one.
+///
+/// Starting a track while another plays replaces it without an end event for
+/// the first. A no-op with a warning when no scenario camera is present, or
+/// the track has no keys.
+///
+/// RON: `PlayCameraTrack((id: "intro", keys: [(time: 0.0, position: World((0.0,
+/// 40.0, 120.0)), look_at: Entity(id: "station")), (time: 6.0, position:
+/// Entity(id: "player", offset: (0.0, 8.0, 30.0)), look_at: Entity(id:
+/// "player"))], blend_out: 1.5, letterbox: 0.1))`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayCameraTrackActionConfig {
+    /// The track's id, carried by its `OnCameraTrackEnd`.
+    pub id: String,
+    /// The keys, by time. Authored order does not matter; they are sorted.
+    pub keys: Vec<CameraTrackKeyConfig>,
+    /// Seconds to ease in from the live camera. Omit (0) to cut.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blend_in: f32,
+    /// Seconds to ease back onto the live camera after the end. Omit (0) to
+    /// cut.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blend_out: f32,
+    /// Height of each letterbox bar, as a share of the screen (0.1 is a
+    /// classic widescreen crop). Omit (0) for none. The bars ride the blends.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub letterbox: f32,
+    /// Whether the scenario-advance key cuts the track short. Omit for `true`.
+    #[cfg_attr(feature = "serde", serde(default = "default_skippable"))]
+    pub skippable: bool,
+}
+
+/// Serde default for [`PlayCameraTrackActionConfig::skippable`]: a track with
+/// the field omitted can be skipped - sitting through a cutscene twice is the
+/// player's choice, not the author's.
+#[cfg(feature = "serde")]
+fn default_skippable() -> bool {
+    true
+}
+
+/// The tallest a letterbox bar may be, as a share of the screen: past half
+/// the bars meet. Taller asks are clamped (and linted).
+pub const CAMERA_TRACK_MAX_LETTERBOX: f32 = 0.5;
+
+impl PlayCameraTrackActionConfig {
+    /// The track's running time: the last key's time.
+    pub fn duration(&self) -> f32 {
+        self.keys.iter().map(|key| key.time).fold(0.0, f32::max)
+    }
+}
+
+impl EventAction<NovaEventWorld> for PlayCameraTrackActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let mut track = self.clone();
+        track.keys.sort_by(|a, b| a.time.total_cmp(&b.time));
+        debug!(
+            "PlayCameraTrack: '{}' ({} keys, {:.1}s)",
+            track.id,
+            track.keys.len(),
+            track.duration()
+        );
+
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                if track.keys.is_empty() {
+                    warn!(
+                        "PlayCameraTrack: '{}' has no keys; nothing to play",
+                        track.id
+                    );
+                    return;
+                }
+                let camera = {
+                    let mut query = world.query_filtered::<Entity, With<ScenarioCameraMarker>>();
+                    query.iter(world).next()
+                };
+                let Some(camera) = camera else {
+                    warn!("PlayCameraTrack: no scenario camera present; nothing to play");
+                    return;
+                };
+
+                if let Ok(mut entity) = world.get_entity_mut(camera) {
+                    // A track replacing a track keeps the FIRST one's base
+                    // FOV: the live projection is mid-track, not the
+                    // camera's own.
+                    let base_fov = match entity.get::<CameraTrackPlayback>() {
+                        Some(playing) => playing.base_fov,
+                        None => match entity.get::<Projection>() {
+                            Some(Projection::Perspective(perspective)) => Some(perspective.fov),
+                            _ => None,
+                        },
+                    };
+                    entity.insert(CameraTrackPlayback::new(track, base_fov));
+                }
+            });
+        });
+    }
+}
+
+/// A camera track playing on the scenario camera, from the first blend-in
+/// frame to the last blend-out one. Inserted by
+/// [`PlayCameraTrackActionConfig`]; ticked by `tick_camera_tracks`, which
+/// removes it once the camera is home.
+#[derive(Component, Debug, Clone)]
+pub struct CameraTrackPlayback {
+    track: PlayCameraTrackActionConfig,
+    /// Seconds into the track. Frozen under pause.
+    elapsed: f32,
+    /// The camera's field of view before the track, radians; `None` on a
+    /// non-perspective camera, whose projection the track leaves alone.
+    base_fov: Option<f32>,
+    /// Last place each followed object was seen.
+    anchors: HashMap<String, Vec3>,
+    /// The pose and field of view the track last put on screen.
+    shown: Option<(Transform, Option<f32>)>,
+    /// Set once the keys ran out or the player skipped: easing home.
+    handback: Option<TrackHandback>,
+}
+
+/// The blend back onto the live camera, from the pose the track held.
+#[derive(Debug, Clone, Copy)]
+struct TrackHandback {
+    from: Transform,
+    from_fov: Option<f32>,
+    elapsed: f32,
+}
+
+/// A key with its points resolved for this frame. The field of view is in
+/// radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TrackSample {
+    position: Vec3,
+    look_at: Vec3,
+    fov: f32,
+}
+
+impl CameraTrackPlayback {
+    pub(crate) fn new(track: PlayCameraTrackActionConfig, base_fov: Option<f32>) -> Self {
+        Self {
+            track,
+            elapsed: 0.0,
+            base_fov,
+            anchors: HashMap::default(),
+            shown: None,
+            handback: None,
+        }
+    }
+
+    /// The playing track's id.
+    pub fn id(&self) -> &str {
+        &self.track.id
+    }
+
+    /// Whether the keys are done and the camera is easing home.
+    pub fn is_handing_back(&self) -> bool {
+        self.handback.is_some()
+    }
+
+    /// Re-read every followed object's position.
+    fn follow<'a>(&mut self, objects: impl IntoIterator<Item = (&'a EntityId, &'a Transform)>) {
+        for (id, transform) in objects {
+            if self.follows(&id.0) {
+                self.anchors.insert(id.0.clone(), transform.translation);
+            }
+        }
+    }
+
+    /// Whether any key rides the object `id`.
+    fn follows(&self, id: &str) -> bool {
+        self.track
+            .keys
+            .iter()
+            .flat_map(|key| [&key.position, &key.look_at])
+            .any(|point| {
+                matches!(point, CameraTrackPointConfig::Entity { id: followed, .. } if followed == id)
+            })
+    }
+
+    fn resolve(&self, point: &CameraTrackPointConfig) -> Vec3 {
+        match point {
+            CameraTrackPointConfig::World(position) => *position,
+            CameraTrackPointConfig::Entity { id, offset } => {
+                let Some(anchor) = self.anchors.get(id) else {
+                    warn_once!(
+                        "PlayCameraTrack: '{}' follows id '{}', which was never seen; \
+                         using the origin",
+                        self.track.id,
+                        id
+                    );
+                    return *offset;
+                };
+                *anchor + *offset
+            }
+        }
+    }
+
+    /// The track's shot at its current time.
+    fn sample(&self) -> TrackSample {
+        let base_fov = self.base_fov.unwrap_or_default();
+        let keys: Vec<(f32, TrackSample)> = self
+            .track
+            .keys
+            .iter()
+            .map(|key| {
+                (
+                    key.time,
+                    TrackSample {
+                        position: self.resolve(&key.position),
+                        look_at: self.resolve(&key.look_at),
+                        fov: key.fov.map_or(base_fov, f32::to_radians),
+                    },
+                )
+            })
+            .collect();
+        sample_keys(&keys, self.elapsed)
+    }
+
+    /// The frame's pose and field of view over the `solved` live camera.
+    fn frame(&mut self, solved: &Transform) -> (Transform, Option<f32>) {
+        let (pose, fov) = match self.handback {
+            Some(handback) => {
+                let weight = handback_ease(handback.elapsed, self.track.blend_out);
+                let fov = match (handback.from_fov, self.base_fov) {
+                    (Some(from), Some(base)) => Some(from + (base - from) * weight),
+                    _ => None,
+                };
+                (blend_camera_pose(&handback.from, solved, weight), fov)
+            }
+            None => {
+                let sample = self.sample();
+                let weight = handback_ease(self.elapsed, self.track.blend_in);
+                let shot = shot_transform(&sample);
+                let fov = self
+                    .base_fov
+                    .map(|base| base + (sample.fov - base) * weight);
+                (blend_camera_pose(solved, &shot, weight), fov)
+            }
+        };
+        self.shown = Some((pose, fov));
+        (pose, fov)
+    }
+
+    /// End the track: ease home from what is on screen. Returns the id for
+    /// the end event.
+    fn hand_back(&mut self) -> String {
+        let (from, from_fov) = self.shown.unwrap_or_else(|| {
+            // Ended before a frame was ever shown (a zero-length track):
+            // hand back from the track's own end.
+            let sample = self.sample();
+            (shot_transform(&sample), self.base_fov.map(|_| sample.fov))
+        });
+        self.handback = Some(TrackHandback {
+            from,
+            from_fov,
+            elapsed: 0.0,
+        });
+        self.track.id.clone()
+    }
+
+    /// How much of the screen each letterbox bar covers right now.
+    fn letterbox(&self) -> f32 {
+        let weight = match self.handback {
+            Some(handback) => 1.0 - handback_ease(handback.elapsed, self.track.blend_out),
+            None => handback_ease(self.elapsed, self.track.blend_in),
+        };
+        self.track.letterbox.clamp(0.0, CAMERA_TRACK_MAX_LETTERBOX) * weight
+    }
+}
+
+fn shot_transform(sample: &TrackSample) -> Transform {
+    Transform::from_translation(sample.position).looking_at(sample.look_at, Vec3::Y)
+}
+
+/// Uniform Catmull-Rom between `p1` and `p2` at `u`, shaped by the neighbours
+/// `p0` and `p3`. Passes through every key, which is what an author expects of
+/// a key.
+fn catmull_rom<T>(p0: T, p1: T, p2: T, p3: T, u: f32) -> T
+where
+    T: Copy
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<f32, Output = T>,
+{
+    let u2 = u * u;
+    let u3 = u2 * u;
+    (p1 * 2.0
+        + (p2 - p0) * u
+        + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * u2
+        + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * u3)
+        * 0.5
+}
+
+/// The shot `elapsed` seconds in, over time-sorted keys: held on the first
+/// key before it and on the last after it, splined between. End segments
+/// repeat their end key as the missing neighbour. Pure for unit testing.
+fn sample_keys(keys: &[(f32, TrackSample)], elapsed: f32) -> TrackSample {
+    let last = keys.len() - 1;
+    let Some(i) = keys.iter().rposition(|(time, _)| *time <= elapsed) else {
+        return keys[0].1;
+    };
+    if i == last {
+        return keys[last].1;
+    }
+    let (t1, k1) = keys[i];
+    let (t2, k2) = keys[i + 1];
+    let k0 = keys[i.saturating_sub(1)].1;
+    let k3 = keys[(i + 2).min(last)].1;
+    let span = t2 - t1;
+    let u = if span > 0.0 {
+        ((elapsed - t1) / span).clamp(0.0, 1.0)
+    } else {
+        1.0
+    };
+    TrackSample {
+        position: catmull_rom(k0.position, k1.position, k2.position, k3.position, u),
+        look_at: catmull_rom(k0.look_at, k1.look_at, k2.look_at, k3.look_at, u),
+        fov: catmull_rom(k0.fov, k1.fov, k2.fov, k3.fov, u),
+    }
+}
+
+/// Advance every playing track. A track whose keys ran out fires
+/// `OnCameraTrackEnd` and starts easing home; one that is home is removed,
+/// its camera's field of view restored. Registered live-and-unpaused, so a
+/// cutscene holds its frame behind the pause menu.
+pub(crate) fn tick_camera_tracks(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q_camera: Query<(Entity, &mut CameraTrackPlayback, Option<&mut Projection>)>,
+) {
+    let dt = time.delta_secs();
+    for (camera, mut playback, projection) in &mut q_camera {
+        let playback = &mut *playback;
+        if let Some(handback) = &mut playback.handback {
+            handback.elapsed += dt;
+            if handback.elapsed < playback.track.blend_out {
+                continue;
+            }
+            if let (Some(base), Some(mut projection)) = (playback.base_fov, projection) {
+                if let Projection::Perspective(perspective) = &mut *projection {
+                    perspective.fov = base;
+                }
+            }
+            commands.entity(camera).remove::<CameraTrackPlayback>();
+            continue;
+        }
+        playback.elapsed += dt;
+        if playback.elapsed >= playback.track.duration() {
+            let id = playback.hand_back();
+            debug!("PlayCameraTrack: '{}' ended", id);
+            commands
+                .fire::<OnCameraTrackEndEvent>(OnCameraTrackEndEventInfo { id, skipped: false });
+        }
+    }
+}
+
+/// Put every playing track's frame on its camera. Runs in
+/// [`CameraAuthoritySystems::Override`] after the scripted pose, so the
+/// `Transform` it reads is the frame's solved camera - chase, free-fly or a
+/// `SetCamera` still - and a track eases off and back onto exactly that.
+/// Followed objects are roots: their `Transform` is this frame's, where a
+/// `GlobalTransform` would trail a ship at speed by a frame.
+pub(crate) fn apply_camera_tracks(
+    mut q_camera: Query<(
+        &mut Transform,
+        &mut CameraTrackPlayback,
+        Option<&mut Projection>,
+    )>,
+    q_objects: Query<
+        (&EntityId, &Transform),
+        (
+            With<ScenarioScopedMarker>,
+            Without<ChildOf>,
+            Without<CameraTrackPlayback>,
+        ),
+    >,
+) {
+    for (mut transform, mut playback, projection) in &mut q_camera {
+        playback.follow(&q_objects);
+        let (pose, fov) = playback.frame(&transform);
+        *transform = pose;
+        if let (Some(fov), Some(mut projection)) = (fov, projection) {
+            if let Projection::Perspective(perspective) = &mut *projection {
+                if perspective.fov != fov {
+                    perspective.fov = fov;
+                }
+            }
+        }
+    }
+}
+
+/// The scenario-advance key skips a skippable track: the end event fires at
+/// once and the camera eases home from where it was. A track already easing
+/// home has nothing left to skip. True when one was skipped - the press is
+/// spent, so `on_next_input` does not release a queued `NextScenario` on it.
+pub(crate) fn skip_camera_tracks(
+    commands: &mut Commands,
+    q_camera: &mut Query<&mut CameraTrackPlayback>,
+) -> bool {
+    let mut skipped = false;
+    for mut playback in q_camera.iter_mut() {
+        if !playback.track.skippable || playback.is_handing_back() {
+            continue;
+        }
+        let id = playback.hand_back();
+        debug!("PlayCameraTrack: '{}' skipped", id);
+        commands.fire::<OnCameraTrackEndEvent>(OnCameraTrackEndEventInfo { id, skipped: true });
+        skipped = true;
+    }
+    skipped
+}
+
+/// Marker for the letterbox layer root.
+#[derive(Component, Debug, Clone)]
+pub struct CameraTrackLetterboxMarker;
+
+/// Marker for one letterbox bar.
+#[derive(Component, Debug, Clone)]
+struct CameraTrackLetterboxBar;
+
+/// Keep the letterbox bars as tall as the playing tracks ask, and gone when
+/// none do. Above the HUD, under the outcome and pause overlays.
+pub(crate) fn sync_camera_track_letterbox(
+    mut commands: Commands,
+    q_playback: Query<&CameraTrackPlayback>,
+    q_layer: Query<Entity, With<CameraTrackLetterboxMarker>>,
+    mut q_bars: Query<&mut Node, With<CameraTrackLetterboxBar>>,
+) {
+    let cover = q_playback
+        .iter()
+        .map(CameraTrackPlayback::letterbox)
+        .fold(0.0, f32::max);
+    if cover <= 0.0 {
+        for layer in &q_layer {
+            commands.entity(layer).despawn();
+        }
+        return;
+    }
+    if q_layer.is_empty() {
+        commands.spawn(letterbox_layer(cover));
+        return;
+    }
+    for mut node in &mut q_bars {
+        node.height = Val::Percent(cover * 100.0);
+    }
+}
+
+fn letterbox_layer(cover: f32) -> impl Bundle {
+    let bar = |name: &'static str, top: bool| {
+        (
+            Name::new(name),
+            CameraTrackLetterboxBar,
+            Node {
+                position_type: PositionType::Absolute,
+                top: if top { Val::Px(0.0) } else { Val::Auto },
+                bottom: if top { Val::Auto } else { Val::Px(0.0) },
+                width: Val::Percent(100.0),
+                height: Val::Percent(cover * 100.0),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            Pickable::IGNORE,
+        )
+    };
+    (
+        Name::new("Camera Track Letterbox"),
+        CameraTrackLetterboxMarker,
+        ScenarioScopedMarker,
+        GlobalZIndex(5),
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        Pickable::IGNORE,
+        children![bar("Letterbox Top", true), bar("Letterbox Bottom", false)],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    fn key(time: f32, x: f32) -> (f32, TrackSample) {
+        (
+            time,
+            TrackSample {
+                position: Vec3::new(x, 0.0, 0.0),
+                look_at: Vec3::new(x, 0.0, -10.0),
+                fov: 1.0,
+            },
+        )
+    }
+
+    /// The spline passes through every key, holds the ends, and moves
+    /// monotonically along a straight run of keys.
+    #[test]
+    fn the_track_passes_through_its_keys_and_holds_the_ends() {
+        let keys = [key(1.0, 0.0), key(2.0, 10.0), key(4.0, 20.0)];
+        assert_eq!(sample_keys(&keys, 0.0).position.x, 0.0);
+        assert_eq!(sample_keys(&keys, 2.0).position.x, 10.0);
+        assert_eq!(sample_keys(&keys, 9.0).position.x, 20.0);
+
+        let mut last = f32::MIN;
+        for step in 0..=30 {
+            let x = sample_keys(&keys, 1.0 + step as f32 * 0.1).position.x;
+            assert!(x >= last - 1e-4, "the move reversed at step {step}");
+            last = x;
+        }
+    }
+
+    #[derive(Resource, Default)]
+    struct Ended(Vec<OnCameraTrackEndEventInfo>);
+
+    fn track_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.init_resource::<Ended>();
+        app.add_systems(Update, tick_camera_tracks);
+        app.add_systems(PostUpdate, apply_camera_tracks);
+        app.add_observer(|event: On<GameEvent>, mut ended: ResMut<Ended>| {
+            if event.name() == "oncameratrackend" {
+                let info = event.info().data.clone().expect("the end carries its info");
+                ended.0.push(serde_json::from_value(info).unwrap());
+            }
+        });
+        app
+    }
+
+    /// A track follows its object, fires its end once the keys run out,
+    /// then eases home onto the solved camera and restores the field of view.
+    #[test]
+    fn a_track_follows_ends_and_hands_back() {
+        let mut app = track_app();
+        app.world_mut().spawn((
+            ScenarioScopedMarker,
+            EntityId::new("hauler"),
+            Transform::from_xyz(100.0, 0.0, 0.0),
+        ));
+        let home = Transform::from_xyz(0.0, 10.0, 20.0);
+        let base_fov = 50f32.to_radians();
+        let track = PlayCameraTrackActionConfig {
+            id: "intro".to_string(),
+            keys: vec![
+                CameraTrackKeyConfig {
+                    time: 0.0,
+                    position: CameraTrackPointConfig::Entity {
+                        id: "hauler".to_string(),
+                        offset: Vec3::new(0.0, 0.0, 30.0),
+                    },
+                    look_at: CameraTrackPointConfig::Entity {
+                        id: "hauler".to_string(),
+                        offset: Vec3::ZERO,
+                    },
+                    fov: Some(30.0),
+                },
+                CameraTrackKeyConfig {
+                    time: 1.0,
+                    position: CameraTrackPointConfig::Entity {
+                        id: "hauler".to_string(),
+                        offset: Vec3::new(0.0, 0.0, 30.0),
+                    },
+                    look_at: CameraTrackPointConfig::World(Vec3::new(100.0, 0.0, 0.0)),
+                    fov: Some(30.0),
+                },
+            ],
+            blend_in: 0.0,
+            blend_out: 1.0,
+            letterbox: 0.1,
+            skippable: true,
+        };
+        let camera = app
+            .world_mut()
+            .spawn((
+                home,
+                Projection::Perspective(PerspectiveProjection {
+                    fov: base_fov,
+                    ..default()
+                }),
+                CameraTrackPlayback::new(track, Some(base_fov)),
+            ))
+            .id();
+        let fov = |app: &App| match app.world().get::<Projection>(camera) {
+            Some(Projection::Perspective(perspective)) => perspective.fov,
+            _ => unreachable!(),
+        };
+
+        // The first frames: on the followed hauler, at the track's FOV.
+        app.update();
+        let transform = *app.world().get::<Transform>(camera).unwrap();
+        assert!(transform.translation.distance(Vec3::new(100.0, 0.0, 30.0)) < 1e-3);
+        assert!((fov(&app) - 30f32.to_radians()).abs() < 1e-5);
+        assert!(app.world().resource::<Ended>().0.is_empty());
+
+        // The keys run out (a quarter second a frame; `Time<Virtual>` clamps
+        // anything longer): the end fires once, and the camera eases home.
+        for _ in 0..4 {
+            app.update();
+        }
+        let ended = &app.world().resource::<Ended>().0;
+        assert_eq!(ended.len(), 1);
+        assert_eq!(ended[0].id, "intro");
+        assert!(!ended[0].skipped);
+        assert!(app
+            .world()
+            .get::<CameraTrackPlayback>(camera)
+            .unwrap()
+            .is_handing_back());
+
+        // Home: the component goes and the camera's own FOV is back. The
+        // solved pose is whatever the rigs write; with none here the track's
+        // last frame simply stays.
+        for _ in 0..4 {
+            app.update();
+        }
+        assert!(app.world().get::<CameraTrackPlayback>(camera).is_none());
+        assert_eq!(fov(&app), base_fov);
+        assert_eq!(app.world().resource::<Ended>().0.len(), 1);
+    }
+
+    /// The letterbox rides the blends: full while the track holds, none once
+    /// it is home, and the layer goes with it.
+    #[test]
+    fn the_letterbox_follows_the_blends() {
+        let mut playback = CameraTrackPlayback::new(
+            PlayCameraTrackActionConfig {
+                id: "finale".to_string(),
+                keys: vec![CameraTrackKeyConfig {
+                    time: 4.0,
+                    position: CameraTrackPointConfig::World(Vec3::Z),
+                    look_at: CameraTrackPointConfig::World(Vec3::ZERO),
+                    fov: None,
+                }],
+                blend_in: 2.0,
+                blend_out: 2.0,
+                letterbox: 0.12,
+                skippable: false,
+            },
+            None,
+        );
+        assert_eq!(playback.letterbox(), 0.0);
+        playback.elapsed = 1.0;
+        assert!((playback.letterbox() - 0.06).abs() < 1e-6);
+        playback.elapsed = 3.0;
+        assert!((playback.letterbox() - 0.12).abs() < 1e-6);
+        playback.hand_back();
+        playback.handback.as_mut().unwrap().elapsed = 2.0;
+        assert_eq!(playback.letterbox(), 0.0);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_systems(Update, sync_camera_track_letterbox);
+        playback.handback = None;
+        let camera = app.world_mut().spawn(playback).id();
+        app.update();
+        let layers = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<CameraTrackLetterboxMarker>>()
+                .iter(app.world())
+                .count()
+        };
+        assert_eq!(layers(&mut app), 1);
+        app.world_mut()
+            .entity_mut(camera)
+            .remove::<CameraTrackPlayback>();
+        app.update();
+        assert_eq!(layers(&mut app), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn play_camera_track_round_trips_through_authored_ron() {
+        let ron_str = r#"PlayCameraTrack((
+            id: "intro",
+            keys: [
+                (time: 0.0, position: World((0.0, 40.0, 120.0)), look_at: Entity(id: "station")),
+                (time: 6.0, position: Entity(id: "player", offset: (0.0, 8.0, 30.0)),
+                    look_at: Entity(id: "player"), fov: Some(40.0)),
+            ],
+            blend_out: 1.5,
+            letterbox: 0.1,
+        ))"#;
+        let parsed: EventActionConfig = ron::from_str(ron_str).unwrap();
+        let EventActionConfig::PlayCameraTrack(track) = &parsed else {
+            panic!("expected PlayCameraTrack, got {parsed:?}");
+        };
+        assert_eq!(track.keys.len(), 2);
+        assert_eq!(track.blend_in, 0.0);
+        assert!(track.skippable, "an omitted skippable defaults to true");
+        assert_eq!(
+            track.keys[0].look_at,
+            CameraTrackPointConfig::Entity {
+                id: "station".to_string(),
+                offset: Vec3::ZERO,
+            }
+        );
+        assert_eq!(track.duration(), 6.0);
+
+        let back: EventActionConfig = ron::from_str(&ron::to_string(&parsed).unwrap()).unwrap();
+        let EventActionConfig::PlayCameraTrack(again) = back else {
+            panic!("round trip lost the variant");
+        };
+        assert_eq!(&again, track);
+    }
+}
@@ -5,15 +5,19 @@ use bevy::prelude::*;
 use nova_events::prelude::*;
 
 use crate::{variables::VariableExpressionNode, world::NovaEventWorld};
+mod cinematic;
 mod flow;
 mod mission;
+mod music;
 mod ship;
 mod spawn;
 mod timer;
 mod view;
 
+pub use cinematic::*;
 pub use flow::*;
 pub use mission::*;
+pub use music::*;
 pub use ship::*;
 pub use spawn::*;
 pub use timer::*;
@@ -23,17 +27,22 @@ pub use view::*;
 /// action config vocabulary and scenario-object types into scope.
 pub mod prelude {
     pub use super::{
-        apply_pending_skybox_swaps, base_scenario_object, BaseScenarioObjectConfig, CurrentOutcome,
+        apply_pending_skybox_swaps, base_scenario_object, feed_music_variables,
+        music_variable_is_truthy, save_screenshot, BaseScenarioObjectConfig, CameraTrackKeyConfig,
+        CameraTrackLetterboxMarker, CameraTrackPlayback, CameraTrackPointConfig, CurrentOutcome,
         DebugMessageActionConfig, DespawnScenarioObjectActionConfig, EventActionConfig,
-        ForceTorpedoLaunchActionConfig, HintEmphasisClearActionConfig, HintEmphasisSetActionConfig,
-        HudReadoutActionConfig, HudReadoutFormatConfig, NextScenarioActionConfig,
-        ObjectiveActionConfig, ObjectiveCompleteActionConfig, ObjectiveMarkerAttachActionConfig,
+        ForceTorpedoLaunchActionConfig, GiveItemActionConfig, HintEmphasisClearActionConfig,
+        HintEmphasisSetActionConfig, HudReadoutActionConfig, HudReadoutFormatConfig,
+        MusicStingerActionConfig, NextScenarioActionConfig, ObjectiveActionConfig,
+        ObjectiveCompleteActionConfig, ObjectiveMarkerAttachActionConfig,
         ObjectiveMarkerDetachActionConfig, OutcomeActionConfig, PendingSkyboxSwap,
-        ScatterObjectsConfig, ScatterRegion, ScenarioAreaConfig, ScenarioObjectConfig,
-        ScenarioObjectKind, ScenarioOutcomeKind, ScreenshotActionConfig, SetAllegianceActionConfig,
-        SetCameraActionConfig, SetControllerVerbActionConfig, SetSkyboxActionConfig,
-        SetSpeedCapActionConfig, StoryMessageActionConfig, TimerCancelActionConfig,
-        TimerStartActionConfig, VariableSetActionConfig, CAPTURE_DIR_ENV, MAX_SCATTER_COUNT,
+        PlayCameraTrackActionConfig, RearmActionConfig, ScatterObjectsConfig, ScatterRegion,
+        ScenarioAreaConfig, ScenarioObjectConfig, ScenarioObjectKind, ScenarioOutcomeKind,
+        ScreenshotActionConfig, SetAllegianceActionConfig, SetCameraActionConfig,
+        SetControllerVerbActionConfig, SetMusicActionConfig, SetPostProfileActionConfig,
+        SetSkyboxActionConfig, SetSpeedCapActionConfig, StoryMessageActionConfig,
+        TakeItemActionConfig, TimerCancelActionConfig, TimerStartActionConfig,
+        VariableSetActionConfig, CAMERA_TRACK_MAX_LETTERBOX, CAPTURE_DIR_ENV, MAX_SCATTER_COUNT,
         NEXT_SCENARIO_DELAY_MAX_SECS, NEXT_SCENARIO_DELAY_WARN_SECS, OUTCOME_AUTO_ADVANCE_MAX_SECS,
     };
 }
@@ -75,6 +84,12 @@ pub enum EventActionConfig {
     SetControllerVerb(SetControllerVerbActionConfig),
     /// Overwrite a scoped ship's `Allegiance` at runtime (neutral-until-provoked).
     SetAllegiance(SetAllegianceActionConfig),
+    /// Load items into a scoped ship's cargo hold (as far as it has room).
+    GiveItem(GiveItemActionConfig),
+    /// Unload items from a scoped ship's cargo hold.
+    TakeItem(TakeItemActionConfig),
+    /// Refill every magazine on a scoped ship.
+    Rearm(RearmActionConfig),
     /// Order a scoped ship's torpedo bays to launch at a named target
     /// (scripted emplacements; no controller involved).
     ForceTorpedoLaunch(ForceTorpedoLaunchActionConfig),
@@ -84,10 +99,20 @@ pub enum EventActionConfig {
     NextScenario(NextScenarioActionConfig),
     /// Pose the scenario camera for a scripted shot (photo mode).
     SetCamera(SetCameraActionConfig),
+    /// Play a keyframed cinematic camera track, easing off the live camera
+    /// and back (fires `OnCameraTrackEnd`).
+    PlayCameraTrack(PlayCameraTrackActionConfig),
     /// Capture the primary window to a PNG (photo mode).
     Screenshot(ScreenshotActionConfig),
     /// Swap the scenario's skybox cubemap mid-scenario (modding hook).
     SetSkybox(SetSkyboxActionConfig),
+    /// Blend the scenario's post-processing look (grade, bloom, vignette,
+    /// wash) to a new profile.
+    SetPostProfile(SetPostProfileActionConfig),
+    /// Play a registered music track by id, or fade the music out.
+    SetMusic(SetMusicActionConfig),
+    /// Play a one-shot stinger over the music, ducking it.
+    MusicStinger(MusicStingerActionConfig),
     /// Declare the scenario's win/lose outcome (drives the outcome overlay).
     Outcome(OutcomeActionConfig),
     /// Speaker-attributed story text, rendered by the HUD comms panel.
@@ -148,6 +173,15 @@ impl EventAction<NovaEventWorld> for EventActionConfig {
             EventActionConfig::SetAllegiance(config) => {
                 config.action(world, info);
             }
+            EventActionConfig::GiveItem(config) => {
+                config.action(world, info);
+            }
+            EventActionConfig::TakeItem(config) => {
+                config.action(world, info);
+            }
+            EventActionConfig::Rearm(config) => {
+                config.action(world, info);
+            }
             EventActionConfig::ForceTorpedoLaunch(config) => {
                 config.action(world, info);
             }
@@ -160,12 +194,24 @@ impl EventAction<NovaEventWorld> for EventActionConfig {
             EventActionConfig::SetCamera(config) => {
                 config.action(world, info);
             }
+            EventActionConfig::PlayCameraTrack(config) => {
+                config.action(world, info);
+            }
             EventActionConfig::Screenshot(config) => {
                 config.action(world, info);
             }
             EventActionConfig::SetSkybox(config) => {
                 config.action(world, info);
             }
+            EventActionConfig::SetPostProfile(config) => {
+                config.action(world, info);
+            }
+            EventActionConfig::SetMusic(config) => {
+                config.action(world, info);
+            }
+            EventActionConfig::MusicStinger(config) => {
+                config.action(world, info);
+            }
             EventActionConfig::Outcome(config) => {
                 config.action(world, info);
             }
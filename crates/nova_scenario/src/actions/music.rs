@@ -0,0 +1,198 @@
+//! Music actions: pick the scenario's score and punctuate it with stingers,
+//! plus the feed that lets the score's stems follow scenario variables.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use nova_events::prelude::*;
+use nova_gameplay::prelude::*;
+
+use crate::prelude::*;
+
+/// Play a registered music track by id, crossfading from whatever is playing;
+/// `None` fades the music out. The track's stems then follow the live cues on
+/// their own. RON: `SetMusic((track: Some("patrol")))`.
+///
+/// A track id nothing registered leaves the music silent with a warning (the
+/// catalog is mod content, so a disabled mod can take its score with it).
+/// Teardown resets the request, so a scenario's score never plays on into the
+/// next scenario or the menu.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetMusicActionConfig {
+    /// The `Music` content id to play, or `None` for silence.
+    pub track: Option<String>,
+}
+
+impl EventAction<NovaEventWorld> for SetMusicActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let track = self.track.clone();
+        debug!("SetMusic: {:?}", track);
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                // Optional: headless rigs run without the audio plugin.
+                if let Some(mut director) = world.get_resource_mut::<MusicDirector>() {
+                    director.set_if_neq(MusicDirector { track });
+                }
+            });
+        });
+    }
+}
+
+/// Play a one-shot stinger over the music - a victory sting, an alarm hit -
+/// on the music volume channel, ducking the stems for `duck` seconds so it
+/// cuts through. RON: `MusicStinger((sound: "self://music/alarm.ogg"))`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MusicStingerActionConfig {
+    /// The stinger clip, authored as an asset path.
+    pub sound: AssetRef<AudioSource>,
+    /// Linear volume of the stinger, `0.0..=1.0`. `None` is full volume.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub volume: Option<f32>,
+    /// Seconds to duck the stems under the stinger. `None` is
+    /// [`MusicStingerActionConfig::DEFAULT_DUCK_SECS`]; `Some(0.0)` plays it
+    /// over the full mix.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub duck: Option<f32>,
+}
+
+impl MusicStingerActionConfig {
+    /// The duck an unauthored stinger gets - about one short sting long.
+    pub const DEFAULT_DUCK_SECS: f32 = 2.0;
+}
+
+impl EventAction<NovaEventWorld> for MusicStingerActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let sound = self.sound.clone();
+        let volume = self.volume.unwrap_or(1.0).clamp(0.0, 1.0);
+        let duck = self.duck.unwrap_or(Self::DEFAULT_DUCK_SECS).max(0.0);
+        debug!("MusicStinger: {:?}", sound.path());
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                let handle = {
+                    let asset_server = world.resource::<AssetServer>();
+                    sound.resolve(asset_server)
+                };
+                world.trigger(PlayMusicStinger {
+                    handle,
+                    volume,
+                    duck,
+                });
+            });
+        });
+    }
+}
+
+/// Whether a scenario variable counts as ON for a [`MusicCue::Variable`]
+/// stem: `true`, a non-zero number, or a non-empty string.
+pub fn music_variable_is_truthy(value: &VariableLiteral) -> bool {
+    match value {
+        VariableLiteral::Boolean(on) => *on,
+        VariableLiteral::Number(n) => *n != 0.0,
+        VariableLiteral::String(s) => !s.is_empty(),
+    }
+}
+
+/// Publish the truthy scenario variables into [`MusicCues`], so a track's
+/// `Variable` stems follow the scenario's own beats. Idle unless the event
+/// world changed, and compared in place so a frame where no variable flipped
+/// allocates nothing; the teardown's variable reset empties the set with
+/// everything else.
+pub fn feed_music_variables(world: Res<NovaEventWorld>, mut cues: ResMut<MusicCues>) {
+    if !world.is_changed() && !cues.is_changed() {
+        return;
+    }
+    let mut truthy = 0;
+    let mut same = true;
+    for (name, _) in world
+        .variables()
+        .filter(|(_, value)| music_variable_is_truthy(value))
+    {
+        truthy += 1;
+        same &= cues.variables.contains(name);
+    }
+    if same && truthy == cues.variables.len() {
+        return;
+    }
+    cues.variables = world
+        .variables()
+        .filter(|(_, value)| music_variable_is_truthy(value))
+        .map(|(name, _)| name.clone())
+        .collect::<HashSet<String>>();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variable_truthiness_matches_the_cue_contract() {
+        assert!(music_variable_is_truthy(&VariableLiteral::Boolean(true)));
+        assert!(!music_variable_is_truthy(&VariableLiteral::Boolean(false)));
+        assert!(music_variable_is_truthy(&VariableLiteral::Number(2.0)));
+        assert!(!music_variable_is_truthy(&VariableLiteral::Number(0.0)));
+        assert!(music_variable_is_truthy(&VariableLiteral::String(
+            "boss".into()
+        )));
+        assert!(!music_variable_is_truthy(&VariableLiteral::String(
+            String::new()
+        )));
+    }
+
+    #[test]
+    fn truthy_variables_reach_the_music_cues() {
+        #[derive(Resource, Default)]
+        struct Writes(u32);
+
+        let mut app = App::new();
+        app.init_resource::<NovaEventWorld>();
+        app.init_resource::<MusicCues>();
+        app.init_resource::<Writes>();
+        app.add_systems(
+            Update,
+            (
+                feed_music_variables,
+                |cues: Res<MusicCues>, mut writes: ResMut<Writes>| {
+                    if cues.is_changed() {
+                        writes.0 += 1;
+                    }
+                },
+            )
+                .chain(),
+        );
+
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .insert_variable("alarm".into(), VariableLiteral::Boolean(true));
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .insert_variable("calm".into(), VariableLiteral::Boolean(false));
+        app.update();
+        let cues = app.world().resource::<MusicCues>();
+        assert!(cues.is_active(&MusicCue::Variable("alarm".into())));
+        assert!(!cues.is_active(&MusicCue::Variable("calm".into())));
+
+        // A frame where nothing flipped leaves the cues untouched.
+        let writes = app.world().resource::<Writes>().0;
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .insert_variable("calm".into(), VariableLiteral::Number(0.0));
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Writes>().0, writes);
+
+        app.world_mut().resource_mut::<NovaEventWorld>().clear();
+        app.update();
+        assert!(
+            app.world().resource::<MusicCues>().variables.is_empty(),
+            "a torn-down scenario leaves no variable stem up"
+        );
+    }
+}
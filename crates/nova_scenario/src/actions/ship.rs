@@ -1,5 +1,6 @@
-//! Actions that retune a live scenario ship: speed cap, allegiance, and
-//! per-verb controller flags.
+//! Actions that retune a live scenario ship: speed cap, allegiance,
+//! per-verb controller flags, the contents of its cargo hold, and its
+//! magazines.
 
 use bevy::prelude::*;
 use nova_events::prelude::*;
@@ -247,6 +248,146 @@ impl EventAction<NovaEventWorld> for ForceTorpedoLaunchActionConfig {
     }
 }
 
+/// Load items into a scenario ship's [`CargoHold`] by id - a mission reward, a
+/// trade, a scripted pickup. Only what fits is loaded; the overflow is logged
+/// and dropped. A ship with no hold (no cargo-bearing hull) warns. Scoped-only
+/// lookup, same rule as SetSpeedCap.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GiveItemActionConfig {
+    /// The `EntityId` of the scoped ship to load.
+    pub id: String,
+    /// The item id to add to.
+    pub item: String,
+    /// Units to add.
+    pub count: u32,
+}
+
+impl EventAction<NovaEventWorld> for GiveItemActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let GiveItemActionConfig { id, item, count } = self.clone();
+        debug!("GiveItem: '{}' <- {} x '{}'", id, count, item);
+
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                let Some(mut hold) = scoped_cargo_hold(world, &id, "GiveItem") else {
+                    return;
+                };
+                let accepted = hold.give(&item, count);
+                if accepted < count {
+                    info!(
+                        "GiveItem: ship '{}' had room for {} of {} '{}'",
+                        id, accepted, count, item
+                    );
+                }
+            });
+        });
+    }
+}
+
+/// Unload items from a scenario ship's [`CargoHold`] by id - a delivery, a
+/// toll, a confiscation. Takes at most what the stack holds; a short stack is
+/// logged, and a scenario that must know gates on the `ItemCount` query
+/// first. Scoped-only lookup, same rule as SetSpeedCap.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TakeItemActionConfig {
+    /// The `EntityId` of the scoped ship to unload.
+    pub id: String,
+    /// The item id to remove from.
+    pub item: String,
+    /// Units to remove.
+    pub count: u32,
+}
+
+impl EventAction<NovaEventWorld> for TakeItemActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let TakeItemActionConfig { id, item, count } = self.clone();
+        debug!("TakeItem: '{}' -> {} x '{}'", id, count, item);
+
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                let Some(mut hold) = scoped_cargo_hold(world, &id, "TakeItem") else {
+                    return;
+                };
+                let taken = hold.take(&item, count);
+                if taken < count {
+                    info!(
+                        "TakeItem: ship '{}' held only {} of {} '{}'",
+                        id, taken, count, item
+                    );
+                }
+            });
+        });
+    }
+}
+
+/// Refill every magazine on a scenario ship by id - the station rearm, as a
+/// scripted beat (a survival rest period, a supply drop). Weapons with no
+/// [`SectionAmmo`] are unlimited and untouched; a severed section is no
+/// longer the ship's and is not refilled. Scoped-only lookup, same rule as
+/// SetSpeedCap.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RearmActionConfig {
+    /// The `EntityId` of the scoped ship to rearm.
+    pub id: String,
+}
+
+impl EventAction<NovaEventWorld> for RearmActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let id = self.id.clone();
+        debug!("Rearm: '{}'", id);
+
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                let mut ships = world.query_filtered::<(Entity, &EntityId), (
+                    With<ScenarioScopedMarker>,
+                    With<SpaceshipRootMarker>,
+                )>();
+                let Some(ship) = ships
+                    .iter(world)
+                    .find(|(_, entity_id)| entity_id.0 == id)
+                    .map(|(entity, _)| entity)
+                else {
+                    warn!("Rearm: no scoped ship with id '{}'", id);
+                    return;
+                };
+                let mut magazines = world.query::<(&ChildOf, &mut SectionAmmo)>();
+                for (child, mut ammo) in magazines.iter_mut(world) {
+                    if child.0 == ship && ammo.rounds < ammo.capacity {
+                        ammo.rounds = ammo.capacity;
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// The cargo hold of the scoped ship `id`, warning (as `action`) when the ship
+/// or its hold is missing.
+fn scoped_cargo_hold<'w>(
+    world: &'w mut World,
+    id: &str,
+    action: &str,
+) -> Option<Mut<'w, CargoHold>> {
+    let mut query = world
+        .query_filtered::<(Entity, &EntityId), (With<ScenarioScopedMarker>, With<SpaceshipRootMarker>)>();
+    let Some(ship) = query
+        .iter(world)
+        .find(|(_, entity_id)| entity_id.0 == id)
+        .map(|(entity, _)| entity)
+    else {
+        warn!("{}: no scoped ship with id '{}'", action, id);
+        return None;
+    };
+    let hold = world.get_mut::<CargoHold>(ship);
+    if hold.is_none() {
+        warn!("{}: ship '{}' has no cargo hold", action, id);
+    }
+    hold
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -447,6 +588,93 @@ mod tests {
         );
     }
 
+    /// GiveItem loads only what fits and TakeItem stops at the stack; both
+    /// reach exactly the addressed ship's hold.
+    #[test]
+    fn give_and_take_item_edit_the_scoped_hold() {
+        use nova_events::prelude::EventWorld;
+
+        let mut world = World::new();
+        world.init_resource::<NovaEventWorld>();
+        world.init_resource::<GameObjectives>();
+        let ship = world
+            .spawn((
+                ScenarioScopedMarker,
+                SpaceshipRootMarker,
+                EntityId::new("hauler".to_string()),
+                CargoHold::new(4),
+            ))
+            .id();
+        let run = |world: &mut World, action: EventActionConfig| {
+            let mut event_world = world.resource_mut::<NovaEventWorld>();
+            action.action(&mut event_world, &GameEventInfo::default());
+            NovaEventWorld::state_to_world_system(world);
+        };
+
+        run(
+            &mut world,
+            EventActionConfig::GiveItem(GiveItemActionConfig {
+                id: "hauler".to_string(),
+                item: "ore".to_string(),
+                count: 6,
+            }),
+        );
+        assert_eq!(world.get::<CargoHold>(ship).unwrap().count("ore"), 4);
+
+        run(
+            &mut world,
+            EventActionConfig::TakeItem(TakeItemActionConfig {
+                id: "hauler".to_string(),
+                item: "ore".to_string(),
+                count: 3,
+            }),
+        );
+        assert_eq!(world.get::<CargoHold>(ship).unwrap().count("ore"), 1);
+    }
+
+    /// Rearm fills exactly the addressed ship's magazines.
+    #[test]
+    fn rearm_fills_only_the_scoped_magazines() {
+        use nova_events::prelude::EventWorld;
+
+        let mut world = World::new();
+        world.init_resource::<NovaEventWorld>();
+        world.init_resource::<GameObjectives>();
+        let spent = SectionAmmo {
+            rounds: 3,
+            capacity: 40,
+        };
+        let ship = world
+            .spawn((
+                ScenarioScopedMarker,
+                SpaceshipRootMarker,
+                EntityId::new("player".to_string()),
+            ))
+            .id();
+        let turret = world.spawn((ChildOf(ship), spent)).id();
+        let bystander = world
+            .spawn((
+                ScenarioScopedMarker,
+                SpaceshipRootMarker,
+                EntityId::new("bystander".to_string()),
+            ))
+            .id();
+        let bystander_turret = world.spawn((ChildOf(bystander), spent)).id();
+
+        let rearm = RearmActionConfig {
+            id: "player".to_string(),
+        };
+        let mut event_world = world.resource_mut::<NovaEventWorld>();
+        rearm.action(&mut event_world, &GameEventInfo::default());
+        NovaEventWorld::state_to_world_system(&mut world);
+
+        assert_eq!(world.get::<SectionAmmo>(turret).unwrap().rounds, 40);
+        assert_eq!(
+            world.get::<SectionAmmo>(bystander_turret).unwrap().rounds,
+            3
+        );
+    }
+
     /// ForceTorpedoLaunch puts a one-shot order on exactly the addressed
     /// ship's bays, locked to the resolved target entity; a missing target
     /// skips the launch entirely (no dumb-fire duds while the target is
@@ -121,6 +121,12 @@ pub enum ScenarioObjectKind {
     Beacon(BeaconConfig),
     /// A proximity pickup crate that fires `OnEnter` when flown through.
     SalvageCrate(SalvageCrateConfig),
+    /// A static structure with docking ports that fires `OnDocked` /
+    /// `OnUndocked` and serves docked ships.
+    Station(StationConfig),
+    /// An ordered run of gates raced against the clock, firing
+    /// `OnCourseFinished` at the finish.
+    Course(CourseConfig),
     /// An authored light - the scene's own key, rim, fill or lamp. A scene that
     /// spawns none renders black; the engine no longer supplies one.
     Light(LightConfig),
@@ -171,6 +177,12 @@ impl EventAction<NovaEventWorld> for ScenarioObjectConfig {
                 ScenarioObjectKind::SalvageCrate(config) => {
                     entity_commands.insert(salvage_crate_scenario_object(config.clone()));
                 }
+                ScenarioObjectKind::Station(config) => {
+                    entity_commands.insert(station_scenario_object(config.clone()));
+                }
+                ScenarioObjectKind::Course(config) => {
+                    entity_commands.insert(course_scenario_object(config.clone()));
+                }
                 ScenarioObjectKind::Light(config) => {
                     entity_commands.insert(light_scenario_object(config.clone()));
                 }
@@ -582,6 +594,7 @@ mod tests {
                     invulnerable: false,
                     seed: None,
                     lock_signature: None,
+                    ore: Default::default(),
                 },
                 5,
             );
@@ -923,6 +936,7 @@ mod tests {
                     invulnerable: false,
                     seed: None,
                     lock_signature: None,
+                    ore: Default::default(),
                 }),
             },
             asteroid_radius: Some((1.0, 3.0)),
@@ -990,6 +1004,7 @@ mod tests {
                     invulnerable: false,
                     seed: None,
                     lock_signature: None,
+                    ore: Default::default(),
                 }),
             },
             asteroid_radius: Some((1.0, 3.0)),
@@ -1069,6 +1084,7 @@ mod tests {
                     invulnerable: false,
                     seed: template_seed,
                     lock_signature: None,
+                    ore: Default::default(),
                 }),
             },
             asteroid_radius: None,
@@ -1145,6 +1161,7 @@ mod tests {
                     invulnerable: false,
                     seed: None,
                     lock_signature: None,
+                    ore: Default::default(),
                 }),
             },
             asteroid_radius: Some((1.0, 3.0)),
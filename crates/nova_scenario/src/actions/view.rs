@@ -123,25 +123,31 @@ impl EventAction<NovaEventWorld> for ScreenshotActionConfig {
 
         world.push_command(move |commands| {
             commands.queue(move |world: &mut World| {
-                let resolved = resolve_capture_path(&path);
-                if let Some(parent) = resolved.parent() {
-                    if !parent.as_os_str().is_empty() {
-                        if let Err(error) = std::fs::create_dir_all(parent) {
-                            warn!(
-                                "Screenshot: could not create capture dir {:?}: {error}",
-                                parent
-                            );
-                        }
-                    }
-                }
-                world
-                    .spawn(Screenshot::primary_window())
-                    .observe(save_to_disk(resolved));
+                save_screenshot(world, Screenshot::primary_window(), &path);
             });
         });
     }
 }
 
+/// The capture path [`ScreenshotActionConfig`] takes, for any source: resolve
+/// `path` (see `resolve_capture_path`), create its parent directory, and spawn
+/// `screenshot` with a `save_to_disk` observer. Photo mode calls it with an
+/// offscreen [`Screenshot::image`] for a supersampled capture.
+pub fn save_screenshot(world: &mut World, screenshot: Screenshot, path: &str) {
+    let resolved = resolve_capture_path(path);
+    if let Some(parent) = resolved.parent() {
+        if !parent.as_os_str().is_empty() {
+            if let Err(error) = std::fs::create_dir_all(parent) {
+                warn!(
+                    "Screenshot: could not create capture dir {:?}: {error}",
+                    parent
+                );
+            }
+        }
+    }
+    world.spawn(screenshot).observe(save_to_disk(resolved));
+}
+
 /// Fallback skybox brightness, matching the value the loader spawns the scenario
 /// camera with (`loader.rs`). Only used if a swap targets a camera that somehow
 /// has no current `SkyboxConfig` to inherit brightness from.
@@ -150,7 +156,8 @@ const DEFAULT_SKYBOX_BRIGHTNESS: f32 = 1000.0;
 /// Swap the scenario's skybox cubemap mid-scenario. A modding hook: a beat can
 /// change the sky by authoring a new cubemap path, resolved through the same
 /// [`AssetRef`] path-or-handle layer the RON format uses for the initial
-/// `cubemap`.
+/// `cubemap` - or a procedural sky, whose sun (when it names no direction)
+/// follows the current scenario's key light.
 ///
 /// The cubemap cannot be applied synchronously: the skybox setup observer in
 /// `nova_ship::camera::skybox` reads the image out of `Assets<Image>` the instant a
@@ -161,9 +168,10 @@ const DEFAULT_SKYBOX_BRIGHTNESS: f32 = 1000.0;
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SetSkyboxActionConfig {
-    /// The new cubemap image, authored as an asset path (e.g.
-    /// `"scenarios/space.cube.png"`) or a live handle in code-built configs.
-    pub cubemap: AssetRef<Image>,
+    /// The new sky: a cubemap image authored as an asset path (e.g.
+    /// `"scenarios/space.cube.png"`, or a live handle in code-built configs),
+    /// or a [`ProceduralSkyConfig`] (`cubemap: (seed: 3)`).
+    pub cubemap: SkyboxSource,
     /// Optional brightness multiplier. `None` keeps the current skybox brightness.
     #[cfg_attr(
         feature = "serde",
@@ -174,7 +182,7 @@ pub struct SetSkyboxActionConfig {
 
 impl SetSkyboxActionConfig {
     /// Construct a swap to `cubemap`, keeping the current brightness.
-    pub fn new(cubemap: impl Into<AssetRef<Image>>) -> Self {
+    pub fn new(cubemap: impl Into<SkyboxSource>) -> Self {
         Self {
             cubemap: cubemap.into(),
             brightness: None,
@@ -192,8 +200,12 @@ impl EventAction<NovaEventWorld> for SetSkyboxActionConfig {
             commands.queue(move |world: &mut World| {
                 // Start the load (idempotent for an already-resolved handle).
                 let handle = {
+                    let key_light = world
+                        .get_resource::<CurrentScenario>()
+                        .and_then(|current| current.0.as_ref())
+                        .and_then(ScenarioConfig::key_light_direction);
                     let asset_server = world.resource::<AssetServer>();
-                    cubemap.resolve(asset_server)
+                    cubemap.with_key_light(key_light).resolve(asset_server)
                 };
 
                 // Resolve the camera before taking a mutable borrow.
@@ -220,6 +232,42 @@ impl EventAction<NovaEventWorld> for SetSkyboxActionConfig {
     }
 }
 
+/// Change the scenario's post-processing look mid-scenario: ease from the
+/// current look to `profile` over `blend` seconds (0 cuts). The look holds
+/// until the next `SetPostProfile` or the scenario ends; teardown restores the
+/// default. The player's hull-damage vignette and the quality tier layer over
+/// it (see `nova_ship::camera::post`). A no-op where the post plugin does not
+/// run (headless rigs).
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SetPostProfileActionConfig {
+    /// The look to blend to. Fields it leaves out take the default look's
+    /// values, not the current ones.
+    pub profile: PostProfile,
+    /// Blend length in seconds, eased on the camera handback's curve.
+    /// Serde-defaulted to 0: a cut.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub blend: f32,
+}
+
+impl EventAction<NovaEventWorld> for SetPostProfileActionConfig {
+    fn action(&self, world: &mut NovaEventWorld, _: &GameEventInfo) {
+        let profile = self.profile.clone();
+        let blend = self.blend;
+        debug!("SetPostProfile: blend {blend}s to {profile:?}");
+
+        world.push_command(move |commands| {
+            commands.queue(move |world: &mut World| {
+                let Some(mut post) = world.get_resource_mut::<PostProfileBlend>() else {
+                    debug!("SetPostProfile: no post-processing plugin; nothing to blend");
+                    return;
+                };
+                post.blend_to(profile, blend);
+            });
+        });
+    }
+}
+
 /// A requested skybox swap waiting on its cubemap image to finish loading. Set by
 /// [`SetSkyboxActionConfig`], consumed by [`apply_pending_skybox_swaps`].
 #[derive(Component, Clone, Debug, Reflect)]
@@ -297,6 +345,35 @@ pub fn apply_pending_skybox_swaps(
 mod tests {
     use super::*;
 
+    /// SetPostProfile reaches the post blend through the event world's
+    /// drain and eases toward the new look rather than cutting to it.
+    #[test]
+    fn set_post_profile_blends_the_post_look() {
+        use nova_events::prelude::EventWorld;
+
+        let mut world = World::new();
+        world.init_resource::<NovaEventWorld>();
+        world.init_resource::<GameObjectives>();
+        world.init_resource::<PostProfileBlend>();
+
+        let grim = PostProfile {
+            saturation: 0.2,
+            ..default()
+        };
+        let action = SetPostProfileActionConfig {
+            profile: grim.clone(),
+            blend: 2.0,
+        };
+        let mut event_world = world.resource_mut::<NovaEventWorld>();
+        action.action(&mut event_world, &GameEventInfo::default());
+        NovaEventWorld::state_to_world_system(&mut world);
+
+        let blend = world.resource::<PostProfileBlend>();
+        assert_eq!(blend.target(), &grim);
+        assert_eq!(blend.duration, 2.0);
+        assert_eq!(blend.current(), PostProfile::default(), "eases, not cuts");
+    }
+
     /// The skybox swap is two-step on purpose: the skybox setup observer
     /// reads the cubemap out of `Assets<Image>` the instant a `SkyboxConfig` is
     /// inserted and panics on an unloaded handle, so
@@ -0,0 +1,643 @@
+//! The post-mission debrief: what the player did in the scenario, tallied as
+//! it happened.
+//!
+//! [`ScenarioDebrief`] is reset when a scenario loads and filled from the seams
+//! the game already has - nothing in the combat code knows it exists:
+//!
+//! - a turret round or torpedo spawned with the player's ship as its
+//!   [`ProjectileOwner`] is a shot FIRED, counted under the section that fired
+//!   it - its scenario id, so two turrets of one part keep their own tally;
+//! - [`HealthDamageLanded`] is a hit. Damage from one of the player's
+//!   projectiles is DEALT, by the section class it landed on; damage on the
+//!   player's own ship is TAKEN, the same way. A round counts as a hit once
+//!   however many sections it rakes; a torpedo hits when its blast catches
+//!   something;
+//! - [`IntegrityDestroyMarker`] on a ship whose last hit was the player's is a
+//!   KILL, and on a hostile torpedo shot to zero by the player's rounds an
+//!   INTERCEPT;
+//! - the combat lock start/end events count the locks and the time held;
+//! - every `ObjectiveComplete` the scenario ran, with its time, and the time
+//!   the outcome landed.
+//!
+//! The outcome overlay (nova_menu) shows it, and the probe snapshot carries it
+//! for balance runs. Damage is what the section a hit landed on actually lost,
+//! as the health store settled it, so a killing blow's overkill never counts.
+//!
+//! Touch this module when adding a statistic to the debrief.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use bevy::prelude::*;
+use nova_events::prelude::*;
+use nova_gameplay::prelude::*;
+use nova_ship::prelude::{TorpedoSectionPartOf, TurretSectionPartOf};
+
+use crate::{actions::prelude::*, loader::prelude::*, world::NovaEventWorld};
+
+/// `ScenarioDebrief`, its weapon tally, the debrief system set and plugin.
+pub mod prelude {
+    pub use super::{DebriefPlugin, DebriefSystems, ScenarioDebrief, WeaponTally};
+}
+
+/// The section-class label damage lands under when what it hit is not a ship
+/// section (an asteroid, a station, debris).
+pub const DEBRIEF_OTHER_CLASS: &str = "Other";
+
+/// One weapon's shots: how many the player fired and how many hit something.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WeaponTally {
+    /// Rounds or torpedoes fired.
+    pub fired: u32,
+    /// Of those, how many hit something.
+    pub hit: u32,
+}
+
+impl WeaponTally {
+    /// Hits over shots, 0..=1; `None` before the first shot.
+    pub fn accuracy(&self) -> Option<f32> {
+        (self.fired > 0).then(|| self.hit.min(self.fired) as f32 / self.fired as f32)
+    }
+}
+
+/// The scenario so far, as the debrief reads it. Reset when a scenario loads;
+/// see the module docs for where each number comes from.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct ScenarioDebrief {
+    /// Shots per weapon, keyed by the firing section: its scenario id, else
+    /// its name, else its class (`Turret`, `Torpedo`).
+    pub weapons: BTreeMap<String, WeaponTally>,
+    /// Damage the player's weapons dealt, keyed by the class of the section
+    /// it landed on, or [`DEBRIEF_OTHER_CLASS`].
+    pub damage_dealt: BTreeMap<String, f32>,
+    /// Damage the player's ship took, keyed by the class of its section.
+    pub damage_taken: BTreeMap<String, f32>,
+    /// Ships destroyed whose last hit was the player's.
+    pub kills: u32,
+    /// Hostile torpedoes the player's rounds shot down.
+    pub intercepts: u32,
+    /// Combat locks acquired.
+    pub locks: u32,
+    /// Seconds a combat lock was held, in total.
+    pub lock_seconds: f64,
+    /// Objectives completed, with the scenario time of each.
+    pub objectives: Vec<(String, f64)>,
+    /// Scenario time the outcome landed; `None` while the scenario runs.
+    pub time: Option<f64>,
+    /// Whether that outcome was a victory.
+    pub victory: bool,
+}
+
+impl ScenarioDebrief {
+    /// All damage dealt, over every section class.
+    pub fn total_dealt(&self) -> f32 {
+        self.damage_dealt.values().sum()
+    }
+
+    /// All damage taken, over every section class.
+    pub fn total_taken(&self) -> f32 {
+        self.damage_taken.values().sum()
+    }
+}
+
+/// The bookkeeping behind the debrief: which projectiles are the player's,
+/// which of them already hit, and who last hit what. Reset with it.
+#[derive(Resource, Debug, Default)]
+struct DebriefLedger {
+    /// Live projectiles (rounds, torpedoes, blasts) the player's ship fired,
+    /// with the [`ScenarioDebrief::weapons`] key of the section that fired
+    /// each.
+    player_shots: HashMap<Entity, String>,
+    /// The player's projectiles that have already counted a hit.
+    hit: HashSet<Entity>,
+    /// The weapon that last hit each ship or torpedo, when it was the player's.
+    last_hit: HashMap<Entity, SectionClass>,
+    /// Scenario time the current combat lock started.
+    locked_since: Option<f64>,
+}
+
+/// The debrief's `Update` systems: copy the completed objectives and stamp
+/// the outcome time. The outcome overlay runs after it, so it shows the time
+/// the frame it appears.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DebriefSystems;
+
+/// Collects the [`ScenarioDebrief`]: its resources, the combat observers, and
+/// the [`DebriefSystems`].
+pub struct DebriefPlugin;
+
+impl Plugin for DebriefPlugin {
+    fn build(&self, app: &mut App) {
+        trace!("DebriefPlugin: build");
+
+        app.init_resource::<ScenarioDebrief>();
+        app.init_resource::<DebriefLedger>();
+        app.add_observer(reset_debrief);
+        app.add_observer(count_fired_rounds);
+        app.add_observer(count_fired_torpedoes);
+        app.add_observer(note_player_blasts);
+        app.add_observer(forget_projectiles);
+        app.add_observer(tally_damage);
+        app.add_observer(tally_destroyed);
+        app.add_observer(tally_locks);
+        app.add_systems(
+            Update,
+            (
+                sync_debrief_objectives.run_if(scenario_is_live),
+                stamp_debrief_outcome,
+            )
+                .in_set(DebriefSystems)
+                .run_if(resource_exists::<NovaEventWorld>),
+        );
+    }
+}
+
+/// A fresh debrief for every scenario load.
+fn reset_debrief(
+    _loaded: On<ScenarioLoaded>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+) {
+    *debrief = default();
+    *ledger = default();
+}
+
+/// The [`ScenarioDebrief::weapons`] key for a shot fired by `section`: its
+/// scenario id, else its name, else `class` when the section is unknown (a
+/// scripted launch, a test rig).
+fn weapon_label(
+    section: Option<Entity>,
+    class: SectionClass,
+    q_sections: &Query<(Option<&EntityId>, Option<&Name>)>,
+) -> String {
+    match section.and_then(|section| q_sections.get(section).ok()) {
+        Some((Some(id), _)) => id.0.clone(),
+        Some((None, Some(name))) => name.to_string(),
+        _ => format!("{class:?}"),
+    }
+}
+
+/// Count a shot fired by the player's ship under the weapon `label`.
+fn count_shot(
+    projectile: Entity,
+    label: String,
+    q_owner: &Query<&ProjectileOwner>,
+    q_player: &Query<(), With<PlayerSpaceshipMarker>>,
+    debrief: &mut ScenarioDebrief,
+    ledger: &mut DebriefLedger,
+) {
+    let Ok(&ProjectileOwner(owner)) = q_owner.get(projectile) else {
+        return;
+    };
+    if !q_player.contains(owner) {
+        return;
+    }
+    debrief.weapons.entry(label.clone()).or_default().fired += 1;
+    ledger.player_shots.insert(projectile, label);
+}
+
+fn count_fired_rounds(
+    add: On<Add, TurretBulletProjectileMarker>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+    q_owner: Query<&ProjectileOwner>,
+    q_player: Query<(), With<PlayerSpaceshipMarker>>,
+    q_turret: Query<&TurretSectionPartOf>,
+    q_sections: Query<(Option<&EntityId>, Option<&Name>)>,
+) {
+    let turret = q_turret.get(add.entity).ok().map(|part_of| part_of.0);
+    count_shot(
+        add.entity,
+        weapon_label(turret, SectionClass::Turret, &q_sections),
+        &q_owner,
+        &q_player,
+        &mut debrief,
+        &mut ledger,
+    );
+}
+
+fn count_fired_torpedoes(
+    add: On<Add, TorpedoProjectileMarker>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+    q_owner: Query<&ProjectileOwner>,
+    q_player: Query<(), With<PlayerSpaceshipMarker>>,
+    q_bay: Query<&TorpedoSectionPartOf>,
+    q_sections: Query<(Option<&EntityId>, Option<&Name>)>,
+) {
+    let bay = q_bay.get(add.entity).ok().map(|part_of| part_of.0);
+    count_shot(
+        add.entity,
+        weapon_label(bay, SectionClass::Torpedo, &q_sections),
+        &q_owner,
+        &q_player,
+        &mut debrief,
+        &mut ledger,
+    );
+}
+
+/// A detonating torpedo's blast is its own entity; remember the player's, so
+/// what it catches counts as the hit of the bay that launched the torpedo.
+fn note_player_blasts(
+    add: On<Add, NovaBlast>,
+    mut ledger: ResMut<DebriefLedger>,
+    q_owner: Query<&ProjectileOwner>,
+    q_player: Query<(), With<PlayerSpaceshipMarker>>,
+    q_bay: Query<&TorpedoSectionPartOf>,
+    q_sections: Query<(Option<&EntityId>, Option<&Name>)>,
+) {
+    if q_owner
+        .get(add.entity)
+        .is_ok_and(|owner| q_player.contains(owner.0))
+    {
+        let bay = q_bay.get(add.entity).ok().map(|part_of| part_of.0);
+        let label = weapon_label(bay, SectionClass::Torpedo, &q_sections);
+        ledger.player_shots.insert(add.entity, label);
+    }
+}
+
+/// A projectile that is gone can hit nothing more.
+fn forget_projectiles(remove: On<Remove, ProjectileOwner>, mut ledger: ResMut<DebriefLedger>) {
+    ledger.player_shots.remove(&remove.entity);
+    ledger.hit.remove(&remove.entity);
+}
+
+/// Tally a hit where it landed - the section it was aimed at, not every
+/// ancestor it bubbles through on the way to the ship root - for what that
+/// section actually lost.
+fn tally_damage(
+    damage: On<HealthDamageLanded>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+    q_owner: Query<&ProjectileOwner>,
+    q_parent: Query<&ChildOf>,
+    q_class: Query<&SectionClass>,
+    q_bodies: Query<
+        Has<PlayerSpaceshipMarker>,
+        Or<(With<SpaceshipRootMarker>, With<TorpedoProjectileMarker>)>,
+    >,
+    q_weapons: Query<(Has<TurretBulletProjectileMarker>, Has<NovaBlast>)>,
+) {
+    if damage.amount <= 0.0 {
+        return;
+    }
+    let target = damage.entity;
+    let class = q_class
+        .get(target)
+        .map_or(DEBRIEF_OTHER_CLASS.to_string(), |class| {
+            format!("{class:?}")
+        });
+    // The ship or torpedo the section belongs to, if any.
+    let body = std::iter::once(target)
+        .chain(q_parent.iter_ancestors(target))
+        .find(|&entity| q_bodies.contains(entity));
+    let on_player = body.is_some_and(|body| q_bodies.get(body).unwrap_or(false));
+    if on_player {
+        *debrief.damage_taken.entry(class.clone()).or_default() += damage.amount;
+    }
+
+    // The projectile that dealt it: the source itself, or the ancestor that
+    // carries the owner (a torpedo's warhead section).
+    let projectile = damage.source.and_then(|source| {
+        std::iter::once(source)
+            .chain(q_parent.iter_ancestors(source))
+            .find(|&entity| q_owner.contains(entity))
+    });
+    let Some((projectile, label)) = projectile.and_then(|shot| {
+        let label = ledger.player_shots.get(&shot)?;
+        Some((shot, label.clone()))
+    }) else {
+        // Someone else's hit: the player is no longer the last to have hit it.
+        if let Some(body) = body {
+            ledger.last_hit.remove(&body);
+        }
+        return;
+    };
+    if on_player {
+        // The player's own blast catching their ship is not damage dealt.
+        return;
+    }
+    *debrief.damage_dealt.entry(class).or_default() += damage.amount;
+    let weapon = match q_weapons.get(projectile) {
+        Ok((true, _)) => SectionClass::Turret,
+        _ => SectionClass::Torpedo,
+    };
+    if let Some(body) = body {
+        ledger.last_hit.insert(body, weapon);
+    }
+    let counts_as_hit = matches!(q_weapons.get(projectile), Ok((true, _) | (_, true)));
+    if counts_as_hit && ledger.hit.insert(projectile) {
+        debrief.weapons.entry(label).or_default().hit += 1;
+    }
+}
+
+/// A ship destroyed after the player's hit is a kill; a hostile torpedo shot
+/// to zero by the player's rounds is an intercept.
+fn tally_destroyed(
+    add: On<Add, IntegrityDestroyMarker>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+    q_parent: Query<&ChildOf>,
+    q_ships: Query<Has<PlayerSpaceshipMarker>, With<SpaceshipRootMarker>>,
+    q_torpedoes: Query<Option<&ProjectileOwner>, With<TorpedoProjectileMarker>>,
+    q_depleted: Query<(), With<HealthZeroMarker>>,
+) {
+    let entity = add.entity;
+    if let Ok(is_player) = q_ships.get(entity) {
+        if ledger.last_hit.remove(&entity).is_some() && !is_player {
+            debrief.kills += 1;
+        }
+        return;
+    }
+    // A torpedo also "destroys" when it detonates; only one shot to zero
+    // health was intercepted.
+    if !q_depleted.contains(entity) {
+        return;
+    }
+    let Some(torpedo) = std::iter::once(entity)
+        .chain(q_parent.iter_ancestors(entity))
+        .find(|&entity| q_torpedoes.contains(entity))
+    else {
+        return;
+    };
+    let owned_by_player = q_torpedoes
+        .get(torpedo)
+        .ok()
+        .flatten()
+        .is_some_and(|owner| q_ships.get(owner.0).unwrap_or(false));
+    if owned_by_player {
+        return;
+    }
+    if ledger.last_hit.remove(&torpedo) == Some(SectionClass::Turret) {
+        debrief.intercepts += 1;
+    }
+}
+
+/// Count the player's combat locks and the time each was held.
+fn tally_locks(
+    event: On<GameEvent>,
+    world: Option<Res<NovaEventWorld>>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+) {
+    let now = world.as_ref().map_or(0.0, |world| world.scenario_elapsed());
+    if event.name() == OnCombatLockStartEvent::name() {
+        debrief.locks += 1;
+        ledger.locked_since = Some(now);
+    } else if event.name() == OnCombatLockEndEvent::name() {
+        if let Some(since) = ledger.locked_since.take() {
+            debrief.lock_seconds += (now - since).max(0.0);
+        }
+    }
+}
+
+/// Copy the scenario's completed objectives into the debrief.
+fn sync_debrief_objectives(world: Res<NovaEventWorld>, mut debrief: ResMut<ScenarioDebrief>) {
+    let completed = world.completed_objectives();
+    if debrief.objectives.len() != completed.len() {
+        debrief.objectives = completed.to_vec();
+    }
+}
+
+/// Stamp the scenario time the outcome landed, and close a lock still held.
+fn stamp_debrief_outcome(
+    world: Res<NovaEventWorld>,
+    outcome: Option<Res<CurrentOutcome>>,
+    mut debrief: ResMut<ScenarioDebrief>,
+    mut ledger: ResMut<DebriefLedger>,
+) {
+    let Some(outcome) = outcome.filter(|outcome| outcome.is_changed()) else {
+        return;
+    };
+    let Some(config) = outcome.0.as_ref() else {
+        return;
+    };
+    if debrief.time.is_some() {
+        return;
+    }
+    let now = world.scenario_elapsed();
+    if let Some(since) = ledger.locked_since.take() {
+        debrief.lock_seconds += (now - since).max(0.0);
+    }
+    debrief.time = Some(now);
+    debrief.victory = config.outcome == ScenarioOutcomeKind::Victory;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::loader::fixtures::scenario_with;
+
+    fn debrief_app() -> (App, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(NovaHealthPlugin);
+        app.init_resource::<NovaEventWorld>();
+        app.init_resource::<CurrentScenario>();
+        app.init_resource::<CurrentOutcome>();
+        app.add_plugins(DebriefPlugin);
+        let player = app
+            .world_mut()
+            .spawn((PlayerSpaceshipMarker, Health::new(100.0)))
+            .id();
+        (app, player)
+    }
+
+    fn section(app: &mut App, ship: Entity, class: SectionClass) -> Entity {
+        app.world_mut()
+            .spawn((class, Health::new(50.0), ChildOf(ship)))
+            .id()
+    }
+
+    fn hit(app: &mut App, target: Entity, source: Entity, amount: f32) {
+        app.world_mut().trigger(HealthApplyDamage {
+            entity: target,
+            source: Some(source),
+            amount,
+        });
+        app.world_mut().flush();
+    }
+
+    /// Shots, hits, damage both ways and kills each come from their seam: a
+    /// round raking two sections is one hit, and a ship the player last hit is
+    /// a kill when it is destroyed.
+    #[test]
+    fn combat_is_tallied_from_its_seams() {
+        let (mut app, player) = debrief_app();
+        let enemy = app
+            .world_mut()
+            .spawn((SpaceshipRootMarker, Health::new(200.0)))
+            .id();
+        let hull = section(&mut app, enemy, SectionClass::Hull);
+        let thruster = section(&mut app, enemy, SectionClass::Thruster);
+        let own_turret = section(&mut app, player, SectionClass::Turret);
+
+        let round = app
+            .world_mut()
+            .spawn((TurretBulletProjectileMarker, ProjectileOwner(player)))
+            .id();
+        let missed = app
+            .world_mut()
+            .spawn((TurretBulletProjectileMarker, ProjectileOwner(player)))
+            .id();
+        let enemy_round = app
+            .world_mut()
+            .spawn((TurretBulletProjectileMarker, ProjectileOwner(enemy)))
+            .id();
+        hit(&mut app, hull, round, 10.0);
+        hit(&mut app, thruster, round, 5.0);
+        hit(&mut app, own_turret, enemy_round, 7.0);
+        app.world_mut().despawn(missed);
+
+        let debrief = app.world().resource::<ScenarioDebrief>().clone();
+        assert_eq!(
+            debrief.weapons["Turret"],
+            WeaponTally { fired: 2, hit: 1 },
+            "the enemy's round is not the player's shot"
+        );
+        assert_eq!(debrief.weapons["Turret"].accuracy(), Some(0.5));
+        assert_eq!(debrief.damage_dealt["Hull"], 10.0);
+        assert_eq!(debrief.damage_dealt["Thruster"], 5.0);
+        assert_eq!(debrief.total_dealt(), 15.0);
+        assert_eq!(debrief.damage_taken["Turret"], 7.0);
+
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert(IntegrityDestroyMarker);
+        assert_eq!(app.world().resource::<ScenarioDebrief>().kills, 1);
+    }
+
+    /// A hostile torpedo the player's rounds shoot to zero is an intercept;
+    /// the player's own torpedo counts as a shot and its blast as its hit.
+    #[test]
+    fn point_defense_intercepts_and_torpedo_hits() {
+        let (mut app, player) = debrief_app();
+        let enemy = app.world_mut().spawn(SpaceshipRootMarker).id();
+        let incoming = app
+            .world_mut()
+            .spawn((TorpedoProjectileMarker, ProjectileOwner(enemy)))
+            .id();
+        let warhead = app
+            .world_mut()
+            .spawn((Health::new(5.0), ChildOf(incoming)))
+            .id();
+        let round = app
+            .world_mut()
+            .spawn((TurretBulletProjectileMarker, ProjectileOwner(player)))
+            .id();
+        hit(&mut app, warhead, round, 10.0);
+        app.world_mut()
+            .entity_mut(warhead)
+            .insert(IntegrityDestroyMarker);
+        assert_eq!(app.world().resource::<ScenarioDebrief>().intercepts, 1);
+
+        let hull = section(&mut app, enemy, SectionClass::Hull);
+        let bay = app
+            .world_mut()
+            .spawn((SectionClass::Torpedo, EntityId::new("bay".to_string())))
+            .id();
+        app.world_mut().spawn((
+            TorpedoProjectileMarker,
+            ProjectileOwner(player),
+            TorpedoSectionPartOf(bay),
+        ));
+        let blast = app
+            .world_mut()
+            .spawn((
+                NovaBlast {
+                    radius: 10.0,
+                    max_damage: 50.0,
+                    kind: DamageType::Explosive,
+                },
+                ProjectileOwner(player),
+                TorpedoSectionPartOf(bay),
+            ))
+            .id();
+        hit(&mut app, hull, blast, 30.0);
+        let debrief = app.world().resource::<ScenarioDebrief>();
+        assert_eq!(debrief.weapons["bay"], WeaponTally { fired: 1, hit: 1 });
+    }
+
+    /// Two turrets of one part keep their own accuracy, and a killing blow
+    /// deals what its section had left, not what the round carried.
+    #[test]
+    fn accuracy_is_per_turret_and_damage_is_what_landed() {
+        let (mut app, player) = debrief_app();
+        let enemy = app.world_mut().spawn(SpaceshipRootMarker).id();
+        let hull = section(&mut app, enemy, SectionClass::Hull);
+        let round_from = |app: &mut App, id: &str| {
+            let turret = app
+                .world_mut()
+                .spawn((SectionClass::Turret, EntityId::new(id.to_string())))
+                .id();
+            app.world_mut()
+                .spawn((
+                    TurretBulletProjectileMarker,
+                    ProjectileOwner(player),
+                    TurretSectionPartOf(turret),
+                ))
+                .id()
+        };
+        let port = round_from(&mut app, "port_gun");
+        round_from(&mut app, "starboard_gun");
+        hit(&mut app, hull, port, 80.0);
+
+        let debrief = app.world().resource::<ScenarioDebrief>();
+        assert_eq!(
+            debrief.weapons["port_gun"],
+            WeaponTally { fired: 1, hit: 1 }
+        );
+        assert_eq!(
+            debrief.weapons["starboard_gun"],
+            WeaponTally { fired: 1, hit: 0 }
+        );
+        assert_eq!(
+            debrief.damage_dealt["Hull"], 50.0,
+            "the hull had 50 to lose; the other 30 was overkill"
+        );
+    }
+
+    /// Locks count with the time held, objectives with their time, and the
+    /// outcome stamps the run's time; a new load starts over.
+    #[test]
+    fn locks_objectives_and_the_outcome_time() {
+        let (mut app, _) = debrief_app();
+        app.insert_resource(CurrentScenario(Some(scenario_with("trial", Vec::new()))));
+        app.world_mut()
+            .commands()
+            .fire::<OnCombatLockStartEvent>(LockEventInfo::default());
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .advance_scenario_elapsed(4.0);
+        app.world_mut()
+            .commands()
+            .fire::<OnCombatLockEndEvent>(LockEventInfo::default());
+        app.world_mut().flush();
+        {
+            let mut world = app.world_mut().resource_mut::<NovaEventWorld>();
+            world.push_objective(ObjectiveActionConfig::new("reach", "Reach the relay"));
+            world.remove_objective("reach");
+            world.advance_scenario_elapsed(6.0);
+        }
+        app.world_mut().resource_mut::<CurrentOutcome>().0 = Some(OutcomeActionConfig::new(
+            ScenarioOutcomeKind::Victory,
+            "Done.",
+        ));
+        app.update();
+
+        let debrief = app.world().resource::<ScenarioDebrief>().clone();
+        assert_eq!((debrief.locks, debrief.lock_seconds), (1, 4.0));
+        assert_eq!(debrief.objectives, vec![("reach".to_string(), 4.0)]);
+        assert_eq!((debrief.time, debrief.victory), (Some(10.0), true));
+
+        app.world_mut().trigger(ScenarioLoaded {
+            scenario_id: "trial".to_string(),
+            handler_count: 0,
+            object_count: 0,
+            survival_seed: None,
+        });
+        assert_eq!(
+            *app.world().resource::<ScenarioDebrief>(),
+            ScenarioDebrief::default()
+        );
+    }
+}
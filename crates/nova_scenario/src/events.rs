@@ -39,6 +39,9 @@ pub enum EventConfig {
     OnEnter,
     /// Fires when a body leaves an area/zone (`id` = the area, other = the body).
     OnExit,
+    /// Fires when the player scoops mined ore into the hold (`id` = the rock,
+    /// other = the ship), once per item id per pickup.
+    OnMined,
     /// An ORBIT maneuver engaged for a well.
     OnOrbitStart,
     /// An ORBIT maneuver entered stable station-keeping.
@@ -55,6 +58,16 @@ pub enum EventConfig {
     OnCombatLockStart,
     /// The player's COMBAT lock left a scenario object.
     OnCombatLockEnd,
+    /// A ship latched to a station's docking port (`id` = the station,
+    /// other = the ship).
+    OnDocked,
+    /// A surviving ship left the station it was docked at.
+    OnUndocked,
+    /// A ship flew the last gate of a race course (`id` = the course,
+    /// other = the ship).
+    OnCourseFinished,
+    /// A scenario camera track finished or was skipped (`id` = the track).
+    OnCameraTrackEnd,
 }
 
 impl From<EventConfig> for EventHandler<NovaEventWorld> {
@@ -68,6 +81,7 @@ impl From<EventConfig> for EventHandler<NovaEventWorld> {
             EventConfig::OnTimerEnd => EventHandler::new::<OnTimerEndEvent>(),
             EventConfig::OnEnter => EventHandler::new::<OnEnterEvent>(),
             EventConfig::OnExit => EventHandler::new::<OnExitEvent>(),
+            EventConfig::OnMined => EventHandler::new::<OnMinedEvent>(),
             EventConfig::OnOrbitStart => EventHandler::new::<OnOrbitStartEvent>(),
             EventConfig::OnOrbitStable => EventHandler::new::<OnOrbitStableEvent>(),
             EventConfig::OnOrbitUnstable => EventHandler::new::<OnOrbitUnstableEvent>(),
@@ -76,6 +90,10 @@ impl From<EventConfig> for EventHandler<NovaEventWorld> {
             EventConfig::OnTravelLockEnd => EventHandler::new::<OnTravelLockEndEvent>(),
             EventConfig::OnCombatLockStart => EventHandler::new::<OnCombatLockStartEvent>(),
             EventConfig::OnCombatLockEnd => EventHandler::new::<OnCombatLockEndEvent>(),
+            EventConfig::OnDocked => EventHandler::new::<OnDockedEvent>(),
+            EventConfig::OnUndocked => EventHandler::new::<OnUndockedEvent>(),
+            EventConfig::OnCourseFinished => EventHandler::new::<OnCourseFinishedEvent>(),
+            EventConfig::OnCameraTrackEnd => EventHandler::new::<OnCameraTrackEndEvent>(),
         }
     }
 }
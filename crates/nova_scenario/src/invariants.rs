@@ -0,0 +1,165 @@
+//! Scenario-declared invariants: what the scenario's design promises never
+//! happens while it runs, written in the variables DSL.
+//!
+//! The scenario only DECLARES them - nothing at play time enforces one. An
+//! armed probe run evaluates every one each frame and puts a broken one on the
+//! run timeline (`nova_probe`'s invariants plugin), and `content lint` checks
+//! them statically. The engine-wide invariants live in the probe; these are the
+//! facts only the content knows, like "the hauler never drops below zero hull
+//! before act 2 ends".
+//!
+//! Touch this module when an invariant needs a new way to say when it holds.
+
+use crate::prelude::*;
+
+/// Glob-import surface for scenario-declared invariants.
+pub mod prelude {
+    pub use super::{InvariantCheck, InvariantRule, ScenarioInvariantConfig};
+}
+
+/// One declared invariant. In RON:
+/// `(name: "hauler_intact", rule: Never(LessThan(..)), when: Some(LessThan(..)))`.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScenarioInvariantConfig {
+    /// The name a violation is reported under; unique within the scenario.
+    pub name: String,
+    /// The comparison, and whether it must always or never be true.
+    pub rule: InvariantRule,
+    /// While this is false the invariant is not in force - `act < 2` for
+    /// "before act 2 ends". Absent, it is in force for the whole scenario.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub when: Option<VariableConditionNode>,
+}
+
+/// The polarity of an invariant's comparison. The DSL compares, it does not
+/// negate, so "never below zero" is `Never(LessThan(..))` rather than a
+/// `GreaterThan` against a hand-picked epsilon.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InvariantRule {
+    /// The comparison must be true whenever the invariant is in force.
+    Always(VariableConditionNode),
+    /// The comparison must be false whenever the invariant is in force.
+    Never(VariableConditionNode),
+}
+
+impl InvariantRule {
+    /// The comparison, whichever polarity it is held to.
+    pub fn condition(&self) -> &VariableConditionNode {
+        match self {
+            InvariantRule::Always(condition) | InvariantRule::Never(condition) => condition,
+        }
+    }
+}
+
+/// What one evaluation of an invariant found.
+#[derive(Clone, Debug)]
+pub enum InvariantCheck {
+    /// The `when` guard is false: the invariant is not in force right now.
+    NotInForce,
+    /// In force, and the rule held.
+    Held,
+    /// In force, and the rule was broken.
+    Broken,
+    /// The guard or the rule could not be evaluated - a variable not set yet,
+    /// or an entity query with nothing to answer it. Neither held nor broken:
+    /// the lint is what catches a name that will never resolve.
+    Unevaluable(VariableError),
+}
+
+impl ScenarioInvariantConfig {
+    /// Evaluate the invariant against the live scenario state.
+    pub fn check(&self, world: &NovaEventWorld) -> InvariantCheck {
+        if let Some(when) = &self.when {
+            match when.evaluate(world) {
+                Ok(true) => {}
+                Ok(false) => return InvariantCheck::NotInForce,
+                Err(error) => return InvariantCheck::Unevaluable(error),
+            }
+        }
+        let (condition, must_be) = match &self.rule {
+            InvariantRule::Always(condition) => (condition, true),
+            InvariantRule::Never(condition) => (condition, false),
+        };
+        match condition.evaluate(world) {
+            Ok(value) if value == must_be => InvariantCheck::Held,
+            Ok(_) => InvariantCheck::Broken,
+            Err(error) => InvariantCheck::Unevaluable(error),
+        }
+    }
+
+    /// Every typed query the invariant reads, guard first, in source order.
+    pub fn queries(&self) -> Vec<&QueryConfig> {
+        let mut out = Vec::new();
+        if let Some(when) = &self.when {
+            collect_condition_queries(when, &mut out);
+        }
+        collect_condition_queries(self.rule.condition(), &mut out);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn var(name: &str) -> VariableExpressionNode {
+        VariableExpressionNode::new_term(VariableTermNode::new_factor(
+            VariableFactorNode::new_name(name),
+        ))
+    }
+
+    fn num(n: f64) -> VariableExpressionNode {
+        VariableExpressionNode::new_term(VariableTermNode::new_factor(
+            VariableFactorNode::new_literal(VariableLiteral::Number(n)),
+        ))
+    }
+
+    /// "The hauler never drops below zero hull before act 2 ends", walked
+    /// through every state it can be in.
+    #[test]
+    fn a_guarded_never_rule_breaks_only_while_in_force() {
+        let invariant = ScenarioInvariantConfig {
+            name: "hauler_intact".to_string(),
+            rule: InvariantRule::Never(VariableConditionNode::new_less_than(
+                var("hauler_hull"),
+                num(0.0),
+            )),
+            when: Some(VariableConditionNode::new_less_than(var("act"), num(2.0))),
+        };
+        let mut world = NovaEventWorld::default();
+        assert!(matches!(
+            invariant.check(&world),
+            InvariantCheck::Unevaluable(VariableError::UndefinedVariable(_))
+        ));
+
+        world.insert_variable("act".to_string(), VariableLiteral::Number(1.0));
+        world.insert_variable("hauler_hull".to_string(), VariableLiteral::Number(40.0));
+        assert!(matches!(invariant.check(&world), InvariantCheck::Held));
+
+        world.insert_variable("hauler_hull".to_string(), VariableLiteral::Number(-5.0));
+        assert!(matches!(invariant.check(&world), InvariantCheck::Broken));
+
+        world.insert_variable("act".to_string(), VariableLiteral::Number(2.0));
+        assert!(matches!(
+            invariant.check(&world),
+            InvariantCheck::NotInForce
+        ));
+    }
+
+    #[test]
+    fn an_invariant_round_trips_through_ron() {
+        let ron = r#"(
+            name: "convoy_escorted",
+            rule: Always(GreaterThan(Term(Factor(Name("escorts"))), Term(Factor(Literal(Number(0.0)))))),
+        )"#;
+        let invariant: ScenarioInvariantConfig = ron::from_str(ron).expect("parse invariant");
+        assert!(invariant.when.is_none());
+        assert!(matches!(invariant.rule, InvariantRule::Always(_)));
+        assert!(invariant.queries().is_empty());
+    }
+}
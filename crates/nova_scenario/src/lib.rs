@@ -3,11 +3,12 @@
 //! `NovaScenarioPlugin` wires it up, and the modules are the vocabulary a
 //! scenario is built from - `events` (what happened), `filters` (which
 //! entities and conditions), `actions` (what to do), `variables` (scenario
-//! state), `objects` (spawnable scenario entities), `world` (the
+//! state), `invariants` (what must never happen), `objects` (spawnable scenario entities), `world` (the
 //! `NovaEventWorld` holding live scenario state), `loader` (parse + register
-//! bundles), and `lint` (the author-time content checks the `content` CLI
-//! runs). This crate is the runtime; the authoring grammar is documented in
-//! the scenario-system wiki.
+//! bundles), `debrief` (the post-mission statistics), `survival` (the
+//! generated wave mode), `threat` (the per-ship threat numbers), and `lint` (the
+//! author-time content checks the `content` CLI runs). This crate is the
+//! runtime; the authoring grammar is documented in the scenario-system wiki.
 //!
 //! `render_scale` is the exception - not vocabulary, but the Low-preset
 //! resolution lever. It lives here because it retargets the
@@ -20,10 +21,14 @@
 
 /// What a handler does when it fires: the action config vocabulary.
 pub mod actions;
+/// The post-mission debrief: combat statistics tallied while the scenario runs.
+pub mod debrief;
 /// What a handler reacts to: the [`events::EventConfig`] trigger enum.
 pub mod events;
 /// Which entities and conditions gate a handler: the filter config vocabulary.
 pub mod filters;
+/// What the scenario promises never happens: invariants an armed probe checks.
+pub mod invariants;
 pub mod lint;
 /// Parse, register, and load/unload scenario bundles at runtime.
 pub mod loader;
@@ -32,10 +37,14 @@ pub mod objects;
 /// Typed read-only scenario queries and continuously sampled watches.
 pub mod queries;
 pub mod render_scale;
+/// Survival mode: seeded escalating waves generated at load, and their scores.
+pub mod survival;
 /// Driving a rig's scenario to live, for tests in this crate and the crates
 /// whose content walks the same pipeline.
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
+/// A ship's derived threat numbers: health, burst dps and weapon reach.
+pub mod threat;
 /// Typed scenario variables and the small expression tree over them.
 pub mod variables;
 /// The [`world::NovaEventWorld`] resource holding live scenario state.
@@ -45,8 +54,9 @@ pub mod world;
 /// API of the scenario engine (the module preludes plus [`NovaScenarioPlugin`]).
 pub mod prelude {
     pub use super::{
-        actions::prelude::*, events::prelude::*, filters::prelude::*, lint::prelude::*,
-        loader::prelude::*, objects::prelude::*, queries::prelude::*, render_scale::prelude::*,
+        actions::prelude::*, debrief::prelude::*, events::prelude::*, filters::prelude::*,
+        invariants::prelude::*, lint::prelude::*, loader::prelude::*, objects::prelude::*,
+        queries::prelude::*, render_scale::prelude::*, survival::prelude::*, threat::prelude::*,
         variables::prelude::*, world::prelude::*, NovaScenarioPlugin,
     };
 }
@@ -73,6 +83,8 @@ impl Plugin for NovaScenarioPlugin {
         app.add_plugins(objects::ScenarioObjectsPlugin {
             render: self.render,
         });
+        app.add_plugins(debrief::DebriefPlugin);
+        app.add_plugins(survival::SurvivalPlugin);
 
         // The render-scale lever only means anything with a window/GPU; a
         // headless rig (render == false) has no scenario view to downscale.
@@ -2,8 +2,13 @@
 
 use std::collections::HashSet;
 
+use nova_ship::prelude::{PostProfile, SkyboxSource};
+
 use super::{ship::check_object_prototypes, KnownSections, KnownShips, LintIssue};
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    survival::{template_player, SURVIVAL_RESTING_VAR},
+};
 
 /// Everything a scenario's actions can DECLARE, collected in one pass:
 /// spawnable entity ids (spawns + areas), scatter prefixes, set variables,
@@ -89,6 +94,15 @@ pub fn lint_scenario(
         ));
     }
 
+    if let Some(survival) = &scenario.survival {
+        check_survival(survival, scenario, ships, &mut issues);
+    }
+    if let Some(post) = &scenario.post {
+        check_post_profile(post, "post", id, &mut issues);
+    }
+    check_skybox(&scenario.cubemap, "cubemap", id, &mut issues);
+    check_sky_suns(scenario, &mut issues);
+
     let mut watch_names = HashSet::new();
     for watch in &scenario.watches {
         if watch.variable.trim().is_empty() {
@@ -116,6 +130,19 @@ pub fn lint_scenario(
     // Pass 1: what the scenario declares. Spawn ids are tracked per event
     // so the duplicate check can tell a definite bug from a branch pattern.
     let mut declared = Declared::default();
+    if scenario.survival.is_some() {
+        // The generated waves set these at load; the template's own handlers
+        // may read them.
+        declared.set_vars.extend(
+            [
+                SURVIVAL_WAVE_VAR,
+                SURVIVAL_HOSTILES_VAR,
+                SURVIVAL_CLEARED_VAR,
+                SURVIVAL_RESTING_VAR,
+            ]
+            .map(str::to_string),
+        );
+    }
     let mut spawns_per_event: Vec<Vec<String>> = Vec::new();
     for event in &scenario.events {
         let mut event_spawns = Vec::new();
@@ -312,9 +339,181 @@ pub fn lint_scenario(
         ));
     }
 
+    check_invariants(scenario, &declared.set_vars, &watch_names, &mut issues);
+
     issues
 }
 
+/// The declared invariants, checked without running anything: a name the
+/// probe can report, variables something actually sets, and comparisons whose
+/// operands can meet. An invariant that can never be evaluated is silent at
+/// run time - the probe cannot tell "held" from "never read" - so this is where
+/// it gets loud. Entity query targets ride `inline_queries` with the rest.
+fn check_invariants(
+    scenario: &ScenarioConfig,
+    set_vars: &HashSet<String>,
+    watch_names: &HashSet<String>,
+    issues: &mut Vec<LintIssue>,
+) {
+    let id = scenario.id.as_str();
+    let mut names = HashSet::new();
+    for invariant in &scenario.invariants {
+        let name = invariant.name.as_str();
+        if name.trim().is_empty() {
+            issues.push(LintIssue::error(
+                id,
+                "invariant has an empty name".to_string(),
+            ));
+        } else if !names.insert(name) {
+            issues.push(LintIssue::error(
+                id,
+                format!("duplicate invariant name '{name}'"),
+            ));
+        }
+
+        let conditions = std::iter::once(invariant.rule.condition()).chain(invariant.when.as_ref());
+        let mut vars = HashSet::new();
+        for condition in conditions {
+            collect_condition_vars(condition, &mut vars);
+            if let Some(mismatch) = condition_type_mismatch(condition) {
+                issues.push(LintIssue::error(
+                    id,
+                    format!("invariant '{name}' {mismatch}; it can never be evaluated"),
+                ));
+            }
+        }
+        let mut sorted: Vec<&String> = vars.iter().collect();
+        sorted.sort();
+        for var in sorted {
+            if !set_vars.contains(var) && !watch_names.contains(var) {
+                issues.push(LintIssue::warn(
+                    id,
+                    format!(
+                        "invariant '{name}' reads variable '{var}', which is never set in \
+                         this scenario (the invariant is never checked)"
+                    ),
+                ));
+            }
+        }
+        if vars.is_empty() && invariant.queries().is_empty() {
+            issues.push(LintIssue::warn(
+                id,
+                format!(
+                    "invariant '{name}' reads no variable and no query; it is constant and \
+                     checks nothing"
+                ),
+            ));
+        }
+    }
+}
+
+/// The literal type an expression is statically known to produce, when it is
+/// a literal or a typed query (through parentheses); `None` for anything that
+/// depends on a variable's runtime value.
+fn static_type(node: &VariableExpressionNode) -> Option<&'static str> {
+    let VariableExpressionNode::Term(VariableTermNode::Factor(factor)) = node else {
+        return None;
+    };
+    match factor {
+        VariableFactorNode::Literal(VariableLiteral::Number(_)) => Some("Number"),
+        VariableFactorNode::Literal(VariableLiteral::Boolean(_)) => Some("Boolean"),
+        VariableFactorNode::Literal(VariableLiteral::String(_)) => Some("String"),
+        VariableFactorNode::Query(QueryConfig::Scenario(ScenarioQuery {
+            property: ScenarioProperty::Difficulty,
+        })) => Some("String"),
+        VariableFactorNode::Query(_) => Some("Number"),
+        VariableFactorNode::Parens(inner) => static_type(inner),
+        VariableFactorNode::Name(_) => None,
+    }
+}
+
+/// Why a comparison fails its type check on every evaluation, if its operand
+/// types are known well enough to say.
+fn condition_type_mismatch(node: &VariableConditionNode) -> Option<String> {
+    let (left, right, ordered) = match node {
+        VariableConditionNode::LessThan(left, right)
+        | VariableConditionNode::GreaterThan(left, right) => (left, right, true),
+        VariableConditionNode::Equal(left, right) => (left, right, false),
+    };
+    let (left, right) = (static_type(left), static_type(right));
+    if ordered {
+        if let Some(kind) = [left, right]
+            .into_iter()
+            .flatten()
+            .find(|kind| *kind != "Number")
+        {
+            return Some(format!(
+                "orders a {kind}, and only Numbers compare less/greater"
+            ));
+        }
+    }
+    match (left, right) {
+        (Some(left), Some(right)) if left != right => {
+            Some(format!("compares a {left} with a {right}"))
+        }
+        _ => None,
+    }
+}
+
+/// A survival template must stage a player ship for the waves to ring, draw
+/// from ships the catalog has, and climb: the generator itself falls back to
+/// an unchanged scenario rather than fail, so the lint is where a broken
+/// template gets loud.
+fn check_survival(
+    survival: &SurvivalConfig,
+    scenario: &ScenarioConfig,
+    ships: &KnownShips,
+    issues: &mut Vec<LintIssue>,
+) {
+    let id = scenario.id.as_str();
+    if template_player(scenario).is_none() {
+        issues.push(LintIssue::error(
+            id,
+            "survival scenario spawns no Player-controlled ship in OnStart; the waves have \
+             nothing to close on"
+                .to_string(),
+        ));
+    }
+    if survival.waves == 0 {
+        issues.push(LintIssue::error(id, "survival has zero waves".to_string()));
+    }
+    if survival.max_wave_size == 0 {
+        issues.push(LintIssue::error(
+            id,
+            "survival max_wave_size is 0".to_string(),
+        ));
+    }
+    for (field, value) in [
+        ("first_wave_ttk", survival.first_wave_ttk),
+        ("last_wave_ttk", survival.last_wave_ttk),
+        ("rest_secs", survival.rest_secs),
+    ] {
+        if !(value.is_finite() && value > 0.0) {
+            issues.push(LintIssue::error(
+                id,
+                format!("survival {field} must be positive and finite, got {value}"),
+            ));
+        }
+    }
+    if survival.first_wave_ttk < survival.last_wave_ttk {
+        issues.push(LintIssue::warn(
+            id,
+            format!(
+                "survival first_wave_ttk {} is below last_wave_ttk {}: the waves get easier",
+                survival.first_wave_ttk, survival.last_wave_ttk
+            ),
+        ));
+    }
+    for ship in &survival.pool {
+        if ships.get(ship).is_none() {
+            issues.push(LintIssue::error(
+                id,
+                format!("survival pool names unknown ship '{ship}'"),
+            ));
+        }
+    }
+}
+
 fn collect_declared(action: &EventActionConfig, declared: &mut Declared) {
     match action {
         EventActionConfig::SpawnScenarioObject(config) => {
@@ -515,6 +714,17 @@ fn check_action(
         EventActionConfig::SetAllegiance(config) => {
             check_target(&config.id, "SetAllegiance", scenario, satisfiable, issues);
         }
+        EventActionConfig::GiveItem(config) => {
+            check_target(&config.id, "GiveItem", scenario, satisfiable, issues);
+            check_item_id(&config.item, "GiveItem", scenario, issues);
+        }
+        EventActionConfig::TakeItem(config) => {
+            check_target(&config.id, "TakeItem", scenario, satisfiable, issues);
+            check_item_id(&config.item, "TakeItem", scenario, issues);
+        }
+        EventActionConfig::Rearm(config) => {
+            check_target(&config.id, "Rearm", scenario, satisfiable, issues);
+        }
         EventActionConfig::ForceTorpedoLaunch(config) => {
             check_target(
                 &config.id,
@@ -540,6 +750,56 @@ fn check_action(
                 issues,
             );
         }
+        EventActionConfig::PlayCameraTrack(config) => {
+            // An empty id still plays, but its OnCameraTrackEnd matches no
+            // Entity filter - a story beat chained off it never runs.
+            if config.id.trim().is_empty() {
+                issues.push(LintIssue::error(
+                    scenario,
+                    "PlayCameraTrack has an empty id (its OnCameraTrackEnd cannot be matched)"
+                        .to_string(),
+                ));
+            }
+            if config.keys.is_empty() {
+                issues.push(LintIssue::error(
+                    scenario,
+                    format!(
+                        "PlayCameraTrack '{}' has no keys (nothing to play)",
+                        config.id
+                    ),
+                ));
+            }
+            let bad_time = |time: f32| !time.is_finite() || time < 0.0;
+            if config.keys.iter().any(|key| bad_time(key.time))
+                || bad_time(config.blend_in)
+                || bad_time(config.blend_out)
+            {
+                issues.push(LintIssue::error(
+                    scenario,
+                    format!(
+                        "PlayCameraTrack '{}' has a negative or non-finite key time or blend",
+                        config.id
+                    ),
+                ));
+            }
+            if !(0.0..=CAMERA_TRACK_MAX_LETTERBOX).contains(&config.letterbox) {
+                issues.push(LintIssue::warn(
+                    scenario,
+                    format!(
+                        "PlayCameraTrack '{}' letterbox {} is outside [0, \
+                         {CAMERA_TRACK_MAX_LETTERBOX}] and will be clamped",
+                        config.id, config.letterbox
+                    ),
+                ));
+            }
+            for key in &config.keys {
+                for point in [&key.position, &key.look_at] {
+                    if let CameraTrackPointConfig::Entity { id, .. } = point {
+                        check_target(id, "PlayCameraTrack", scenario, satisfiable, issues);
+                    }
+                }
+            }
+        }
         EventActionConfig::HudReadout(config) => {
             // An empty slot or variable is an authoring typo the sync would
             // silently accept (an empty-slot readout can never be cleared).
@@ -561,9 +821,98 @@ fn check_action(
             // VariableSet ever writes (the engine clock is exempted there).
             used_vars.insert(config.variable.clone());
         }
+        EventActionConfig::SetPostProfile(config) => {
+            if !config.blend.is_finite() || config.blend < 0.0 {
+                issues.push(LintIssue::error(
+                    scenario,
+                    format!(
+                        "SetPostProfile blend {}s is negative or non-finite",
+                        config.blend
+                    ),
+                ));
+            }
+            check_post_profile(&config.profile, "SetPostProfile", scenario, issues);
+        }
+        EventActionConfig::SetSkybox(config) => {
+            check_skybox(&config.cubemap, "SetSkybox", scenario, issues);
+        }
         _ => {}
     }
 }
+
+/// A post profile out of range still renders - the blend clamps it - but not
+/// as written, so it warns like an oversized letterbox.
+fn check_post_profile(
+    profile: &PostProfile,
+    what: &str,
+    scenario: &str,
+    issues: &mut Vec<LintIssue>,
+) {
+    let fields = profile.out_of_range();
+    if !fields.is_empty() {
+        issues.push(LintIssue::warn(
+            scenario,
+            format!(
+                "{what} profile has {} out of range; it will be clamped",
+                fields.join(", ")
+            ),
+        ));
+    }
+}
+
+/// Same contract for a procedural sky: the generator clamps, so an out of
+/// range field warns rather than refusing the load.
+fn check_skybox(sky: &SkyboxSource, what: &str, scenario: &str, issues: &mut Vec<LintIssue>) {
+    let SkyboxSource::Procedural(config) = sky else {
+        return;
+    };
+    let fields = config.out_of_range();
+    if !fields.is_empty() {
+        issues.push(LintIssue::warn(
+            scenario,
+            format!(
+                "{what} procedural sky has {} out of range; it will be clamped",
+                fields.join(", ")
+            ),
+        ));
+    }
+}
+
+/// A procedural sun that names no direction is placed by the key light, so
+/// a scenario that spawns no directional `Light` leaves it nowhere to go and
+/// the sky is generated without it.
+fn check_sky_suns(scenario: &ScenarioConfig, issues: &mut Vec<LintIssue>) {
+    if scenario.key_light_direction().is_some() {
+        return;
+    }
+    let swaps = scenario
+        .events
+        .iter()
+        .flat_map(|event| &event.actions)
+        .filter_map(|action| match action {
+            EventActionConfig::SetSkybox(config) => Some((&config.cubemap, "SetSkybox")),
+            _ => None,
+        });
+    for (sky, what) in std::iter::once((&scenario.cubemap, "cubemap")).chain(swaps) {
+        let SkyboxSource::Procedural(config) = sky else {
+            continue;
+        };
+        if config
+            .sun
+            .as_ref()
+            .is_some_and(|sun| sun.direction.is_none())
+        {
+            issues.push(LintIssue::warn(
+                &scenario.id,
+                format!(
+                    "{what} procedural sky has a sun with no direction and no directional \
+                     Light spawned OnStart to place it by; the sun will not be drawn"
+                ),
+            ));
+        }
+    }
+}
+
 fn direct_number_literal(expression: &VariableExpressionNode) -> Option<f64> {
     let VariableExpressionNode::Term(VariableTermNode::Factor(VariableFactorNode::Literal(
         VariableLiteral::Number(value),
@@ -589,6 +938,16 @@ fn check_target(
     }
 }
 
+/// An empty item id is a typo the hold would silently accept as its own stack.
+fn check_item_id(item: &str, what: &str, scenario: &str, issues: &mut Vec<LintIssue>) {
+    if item.trim().is_empty() {
+        issues.push(LintIssue::error(
+            scenario,
+            format!("{what} has an empty item id"),
+        ));
+    }
+}
+
 fn check_filter(
     filter: &EventFilterConfig,
     scenario: &str,
@@ -709,6 +1068,7 @@ fn collect_factor_vars(node: &VariableFactorNode, vars: &mut HashSet<String>) {
 #[cfg(test)]
 mod tests {
     use bevy::prelude::*;
+    use nova_ship::prelude::ProceduralSkyConfig;
 
     use super::*;
     use crate::lint::{fixtures::*, LintSeverity};
@@ -957,6 +1317,190 @@ mod tests {
         assert!(errors(&issues).is_empty(), "{issues:?}");
     }
 
+    /// A camera track that follows an id nothing spawns, or runs backwards,
+    /// is an error; an oversized letterbox only warns (it is clamped).
+    #[test]
+    fn a_camera_track_following_a_ghost_or_running_backwards_is_an_error() {
+        let track = |follow: &str, time: f32, letterbox: f32| {
+            scenario(
+                vec![EventActionConfig::PlayCameraTrack(
+                    PlayCameraTrackActionConfig {
+                        id: "intro".to_string(),
+                        keys: vec![CameraTrackKeyConfig {
+                            time,
+                            position: CameraTrackPointConfig::World(Vec3::Z),
+                            look_at: CameraTrackPointConfig::Entity {
+                                id: follow.to_string(),
+                                offset: Vec3::ZERO,
+                            },
+                            fov: None,
+                        }],
+                        blend_in: 0.0,
+                        blend_out: 1.0,
+                        letterbox,
+                        skippable: true,
+                    },
+                )],
+                vec![],
+            )
+        };
+        let lint = |s: &ScenarioConfig| {
+            lint_scenario(s, &sections(&[]), &ships(&[]), &known(&["test_scenario"]))
+        };
+
+        let issues = lint(&track("ghost", 2.0, 0.1));
+        let errs = errors(&issues);
+        assert_eq!(errs.len(), 1, "{issues:?}");
+        assert!(errs[0].message.contains("ghost"));
+
+        let issues = lint(&track("ghost", -1.0, 0.1));
+        assert_eq!(errors(&issues).len(), 2, "{issues:?}");
+
+        let mut wide = track("ghost", 2.0, 0.8);
+        wide.events[0].actions.insert(
+            0,
+            EventActionConfig::CreateScenarioArea(ScenarioAreaConfig {
+                id: "ghost".to_string(),
+                name: "Ghost".to_string(),
+                position: Vec3::ZERO,
+                rotation: Quat::IDENTITY,
+                radius: 10.0,
+            }),
+        );
+        let issues = lint(&wide);
+        assert!(errors(&issues).is_empty(), "{issues:?}");
+        assert!(issues.iter().any(
+            |issue| issue.severity == LintSeverity::Warn && issue.message.contains("letterbox")
+        ));
+    }
+
+    /// A post profile out of range warns (it is clamped), on the scenario's
+    /// own look and on a SetPostProfile alike; a negative blend is an error.
+    #[test]
+    fn post_profiles_warn_out_of_range_and_blends_must_not_run_backwards() {
+        let lint = |s: &ScenarioConfig| {
+            lint_scenario(s, &sections(&[]), &ships(&[]), &known(&["test_scenario"]))
+        };
+        let set_post = |bloom: f32, blend: f32| {
+            EventActionConfig::SetPostProfile(SetPostProfileActionConfig {
+                profile: PostProfile { bloom, ..default() },
+                blend,
+            })
+        };
+
+        let mut clean = scenario(vec![set_post(0.3, 2.0)], vec![]);
+        clean.post = Some(PostProfile {
+            saturation: 0.6,
+            ..default()
+        });
+        assert!(lint(&clean).is_empty(), "{:?}", lint(&clean));
+
+        let mut loud = scenario(vec![set_post(0.3, -1.0), set_post(5.0, 0.0)], vec![]);
+        loud.post = Some(PostProfile {
+            vignette: 2.0,
+            ..default()
+        });
+        let issues = lint(&loud);
+        let errs = errors(&issues);
+        assert_eq!(errs.len(), 1, "{issues:?}");
+        assert!(errs[0].message.contains("blend -1s"));
+        let warns: Vec<_> = issues
+            .iter()
+            .filter(|issue| issue.severity == LintSeverity::Warn)
+            .collect();
+        assert_eq!(warns.len(), 2, "{issues:?}");
+        assert!(warns.iter().any(|issue| issue.message.contains("vignette")));
+        assert!(warns.iter().any(|issue| issue.message.contains("bloom")));
+    }
+
+    /// A procedural sky out of range warns (it is clamped), as the scenario's
+    /// sky and on a SetSkybox alike; an image path is not checked here.
+    #[test]
+    fn procedural_skies_warn_out_of_range() {
+        let lint = |s: &ScenarioConfig| {
+            lint_scenario(s, &sections(&[]), &ships(&[]), &known(&["test_scenario"]))
+        };
+        let set_sky = |star_density: f32| {
+            EventActionConfig::SetSkybox(SetSkyboxActionConfig::new(ProceduralSkyConfig {
+                star_density,
+                ..default()
+            }))
+        };
+
+        let mut clean = scenario(vec![set_sky(0.5)], vec![]);
+        clean.cubemap = ProceduralSkyConfig::default().into();
+        assert!(lint(&clean).is_empty(), "{:?}", lint(&clean));
+
+        let mut loud = scenario(vec![set_sky(3.0)], vec![]);
+        loud.cubemap = ProceduralSkyConfig {
+            face_size: 4096,
+            ..default()
+        }
+        .into();
+        let issues = lint(&loud);
+        assert_eq!(issues.len(), 2, "{issues:?}");
+        assert!(issues
+            .iter()
+            .all(|issue| issue.severity == LintSeverity::Warn));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.message.contains("face_size")));
+        assert!(issues.iter().any(|issue| issue
+            .message
+            .contains("SetSkybox procedural sky has star_density")));
+    }
+
+    /// A sun left to follow the key light warns when the scenario spawns no
+    /// directional light, as the scenario's sky and on a SetSkybox alike; a
+    /// key light or a direction of its own places it.
+    #[test]
+    fn a_sun_with_nothing_to_follow_warns() {
+        use nova_ship::prelude::ProceduralSunConfig;
+
+        let lint = |s: &ScenarioConfig| {
+            lint_scenario(s, &sections(&[]), &ships(&[]), &known(&["test_scenario"]))
+        };
+        let sunny = |direction: Option<Vec3>| ProceduralSkyConfig {
+            sun: Some(ProceduralSunConfig {
+                direction,
+                ..default()
+            }),
+            ..default()
+        };
+        let key_light = EventActionConfig::SpawnScenarioObject(ScenarioObjectConfig {
+            base: BaseScenarioObjectConfig {
+                id: "key".to_string(),
+                name: "Key".to_string(),
+                position: Vec3::new(-6.0, 5.0, 6.0),
+                rotation: Quat::IDENTITY,
+            },
+            kind: ScenarioObjectKind::Light(LightConfig::Directional {
+                illuminance: 11000.0,
+                color: Color::WHITE,
+                shadows: true,
+                aim: Some(Vec3::ZERO),
+            }),
+        });
+        let set_sky = EventActionConfig::SetSkybox(SetSkyboxActionConfig::new(sunny(None)));
+
+        let mut dark = scenario(vec![set_sky.clone()], vec![]);
+        dark.cubemap = sunny(None).into();
+        let issues = lint(&dark);
+        assert_eq!(issues.len(), 2, "{issues:?}");
+        assert!(issues
+            .iter()
+            .all(|issue| issue.severity == LintSeverity::Warn
+                && issue.message.contains("sun with no direction")));
+
+        let mut lit = scenario(vec![key_light, set_sky], vec![]);
+        lit.cubemap = sunny(None).into();
+        assert!(lint(&lit).is_empty(), "{:?}", lint(&lit));
+
+        let mut aimed = scenario(vec![], vec![]);
+        aimed.cubemap = sunny(Some(Vec3::Y)).into();
+        assert!(lint(&aimed).is_empty(), "{:?}", lint(&aimed));
+    }
+
     /// ForceTorpedoLaunch references TWO ships by id (launcher and target);
     /// both must lint as dangling targets on a typo, not no-op at runtime.
     #[test]
@@ -1388,4 +1932,145 @@ mod tests {
         );
         assert!(issues[0].message.contains(PLAYER_SPEED_VAR));
     }
+
+    /// A survival template with no player ship and a pool naming an unknown
+    /// ship errors on both; reading the generated wave variables is clean.
+    #[test]
+    fn survival_templates_need_a_player_and_a_known_pool() {
+        let mut s = scenario(
+            vec![],
+            vec![EventFilterConfig::Expression(ExpressionFilterConfig(
+                VariableConditionNode::new_equals(
+                    VariableExpressionNode::new_term(VariableTermNode::new_factor(
+                        VariableFactorNode::new_name(SURVIVAL_CLEARED_VAR),
+                    )),
+                    VariableExpressionNode::new_term(VariableTermNode::new_factor(
+                        VariableFactorNode::new_literal(VariableLiteral::Number(3.0)),
+                    )),
+                ),
+            ))],
+        );
+        s.survival = Some(SurvivalConfig {
+            pool: vec!["raider".to_string(), "wraith".to_string()],
+            ..default()
+        });
+        let issues = lint_scenario(&s, &sections(&[]), &ships(&["raider"]), &known(&[]));
+        let errors = errors(&issues);
+        assert_eq!(errors.len(), 2, "{issues:?}");
+        assert!(errors
+            .iter()
+            .any(|issue| issue.message.contains("no Player")));
+        assert!(errors
+            .iter()
+            .any(|issue| issue.message.contains("'wraith'")));
+        assert!(
+            !issues
+                .iter()
+                .any(|issue| issue.message.contains(SURVIVAL_CLEARED_VAR)),
+            "the generated variables count as set: {issues:?}"
+        );
+    }
+
+    /// The declared invariants are checked statically: a reportable name, a
+    /// variable something sets, operands that can meet - and an entity query
+    /// target the scenario spawns, through `inline_queries`.
+    #[test]
+    fn declared_invariants_are_linted_without_running() {
+        let term = |factor| VariableExpressionNode::new_term(VariableTermNode::new_factor(factor));
+        let var = |name: &str| term(VariableFactorNode::new_name(name));
+        let num = |n| term(VariableFactorNode::new_literal(VariableLiteral::Number(n)));
+        let invariant = |name: &str, rule| ScenarioInvariantConfig {
+            name: name.to_string(),
+            rule,
+            when: None,
+        };
+        let set_act = EventActionConfig::VariableSet(VariableSetActionConfig {
+            key: "act".to_string(),
+            expression: num(1.0),
+        });
+
+        let mut clean = scenario(vec![set_act.clone()], vec![]);
+        clean.invariants.push(ScenarioInvariantConfig {
+            when: Some(VariableConditionNode::new_less_than(var("act"), num(2.0))),
+            ..invariant(
+                "act_in_range",
+                InvariantRule::Never(VariableConditionNode::new_less_than(var("act"), num(1.0))),
+            )
+        });
+        let issues = lint_scenario(
+            &clean,
+            &sections(&[]),
+            &ships(&[]),
+            &known(&["test_scenario"]),
+        );
+        assert!(issues.is_empty(), "{issues:?}");
+
+        let mut broken = scenario(vec![set_act], vec![]);
+        broken.invariants = vec![
+            invariant(
+                "",
+                InvariantRule::Always(VariableConditionNode::new_greater_than(
+                    var("act"),
+                    num(0.0),
+                )),
+            ),
+            invariant(
+                "typo",
+                InvariantRule::Always(VariableConditionNode::new_greater_than(
+                    var("atc"),
+                    num(0.0),
+                )),
+            ),
+            invariant(
+                "typo",
+                InvariantRule::Always(VariableConditionNode::new_less_than(
+                    var("act"),
+                    term(VariableFactorNode::new_literal(VariableLiteral::String(
+                        "two".to_string(),
+                    ))),
+                )),
+            ),
+            invariant(
+                "constant",
+                InvariantRule::Never(VariableConditionNode::new_equals(num(1.0), num(2.0))),
+            ),
+            invariant(
+                "lost_courier",
+                InvariantRule::Never(VariableConditionNode::new_greater_than(
+                    term(VariableFactorNode::new_query(QueryConfig::Entity(
+                        EntityQuery {
+                            filter: EntityQueryFilter {
+                                id: "courier".to_string(),
+                            },
+                            property: EntityProperty::Speed,
+                        },
+                    ))),
+                    num(50.0),
+                )),
+            ),
+        ];
+        let issues = lint_scenario(
+            &broken,
+            &sections(&[]),
+            &ships(&[]),
+            &known(&["test_scenario"]),
+        );
+        let has = |severity: LintSeverity, needle: &str| {
+            issues
+                .iter()
+                .any(|issue| issue.severity == severity && issue.message.contains(needle))
+        };
+        assert!(has(LintSeverity::Error, "empty name"), "{issues:?}");
+        assert!(
+            has(LintSeverity::Error, "duplicate invariant name 'typo'"),
+            "{issues:?}"
+        );
+        assert!(has(LintSeverity::Warn, "variable 'atc'"), "{issues:?}");
+        assert!(has(LintSeverity::Error, "orders a String"), "{issues:?}");
+        assert!(
+            has(LintSeverity::Warn, "'constant' reads no variable"),
+            "{issues:?}"
+        );
+        assert!(has(LintSeverity::Error, "targets 'courier'"), "{issues:?}");
+    }
 }
@@ -916,6 +916,7 @@ mod tests {
                     kind: SectionKind::Hull(HullSectionConfig {
                         render_mesh: None,
                         render_mesh_transform: None,
+                        cargo_capacity: 0,
                     }),
                 }),
                 modifications: vec![],
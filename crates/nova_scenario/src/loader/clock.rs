@@ -1,9 +1,10 @@
 //! Typed query sampling, keyed timers, and the per-frame scenario pulse.
 
 use avian3d::prelude::LinearVelocity;
-use bevy::{platform::collections::HashMap, prelude::*};
+use bevy::{ecs::schedule::ScheduleLabel, platform::collections::HashMap, prelude::*};
 use nova_events::prelude::*;
 use nova_gameplay::prelude::*;
+use nova_ship::prelude::CargoHold;
 
 use super::scenario_is_live;
 use crate::prelude::*;
@@ -11,7 +12,8 @@ use crate::prelude::*;
 /// Accumulate the scenario clock. Registered CHAINED AHEAD of
 /// [`fire_on_update`] under the same live+unpaused gate, so the pulse that
 /// evaluates time-gated handlers always sees this frame's clock; pausing
-/// (ESC menu or the outcome frame) freezes the clock by construction.
+/// (ESC menu or the outcome frame) freezes the clock by construction. In
+/// lockstep `Time` is the fixed clock, so it advances one step at a time.
 pub(super) fn tick_scenario_clock(time: Res<Time>, mut world: ResMut<NovaEventWorld>) {
     world.advance_scenario_elapsed(time.delta_secs_f64());
 }
@@ -27,32 +29,42 @@ fn scenario_reads_an_entity_query(world: Res<NovaEventWorld>) -> bool {
     world.reads_entity_queries()
 }
 
-/// Sample every exact-id entity speed once for a coherent query snapshot.
-/// Entities without velocity are outside the `Speed` query domain. In
+/// Sample every exact-id entity speed and cargo hold once for a coherent query
+/// snapshot. Entities without velocity are outside the entity query domain. In
 /// particular, ship section children carry reusable section-local `EntityId`s;
 /// scanning them would falsely report duplicates across unrelated ships.
 fn sample_scenario_queries(
-    entities: Query<(&EntityId, &LinearVelocity)>,
+    entities: Query<(&EntityId, &LinearVelocity, Option<&CargoHold>)>,
     mut world: ResMut<NovaEventWorld>,
 ) {
     let mut speeds = HashMap::new();
-    for (id, velocity) in &entities {
+    let mut cargo = HashMap::new();
+    for (id, velocity, hold) in &entities {
         let value = Some(velocity.length() as f64);
         match speeds.entry(id.0.clone()) {
             bevy::platform::collections::hash_map::Entry::Vacant(entry) => {
                 entry.insert(value);
+                if let Some(hold) = hold {
+                    let stacks = hold
+                        .stacks()
+                        .map(|(item, count)| (item.to_string(), count))
+                        .collect();
+                    cargo.insert(id.0.clone(), stacks);
+                }
             }
             bevy::platform::collections::hash_map::Entry::Occupied(mut entry) => {
                 if entry.get().is_some() {
                     error!(
-                        "entity speed query id '{}' matched more than one entity; value is unavailable",
+                        "entity query id '{}' matched more than one entity; value is unavailable",
                         id.0
                     );
                 }
                 entry.insert(None);
+                cargo.remove(&id.0);
             }
         }
     }
+    world.sample_entity_cargo(cargo);
     world.sample_entity_speeds(speeds);
 }
 
@@ -77,22 +89,31 @@ fn tick_scenario_timers(mut commands: Commands, mut world: ResMut<NovaEventWorld
 /// scenario's queued spawns are still landing the world is not yet LIVE, so
 /// its clock must not advance, its timers must not expire and the pulse must
 /// not offer handlers a half-built world to read.
+///
+/// Registered in both `Update` and `FixedUpdate`, and only one of them runs:
+/// the frame's copy in play, the fixed step's in
+/// [lockstep](nova_gameplay::lockstep), where a recorded run needs its clock
+/// and its beats on the tick the simulation reached rather than on whichever
+/// frame followed it.
 pub(super) fn register_clock_and_pulse(app: &mut App) {
-    app.add_systems(
-        Update,
-        (
-            tick_scenario_clock,
-            sample_scenario_queries.run_if(scenario_reads_an_entity_query),
-            tick_scenario_timers,
-            fire_on_update,
-        )
-            .chain()
-            .run_if(
-                scenario_is_live
-                    .and_then(in_state(PauseStates::Unpaused))
-                    .and_then(scenario_has_settled),
-            ),
-    );
+    for (schedule, lockstep) in [(Update.intern(), false), (FixedUpdate.intern(), true)] {
+        app.add_systems(
+            schedule,
+            (
+                tick_scenario_clock,
+                sample_scenario_queries.run_if(scenario_reads_an_entity_query),
+                tick_scenario_timers,
+                fire_on_update,
+            )
+                .chain()
+                .run_if(
+                    scenario_is_live
+                        .and_then(in_state(PauseStates::Unpaused))
+                        .and_then(scenario_has_settled)
+                        .and_then(simulation_lockstep(lockstep)),
+                ),
+        );
+    }
 }
 
 /// The per-frame pulse behind `EventConfig::OnUpdate` handlers. Scenarios
@@ -22,6 +22,7 @@ pub(crate) fn spawn_object_action() -> EventActionConfig {
             invulnerable: false,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     })
 }
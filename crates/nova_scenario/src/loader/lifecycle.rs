@@ -9,7 +9,7 @@ use nova_hud::prelude::*;
 use nova_ship::prelude::*;
 
 use super::{scenario_is_live, ScenarioLoaded};
-use crate::prelude::*;
+use crate::{prelude::*, survival::SurvivalLoad};
 
 /// Ships act only while a scenario is live: gate the spaceship input/section
 /// sets on [`scenario_is_live`]. Owned here rather than by the editor (which
@@ -46,6 +46,7 @@ fn teardown_scenario_entities(
     outcome: Option<&mut CurrentOutcome>,
     objectives: Option<&mut GameObjectives>,
     story_feed: Option<&mut StoryFeed>,
+    music: Option<&mut MusicDirector>,
 ) {
     world.clear();
     // The objectives HUD mirror dies with the scenario too (same reset class as
@@ -87,6 +88,23 @@ fn teardown_scenario_entities(
     if let Some(outcome) = outcome {
         outcome.0 = None;
     }
+    // The scenario's score dies with it too: a `SetMusic` track would
+    // otherwise play on over the menu or under a next scenario that never
+    // asked for music. Fading out rather than cutting is the music systems'
+    // business; this only withdraws the request.
+    if let Some(music) = music {
+        if music.track.is_some() {
+            music.track = None;
+        }
+    }
+    // The scenario's post-processing look dies with it: a `SetPostProfile`
+    // grade would otherwise tint the menu. Queued rather than a parameter -
+    // the blend only exists where the post plugin runs (not headless).
+    commands.queue(|world: &mut World| {
+        if let Some(mut blend) = world.get_resource_mut::<PostProfileBlend>() {
+            blend.cut(PostProfile::default());
+        }
+    });
     for entity in q_scoped.iter() {
         commands.entity(entity).despawn();
     }
@@ -102,6 +120,7 @@ pub(super) fn unload_scenario(
     mut outcome: Option<ResMut<CurrentOutcome>>,
     mut objectives: Option<ResMut<GameObjectives>>,
     mut story_feed: Option<ResMut<StoryFeed>>,
+    mut music: Option<ResMut<MusicDirector>>,
 ) {
     teardown_scenario_entities(
         &mut commands,
@@ -111,6 +130,7 @@ pub(super) fn unload_scenario(
         outcome.as_deref_mut(),
         objectives.as_deref_mut(),
         story_feed.as_deref_mut(),
+        music.as_deref_mut(),
     );
     **current_scenario = None;
 }
@@ -125,9 +145,13 @@ pub(super) fn on_load_scenario(
     mut outcome: Option<ResMut<CurrentOutcome>>,
     mut objectives: Option<ResMut<GameObjectives>>,
     mut story_feed: Option<ResMut<StoryFeed>>,
+    mut music: Option<ResMut<MusicDirector>>,
     asset_server: Res<AssetServer>,
     issues: Option<Res<ContentIssues>>,
     mut failure: Option<ResMut<ScenarioStartFailure>>,
+    difficulty: Option<Res<Difficulty>>,
+    pinned: Option<Res<DifficultyOverride>>,
+    survival: SurvivalLoad,
 ) {
     // The runtime content gate: a scenario with Error-level findings REFUSES to
     // start - better a clear failure than a silently half-spawned scene.
@@ -168,13 +192,32 @@ pub(super) fn on_load_scenario(
         outcome.as_deref_mut(),
         objectives.as_deref_mut(),
         story_feed.as_deref_mut(),
+        music.as_deref_mut(),
     );
 
-    let scenario = (**load).clone();
+    // A survival template is stamped with the run's seed and KEPT as the
+    // current scenario, so a Retry re-expands the same waves; what runs is its
+    // expansion.
+    let mut template = (**load).clone();
+    survival.stamp(&mut template);
+    **current_scenario = Some(template.clone());
+    let scenario = survival.expand(template);
     world.set_watches(scenario.watches.clone(), scenario.reads_an_entity_query());
-    **current_scenario = Some(scenario.clone());
+    // Stamped before the handlers spawn, so `OnStart` can already branch on
+    // the tier the player picked (or the one a replay pinned).
+    let setting = difficulty.map(|difficulty| *difficulty).unwrap_or_default();
+    world.set_difficulty(pinned.map_or(setting, |pinned| pinned.tier(setting)));
     debug!("on_load_scenario: scenario {:?}", scenario.name);
 
+    // The authored look is a cut, not a blend: the first frame is already
+    // the scenario's. Queued after teardown's reset to the default.
+    let post = scenario.post.clone().unwrap_or_default();
+    commands.queue(move |world: &mut World| {
+        if let Some(mut blend) = world.get_resource_mut::<PostProfileBlend>() {
+            blend.cut(post);
+        }
+    });
+
     // `SfxListenerMarker` makes this the explicit
     // SFX/juice listener (attenuation, camera shake, flash facing); the editor
     // camera deliberately never carries it.
@@ -197,7 +240,11 @@ pub(super) fn on_load_scenario(
         WASDCameraController,
         Transform::from_xyz(0.0, 10.0, 20.0).looking_at(Vec3::ZERO, Vec3::Y),
         PendingSkyboxSwap {
-            cubemap: scenario.cubemap.resolve(&asset_server),
+            cubemap: scenario
+                .cubemap
+                .clone()
+                .with_key_light(scenario.key_light_direction())
+                .resolve(&asset_server),
             brightness: Some(1000.0),
         },
     ));
@@ -297,13 +344,14 @@ pub(super) fn on_add_entity_with<T: Component>(
 #[derive(Component, Debug, Clone)]
 pub(super) struct ScenarioInputMarker;
 
+/// The scenario-advance key: releases a queued next scenario, leaves a
+/// finished one, and skips a playing camera track.
 #[derive(InputAction)]
 #[action_output(bool)]
-pub(super) struct NextScenarioInput;
+pub(crate) struct NextScenarioInput;
 
 /// What the scenario-advance input does, given the current state. Extracted
-/// from the observer so the decision table is unit-testable (synthesizing a
-/// bevy_enhanced_input `Start<>` trigger in a rig is not worth the harness).
+/// from the observer so the decision table is unit-testable.
 #[derive(Debug, PartialEq, Eq)]
 enum AdvanceDecision {
     /// A lingering `NextScenario` is queued: release it.
@@ -334,7 +382,9 @@ fn decide_advance(paused: bool, has_queued: bool, has_outcome: bool) -> AdvanceD
 
 pub(super) fn on_next_input(
     _: On<Start<NextScenarioInput>>,
+    mut commands: Commands,
     mut world: ResMut<crate::world::NovaEventWorld>,
+    mut q_tracks: Query<&mut CameraTrackPlayback>,
     pause: Res<State<PauseStates>>,
     outcome: Option<Res<CurrentOutcome>>,
     mut game_state: Option<ResMut<NextState<GameStates>>>,
@@ -343,6 +393,11 @@ pub(super) fn on_next_input(
     // overlay is up. Releases stay ungated so held keys clear cleanly during a
     // pause.
     let paused = pause.get().is_frozen();
+    // A skippable camera track takes the press whole: the cutscene ends, and
+    // a queued next scenario waits for the next press.
+    if !paused && crate::actions::skip_camera_tracks(&mut commands, &mut q_tracks) {
+        return;
+    }
     let has_queued = world.next_scenario.is_some();
     let has_outcome = outcome.map(|o| o.0.is_some()).unwrap_or(false);
 
@@ -592,6 +647,73 @@ mod tests {
         assert_eq!(decide_advance(false, false, false), AdvanceDecision::Ignore);
     }
 
+    /// A skippable camera track takes the advance press whole: the first press
+    /// cuts the cutscene and leaves the queued `NextScenario` lingering, and
+    /// only the next one releases it.
+    #[test]
+    fn a_track_skip_spends_the_press_before_a_queued_next_scenario() {
+        use bevy::state::app::StatesPlugin;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<PauseStates>();
+        app.init_resource::<NovaEventWorld>();
+        app.add_observer(on_next_input);
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .next_scenario = Some(NextScenarioActionConfig {
+            scenario_id: "chapter_two".to_string(),
+            linger: true,
+            delay: None,
+        });
+        let track = PlayCameraTrackActionConfig {
+            id: "outro".to_string(),
+            keys: vec![CameraTrackKeyConfig {
+                time: 5.0,
+                position: CameraTrackPointConfig::World(Vec3::Z),
+                look_at: CameraTrackPointConfig::World(Vec3::ZERO),
+                fov: None,
+            }],
+            blend_in: 0.0,
+            blend_out: 1.0,
+            letterbox: 0.0,
+            skippable: true,
+        };
+        let camera = app
+            .world_mut()
+            .spawn((Transform::default(), CameraTrackPlayback::new(track, None)))
+            .id();
+        app.update();
+
+        let press = |app: &mut App| {
+            app.world_mut().trigger(Start::<NextScenarioInput> {
+                context: camera,
+                action: Entity::PLACEHOLDER,
+                value: true,
+                state: TriggerState::Fired,
+            });
+            app.update();
+        };
+        let lingering = |app: &App| {
+            app.world()
+                .resource::<NovaEventWorld>()
+                .next_scenario
+                .as_ref()
+                .map(|next| next.linger)
+        };
+
+        press(&mut app);
+        assert!(app
+            .world()
+            .get::<CameraTrackPlayback>(camera)
+            .unwrap()
+            .is_handing_back());
+        assert_eq!(lingering(&app), Some(true), "the skip spent the press");
+
+        press(&mut app);
+        assert_eq!(lingering(&app), Some(false), "the next press advances");
+    }
+
     /// A declared outcome dies with its scenario: the unload teardown resets
     /// `CurrentOutcome` alongside the scoped-entity sweep.
     #[test]
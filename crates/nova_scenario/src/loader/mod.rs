@@ -14,7 +14,10 @@ use bevy_enhanced_input::prelude::*;
 use nova_gameplay::prelude::*;
 use nova_ship::prelude::*;
 
-use crate::prelude::*;
+use crate::{
+    actions::{apply_camera_tracks, sync_camera_track_letterbox, tick_camera_tracks},
+    prelude::*,
+};
 
 mod clock;
 #[cfg(test)]
@@ -25,13 +28,16 @@ pub mod preload;
 mod trackers;
 
 use clock::register_clock_and_pulse;
+pub(crate) use lifecycle::NextScenarioInput;
 pub use lifecycle::ScenarioCameraMarker;
 use lifecycle::{
     configure_scenario_gating, on_load_scenario, on_next_input, on_player_spaceship_destroyed,
     on_player_spaceship_spawned, register_scenario_scoping, unload_scenario, ScenarioInputMarker,
 };
 use preload::register_scenario_preload;
-use trackers::{track_orbit_transitions, track_player_locks, LockEcho, OrbitEcho};
+use trackers::{
+    track_docking, track_orbit_transitions, track_player_locks, DockEcho, LockEcho, OrbitEcho,
+};
 
 /// Glob-import surface: `use nova_scenario::loader::prelude::*` brings the
 /// scenario registry resources, load/unload triggers, and markers into scope.
@@ -161,9 +167,12 @@ pub struct ScenarioConfig {
     pub name: String,
     /// A brief description of the scenario
     pub description: String,
-    /// The cubemap image used for the scenario's skybox. Authored as an asset
-    /// path; resolved to a live handle at load time (see `on_load_scenario`).
-    pub cubemap: AssetRef<Image>,
+    /// The scenario's skybox: a stacked cubemap image authored as an asset
+    /// path, or a procedural sky generated from a seed
+    /// (`cubemap: (seed: 7, star_density: 0.4)`, see [`ProceduralSkyConfig`]).
+    /// Resolved to a live handle at load time (see `on_load_scenario`); a
+    /// procedural sun with no direction follows [`Self::key_light_direction`].
+    pub cubemap: SkyboxSource,
     /// An optional thumbnail image for menus (the Scenarios picker renders it in
     /// the details pane). Authored as an asset path exactly like `cubemap`, so a
     /// mod thumbnail gets the same path handling. Serde-defaulted, so scenarios
@@ -192,12 +201,39 @@ pub struct ScenarioConfig {
     /// Serde-defaulted to false; author as `menu_backdrop: true`.
     #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
     pub menu_backdrop: bool,
+    /// When set, the scenario is a SURVIVAL template: its own events stage the
+    /// arena and the player ship, and every load appends seeded, escalating
+    /// hostile waves generated from the ship catalog (see
+    /// [`crate::survival`]). Serde-defaulted; author as
+    /// `survival: Some((waves: 8))`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub survival: Option<SurvivalConfig>,
+    /// The scenario's post-processing look: tonemapper, bloom, colour grade,
+    /// vignette and colour wash (see [`PostProfile`]). Applied as a cut on
+    /// load; `SetPostProfile` blends away from it mid-scenario, and teardown
+    /// restores the default look. `None` is the default look. Serde-defaulted;
+    /// author as `post: Some((tonemapping: AgX, saturation: 0.8))`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub post: Option<PostProfile>,
     /// Read-only queries sampled into auto-updating scenario variables.
     #[cfg_attr(
         feature = "serde",
         serde(default, skip_serializing_if = "Vec::is_empty")
     )]
     pub watches: Vec<WatchConfig>,
+    /// What the scenario promises never happens while it runs, checked every
+    /// frame by an armed probe run (see [`crate::invariants`]).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub invariants: Vec<ScenarioInvariantConfig>,
     /// Events associated with the scenario
     #[cfg_attr(
         feature = "serde",
@@ -208,8 +244,9 @@ pub struct ScenarioConfig {
 
 impl ScenarioConfig {
     /// A scenario with only its three REQUIRED fields set: everything else
-    /// (`description`, `thumbnail`, `hidden`, `menu_backdrop`, `events`) takes
-    /// its empty value, to be overridden through struct-update syntax.
+    /// (`description`, `thumbnail`, `hidden`, `menu_backdrop`, `survival`,
+    /// `post`, `watches`, `invariants`, `events`) takes its empty value, to be
+    /// overridden through struct-update syntax.
     ///
     /// # Panics
     ///
@@ -221,7 +258,7 @@ impl ScenarioConfig {
     /// straight into the struct.
     // `cubemap` stays a concrete `AssetRef<Image>`: an `impl Into` there makes
     // the callers' own `handle.into()` ambiguous, since half of bevy converts
-    // a `Handle<Image>`.
+    // a `Handle<Image>`. A procedural sky is set through struct-update syntax.
     pub fn new(
         id: impl Into<ScenarioId>,
         name: impl Into<String>,
@@ -239,17 +276,21 @@ impl ScenarioConfig {
             id,
             name: name.into(),
             description: String::new(),
-            cubemap,
+            cubemap: cubemap.into(),
             thumbnail: None,
             hidden: false,
             menu_backdrop: false,
+            survival: None,
+            post: None,
             watches: Vec::new(),
+            invariants: Vec::new(),
             events: Vec::new(),
         }
     }
 
-    /// Every typed query the scenario's HANDLERS read as an inline expression
-    /// factor, in source order. [`ScenarioConfig::watches`] declares the rest.
+    /// Every typed query the scenario's HANDLERS and INVARIANTS read as an
+    /// inline expression factor, in source order. [`ScenarioConfig::watches`]
+    /// declares the rest.
     ///
     /// Two readers, and they must see the same list: the lint checks each
     /// query's target against what the scenario can spawn, and the loader
@@ -276,6 +317,9 @@ impl ScenarioConfig {
                 }
             }
         }
+        for invariant in &self.invariants {
+            out.extend(invariant.queries());
+        }
         out
     }
 
@@ -293,6 +337,44 @@ impl ScenarioConfig {
         self.watches.iter().any(|watch| is_entity(&watch.query))
             || self.inline_queries().into_iter().any(is_entity)
     }
+
+    /// The direction the scenario's key light shines FROM: its shadow-casting
+    /// directional `Light` spawned `OnStart`, else its brightest one. `None`
+    /// when it authors no directional light.
+    ///
+    /// A procedural sky's sun with no direction of its own is placed here, so
+    /// the disk sits where the shading says the light comes from. The key wins
+    /// on its shadow flag rather than raw lux because a three-point rig's rim
+    /// is brighter than its key.
+    pub fn key_light_direction(&self) -> Option<Vec3> {
+        self.events
+            .iter()
+            .filter(|event| matches!(event.name, EventConfig::OnStart))
+            .flat_map(|event| &event.actions)
+            .filter_map(|action| match action {
+                EventActionConfig::SpawnScenarioObject(ScenarioObjectConfig {
+                    base,
+                    kind:
+                        ScenarioObjectKind::Light(LightConfig::Directional {
+                            illuminance,
+                            shadows,
+                            aim,
+                            ..
+                        }),
+                }) => {
+                    // A directional light shines down its -Z; the sun sits
+                    // back up it.
+                    let toward = match aim {
+                        Some(target) => (base.position - *target).try_normalize()?,
+                        None => base.rotation * Vec3::Z,
+                    };
+                    Some(((*shadows, *illuminance), toward))
+                }
+                _ => None,
+            })
+            .max_by(|(a, _), (b, _)| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)))
+            .map(|(_, toward)| toward)
+    }
 }
 
 /// `skip_serializing_if` predicate for a `bool` that defaults to false: omit it
@@ -344,6 +426,9 @@ pub struct ScenarioLoaded {
     /// The number of scenario objects the scenario will spawn, counted from the
     /// `SpawnScenarioObject` actions across all of its events.
     pub object_count: usize,
+    /// The seed a survival scenario's waves were built from; `None` for any
+    /// other scenario. A recording keeps it so the replay rebuilds the waves.
+    pub survival_seed: Option<u64>,
 }
 
 impl ScenarioLoaded {
@@ -360,6 +445,10 @@ impl ScenarioLoaded {
             scenario_id: scenario.id.clone(),
             handler_count: scenario.events.len(),
             object_count,
+            survival_seed: scenario
+                .survival
+                .as_ref()
+                .and_then(|survival| survival.seed),
         }
     }
 }
@@ -389,7 +478,7 @@ pub fn scenario_is_live(current: Res<CurrentScenario>) -> bool {
 /// [`UnloadScenario`] observers, gates the spaceship input/section sets on
 /// [`scenario_is_live`], and adds the clock tick + OnUpdate pulse plus the
 /// orbit/lock/skybox/scripted-camera trackers (mostly `Update`, with the
-/// scripted-camera enforce in `PostUpdate`).
+/// scripted-camera enforce and the camera-track writer in `PostUpdate`).
 pub struct ScenarioLoaderPlugin {
     /// Whether section render meshes are built at all; gates the
     /// [`preload`] warm-up. Mirrors `NovaScenarioPlugin::render`.
@@ -446,6 +535,16 @@ impl Plugin for ScenarioLoaderPlugin {
                 .run_if(scenario_is_live),
         );
 
+        // Docking edges: the latch and the release, read off `Docked` after
+        // the flight layer has latched or released the ship this tick.
+        app.register_type::<DockEcho>();
+        app.add_systems(
+            FixedUpdate,
+            track_docking
+                .after(NovaFlightSystems)
+                .run_if(scenario_is_live),
+        );
+
         // Player lock lifecycle edges. AI locks remain gameplay-internal.
         app.register_type::<LockEcho>();
         app.add_systems(
@@ -464,6 +563,15 @@ impl Plugin for ScenarioLoaderPlugin {
         app.register_type::<PendingSkyboxSwap>();
         app.add_systems(Update, apply_pending_skybox_swaps.run_if(scenario_is_live));
 
+        // The score's `Variable` stems follow the scenario variables. Ungated
+        // like the skybox applier: between scenarios the cleared variables
+        // publish an empty set, which is exactly what drops those stems.
+        // Optional: the music cues belong to the audio plugin.
+        app.add_systems(
+            Update,
+            feed_music_variables.run_if(resource_exists::<MusicCues>),
+        );
+
         // Scripted-camera override (photo mode / the capture scripts): the
         // `SetCamera` action pins a `ScriptedCameraPose` on the scenario camera;
         // enforce it in `CameraAuthoritySystems::Override`, the phase that runs after
@@ -486,6 +594,28 @@ impl Plugin for ScenarioLoaderPlugin {
             PostUpdate,
             enforce_scripted_camera_pose.in_set(CameraAuthoritySystems::Override),
         );
+
+        // Cinematic camera tracks (`PlayCameraTrack`). The clock ticks
+        // live-and-unpaused, so a cutscene holds its frame behind the pause
+        // menu; the frame itself is written in the override phase AFTER the
+        // scripted pose, so a track eases off a `SetCamera` still as readily
+        // as off the chase rig, and back onto it. The skip rides the
+        // scenario-advance input (`on_next_input`).
+        app.add_systems(
+            Update,
+            (
+                tick_camera_tracks.run_if(in_state(PauseStates::Unpaused)),
+                sync_camera_track_letterbox,
+            )
+                .chain()
+                .run_if(scenario_is_live),
+        );
+        app.add_systems(
+            PostUpdate,
+            apply_camera_tracks
+                .in_set(CameraAuthoritySystems::Override)
+                .after(enforce_scripted_camera_pose),
+        );
     }
 }
 
@@ -605,6 +735,7 @@ mod tests {
                 input_mapping,
                 speed_cap: Some(100.0),
                 infinite_ammo: true,
+                repair_parts: None,
             }),
             hull: ShipSource::Inline(ShipHull {
                 sections: vec![SpaceshipSectionConfig {
@@ -647,6 +778,7 @@ mod tests {
                             invulnerable: false,
                             seed: None,
                             lock_signature: None,
+                            ore: Default::default(),
                         }),
                     }),
                     EventActionConfig::SpawnScenarioObject(ScenarioObjectConfig {
@@ -746,11 +878,14 @@ mod tests {
             id: "cfg".to_string(),
             name: "Configured".to_string(),
             description: "new".to_string(),
-            cubemap: AssetRef::from("sky.png"),
+            cubemap: AssetRef::from("sky.png").into(),
             thumbnail: Some(AssetRef::from("thumb.png")),
             hidden: true,
             menu_backdrop: true,
+            survival: None,
+            post: None,
             watches: vec![],
+            invariants: vec![],
             events: vec![],
         };
         // `ron::to_string` is compact (no spaces after colons).
@@ -775,6 +910,87 @@ mod tests {
         assert!(!bare.contains("menu_backdrop"), "ron: {bare}");
     }
 
+    /// `cubemap` takes a procedural sky in place of a path, and the path form
+    /// every existing scenario authors still parses as an image.
+    #[test]
+    fn cubemap_parses_a_path_or_a_procedural_sky() {
+        let path: ScenarioConfig =
+            ron::from_str(r#"(id: "p", name: "P", description: "", cubemap: "sky.png")"#)
+                .expect("path cubemap parses");
+        assert_eq!(path.cubemap.path(), Some("sky.png"));
+
+        let authored = r#"(id: "g", name: "G", description: "", cubemap: (
+            seed: 7,
+            star_density: 0.5,
+            nebula_colors: [(0.4, 0.1, 0.5)],
+            galaxy_band: Some((width: 0.1)),
+            sun: Some((size: 2.0)),
+        ))"#;
+        let parsed: ScenarioConfig = ron::from_str(authored).expect("procedural cubemap parses");
+        let SkyboxSource::Procedural(sky) = &parsed.cubemap else {
+            panic!("expected a procedural sky, got {:?}", parsed.cubemap);
+        };
+        assert_eq!(sky.seed, 7);
+        assert_eq!(sky.star_density, 0.5);
+        assert_eq!(sky.nebula_colors, [[0.4, 0.1, 0.5]]);
+        assert_eq!(sky.galaxy_band.as_ref().map(|band| band.width), Some(0.1));
+        assert_eq!(sky.sun.as_ref().and_then(|sun| sun.direction), None);
+        assert_eq!(sky.face_size, ProceduralSkyConfig::default().face_size);
+
+        let ron = ron::to_string(&parsed).expect("procedural sky serializes");
+        let back: ScenarioConfig = ron::from_str(&ron).expect("procedural sky re-parses");
+        assert_eq!(back.cubemap, parsed.cubemap);
+    }
+
+    /// The procedural sun follows the key: the shadow-casting directional
+    /// light, even when a rim light is brighter, with `aim` beating rotation.
+    #[test]
+    fn key_light_direction_picks_the_shadow_caster() {
+        let directional = |id: &str, from: Vec3, illuminance: f32, shadows: bool, aim| {
+            EventActionConfig::SpawnScenarioObject(ScenarioObjectConfig {
+                base: aimed_light_base(id, id, from, Vec3::ZERO),
+                kind: ScenarioObjectKind::Light(LightConfig::Directional {
+                    illuminance,
+                    color: Color::WHITE,
+                    shadows,
+                    aim,
+                }),
+            })
+        };
+        let key_from = Vec3::new(-6.0, 5.0, 6.0);
+        let scenario = |actions| ScenarioConfig {
+            events: vec![ScenarioEventConfig {
+                name: EventConfig::OnStart,
+                filters: vec![],
+                actions,
+            }],
+            ..ScenarioConfig::new("lit", "Lit", AssetRef::from("sky.png"))
+        };
+
+        let rigged = scenario(vec![
+            directional("key", key_from, 11000.0, true, None),
+            directional("rim", Vec3::new(3.0, 4.0, -8.0), 16000.0, false, None),
+        ]);
+        let toward = rigged.key_light_direction().expect("a key light");
+        assert!(toward.angle_between(key_from) < 1e-4, "{toward}");
+
+        // `aim` overrides the base rotation.
+        let aimed = scenario(vec![directional(
+            "key",
+            key_from,
+            11000.0,
+            true,
+            Some(Vec3::new(0.0, 5.0, 0.0)),
+        )]);
+        let toward = aimed.key_light_direction().expect("a key light");
+        assert!(
+            toward.angle_between(Vec3::new(-1.0, 0.0, 1.0)) < 1e-4,
+            "{toward}"
+        );
+
+        assert_eq!(scenario(vec![]).key_light_direction(), None);
+    }
+
     /// A campaign parses from a HAND-WRITTEN RON string (not just a
     /// self-authored round-trip) and round-trips, preserving member order
     /// including hidden ids. A campaign is a first-class content entity, so
@@ -161,6 +161,8 @@ fn preload_scenario_render_meshes(
     let Some(scenario) = &**current else {
         return;
     };
+    // The generated waves spawn catalog ships the template never names.
+    let scenario = &expand_survival(scenario.clone(), &sections, &ships);
     let meshes = scenario_render_meshes(scenario, &ships, &sections);
     preload.handles = meshes
         .iter()
@@ -305,6 +307,7 @@ mod tests {
             kind: SectionKind::Hull(HullSectionConfig {
                 render_mesh: Some(AssetRef::from(path)),
                 render_mesh_transform: None,
+                cargo_capacity: 0,
             }),
         }
     }
@@ -1,4 +1,5 @@
-//! Orbit-lifecycle and weapon-lock events derived from live ship state.
+//! Orbit-lifecycle, weapon-lock and docking events derived from live ship
+//! state.
 
 use bevy::prelude::*;
 use nova_events::prelude::*;
@@ -214,6 +215,56 @@ pub(super) fn track_player_locks(
     }
 }
 
+/// The station a ship was last reported docked at.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component)]
+pub(super) struct DockEcho {
+    /// Scenario id retained so a despawned station can still produce an
+    /// undock edge.
+    pub station_id: String,
+}
+
+fn dock_info(
+    station_id: &str,
+    ship_id: &EntityId,
+    ship_type_name: &EntityTypeName,
+) -> DockEventInfo {
+    DockEventInfo {
+        id: station_id.to_string(),
+        other_id: ship_id.0.clone(),
+        other_type_name: ship_type_name.0.clone(),
+    }
+}
+
+/// Emit edge-triggered docking events. Like ORBIT, a despawned ship uses
+/// `OnDestroyed` and emits no undock; a station without an id stays quiet.
+pub(super) fn track_docking(
+    mut commands: Commands,
+    q_docked: Query<
+        (Entity, &Docked, &EntityId, &EntityTypeName),
+        (With<SpaceshipRootMarker>, Without<DockEcho>),
+    >,
+    q_undocked: Query<
+        (Entity, &DockEcho, &EntityId, &EntityTypeName),
+        (With<SpaceshipRootMarker>, Without<Docked>),
+    >,
+    q_ids: Query<&EntityId>,
+) {
+    for (ship, echo, ship_id, ship_type_name) in &q_undocked {
+        commands.fire::<OnUndockedEvent>(dock_info(&echo.station_id, ship_id, ship_type_name));
+        commands.entity(ship).remove::<DockEcho>();
+    }
+    for (ship, docked, ship_id, ship_type_name) in &q_docked {
+        let Ok(station_id) = q_ids.get(docked.station) else {
+            continue;
+        };
+        commands.fire::<OnDockedEvent>(dock_info(&station_id.0, ship_id, ship_type_name));
+        commands.entity(ship).insert(DockEcho {
+            station_id: station_id.0.clone(),
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -530,4 +581,73 @@ mod tests {
         );
         assert_eq!(count(&app, "combat_start"), 1.0, "AI locks never fire");
     }
+
+    /// Docking fires once on the latch and once on the release.
+    #[test]
+    fn docking_events_are_edge_triggered() {
+        use nova_events::prelude::{EventHandler, GameEventsPlugin};
+        use nova_gameplay::prelude::{GameObjectives, SpaceshipRootMarker};
+        use nova_ship::prelude::Docked;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_plugins(GameEventsPlugin::<NovaEventWorld>::default());
+        app.init_resource::<NovaEventWorld>();
+        app.init_resource::<GameObjectives>();
+        app.add_systems(Update, track_docking);
+
+        for (event, key) in [
+            (EventConfig::OnDocked, "docked"),
+            (EventConfig::OnUndocked, "undocked"),
+        ] {
+            let mut handler = EventHandler::<NovaEventWorld>::from(event);
+            handler.add_action(EventActionConfig::VariableSet(VariableSetActionConfig {
+                key: key.to_string(),
+                expression: VariableExpressionNode::new_add(
+                    VariableTermNode::new_factor(VariableFactorNode::new_name(key)),
+                    VariableExpressionNode::new_term(VariableTermNode::new_factor(
+                        VariableFactorNode::new_literal(VariableLiteral::Number(1.0)),
+                    )),
+                ),
+            }));
+            app.world_mut().spawn(handler);
+            app.world_mut()
+                .resource_mut::<NovaEventWorld>()
+                .insert_variable(key.to_string(), VariableLiteral::Number(0.0));
+        }
+        let count =
+            |app: &App, key: &str| match app.world().resource::<NovaEventWorld>().get_variable(key)
+            {
+                Some(VariableLiteral::Number(value)) => *value,
+                other => panic!("{key} count missing: {other:?}"),
+            };
+        let settle = |app: &mut App| {
+            app.update();
+            app.update();
+        };
+
+        let station = app.world_mut().spawn(EntityId::new("waystation")).id();
+        let port = app.world_mut().spawn_empty().id();
+        let ship = app
+            .world_mut()
+            .spawn((
+                SpaceshipRootMarker,
+                EntityId::new("player"),
+                EntityTypeName::new(SPACESHIP_TYPE_NAME),
+            ))
+            .id();
+
+        app.world_mut()
+            .entity_mut(ship)
+            .insert(Docked { port, station });
+        settle(&mut app);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(count(&app, "docked"), 1.0, "a held latch stays quiet");
+
+        app.world_mut().entity_mut(ship).remove::<Docked>();
+        settle(&mut app);
+        assert_eq!(count(&app, "undocked"), 1.0);
+    }
 }
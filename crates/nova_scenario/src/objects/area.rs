@@ -9,6 +9,7 @@
 use avian3d::prelude::*;
 use bevy::prelude::*;
 use nova_events::prelude::{CommandsGameEventExt, *};
+use nova_gameplay::prelude::GhostHullMarker;
 
 /// `ScenarioAreaMarker` and `ScenarioAreaPlugin`.
 pub mod prelude {
@@ -108,7 +109,7 @@ fn on_collision_start_event(
     mut commands: Commands,
     mut occupancy: ResMut<AreaOccupancy>,
     q_area: Query<&EntityId, With<ScenarioAreaMarker>>,
-    q_other: Query<(&EntityId, &EntityTypeName)>,
+    q_other: Query<(&EntityId, &EntityTypeName), Without<GhostHullMarker>>,
 ) {
     trace!(
         "on_collision_start_event: collision between {:?} and {:?}",
@@ -150,7 +151,7 @@ fn on_collision_end_event(
     mut commands: Commands,
     mut occupancy: ResMut<AreaOccupancy>,
     q_area: Query<&EntityId, With<ScenarioAreaMarker>>,
-    q_other: Query<(&EntityId, &EntityTypeName)>,
+    q_other: Query<(&EntityId, &EntityTypeName), Without<GhostHullMarker>>,
 ) {
     trace!(
         "on_collision_end_event: collision between {:?} and {:?}",
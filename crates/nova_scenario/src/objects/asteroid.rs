@@ -6,6 +6,8 @@
 //!
 //! Touch this module when changing what an authored asteroid spawns as.
 
+use std::collections::BTreeMap;
+
 use avian3d::prelude::*;
 // NOTE: bevy's platform Instant, not std's - `std::time::Instant::now` panics
 // on wasm32-unknown-unknown, which this crate ships to.
@@ -18,6 +20,7 @@ use nova_ship::prelude::*;
 
 use super::{
     asteroid_carve::pristine_rock_mesh,
+    asteroid_ore::prelude::AsteroidOre,
     asteroid_surface::prelude::{AsteroidSurfaceMaterial, AsteroidSurfaceMaterialExt},
 };
 
@@ -102,6 +105,14 @@ pub struct AsteroidConfig {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub seed: Option<u32>,
+    /// What the rock is made of, for mining: units of each item id per cubic
+    /// world unit of rock carved or cut away. Empty (the default) is barren
+    /// rock that yields nothing. See [`asteroid_ore`](super::asteroid_ore).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub ore: BTreeMap<String, f32>,
 }
 
 /// The silhouette seed an asteroid gets when its config authors none: a stable
@@ -210,6 +221,9 @@ pub fn asteroid_scenario_object(entity: &mut EntityCommands, config: AsteroidCon
         // what sequences the gravity well after this build.
         BodyRadius(radius * unit_extent),
     ));
+    if !config.ore.is_empty() {
+        entity.insert(AsteroidOre::new(config.ore));
+    }
 
     entity.with_children(|parent| {
         let mut node = parent.spawn((
@@ -1030,6 +1044,7 @@ mod tests {
             invulnerable: false,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }
     }
 
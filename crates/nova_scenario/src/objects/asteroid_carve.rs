@@ -79,6 +79,7 @@ use nova_ship::prelude::BodyRadius;
 
 use super::{
     asteroid::{AsteroidMarker, AsteroidRadius, AsteroidSeed},
+    asteroid_ore::{ore_chip_velocity, prelude::*},
     asteroid_surface::prelude::{AsteroidSurfaceMaterial, RockHeight},
 };
 
@@ -410,18 +411,34 @@ fn sever_piece(island: &SignedField, cubic_scale: f32) -> Option<CarvedPiece> {
 /// which is what makes it read as material that came loose rather than as
 /// something spawned nearby. The spin it inherits outright - a rigid body's
 /// pieces all turn at the body's rate.
+///
+/// `ore` is the rock's composition and scenario id when it bears any: a body
+/// carries its share of ore as an [`OreLoad`], and a crumb leaves its share as
+/// a chip where it crumbled.
 fn throw_severed_pieces(
     commands: &mut Commands,
     meshes: &mut Assets<Mesh>,
     parent: &Parent,
     pieces: Vec<CarvedPiece>,
+    mut ore: Option<(&mut AsteroidOre, &str)>,
 ) -> usize {
     let (scale, rotation, _) = parent.frame.to_scale_rotation_translation();
     let mut thrown = 0;
 
     for piece in pieces {
         let at = parent.frame.transform_point(piece.at);
+        let velocity = parent.linear + parent.angular.cross(at - parent.centre);
+        let load = ore.as_mut().and_then(|(ore, source)| {
+            let items = ore.accrue(piece.volume);
+            (!items.is_empty()).then(|| OreLoad {
+                source: source.to_string(),
+                items,
+            })
+        });
         let Some(body) = piece.body else {
+            if let Some(load) = load {
+                spawn_ore_chip(commands, at, velocity, load);
+            }
             commands.trigger(CarveSpew {
                 entity: parent.node,
                 at,
@@ -447,7 +464,7 @@ fn throw_severed_pieces(
                     rotation,
                     scale,
                 },
-                velocity: parent.linear + parent.angular.cross(at - parent.centre),
+                velocity,
                 spin: parent.angular,
                 collider: body.collider,
             },
@@ -456,6 +473,9 @@ fn throw_severed_pieces(
         if let Some(material) = parent.material.clone() {
             commands.entity(spawned).insert(material);
         }
+        if let Some(load) = load {
+            commands.entity(spawned).insert(load);
+        }
         thrown += 1;
     }
 
@@ -645,26 +665,70 @@ fn collect_asteroid_field_seeds(
 ///
 /// What is left here is the carve itself: a sphere subtraction over the cells
 /// one mark reaches, which is bounded by the mark and not by the grid.
+///
+/// It is also where an ore-bearing rock pays out for a crater: the volume a
+/// mark took is exactly the material that left, so it is what the ore is
+/// priced off, and it leaves as a chip at the mark.
 fn carve_asteroid_fields(
     mut commands: Commands,
     mut q_nodes: Query<
-        (Entity, &DamageMarks, &mut AsteroidField, &GlobalTransform),
+        (
+            Entity,
+            &DamageMarks,
+            &mut AsteroidField,
+            &GlobalTransform,
+            &ChildOf,
+        ),
         Without<AsteroidRemesh>,
     >,
+    mut q_ore: Query<(
+        &mut AsteroidOre,
+        Option<&EntityId>,
+        &GlobalTransform,
+        Option<&LinearVelocity>,
+    )>,
 ) {
-    for (node, marks, mut field, frame) in &mut q_nodes {
+    for (node, marks, mut field, frame, ChildOf(root)) in &mut q_nodes {
         if marks.0.is_empty() {
             continue;
         }
 
+        // The node's own scale, which is fixed at spawn: the grid is the rock's
+        // unit space and the piece threshold is a world size, so the worker has
+        // to be told the ratio it cannot see.
+        let (scale, _, _) = frame.to_scale_rotation_translation();
+        let cubic_scale = (scale.x * scale.y * scale.z).abs();
+
         let signature = AsteroidField::signature(marks);
         if field.applied != signature {
             field.applied = signature;
+            let mut ore = q_ore.get_mut(*root).ok();
             // EVERY mark, not just the ones that look new. Subtraction is a
             // max, so re-applying one already in the solid changes nothing -
             // and reports taking nothing, which is what keeps `volume` exact.
+            // It is also what keeps the ore honest: a re-applied mark yields
+            // nothing a second time.
             for mark in &marks.0 {
-                field.volume -= field.field.subtract_sphere(mark.at, mark.radius);
+                let taken = field.field.subtract_sphere(mark.at, mark.radius);
+                field.volume -= taken;
+                let Some((ore, id, body, velocity)) = ore.as_mut() else {
+                    continue;
+                };
+                let items = ore.accrue(taken * cubic_scale);
+                if items.is_empty() {
+                    continue;
+                }
+                let at = frame.transform_point(mark.at);
+                let velocity = velocity.map_or(Vec3::ZERO, |velocity| velocity.0);
+                spawn_ore_chip(
+                    &mut commands,
+                    at,
+                    ore_chip_velocity(at, body.translation(), velocity),
+                    OreLoad {
+                        source: id.map(|id| id.to_string()).unwrap_or_default(),
+                        items,
+                    },
+                );
             }
         }
 
@@ -682,11 +746,6 @@ fn carve_asteroid_fields(
         // the old mesh around a different internal solid.
         let candidate = field.field.clone();
         let tracked = field.volume;
-        // The node's own scale, which is fixed at spawn: the grid is the rock's
-        // unit space and the piece threshold is a world size, so the worker has
-        // to be told the ratio it cannot see.
-        let (scale, _, _) = frame.to_scale_rotation_translation();
-        let cubic_scale = (scale.x * scale.y * scale.z).abs();
         let task = AsyncComputeTaskPool::get()
             .spawn(async move { carve_surface(node, candidate, tracked, cubic_scale) });
         commands.entity(node).insert(AsteroidRemesh(task));
@@ -729,6 +788,7 @@ fn collect_asteroid_remeshes(
         Option<&LinearVelocity>,
         Option<&AngularVelocity>,
     )>,
+    mut q_ore: Query<&mut AsteroidOre>,
 ) {
     let started = Instant::now();
     let mut frame_cost = CarveApplyReport::default();
@@ -767,6 +827,9 @@ fn collect_asteroid_remeshes(
             angular,
             material: chunk_material.cloned(),
         };
+        let source = id.map(|id| id.to_string()).unwrap_or_default();
+        let mut ore = q_ore.get_mut(*root).ok();
+        let ore = ore.as_deref_mut().map(|ore| (ore, source.as_str()));
 
         if remaining_world < CHUNK_MIN_VOLUME || carved.surface.count_vertices() == 0 {
             trace!(
@@ -777,7 +840,7 @@ fn collect_asteroid_remeshes(
             // the hit that took it already threw the dust it was priced for, and
             // a second puff for the same material is the same round paid twice.
             frame_cost.pieces +=
-                throw_severed_pieces(&mut commands, &mut meshes, &parent, carved.pieces);
+                throw_severed_pieces(&mut commands, &mut meshes, &parent, carved.pieces, ore);
             // Reuse the common destruction cue seam without opting into its
             // health or random-fragment finale.
             commands.entity(node).insert(IntegrityDestroyMarker);
@@ -801,7 +864,7 @@ fn collect_asteroid_remeshes(
         // Validation succeeded. Only now may the field and its pieces become
         // observable.
         frame_cost.pieces +=
-            throw_severed_pieces(&mut commands, &mut meshes, &parent, carved.pieces);
+            throw_severed_pieces(&mut commands, &mut meshes, &parent, carved.pieces, ore);
         field.field = carved.field;
         field.volume = carved.volume;
         field.meshed_volume = carved.volume;
@@ -958,6 +1021,7 @@ mod tests {
                             material: None,
                         },
                         vec![piece.take().expect("the throw runs once")],
+                        None,
                     );
                 },
             )
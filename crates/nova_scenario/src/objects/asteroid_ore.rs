@@ -0,0 +1,513 @@
+//! Mining: what an ore-bearing rock gives up when it is carved, and how the
+//! player collects it.
+//!
+//! A rock authored with [`AsteroidConfig::ore`](super::asteroid::AsteroidConfig)
+//! carries an [`AsteroidOre`]: a yield per cubic world unit of each item. The
+//! carve pipeline already knows exactly how much material every hit took - it
+//! is the volume the crater's sphere subtraction reports - so ore is priced off
+//! that, not off damage. A round that grazes a rock yields a grazing amount; a
+//! mining laser that bites sixteen times deeper yields sixteen times as much.
+//!
+//! What comes loose travels as an [`OreLoad`]:
+//!
+//! - the material a crater took leaves as an ore CHIP at the mark, a small
+//!   glowing pickup with no collider;
+//! - a piece the carve cut free carries its own share, so a severed chunk is
+//!   itself worth scooping, and a crumb too small to be a body leaves a chip.
+//!
+//! Yields are fractional and holds are not, so the rock keeps what it owes
+//! between hits ([`AsteroidOre::accrue`]) and a load only ever carries whole
+//! units.
+//!
+//! Pickup is player-only, like salvage crates. Within
+//! [`ORE_TRACTOR_RANGE`] of a player ship whose hold has room, a load is
+//! drawn in by a tractor force; once its near side is within
+//! [`ORE_SCOOP_RADIUS`] it is scooped: as much as fits moves
+//! into the [`CargoHold`], `OnMined` fires once per item, and the scenario's
+//! `OreMined` total counts it. A load the hold cannot take stays where it is.
+//!
+//! Touch this module when changing what mining yields or how ore is collected.
+
+use std::collections::BTreeMap;
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use nova_events::prelude::*;
+use nova_gameplay::prelude::*;
+use nova_ship::prelude::CargoHold;
+
+use crate::prelude::*;
+
+/// `AsteroidOre`, `OreLoad`, the chip spawn and `AsteroidOrePlugin`.
+pub mod prelude {
+    pub use super::{
+        spawn_ore_chip, AsteroidOre, AsteroidOrePlugin, OreChipMarker, OreLoad, ORE_SCOOP_RADIUS,
+        ORE_TRACTOR_RANGE,
+    };
+}
+
+/// How close (world units, from the ship's centre to the load's near side) a
+/// load has to come to a player ship to be scooped into its hold.
+pub const ORE_SCOOP_RADIUS: f32 = 6.0;
+
+/// How far (world units) a player ship with room in its hold reaches to draw
+/// ore in.
+pub const ORE_TRACTOR_RANGE: f32 = 40.0;
+
+/// The speed (world units per second, relative to the ship) a tractored load
+/// closes at.
+const ORE_TRACTOR_SPEED: f32 = 14.0;
+
+/// How quickly a tractored load's velocity turns onto the pull, per second:
+/// the tractor accelerates by this times the velocity still to gain.
+/// High enough that a chip thrown off a rock comes about inside a second, low
+/// enough that it visibly swings round rather than snapping.
+const ORE_TRACTOR_RESPONSE: f32 = 3.0;
+
+/// How fast a chip leaves the crater it was cut from, on top of the rock's own
+/// motion.
+const ORE_CHIP_EJECT_SPEED: f32 = 3.0;
+
+/// Seconds an uncollected chip drifts before it is gone. Twice a chunk's
+/// lifetime: a chip is the thing the player is meant to go back for.
+const ORE_CHIP_LIFETIME_SECS: f32 = 60.0;
+
+/// Visible radius of a chip (world units), and its reach when scooped.
+const ORE_CHIP_RADIUS: f32 = 0.35;
+
+/// A chip's density: light enough to be nothing against a hull, but a mass
+/// all the same, so the tractor's force moves it like any other body.
+const ORE_CHIP_DENSITY: f32 = 1.0;
+
+/// Chip color: a cold blue-white, apart from the crate's orange so the two
+/// pickups never read as one another.
+const ORE_CHIP_COLOR: Color = Color::srgb(0.55, 0.85, 1.0);
+
+/// Chip self-glow: crate-bright, so it reads against the rock it came off.
+const ORE_CHIP_EMISSIVE: f32 = 5.0;
+
+/// What an ore-bearing rock is made of, and what it still owes.
+///
+/// Lives on the asteroid ROOT, inserted at spawn from the authored
+/// `AsteroidConfig::ore`. A barren rock has none.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct AsteroidOre {
+    /// Units of each item per cubic world unit of rock.
+    rates: BTreeMap<String, f32>,
+    /// The fraction of a unit of each item carved but not yet handed out.
+    owed: BTreeMap<String, f32>,
+}
+
+impl AsteroidOre {
+    /// A rock yielding `rates` units of each item per cubic world unit.
+    /// Non-positive rates are dropped.
+    pub fn new(rates: BTreeMap<String, f32>) -> Self {
+        Self {
+            rates: rates.into_iter().filter(|(_, rate)| *rate > 0.0).collect(),
+            owed: BTreeMap::new(),
+        }
+    }
+
+    /// Account for `volume` cubic world units of this rock coming loose, and
+    /// return the WHOLE units it yields. The fractions stay owed to the next
+    /// call, so a run of small hits yields what one big one would.
+    pub fn accrue(&mut self, volume: f32) -> BTreeMap<String, u32> {
+        let mut yielded = BTreeMap::new();
+        if volume.is_nan() || volume <= 0.0 {
+            return yielded;
+        }
+        for (item, rate) in &self.rates {
+            let owed = self.owed.entry(item.clone()).or_insert(0.0);
+            *owed += rate * volume;
+            let whole = owed.floor();
+            if whole >= 1.0 {
+                *owed -= whole;
+                yielded.insert(item.clone(), whole as u32);
+            }
+        }
+        yielded
+    }
+}
+
+/// Mined ore in flight: a chip or a severed chunk, and what it carries.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq)]
+pub struct OreLoad {
+    /// Scenario id of the rock it was cut from; empty for an unnamed rock.
+    pub source: String,
+    /// Units of each item still aboard. Emptied by scooping.
+    pub items: BTreeMap<String, u32>,
+}
+
+/// Marks an ore chip - a load spawned on its own rather than riding a chunk.
+/// The render observer dresses these.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct OreChipMarker;
+
+/// Spawn an ore chip carrying `load` at `at`, drifting at `velocity`.
+///
+/// A collider-less dynamic body: a chip goes where it was thrown until the
+/// tractor pulls it or it expires, and nothing can knock it about or be
+/// knocked by it. Its mass comes from its visible sphere, since it has no
+/// collider to take one from.
+pub fn spawn_ore_chip(commands: &mut Commands, at: Vec3, velocity: Vec3, load: OreLoad) -> Entity {
+    trace!("spawn_ore_chip: {:?} at {at}", load.items);
+
+    commands
+        .spawn((
+            Name::new("Ore Chip"),
+            OreChipMarker,
+            load,
+            Transform::from_translation(at),
+            RigidBody::Dynamic,
+            MassPropertiesBundle::from_shape(&Sphere::new(ORE_CHIP_RADIUS), ORE_CHIP_DENSITY),
+            LinearVelocity(velocity),
+            TempEntity(ORE_CHIP_LIFETIME_SECS),
+            ItemHighlight::new(ORE_CHIP_RADIUS),
+        ))
+        .id()
+}
+
+/// The velocity a chip cut at `at` leaves with: outward from the rock's
+/// centre, on top of the rock's own motion.
+pub(crate) fn ore_chip_velocity(at: Vec3, centre: Vec3, rock_velocity: Vec3) -> Vec3 {
+    rock_velocity + (at - centre).normalize_or_zero() * ORE_CHIP_EJECT_SPEED
+}
+
+/// Collects mined ore into player holds. `render` gates the chip visuals; the
+/// tractor and scoop are gameplay and run headless.
+/// Adds the `scoop_ore` `FixedUpdate` system, which stands still while the
+/// game is frozen, and (when `render`) the chip-render observer.
+pub struct AsteroidOrePlugin {
+    /// Whether to add the chip-render observer (false for headless tools).
+    pub render: bool,
+}
+
+impl Plugin for AsteroidOrePlugin {
+    fn build(&self, app: &mut App) {
+        trace!("AsteroidOrePlugin: build");
+
+        // NOTE: fixed-step, beside the other forces on a body (gravity,
+        // thrust), so the tractor's pull is the same at any frame rate.
+        app.add_systems(FixedUpdate, scoop_ore.run_if(gameplay_unfrozen));
+
+        if self.render {
+            app.add_observer(insert_ore_chip_render);
+        }
+    }
+}
+
+/// Run condition: the game is not frozen behind a pause or an overlay. Rigs
+/// without the pause state are never frozen.
+fn gameplay_unfrozen(pause: Option<Res<State<PauseStates>>>) -> bool {
+    pause.is_none_or(|pause| !pause.get().is_frozen())
+}
+
+/// How far a load reaches from its centre: half its collider's longest side
+/// (a severed chunk), else a chip's radius.
+fn load_radius(aabb: Option<&ColliderAabb>) -> f32 {
+    aabb.map_or(ORE_CHIP_RADIUS, |aabb| aabb.size().max_element() * 0.5)
+}
+
+/// Draw in and scoop up every load within reach of a player ship.
+///
+/// The nearest player ship with room claims a load; a ship with a full hold
+/// neither pulls nor takes. The pull is an acceleration toward the ship's
+/// velocity plus a closing speed, applied through [`Forces`] so the solver
+/// still owns the load's velocity - a chunk tractored into a rock bounces off
+/// it. Reach is measured to the load's near side, so a big chunk is scooped
+/// when it touches the scoop, not when its centre does. What fits moves into the hold, `OnMined` fires per
+/// item with the rock as `id` and the ship as `other`, and the scenario total
+/// is counted. A load with nothing left aboard is despawned; a part-scooped one
+/// stays, and is drawn in again once there is room.
+fn scoop_ore(
+    mut commands: Commands,
+    mut world: Option<ResMut<NovaEventWorld>>,
+    mut q_loads: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut OreLoad,
+        Option<&ColliderAabb>,
+        Forces,
+    )>,
+    mut q_ships: Query<
+        (
+            &GlobalTransform,
+            &mut CargoHold,
+            Option<&LinearVelocity>,
+            Option<&EntityId>,
+            Option<&EntityTypeName>,
+        ),
+        (With<PlayerSpaceshipMarker>, Without<OreLoad>),
+    >,
+) {
+    for (load_entity, load_frame, mut load, aabb, mut forces) in &mut q_loads {
+        let at = load_frame.translation();
+        let reach = load_radius(aabb);
+        let nearest = q_ships
+            .iter_mut()
+            .filter(|(_, hold, ..)| hold.free() > 0)
+            .map(|ship| ((ship.0.translation().distance(at) - reach).max(0.0), ship))
+            .filter(|(distance, _)| *distance <= ORE_TRACTOR_RANGE)
+            .min_by(|(a, _), (b, _)| a.total_cmp(b));
+        let Some((distance, (ship_frame, mut hold, ship_velocity, id, type_name))) = nearest else {
+            continue;
+        };
+
+        if distance > ORE_SCOOP_RADIUS {
+            let ship_velocity = ship_velocity.map_or(Vec3::ZERO, |velocity| velocity.0);
+            let pull = (ship_frame.translation() - at).normalize_or_zero() * ORE_TRACTOR_SPEED;
+            let shortfall = ship_velocity + pull - forces.linear_velocity();
+            forces.apply_linear_acceleration(shortfall * ORE_TRACTOR_RESPONSE);
+            continue;
+        }
+
+        let mut mined = 0;
+        for (item, count) in load.items.iter_mut() {
+            let accepted = hold.give(item, *count);
+            if accepted == 0 {
+                continue;
+            }
+            *count -= accepted;
+            mined += accepted;
+            commands.fire::<OnMinedEvent>(OnMinedEventInfo {
+                id: load.source.clone(),
+                other_id: id.map(|id| id.to_string()).unwrap_or_default(),
+                other_type_name: type_name
+                    .map(|type_name| type_name.to_string())
+                    .unwrap_or_default(),
+                item: item.clone(),
+                count: accepted,
+            });
+        }
+        load.items.retain(|_, count| *count > 0);
+        if mined > 0 {
+            debug!("scoop_ore: {mined} unit(s) from {load_entity:?}");
+            if let Some(world) = world.as_mut() {
+                world.record_mined(mined);
+            }
+        }
+        if load.items.is_empty() {
+            commands.entity(load_entity).try_despawn();
+        }
+    }
+}
+
+/// The visible chip: a small glowing ball. Chunks that carry ore are drawn as
+/// the rock they came off and need nothing here.
+fn insert_ore_chip_render(
+    add: On<Add, OreChipMarker>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let material = materials.add(StandardMaterial {
+        base_color: ORE_CHIP_COLOR,
+        emissive: ORE_CHIP_COLOR.to_linear() * ORE_CHIP_EMISSIVE,
+        ..default()
+    });
+
+    commands.entity(add.entity).insert((
+        Mesh3d(meshes.add(Sphere::new(ORE_CHIP_RADIUS))),
+        MeshMaterial3d(material),
+        Visibility::Inherited,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use nova_events::engine::GameEvent;
+
+    use super::*;
+
+    fn ore(rates: &[(&str, f32)]) -> AsteroidOre {
+        AsteroidOre::new(
+            rates
+                .iter()
+                .map(|(item, rate)| (item.to_string(), *rate))
+                .collect(),
+        )
+    }
+
+    /// Small carves add up: the fractions a hit leaves owed are paid out by
+    /// the next, so four quarter-units yield one unit exactly once.
+    #[test]
+    fn accrual_keeps_the_fraction_between_hits() {
+        let mut rock = ore(&[("iron", 0.25), ("ice", 0.0)]);
+
+        let yields: Vec<_> = (0..4).map(|_| rock.accrue(1.0)).collect();
+
+        assert!(yields[..3].iter().all(BTreeMap::is_empty));
+        assert_eq!(yields[3], BTreeMap::from([("iron".to_string(), 1)]));
+        assert!(rock.accrue(0.0).is_empty());
+        assert!(rock.accrue(f32::NAN).is_empty());
+    }
+
+    #[derive(Resource, Default)]
+    struct Mined(Vec<OnMinedEventInfo>);
+
+    fn scoop_app() -> App {
+        use core::time::Duration;
+
+        use bevy::{state::app::StatesPlugin, time::TimeUpdateStrategy};
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            StatesPlugin,
+            TransformPlugin,
+            AssetPlugin::default(),
+            bevy::mesh::MeshPlugin,
+            PhysicsPlugins::default(),
+        ));
+        app.init_state::<PauseStates>();
+        app.insert_resource(Gravity(Vec3::ZERO));
+        // One fixed tick per update: the scoop is fixed-step.
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            1.0 / 64.0,
+        )));
+        app.init_resource::<NovaEventWorld>();
+        app.init_resource::<Mined>();
+        app.add_plugins(AsteroidOrePlugin { render: false });
+        app.add_observer(|event: On<GameEvent>, mut mined: ResMut<Mined>| {
+            if event.name() == "onmined" {
+                let info = event.info().data.clone().expect("OnMined carries its info");
+                mined.0.push(serde_json::from_value(info).unwrap());
+            }
+        });
+        app.finish();
+        app
+    }
+
+    /// Run `frames` updates: the first frame carries no time, every later one
+    /// exactly one fixed tick.
+    fn step(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+        }
+    }
+
+    fn load(items: &[(&str, u32)]) -> OreLoad {
+        OreLoad {
+            source: "rock".to_string(),
+            items: items
+                .iter()
+                .map(|(item, count)| (item.to_string(), *count))
+                .collect(),
+        }
+    }
+
+    fn spawn_load(app: &mut App, at: Vec3, items: &[(&str, u32)]) -> Entity {
+        let mut commands = app.world_mut().commands();
+        let chip = spawn_ore_chip(&mut commands, at, Vec3::ZERO, load(items));
+        app.world_mut().flush();
+        chip
+    }
+
+    fn spawn_player(app: &mut App, capacity: u32) -> Entity {
+        app.world_mut()
+            .spawn((
+                PlayerSpaceshipMarker,
+                CargoHold::new(capacity),
+                Transform::default(),
+                EntityId::new("hauler"),
+                EntityTypeName::new(SPACESHIP_TYPE_NAME),
+            ))
+            .id()
+    }
+
+    /// A load beside the player goes into the hold as far as it fits, fires
+    /// `OnMined` for what landed, and counts toward `OreMined`; the overflow
+    /// stays aboard the load.
+    #[test]
+    fn a_player_scoops_what_fits_and_the_rest_stays() {
+        let mut app = scoop_app();
+        let ship = spawn_player(&mut app, 4);
+        let load = spawn_load(&mut app, Vec3::X * 2.0, &[("iron", 3), ("nickel", 3)]);
+
+        step(&mut app, 2);
+
+        let hold = app.world().get::<CargoHold>(ship).unwrap();
+        assert_eq!((hold.count("iron"), hold.count("nickel")), (3, 1));
+        assert_eq!(
+            app.world().get::<OreLoad>(load).unwrap().items,
+            BTreeMap::from([("nickel".to_string(), 2)])
+        );
+        let mined = &app.world().resource::<Mined>().0;
+        assert_eq!(mined.len(), 2);
+        assert_eq!(
+            (mined[0].id.as_str(), mined[0].other_id.as_str()),
+            ("rock", "hauler")
+        );
+        assert_eq!((mined[1].item.as_str(), mined[1].count), ("nickel", 1));
+        let ore_mined = QueryConfig::Scenario(ScenarioQuery {
+            property: ScenarioProperty::OreMined,
+        });
+        assert_eq!(
+            app.world()
+                .resource::<NovaEventWorld>()
+                .query_value(&ore_mined),
+            Some(VariableLiteral::Number(4.0))
+        );
+    }
+
+    /// Out of scoop reach but inside tractor range, a load is pulled toward
+    /// the ship and nothing is taken yet; a full hold pulls nothing.
+    #[test]
+    fn a_load_in_range_is_drawn_toward_a_hold_with_room() {
+        let mut app = scoop_app();
+        let ship = spawn_player(&mut app, 1);
+        let load = spawn_load(&mut app, Vec3::X * 20.0, &[("iron", 1)]);
+
+        step(&mut app, 3);
+
+        let pull = app.world().get::<LinearVelocity>(load).unwrap().0;
+        assert!(pull.x < 0.0, "the load heads for the ship: {pull}");
+        assert_eq!(app.world().get::<CargoHold>(ship).unwrap().count("iron"), 0);
+
+        app.world_mut()
+            .get_mut::<CargoHold>(ship)
+            .unwrap()
+            .give("ice", 1);
+        app.world_mut().get_mut::<LinearVelocity>(load).unwrap().0 = Vec3::ZERO;
+        step(&mut app, 1);
+        assert_eq!(
+            app.world().get::<LinearVelocity>(load).unwrap().0,
+            Vec3::ZERO,
+            "a full hold draws nothing in"
+        );
+    }
+
+    /// Reach is measured to a load's near side: a chunk whose centre is past
+    /// the scoop radius is taken once its surface is inside it.
+    #[test]
+    fn a_big_chunk_is_scooped_when_its_surface_arrives() {
+        let mut app = scoop_app();
+        let ship = spawn_player(&mut app, 4);
+        app.world_mut().spawn((
+            load(&[("iron", 2)]),
+            Transform::from_translation(Vec3::X * (ORE_SCOOP_RADIUS + 3.0)),
+            RigidBody::Dynamic,
+            Collider::sphere(4.0),
+        ));
+
+        // A frame to spawn, a tick for avian to size the collider, one to take.
+        step(&mut app, 3);
+
+        assert_eq!(app.world().get::<CargoHold>(ship).unwrap().count("iron"), 2);
+    }
+
+    /// Behind the pause menu nothing is pulled and nothing is taken.
+    #[test]
+    fn a_frozen_game_scoops_nothing() {
+        let mut app = scoop_app();
+        let ship = spawn_player(&mut app, 4);
+        spawn_load(&mut app, Vec3::X * 2.0, &[("iron", 1)]);
+        app.world_mut()
+            .resource_mut::<NextState<PauseStates>>()
+            .set(PauseStates::Paused);
+
+        step(&mut app, 3);
+
+        assert_eq!(app.world().get::<CargoHold>(ship).unwrap().count("iron"), 0);
+    }
+}
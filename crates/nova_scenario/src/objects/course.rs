@@ -0,0 +1,781 @@
+//! Race course scenario object: an ordered run of gates, flown against the
+//! clock.
+//!
+//! A course is a list of gate positions (relative to the course) and a gate
+//! radius. Flying through the first gate starts the run; every gate after it
+//! must be flown in order, and each one records a SPLIT - the time since the
+//! start. A sprint ends at the last gate; a circuit (`laps: Some(n)`) runs
+//! back through the first gate to close each lap and ends when the last lap
+//! closes. A gate counts when the player ship's path since the last frame
+//! passes within the radius of its centre, so a fast ship cannot skip one
+//! between frames.
+//!
+//! Times are scenario time (`scenario_elapsed`), so a run freezes on pause
+//! like the rest of the scenario. The finish fires `OnCourseFinished` under
+//! the course's scenario id and compares the run with [`RaceBests`], the best
+//! run per scenario and course. `nova_menu` loads and saves those bests and
+//! reads the finished run's [`CourseResult`] into the results panel; a sync
+//! copies the run into the HUD's race timer every frame. A best also keeps the
+//! run's track, which `course_ghost` flies again as a ghost on the next run.
+//!
+//! Touch this module when changing how a race is flown or timed.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use nova_events::prelude::*;
+use nova_gameplay::prelude::*;
+use nova_hud::prelude::{RaceClock, RaceClockState, RaceSplit};
+
+use super::course_ghost::CourseGhost;
+use crate::prelude::*;
+
+/// The course scenario object, its run and bests, and `CoursePlugin`.
+pub mod prelude {
+    pub use super::{
+        course_scenario_object, CourseConfig, CourseMarker, CoursePlugin, CourseResult, CourseRun,
+        RaceBests, RaceRecord,
+    };
+}
+
+/// The furthest (world units) the player ship may move in one frame and still
+/// be checked against a gate. Further than that is a respawn or a teleport,
+/// not flight, and must not count as flying every gate on the way.
+const MAX_GATE_STEP: f32 = 250.0;
+
+/// Emissive luminance of the gate to fly next: it has to stand out from the
+/// rest of the course at a glance.
+const NEXT_GATE_EMISSIVE: f32 = 25.0;
+
+/// Emissive luminance of every other gate: visible, not competing.
+const IDLE_GATE_EMISSIVE: f32 = 2.0;
+
+/// The scenario/modding RON surface for a race course: where its gates sit,
+/// how wide they are, and whether it is a sprint or a circuit. Passed to
+/// [`course_scenario_object`] to build the course bundle.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CourseConfig {
+    /// Gate centres in flying order, relative to the course. The first is the
+    /// start gate. At least two.
+    pub gates: Vec<Vec3>,
+    /// Radius of every gate (world units).
+    pub gate_radius: f32,
+    /// `None` (the default) is a sprint from the first gate to the last;
+    /// `Some(n)` is an `n`-lap circuit, each lap closing back through the
+    /// first gate.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub laps: Option<u32>,
+    /// Gate color.
+    pub color: Color,
+}
+
+/// Marker on a course root.
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct CourseMarker;
+
+/// The authored gates, relative to the course.
+#[derive(Component, Clone, Debug)]
+struct CourseGates {
+    positions: Vec<Vec3>,
+    radius: f32,
+}
+
+/// Render inputs, consumed by `insert_course_render`.
+#[derive(Component, Clone, Debug)]
+struct CourseRenderConfig {
+    color: Color,
+}
+
+/// The next-gate and idle-gate materials, so the tint can swap between them.
+#[derive(Component, Clone, Debug)]
+struct CourseGateMaterials {
+    next: Handle<StandardMaterial>,
+    idle: Handle<StandardMaterial>,
+}
+
+/// A visible gate ring, by its index into the course's gates.
+#[derive(Component, Clone, Copy, Debug)]
+struct CourseGateRing(usize);
+
+/// A finished run, inserted on the course at the finish for the results
+/// panel.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct CourseResult {
+    /// Seconds from the start gate to the finish.
+    pub time: f64,
+    /// The split at every gate after the start, the last being the finish.
+    pub splits: Vec<f64>,
+    /// The best time before this run, if the course had been finished before.
+    pub previous_best: Option<f64>,
+}
+
+impl CourseResult {
+    /// Whether this run beat the previous best (or set the first one).
+    pub fn is_best(&self) -> bool {
+        self.previous_best.is_none_or(|best| self.time < best)
+    }
+}
+
+/// The player's run of a course: which gate is next, when the start gate was
+/// flown, and the splits so far. Lives on the course root.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct CourseRun {
+    /// Gate index to fly at each step, start gate first.
+    sequence: Vec<usize>,
+    /// Gates per lap after the start, for the HUD's `GATE n/m`.
+    per_lap: usize,
+    /// The step to fly next, as an index into `sequence`.
+    next: usize,
+    /// Scenario time the start gate was flown.
+    started: Option<f64>,
+    /// Split at every step after the start so far.
+    splits: Vec<f64>,
+    /// Where the player ship was last frame.
+    last_position: Option<Vec3>,
+    /// The final time, once finished.
+    finish: Option<f64>,
+}
+
+/// What flying the next gate did to a run.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum CourseStep {
+    /// The start gate: the clock is running.
+    Started,
+    /// A gate between the start and the finish, at this split.
+    Split(f64),
+    /// The finish, at this time.
+    Finished(f64),
+}
+
+impl CourseRun {
+    /// A fresh run of a course with `gates` gates: a sprint with `laps: None`,
+    /// otherwise a circuit of that many laps (at least one).
+    pub fn new(gates: usize, laps: Option<u32>) -> Self {
+        Self {
+            sequence: course_sequence(gates, laps),
+            per_lap: match laps {
+                Some(_) => gates,
+                None => gates.saturating_sub(1),
+            },
+            ..default()
+        }
+    }
+
+    /// The gate index to fly next; `None` once finished, or for a course too
+    /// short to race.
+    pub fn next_gate(&self) -> Option<usize> {
+        self.sequence.get(self.next).copied()
+    }
+
+    /// Whether the start gate has been flown.
+    pub fn started(&self) -> bool {
+        self.started.is_some()
+    }
+
+    /// The splits so far, one per gate flown after the start.
+    pub fn splits(&self) -> &[f64] {
+        &self.splits
+    }
+
+    /// Whether the last gate has been flown.
+    pub fn finished(&self) -> bool {
+        self.finish.is_some()
+    }
+
+    /// Seconds on the run's clock at scenario time `now`: 0 before the start
+    /// gate, the final time after the finish.
+    pub fn elapsed(&self, now: f64) -> f64 {
+        match (self.finish, self.started) {
+            (Some(finish), _) => finish,
+            (None, Some(started)) => (now - started).max(0.0),
+            (None, None) => 0.0,
+        }
+    }
+
+    /// Fly the next gate at scenario time `now`.
+    fn pass_gate(&mut self, now: f64) -> CourseStep {
+        self.next += 1;
+        let Some(started) = self.started else {
+            self.started = Some(now);
+            return CourseStep::Started;
+        };
+        let split = now - started;
+        self.splits.push(split);
+        if self.next >= self.sequence.len() {
+            self.finish = Some(split);
+            CourseStep::Finished(split)
+        } else {
+            CourseStep::Split(split)
+        }
+    }
+
+    /// The lap being flown and the gate within it being flown to, both from
+    /// 1; the gate is 0 before the start gate.
+    fn lap_and_gate(&self) -> (u32, u32) {
+        if self.next == 0 || self.per_lap == 0 {
+            return (1, 0);
+        }
+        // Once finished, hold on the last gate of the last lap.
+        let step = self.next.min(self.sequence.len() - 1) - 1;
+        (
+            (step / self.per_lap) as u32 + 1,
+            (step % self.per_lap) as u32 + 1,
+        )
+    }
+
+    /// The run as the HUD's race timer shows it, at scenario time `now`, with
+    /// split deltas against `best`.
+    fn clock_state(&self, now: f64, best: Option<&RaceRecord>) -> RaceClockState {
+        let (lap, gate) = self.lap_and_gate();
+        let laps = if self.per_lap == 0 {
+            1
+        } else {
+            (self.sequence.len().saturating_sub(1) / self.per_lap).max(1) as u32
+        };
+        RaceClockState {
+            lap,
+            laps,
+            gate,
+            gates: self.per_lap as u32,
+            elapsed: self.elapsed(now),
+            split: self.splits.last().map(|&time| RaceSplit {
+                time,
+                delta: best
+                    .and_then(|best| best.splits.get(self.splits.len() - 1))
+                    .map(|best| time - best),
+            }),
+            finished: self.finished(),
+            ghost: None,
+        }
+    }
+}
+
+/// The gate indices a run flies, start gate first: `0..gates` for a sprint;
+/// the start gate, then each lap's gates closing back through it, for a
+/// circuit. Empty for a course of fewer than two gates.
+pub(crate) fn course_sequence(gates: usize, laps: Option<u32>) -> Vec<usize> {
+    if gates < 2 {
+        return Vec::new();
+    }
+    match laps {
+        None => (0..gates).collect(),
+        Some(laps) => {
+            let lap = (1..gates).chain([0]);
+            std::iter::once(0)
+                .chain((0..laps.max(1)).flat_map(|_| lap.clone()))
+                .collect()
+        }
+    }
+}
+
+/// Where the path from `from` to `to` passes through a gate of `radius` at
+/// `centre` - its closest approach to the centre - or `None` if it misses.
+pub(crate) fn gate_crossing(from: Vec3, to: Vec3, centre: Vec3, radius: f32) -> Option<Vec3> {
+    let path = to - from;
+    let length_squared = path.length_squared();
+    let along = if length_squared > f32::EPSILON {
+        ((centre - from).dot(path) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let closest = from + path * along;
+    (closest.distance_squared(centre) <= radius * radius).then_some(closest)
+}
+
+/// One course's best run: its time and the split at every gate after the
+/// start, for the deltas, and the track the ghost racer flies.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaceRecord {
+    /// Seconds from the start gate to the finish.
+    pub time: f64,
+    /// The split at every gate after the start.
+    pub splits: Vec<f64>,
+    /// The player ship's pose at every fixed step of the run, ending at the
+    /// finish; empty for a best set before ghosts were recorded.
+    pub ghost: Vec<GhostSample>,
+}
+
+/// The best run of every course the player has finished, keyed by
+/// [`RaceBests::key`]. Only ever written by a finish that beats the entry, so
+/// a change means a new best; `nova_menu` loads it at startup and saves it
+/// when it changes.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct RaceBests(pub BTreeMap<String, RaceRecord>);
+
+impl RaceBests {
+    /// The key of a course's best: `<scenario id>/<course id>`.
+    pub fn key(scenario: &str, course: &str) -> String {
+        format!("{scenario}/{course}")
+    }
+}
+
+/// Build the course bundle from a [`CourseConfig`]: no body - a course is
+/// only the gates, which nothing collides with - and a fresh run.
+pub fn course_scenario_object(config: CourseConfig) -> impl Bundle {
+    trace!("course_scenario_object: config {:?}", config);
+
+    if config.gates.len() < 2 {
+        warn!(
+            "course_scenario_object: a course needs at least two gates, got {}; it cannot be raced",
+            config.gates.len()
+        );
+    }
+
+    (
+        CourseMarker,
+        EntityTypeName::new(COURSE_TYPE_NAME),
+        CourseRun::new(config.gates.len(), config.laps),
+        CourseGhost::default(),
+        CourseGates {
+            positions: config.gates,
+            radius: config.gate_radius,
+        },
+        CourseRenderConfig {
+            color: config.color,
+        },
+    )
+}
+
+/// The race course scenario object: gate order, timing and bests always, the
+/// gate rings only when `render`.
+/// Adds the course `Update` systems (fly the gates while a scenario is live,
+/// then copy the run into the HUD's [`RaceClock`]), and (when `render`) the
+/// gate-ring observer and next-gate tint.
+pub struct CoursePlugin {
+    /// Whether to add the gate-ring observer and tint (false for headless tools).
+    pub render: bool,
+}
+
+impl Plugin for CoursePlugin {
+    fn build(&self, app: &mut App) {
+        trace!("CoursePlugin: build");
+
+        app.register_type::<CourseMarker>();
+        app.init_resource::<RaceBests>();
+        // NOTE: owned by ScenarioLoaderPlugin; repeated for rigs that add the
+        // objects without the loader.
+        app.init_resource::<CurrentScenario>();
+        // NOTE: owned by nova_hud's RaceTimerPlugin; repeated so the sync has
+        // somewhere to write in headless rigs without the HUD.
+        app.init_resource::<RaceClock>();
+        // NOTE: the sync is ungated on purpose - with no scenario there is no
+        // course, and it is the sync that writes the cleared clock.
+        app.add_systems(
+            Update,
+            (fly_courses.run_if(scenario_is_live), sync_race_clock).chain(),
+        );
+        if self.render {
+            app.add_observer(insert_course_render);
+            app.add_systems(Update, tint_course_gates.after(fly_courses));
+        }
+    }
+}
+
+/// Fly every course's next gate the player ship passed through since the last
+/// frame. The start gate starts the clock, every later gate records a split,
+/// and the last one finishes the run: `OnCourseFinished` fires, and a best
+/// run replaces the stored one.
+pub(crate) fn fly_courses(
+    mut commands: Commands,
+    world: Option<Res<NovaEventWorld>>,
+    current: Res<CurrentScenario>,
+    mut bests: ResMut<RaceBests>,
+    mut q_courses: Query<(
+        Entity,
+        &EntityId,
+        &GlobalTransform,
+        &CourseGates,
+        &mut CourseRun,
+        &mut CourseGhost,
+    )>,
+    q_player: Query<
+        (&GlobalTransform, Option<&EntityId>, Option<&EntityTypeName>),
+        (With<PlayerSpaceshipMarker>, Without<CourseMarker>),
+    >,
+) {
+    let now = world.as_ref().map_or(0.0, |world| world.scenario_elapsed());
+    let player = q_player.iter().next();
+
+    for (course, course_id, course_frame, gates, mut run, mut ghost) in &mut q_courses {
+        if run.finished() {
+            continue;
+        }
+        let Some((ship_frame, ship_id, ship_type)) = player else {
+            run.last_position = None;
+            continue;
+        };
+        let position = ship_frame.translation();
+        let Some(mut from) = run.last_position.replace(position) else {
+            continue;
+        };
+        if from.distance(position) > MAX_GATE_STEP {
+            continue;
+        }
+
+        // One frame's path can fly several gates; each is checked against what
+        // is left of it after the one before, so order along the path holds.
+        while let Some(gate) = run.next_gate() {
+            let centre = course_frame.transform_point(gates.positions[gate]);
+            let Some(crossing) = gate_crossing(from, position, centre, gates.radius) else {
+                break;
+            };
+            from = crossing;
+            let CourseStep::Finished(time) = run.pass_gate(now) else {
+                continue;
+            };
+
+            let key = RaceBests::key(
+                current
+                    .0
+                    .as_ref()
+                    .map_or("", |scenario| scenario.id.as_str()),
+                course_id,
+            );
+            let result = CourseResult {
+                time,
+                splits: run.splits.clone(),
+                previous_best: bests.0.get(&key).map(|record| record.time),
+            };
+            let best = result.is_best();
+            debug!(
+                "fly_courses: '{}' finished in {time:.2}s (best: {best})",
+                **course_id
+            );
+            if best {
+                bests.0.insert(
+                    key,
+                    RaceRecord {
+                        time,
+                        splits: result.splits.clone(),
+                        ghost: ghost.close_track(time, ship_frame),
+                    },
+                );
+            }
+            commands.entity(course).insert(result);
+            commands.fire::<OnCourseFinishedEvent>(OnCourseFinishedEventInfo {
+                id: course_id.to_string(),
+                other_id: ship_id.map(|id| id.to_string()).unwrap_or_default(),
+                other_type_name: ship_type
+                    .map(|type_name| type_name.to_string())
+                    .unwrap_or_default(),
+                time,
+                best,
+            });
+            break;
+        }
+    }
+}
+
+/// Copy the course being raced into the HUD's [`RaceClock`]: the run in
+/// progress first, else a finished one, else one waiting at its start gate,
+/// with its ghost delta; `None` with no course.
+pub(crate) fn sync_race_clock(
+    world: Option<Res<NovaEventWorld>>,
+    current: Res<CurrentScenario>,
+    bests: Res<RaceBests>,
+    mut clock: ResMut<RaceClock>,
+    q_courses: Query<(
+        &EntityId,
+        &CourseRun,
+        Option<&CourseResult>,
+        Option<&CourseGhost>,
+    )>,
+) {
+    let now = world.as_ref().map_or(0.0, |world| world.scenario_elapsed());
+    let scenario = current
+        .0
+        .as_ref()
+        .map_or("", |scenario| scenario.id.as_str());
+    let raced = q_courses.iter().min_by_key(|(_, run, _, _)| {
+        if run.finished() {
+            1
+        } else if run.started() {
+            0
+        } else {
+            2
+        }
+    });
+    let state = raced.map(|(id, run, result, ghost)| {
+        let mut state = if run.finished() {
+            // A finished run compares with the best it was flown against, not
+            // the one it may just have set.
+            let mut state = run.clock_state(now, None);
+            let previous = result.and_then(|result| result.previous_best);
+            if let (Some(split), Some(previous)) = (state.split.as_mut(), previous) {
+                split.delta = Some(split.time - previous);
+            }
+            state
+        } else {
+            run.clock_state(now, bests.0.get(&RaceBests::key(scenario, id)))
+        };
+        state.ghost = ghost.and_then(CourseGhost::delta);
+        state
+    });
+    if clock.0 != state {
+        clock.0 = state;
+    }
+}
+
+/// The visible course: a ring at every gate, facing along the course, tinted
+/// bright for the gate to fly next.
+fn insert_course_render(
+    add: On<Add, CourseMarker>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_course: Query<(&CourseGates, &CourseRenderConfig), With<CourseMarker>>,
+) {
+    let entity = add.entity;
+    let Ok((gates, config)) = q_course.get(entity) else {
+        error!(
+            "insert_course_render: entity {:?} not found in q_course",
+            entity
+        );
+        return;
+    };
+
+    let material = |emissive: f32| StandardMaterial {
+        base_color: config.color,
+        emissive: config.color.to_linear() * emissive,
+        ..default()
+    };
+    let next = materials.add(material(NEXT_GATE_EMISSIVE));
+    let idle = materials.add(material(IDLE_GATE_EMISSIVE));
+    let ring = meshes.add(Torus::new(gates.radius * 0.92, gates.radius));
+
+    commands.entity(entity).insert(CourseGateMaterials {
+        next: next.clone(),
+        idle: idle.clone(),
+    });
+    commands.entity(entity).with_children(|parent| {
+        let count = gates.positions.len();
+        for (index, position) in gates.positions.iter().enumerate() {
+            // Face the ring along the leg into it (out of it, for the start).
+            let heading = if index == 0 {
+                gates.positions.get(1).map(|to| *to - *position)
+            } else {
+                Some(*position - gates.positions[index - 1])
+            };
+            let rotation = heading
+                .and_then(|heading| heading.try_normalize())
+                .map_or(Quat::IDENTITY, |heading| {
+                    Quat::from_rotation_arc(Vec3::Y, heading)
+                });
+            parent.spawn((
+                Name::new(format!("CourseGate{index}/{count}")),
+                CourseGateRing(index),
+                Transform::from_translation(*position).with_rotation(rotation),
+                Mesh3d(ring.clone()),
+                MeshMaterial3d(if index == 0 {
+                    next.clone()
+                } else {
+                    idle.clone()
+                }),
+            ));
+        }
+    });
+}
+
+/// Tint the gate to fly next bright and the rest dim, whenever a run moves on.
+fn tint_course_gates(
+    q_courses: Query<(&CourseRun, &CourseGateMaterials, &Children), Changed<CourseRun>>,
+    mut q_rings: Query<(&CourseGateRing, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (run, materials, children) in &q_courses {
+        let next = run.next_gate();
+        for child in children.iter() {
+            let Ok((ring, mut material)) = q_rings.get_mut(child) else {
+                continue;
+            };
+            let wanted = if Some(ring.0) == next {
+                &materials.next
+            } else {
+                &materials.idle
+            };
+            if material.0 != *wanted {
+                material.0 = wanted.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A sprint flies its gates once; a circuit starts through the first gate
+    /// and closes every lap back through it.
+    #[test]
+    fn a_course_flies_its_gates_in_order() {
+        assert_eq!(course_sequence(3, None), vec![0, 1, 2]);
+        assert_eq!(course_sequence(3, Some(2)), vec![0, 1, 2, 0, 1, 2, 0]);
+        assert_eq!(course_sequence(3, Some(0)), vec![0, 1, 2, 0]);
+        assert!(course_sequence(1, None).is_empty());
+    }
+
+    /// A gate counts when the path passes through it between frames, not only
+    /// when a frame lands inside it.
+    #[test]
+    fn a_fast_pass_still_crosses_the_gate() {
+        let centre = Vec3::new(0.0, 0.0, 50.0);
+        assert_eq!(
+            gate_crossing(Vec3::ZERO, Vec3::Z * 100.0, centre, 5.0),
+            Some(centre)
+        );
+        assert_eq!(
+            gate_crossing(Vec3::X * 4.0, Vec3::new(4.0, 0.0, 100.0), centre, 5.0),
+            Some(Vec3::new(4.0, 0.0, 50.0))
+        );
+        assert_eq!(
+            gate_crossing(Vec3::X * 6.0, Vec3::new(6.0, 0.0, 100.0), centre, 5.0),
+            None
+        );
+        assert_eq!(gate_crossing(Vec3::ZERO, Vec3::Z * 40.0, centre, 5.0), None);
+    }
+
+    /// Laps and gates count from the start gate, and the HUD's split delta
+    /// compares with the best run's split at the same gate.
+    #[test]
+    fn a_run_counts_laps_gates_and_split_deltas() {
+        let mut run = CourseRun::new(2, Some(2));
+        assert_eq!(run.lap_and_gate(), (1, 0));
+        assert_eq!(run.pass_gate(10.0), CourseStep::Started);
+        assert_eq!(run.lap_and_gate(), (1, 1));
+        assert_eq!(run.pass_gate(15.0), CourseStep::Split(5.0));
+        assert_eq!(run.pass_gate(21.0), CourseStep::Split(11.0));
+        assert_eq!(run.lap_and_gate(), (2, 1));
+
+        let best = RaceRecord {
+            time: 20.0,
+            splits: vec![4.0, 10.0, 15.0, 20.0],
+            ghost: Vec::new(),
+        };
+        let state = run.clock_state(22.0, Some(&best));
+        assert_eq!(
+            (state.lap, state.laps, state.gate, state.gates),
+            (2, 2, 1, 2)
+        );
+        assert_eq!(state.elapsed, 12.0);
+        assert_eq!(
+            state.split,
+            Some(RaceSplit {
+                time: 11.0,
+                delta: Some(1.0),
+            })
+        );
+
+        assert_eq!(run.pass_gate(26.0), CourseStep::Split(16.0));
+        assert_eq!(run.pass_gate(29.0), CourseStep::Finished(19.0));
+        assert_eq!(run.next_gate(), None);
+        assert_eq!(run.lap_and_gate(), (2, 2));
+    }
+
+    #[derive(Resource, Default)]
+    struct Finished(Vec<OnCourseFinishedEventInfo>);
+
+    fn course_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<NovaEventWorld>();
+        app.init_resource::<Finished>();
+        app.insert_resource(CurrentScenario(Some(ScenarioConfig::new(
+            "trial".to_string(),
+            "Trial".to_string(),
+            default(),
+        ))));
+        app.add_plugins(CoursePlugin { render: false });
+        app.add_observer(|event: On<GameEvent>, mut finished: ResMut<Finished>| {
+            if event.name() == "oncoursefinished" {
+                let info = event
+                    .info()
+                    .data
+                    .clone()
+                    .expect("OnCourseFinished carries its info");
+                finished.0.push(serde_json::from_value(info).unwrap());
+            }
+        });
+        app
+    }
+
+    /// Move the ship to `at` a second of scenario time later.
+    fn fly_to(app: &mut App, ship: Entity, at: Vec3) {
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .advance_scenario_elapsed(1.0);
+        *app.world_mut().get_mut::<GlobalTransform>(ship).unwrap() =
+            GlobalTransform::from_translation(at);
+        app.update();
+    }
+
+    /// Flying the gates in order finishes the run, fires `OnCourseFinished`
+    /// and stores the best; skipping a gate finishes nothing.
+    #[test]
+    fn flying_the_course_finishes_and_records_a_best() {
+        let mut app = course_app();
+        app.world_mut().spawn((
+            EntityId::new("loop"),
+            GlobalTransform::from_translation(Vec3::Z * 100.0),
+            course_scenario_object(CourseConfig {
+                gates: vec![Vec3::ZERO, Vec3::Z * 100.0, Vec3::Z * 200.0],
+                gate_radius: 10.0,
+                laps: None,
+                color: Color::WHITE,
+            }),
+        ));
+        let ship = app
+            .world_mut()
+            .spawn((
+                PlayerSpaceshipMarker,
+                GlobalTransform::default(),
+                EntityId::new("racer"),
+                EntityTypeName::new(SPACESHIP_TYPE_NAME),
+            ))
+            .id();
+        app.update();
+
+        // Around the middle gate rather than through it.
+        fly_to(&mut app, ship, Vec3::Z * 100.0);
+        fly_to(&mut app, ship, Vec3::new(50.0, 0.0, 150.0));
+        fly_to(&mut app, ship, Vec3::new(50.0, 0.0, 250.0));
+        fly_to(&mut app, ship, Vec3::new(0.0, 0.0, 300.0));
+        assert!(app.world().resource::<Finished>().0.is_empty());
+        let clock = app.world().resource::<RaceClock>().0.clone().unwrap();
+        assert_eq!((clock.gate, clock.gates), (1, 2));
+
+        // Back through the middle - the path starts on the last gate, which
+        // must not count ahead of the middle one - and on through the last.
+        fly_to(&mut app, ship, Vec3::new(0.0, 0.0, 150.0));
+        assert!(app.world().resource::<Finished>().0.is_empty());
+        fly_to(&mut app, ship, Vec3::new(0.0, 0.0, 320.0));
+
+        let finished = &app.world().resource::<Finished>().0;
+        assert_eq!(finished.len(), 1);
+        assert_eq!(
+            (finished[0].id.as_str(), finished[0].other_id.as_str()),
+            ("loop", "racer")
+        );
+        assert_eq!(finished[0].time, 5.0);
+        assert!(finished[0].best);
+        let bests = app.world().resource::<RaceBests>();
+        assert!(bests.0.contains_key("trial/loop"));
+        let result = app
+            .world_mut()
+            .query::<&CourseResult>()
+            .single(app.world())
+            .unwrap()
+            .clone();
+        assert_eq!((result.time, result.previous_best), (5.0, None));
+        assert!(
+            app.world()
+                .resource::<RaceClock>()
+                .0
+                .as_ref()
+                .unwrap()
+                .finished
+        );
+    }
+}
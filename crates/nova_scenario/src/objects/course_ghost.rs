@@ -0,0 +1,600 @@
+//! Ghost racer: a course's personal best, flown again beside the player.
+//!
+//! While a run is on the clock the player ship's pose is sampled every fixed
+//! step; a finish that sets a new best closes the track at the finish time and
+//! stores it beside the best in [`RaceRecord::ghost`], so it persists with the
+//! bests. Storage is where a track is thinned ([`thin_ghost_track`]: 10 Hz,
+//! at most [`GHOST_MAX_SAMPLES`]), as it is saved and loaded. The next run of the course spawns a ghost hull as its start gate is
+//! flown and poses it from that track at the same run time - both ships leave
+//! the start together, and a pause freezes both.
+//!
+//! A ghost hull has no body, so nothing collides with it, and carries
+//! [`GhostHullMarker`], which AI acquisition, targeting and trigger areas
+//! filter out. With `render` it wears a translucent copy of the player ship's
+//! meshes. How far behind (positive) or ahead (negative) of the ghost the
+//! player is - the run time now against the ghost's time at the same point of
+//! the course - goes into the HUD's race timer as its `GHOST` chip.
+//!
+//! Touch this module when changing how a best run is recorded or flown again.
+
+use bevy::{light::NotShadowCaster, prelude::*};
+use nova_events::prelude::*;
+use nova_gameplay::prelude::*;
+
+use super::course::{fly_courses, sync_race_clock};
+use crate::prelude::*;
+
+/// The ghost sample type and `CourseGhostPlugin`.
+pub mod prelude {
+    pub use super::{thin_ghost_track, CourseGhostPlugin, GhostSample, GHOST_MAX_SAMPLES};
+}
+
+/// Seconds between stored samples: 10 Hz. The pose is interpolated between
+/// them, so a finer track only costs storage.
+const GHOST_SAMPLE_SECS: f32 = 0.1;
+
+/// The most samples a stored track keeps: ten minutes at
+/// [`GHOST_SAMPLE_SECS`]. A longer run is thinned to every other sample until
+/// it fits.
+pub const GHOST_MAX_SAMPLES: usize = 6000;
+
+/// How far past the last nearest sample, in track seconds, the player's
+/// position is matched against. Searching forward from the last match keeps a
+/// course that doubles back from matching the wrong leg. In seconds rather
+/// than samples because a best set this session is raced at the fixed step's
+/// density and one loaded from a save at the stored 10 Hz.
+const GHOST_SEARCH_AHEAD_SECS: f32 = 2.0;
+
+/// How far before the last nearest sample, in track seconds, is still
+/// matched, for a player who slows or backs up.
+const GHOST_SEARCH_BEHIND_SECS: f32 = 0.3;
+
+/// The ghost hull's tint: pale and see-through, so it never reads as a ship
+/// to fight.
+const GHOST_COLOR: Color = Color::srgba(0.55, 0.85, 1.0, 0.3);
+
+/// One recorded pose of the player ship, at `t` seconds on the run's clock.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GhostSample {
+    /// Seconds since the start gate.
+    pub t: f32,
+    /// World position of the ship root.
+    pub position: Vec3,
+    /// World rotation of the ship root.
+    pub rotation: Quat,
+}
+
+/// A course's ghost: the run being recorded, the best being raced and the
+/// hull flying it. Lives on the course root beside its [`CourseRun`].
+#[derive(Component, Clone, Debug, Default)]
+pub(crate) struct CourseGhost {
+    /// This run's track so far, while it is on the clock.
+    recording: Vec<GhostSample>,
+    /// The run's time at the last fixed step recorded from.
+    clock: Option<f32>,
+    /// The best run's track, looked up as the start gate is flown; empty when
+    /// there is no best with a ghost to race.
+    best: Option<Vec<GhostSample>>,
+    /// The ghost hull flying `best`.
+    hull: Option<Entity>,
+    /// The `best` sample the player was last nearest.
+    cursor: usize,
+    /// Seconds behind (positive) or ahead (negative) of the ghost.
+    delta: Option<f64>,
+}
+
+impl CourseGhost {
+    /// Seconds behind (positive) or ahead (negative) of the ghost; `None`
+    /// with no ghost to race.
+    pub(crate) fn delta(&self) -> Option<f64> {
+        self.delta
+    }
+
+    /// Close the recording at the finish, `time` seconds after the start with
+    /// the ship at `frame`, and hand the whole track over.
+    pub(crate) fn close_track(&mut self, time: f64, frame: &GlobalTransform) -> Vec<GhostSample> {
+        let t = time as f32;
+        self.recording.retain(|sample| sample.t < t);
+        let (_, rotation, position) = frame.to_scale_rotation_translation();
+        self.recording.push(GhostSample {
+            t,
+            position,
+            rotation,
+        });
+        self.clock = None;
+        std::mem::take(&mut self.recording)
+    }
+
+    /// Keep the pose at run time `t`. Every fixed step is kept: a run's track
+    /// is thinned only when it is stored.
+    fn record(&mut self, t: f32, position: Vec3, rotation: Quat) {
+        self.recording.push(GhostSample {
+            t,
+            position,
+            rotation,
+        });
+    }
+}
+
+/// `track` as it is stored: at most one sample per [`GHOST_SAMPLE_SECS`] and
+/// at most [`GHOST_MAX_SAMPLES`] samples, the first and the last kept. A run
+/// records every fixed step; this is applied as the bests are saved, and
+/// again as they are loaded for a save written before tracks were thinned.
+pub fn thin_ghost_track(track: &[GhostSample]) -> Vec<GhostSample> {
+    let mut thinned: Vec<GhostSample> = Vec::new();
+    for (index, sample) in track.iter().enumerate() {
+        let last = index + 1 == track.len();
+        if last
+            || thinned
+                .last()
+                .is_none_or(|kept| sample.t - kept.t >= GHOST_SAMPLE_SECS)
+        {
+            thinned.push(*sample);
+        }
+    }
+    while thinned.len() > GHOST_MAX_SAMPLES {
+        halve_track(&mut thinned);
+    }
+    thinned
+}
+
+/// Drop every other sample, keeping the first and the last.
+fn halve_track(track: &mut Vec<GhostSample>) {
+    let Some(last) = track.pop() else {
+        return;
+    };
+    let mut index = 0;
+    track.retain(|_| {
+        index += 1;
+        index % 2 == 1
+    });
+    track.push(last);
+}
+
+/// The ghost pose on `track` at `t` seconds: interpolated between the samples
+/// either side, held at the first before it and the last after it.
+fn ghost_pose(track: &[GhostSample], t: f32) -> Option<(Vec3, Quat)> {
+    let (first, last) = (track.first()?, track.last()?);
+    let after = track.partition_point(|sample| sample.t <= t);
+    if after == 0 {
+        return Some((first.position, first.rotation));
+    }
+    if after == track.len() {
+        return Some((last.position, last.rotation));
+    }
+    let (from, to) = (track[after - 1], track[after]);
+    let along = (t - from.t) / (to.t - from.t);
+    Some((
+        from.position.lerp(to.position, along),
+        from.rotation.slerp(to.rotation, along),
+    ))
+}
+
+/// The ghost's time where it passed closest to `position`, searched from
+/// `cursor` (which moves to the nearest sample) and refined along the leg
+/// either side of it.
+fn ghost_time_near(track: &[GhostSample], cursor: &mut usize, position: Vec3) -> Option<f32> {
+    let last = track.len().checked_sub(1)?;
+    let at = (*cursor).min(last);
+    let t = track[at].t;
+    let from = track[..at].partition_point(|sample| sample.t < t - GHOST_SEARCH_BEHIND_SECS);
+    let to = at + track[at + 1..].partition_point(|sample| sample.t <= t + GHOST_SEARCH_AHEAD_SECS);
+    let nearest = (from..=to).min_by(|&a, &b| {
+        track[a]
+            .position
+            .distance_squared(position)
+            .total_cmp(&track[b].position.distance_squared(position))
+    })?;
+    *cursor = nearest;
+
+    let leg = |a: usize, b: usize| {
+        let (from, to) = (track[a], track[b]);
+        let path = to.position - from.position;
+        let length_squared = path.length_squared();
+        let along = if length_squared > f32::EPSILON {
+            ((position - from.position).dot(path) / length_squared).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let closest = from.position + path * along;
+        (
+            closest.distance_squared(position),
+            from.t + (to.t - from.t) * along,
+        )
+    };
+    let before = (nearest > 0).then(|| leg(nearest - 1, nearest));
+    let after = (nearest < last).then(|| leg(nearest, nearest + 1));
+    Some(
+        before
+            .into_iter()
+            .chain(after)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map_or(track[nearest].t, |(_, t)| t),
+    )
+}
+
+/// The ghost racer: recording always, the ghost hull's meshes only when
+/// `render`.
+/// Adds the `FixedUpdate` recorder and the `Update` ghost flight (between the
+/// course's gate flight and its race-clock sync, so the clock reads this
+/// frame's delta), both while a scenario is live, and (when `render`) the
+/// ghost-hull mesh observer.
+pub struct CourseGhostPlugin {
+    /// Whether to add the ghost-hull mesh observer (false for headless tools).
+    pub render: bool,
+}
+
+impl Plugin for CourseGhostPlugin {
+    fn build(&self, app: &mut App) {
+        trace!("CourseGhostPlugin: build");
+
+        app.add_systems(FixedUpdate, record_course_ghosts.run_if(scenario_is_live));
+        app.add_systems(
+            Update,
+            fly_course_ghosts
+                .run_if(scenario_is_live)
+                .after(fly_courses)
+                .before(sync_race_clock),
+        );
+        if self.render {
+            app.add_observer(insert_ghost_render);
+        }
+    }
+}
+
+/// Sample the player ship's pose into every course run on the clock, once per
+/// fixed step. The first step is stamped with the run's clock; each after it
+/// one fixed step later, so the track keeps the simulation's own cadence.
+fn record_course_ghosts(
+    time: Res<Time>,
+    world: Option<Res<NovaEventWorld>>,
+    mut q_courses: Query<(&CourseRun, &mut CourseGhost)>,
+    q_player: Query<&GlobalTransform, (With<PlayerSpaceshipMarker>, Without<CourseMarker>)>,
+) {
+    let Some(frame) = q_player.iter().next() else {
+        return;
+    };
+    let now = world.as_ref().map_or(0.0, |world| world.scenario_elapsed());
+    let (_, rotation, position) = frame.to_scale_rotation_translation();
+
+    for (run, mut ghost) in &mut q_courses {
+        if !run.started() || run.finished() {
+            continue;
+        }
+        let t = match ghost.clock {
+            Some(clock) => clock + time.delta_secs(),
+            None => run.elapsed(now) as f32,
+        };
+        ghost.clock = Some(t);
+        ghost.record(t, position, rotation);
+    }
+}
+
+/// Fly every course's ghost: spawn the hull when the start gate is flown and
+/// the best has a track, pose it at the run's time, and work out how far
+/// behind or ahead of it the player is.
+fn fly_course_ghosts(
+    mut commands: Commands,
+    world: Option<Res<NovaEventWorld>>,
+    current: Res<CurrentScenario>,
+    bests: Res<RaceBests>,
+    mut q_courses: Query<(&EntityId, &CourseRun, &mut CourseGhost)>,
+    mut q_hulls: Query<&mut Transform, With<GhostHullMarker>>,
+    q_player: Query<&GlobalTransform, (With<PlayerSpaceshipMarker>, Without<CourseMarker>)>,
+) {
+    let now = world.as_ref().map_or(0.0, |world| world.scenario_elapsed());
+    let scenario = current
+        .0
+        .as_ref()
+        .map_or("", |scenario| scenario.id.as_str());
+    let player = q_player.iter().next().map(GlobalTransform::translation);
+
+    for (course_id, run, mut ghost) in &mut q_courses {
+        if !run.started() {
+            continue;
+        }
+        let ghost = &mut *ghost;
+        let best = ghost.best.get_or_insert_with(|| {
+            bests
+                .0
+                .get(&RaceBests::key(scenario, course_id))
+                .map(|record| record.ghost.clone())
+                .filter(|track| track.len() >= 2)
+                .unwrap_or_default()
+        });
+        let elapsed = run.elapsed(now);
+        let Some((position, rotation)) = ghost_pose(best, elapsed as f32) else {
+            continue;
+        };
+
+        match ghost.hull {
+            Some(hull) => {
+                if let Ok(mut transform) = q_hulls.get_mut(hull) {
+                    transform.translation = position;
+                    transform.rotation = rotation;
+                }
+            }
+            None => {
+                debug!("fly_course_ghosts: '{}' races its best", **course_id);
+                ghost.hull = Some(
+                    commands
+                        .spawn((
+                            Name::new("Ghost Racer"),
+                            GhostHullMarker,
+                            Transform::from_translation(position).with_rotation(rotation),
+                            Visibility::default(),
+                            ScenarioScopedMarker,
+                        ))
+                        .id(),
+                );
+            }
+        }
+
+        // A finished run is behind or ahead by its whole time; one on the
+        // clock by where the ghost was when it passed the player's point.
+        ghost.delta = if run.finished() {
+            best.last().map(|last| elapsed - f64::from(last.t))
+        } else {
+            player
+                .and_then(|player| ghost_time_near(best, &mut ghost.cursor, player))
+                .map(|t| elapsed - f64::from(t))
+        };
+    }
+}
+
+/// The visible ghost: a translucent copy of every mesh on the player ship,
+/// placed as it sits on the ship, with no shadow.
+fn insert_ghost_render(
+    add: On<Add, GhostHullMarker>,
+    mut commands: Commands,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_player: Query<(Entity, &GlobalTransform), With<PlayerSpaceshipMarker>>,
+    q_children: Query<&Children>,
+    q_meshes: Query<(&Mesh3d, &GlobalTransform)>,
+) {
+    let entity = add.entity;
+    let Some((player, root)) = q_player.iter().next() else {
+        warn!("insert_ghost_render: no player ship to copy the ghost hull from");
+        return;
+    };
+
+    let material = materials.add(StandardMaterial {
+        base_color: GHOST_COLOR,
+        unlit: true,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    let to_root = root.affine().inverse();
+    let parts = q_children
+        .iter_descendants(player)
+        .filter_map(|child| q_meshes.get(child).ok())
+        .map(|(mesh, frame)| {
+            (
+                mesh.clone(),
+                Transform::from_matrix(Mat4::from(to_root * frame.affine())),
+            )
+        })
+        .collect::<Vec<_>>();
+    commands.entity(entity).with_children(|parent| {
+        for (mesh, transform) in parts {
+            parent.spawn((
+                Name::new("Ghost Racer Part"),
+                mesh,
+                MeshMaterial3d(material.clone()),
+                transform,
+                NotShadowCaster,
+            ));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use avian3d::prelude::RigidBody;
+    use bevy::time::TimeUpdateStrategy;
+    use nova_hud::prelude::RaceClock;
+
+    use super::*;
+
+    /// One fixed step per update, so each update records one sample.
+    const STEP: f32 = 1.0 / 64.0;
+
+    fn ghost_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            STEP,
+        )));
+        app.insert_resource(Time::<Fixed>::from_seconds(f64::from(STEP)));
+        app.init_resource::<NovaEventWorld>();
+        app.insert_resource(CurrentScenario(Some(ScenarioConfig::new(
+            "trial".to_string(),
+            "Trial".to_string(),
+            default(),
+        ))));
+        app.add_plugins(CoursePlugin { render: false });
+        app.add_plugins(CourseGhostPlugin { render: false });
+        app
+    }
+
+    /// A fresh sprint along +Z, with the ship waiting short of its start gate.
+    fn line_up(app: &mut App) -> (Entity, Entity) {
+        let course = app
+            .world_mut()
+            .spawn((
+                EntityId::new("sprint"),
+                GlobalTransform::default(),
+                course_scenario_object(CourseConfig {
+                    gates: vec![Vec3::ZERO, Vec3::Z * 100.0, Vec3::Z * 200.0],
+                    gate_radius: 10.0,
+                    laps: None,
+                    color: Color::WHITE,
+                }),
+            ))
+            .id();
+        let ship = app
+            .world_mut()
+            .spawn((
+                PlayerSpaceshipMarker,
+                GlobalTransform::from_translation(Vec3::Z * -20.0),
+            ))
+            .id();
+        app.update();
+        (course, ship)
+    }
+
+    /// Move the ship `speed` units along the course over one fixed step.
+    fn step(app: &mut App, ship: Entity, speed: f32) {
+        app.world_mut()
+            .resource_mut::<NovaEventWorld>()
+            .advance_scenario_elapsed(f64::from(STEP));
+        let at = app
+            .world()
+            .get::<GlobalTransform>(ship)
+            .unwrap()
+            .translation();
+        *app.world_mut().get_mut::<GlobalTransform>(ship).unwrap() =
+            GlobalTransform::from_translation(at + Vec3::Z * speed);
+        app.update();
+    }
+
+    /// A best run stores its track beside the best; the next run races a
+    /// bodiless ghost hull along it, and the clock shows how far behind it a
+    /// slower run falls.
+    #[test]
+    fn a_best_run_is_raced_again_as_a_ghost() {
+        let mut app = ghost_app();
+        let (course, ship) = line_up(&mut app);
+        while app.world().get::<CourseResult>(course).is_none() {
+            step(&mut app, ship, 1.0);
+        }
+        let record = app.world().resource::<RaceBests>().0["trial/sprint"].clone();
+        assert_eq!(record.ghost.last().unwrap().t, record.time as f32);
+        let gaps = record.ghost.windows(2).map(|pair| pair[1].t - pair[0].t);
+        assert!(
+            gaps.rev().skip(1).all(|gap| (gap - STEP).abs() < 1e-4),
+            "one sample per fixed step; thinning is the save's"
+        );
+        assert!(record
+            .ghost
+            .windows(2)
+            .all(|pair| pair[0].t < pair[1].t && pair[0].position.z < pair[1].position.z));
+        let no_ghost = app
+            .world_mut()
+            .query_filtered::<(), With<GhostHullMarker>>()
+            .iter(app.world())
+            .count();
+        assert_eq!(no_ghost, 0, "a first run has nothing to race");
+
+        // The rerun, at half the speed.
+        app.world_mut().despawn(course);
+        app.world_mut().despawn(ship);
+        let (_, ship) = line_up(&mut app);
+        for _ in 0..20 {
+            step(&mut app, ship, 0.5);
+        }
+        let hulls = app
+            .world_mut()
+            .query_filtered::<Entity, With<GhostHullMarker>>()
+            .iter(app.world())
+            .collect::<Vec<_>>();
+        assert_eq!(hulls.len(), 1);
+        assert!(app.world().get::<RigidBody>(hulls[0]).is_none());
+        assert!(app.world().get::<EntityId>(hulls[0]).is_none());
+
+        for _ in 0..64 {
+            step(&mut app, ship, 0.5);
+        }
+        let player = app
+            .world()
+            .get::<GlobalTransform>(ship)
+            .unwrap()
+            .translation();
+        let ghost = app.world().get::<Transform>(hulls[0]).unwrap().translation;
+        assert!(
+            ghost.z > player.z + 20.0,
+            "the ghost flies the faster best: ghost {ghost}, player {player}"
+        );
+        let behind = app
+            .world()
+            .resource::<RaceClock>()
+            .0
+            .as_ref()
+            .and_then(|clock| clock.ghost)
+            .expect("the clock shows the ghost delta");
+        let elapsed = app
+            .world()
+            .resource::<RaceClock>()
+            .0
+            .as_ref()
+            .unwrap()
+            .elapsed;
+        assert!(
+            (behind - elapsed / 2.0).abs() < 0.05,
+            "half the speed is half the run behind: {behind} of {elapsed}"
+        );
+    }
+
+    /// The pose interpolates between samples and holds at either end; the
+    /// ghost's time at a point refines along the leg it lies on.
+    #[test]
+    fn the_track_is_read_between_its_samples() {
+        let sample = |t: f32, z: f32| GhostSample {
+            t,
+            position: Vec3::Z * z,
+            rotation: Quat::IDENTITY,
+        };
+        let track = [sample(0.0, 0.0), sample(1.0, 10.0), sample(2.0, 30.0)];
+        assert_eq!(ghost_pose(&track, -1.0).unwrap().0, Vec3::ZERO);
+        assert_eq!(ghost_pose(&track, 1.5).unwrap().0, Vec3::Z * 20.0);
+        assert_eq!(ghost_pose(&track, 9.0).unwrap().0, Vec3::Z * 30.0);
+        assert_eq!(ghost_pose(&[], 0.0), None);
+
+        let mut cursor = 0;
+        assert_eq!(
+            ghost_time_near(&track, &mut cursor, Vec3::new(3.0, 0.0, 25.0)),
+            Some(1.75)
+        );
+        assert_eq!(cursor, 2);
+        assert_eq!(ghost_time_near(&[], &mut cursor, Vec3::ZERO), None);
+    }
+
+    /// A stored track is 10 Hz at most and never longer than the cap, however
+    /// long or fine the run it came from.
+    #[test]
+    fn a_track_is_thinned_and_capped() {
+        let track = |samples: usize| {
+            (0..samples)
+                .map(|index| GhostSample {
+                    t: index as f32 * STEP,
+                    position: Vec3::Z * index as f32,
+                    rotation: Quat::IDENTITY,
+                })
+                .collect::<Vec<_>>()
+        };
+        let short = track(641);
+        let thinned = thin_ghost_track(&short);
+        assert_eq!(thinned.first(), short.first());
+        assert_eq!(thinned.last(), short.last());
+        assert!(thinned[..thinned.len() - 1]
+            .windows(2)
+            .all(|pair| pair[1].t - pair[0].t >= GHOST_SAMPLE_SECS - 1e-4));
+        assert!(thinned.len() as f32 <= 10.0 / GHOST_SAMPLE_SECS + 2.0);
+
+        // An hour at the fixed step.
+        let long = track(64 * 3600);
+        let thinned = thin_ghost_track(&long);
+        assert!(thinned.len() <= GHOST_MAX_SAMPLES);
+        assert!(thinned.len() > GHOST_MAX_SAMPLES / 2);
+        assert_eq!(thinned.last(), long.last());
+
+        // The run itself keeps every step; only storage thins it.
+        let mut ghost = CourseGhost::default();
+        for sample in &long {
+            ghost.record(sample.t, sample.position, sample.rotation);
+        }
+        assert_eq!(ghost.recording.len(), long.len());
+    }
+}
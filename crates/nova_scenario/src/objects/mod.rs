@@ -1,5 +1,6 @@
-//! The things a scenario can place in the world - asteroids, beacons, lights,
-//! salvage, spaceships, trigger areas - one module each.
+//! The things a scenario can place in the world - asteroids, beacons, race
+//! courses, lights, salvage, spaceships, stations, trigger areas - one module
+//! each.
 //!
 //! Each submodule owns its authored config, its spawn bundle and its plugin;
 //! [`ScenarioObjectsPlugin`] adds them all and is the only registration point.
@@ -15,10 +16,16 @@ pub mod area;
 pub mod asteroid;
 /// The signed field behind a carvable rock, and the remesh that follows a hit.
 pub mod asteroid_carve;
+/// Mining: the ore a carved rock yields, and the player's pickup of it.
+pub mod asteroid_ore;
 /// How a rock is textured (triplanar, no UVs) and how its silhouette is shaped.
 pub mod asteroid_surface;
 pub mod beacon;
 pub mod binding_input;
+/// Race course scenario object: ordered gates, laps, splits and personal bests.
+pub mod course;
+/// Ghost racer: the course personal best recorded, stored and flown again.
+pub mod course_ghost;
 /// Light scenario object: the authored directional and point lights a scene
 /// lights itself with.
 pub mod light;
@@ -28,27 +35,32 @@ pub mod salvage;
 pub mod ship;
 /// Spaceship scenario object: player/AI ships built from a section list.
 pub mod spaceship;
+/// Station scenario object: a static structure with docking ports and services.
+pub mod station;
 
 /// Every scenario object submodule's prelude plus `ScenarioObjectsPlugin`.
 pub mod prelude {
     pub use super::{
         anchor::prelude::*, area::prelude::*, asteroid::prelude::*, asteroid_carve::prelude::*,
-        asteroid_surface::prelude::*, beacon::prelude::*, binding_input::prelude::*,
-        light::prelude::*, modification::prelude::*, salvage::prelude::*, ship::prelude::*,
-        spaceship::prelude::*, ScenarioObjectsPlugin,
+        asteroid_ore::prelude::*, asteroid_surface::prelude::*, beacon::prelude::*,
+        binding_input::prelude::*, course::prelude::*, course_ghost::prelude::*, light::prelude::*,
+        modification::prelude::*, salvage::prelude::*, ship::prelude::*, spaceship::prelude::*,
+        station::prelude::*, ScenarioObjectsPlugin,
     };
 }
 
 use bevy::prelude::*;
 
 /// Aggregates the scenario-object plugins (asteroid, spaceship, area, beacon,
-/// salvage crate, light) into one group. `render` is threaded to the
+/// salvage crate, station, course, light) into one group. `render` is threaded to the
 /// render-bearing members so headless tools can spawn objects without their
 /// visuals.
 /// Adds each object type's own plugin (see [`asteroid::AsteroidPlugin`],
+/// [`asteroid_ore::AsteroidOrePlugin`],
 /// [`spaceship::SpaceshipPlugin`], [`area::ScenarioAreaPlugin`],
 /// [`beacon::BeaconPlugin`], [`salvage::SalvageCratePlugin`],
-/// [`light::LightPlugin`]) at build time.
+/// [`station::StationPlugin`], [`course::CoursePlugin`],
+/// [`course_ghost::CourseGhostPlugin`], [`light::LightPlugin`]) at build time.
 pub struct ScenarioObjectsPlugin {
     /// Whether the render-bearing object plugins spawn their visuals (false for headless tools).
     pub render: bool,
@@ -65,6 +77,9 @@ impl Plugin for ScenarioObjectsPlugin {
         app.add_plugins(asteroid_carve::AsteroidCarvePlugin {
             render: self.render,
         });
+        app.add_plugins(asteroid_ore::AsteroidOrePlugin {
+            render: self.render,
+        });
         app.add_plugins(spaceship::SpaceshipPlugin);
         app.add_plugins(area::ScenarioAreaPlugin);
         app.add_plugins(beacon::BeaconPlugin {
@@ -73,6 +88,15 @@ impl Plugin for ScenarioObjectsPlugin {
         app.add_plugins(salvage::SalvageCratePlugin {
             render: self.render,
         });
+        app.add_plugins(station::StationPlugin {
+            render: self.render,
+        });
+        app.add_plugins(course::CoursePlugin {
+            render: self.render,
+        });
+        app.add_plugins(course_ghost::CourseGhostPlugin {
+            render: self.render,
+        });
         app.add_plugins(light::LightPlugin {
             render: self.render,
         });
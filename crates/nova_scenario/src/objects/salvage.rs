@@ -1,21 +1,30 @@
 //! Salvage crate scenario object: a minimal proximity pickup. The crate is a
 //! small tumbling prop that doubles as its own trigger area - flying through it
 //! fires `OnEnter` under the crate's scenario id, and the scenario script pairs
-//! that with `DespawnScenarioObject` plus whatever counting it wants. No
-//! inventory: "collected" is scenario state, which keeps pickup consequences in
-//! scenario data instead of a hardcoded item system.
+//! that with `DespawnScenarioObject` plus whatever else the beat needs.
+//!
+//! A crate may also carry authored `contents`. When the player flies in, as
+//! much as fits moves into the ship's `CargoHold`; what does not fit stays in
+//! the crate for a later pass. Scenarios read the result through the
+//! `ItemCount` entity query instead of counting pickups in variables. A crate
+//! with no contents is exactly the old trigger-only prop.
+
+use std::collections::BTreeMap;
 
 use avian3d::prelude::*;
 use bevy::prelude::*;
 use nova_events::prelude::*;
 use nova_gameplay::prelude::*;
 use nova_hud::prelude::*;
+use nova_ship::prelude::CargoHold;
 
 use crate::prelude::*;
 
 /// The salvage-crate scenario object, its config and `SalvageCratePlugin`.
 pub mod prelude {
-    pub use super::{salvage_crate_scenario_object, SalvageCrateConfig, SalvageCratePlugin};
+    pub use super::{
+        salvage_crate_scenario_object, SalvageCrateConfig, SalvageCrateContents, SalvageCratePlugin,
+    };
 }
 
 /// Tumble rate (radians/second) of the crate's render child.
@@ -34,7 +43,7 @@ const CRATE_EMISSIVE_MIN: f32 = 3.0;
 const CRATE_EMISSIVE_MAX: f32 = 6.0;
 
 /// The scenario/modding RON surface for a salvage crate object: its visible
-/// size, pickup radius, and optional pickup sound. Passed to
+/// size, pickup radius, optional pickup sound and cargo contents. Passed to
 /// [`salvage_crate_scenario_object`] to build the crate bundle.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -52,6 +61,13 @@ pub struct SalvageCrateConfig {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub pickup_sound: Option<AssetRef<AudioSource>>,
+    /// Items the crate holds, by item id. Loaded into the player's cargo hold
+    /// on pickup; empty (the default) is a trigger-only crate.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub contents: BTreeMap<String, u32>,
 }
 
 /// The crate's authored pickup ding, snapshotted UNRESOLVED from
@@ -60,6 +76,11 @@ pub struct SalvageCrateConfig {
 #[derive(Component, Clone, Debug)]
 struct SalvageCratePickupSound(Option<AssetRef<AudioSource>>);
 
+/// The items still in a crate, seeded from [`SalvageCrateConfig::contents`].
+/// Emptied as the player's hold takes them.
+#[derive(Component, Clone, Debug, Default, Deref, DerefMut, Reflect)]
+pub struct SalvageCrateContents(pub BTreeMap<String, u32>);
+
 /// Build the salvage crate bundle from a [`SalvageCrateConfig`]: a static sensor
 /// trigger (the pickup volume) that fires `OnEnter` under its own scenario id,
 /// carrying the marker, size, highlight, and pickup sound the crate observers
@@ -72,6 +93,7 @@ pub fn salvage_crate_scenario_object(config: SalvageCrateConfig) -> impl Bundle
         EntityTypeName::new(SALVAGE_CRATE_TYPE_NAME),
         SalvageCrateSize(config.size),
         SalvageCratePickupSound(config.pickup_sound.clone()),
+        SalvageCrateContents(config.contents.clone()),
         // Every pickup advertises itself: the HUD's item-highlights observer
         // grows a bracket sized to the crate's VISIBLE half-diagonal.
         // Intrinsic, not scenario data - a silent pickup is a bug.
@@ -202,7 +224,34 @@ fn on_crate_pickup_play_sfx(
     }
 }
 
-/// Bind the pickup cue TO THE CRATE.
+/// Move a crate's contents into the PLAYER's cargo hold when the player flies
+/// into its sensor. Player-only for the same reason as the ding. Each stack
+/// moves as far as the hold has room; the remainder stays in the crate, so a
+/// second pass after making room picks it up. The per-section-collider burst
+/// needs no dedup here: after the first event there is nothing left that fits.
+///
+/// A player ship with no hold (no cargo-bearing hull) takes nothing.
+fn on_crate_pickup_load_cargo(
+    collision: On<CollisionStart>,
+    mut q_crate: Query<&mut SalvageCrateContents, With<SalvageCrateMarker>>,
+    mut q_hold: Query<&mut CargoHold, With<PlayerSpaceshipMarker>>,
+) {
+    let Ok(mut contents) = q_crate.get_mut(collision.collider1) else {
+        return;
+    };
+    if contents.is_empty() {
+        return;
+    }
+    let Some(mut hold) = collision.body2.and_then(|other| q_hold.get_mut(other).ok()) else {
+        return;
+    };
+    for (item, count) in contents.iter_mut() {
+        *count -= hold.give(item, *count);
+    }
+    contents.retain(|_, count| *count > 0);
+}
+
+/// Bind the pickup cue and cargo load TO THE CRATE.
 ///
 /// As a global `add_observer` this ran for every collision in the world -
 /// 23,363 invocations in four seconds of a headless duel that carries no
@@ -211,7 +260,8 @@ fn on_crate_pickup_play_sfx(
 fn wire_crate_pickup(add: On<Add, SalvageCrateMarker>, mut commands: Commands) {
     commands
         .entity(add.entity)
-        .observe(on_crate_pickup_play_sfx);
+        .observe(on_crate_pickup_play_sfx)
+        .observe(on_crate_pickup_load_cargo);
 }
 
 /// Drop a crate from the ding-dedup set when it leaves the world (picked up or
@@ -309,6 +359,7 @@ mod tests {
                 size: 1.5,
                 area_radius: 6.0,
                 pickup_sound: None,
+                contents: BTreeMap::new(),
             }))
             .id();
 
@@ -387,6 +438,7 @@ mod tests {
                 size: 1.5,
                 area_radius: 6.0,
                 pickup_sound: None,
+                contents: BTreeMap::new(),
             }),
             EntityId::new("crate_1".to_string()),
             Transform::from_translation(Vec3::ZERO),
@@ -479,6 +531,7 @@ mod tests {
                     size: 1.5,
                     area_radius: 6.0,
                     pickup_sound: Some(AssetRef::from("base/sounds/salvage_pickup.wav")),
+                    contents: BTreeMap::new(),
                 }),
                 CollisionEventsEnabled,
                 Transform::from_translation(Vec3::ZERO),
@@ -539,6 +592,7 @@ mod tests {
                 size: 1.5,
                 area_radius: 6.0,
                 pickup_sound: None,
+                contents: BTreeMap::new(),
             }),
             CollisionEventsEnabled,
             Transform::from_translation(Vec3::ZERO),
@@ -633,6 +687,46 @@ mod tests {
         );
     }
 
+    /// Authored contents move into the player's hold as far as it has room;
+    /// the rest stays in the crate. Delivery guard: the hold starts empty.
+    #[test]
+    fn a_player_pickup_loads_what_fits_into_the_hold() {
+        let mut app = pickup_audio_app();
+        let crate_entity = app
+            .world_mut()
+            .spawn((
+                salvage_crate_scenario_object(SalvageCrateConfig {
+                    size: 1.5,
+                    area_radius: 6.0,
+                    pickup_sound: None,
+                    contents: BTreeMap::from([("ore".to_string(), 5)]),
+                }),
+                CollisionEventsEnabled,
+                Transform::from_translation(Vec3::ZERO),
+            ))
+            .id();
+        spawn_mover(&mut app, (PlayerSpaceshipMarker, CargoHold::new(3)));
+
+        for _ in 0..25 {
+            app.update();
+        }
+
+        let world = app.world_mut();
+        let hold = world
+            .query_filtered::<&CargoHold, With<PlayerSpaceshipMarker>>()
+            .single(world)
+            .unwrap();
+        assert_eq!(hold.count("ore"), 3, "the hold filled to capacity");
+        assert_eq!(
+            world
+                .get::<SalvageCrateContents>(crate_entity)
+                .unwrap()
+                .get("ore"),
+            Some(&2),
+            "the overflow stays in the crate"
+        );
+    }
+
     /// The glow pulse actually moves the emissive: after a nonzero step off the
     /// wave's crest the luminance has left its spawn value, and it stays inside
     /// the authored band. Real observer + system, real material asset,
@@ -665,6 +759,7 @@ mod tests {
                 size: 1.5,
                 area_radius: 6.0,
                 pickup_sound: None,
+                contents: BTreeMap::new(),
             }))
             .id();
 
@@ -720,6 +815,7 @@ mod tests {
                 size: 1.5,
                 area_radius: 6.0,
                 pickup_sound: None,
+                contents: BTreeMap::new(),
             }))
             .id();
 
@@ -84,6 +84,14 @@ pub struct PlayerControllerConfig {
     /// reload. A shipped build logs a warning and keeps the authored magazines,
     /// so no released scenario can hand the player free point defense.
     pub infinite_ammo: bool,
+    /// Repair parts the ship's damage control starts with, inserted as
+    /// [`DamageControl`] on the ship root: each repair costs one, each rebuild
+    /// of a lost section three. None = the engine default of six.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub repair_parts: Option<u32>,
 }
 
 /// AI-driver settings for a [`SpaceshipController::AI`] ship: its passive
@@ -323,6 +331,8 @@ impl Plugin for SpaceshipPlugin {
         app.init_resource::<GameShips>();
 
         app.add_observer(insert_spaceship_sections);
+        app.add_observer(rebuild_spaceship_section);
+        app.add_observer(refit_spaceship_section);
 
         // Section modifications: the per-variant components + their apply-on-add
         // observers (DisableVerb / SetHealth / Rename).
@@ -344,7 +354,9 @@ fn insert_spaceship_sections(
         ),
         With<SpaceshipRootMarker>,
     >,
+    policy: Option<Res<DifficultyPolicy>>,
 ) {
+    let policy = policy.map(|policy| *policy).unwrap_or_default();
     let entity = add.entity;
     trace!("insert_spaceship_sections: entity {:?}", entity);
 
@@ -394,30 +406,8 @@ fn insert_spaceship_sections(
         ShipStyle(hull.style.clone()),
     ));
 
-    // A player ship flagged for infinite ammo has its weapons built without a
-    // magazine: overriding `ammo_capacity` to None means `insert_turret_section`
-    // / `insert_torpedo_section` attach no `SectionAmmo`, which is exactly the
-    // unlimited-ammo default. Enemy ships are never flagged, so they keep theirs.
-    //
-    // The grant is a DEBUG-ONLY cheat. A shipped build ignores the flag and
-    // every player ship fights on its authored magazines, so point defense
-    // costs something; examples and harness runs, which build `--features
-    // debug`, keep guns that never gate on a reload.
-    let flagged =
-        matches!(controller_config, SpaceshipController::Player(config) if config.infinite_ammo);
-    #[cfg(feature = "debug")]
-    let infinite_ammo = flagged;
-    #[cfg(not(feature = "debug"))]
-    let infinite_ammo = {
-        if flagged {
-            warn!(
-                "insert_spaceship_sections: entity {:?} authors infinite_ammo, a debug-only \
-                 cheat; this build ignores it and keeps the authored magazines",
-                entity
-            );
-        }
-        false
-    };
+    let infinite_ammo = honored_infinite_ammo(entity, controller_config);
+    let ammo_policy = ammo_policy(controller_config, policy);
 
     // An AI ship with no turret or torpedo section cannot fight; it becomes a
     // non-combatant below so it flies its routine and never chases. Tracked
@@ -426,118 +416,27 @@ fn insert_spaceship_sections(
 
     commands.entity(entity).with_children(|parent| {
         for section in hull.sections.iter() {
-            // Resolve the section's source to an owned SectionConfig: an inline
-            // config is used as-is; a prototype is looked up in the catalog
-            // (missing -> error + skip this section, no panic).
-            let config: SectionConfig = match &section.source {
-                SectionSource::Inline(config) => config.clone(),
-                SectionSource::Prototype(id) => match game_sections.get_section(id) {
-                    Some(config) => config.clone(),
-                    None => {
-                        error!(
-                            "insert_spaceship_sections: unknown section prototype '{}' for \
-                             section '{}'; skipping",
-                            id, section.id
-                        );
-                        continue;
-                    }
-                },
-            };
-
-            let mut section_entity = parent.spawn((
-                EntityId::new(section.id.clone()),
-                EntityTypeName::new(config.base.id.clone()),
-                base_section(config.base.clone()),
-                Transform::from_translation(section.position).with_rotation(section.rotation),
-            ));
-
-            // The last point anything knows this section's KIND. A live section
-            // carries its sockets and its collider and nothing that says what
-            // sort of part it is, and the derived skin has to know which face a
-            // part fires through to leave that one cell of it bare.
-            if let Some(exit) = SectionExit::of(&config) {
-                section_entity.insert(exit);
-            }
-
-            match &config.kind {
-                SectionKind::Hull(hull_config) => {
-                    section_entity.insert(hull_section(hull_config.clone()));
-                }
-                SectionKind::Controller(controller_config) => {
-                    section_entity.insert(controller_section(controller_config.clone()));
-                }
-                SectionKind::Thruster(thruster_config) => {
-                    section_entity.insert(thruster_section(thruster_config.clone()));
-
-                    match controller_config {
-                        SpaceshipController::None => {}
-                        SpaceshipController::Player(config) => {
-                            if let Some(bindings) = config.input_mapping.get(&section.id) {
-                                section_entity
-                                    .insert(SpaceshipThrusterInputBinding(bindings.clone()));
-                            };
-                        }
-                        SpaceshipController::AI(_) => {}
-                    }
-                }
-                SectionKind::Turret(turret_config) => {
-                    has_weapon = true;
-                    let mut turret_config = turret_config.clone();
-                    if infinite_ammo {
-                        turret_config.ammo_capacity = None;
-                    }
-                    section_entity.insert(turret_section(turret_config));
-
-                    match controller_config {
-                        SpaceshipController::None => {}
-                        SpaceshipController::Player(config) => {
-                            if let Some(bindings) = config.input_mapping.get(&section.id) {
-                                section_entity
-                                    .insert(SpaceshipTurretInputBinding(bindings.clone()));
-                            }
-                        }
-                        SpaceshipController::AI(_) => {}
-                    }
-                }
-                SectionKind::Torpedo(torpedo_config) => {
-                    has_weapon = true;
-                    let mut torpedo_config = torpedo_config.clone();
-                    if infinite_ammo {
-                        torpedo_config.ammo_capacity = None;
-                    }
-                    section_entity.insert(torpedo_section(torpedo_config));
-
-                    match controller_config {
-                        SpaceshipController::None => {}
-                        SpaceshipController::Player(config) => {
-                            if let Some(bindings) = config.input_mapping.get(&section.id) {
-                                section_entity
-                                    .insert(SpaceshipTorpedoInputBinding(bindings.clone()));
-                            }
-                        }
-                        SpaceshipController::AI(_) => {}
-                    }
-                }
-            }
-
-            // Insert the authored modification components; their observers apply
-            // each delta where relevant (and are inert elsewhere). The hull's
-            // own list first, then this spawn's overrides for the section - a
-            // later component insert replaces an earlier one, so the spawn wins.
-            let mut modifications = section.modifications.clone();
-            for override_ in spawn_modifications.iter() {
-                if override_.section == section.id {
-                    modifications.extend(override_.modifications.iter().cloned());
-                }
-            }
-            SectionModification::insert_all(&modifications, &mut section_entity);
+            has_weapon |= spawn_hull_section(
+                parent,
+                section,
+                &game_sections,
+                controller_config,
+                infinite_ammo,
+                ammo_policy,
+                spawn_modifications,
+            );
         }
     });
 
     match controller_config {
         SpaceshipController::None => {}
         SpaceshipController::Player(config) => {
-            commands.entity(entity).insert(PlayerSpaceshipMarker);
+            commands.entity(entity).insert((
+                PlayerSpaceshipMarker,
+                DamageControl::new(
+                    policy.repair_allowance(config.repair_parts.unwrap_or(DEFAULT_REPAIR_PARTS)),
+                ),
+            ));
             if let Some(cap) = config.speed_cap {
                 commands.entity(entity).insert(FlightSpeedCap(cap));
             }
@@ -574,10 +473,13 @@ fn insert_spaceship_sections(
             }
             // Non-positive delays are "no grace" (documented on the field):
             // a zero timer would be born finished anyway, so the guard just
-            // keeps the component off ships that never asked for one.
+            // keeps the component off ships that never asked for one. The
+            // difficulty scales an authored grace; it never invents one.
             if let Some(delay) = config.engage_delay {
                 if delay > 0.0 {
-                    commands.entity(entity).insert(AIEngageGrace::new(delay));
+                    commands
+                        .entity(entity)
+                        .insert(AIEngageGrace::new(delay * policy.ai_engage_grace));
                 }
             }
             // Same guard shape: a non-positive range would make the ship
@@ -609,6 +511,291 @@ fn insert_spaceship_sections(
     }
 }
 
+/// Answer a finished damage-control rebuild: build the lost section back onto
+/// the ship from the hull it was spawned with, where it first stood.
+///
+/// The hull is resolved again rather than remembered, so a rebuilt section is
+/// the catalog part as it stands now. A section the hull no longer names is
+/// logged and left lost; the parts are already spent.
+fn rebuild_spaceship_section(
+    rebuild: On<RebuildSection>,
+    mut commands: Commands,
+    game_sections: Res<GameSections>,
+    game_ships: Res<GameShips>,
+    q_spaceship: Query<
+        (
+            &SpaceshipHull,
+            &SpaceshipModifications,
+            &SpaceshipController,
+        ),
+        With<SpaceshipRootMarker>,
+    >,
+    policy: Option<Res<DifficultyPolicy>>,
+) {
+    let entity = rebuild.entity;
+    let Ok((hull_source, spawn_modifications, controller_config)) = q_spaceship.get(entity) else {
+        return;
+    };
+    let Some(section) = hull_source
+        .resolve(&game_ships)
+        .and_then(|hull| hull.sections.iter().find(|s| s.id == rebuild.section))
+    else {
+        error!(
+            "rebuild_spaceship_section: ship {:?} has no section '{}' to rebuild",
+            entity, rebuild.section
+        );
+        return;
+    };
+    let infinite_ammo = honored_infinite_ammo(entity, controller_config);
+    let policy = policy.map(|policy| *policy).unwrap_or_default();
+    let ammo_policy = ammo_policy(controller_config, policy);
+    commands.entity(entity).with_children(|parent| {
+        spawn_hull_section(
+            parent,
+            section,
+            &game_sections,
+            controller_config,
+            infinite_ammo,
+            ammo_policy,
+            spawn_modifications,
+        );
+    });
+}
+
+/// Answer a station refit: take the section off the ship and build the
+/// catalog part in its place, on the same mount and under the same id, so its
+/// bindings, scripts and damage-control record carry over.
+///
+/// The new part is the catalog part as it stands: the hull's authored
+/// modifications were for the part that came off, so only this spawn's
+/// overrides for the section apply. An unknown part leaves the section on.
+fn refit_spaceship_section(
+    refit: On<RefitSection>,
+    mut commands: Commands,
+    game_sections: Res<GameSections>,
+    q_spaceship: Query<
+        (&Children, &SpaceshipModifications, &SpaceshipController),
+        With<SpaceshipRootMarker>,
+    >,
+    q_section: Query<(&EntityId, &Transform), With<SectionMarker>>,
+    policy: Option<Res<DifficultyPolicy>>,
+) {
+    let entity = refit.entity;
+    let Ok((children, spawn_modifications, controller_config)) = q_spaceship.get(entity) else {
+        return;
+    };
+    if game_sections.get_section(&refit.part).is_none() {
+        error!(
+            "refit_spaceship_section: unknown section prototype '{}' for ship {:?}",
+            refit.part, entity
+        );
+        return;
+    }
+    let Some((old, transform)) = children.iter().find_map(|child| {
+        q_section
+            .get(child)
+            .ok()
+            .filter(|(id, _)| id.0 == refit.section)
+            .map(|(_, transform)| (child, *transform))
+    }) else {
+        error!(
+            "refit_spaceship_section: ship {:?} has no section '{}' to refit",
+            entity, refit.section
+        );
+        return;
+    };
+    let section = SpaceshipSectionConfig {
+        id: refit.section.clone(),
+        position: transform.translation,
+        rotation: transform.rotation,
+        source: SectionSource::Prototype(refit.part.clone()),
+        modifications: Vec::new(),
+    };
+    let infinite_ammo = honored_infinite_ammo(entity, controller_config);
+    let policy = policy.map(|policy| *policy).unwrap_or_default();
+    let ammo_policy = ammo_policy(controller_config, policy);
+    commands.entity(old).despawn();
+    commands.entity(entity).with_children(|parent| {
+        spawn_hull_section(
+            parent,
+            &section,
+            &game_sections,
+            controller_config,
+            infinite_ammo,
+            ammo_policy,
+            spawn_modifications,
+        );
+    });
+}
+
+/// Whether this ship's weapons are built without magazines.
+///
+/// A player ship flagged for infinite ammo has its weapons built without a
+/// magazine: overriding `ammo_capacity` to None means `insert_turret_section`
+/// / `insert_torpedo_section` attach no `SectionAmmo`, which is exactly the
+/// unlimited-ammo default. Enemy ships are never flagged, so they keep theirs.
+///
+/// The grant is a DEBUG-ONLY cheat. A shipped build ignores the flag and
+/// every player ship fights on its authored magazines, so point defense
+/// costs something; examples and harness runs, which build `--features
+/// debug`, keep guns that never gate on a reload.
+fn honored_infinite_ammo(entity: Entity, controller_config: &SpaceshipController) -> bool {
+    let flagged =
+        matches!(controller_config, SpaceshipController::Player(config) if config.infinite_ammo);
+    let honored = cfg!(feature = "debug");
+    if flagged && !honored {
+        warn!(
+            "insert_spaceship_sections: entity {:?} authors infinite_ammo, a debug-only \
+             cheat; this build ignores it and keeps the authored magazines",
+            entity
+        );
+    }
+    flagged && honored
+}
+
+/// The difficulty policy this ship's magazines are sized by: the player's
+/// ship only. Everyone else fights on the authored numbers.
+fn ammo_policy(
+    controller_config: &SpaceshipController,
+    policy: DifficultyPolicy,
+) -> Option<DifficultyPolicy> {
+    matches!(controller_config, SpaceshipController::Player(_)).then_some(policy)
+}
+
+/// A weapon's magazine as built: none under the infinite-ammo cheat, else the
+/// authored size at the difficulty's allowance. An unlimited weapon stays
+/// unlimited.
+fn built_magazine(
+    authored: Option<u32>,
+    infinite_ammo: bool,
+    ammo_policy: Option<DifficultyPolicy>,
+) -> Option<u32> {
+    if infinite_ammo {
+        return None;
+    }
+    authored.map(|capacity| ammo_policy.map_or(capacity, |policy| policy.ammo_allowance(capacity)))
+}
+
+/// Spawn one authored section of a ship under its root, `parent`: resolve its
+/// source, build the kind-specific bundle, wire the player's bindings for it
+/// and apply its modifications. Returns whether it is a weapon.
+///
+/// Shared by the spawn and by a damage-control rebuild, which is why it takes
+/// the root's components rather than reading them: a rebuilt section must come
+/// back exactly as it was first built.
+fn spawn_hull_section(
+    parent: &mut ChildSpawnerCommands,
+    section: &SpaceshipSectionConfig,
+    game_sections: &GameSections,
+    controller_config: &SpaceshipController,
+    infinite_ammo: bool,
+    ammo_policy: Option<DifficultyPolicy>,
+    spawn_modifications: &SpaceshipModifications,
+) -> bool {
+    let mut is_weapon = false;
+    // Resolve the section's source to an owned SectionConfig: an inline
+    // config is used as-is; a prototype is looked up in the catalog
+    // (missing -> error + skip this section, no panic).
+    let config: SectionConfig = match &section.source {
+        SectionSource::Inline(config) => config.clone(),
+        SectionSource::Prototype(id) => match game_sections.get_section(id) {
+            Some(config) => config.clone(),
+            None => {
+                error!(
+                    "spawn_hull_section: unknown section prototype '{}' for \
+                     section '{}'; skipping",
+                    id, section.id
+                );
+                return false;
+            }
+        },
+    };
+
+    let mut section_entity = parent.spawn((
+        EntityId::new(section.id.clone()),
+        EntityTypeName::new(config.base.id.clone()),
+        base_section(config.base.clone()),
+        Transform::from_translation(section.position).with_rotation(section.rotation),
+    ));
+
+    // The last point anything knows this section's KIND. A live section
+    // carries its sockets and its collider and nothing that says what
+    // sort of part it is, and the derived skin has to know which face a
+    // part fires through to leave that one cell of it bare.
+    if let Some(exit) = SectionExit::of(&config) {
+        section_entity.insert(exit);
+    }
+
+    match &config.kind {
+        SectionKind::Hull(hull_config) => {
+            section_entity.insert(hull_section(hull_config.clone()));
+        }
+        SectionKind::Controller(controller_config) => {
+            section_entity.insert(controller_section(controller_config.clone()));
+        }
+        SectionKind::Thruster(thruster_config) => {
+            section_entity.insert(thruster_section(thruster_config.clone()));
+
+            match controller_config {
+                SpaceshipController::None => {}
+                SpaceshipController::Player(config) => {
+                    if let Some(bindings) = config.input_mapping.get(&section.id) {
+                        section_entity.insert(SpaceshipThrusterInputBinding(bindings.clone()));
+                    };
+                }
+                SpaceshipController::AI(_) => {}
+            }
+        }
+        SectionKind::Turret(turret_config) => {
+            is_weapon = true;
+            let mut turret_config = turret_config.clone();
+            turret_config.ammo_capacity =
+                built_magazine(turret_config.ammo_capacity, infinite_ammo, ammo_policy);
+            section_entity.insert(turret_section(turret_config));
+
+            match controller_config {
+                SpaceshipController::None => {}
+                SpaceshipController::Player(config) => {
+                    if let Some(bindings) = config.input_mapping.get(&section.id) {
+                        section_entity.insert(SpaceshipTurretInputBinding(bindings.clone()));
+                    }
+                }
+                SpaceshipController::AI(_) => {}
+            }
+        }
+        SectionKind::Torpedo(torpedo_config) => {
+            is_weapon = true;
+            let mut torpedo_config = torpedo_config.clone();
+            torpedo_config.ammo_capacity =
+                built_magazine(torpedo_config.ammo_capacity, infinite_ammo, ammo_policy);
+            section_entity.insert(torpedo_section(torpedo_config));
+
+            match controller_config {
+                SpaceshipController::None => {}
+                SpaceshipController::Player(config) => {
+                    if let Some(bindings) = config.input_mapping.get(&section.id) {
+                        section_entity.insert(SpaceshipTorpedoInputBinding(bindings.clone()));
+                    }
+                }
+                SpaceshipController::AI(_) => {}
+            }
+        }
+    }
+
+    // Insert the authored modification components; their observers apply
+    // each delta where relevant (and are inert elsewhere). The hull's
+    // own list first, then this spawn's overrides for the section - a
+    // later component insert replaces an earlier one, so the spawn wins.
+    let mut modifications = section.modifications.clone();
+    for override_ in spawn_modifications.iter() {
+        if override_.section == section.id {
+            modifications.extend(override_.modifications.iter().cloned());
+        }
+    }
+    SectionModification::insert_all(&modifications, &mut section_entity);
+    is_weapon
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -793,6 +980,176 @@ mod tests {
         );
     }
 
+    /// The difficulty sizes the player's authored magazines and nobody else's;
+    /// an unlimited weapon and the infinite-ammo cheat are left alone.
+    #[test]
+    fn the_difficulty_sizes_only_the_players_magazines() {
+        let hard = DifficultyPolicy::for_difficulty(Difficulty::Hard);
+        let player = SpaceshipController::Player(PlayerControllerConfig::default());
+        let ai = SpaceshipController::AI(default());
+
+        assert_eq!(
+            built_magazine(Some(10), false, ammo_policy(&player, hard)),
+            Some(8)
+        );
+        assert_eq!(
+            built_magazine(Some(10), false, ammo_policy(&ai, hard)),
+            Some(10)
+        );
+        assert_eq!(
+            built_magazine(None, false, ammo_policy(&player, hard)),
+            None
+        );
+        assert_eq!(
+            built_magazine(Some(10), true, ammo_policy(&player, hard)),
+            None
+        );
+    }
+
+    /// A finished damage-control rebuild brings a lost section back where the
+    /// hull put it, with its id and full health, and a player ship gets its
+    /// authored parts.
+    #[test]
+    fn a_rebuild_respawns_the_lost_section_from_the_hull() {
+        let mut world = World::new();
+        world.init_resource::<GameSections>();
+        world.init_resource::<GameShips>();
+        world.add_observer(insert_spaceship_sections);
+        world.add_observer(rebuild_spaceship_section);
+
+        let hull_section = |id: &str, x: f32| SpaceshipSectionConfig {
+            id: id.to_string(),
+            position: Vec3::X * x,
+            rotation: Quat::IDENTITY,
+            source: SectionSource::Inline(SectionConfig {
+                base: BaseSectionConfig {
+                    id: "hull".to_string(),
+                    health: 40.0,
+                    ..default()
+                },
+                kind: SectionKind::Hull(HullSectionConfig::default()),
+            }),
+            modifications: vec![],
+        };
+        let ship = world
+            .spawn((
+                Transform::default(),
+                spaceship_scenario_object(SpaceshipConfig {
+                    controller: SpaceshipController::Player(PlayerControllerConfig {
+                        repair_parts: Some(4),
+                        ..default()
+                    }),
+                    hull: ShipSource::Inline(ShipHull {
+                        sections: vec![hull_section("core", 0.0), hull_section("wing", 1.0)],
+                        ..default()
+                    }),
+                    ..default()
+                }),
+            ))
+            .id();
+        world.flush();
+        assert_eq!(world.get::<DamageControl>(ship).map(|c| c.parts), Some(4));
+
+        let wing = |world: &mut World| {
+            world
+                .query::<(Entity, &EntityId, &Transform, &Health)>()
+                .iter(world)
+                .find(|(_, id, ..)| id.0 == "wing")
+                .map(|(entity, _, transform, health)| {
+                    (entity, transform.translation, health.current)
+                })
+        };
+        let (lost, ..) = wing(&mut world).expect("the wing was spawned");
+        world.entity_mut(lost).despawn();
+        assert!(wing(&mut world).is_none());
+
+        world.trigger(RebuildSection {
+            entity: ship,
+            section: "wing".to_string(),
+        });
+        world.flush();
+
+        let (rebuilt, at, health) = wing(&mut world).expect("the wing was rebuilt");
+        assert_eq!(world.get::<ChildOf>(rebuilt).map(|c| c.0), Some(ship));
+        assert_eq!(at, Vec3::X);
+        assert_eq!(health, 40.0);
+    }
+
+    /// A refit swaps the section for the catalog part on the same mount and
+    /// under the same id; an unknown part leaves the old section on.
+    #[test]
+    fn a_refit_swaps_the_section_for_the_catalog_part() {
+        let mut world = World::new();
+        let part = |id: &str, health: f32| SectionConfig {
+            base: BaseSectionConfig {
+                id: id.to_string(),
+                health,
+                ..default()
+            },
+            kind: SectionKind::Hull(HullSectionConfig::default()),
+        };
+        world.insert_resource(GameSections(vec![
+            part("light_hull", 40.0),
+            part("heavy_hull", 120.0),
+        ]));
+        world.init_resource::<GameShips>();
+        world.add_observer(insert_spaceship_sections);
+        world.add_observer(refit_spaceship_section);
+
+        let ship = world
+            .spawn((
+                Transform::default(),
+                spaceship_scenario_object(SpaceshipConfig {
+                    hull: ShipSource::Inline(ShipHull {
+                        sections: vec![SpaceshipSectionConfig {
+                            id: "wing".to_string(),
+                            position: Vec3::X,
+                            rotation: Quat::IDENTITY,
+                            source: SectionSource::Prototype("light_hull".to_string()),
+                            modifications: vec![],
+                        }],
+                        ..default()
+                    }),
+                    ..default()
+                }),
+            ))
+            .id();
+        world.flush();
+
+        let wing = |world: &mut World| -> Vec<(String, Vec3, f32)> {
+            world
+                .query::<(&EntityId, &EntityTypeName, &Transform, &Health)>()
+                .iter(world)
+                .filter(|(id, ..)| id.0 == "wing")
+                .map(|(_, part, transform, health)| {
+                    (part.0.clone(), transform.translation, health.max)
+                })
+                .collect()
+        };
+
+        world.trigger(RefitSection {
+            entity: ship,
+            section: "wing".to_string(),
+            part: "no_such_part".to_string(),
+        });
+        world.flush();
+        assert_eq!(
+            wing(&mut world),
+            vec![("light_hull".to_string(), Vec3::X, 40.0)]
+        );
+
+        world.trigger(RefitSection {
+            entity: ship,
+            section: "wing".to_string(),
+            part: "heavy_hull".to_string(),
+        });
+        world.flush();
+        assert_eq!(
+            wing(&mut world),
+            vec![("heavy_hull".to_string(), Vec3::X, 120.0)]
+        );
+    }
+
     /// An AI ship with no turret/torpedo section is tagged `AINonCombatant` at
     /// spawn, so it flies its routine and never chases; an armed AI ship is
     /// not. Non-AI ships never get the tag regardless.
@@ -0,0 +1,276 @@
+//! Station scenario object: a static, lockable structure with docking ports.
+//!
+//! The body is a hub and a ring on rails, lockable like a beacon so the player
+//! can travel-lock it and press `G`. Each authored port becomes a child
+//! [`DockingPort`] the flight layer docks to (see `nova_ship::flight`), and
+//! the station's [`StationServices`] say what a docked ship may ask for from
+//! NOVA OS. `OnDocked` / `OnUndocked` fire under the station's scenario id.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use nova_events::prelude::*;
+use nova_ship::prelude::*;
+
+/// The station scenario object, its config and `StationPlugin`.
+pub mod prelude {
+    pub use super::{station_scenario_object, StationConfig, StationMarker, StationPlugin};
+}
+
+/// The lock scanner sees a station from further out than a beacon: it is a
+/// destination a scenario sends the player across a whole system to.
+const STATION_LOCK_SIGNATURE: f32 = 40.0;
+
+/// Emissive luminance of a port's marker light: bright enough to pick the
+/// port out against the hull from the approach.
+const PORT_LIGHT_EMISSIVE: f32 = 30.0;
+
+/// The scenario/modding RON surface for a station object: its size and color,
+/// where its docking ports sit, how close a ship parks at one, what it offers
+/// a docked ship, and an optional lock-signature override. Passed to
+/// [`station_scenario_object`] to build the station bundle.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StationConfig {
+    /// Radius of the station's ring and collider (world units).
+    pub radius: f32,
+    /// Hull color.
+    pub color: Color,
+    /// Docking ports, as positions relative to the station. Empty (the
+    /// default) is a single port on the station's +Z rim.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub ports: Vec<Vec3>,
+    /// How far from a port (world units) the docking approach parks; `None`
+    /// = the flight layer's default.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub approach: Option<f32>,
+    /// What the station offers a docked ship; omitted offers everything.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub services: StationServices,
+    /// Radar signature override; `None` = the default
+    /// `STATION_LOCK_SIGNATURE` (1200u lock range).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub lock_signature: Option<f32>,
+}
+
+/// Marker on a station root.
+#[derive(Component, Clone, Debug, Reflect)]
+pub struct StationMarker;
+
+/// The authored ports, consumed by `insert_station_ports`.
+#[derive(Component, Clone, Debug, Reflect)]
+struct StationPorts {
+    positions: Vec<Vec3>,
+    port: DockingPort,
+}
+
+/// Render inputs, consumed by `insert_station_render`.
+#[derive(Component, Clone, Debug, Reflect)]
+struct StationRenderConfig {
+    radius: f32,
+    color: Color,
+}
+
+/// Build the station bundle from a [`StationConfig`]: a static, lockable,
+/// solid body carrying its services, and the port and render inputs the
+/// station observers read at spawn.
+pub fn station_scenario_object(config: StationConfig) -> impl Bundle {
+    trace!("station_scenario_object: config {:?}", config);
+
+    let positions = if config.ports.is_empty() {
+        vec![Vec3::Z * config.radius]
+    } else {
+        config.ports
+    };
+    let mut port = DockingPort::default();
+    if let Some(approach) = config.approach {
+        // Keep the latch outside the park point by the default margin.
+        port.latch_range = approach + (port.latch_range - port.approach);
+        port.approach = approach;
+    }
+
+    (
+        StationMarker,
+        EntityTypeName::new(STATION_TYPE_NAME),
+        config.services,
+        StationPorts { positions, port },
+        StationRenderConfig {
+            radius: config.radius,
+            color: config.color,
+        },
+        // On rails like a beacon, and lockable through its signature.
+        RigidBody::Static,
+        Collider::sphere(config.radius),
+        BodyRadius(config.radius),
+        LockSignature(config.lock_signature.unwrap_or(STATION_LOCK_SIGNATURE)),
+    )
+}
+
+/// The station scenario object: its docking ports always, its hub and ring
+/// only when `render`.
+pub struct StationPlugin {
+    /// Whether to add the render-insert observer for the visible hull (false for headless tools).
+    pub render: bool,
+}
+
+impl Plugin for StationPlugin {
+    fn build(&self, app: &mut App) {
+        trace!("StationPlugin: build");
+
+        app.register_type::<StationMarker>();
+        app.add_observer(insert_station_ports);
+        if self.render {
+            app.add_observer(insert_station_render);
+        }
+    }
+}
+
+/// One child [`DockingPort`] per authored port position.
+fn insert_station_ports(
+    add: On<Add, StationMarker>,
+    mut commands: Commands,
+    q_station: Query<&StationPorts, With<StationMarker>>,
+) {
+    let entity = add.entity;
+    let Ok(ports) = q_station.get(entity) else {
+        return;
+    };
+    let port = ports.port;
+    commands.entity(entity).with_children(|parent| {
+        for (index, position) in ports.positions.iter().enumerate() {
+            parent.spawn((
+                Name::new(format!("DockingPort{index}")),
+                port,
+                Transform::from_translation(*position),
+            ));
+        }
+    });
+}
+
+/// The visible station: a hub, a ring at the authored radius, and a marker
+/// light on each port.
+fn insert_station_render(
+    add: On<Add, StationMarker>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    q_station: Query<(&StationRenderConfig, &StationPorts), With<StationMarker>>,
+) {
+    let entity = add.entity;
+    let Ok((config, ports)) = q_station.get(entity) else {
+        error!(
+            "insert_station_render: entity {:?} not found in q_station",
+            entity
+        );
+        return;
+    };
+
+    let hull = materials.add(StandardMaterial {
+        base_color: config.color,
+        perceptual_roughness: 0.6,
+        metallic: 0.4,
+        ..default()
+    });
+    let light = materials.add(StandardMaterial {
+        base_color: Color::WHITE,
+        emissive: LinearRgba::WHITE * PORT_LIGHT_EMISSIVE,
+        ..default()
+    });
+    let hub = meshes.add(Sphere::new(config.radius * 0.35));
+    let ring = meshes.add(Torus::new(config.radius * 0.85, config.radius));
+    let marker = meshes.add(Sphere::new((config.radius * 0.04).max(0.5)));
+
+    commands.entity(entity).with_children(|parent| {
+        parent.spawn((
+            Name::new("StationHub"),
+            Mesh3d(hub),
+            MeshMaterial3d(hull.clone()),
+        ));
+        parent.spawn((
+            Name::new("StationRing"),
+            Transform::from_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            Mesh3d(ring),
+            MeshMaterial3d(hull),
+        ));
+        for position in &ports.positions {
+            parent.spawn((
+                Name::new("StationPortLight"),
+                Transform::from_translation(*position),
+                Mesh3d(marker.clone()),
+                MeshMaterial3d(light.clone()),
+            ));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> StationConfig {
+        StationConfig {
+            radius: 40.0,
+            color: Color::srgb(0.6, 0.65, 0.7),
+            ports: Vec::new(),
+            approach: None,
+            services: StationServices::default(),
+            lock_signature: None,
+        }
+    }
+
+    /// A station is a static lockable body with one port per authored
+    /// position, or one on its rim when none are authored.
+    #[test]
+    fn a_station_spawns_its_docking_ports() {
+        let mut world = World::new();
+        world.add_observer(insert_station_ports);
+
+        let plain = world.spawn(station_scenario_object(config())).id();
+        let busy = world
+            .spawn(station_scenario_object(StationConfig {
+                ports: vec![Vec3::X * 40.0, Vec3::NEG_X * 40.0],
+                approach: Some(30.0),
+                ..config()
+            }))
+            .id();
+        world.flush();
+
+        let ports_of = |world: &mut World, station: Entity| -> Vec<(Vec3, DockingPort)> {
+            world
+                .query::<(&DockingPort, &Transform, &ChildOf)>()
+                .iter(world)
+                .filter(|(_, _, &ChildOf(parent))| parent == station)
+                .map(|(port, transform, _)| (transform.translation, *port))
+                .collect()
+        };
+
+        let plain_ports = ports_of(&mut world, plain);
+        assert_eq!(plain_ports.len(), 1);
+        assert_eq!(plain_ports[0].0, Vec3::Z * 40.0);
+        assert!(matches!(
+            world.get::<RigidBody>(plain),
+            Some(RigidBody::Static)
+        ));
+        assert_eq!(
+            world.get::<LockSignature>(plain).map(|s| **s),
+            Some(STATION_LOCK_SIGNATURE)
+        );
+
+        let busy_ports = ports_of(&mut world, busy);
+        assert_eq!(busy_ports.len(), 2);
+        let port = busy_ports[0].1;
+        assert_eq!(port.approach, 30.0);
+        assert!(
+            port.latch_range > port.approach,
+            "an authored approach keeps the latch outside the park point"
+        );
+    }
+}
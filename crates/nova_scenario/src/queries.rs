@@ -32,6 +32,12 @@ pub struct ScenarioQuery {
 pub enum ScenarioProperty {
     /// Live, unpaused seconds since this scenario started.
     Elapsed,
+    /// Ore units the player has scooped into the hold this scenario. Counts
+    /// what was mined, so taking ore back out of the hold does not lower it.
+    OreMined,
+    /// The difficulty the scenario was started at, as its label: `"Easy"`,
+    /// `"Normal"` or `"Hard"`. Fixed for the run.
+    Difficulty,
 }
 
 /// A strict single-entity query.
@@ -58,6 +64,9 @@ pub struct EntityQueryFilter {
 pub enum EntityProperty {
     /// Magnitude of the entity's linear velocity in units per second.
     Speed,
+    /// Units of the named item in the entity's cargo hold; 0 when the hold
+    /// has none. Unavailable for an entity with no hold.
+    ItemCount(String),
 }
 
 /// A query sampled each live update and published under a variable name.
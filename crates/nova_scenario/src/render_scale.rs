@@ -45,11 +45,20 @@
 //! menu that toggles this very preset usable on Low). The world->screen
 //! projection stays aligned via the image target's `scale_factor` (step 2), not
 //! by sharing a coordinate space with the UI.
+//!
+//! ## Supersampling a capture
+//!
+//! The same setup runs ABOVE native for a high-resolution screenshot: while a
+//! [`RenderScaleSupersample`] is present the target is sized at that multiple
+//! of the window instead of the budget's fraction, and
+//! [`RenderScaleState::target`] hands it to the capture. Because the UI lives
+//! on the blit camera, the captured image is the world alone - photo mode gets
+//! a clean frame without hiding anything.
 
 /// Glob-import surface: `use nova_scenario::render_scale::prelude::*`
 /// re-exports the public API of this module.
 pub mod prelude {
-    pub use super::RenderScalePlugin;
+    pub use super::{RenderScalePlugin, RenderScaleState, RenderScaleSupersample};
 }
 
 use bevy::{
@@ -68,6 +77,11 @@ use crate::loader::prelude::ScenarioCameraMarker;
 /// offscreen image it samples has been rendered this frame.
 const UPSCALE_CAMERA_ORDER: isize = 1;
 
+/// The largest offscreen target axis a supersample may ask for, in pixels: the
+/// WebGL2 / downlevel `max_texture_dimension_2d` floor, so a 4x capture of a
+/// wide window shrinks its factor instead of failing the allocation.
+const MAX_TARGET_AXIS: u32 = 8192;
+
 /// Adds the render-scale reconcile. Registered by [`crate::NovaScenarioPlugin`]
 /// only when rendering (the lever is a no-op without a window/GPU).
 pub struct RenderScalePlugin;
@@ -79,11 +93,38 @@ impl Plugin for RenderScalePlugin {
     }
 }
 
+/// Draw the scenario view at this multiple of the window's resolution while
+/// present - the high-resolution capture lever. Clamped to
+/// `1.0..=`[`RenderScaleSupersample::MAX`]; insert it, wait for a frame to
+/// render into [`RenderScaleState::target`], capture, then remove it.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct RenderScaleSupersample(pub f32);
+
+impl RenderScaleSupersample {
+    /// The largest factor honored.
+    pub const MAX: f32 = 4.0;
+
+    /// The target size for a window of `window_physical` pixels: the factor
+    /// clamped into `[1, MAX]`, then shrunk so neither axis passes the texture
+    /// limit.
+    pub fn target_size(self, window_physical: UVec2) -> UVec2 {
+        let longest = window_physical.max_element().max(1) as f32;
+        let scale = self
+            .0
+            .clamp(1.0, Self::MAX)
+            .min(MAX_TARGET_AXIS as f32 / longest);
+        UVec2::new(
+            ((window_physical.x as f32 * scale).round() as u32).max(1),
+            ((window_physical.y as f32 * scale).round() as u32).max(1),
+        )
+    }
+}
+
 /// The live render-scale setup, so the reconcile only rebuilds on an actual
 /// change (missing/wrong-sized target, or a quality/window flip) rather than
 /// churning the render graph every frame.
 #[derive(Resource, Default)]
-struct RenderScaleState {
+pub struct RenderScaleState {
     /// The offscreen target the scenario view renders into, when downscaling.
     image: Option<Handle<Image>>,
     /// The size `image` was created at, to detect window/scale changes.
@@ -92,6 +133,19 @@ struct RenderScaleState {
     upscale_camera: Option<Entity>,
 }
 
+impl RenderScaleState {
+    /// The offscreen image the scenario view is drawn into, when it is not
+    /// drawn straight to the window.
+    pub fn target(&self) -> Option<&Handle<Image>> {
+        self.image.as_ref()
+    }
+
+    /// The size of [`target`](Self::target), or zero when there is none.
+    pub fn target_size(&self) -> UVec2 {
+        self.size
+    }
+}
+
 /// Marks the render-scale blit camera (the full-window Camera2d).
 #[derive(Component)]
 struct RenderScaleUpscaleCamera;
@@ -106,6 +160,7 @@ struct RenderScaleUpscaleSprite;
 fn reconcile_render_scale(
     mut commands: Commands,
     budget: Res<GraphicsBudget>,
+    supersample: Option<Res<RenderScaleSupersample>>,
     windows: Query<&Window, With<PrimaryWindow>>,
     mut images: ResMut<Assets<Image>>,
     mut state: ResMut<RenderScaleState>,
@@ -121,17 +176,18 @@ fn reconcile_render_scale(
     };
     let physical = window.physical_size();
 
-    // NOTE: downscale only when the preset asks for it AND there is a scenario camera
-    // to redirect (never in the menu/editor, whose cameras are not scenario
-    // cameras - they keep full resolution). A zero-axis window (minimized, or
-    // not yet sized) is left untouched: recreating a zero-area target is a fatal
-    // wgpu allocation.
-    let want_downscale = !budget.is_native_resolution()
+    // NOTE: go offscreen only when the preset or a capture asks for it AND there is
+    // a scenario camera to redirect (never in the menu/editor, whose cameras are
+    // not scenario cameras - they keep full resolution). A zero-axis window
+    // (minimized, or not yet sized) is left untouched: recreating a zero-area
+    // target is a fatal wgpu allocation.
+    let supersample = supersample.map(|supersample| *supersample);
+    let want_offscreen = (supersample.is_some() || !budget.is_native_resolution())
         && !q_scenario_cam.is_empty()
         && physical.x > 0
         && physical.y > 0;
 
-    if !want_downscale {
+    if !want_offscreen {
         teardown_render_scale(
             &mut commands,
             &mut state,
@@ -141,7 +197,10 @@ fn reconcile_render_scale(
         return;
     }
 
-    let desired = budget.render_target_size(physical);
+    let desired = match supersample {
+        Some(supersample) => supersample.target_size(physical),
+        None => budget.render_target_size(physical),
+    };
 
     let need_new_target = match &state.image {
         Some(handle) => state.size != desired || !images.contains(handle),
@@ -515,6 +574,40 @@ mod tests {
         assert_ne!(state.size, first, "resize rebuilt the target");
     }
 
+    /// A supersample draws ABOVE native on the crisp tiers, clamps to the
+    /// texture limit, and tears down to the window path once removed.
+    #[test]
+    fn a_supersample_renders_above_native_until_removed() {
+        let mut app = test_app(GraphicsQuality::High);
+        let cam = spawn_scenario_camera(&mut app);
+        app.insert_resource(RenderScaleSupersample(2.0));
+        app.update();
+
+        let state = app.world().resource::<RenderScaleState>();
+        assert_eq!(state.target_size(), UVec2::new(2560, 1440));
+        assert!(state.target().is_some());
+        assert!(matches!(
+            app.world().entity(cam).get::<RenderTarget>(),
+            Some(RenderTarget::Image(_))
+        ));
+        assert_eq!(
+            RenderScaleSupersample(4.0).target_size(UVec2::new(3840, 2160)),
+            UVec2::new(8192, 4608)
+        );
+
+        app.world_mut().remove_resource::<RenderScaleSupersample>();
+        app.update();
+        assert!(app
+            .world()
+            .resource::<RenderScaleState>()
+            .target()
+            .is_none());
+        assert!(matches!(
+            app.world().entity(cam).get::<RenderTarget>(),
+            Some(RenderTarget::Window(_))
+        ));
+    }
+
     #[test]
     fn downscale_needs_a_scenario_camera() {
         // Low preset but no scenario camera (menu/editor): nothing is set up, so
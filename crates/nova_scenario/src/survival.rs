@@ -0,0 +1,923 @@
+//! Survival mode: seeded, escalating hostile waves generated from the ship
+//! catalog when the scenario loads.
+//!
+//! A survival scenario is a TEMPLATE ([`ScenarioConfig::survival`]). Its own
+//! events stage the arena, the lights and the player ship, and
+//! [`expand_survival`] appends the waves at load. Each wave is sized with the
+//! threat numbers the balance audit grades authored spawns on ([`ShipStats`]):
+//!
+//! - its combined burst dps is budgeted so the player ship, at its summed
+//!   section health, would last a target TTK against the wave's aligned fire.
+//!   The target tightens geometrically from `first_wave_ttk` to
+//!   `last_wave_ttk`, and the difficulty tier scales incoming damage on top, as
+//!   it does for authored content;
+//! - every hostile spawns outside its own threat envelope, plus
+//!   `spawn_margin`, from the player spawn - the distance the audit's
+//!   `spawned-dead` and `close-spawn` rules measure, so a generated wave never
+//!   trips either;
+//! - a cleared wave rearms the player and starts a rest timer; the next wave
+//!   launches when it ends.
+//!
+//! The seed pins the run: ship picks and spawn bearings all come from one
+//! `StdRng`. The load stamps [`SurvivalSeed`] into a template that carries no
+//! seed, and [`CurrentScenario`] keeps the stamped template, so a Retry flies
+//! the same waves and a replay, which records the seed, rebuilds them.
+//!
+//! Progress lives in scenario variables ([`SURVIVAL_WAVE_VAR`] and friends)
+//! that ordinary `HudReadout`s put on the HUD, and [`SurvivalScores`] keeps
+//! the best runs of every survival scenario at each tier. A run flown under a
+//! replay or the fuzzer (`PlayerInputSuspended`) is not filed.
+
+use std::{collections::BTreeMap, f32::consts::TAU};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use nova_gameplay::prelude::*;
+use nova_ship::prelude::*;
+use rand::{rngs::StdRng, RngExt, SeedableRng};
+
+use crate::{prelude::*, world::NovaEventWorld};
+
+/// The survival config, the generator, the seed and score resources, and the
+/// wave variables.
+pub mod prelude {
+    pub use super::{
+        expand_survival, plan_survival_waves, SurvivalConfig, SurvivalHostile, SurvivalPlugin,
+        SurvivalRun, SurvivalScore, SurvivalScores, SurvivalSeed, SurvivalWave,
+        SURVIVAL_CLEARED_VAR, SURVIVAL_HOSTILES_VAR, SURVIVAL_SCORES_KEPT, SURVIVAL_WAVE_VAR,
+    };
+}
+
+/// The wave in progress, from 1.
+pub const SURVIVAL_WAVE_VAR: &str = "survival_wave";
+/// Hostiles of the current wave still in the fight.
+pub const SURVIVAL_HOSTILES_VAR: &str = "survival_hostiles";
+/// Waves cleared so far - the run's score.
+pub const SURVIVAL_CLEARED_VAR: &str = "survival_cleared";
+/// 1 while a rest period runs, so the clear check fires once per wave.
+pub(crate) const SURVIVAL_RESTING_VAR: &str = "survival_resting";
+/// The rest timer between waves.
+const SURVIVAL_REST_TIMER: &str = "survival_rest";
+
+/// How many runs [`SurvivalScores`] keeps per scenario and tier.
+pub const SURVIVAL_SCORES_KEPT: usize = 5;
+
+/// The speaker of the generated wave calls.
+const SURVIVAL_SPEAKER: &str = "Control";
+
+/// The authored half of a survival scenario: how many waves, what they are
+/// drawn from and how hard they climb. Every field is serde-defaulted, so
+/// `survival: Some(())` is a complete eight-wave run over the whole catalog.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SurvivalConfig {
+    /// Waves to survive; clearing the last one is the victory.
+    pub waves: u32,
+    /// Catalog ship ids the waves draw from. Empty draws from every catalog
+    /// ship with turret fire; a ship without any carries no dps to budget and
+    /// is never drawn.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub pool: Vec<ShipId>,
+    /// Seconds of rest between a cleared wave and the next one.
+    pub rest_secs: f32,
+    /// Burst-fire seconds the player ship lasts against the whole first wave
+    /// (summed section health over the wave's combined dps).
+    pub first_wave_ttk: f32,
+    /// The same for the last wave. Lower is harder.
+    pub last_wave_ttk: f32,
+    /// The most hostiles one wave may field, whatever its budget.
+    pub max_wave_size: u32,
+    /// Extra distance past each hostile's own threat envelope that it spawns
+    /// at, from the player spawn.
+    pub spawn_margin: f32,
+    /// The seed the waves are built from. Leave it unset: the load stamps the
+    /// player's pick into it. An authored seed pins every run to one layout.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub seed: Option<u64>,
+}
+
+impl Default for SurvivalConfig {
+    fn default() -> Self {
+        Self {
+            waves: 8,
+            pool: Vec::new(),
+            rest_secs: 20.0,
+            first_wave_ttk: 2.5,
+            last_wave_ttk: 0.5,
+            max_wave_size: 6,
+            spawn_margin: 100.0,
+            seed: None,
+        }
+    }
+}
+
+impl SurvivalConfig {
+    /// The target TTK of wave `index` (from 0): a geometric slide from
+    /// `first_wave_ttk` to `last_wave_ttk`, so every wave is the same factor
+    /// harder than the one before.
+    pub fn wave_ttk(&self, index: u32) -> f32 {
+        let first = self.first_wave_ttk.max(f32::EPSILON);
+        let last = self.last_wave_ttk.max(f32::EPSILON);
+        if self.waves <= 1 {
+            return first;
+        }
+        let t = index.min(self.waves - 1) as f32 / (self.waves - 1) as f32;
+        first * (last / first).powf(t)
+    }
+}
+
+/// The seed the next survival load builds its waves from. The scenario picker
+/// draws and rerolls it; a load only reads it.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SurvivalSeed(pub Option<u64>);
+
+/// One generated hostile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SurvivalHostile {
+    /// Its scenario object id, `survival_w<wave>_<n>`.
+    pub id: String,
+    /// The catalog ship it flies.
+    pub ship: ShipId,
+    /// Where it spawns.
+    pub position: Vec3,
+    /// Its threat numbers, as budgeted.
+    pub stats: ShipStats,
+}
+
+/// One generated wave.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SurvivalWave {
+    /// The combined dps the wave was allowed.
+    pub budget: f32,
+    /// The hostiles it fields.
+    pub hostiles: Vec<SurvivalHostile>,
+}
+
+impl SurvivalWave {
+    /// The combined burst dps the wave actually fields.
+    pub fn dps(&self) -> f32 {
+        self.hostiles.iter().map(|hostile| hostile.stats.dps).sum()
+    }
+}
+
+/// The player ship a template stages: its id, spawn point and spawn config.
+pub(crate) fn template_player(
+    scenario: &ScenarioConfig,
+) -> Option<(String, Vec3, &SpaceshipConfig)> {
+    scenario
+        .events
+        .iter()
+        .filter(|event| matches!(event.name, EventConfig::OnStart))
+        .flat_map(|event| &event.actions)
+        .find_map(|action| match action {
+            EventActionConfig::SpawnScenarioObject(config) => match &config.kind {
+                ScenarioObjectKind::Spaceship(ship)
+                    if matches!(ship.controller, SpaceshipController::Player(_)) =>
+                {
+                    Some((config.base.id.clone(), config.base.position, ship))
+                }
+                _ => None,
+            },
+            _ => None,
+        })
+}
+
+/// The spawn config of a generated hostile: the catalog ship under an AI that
+/// heads for the arena and hunts across all of it.
+fn hostile_ship(ship: &str, arena: Vec3, reach: f32) -> SpaceshipConfig {
+    SpaceshipConfig {
+        hull: ShipSource::Prototype(ship.to_string()),
+        controller: SpaceshipController::AI(AIControllerConfig {
+            patrol: vec![arena],
+            // Spawned past the default detection range on purpose (outside the
+            // envelope): the wave must still come for the player.
+            engage_range: Some(reach),
+            ..default()
+        }),
+        allegiance: Some(Allegiance::Enemy),
+        ..default()
+    }
+}
+
+/// Build the waves a survival template flies with `seed`, against the player
+/// ship it stages. Empty when the template stages no player ship or its pool
+/// holds nothing with turret fire.
+pub fn plan_survival_waves(
+    scenario: &ScenarioConfig,
+    seed: u64,
+    sections: &GameSections,
+    ships: &GameShips,
+) -> Vec<SurvivalWave> {
+    let Some(config) = &scenario.survival else {
+        return Vec::new();
+    };
+    let Some((_, spawn, player)) = template_player(scenario) else {
+        return Vec::new();
+    };
+    let player = ships.stats(player, sections);
+
+    let pool: Vec<&ShipId> = if config.pool.is_empty() {
+        ships.iter().map(|ship| &ship.id).collect()
+    } else {
+        config.pool.iter().collect()
+    };
+    let mut candidates: Vec<(&ShipId, ShipStats)> = pool
+        .into_iter()
+        .map(|id| (id, ships.stats(&hostile_ship(id, spawn, 0.0), sections)))
+        .filter(|(_, stats)| stats.dps > 0.0)
+        .collect();
+    candidates.sort_by(|a, b| a.1.dps.total_cmp(&b.1.dps).then_with(|| a.0.cmp(b.0)));
+    let Some(&(weakest, weakest_stats)) = candidates.first() else {
+        return Vec::new();
+    };
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..config.waves)
+        .map(|index| {
+            let budget = player.hp / config.wave_ttk(index);
+            let mut picks: Vec<(&ShipId, ShipStats)> = Vec::new();
+            let mut spent = 0.0;
+            while picks.len() < config.max_wave_size.max(1) as usize {
+                let fits: Vec<&(&ShipId, ShipStats)> = candidates
+                    .iter()
+                    .filter(|(_, stats)| spent + stats.dps <= budget)
+                    .collect();
+                let (id, stats) = match fits.len() {
+                    // A wave always fields someone, even when the budget
+                    // cannot afford the weakest ship.
+                    0 if picks.is_empty() => (weakest, weakest_stats),
+                    0 => break,
+                    n => *fits[rng.random_range(0..n)],
+                };
+                spent += stats.dps;
+                picks.push((id, stats));
+            }
+
+            // An even ring around the spawn, turned and jittered per wave.
+            let turn = rng.random_range(0.0..TAU);
+            let slot = TAU / picks.len() as f32;
+            let hostiles = picks
+                .into_iter()
+                .enumerate()
+                .map(|(n, (ship, stats))| {
+                    let distance = stats.threat_envelope() + config.spawn_margin.max(0.0);
+                    let bearing = turn + slot * n as f32 + rng.random_range(-0.25..0.25) * slot;
+                    let rise = rng.random_range(-0.2..0.2) * distance;
+                    let position =
+                        spawn + Vec3::new(bearing.cos() * distance, rise, bearing.sin() * distance);
+                    SurvivalHostile {
+                        id: format!("survival_w{}_{}", index + 1, n),
+                        ship: ship.clone(),
+                        position,
+                        stats,
+                    }
+                })
+                .collect();
+            SurvivalWave { budget, hostiles }
+        })
+        .collect()
+}
+
+fn number(value: f64) -> VariableExpressionNode {
+    VariableExpressionNode::new_term(VariableTermNode::new_factor(
+        VariableFactorNode::new_literal(VariableLiteral::Number(value)),
+    ))
+}
+
+fn variable(name: &str) -> VariableExpressionNode {
+    VariableExpressionNode::new_term(VariableTermNode::new_factor(VariableFactorNode::new_name(
+        name,
+    )))
+}
+
+fn set(key: &str, expression: VariableExpressionNode) -> EventActionConfig {
+    EventActionConfig::VariableSet(VariableSetActionConfig {
+        key: key.to_string(),
+        expression,
+    })
+}
+
+fn equals(name: &str, value: f64) -> EventFilterConfig {
+    EventFilterConfig::Expression(ExpressionFilterConfig(VariableConditionNode::new_equals(
+        variable(name),
+        number(value),
+    )))
+}
+
+fn call(text: String) -> EventActionConfig {
+    EventActionConfig::StoryMessage(StoryMessageActionConfig {
+        speaker: SURVIVAL_SPEAKER.to_string(),
+        text,
+        dwell: None,
+        icon: None,
+    })
+}
+
+fn readout(slot: &str, variable: &str, label: &str) -> EventActionConfig {
+    EventActionConfig::HudReadout(HudReadoutActionConfig {
+        format: HudReadoutFormatConfig::Integer,
+        label: Some(label.to_string()),
+        ..HudReadoutActionConfig::new(slot, variable)
+    })
+}
+
+fn event(
+    name: EventConfig,
+    filters: Vec<EventFilterConfig>,
+    actions: Vec<EventActionConfig>,
+) -> ScenarioEventConfig {
+    ScenarioEventConfig {
+        name,
+        filters,
+        actions,
+    }
+}
+
+/// The wave's launch: its counters, its call, and its ships.
+fn launch(number_from_one: u32, wave: &SurvivalWave, arena: Vec3) -> Vec<EventActionConfig> {
+    let reach = wave
+        .hostiles
+        .iter()
+        .map(|hostile| hostile.position.distance(arena))
+        .fold(0.0, f32::max)
+        * 2.0;
+    let mut actions = vec![
+        set(SURVIVAL_WAVE_VAR, number(number_from_one as f64)),
+        set(SURVIVAL_HOSTILES_VAR, number(wave.hostiles.len() as f64)),
+        set(SURVIVAL_RESTING_VAR, number(0.0)),
+        call(format!(
+            "Wave {number_from_one}: {} contact(s) inbound.",
+            wave.hostiles.len()
+        )),
+    ];
+    actions.extend(wave.hostiles.iter().map(|hostile| {
+        EventActionConfig::SpawnScenarioObject(ScenarioObjectConfig {
+            base: BaseScenarioObjectConfig {
+                id: hostile.id.clone(),
+                name: format!("Wave {number_from_one} Hostile"),
+                position: hostile.position,
+                rotation: Transform::from_translation(hostile.position)
+                    .looking_at(arena, Vec3::Y)
+                    .rotation,
+            },
+            kind: ScenarioObjectKind::Spaceship(hostile_ship(&hostile.ship, arena, reach)),
+        })
+    }));
+    actions
+}
+
+/// Append a survival template's waves, built from its stamped seed (0 when
+/// unstamped), to its events. A scenario without a survival config, or whose
+/// waves come out empty, is returned unchanged.
+///
+/// What the waves add, all keyed off the [`SURVIVAL_WAVE_VAR`] family:
+///
+/// - at start, the counters, the WAVE and HOSTILES readouts and wave 1;
+/// - per hostile, an `OnDefeated` counting it out of the wave;
+/// - the clear: rearm the player and start the rest timer, or, after the last
+///   wave, the victory;
+/// - per later wave, its launch when the rest timer ends on the wave before;
+/// - the defeat, when the player ship is.
+pub fn expand_survival(
+    mut scenario: ScenarioConfig,
+    sections: &GameSections,
+    ships: &GameShips,
+) -> ScenarioConfig {
+    let Some(config) = scenario.survival.clone() else {
+        return scenario;
+    };
+    let waves = plan_survival_waves(&scenario, config.seed.unwrap_or_default(), sections, ships);
+    let Some((player, arena, _)) = template_player(&scenario) else {
+        warn!(
+            "expand_survival: '{}' stages no player ship; no waves to build",
+            scenario.id
+        );
+        return scenario;
+    };
+    if waves.is_empty() {
+        warn!(
+            "expand_survival: '{}' draws from no ship with turret fire; no waves to build",
+            scenario.id
+        );
+        return scenario;
+    }
+    let last = waves.len() as f64;
+
+    let mut events = Vec::new();
+    let mut start = vec![
+        set(SURVIVAL_CLEARED_VAR, number(0.0)),
+        readout(SURVIVAL_WAVE_VAR, SURVIVAL_WAVE_VAR, "WAVE"),
+        readout(SURVIVAL_HOSTILES_VAR, SURVIVAL_HOSTILES_VAR, "HOSTILES"),
+    ];
+    start.extend(launch(1, &waves[0], arena));
+    events.push(event(EventConfig::OnStart, vec![], start));
+
+    for (index, wave) in waves.iter().enumerate() {
+        for hostile in &wave.hostiles {
+            events.push(event(
+                EventConfig::OnDefeated,
+                vec![EventFilterConfig::Entity(EntityFilterConfig {
+                    id: Some(hostile.id.clone()),
+                    ..default()
+                })],
+                vec![set(
+                    SURVIVAL_HOSTILES_VAR,
+                    VariableExpressionNode::new_subtract(
+                        VariableTermNode::new_factor(VariableFactorNode::new_name(
+                            SURVIVAL_HOSTILES_VAR,
+                        )),
+                        number(1.0),
+                    ),
+                )],
+            ));
+        }
+        if index > 0 {
+            events.push(event(
+                EventConfig::OnTimerEnd,
+                vec![
+                    EventFilterConfig::Timer(TimerFilterConfig {
+                        key: SURVIVAL_REST_TIMER.to_string(),
+                    }),
+                    equals(SURVIVAL_WAVE_VAR, index as f64),
+                ],
+                launch(index as u32 + 1, wave, arena),
+            ));
+        }
+    }
+
+    let cleared = vec![
+        equals(SURVIVAL_HOSTILES_VAR, 0.0),
+        equals(SURVIVAL_RESTING_VAR, 0.0),
+    ];
+    events.push(event(
+        EventConfig::OnUpdate,
+        [
+            cleared.clone(),
+            vec![EventFilterConfig::Expression(ExpressionFilterConfig(
+                VariableConditionNode::new_less_than(variable(SURVIVAL_WAVE_VAR), number(last)),
+            ))],
+        ]
+        .concat(),
+        vec![
+            set(SURVIVAL_CLEARED_VAR, variable(SURVIVAL_WAVE_VAR)),
+            set(SURVIVAL_RESTING_VAR, number(1.0)),
+            EventActionConfig::Rearm(RearmActionConfig { id: player.clone() }),
+            call(format!(
+                "Wave cleared. Magazines refilled - next wave in {:.0} seconds.",
+                config.rest_secs
+            )),
+            EventActionConfig::TimerStart(TimerStartActionConfig {
+                key: SURVIVAL_REST_TIMER.to_string(),
+                seconds: number(config.rest_secs.max(0.1) as f64),
+            }),
+        ],
+    ));
+    events.push(event(
+        EventConfig::OnUpdate,
+        [cleared, vec![equals(SURVIVAL_WAVE_VAR, last)]].concat(),
+        vec![
+            set(SURVIVAL_CLEARED_VAR, number(last)),
+            set(SURVIVAL_RESTING_VAR, number(1.0)),
+            EventActionConfig::Outcome(OutcomeActionConfig::new(
+                ScenarioOutcomeKind::Victory,
+                &format!("Survived all {last} waves."),
+            )),
+        ],
+    ));
+    events.push(event(
+        EventConfig::OnDefeated,
+        vec![EventFilterConfig::Entity(EntityFilterConfig {
+            id: Some(player),
+            ..default()
+        })],
+        vec![EventActionConfig::Outcome(OutcomeActionConfig::new(
+            ScenarioOutcomeKind::Defeat,
+            "The waves overran you.",
+        ))],
+    ));
+
+    scenario.events.extend(events);
+    scenario
+}
+
+/// The survival half of the load: the player's seed and the catalogs the waves
+/// are built from. One param so the loader stays under the system arity.
+#[derive(SystemParam)]
+pub(crate) struct SurvivalLoad<'w> {
+    seed: Option<Res<'w, SurvivalSeed>>,
+    sections: Option<Res<'w, GameSections>>,
+    ships: Option<Res<'w, GameShips>>,
+}
+
+impl SurvivalLoad<'_> {
+    /// Stamp the player's seed into a survival template that carries none.
+    pub(crate) fn stamp(&self, scenario: &mut ScenarioConfig) {
+        let seed = self.seed.as_deref().and_then(|seed| seed.0);
+        if let Some(survival) = scenario.survival.as_mut() {
+            survival.seed = survival.seed.or(seed).or(Some(0));
+        }
+    }
+
+    /// [`expand_survival`] through the live catalogs.
+    pub(crate) fn expand(&self, scenario: ScenarioConfig) -> ScenarioConfig {
+        match (&self.sections, &self.ships) {
+            (Some(sections), Some(ships)) => expand_survival(scenario, sections, ships),
+            _ => scenario,
+        }
+    }
+}
+
+/// One finished survival run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SurvivalScore {
+    /// Waves cleared.
+    pub waves: u32,
+    /// Scenario seconds from the start to the outcome.
+    pub time: f64,
+    /// The seed the waves were built from.
+    pub seed: u64,
+    /// The tier the run was flown at.
+    pub difficulty: Difficulty,
+}
+
+impl SurvivalScore {
+    /// More waves is better; between equal wave counts, the quicker run.
+    fn beats(&self, other: &SurvivalScore) -> bool {
+        self.waves > other.waves || (self.waves == other.waves && self.time < other.time)
+    }
+}
+
+/// The best survival runs, per scenario id. Each tier ranks apart - an Easy
+/// run never bumps a Hard one - best first and at most
+/// [`SURVIVAL_SCORES_KEPT`] per tier.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct SurvivalScores(pub BTreeMap<ScenarioId, Vec<SurvivalScore>>);
+
+impl SurvivalScores {
+    /// Insert a run; its place in its tier's table (from 0), or `None` when
+    /// it did not make the cut.
+    pub fn record(&mut self, scenario: &str, score: SurvivalScore) -> Option<usize> {
+        let tier = score.difficulty;
+        let scores = self.0.entry(scenario.to_string()).or_default();
+        let rivals: Vec<usize> = (0..scores.len())
+            .filter(|&at| scores[at].difficulty == tier)
+            .collect();
+        let place = rivals
+            .iter()
+            .position(|&at| score.beats(&scores[at]))
+            .unwrap_or(rivals.len());
+        if place >= SURVIVAL_SCORES_KEPT {
+            return None;
+        }
+        scores.insert(rivals.get(place).copied().unwrap_or(scores.len()), score);
+        let mut kept = 0;
+        scores.retain(|score| {
+            kept += usize::from(score.difficulty == tier);
+            score.difficulty != tier || kept <= SURVIVAL_SCORES_KEPT
+        });
+        Some(place)
+    }
+
+    /// A scenario's table at one tier, best first.
+    pub fn table(&self, scenario: &str, difficulty: Difficulty) -> Vec<&SurvivalScore> {
+        self.0
+            .get(scenario)
+            .into_iter()
+            .flatten()
+            .filter(|score| score.difficulty == difficulty)
+            .collect()
+    }
+}
+
+/// The survival run in play: its seed, and whether its score is in.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct SurvivalRun {
+    /// The seed the live scenario's waves were built from; `None` when it is
+    /// not a survival scenario.
+    pub seed: Option<u64>,
+    /// Where the run landed in [`SurvivalScores`], once it has ended.
+    pub place: Option<Option<usize>>,
+}
+
+/// Collects [`SurvivalScores`] and tracks the [`SurvivalRun`].
+pub struct SurvivalPlugin;
+
+impl Plugin for SurvivalPlugin {
+    fn build(&self, app: &mut App) {
+        trace!("SurvivalPlugin: build");
+
+        app.init_resource::<SurvivalSeed>();
+        app.init_resource::<SurvivalScores>();
+        app.init_resource::<SurvivalRun>();
+        app.add_observer(start_survival_run);
+        app.add_systems(
+            Update,
+            record_survival_score.run_if(resource_exists::<NovaEventWorld>),
+        );
+    }
+}
+
+fn start_survival_run(loaded: On<ScenarioLoaded>, mut run: ResMut<SurvivalRun>) {
+    *run = SurvivalRun {
+        seed: loaded.survival_seed,
+        place: None,
+    };
+}
+
+/// File the run when its outcome lands - once, whichever way it went. A run
+/// played back or fuzzed with the player's input suspended is not the
+/// player's, so it is left out.
+fn record_survival_score(
+    world: Res<NovaEventWorld>,
+    suspended: Option<Res<PlayerInputSuspended>>,
+    outcome: Option<Res<CurrentOutcome>>,
+    current: Res<CurrentScenario>,
+    mut run: ResMut<SurvivalRun>,
+    mut scores: ResMut<SurvivalScores>,
+) {
+    let Some(outcome) = outcome.filter(|outcome| outcome.is_changed()) else {
+        return;
+    };
+    if suspended.is_some_and(|suspended| suspended.0) {
+        return;
+    }
+    let (Some(_), Some(seed), None) = (&outcome.0, run.seed, run.place) else {
+        return;
+    };
+    let Some(scenario) = current.as_ref() else {
+        return;
+    };
+    let waves = match world.get_variable(SURVIVAL_CLEARED_VAR) {
+        Some(VariableLiteral::Number(cleared)) => cleared.max(0.0) as u32,
+        _ => 0,
+    };
+    let score = SurvivalScore {
+        waves,
+        time: world.scenario_elapsed(),
+        seed,
+        difficulty: world.difficulty(),
+    };
+    debug!(
+        "record_survival_score: '{}' cleared {} wave(s) on seed {seed:#x}",
+        scenario.id, waves
+    );
+    run.place = Some(scores.record(&scenario.id, score));
+}
+
+#[cfg(test)]
+mod tests {
+    use nova_gameplay::prelude::AssetRef;
+
+    use super::*;
+
+    fn hull_section(id: &str, health: f32) -> SectionConfig {
+        SectionConfig {
+            base: BaseSectionConfig {
+                id: id.to_string(),
+                name: id.to_string(),
+                health,
+                ..default()
+            },
+            kind: SectionKind::Hull(HullSectionConfig::default()),
+        }
+    }
+
+    fn catalog() -> (GameSections, GameShips) {
+        let sections = GameSections(vec![
+            hull_section("plate", 500.0),
+            SectionConfig {
+                base: BaseSectionConfig {
+                    id: "gun".to_string(),
+                    name: "Gun".to_string(),
+                    health: 100.0,
+                    ..default()
+                },
+                kind: SectionKind::Turret(TurretSectionConfig::default()),
+            },
+        ]);
+        let ship = |id: &str, guns: usize| ShipConfig {
+            id: id.to_string(),
+            name: id.to_string(),
+            hull: ShipHull {
+                sections: std::iter::once("plate")
+                    .chain(std::iter::repeat_n("gun", guns))
+                    .enumerate()
+                    .map(|(n, source)| SpaceshipSectionConfig {
+                        id: format!("s{n}"),
+                        position: Vec3::ZERO,
+                        rotation: Quat::IDENTITY,
+                        source: SectionSource::Prototype(source.to_string()),
+                        modifications: vec![],
+                    })
+                    .collect(),
+                ..default()
+            },
+        };
+        let ships = GameShips(vec![
+            ship("player_grade", 2),
+            ship("skiff", 1),
+            ship("gunboat", 3),
+            ship("barge", 0),
+        ]);
+        (sections, ships)
+    }
+
+    fn template(survival: SurvivalConfig) -> ScenarioConfig {
+        let player = ScenarioObjectConfig {
+            base: BaseScenarioObjectConfig {
+                id: "player".to_string(),
+                name: "Player".to_string(),
+                position: Vec3::new(10.0, 0.0, -5.0),
+                rotation: Quat::IDENTITY,
+            },
+            kind: ScenarioObjectKind::Spaceship(SpaceshipConfig {
+                hull: ShipSource::Prototype("player_grade".to_string()),
+                controller: SpaceshipController::Player(default()),
+                ..default()
+            }),
+        };
+        ScenarioConfig {
+            survival: Some(survival),
+            events: vec![ScenarioEventConfig {
+                name: EventConfig::OnStart,
+                filters: vec![],
+                actions: vec![EventActionConfig::SpawnScenarioObject(player)],
+            }],
+            ..ScenarioConfig::new("holdout", "Holdout", AssetRef::default())
+        }
+    }
+
+    /// One seed is one layout; the waves climb toward the budget and every
+    /// hostile spawns outside its own threat envelope from the player spawn.
+    #[test]
+    fn a_seed_builds_escalating_waves_outside_every_envelope() {
+        let (sections, ships) = catalog();
+        let scenario = template(SurvivalConfig::default());
+
+        let waves = plan_survival_waves(&scenario, 7, &sections, &ships);
+        assert_eq!(waves, plan_survival_waves(&scenario, 7, &sections, &ships));
+        assert_ne!(waves, plan_survival_waves(&scenario, 8, &sections, &ships));
+        assert_eq!(waves.len(), 8);
+
+        let spawn = Vec3::new(10.0, 0.0, -5.0);
+        for wave in &waves {
+            assert!(!wave.hostiles.is_empty() && wave.hostiles.len() <= 6);
+            assert!(wave.hostiles.len() == 1 || wave.dps() <= wave.budget);
+            for hostile in &wave.hostiles {
+                assert_ne!(hostile.ship, "barge", "an unarmed ship is never drawn");
+                assert!(hostile.position.distance(spawn) > hostile.stats.threat_envelope());
+            }
+        }
+        assert!(waves[7].budget > waves[0].budget * 4.0);
+        assert!(waves[7].dps() > waves[0].dps());
+    }
+
+    /// The expansion stages the counters and readouts, wave 1 at start, and
+    /// the clear, launch, victory and defeat handlers for the rest.
+    #[test]
+    fn the_expansion_appends_the_wave_handlers() {
+        let (sections, ships) = catalog();
+        let scenario = template(SurvivalConfig {
+            waves: 3,
+            seed: Some(11),
+            ..default()
+        });
+        let waves = plan_survival_waves(&scenario, 11, &sections, &ships);
+        let expanded = expand_survival(scenario, &sections, &ships);
+
+        let count = |name: fn(&EventConfig) -> bool| {
+            expanded
+                .events
+                .iter()
+                .filter(|event| name(&event.name))
+                .count()
+        };
+        let hostiles: usize = waves.iter().map(|wave| wave.hostiles.len()).sum();
+        assert_eq!(count(|name| matches!(name, EventConfig::OnStart)), 2);
+        assert_eq!(
+            count(|name| matches!(name, EventConfig::OnTimerEnd)),
+            2,
+            "waves 2 and 3 launch on rest"
+        );
+        assert_eq!(
+            count(|name| matches!(name, EventConfig::OnUpdate)),
+            2,
+            "the clear and the victory"
+        );
+        assert_eq!(
+            count(|name| matches!(name, EventConfig::OnDefeated)),
+            hostiles + 1
+        );
+
+        let start = &expanded.events[1].actions;
+        let spawned = start
+            .iter()
+            .filter(|action| matches!(action, EventActionConfig::SpawnScenarioObject(_)))
+            .count();
+        assert_eq!(spawned, waves[0].hostiles.len());
+        assert!(start.iter().any(|action| matches!(
+            action,
+            EventActionConfig::HudReadout(readout) if readout.variable == SURVIVAL_WAVE_VAR
+        )));
+        assert!(expanded
+            .events
+            .iter()
+            .flat_map(|e| &e.actions)
+            .any(|action| matches!(
+                action,
+                EventActionConfig::Rearm(rearm) if rearm.id == "player"
+            )));
+    }
+
+    /// More waves beat fewer, a quicker run beats a slower one on the same
+    /// count, and the table keeps only the best few.
+    #[test]
+    fn the_score_table_ranks_and_keeps_the_best_runs() {
+        let run = |waves: u32, time: f64| SurvivalScore {
+            waves,
+            time,
+            seed: 1,
+            difficulty: Difficulty::Normal,
+        };
+        let mut scores = SurvivalScores::default();
+        assert_eq!(scores.record("holdout", run(3, 200.0)), Some(0));
+        assert_eq!(scores.record("holdout", run(5, 400.0)), Some(0));
+        assert_eq!(scores.record("holdout", run(3, 150.0)), Some(1));
+        for _ in 0..3 {
+            scores.record("holdout", run(4, 300.0));
+        }
+        assert_eq!(scores.record("holdout", run(1, 10.0)), None);
+
+        let table = scores.table("holdout", Difficulty::Normal);
+        let waves: Vec<u32> = table.iter().map(|score| score.waves).collect();
+        assert_eq!(waves, vec![5, 4, 4, 4, 3]);
+        assert_eq!(table[4].time, 150.0);
+        assert!(scores.table("elsewhere", Difficulty::Normal).is_empty());
+    }
+
+    /// Each tier keeps its own table: a long Easy run takes first place
+    /// among Easy runs without pushing a Hard one off the board.
+    #[test]
+    fn each_tier_ranks_its_own_runs() {
+        let run = |waves: u32, difficulty: Difficulty| SurvivalScore {
+            waves,
+            time: 100.0,
+            seed: 1,
+            difficulty,
+        };
+        let mut scores = SurvivalScores::default();
+        for waves in 1..=SURVIVAL_SCORES_KEPT as u32 {
+            scores.record("holdout", run(waves, Difficulty::Hard));
+        }
+        assert_eq!(scores.record("holdout", run(8, Difficulty::Easy)), Some(0));
+        assert_eq!(scores.record("holdout", run(2, Difficulty::Easy)), Some(1));
+
+        let hard: Vec<u32> = scores
+            .table("holdout", Difficulty::Hard)
+            .iter()
+            .map(|score| score.waves)
+            .collect();
+        assert_eq!(hard, vec![5, 4, 3, 2, 1]);
+        assert_eq!(scores.table("holdout", Difficulty::Easy).len(), 2);
+        assert!(scores.table("holdout", Difficulty::Normal).is_empty());
+    }
+
+    /// A run that ends while the player's input is suspended - a replay
+    /// playing back, the fuzzer flying - files no score; the player's own
+    /// run does.
+    #[test]
+    fn a_suspended_run_files_no_score() {
+        let mut app = App::new();
+        app.add_plugins(SurvivalPlugin);
+        let mut world = NovaEventWorld::default();
+        world.insert_variable(
+            SURVIVAL_CLEARED_VAR.to_string(),
+            VariableLiteral::Number(3.0),
+        );
+        app.insert_resource(world);
+        app.insert_resource(CurrentScenario(Some(template(SurvivalConfig::default()))));
+        app.insert_resource(PlayerInputSuspended(true));
+        app.insert_resource(SurvivalRun {
+            seed: Some(7),
+            place: None,
+        });
+        app.insert_resource(CurrentOutcome(Some(OutcomeActionConfig::new(
+            ScenarioOutcomeKind::Defeat,
+            "Overrun.",
+        ))));
+        app.update();
+        assert!(app.world().resource::<SurvivalScores>().0.is_empty());
+        assert_eq!(app.world().resource::<SurvivalRun>().place, None);
+
+        app.insert_resource(PlayerInputSuspended(false));
+        app.world_mut()
+            .resource_mut::<CurrentOutcome>()
+            .set_changed();
+        app.update();
+        let scores = app.world().resource::<SurvivalScores>();
+        let table = scores.table("holdout", Difficulty::Normal);
+        assert_eq!(table.len(), 1);
+        assert_eq!((table[0].waves, table[0].seed), (3, 7));
+        assert_eq!(app.world().resource::<SurvivalRun>().place, Some(Some(0)));
+    }
+}
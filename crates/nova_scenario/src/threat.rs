@@ -0,0 +1,155 @@
+//! A ship's derived threat numbers: summed section health, burst dps, weapon
+//! reach.
+//!
+//! Two readers size danger from the same catalog data, so the numbers live
+//! here rather than in either of them. The balance audit (nova_authoring)
+//! grades authored spawns with them, and the survival generator
+//! ([`crate::survival`]) budgets its waves with them at load time. A change to
+//! [`ShipStats`] therefore moves both the audit's verdicts and the waves a
+//! seed builds, by design: a generated wave is held to the same spawn rules
+//! the audit grades authored ones on.
+//!
+//! The lookups are closures so each reader joins through its own catalog:
+//! the audit resolves through a bundle's dependency overlay, the runtime
+//! through the merged [`GameSections`] and [`GameShips`].
+
+use nova_ship::prelude::*;
+
+use crate::prelude::*;
+
+/// `ShipStats`, the ship rollup and the envelope constants.
+pub mod prelude {
+    pub use super::{ship_stats_with, ShipStats, EFFECTIVE_RANGE_MARGIN, TORPEDO_ENVELOPE};
+}
+
+/// The AI's own shot-worth-taking margin: effective range = margin x
+/// muzzle_speed x projectile_lifetime. Aliased to the engine constant rather
+/// than copied, so threat envelopes cannot drift from the gate the AI
+/// actually fires on.
+pub const EFFECTIVE_RANGE_MARGIN: f32 = AI_FIRE_RANGE_FACTOR;
+
+/// Mirrors AI_TORPEDO_MAX_RANGE (nova_ship/src/input/ai/torpedo.rs): the
+/// outer edge of the AI launch envelope, whose per-bay cooldown starts ELAPSED
+/// - a tube inside this range is a live opening threat.
+pub const TORPEDO_ENVELOPE: f32 = 1000.0;
+
+/// A ship's derived combat numbers, summed over its resolved sections.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShipStats {
+    /// Summed section health (per-section HP is the shipped damage model;
+    /// the sum is the ship's total pool as the HUD aggregates it).
+    pub hp: f32,
+    /// BURST turret dps: sum of fire_rate x bullet_damage - the
+    /// first-magazine rate (reloads make true sustained ~62% of this for
+    /// the catalog turrets, but shipped TTKs land inside one magazine).
+    /// Kinetic resistance is 1.0 everywhere in the shipped table, so
+    /// authored damage IS applied damage for every catalog turret.
+    pub dps: f32,
+    /// The longest effective range among the ship's turrets
+    /// ([`EFFECTIVE_RANGE_MARGIN`] x muzzle_speed x projectile_lifetime).
+    pub max_effective_range: f32,
+    /// Torpedo tubes are counted, not folded into dps: a tube's threat is
+    /// blast area + guidance, not sustained fire.
+    pub torpedo_tubes: usize,
+}
+
+impl ShipStats {
+    /// How far this ship threatens the moment it exists: its longest turret
+    /// reach, or the AI torpedo launch envelope if it carries tubes (the
+    /// bay's first-launch cooldown starts elapsed). Zero = unarmed.
+    pub fn threat_envelope(&self) -> f32 {
+        let tube_reach = if self.torpedo_tubes > 0 {
+            TORPEDO_ENVELOPE
+        } else {
+            0.0
+        };
+        self.max_effective_range.max(tube_reach)
+    }
+
+    /// Whether the ship carries anything that can hurt the player.
+    pub fn is_armed(&self) -> bool {
+        self.threat_envelope() > 0.0
+    }
+}
+
+/// Sum the fire rate of every muzzle in a turret's joint tree. Fire rate is
+/// per-muzzle since the joint-tree refactor; the shipped turrets each carry one
+/// muzzle, so for the catalog this is that one rate.
+fn turret_total_fire_rate(joint: &TurretJoint) -> f32 {
+    let here = joint.muzzle.as_ref().map(|m| m.fire_rate).unwrap_or(0.0);
+    here + joint
+        .children
+        .iter()
+        .map(turret_total_fire_rate)
+        .sum::<f32>()
+}
+
+/// Sum a ship's stats, resolving section and hull prototypes through the
+/// given lookups. An unknown ship or section prototype contributes nothing
+/// (content_lint already errors on them; the rollup stays total).
+pub fn ship_stats_with<'a>(
+    ship: &'a SpaceshipConfig,
+    section: impl Fn(&str) -> Option<&'a SectionConfig>,
+    hull: impl Fn(&str) -> Option<&'a ShipHull>,
+) -> ShipStats {
+    let mut stats = ShipStats {
+        hp: 0.0,
+        dps: 0.0,
+        max_effective_range: 0.0,
+        torpedo_tubes: 0,
+    };
+    let resolved_hull = match &ship.hull {
+        ShipSource::Inline(hull) => Some(hull),
+        ShipSource::Prototype(id) => hull(id),
+    };
+    let Some(resolved_hull) = resolved_hull else {
+        return stats;
+    };
+    for hull_section in &resolved_hull.sections {
+        let resolved: Option<&SectionConfig> = match &hull_section.source {
+            SectionSource::Prototype(id) => section(id),
+            SectionSource::Inline(config) => Some(config),
+        };
+        let Some(config) = resolved else { continue };
+        // An authored SetHealth override wins over the prototype (last one
+        // wins, like the runtime observers applying the list in order), and a
+        // SPAWN override wins over the hull's own for the same reason.
+        let hp_override = ship
+            .modifications
+            .iter()
+            .filter(|m| m.section == hull_section.id)
+            .flat_map(|m| m.modifications.iter())
+            .chain(hull_section.modifications.iter())
+            .rev()
+            .find_map(|m| match m {
+                SectionModification::SetHealth(hp) => Some(*hp),
+                _ => None,
+            });
+        stats.hp += hp_override.unwrap_or(config.base.health);
+        match &config.kind {
+            SectionKind::Turret(turret) => {
+                // Fire rate is per-muzzle now; burst DPS sums every muzzle in
+                // the joint tree. The shipped turrets each carry one muzzle, so
+                // this is unchanged for the catalog.
+                stats.dps += turret_total_fire_rate(&turret.root) * turret.bullet_damage;
+                stats.max_effective_range = stats
+                    .max_effective_range
+                    .max(EFFECTIVE_RANGE_MARGIN * turret.muzzle_speed * turret.projectile_lifetime);
+            }
+            SectionKind::Torpedo(_) => stats.torpedo_tubes += 1,
+            _ => {}
+        }
+    }
+    stats
+}
+
+impl GameShips {
+    /// [`ship_stats_with`] resolved through the merged runtime catalogs.
+    pub fn stats(&self, ship: &SpaceshipConfig, sections: &GameSections) -> ShipStats {
+        ship_stats_with(
+            ship,
+            |id| sections.get_section(id),
+            |id| self.get_ship(id).map(|config| &config.hull),
+        )
+    }
+}
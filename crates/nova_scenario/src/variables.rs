@@ -92,7 +92,6 @@ impl VariableFactorNode {
                 .ok_or_else(|| VariableError::UndefinedVariable(name.clone())),
             VariableFactorNode::Query(query) => world
                 .query_value(query)
-                .cloned()
                 .ok_or_else(|| VariableError::UnavailableQuery(query.clone())),
         }
     }
@@ -14,7 +14,7 @@ pub mod prelude {
 }
 
 use core::time::Duration;
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 
 // NOTE: bevy's platform Instant, not std's - `std::time::Instant::now` panics
 // on wasm32-unknown-unknown, which this crate ships to.
@@ -75,6 +75,9 @@ pub fn scenario_has_settled(
 pub struct NovaEventWorld {
     queued_commands: VecDeque<Box<dyn FnOnce(&mut Commands) + Send + Sync>>,
     objectives: Vec<ObjectiveActionConfig>,
+    /// Every objective completed this scenario, with the scenario time it was
+    /// completed at, in completion order. Read by the debrief.
+    completed_objectives: Vec<(String, f64)>,
     /// The scenario's story-message log, in delivery order. Append-only within
     /// a scenario; cleared at teardown with the rest of the event world.
     story_messages: Vec<StoryMessageActionConfig>,
@@ -91,6 +94,16 @@ pub struct NovaEventWorld {
     /// `ScenarioConfig::reads_an_entity_query` at load; false at teardown.
     reads_entity_queries: bool,
     query_values: HashMap<QueryConfig, VariableLiteral>,
+    /// The cargo stacks of every uniquely identified entity that has a hold,
+    /// sampled with the speeds. Kept whole rather than flattened into
+    /// `query_values` so an `ItemCount` of an item the hold lacks reads 0.
+    entity_cargo: HashMap<String, BTreeMap<String, u32>>,
+    /// Ore units the player has scooped up this scenario, read by the
+    /// `OreMined` scenario query.
+    ore_mined: u32,
+    /// The difficulty this scenario was started at, read by the `Difficulty`
+    /// scenario query. Stamped by the loader before `OnStart` fires.
+    difficulty: Difficulty,
     scenario_elapsed: f64,
     /// Keyed timer deadlines on the pause-frozen scenario clock.
     timers: HashMap<String, f64>,
@@ -351,6 +364,7 @@ impl NovaEventWorld {
         }
         self.queued_commands.clear();
         self.objectives.clear();
+        self.completed_objectives.clear();
         self.story_messages.clear();
         self.hud_readouts.clear();
         self.variables.clear();
@@ -358,6 +372,9 @@ impl NovaEventWorld {
         self.watches.clear();
         self.reads_entity_queries = false;
         self.query_values.clear();
+        self.entity_cargo.clear();
+        self.ore_mined = 0;
+        self.difficulty = Difficulty::default();
         self.scenario_elapsed = 0.0;
         self.timers.clear();
         self.scatter_placements.clear();
@@ -442,9 +459,17 @@ impl NovaEventWorld {
             );
         } else {
             debug!("remove_objective: completed objective '{}'", id);
+            self.completed_objectives
+                .push((id.to_string(), self.scenario_elapsed));
         }
     }
 
+    /// Every objective completed this scenario and the scenario time it was
+    /// completed at, in completion order.
+    pub fn completed_objectives(&self) -> &[(String, f64)] {
+        &self.completed_objectives
+    }
+
     /// Release a lingering `NextScenario` request so the switch fires on the
     /// next state sync. Returns false when nothing is queued. The one
     /// mechanism behind both the scenario-advance input (Enter/DPadDown) and
@@ -547,13 +572,16 @@ impl NovaEventWorld {
     }
 
     fn publish_watches(&mut self) {
+        let published: Vec<_> = self
+            .watches
+            .iter()
+            .filter_map(|watch| {
+                self.query_value(&watch.query)
+                    .map(|value| (watch.variable.clone(), value))
+            })
+            .collect();
         self.watched_values.clear();
-        for watch in &self.watches {
-            if let Some(value) = self.query_values.get(&watch.query) {
-                self.watched_values
-                    .insert(watch.variable.clone(), value.clone());
-            }
-        }
+        self.watched_values.extend(published);
     }
 
     /// Current pause-frozen scenario time.
@@ -573,6 +601,34 @@ impl NovaEventWorld {
         self.publish_watches();
     }
 
+    /// Count `count` more ore units into the scenario's mined total and
+    /// republish the watches, so a watch on `OreMined` moves the moment the
+    /// ore lands in the hold rather than on the next sample.
+    pub(crate) fn record_mined(&mut self, count: u32) {
+        self.ore_mined = self.ore_mined.saturating_add(count);
+        self.publish_watches();
+    }
+
+    /// Stamp the difficulty the scenario is being started at. The loader
+    /// calls it after teardown and before `OnStart`, so the start handlers
+    /// already read the chosen tier.
+    pub(crate) fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.difficulty = difficulty;
+        self.publish_watches();
+    }
+
+    /// The difficulty the scenario was started at.
+    pub(crate) fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Replace the sampled entity cargo. Publishes nothing on its own: call it
+    /// ahead of [`sample_entity_speeds`](Self::sample_entity_speeds), which
+    /// publishes the watches over both.
+    pub(crate) fn sample_entity_cargo(&mut self, cargo: HashMap<String, BTreeMap<String, u32>>) {
+        self.entity_cargo = cargo;
+    }
+
     /// Replace sampled entity query values, then publish all watches.
     pub(crate) fn sample_entity_speeds(&mut self, speeds: HashMap<String, Option<f64>>) {
         self.query_values
@@ -592,8 +648,22 @@ impl NovaEventWorld {
     }
 
     /// Read a typed query from the current coherent world snapshot.
-    pub fn query_value(&self, query: &QueryConfig) -> Option<&VariableLiteral> {
-        self.query_values.get(query)
+    pub fn query_value(&self, query: &QueryConfig) -> Option<VariableLiteral> {
+        match query {
+            QueryConfig::Entity(EntityQuery {
+                filter,
+                property: EntityProperty::ItemCount(item),
+            }) => self.entity_cargo.get(&filter.id).map(|stacks| {
+                VariableLiteral::Number(stacks.get(item).copied().unwrap_or(0) as f64)
+            }),
+            QueryConfig::Scenario(ScenarioQuery {
+                property: ScenarioProperty::OreMined,
+            }) => Some(VariableLiteral::Number(f64::from(self.ore_mined))),
+            QueryConfig::Scenario(ScenarioQuery {
+                property: ScenarioProperty::Difficulty,
+            }) => Some(VariableLiteral::String(self.difficulty.label().to_string())),
+            _ => self.query_values.get(query).cloned(),
+        }
     }
 
     /// Read-only iteration over all scenario variables (unordered). For
@@ -732,6 +802,104 @@ mod tests {
         );
     }
 
+    /// An `ItemCount` reads the sampled hold: an item it lacks is 0, and an
+    /// entity with no sampled hold has no value at all.
+    #[test]
+    fn item_count_reads_the_sampled_hold() {
+        let item_count = |id: &str, item: &str| {
+            QueryConfig::Entity(EntityQuery {
+                filter: EntityQueryFilter { id: id.to_string() },
+                property: EntityProperty::ItemCount(item.to_string()),
+            })
+        };
+        let mut world = NovaEventWorld::default();
+        world.set_watches(
+            vec![WatchConfig {
+                variable: "ore".to_string(),
+                query: item_count("hauler", "ore"),
+            }],
+            true,
+        );
+        world.sample_entity_cargo(HashMap::from_iter([(
+            "hauler".to_string(),
+            BTreeMap::from([("ore".to_string(), 3)]),
+        )]));
+        world.sample_entity_speeds(HashMap::new());
+
+        assert_eq!(
+            world.get_variable("ore"),
+            Some(&VariableLiteral::Number(3.0))
+        );
+        assert_eq!(
+            world.query_value(&item_count("hauler", "ice")),
+            Some(VariableLiteral::Number(0.0))
+        );
+        assert_eq!(world.query_value(&item_count("drone", "ore")), None);
+    }
+
+    /// `OreMined` counts up as ore is recorded, publishes to its watch at
+    /// once, and resets with the scenario.
+    #[test]
+    fn ore_mined_totals_what_was_recorded() {
+        let ore_mined = QueryConfig::Scenario(ScenarioQuery {
+            property: ScenarioProperty::OreMined,
+        });
+        let mut world = NovaEventWorld::default();
+        world.set_watches(
+            vec![WatchConfig {
+                variable: "mined".to_string(),
+                query: ore_mined.clone(),
+            }],
+            false,
+        );
+        assert_eq!(
+            world.get_variable("mined"),
+            Some(&VariableLiteral::Number(0.0))
+        );
+
+        world.record_mined(3);
+        world.record_mined(2);
+        assert_eq!(
+            world.get_variable("mined"),
+            Some(&VariableLiteral::Number(5.0))
+        );
+
+        world.clear();
+        assert_eq!(
+            world.query_value(&ore_mined),
+            Some(VariableLiteral::Number(0.0))
+        );
+    }
+
+    /// `Difficulty` reads the stamped tier's label, reaches its watch at
+    /// once, and falls back to `Normal` with the scenario.
+    #[test]
+    fn the_difficulty_query_reads_the_stamped_tier() {
+        let difficulty = QueryConfig::Scenario(ScenarioQuery {
+            property: ScenarioProperty::Difficulty,
+        });
+        let mut world = NovaEventWorld::default();
+        world.set_watches(
+            vec![WatchConfig {
+                variable: "tier".to_string(),
+                query: difficulty.clone(),
+            }],
+            false,
+        );
+
+        world.set_difficulty(Difficulty::Hard);
+        assert_eq!(
+            world.get_variable("tier"),
+            Some(&VariableLiteral::String("Hard".to_string()))
+        );
+
+        world.clear();
+        assert_eq!(
+            world.query_value(&difficulty),
+            Some(VariableLiteral::String("Normal".to_string()))
+        );
+    }
+
     /// The delayed non-lingering cut: the switch holds for the authored delay
     /// while the world keeps running, then fires. The fail-first is the first
     /// assert - today's instant cut would have switched on the first update.
@@ -763,7 +931,10 @@ mod tests {
                 thumbnail: None,
                 hidden: true,
                 menu_backdrop: false,
+                survival: None,
+                post: None,
                 watches: vec![],
+                invariants: vec![],
                 events: vec![],
             },
         );
@@ -1,7 +1,7 @@
 //! Who owns the camera `Transform` this frame, and in what order.
 //!
-//! The camera Transform has four independent writers - chase sync, WASD
-//! sync, camera shake (`Restore`/`Apply`) and
+//! The camera Transform has five independent writers - chase sync, cockpit
+//! sync, WASD sync, camera shake (`Restore`/`Apply`) and
 //! `enforce_scripted_camera_pose` - and until this module existed the lattice
 //! between them was PARTIAL. The missing edges were filled in by executor
 //! readiness, i.e. a per-frame coin flip, which is what made the scripted pose
@@ -21,6 +21,7 @@ use nova_gameplay::shake::{CameraShakeSuppressed, CameraShakeSystems};
 
 use super::{
     chase::ChaseCameraSystems,
+    cockpit::CockpitCameraSystems,
     wasd::{WASDCamera, WASDCameraSystems},
 };
 
@@ -32,9 +33,11 @@ use super::{
 /// by naming a phase and nothing else.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CameraAuthoritySystems {
-    /// Solve the base pose from game state: chase sync and WASD sync.
+    /// Solve the base pose from game state: chase sync, then the cockpit seat
+    /// over it while the cockpit view holds, and WASD sync.
     Solve,
-    /// Add an offset on top of the solved pose: camera shake `Apply`.
+    /// Add an offset on top of the solved pose: camera shake `Apply`, and the
+    /// pause menu's photo-mode roll.
     Additive,
     /// Overwrite the pose with a scripted one (photo mode, the capture
     /// scripts). Runs LAST, so the script wins the frame and a posed shot is
@@ -82,6 +85,11 @@ impl Plugin for CameraAuthorityPlugin {
             (
                 (ChaseCameraSystems::Sync, WASDCameraSystems::Sync)
                     .in_set(CameraAuthoritySystems::Solve),
+                // The seat overwrites the chase pose inside the phase, so the
+                // chase keeps tracking underneath and the swap back is a cut.
+                CockpitCameraSystems::Sync
+                    .in_set(CameraAuthoritySystems::Solve)
+                    .after(ChaseCameraSystems::Sync),
                 CameraShakeSystems::Apply.in_set(CameraAuthoritySystems::Additive),
             ),
         );
@@ -92,7 +100,8 @@ impl Plugin for CameraAuthorityPlugin {
 }
 
 /// Free-fly is not a player camera, so combat trauma must never jitter it: the
-/// WASD rig is a detached observer's tripod, not a cockpit. The gate is keyed
+/// WASD rig is a detached observer's tripod, not a seat in the ship (the
+/// cockpit view, a player camera, keeps its shake). The gate is keyed
 /// on the RIG component itself - present exactly while WASD drives - because
 /// the camera ENTITY persists across the WASD<->chase handoffs (its
 /// `CameraShake` rides along), so component insertion alone cannot scope the
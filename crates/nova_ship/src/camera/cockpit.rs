@@ -0,0 +1,141 @@
+//! The cockpit seat: a first-person pose on the player's flight computer.
+//!
+//! While [`SpaceshipCameraView::Cockpit`] holds, [`sync_cockpit_camera`] puts
+//! the player camera at [`COCKPIT_EYE_OFFSET`] from the seat section - the
+//! first working controller section on the player root - and points it down
+//! the hull's nose, or down the live look ray while free-look or turret aim
+//! turns the pilot's head. The chase rig keeps solving underneath and is
+//! simply overwritten (the authority rule: order, don't disable), so the swap
+//! back is a cut onto a camera that never stopped tracking.
+//!
+//! The seat is on the SECTION, not the root: the root origin is where the
+//! first sections were built, and a computer mounted anywhere else must see
+//! from where it is. Losing the seat (the computer destroyed, or knocked
+//! inactive) drops the view back to chase through [`super::mode`], and the
+//! screen never shows a frame from an empty chair.
+
+use bevy::prelude::*;
+use nova_gameplay::prelude::*;
+
+use super::{
+    mode::{SpaceshipCameraControlMode, SpaceshipCameraView},
+    rig::{
+        SpaceshipCameraController, SpaceshipCameraInputMarker, SpaceshipRotationInputActiveMarker,
+    },
+};
+
+/// The pilot's eye, from the seat section's centre in the ROOT frame: up
+/// through the roof of a 1 u section into the canopy bubble over it, so a
+/// hull section built ahead of the computer does not fill the windscreen.
+pub const COCKPIT_EYE_OFFSET: Vec3 = Vec3::new(0.0, 0.6, 0.0);
+
+/// The cockpit rig's place in the camera authority chain.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CockpitCameraSystems {
+    /// Writes the camera `Transform` from the seat, after the chase rig.
+    Sync,
+}
+
+/// The player's seat: the first WORKING controller section under `root`, as
+/// its root-local `Transform`, from a query over live controllers (filtered
+/// `Without<SectionInactiveMarker>`). `None` once every computer is destroyed
+/// (despawned) or knocked out.
+pub fn cockpit_seat<'a>(
+    root: Entity,
+    controllers: impl IntoIterator<Item = (&'a ChildOf, &'a Transform)>,
+) -> Option<Transform> {
+    controllers
+        .into_iter()
+        .find(|(child_of, _)| child_of.parent() == root)
+        .map(|(_, transform)| *transform)
+}
+
+/// The world-space eye for a seat on a root at `root`.
+pub fn cockpit_eye(root: &Transform, seat: &Transform) -> Vec3 {
+    root.transform_point(seat.translation + COCKPIT_EYE_OFFSET)
+}
+
+/// Seat the player camera for the frame. Root-local `Transform`s rather than
+/// `GlobalTransform`s: this runs before propagation, where a global is last
+/// frame's and would trail the hull by one frame at speed.
+pub(super) fn sync_cockpit_camera(
+    view: Res<SpaceshipCameraView>,
+    mode: Res<SpaceshipCameraControlMode>,
+    mut q_camera: Query<&mut Transform, With<SpaceshipCameraController>>,
+    q_ship: Query<
+        (Entity, &Transform),
+        (
+            With<SpaceshipRootMarker>,
+            With<PlayerSpaceshipMarker>,
+            Without<SpaceshipCameraController>,
+        ),
+    >,
+    q_controller: Query<
+        (&ChildOf, &Transform),
+        (
+            With<ControllerSectionMarker>,
+            Without<SectionInactiveMarker>,
+            Without<SpaceshipCameraController>,
+        ),
+    >,
+    q_look: Query<
+        &PointRotationOutput,
+        (
+            With<SpaceshipCameraInputMarker>,
+            With<SpaceshipRotationInputActiveMarker>,
+        ),
+    >,
+) {
+    if *view != SpaceshipCameraView::Cockpit {
+        return;
+    }
+    let Ok(mut camera) = q_camera.single_mut() else {
+        return;
+    };
+    let Ok((root, root_transform)) = q_ship.single() else {
+        return;
+    };
+    let Some(seat) = cockpit_seat(root, &q_controller) else {
+        return;
+    };
+
+    // Normal flight looks down the nose: the NORMAL rig's output is where the
+    // ship is being STEERED, and the hull lags it by the controller's steering
+    // lag - bolting the view to the command would make the cockpit swing ahead
+    // of its own frame. Free-look and turret aim are the pilot's head turning,
+    // so they look down the live ray.
+    let rotation = match *mode {
+        SpaceshipCameraControlMode::Normal => root_transform.rotation,
+        SpaceshipCameraControlMode::FreeLook | SpaceshipCameraControlMode::Turret => q_look
+            .iter()
+            .next()
+            .map_or(root_transform.rotation, |output| **output),
+    };
+    camera.translation = cockpit_eye(root_transform, &seat);
+    camera.rotation = rotation;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The eye rides the SEAT section, in the hull's frame: a computer built
+    /// two units aft of the root sees from two units aft, and a banked hull
+    /// carries the canopy offset round with it.
+    #[test]
+    fn the_eye_rides_the_seat_section_in_the_hull_frame() {
+        let seat = Transform::from_xyz(0.0, 0.0, 2.0);
+        let level = Transform::from_xyz(10.0, 0.0, 0.0);
+        assert_eq!(
+            cockpit_eye(&level, &seat),
+            Vec3::new(10.0, COCKPIT_EYE_OFFSET.y, 2.0)
+        );
+
+        let banked = Transform::from_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        let eye = cockpit_eye(&banked, &Transform::IDENTITY);
+        assert!(
+            eye.distance(Vec3::new(-COCKPIT_EYE_OFFSET.y, 0.0, 0.0)) < 1e-5,
+            "rolled 90 degrees, the canopy is off the hull's side: {eye}"
+        );
+    }
+}
@@ -1,6 +1,11 @@
 //! The autopilot handback: when a maneuver disengages the mouse rig is
 //! re-seeded from the hull instantly (the ship's no-lurch contract), and the
 //! camera eases across that discontinuity instead of teleporting.
+//!
+//! The same ease is exported for whole poses ([`handback_ease`],
+//! [`blend_camera_pose`]), so a scripted camera that takes the screen - a
+//! scenario camera track - eases off the chase rig and back onto it with the
+//! curve the player already knows from a disengage.
 
 use avian3d::prelude::Rotation;
 use bevy::prelude::*;
@@ -33,9 +38,28 @@ pub struct CameraHandbackBlend {
 /// smoothstep-eased slerp from the held direction to the live rig. Pure
 /// for unit testing.
 pub(super) fn handback_anchor_rot(from: Quat, to: Quat, elapsed: f32) -> Quat {
-    let t = (elapsed / HANDBACK_BLEND_SECONDS.max(1e-3)).clamp(0.0, 1.0);
-    let eased = t * t * (3.0 - 2.0 * t);
-    from.slerp(to, eased)
+    from.slerp(to, handback_ease(elapsed, HANDBACK_BLEND_SECONDS))
+}
+
+/// The handback curve: smoothstep progress `elapsed` seconds into a blend of
+/// `seconds`, 0 at the start and 1 from the end on. A zero-length blend is a
+/// cut (1 at once).
+pub fn handback_ease(elapsed: f32, seconds: f32) -> f32 {
+    if seconds <= 0.0 {
+        return 1.0;
+    }
+    let t = (elapsed / seconds).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
+
+/// A camera pose `weight` of the way from `from` to `to`: position lerped,
+/// rotation slerped. Pair with [`handback_ease`] for the eased weight.
+pub fn blend_camera_pose(from: &Transform, to: &Transform, weight: f32) -> Transform {
+    Transform {
+        translation: from.translation.lerp(to.translation, weight),
+        rotation: from.rotation.slerp(to.rotation, weight),
+        scale: to.scale,
+    }
 }
 
 /// When an autopilot maneuver disengages, re-seed the normal rotation rig
@@ -132,6 +156,24 @@ mod tests {
         }
     }
 
+    /// The exported pose blend holds `from` at zero weight and lands on `to`
+    /// at one; a zero-length blend is a cut.
+    #[test]
+    fn pose_blend_runs_the_handback_curve_between_two_poses() {
+        let from = Transform::from_xyz(0.0, 0.0, 10.0);
+        let to = Transform::from_xyz(10.0, 0.0, 0.0).looking_at(Vec3::ZERO, Vec3::Y);
+
+        let start = blend_camera_pose(&from, &to, handback_ease(0.0, 2.0));
+        assert_eq!(start.translation, from.translation);
+        let end = blend_camera_pose(&from, &to, handback_ease(2.0, 2.0));
+        assert!(end.translation.distance(to.translation) < 1e-5);
+        assert!(end.rotation.angle_between(to.rotation) < 2e-3);
+
+        // Smoothstep is symmetric: half the time is half the way.
+        assert!((handback_ease(1.0, 2.0) - 0.5).abs() < 1e-6);
+        assert_eq!(handback_ease(0.0, 0.0), 1.0);
+    }
+
     /// The autopilot handback keeps the camera continuous: at the
     /// disengage frame the anchor still points where the camera was
     /// looking (NOT the hull attitude the rig was re-seeded to), and the
@@ -2,14 +2,17 @@
 //! mode ([`SpaceshipCameraControlMode`]: normal / free-look / turret) and the
 //! weapons-raised stance ([`WeaponsRaised`]) each frame from held inputs, and
 //! exposes the live look ray ([`ActiveLookRay`]) that targeting and the radar
-//! read to know where the player is aiming right now.
+//! read to know where the player is aiming right now. The chase/cockpit seat
+//! ([`SpaceshipCameraView`]) is a toggle on top of the mode; the cockpit pose
+//! sits on the flight computer and is ordered over the chase one.
 //!
 //! Touch this module for camera framing and look-input routing. Gameplay
 //! consumers should read [`WeaponsRaised`] / [`ActiveLookRay`], never the raw
 //! camera enum.
 //!
-//! Nova owns the rigs the controller is built on, too: [`chase`], [`skybox`],
-//! [`post`], [`wasd`] and [`wasd_controller`]. The trauma shake rig they compose
+//! Nova owns the rigs the controller is built on, too: [`chase`], [`skybox`]
+//! (with its seeded generator, [`procedural_sky`]), [`post`], [`wasd`] and
+//! [`wasd_controller`]. The trauma shake rig they compose
 //! with is [`nova_gameplay::shake`] - it is fed by combat juice, not by the camera, so
 //! it sits outside this module, and the private `authority` submodule is what orders it against
 //! these. They used to live in an engine crate, which made the `authority`
@@ -20,10 +23,12 @@ use bevy_enhanced_input::prelude::*;
 
 mod authority;
 pub mod chase;
+mod cockpit;
 mod framing;
 mod handback;
 mod mode;
 pub mod post;
+pub mod procedural_sky;
 mod rig;
 pub mod skybox;
 pub mod wasd;
@@ -31,8 +36,9 @@ pub mod wasd_controller;
 
 pub use self::{
     authority::{CameraAuthorityPlugin, CameraAuthoritySystems},
-    handback::CameraHandbackBlend,
-    mode::{SpaceshipCameraControlMode, WeaponsRaised},
+    cockpit::{cockpit_eye, cockpit_seat, CockpitCameraSystems, COCKPIT_EYE_OFFSET},
+    handback::{blend_camera_pose, handback_ease, CameraHandbackBlend},
+    mode::{SpaceshipCameraControlMode, SpaceshipCameraView, WeaponsRaised},
     rig::{
         ActiveLookRay, SpaceshipCameraController, SpaceshipCameraFreeLookInputMarker,
         SpaceshipCameraInputMarker, SpaceshipCameraNormalInputMarker,
@@ -40,15 +46,17 @@ pub use self::{
     },
 };
 use self::{
+    cockpit::sync_cockpit_camera,
     framing::{update_camera_rig, update_chase_camera_input},
     handback::on_autopilot_disengaged,
     mode::{
-        derive_control_mode_and_raised, on_rotation_input, on_rotation_input_completed,
-        sync_spaceship_control_mode,
+        derive_control_mode_and_raised, drop_cockpit_view_without_seat, on_cockpit_view_toggle,
+        on_rotation_input, on_rotation_input_completed, sync_spaceship_control_mode,
     },
     rig::{
         destroy_camera_controller, insert_camera_controller, insert_camera_freelook,
-        insert_camera_turret, insert_player_input, PlayerInputMarker,
+        insert_camera_turret, insert_player_input, respawn_player_input_on_keymap_change,
+        PlayerInputMarker,
     },
 };
 
@@ -56,12 +64,14 @@ use self::{
 /// public API of this module and of the six rigs it is built on.
 pub mod prelude {
     pub use super::{
-        chase::prelude::*, post::prelude::*, skybox::prelude::*, wasd::prelude::*,
+        blend_camera_pose, chase::prelude::*, cockpit_eye, cockpit_seat, handback_ease,
+        post::prelude::*, procedural_sky::prelude::*, skybox::prelude::*, wasd::prelude::*,
         wasd_controller::prelude::*, ActiveLookRay, CameraAuthorityPlugin, CameraAuthoritySystems,
-        NovaCameraSystems, SpaceshipCameraControlMode, SpaceshipCameraController,
-        SpaceshipCameraControllerPlugin, SpaceshipCameraFreeLookInputMarker,
-        SpaceshipCameraInputMarker, SpaceshipCameraNormalInputMarker,
-        SpaceshipCameraTurretInputMarker, SpaceshipRotationInputActiveMarker, WeaponsRaised,
+        CockpitCameraSystems, NovaCameraSystems, SpaceshipCameraControlMode,
+        SpaceshipCameraController, SpaceshipCameraControllerPlugin,
+        SpaceshipCameraFreeLookInputMarker, SpaceshipCameraInputMarker,
+        SpaceshipCameraNormalInputMarker, SpaceshipCameraTurretInputMarker, SpaceshipCameraView,
+        SpaceshipRotationInputActiveMarker, WeaponsRaised, COCKPIT_EYE_OFFSET,
     };
 }
 
@@ -79,6 +89,10 @@ impl Plugin for SpaceshipCameraControllerPlugin {
         trace!("SpaceshipCameraControllerPlugin: build");
 
         app.init_resource::<SpaceshipCameraControlMode>();
+        app.init_resource::<SpaceshipCameraView>();
+        // NOTE: owned by SpaceshipInputPlugin; repeated so the camera plugin
+        // stands alone in rigs that skip the input plugin.
+        app.init_resource::<crate::input::keymap::Keymap>();
         app.add_input_context::<PlayerInputMarker>();
 
         app.add_observer(insert_camera_controller);
@@ -91,8 +105,10 @@ impl Plugin for SpaceshipCameraControllerPlugin {
 
         app.add_observer(on_rotation_input);
         app.add_observer(on_rotation_input_completed);
+        app.add_observer(on_cockpit_view_toggle);
 
         app.register_type::<WeaponsRaised>();
+        app.register_type::<SpaceshipCameraView>();
 
         app.add_systems(
             Update,
@@ -113,12 +129,23 @@ impl Plugin for SpaceshipCameraControllerPlugin {
                 update_chase_camera_input,
                 sync_spaceship_control_mode,
                 update_camera_rig,
+                drop_cockpit_view_without_seat,
             )
                 .chain()
                 .in_set(NovaCameraSystems),
         );
+        app.add_systems(
+            PostUpdate,
+            sync_cockpit_camera.in_set(CockpitCameraSystems::Sync),
+        );
+        app.add_systems(
+            Update,
+            respawn_player_input_on_keymap_change
+                .run_if(resource_changed::<crate::input::keymap::Keymap>)
+                .before(NovaCameraSystems),
+        );
 
-        // Every camera-Transform writer in the app - nova's three rigs and
+        // Every camera-Transform writer in the app - nova's four rigs and
         // nova's scripted pose - is ordered by this one chain. Guarded because
         // nova_scenario adds it too when it is the only camera consumer, and
         // plugin add order between the two crates is the app's business.
@@ -1,15 +1,21 @@
 //! Camera mode and stance: derive [`SpaceshipCameraControlMode`] and
 //! [`WeaponsRaised`] from the held inputs each frame, move the active-rig
-//! marker to match, and route the look input onto whichever rig is live.
+//! marker to match, and route the look input onto whichever rig is live. The
+//! chase/cockpit [`SpaceshipCameraView`] toggle lives here too, and falls back
+//! to chase the frame the seat is lost.
 
 use bevy::prelude::*;
 use bevy_enhanced_input::prelude::*;
 use nova_gameplay::{prelude::*, transform::prelude::PointRotationInput};
 
-use super::rig::{
-    CameraInputRotate, CombatInput, FreeLookInput, SpaceshipCameraFreeLookInputMarker,
-    SpaceshipCameraInputMarker, SpaceshipCameraNormalInputMarker, SpaceshipCameraTurretInputMarker,
-    SpaceshipRotationInputActiveMarker,
+use super::{
+    cockpit::cockpit_seat,
+    rig::{
+        CameraInputRotate, CockpitViewInput, CombatInput, FreeLookInput,
+        SpaceshipCameraFreeLookInputMarker, SpaceshipCameraInputMarker,
+        SpaceshipCameraNormalInputMarker, SpaceshipCameraTurretInputMarker,
+        SpaceshipRotationInputActiveMarker,
+    },
 };
 use crate::prelude::*;
 
@@ -33,6 +39,23 @@ pub enum SpaceshipCameraControlMode {
     Turret,
 }
 
+/// Where the player camera sits: behind the ship on the chase rig, or in the
+/// pilot's seat on the flight computer (see [`super::cockpit`]).
+///
+/// Orthogonal to [`SpaceshipCameraControlMode`]: free-look and turret aim work
+/// from either seat, and the mode derivation never touches the view. A toggle
+/// rather than a hold; losing the ship loses the seat, so a respawn starts
+/// back in chase.
+#[derive(Resource, Default, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub enum SpaceshipCameraView {
+    /// Third-person chase framing.
+    #[default]
+    Chase,
+    /// First-person from the controller section, with the in-cockpit screens.
+    Cockpit,
+}
+
 /// Weapons-raised: the gameplay-facing flag for "the player is holding the
 /// combat stance" (RMB/CombatInput held), derived each frame onto the PLAYER
 /// ship root alongside the camera mode. Gameplay consumers (the radar slot
@@ -177,6 +200,72 @@ pub(super) fn on_rotation_input_completed(
     }
 }
 
+/// Flip between the chase and cockpit views. Entering the cockpit needs a
+/// working controller on the player ship to sit at; leaving it never does.
+pub(super) fn on_cockpit_view_toggle(
+    _: On<Start<CockpitViewInput>>,
+    mut view: ResMut<SpaceshipCameraView>,
+    q_ship: Query<Entity, (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>)>,
+    q_controller: Query<
+        (&ChildOf, &Transform),
+        (
+            With<ControllerSectionMarker>,
+            Without<SectionInactiveMarker>,
+        ),
+    >,
+    pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
+) {
+    // Observers bypass system-set gating; a press behind the pause overlay or
+    // during a replay is not the pilot's.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
+        return;
+    }
+
+    match *view {
+        SpaceshipCameraView::Chase => {
+            let seated = q_ship
+                .single()
+                .is_ok_and(|root| cockpit_seat(root, &q_controller).is_some());
+            if seated {
+                *view = SpaceshipCameraView::Cockpit;
+            } else {
+                debug!("on_cockpit_view_toggle: no working controller to sit at");
+            }
+        }
+        SpaceshipCameraView::Cockpit => *view = SpaceshipCameraView::Chase,
+    }
+}
+
+/// Drop the cockpit view back to chase once the seat is gone: the last
+/// computer destroyed or knocked out, or the ship itself despawned. The chase
+/// rig never stopped tracking under the cockpit pose, so the fall-back is a
+/// clean cut to a settled camera rather than a frame from an empty seat.
+/// Runs paused too - a ship lost on the frame the menu opened must not leave
+/// the pause menu framed from inside the wreck.
+pub(super) fn drop_cockpit_view_without_seat(
+    mut view: ResMut<SpaceshipCameraView>,
+    q_ship: Query<Entity, (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>)>,
+    q_controller: Query<
+        (&ChildOf, &Transform),
+        (
+            With<ControllerSectionMarker>,
+            Without<SectionInactiveMarker>,
+        ),
+    >,
+) {
+    if *view != SpaceshipCameraView::Cockpit {
+        return;
+    }
+    let seated = q_ship
+        .single()
+        .is_ok_and(|root| cockpit_seat(root, &q_controller).is_some());
+    if !seated {
+        debug!("drop_cockpit_view_without_seat: controller lost, back to chase");
+        *view = SpaceshipCameraView::Chase;
+    }
+}
+
 /// Whether a held bool action currently fires, read from its action entity's
 /// state (the `cycle_modifier_held` pattern - a plain Down-conditioned action
 /// reports `Fired` while its key is held).
@@ -202,6 +291,7 @@ pub(super) fn derive_control_mode_and_raised(
         (Entity, Option<&mut WeaponsRaised>),
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
     >,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     let combat_held = action_held(&q_combat);
     let next = if combat_held {
@@ -215,7 +305,11 @@ pub(super) fn derive_control_mode_and_raised(
 
     // The raised flag mirrors the combat hold onto the player ship root
     // (self-healing insert: a fresh ship starts lowered and gains the flag on
-    // its first frame).
+    // its first frame). A replay flying the ship writes the stance it
+    // recorded, so the spectator's held keys only move the camera.
+    if suspended.is_some_and(|suspended| suspended.0) {
+        return;
+    }
     for (ship, raised) in &mut q_ship {
         match raised {
             Some(mut raised) => {
@@ -657,4 +751,56 @@ mod tests {
             "RCS holds the rig rate at zero so the view does not drift"
         );
     }
+
+    /// The cockpit holds while a working computer is aboard, and drops to
+    /// chase the frame the last one is knocked out - inactive first, then
+    /// destroyed outright - rather than framing the wreck from an empty seat.
+    #[test]
+    fn losing_the_controller_drops_the_cockpit_view() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SpaceshipCameraView::Cockpit);
+        app.add_systems(Update, drop_cockpit_view_without_seat);
+
+        let ship = app
+            .world_mut()
+            .spawn((
+                SpaceshipRootMarker,
+                PlayerSpaceshipMarker,
+                Transform::IDENTITY,
+            ))
+            .id();
+        let controller = app
+            .world_mut()
+            .spawn((ControllerSectionMarker, Transform::IDENTITY, ChildOf(ship)))
+            .id();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<SpaceshipCameraView>(),
+            SpaceshipCameraView::Cockpit
+        );
+
+        app.world_mut()
+            .entity_mut(controller)
+            .insert(SectionInactiveMarker);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<SpaceshipCameraView>(),
+            SpaceshipCameraView::Chase,
+            "an inactive computer is no seat"
+        );
+
+        // Re-seated, then the section is destroyed (despawned).
+        app.world_mut()
+            .entity_mut(controller)
+            .remove::<SectionInactiveMarker>();
+        app.insert_resource(SpaceshipCameraView::Cockpit);
+        app.update();
+        app.world_mut().entity_mut(controller).despawn();
+        app.update();
+        assert_eq!(
+            *app.world().resource::<SpaceshipCameraView>(),
+            SpaceshipCameraView::Chase
+        );
+    }
 }
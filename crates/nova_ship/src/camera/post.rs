@@ -1,15 +1,23 @@
-//! A plugin that applies default post processing settings to 3D cameras.
+//! Post-processing for 3D cameras: a data-driven [`PostProfile`] instead of one
+//! hard-coded look.
 //!
-//! This plugin automatically enables tonemapping and bloom on any `Camera3d`
-//! entity marked with the `PostProcessingCamera` component. It is meant to
-//! provide a simple and visually appealing default look without requiring
-//! any manual setup.
+//! Every `Camera3d` marked with [`PostProcessingCamera`] carries the *applied*
+//! profile: the tonemapper, bloom, a colour grade (exposure, white balance,
+//! saturation, contrast) and chromatic aberration. The vignette and a
+//! full-screen colour wash are one UI overlay drawn under the HUD, so they
+//! cover every post camera without a custom render pass.
 //!
-//! The current defaults are:
-//! - Tonemapping::TonyMcMapface
-//! - Bloom::NATURAL
+//! The applied profile ([`AppliedPostProfile`]) is resolved each frame from
+//! three layers:
 //!
-//! Example usage:
+//! | Layer | Owner | Does |
+//! |-------|-------|------|
+//! | [`PostProfileBlend`] | the scenario (`ScenarioConfig::post`, `SetPostProfile`) | the authored look, eased between profiles |
+//! | [`HullPostEffects`] | the player's hull | a vignette that closes in at low hull, an aberration kick on heavy hits |
+//! | [`GraphicsBudget`] | the quality tier | `Low` drops chromatic aberration and the vignette |
+//!
+//! The default profile is the old fixed look - `Tonemapping::TonyMcMapface`
+//! and `Bloom::NATURAL` - so a scenario that says nothing renders as before.
 //!
 //! ```rust
 //! # use bevy::prelude::*;
@@ -19,36 +27,409 @@
 //!     .add_plugins(DefaultPlugins)
 //!     .add_plugins(PostProcessingDefaultPlugin);
 //!
-//! // Cameras marked with PostProcessingCamera receive bloom and tonemapping:
+//! // Cameras marked with PostProcessingCamera receive the applied profile:
 //! commands.spawn((Camera3d::default(), PostProcessingCamera));
 //! # }
 //! ```
-//!
-//! If you want different defaults or more control over post processing,
-//! consider writing your own plugin or inserting the components manually.
 
-use bevy::{core_pipeline::tonemapping::Tonemapping, post_process::bloom::Bloom, prelude::*};
+use bevy::{
+    core_pipeline::tonemapping::Tonemapping,
+    post_process::{bloom::Bloom, effect_stack::ChromaticAberration},
+    prelude::*,
+    render::view::{ColorGrading, ColorGradingGlobal, ColorGradingSection},
+};
+use nova_gameplay::prelude::*;
 
-/// Glob-import surface for the post-processing camera defaults.
+use super::handback_ease;
+
+/// Glob-import surface for the post-processing camera and its profiles.
 pub mod prelude {
-    pub use super::{PostProcessingCamera, PostProcessingDefaultPlugin};
+    pub use super::{
+        hull_vignette, AppliedPostProfile, HullPostEffects, PostProcessingCamera,
+        PostProcessingDefaultPlugin, PostProfile, PostProfileBlend, PostTonemapping,
+    };
 }
 
 /// Post Processing Camera settings.
 #[derive(Component, Clone, Debug, Reflect)]
 pub struct PostProcessingCamera;
 
-/// A plugin that applies default post processing settings.
+/// The tonemapper a [`PostProfile`] selects. Mirrors Bevy's `Tonemapping` so
+/// scenario files name it without a render dependency; see [`Tonemapping`] for
+/// what each curve looks like.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PostTonemapping {
+    /// The default: a neutral filmic curve that keeps saturated lights from
+    /// clipping to white.
+    #[default]
+    TonyMcMapface,
+    /// Desaturates toward white in highlights; the flattest of the filmic curves.
+    AgX,
+    /// The ACES reference curve: punchy contrast, hue shifts in bright reds.
+    AcesFitted,
+    /// Blender's Filmic.
+    BlenderFilmic,
+    /// Plain Reinhard per channel.
+    Reinhard,
+    /// Reinhard on luminance only.
+    ReinhardLuminance,
+    /// Bevy's SBDT curve.
+    SomewhatBoringDisplayTransform,
+    /// No tonemapping: HDR values clip.
+    None,
+}
+
+impl From<PostTonemapping> for Tonemapping {
+    fn from(value: PostTonemapping) -> Self {
+        match value {
+            PostTonemapping::TonyMcMapface => Tonemapping::TonyMcMapface,
+            PostTonemapping::AgX => Tonemapping::AgX,
+            PostTonemapping::AcesFitted => Tonemapping::AcesFitted,
+            PostTonemapping::BlenderFilmic => Tonemapping::BlenderFilmic,
+            PostTonemapping::Reinhard => Tonemapping::Reinhard,
+            PostTonemapping::ReinhardLuminance => Tonemapping::ReinhardLuminance,
+            PostTonemapping::SomewhatBoringDisplayTransform => {
+                Tonemapping::SomewhatBoringDisplayTransform
+            }
+            PostTonemapping::None => Tonemapping::None,
+        }
+    }
+}
+
+/// A post-processing look: what a scenario authors in `ScenarioConfig::post`
+/// and blends to with `SetPostProfile`. Every field has a neutral default, so
+/// a profile only names what it changes.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct PostProfile {
+    /// The tonemapping curve. Not blendable: a blend switches it halfway.
+    pub tonemapping: PostTonemapping,
+    /// Bloom intensity, 0 to 1. 0 removes bloom; the default is
+    /// `Bloom::NATURAL`'s.
+    pub bloom: f32,
+    /// Exposure offset in EV, -8 to 8. A grade, on top of the camera's own
+    /// exposure (which photo mode owns).
+    pub exposure: f32,
+    /// White balance, -1 (cool) to 1 (warm).
+    pub temperature: f32,
+    /// White balance, -1 (green) to 1 (magenta).
+    pub tint: f32,
+    /// Saturation, 0 (greyscale) to 3; 1 is neutral.
+    pub saturation: f32,
+    /// Contrast, 0 to 3; 1 is neutral.
+    pub contrast: f32,
+    /// Chromatic aberration intensity, 0 to 1. Subtle below 0.05.
+    pub chromatic_aberration: f32,
+    /// Vignette strength, 0 to 1: how far the darkened edge reaches in and how
+    /// dark it gets.
+    pub vignette: f32,
+    /// A full-screen colour drawn over the frame; its alpha is the strength.
+    /// Transparent by default.
+    pub wash: Color,
+}
+
+impl Default for PostProfile {
+    fn default() -> Self {
+        Self {
+            tonemapping: PostTonemapping::default(),
+            bloom: Bloom::NATURAL.intensity,
+            exposure: 0.0,
+            temperature: 0.0,
+            tint: 0.0,
+            saturation: 1.0,
+            contrast: 1.0,
+            chromatic_aberration: 0.0,
+            vignette: 0.0,
+            wash: Color::NONE,
+        }
+    }
+}
+
+/// The scalar fields' names and accepted ranges, in [`PostProfile::scalars`]
+/// order.
+const POST_RANGES: [(&str, f32, f32); 9] = [
+    ("bloom", 0.0, 1.0),
+    ("exposure", -8.0, 8.0),
+    ("temperature", -1.0, 1.0),
+    ("tint", -1.0, 1.0),
+    ("saturation", 0.0, 3.0),
+    ("contrast", 0.0, 3.0),
+    ("chromatic_aberration", 0.0, 1.0),
+    ("vignette", 0.0, 1.0),
+    ("wash.alpha", 0.0, 1.0),
+];
+
+impl PostProfile {
+    fn scalars(&self) -> [f32; 9] {
+        [
+            self.bloom,
+            self.exposure,
+            self.temperature,
+            self.tint,
+            self.saturation,
+            self.contrast,
+            self.chromatic_aberration,
+            self.vignette,
+            self.wash.alpha(),
+        ]
+    }
+
+    fn with_scalars(
+        &self,
+        [bloom, exposure, temperature, tint, saturation, contrast, chromatic_aberration, vignette, wash_alpha]: [f32; 9],
+    ) -> Self {
+        Self {
+            tonemapping: self.tonemapping,
+            bloom,
+            exposure,
+            temperature,
+            tint,
+            saturation,
+            contrast,
+            chromatic_aberration,
+            vignette,
+            wash: self.wash.with_alpha(wash_alpha),
+        }
+    }
+
+    /// The fields outside their documented range (a non-finite value counts),
+    /// by name. Content lint reports these; [`Self::clamped`] is what the
+    /// renderer uses instead.
+    pub fn out_of_range(&self) -> Vec<&'static str> {
+        POST_RANGES
+            .iter()
+            .zip(self.scalars())
+            .filter(|((_, lo, hi), value)| !(*lo..=*hi).contains(value))
+            .map(|((name, _, _), _)| *name)
+            .collect()
+    }
+
+    /// This profile with every field pulled into its range; a non-finite field
+    /// falls back to the default's value.
+    pub fn clamped(&self) -> Self {
+        let default = Self::default().scalars();
+        let mut scalars = self.scalars();
+        for (i, value) in scalars.iter_mut().enumerate() {
+            let (_, lo, hi) = POST_RANGES[i];
+            *value = if value.is_finite() {
+                value.clamp(lo, hi)
+            } else {
+                default[i]
+            };
+        }
+        self.with_scalars(scalars)
+    }
+
+    /// The profile `t` of the way from `self` to `other`. Scalars lerp, the
+    /// wash fades through the visible end's colour (so fading in from
+    /// transparent does not darken through black), and the tonemapper
+    /// switches at the halfway mark.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        if t <= 0.0 {
+            return self.clone();
+        }
+        if t >= 1.0 {
+            return other.clone();
+        }
+        let (a, b) = (self.scalars(), other.scalars());
+        let scalars = std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t);
+        let from = if self.wash.alpha() == 0.0 {
+            other.wash
+        } else {
+            self.wash
+        };
+        let to = if other.wash.alpha() == 0.0 {
+            self.wash
+        } else {
+            other.wash
+        };
+        Self {
+            tonemapping: if t < 0.5 {
+                self.tonemapping
+            } else {
+                other.tonemapping
+            },
+            wash: from.mix(&to, t).with_alpha(scalars[8]),
+            ..self.with_scalars(scalars)
+        }
+    }
+
+    /// This profile as the quality tier allows it: without
+    /// [`GraphicsBudget::post_effects`] the chromatic aberration and vignette
+    /// are dropped. The grade, tonemapper, bloom and wash always stay.
+    pub fn budgeted(&self, budget: &GraphicsBudget) -> Self {
+        if budget.post_effects {
+            return self.clone();
+        }
+        Self {
+            chromatic_aberration: 0.0,
+            vignette: 0.0,
+            ..self.clone()
+        }
+    }
+
+    /// The camera `ColorGrading` this profile's grade fields make.
+    pub fn color_grading(&self) -> ColorGrading {
+        ColorGrading::with_identical_sections(
+            ColorGradingGlobal {
+                exposure: self.exposure,
+                temperature: self.temperature,
+                tint: self.tint,
+                ..default()
+            },
+            ColorGradingSection {
+                saturation: self.saturation,
+                contrast: self.contrast,
+                ..default()
+            },
+        )
+    }
+}
+
+/// The authored layer of the post look: the profile the scenario set and the
+/// ease toward it. Scenario load cuts it to `ScenarioConfig::post` (or the
+/// default), `SetPostProfile` blends it, and teardown cuts it back to the
+/// default. The blend uses the camera handback's curve ([`handback_ease`]).
+#[derive(Resource, Clone, Debug, Default)]
+pub struct PostProfileBlend {
+    /// The profile the blend starts from.
+    pub from: PostProfile,
+    /// The profile the blend lands on.
+    pub to: PostProfile,
+    /// Seconds into the blend.
+    pub elapsed: f32,
+    /// The blend's length in seconds; 0 is a cut.
+    pub duration: f32,
+}
+
+impl PostProfileBlend {
+    /// Switch to `profile` at once.
+    pub fn cut(&mut self, profile: PostProfile) {
+        self.blend_to(profile, 0.0);
+    }
+
+    /// Ease from wherever the look is now to `profile` over `seconds`. A
+    /// blend started mid-blend starts from the current mix, not the old target.
+    pub fn blend_to(&mut self, profile: PostProfile, seconds: f32) {
+        self.from = self.current();
+        self.to = profile.clamped();
+        self.elapsed = 0.0;
+        self.duration = seconds.max(0.0);
+    }
+
+    /// The authored profile at this point of the blend.
+    pub fn current(&self) -> PostProfile {
+        self.from
+            .lerp(&self.to, handback_ease(self.elapsed, self.duration))
+    }
+
+    /// The profile the blend is heading to.
+    pub fn target(&self) -> &PostProfile {
+        &self.to
+    }
+
+    /// Advance the blend by `dt` seconds.
+    pub fn tick(&mut self, dt: f32) {
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+    }
+}
+
+/// Hull fraction below which the damage vignette starts closing in.
+pub const HULL_VIGNETTE_ONSET: f32 = 0.35;
+
+/// The damage vignette's strength at zero hull.
+pub const HULL_VIGNETTE_MAX: f32 = 0.55;
+
+/// A hull drop of at least this fraction of max in one frame is a heavy hit:
+/// it kicks the chromatic aberration.
+pub const HULL_KICK_THRESHOLD: f32 = 0.05;
+
+/// How long a heavy-hit kick takes to fade, seconds.
+pub const HULL_KICK_SECONDS: f32 = 0.4;
+
+/// Chromatic aberration added at the peak of a heavy-hit kick.
+const HULL_KICK_ABERRATION: f32 = 0.08;
+
+/// The damage vignette's strength at `fraction` of max hull: none above
+/// [`HULL_VIGNETTE_ONSET`], rising linearly to [`HULL_VIGNETTE_MAX`] at zero.
+pub fn hull_vignette(fraction: f32) -> f32 {
+    if fraction >= HULL_VIGNETTE_ONSET {
+        return 0.0;
+    }
+    (1.0 - fraction / HULL_VIGNETTE_ONSET).clamp(0.0, 1.0) * HULL_VIGNETTE_MAX
+}
+
+/// The hull-damage layer of the post look, tracked from the player ship's
+/// aggregate `Health`. No player ship, no effect.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct HullPostEffects {
+    /// The player's hull as a fraction of max, last frame.
+    pub fraction: Option<f32>,
+    /// The heavy-hit kick: 1 on the hit, fading to 0 over
+    /// [`HULL_KICK_SECONDS`].
+    pub kick: f32,
+}
+
+impl HullPostEffects {
+    /// Fold in this frame's hull `fraction`, `dt` seconds after the last.
+    pub fn observe(&mut self, fraction: Option<f32>, dt: f32) {
+        self.kick = (self.kick - dt / HULL_KICK_SECONDS).max(0.0);
+        if let (Some(before), Some(now)) = (self.fraction, fraction) {
+            if before - now >= HULL_KICK_THRESHOLD {
+                self.kick = 1.0;
+            }
+        }
+        self.fraction = fraction;
+    }
+
+    /// Layer the hull effects over `profile`. The vignette takes the stronger
+    /// of the two; the kick adds aberration, scaled by `motion_scale` so
+    /// reduced motion drops it like the other hit flashes.
+    pub fn apply(&self, profile: &mut PostProfile, motion_scale: f32) {
+        if let Some(fraction) = self.fraction {
+            profile.vignette = profile.vignette.max(hull_vignette(fraction));
+        }
+        profile.chromatic_aberration += self.kick * self.kick * HULL_KICK_ABERRATION * motion_scale;
+    }
+}
+
+/// The profile the cameras and the overlay draw this frame: the blend, the
+/// hull layer and the quality tier resolved together. Only changes when the
+/// look does, so the camera components are rewritten only then.
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct AppliedPostProfile(pub PostProfile);
+
+/// The full-screen node that draws the vignette and the wash.
+#[derive(Component, Clone, Debug)]
+struct PostOverlayMarker;
+
+/// A plugin that applies the post-processing profile.
 ///
-/// When a `Camera3d` is added to an entity, this plugin automatically inserts
-/// `Tonemapping::TonyMcMapface` and `Bloom::NATURAL`.
+/// When a `Camera3d` with [`PostProcessingCamera`] is added, it gets the
+/// applied profile's components; when the profile changes, every such camera
+/// is updated.
 pub struct PostProcessingDefaultPlugin;
 
 impl Plugin for PostProcessingDefaultPlugin {
     fn build(&self, app: &mut App) {
         trace!("PostProcessingDefaultPlugin: build");
 
+        app.init_resource::<PostProfileBlend>()
+            .init_resource::<HullPostEffects>()
+            .init_resource::<AppliedPostProfile>();
+
         app.add_observer(setup_post_processing_camera);
+        app.add_systems(
+            PostUpdate,
+            (
+                resolve_post_profile,
+                (apply_post_profile, sync_post_overlay)
+                    .run_if(resource_changed::<AppliedPostProfile>),
+            )
+                .chain(),
+        );
     }
 }
 
@@ -56,6 +437,7 @@ fn setup_post_processing_camera(
     insert: On<Insert, PostProcessingCamera>,
     mut commands: Commands,
     q_camera: Query<&PostProcessingCamera, With<Camera3d>>,
+    applied: Option<Res<AppliedPostProfile>>,
 ) {
     let entity = insert.entity;
     trace!("setup_post_processing_camera: entity {:?}", entity);
@@ -68,7 +450,251 @@ fn setup_post_processing_camera(
         return;
     };
 
-    commands
-        .entity(entity)
-        .insert((Tonemapping::TonyMcMapface, Bloom::NATURAL));
+    let profile = applied.map(|applied| applied.0.clone()).unwrap_or_default();
+    insert_post_components(&mut commands.entity(entity), &profile);
+}
+
+fn insert_post_components(entity: &mut EntityCommands, profile: &PostProfile) {
+    entity.try_insert((
+        Tonemapping::from(profile.tonemapping),
+        profile.color_grading(),
+    ));
+    if profile.bloom > 0.0 {
+        entity.try_insert(Bloom {
+            intensity: profile.bloom,
+            ..Bloom::NATURAL
+        });
+    } else {
+        entity.try_remove::<Bloom>();
+    }
+    if profile.chromatic_aberration > 0.0 {
+        entity.try_insert(ChromaticAberration {
+            intensity: profile.chromatic_aberration,
+            ..default()
+        });
+    } else {
+        entity.try_remove::<ChromaticAberration>();
+    }
+}
+
+fn resolve_post_profile(
+    time: Res<Time>,
+    mut blend: ResMut<PostProfileBlend>,
+    mut hull: ResMut<HullPostEffects>,
+    mut applied: ResMut<AppliedPostProfile>,
+    budget: Option<Res<GraphicsBudget>>,
+    accessibility: Option<Res<AccessibilitySettings>>,
+    q_player: Query<&Health, With<PlayerSpaceshipMarker>>,
+) {
+    let dt = time.delta_secs();
+    if blend.elapsed < blend.duration {
+        blend.tick(dt);
+    }
+
+    let fraction = q_player
+        .single()
+        .ok()
+        .filter(|health| health.max > 0.0)
+        .map(|health| (health.current / health.max).clamp(0.0, 1.0));
+    let mut next_hull = hull.clone();
+    next_hull.observe(fraction, dt);
+    hull.set_if_neq(next_hull);
+
+    let mut profile = blend.current();
+    let motion_scale = accessibility.map_or(1.0, |a11y| a11y.motion_scale());
+    hull.apply(&mut profile, motion_scale);
+    let profile = profile
+        .clamped()
+        .budgeted(&budget.as_deref().copied().unwrap_or_default());
+    applied.set_if_neq(AppliedPostProfile(profile));
+}
+
+fn apply_post_profile(
+    mut commands: Commands,
+    applied: Res<AppliedPostProfile>,
+    q_camera: Query<Entity, (With<PostProcessingCamera>, With<Camera3d>)>,
+) {
+    for entity in &q_camera {
+        insert_post_components(&mut commands.entity(entity), &applied.0);
+    }
+}
+
+/// The vignette as a radial gradient: clear out to an inner radius that
+/// shrinks as the strength rises, darkening to the corners.
+fn vignette_gradient(strength: f32) -> BackgroundGradient {
+    let inner = 75.0 - 45.0 * strength;
+    BackgroundGradient(vec![Gradient::from(RadialGradient::new(
+        UiPosition::CENTER,
+        RadialGradientShape::FarthestCorner,
+        vec![
+            ColorStop::percent(Color::NONE, inner),
+            ColorStop::percent(Color::BLACK.with_alpha(0.5 + 0.45 * strength), 100.0),
+        ],
+    ))])
+}
+
+fn sync_post_overlay(
+    mut commands: Commands,
+    applied: Res<AppliedPostProfile>,
+    q_overlay: Query<Entity, With<PostOverlayMarker>>,
+) {
+    let profile = &applied.0;
+    let visible = profile.vignette > 0.0 || profile.wash.alpha() > 0.0;
+    let existing = q_overlay.iter().next();
+    if !visible {
+        if let Some(entity) = existing {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let gradient = if profile.vignette > 0.0 {
+        vignette_gradient(profile.vignette)
+    } else {
+        BackgroundGradient(Vec::new())
+    };
+    let look = (BackgroundColor(profile.wash), gradient);
+    match existing {
+        Some(entity) => {
+            commands.entity(entity).try_insert(look);
+        }
+        None => {
+            commands.spawn((
+                Name::new("Post Overlay"),
+                PostOverlayMarker,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                // Under the canopy (-1) and everything the HUD draws.
+                GlobalZIndex(-2),
+                Pickable::IGNORE,
+                look,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_default_profile_is_the_old_fixed_look() {
+        let profile = PostProfile::default();
+        assert_eq!(
+            Tonemapping::from(profile.tonemapping),
+            Tonemapping::TonyMcMapface
+        );
+        assert_eq!(profile.bloom, Bloom::NATURAL.intensity);
+        assert!(profile.out_of_range().is_empty());
+        let grading = profile.color_grading();
+        assert_eq!(grading.global.exposure, 0.0);
+        assert_eq!(grading.midtones.saturation, 1.0);
+        assert_eq!(grading.midtones.contrast, 1.0);
+    }
+
+    #[test]
+    fn a_blend_eases_between_profiles_and_restarts_from_the_mix() {
+        let grim = PostProfile {
+            tonemapping: PostTonemapping::AgX,
+            saturation: 0.2,
+            wash: Color::srgba(1.0, 0.0, 0.0, 0.4),
+            ..default()
+        };
+        let mut blend = PostProfileBlend::default();
+        blend.blend_to(grim.clone(), 2.0);
+        assert_eq!(
+            blend.current(),
+            PostProfile::default(),
+            "starts where it was"
+        );
+
+        blend.tick(1.0);
+        let half = blend.current();
+        assert!((half.saturation - 0.6).abs() < 1e-5, "{half:?}");
+        assert_eq!(half.tonemapping, PostTonemapping::AgX, "switches at half");
+        // Fading in from transparent keeps the wash's hue instead of going
+        // through black.
+        assert_eq!(half.wash.to_srgba().red, 1.0);
+        assert!((half.wash.alpha() - 0.2).abs() < 1e-5);
+
+        // A new blend mid-way starts from the mix, not the old target.
+        blend.blend_to(PostProfile::default(), 1.0);
+        assert_eq!(blend.current(), half);
+        blend.tick(5.0);
+        assert_eq!(blend.current(), PostProfile::default());
+
+        blend.cut(grim.clone());
+        assert_eq!(blend.current(), grim);
+    }
+
+    #[test]
+    fn out_of_range_fields_are_named_and_clamped() {
+        let wild = PostProfile {
+            bloom: 4.0,
+            vignette: f32::NAN,
+            saturation: -1.0,
+            ..default()
+        };
+        assert_eq!(wild.out_of_range(), ["bloom", "saturation", "vignette"]);
+        let clamped = wild.clamped();
+        assert_eq!(clamped.bloom, 1.0);
+        assert_eq!(clamped.saturation, 0.0);
+        assert_eq!(
+            clamped.vignette, 0.0,
+            "non-finite falls back to the default"
+        );
+        assert!(clamped.out_of_range().is_empty());
+    }
+
+    #[test]
+    fn the_low_tier_drops_aberration_and_vignette_only() {
+        let profile = PostProfile {
+            chromatic_aberration: 0.1,
+            vignette: 0.5,
+            saturation: 0.5,
+            wash: Color::srgba(0.0, 0.0, 1.0, 0.3),
+            ..default()
+        };
+        let high = GraphicsBudget::for_quality(GraphicsQuality::High);
+        let low = GraphicsBudget::for_quality(GraphicsQuality::Low);
+        assert_eq!(profile.budgeted(&high), profile);
+        let budgeted = profile.budgeted(&low);
+        assert_eq!(budgeted.chromatic_aberration, 0.0);
+        assert_eq!(budgeted.vignette, 0.0);
+        assert_eq!(budgeted.saturation, 0.5);
+        assert_eq!(budgeted.wash, profile.wash);
+    }
+
+    #[test]
+    fn low_hull_closes_the_vignette_and_heavy_hits_kick_aberration() {
+        assert_eq!(hull_vignette(1.0), 0.0);
+        assert_eq!(hull_vignette(HULL_VIGNETTE_ONSET), 0.0);
+        assert_eq!(hull_vignette(0.0), HULL_VIGNETTE_MAX);
+
+        let mut hull = HullPostEffects::default();
+        hull.observe(Some(1.0), 0.016);
+        assert_eq!(hull.kick, 0.0, "spawning at full hull is not a hit");
+        hull.observe(Some(0.99), 0.016);
+        assert_eq!(hull.kick, 0.0, "a graze is not a heavy hit");
+        hull.observe(Some(0.2), 0.016);
+        assert_eq!(hull.kick, 1.0);
+
+        let mut profile = PostProfile::default();
+        hull.apply(&mut profile, 1.0);
+        assert_eq!(profile.vignette, hull_vignette(0.2));
+        assert!(profile.chromatic_aberration > 0.0);
+
+        // Reduced motion keeps the vignette and drops the kick.
+        let mut calm = PostProfile::default();
+        hull.apply(&mut calm, 0.0);
+        assert_eq!(calm.vignette, profile.vignette);
+        assert_eq!(calm.chromatic_aberration, 0.0);
+
+        hull.observe(Some(0.2), HULL_KICK_SECONDS);
+        assert_eq!(hull.kick, 0.0, "the kick fades out");
+    }
 }
@@ -0,0 +1,766 @@
+//! Procedural skyboxes: a stacked cubemap generated from a seed instead of
+//! shipped as a PNG.
+//!
+//! A [`SkyboxSource`] is what a scenario's `cubemap` (and `SetSkybox`) holds:
+//! either an asset path to a stacked cubemap image, or a
+//! [`ProceduralSkyConfig`] - a seed plus star density, nebula colours, a galaxy
+//! band and a distant sun. Both resolve to a `Handle<Image>` in the same
+//! stacked layout [`super::skybox`] expects, so everything downstream of the
+//! handle (the deferred `PendingSkyboxSwap` install, the cube view) is shared.
+//!
+//! Generation runs as an asset load ([`AssetServer::add_async`]) and writes the
+//! result to a PNG under the sky cache directory, keyed by a hash of the config
+//! and [`PROCEDURAL_SKY_VERSION`]. A repeated load of the same sky decodes that
+//! PNG instead of generating again - and the PNG is an ordinary stacked
+//! cubemap, so a mod can ship a baked one as a plain asset path. The cache is
+//! `$NOVA_SKY_CACHE_DIR` when set, else the platform cache dir; the web build
+//! has neither and always generates.
+//!
+//! Generation is deterministic on one machine: the same config produces the
+//! same pixels, which is what makes the cache key sound. Across machines it
+//! can differ in the last bits - `exp`, `acos` and `powf` come from the
+//! platform's math library - so the cache is per-machine and never shipped,
+//! and a sky that must match everywhere is baked and shipped as an image.
+
+use std::path::{Path, PathBuf};
+
+use bevy::{
+    asset::RenderAssetUsages,
+    color::ColorToPacked,
+    image::{CompressedImageFormats, ImageSampler, ImageType},
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use nova_gameplay::prelude::*;
+
+/// Glob-import surface for the procedural skybox generator.
+pub mod prelude {
+    pub use super::{
+        generate_procedural_sky, GalaxyBandConfig, ProceduralSkyConfig, ProceduralSunConfig,
+        SkyboxSource, PROCEDURAL_SKY_MAX_FACE, PROCEDURAL_SKY_MIN_FACE, PROCEDURAL_SKY_VERSION,
+        SKY_CACHE_DIR_ENV,
+    };
+}
+
+/// Bumped whenever the generator's output changes for the same config, so
+/// stale cache files are never read back.
+pub const PROCEDURAL_SKY_VERSION: u32 = 1;
+
+/// Environment variable that moves the procedural sky cache off the platform
+/// cache dir - the test and tooling override.
+pub const SKY_CACHE_DIR_ENV: &str = "NOVA_SKY_CACHE_DIR";
+
+/// Smallest face edge the generator renders, in pixels.
+pub const PROCEDURAL_SKY_MIN_FACE: u32 = 16;
+
+/// Largest face edge the generator renders, in pixels. A 2048 face is a
+/// 2048x12288 image - past that the generation time and the cache file stop
+/// being cheap.
+pub const PROCEDURAL_SKY_MAX_FACE: u32 = 2048;
+
+/// Where a skybox comes from: a stacked cubemap image, or a generator config.
+///
+/// Untagged in RON, so the path form every scenario already authors is
+/// unchanged - `cubemap: "self://textures/sky.png"` - and a procedural sky is
+/// the config struct in its place: `cubemap: (seed: 7, star_density: 0.4)`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum SkyboxSource {
+    /// A stacked cubemap image (see [`super::skybox`] for the layout).
+    Image(AssetRef<Image>),
+    /// A sky generated from a seed.
+    Procedural(ProceduralSkyConfig),
+}
+
+impl Default for SkyboxSource {
+    fn default() -> Self {
+        SkyboxSource::Image(AssetRef::default())
+    }
+}
+
+impl From<AssetRef<Image>> for SkyboxSource {
+    fn from(image: AssetRef<Image>) -> Self {
+        SkyboxSource::Image(image)
+    }
+}
+
+impl From<Handle<Image>> for SkyboxSource {
+    fn from(handle: Handle<Image>) -> Self {
+        SkyboxSource::Image(handle.into())
+    }
+}
+
+impl From<String> for SkyboxSource {
+    fn from(path: String) -> Self {
+        SkyboxSource::Image(path.into())
+    }
+}
+
+impl From<&str> for SkyboxSource {
+    fn from(path: &str) -> Self {
+        SkyboxSource::Image(path.into())
+    }
+}
+
+impl From<ProceduralSkyConfig> for SkyboxSource {
+    fn from(config: ProceduralSkyConfig) -> Self {
+        SkyboxSource::Procedural(config)
+    }
+}
+
+impl SkyboxSource {
+    /// The authored image path, if this is a path-authored image (`None` for a
+    /// handle or a procedural sky).
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            SkyboxSource::Image(image) => image.path(),
+            SkyboxSource::Procedural(_) => None,
+        }
+    }
+
+    /// Resolve to a live handle. An image loads through the `AssetServer`
+    /// like any [`AssetRef`]; a procedural sky is queued as an async load that
+    /// reads the disk cache or generates (and caches) the cubemap. Either way
+    /// the handle is not loaded yet - install it through the deferred skybox
+    /// swap, never straight into a `SkyboxConfig`.
+    pub fn resolve(&self, asset_server: &AssetServer) -> Handle<Image> {
+        match self {
+            SkyboxSource::Image(image) => image.resolve(asset_server),
+            SkyboxSource::Procedural(config) => {
+                let config = config.clone();
+                let cache_dir = sky_cache_dir();
+                asset_server.add_async(async move {
+                    Ok::<_, std::convert::Infallible>(load_or_generate(
+                        &config,
+                        cache_dir.as_deref(),
+                    ))
+                })
+            }
+        }
+    }
+
+    /// This source with a procedural sun that names no direction pointed at
+    /// `key_light` - the direction the scene's key light shines FROM. A
+    /// no-op for images, for skies without a sun, and for an explicit sun
+    /// direction.
+    pub fn with_key_light(mut self, key_light: Option<Vec3>) -> Self {
+        if let (SkyboxSource::Procedural(config), Some(key_light)) = (&mut self, key_light) {
+            if let Some(sun) = &mut config.sun {
+                sun.direction.get_or_insert(key_light);
+            }
+        }
+        self
+    }
+}
+
+/// The inputs of a generated sky. Every field has a default, so a config only
+/// names what it changes; the default is a moderate starfield with a violet
+/// and teal nebula. Colours are sRGB triples, 0 to 1.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ProceduralSkyConfig {
+    /// The seed: every random choice (star positions, nebula shape) derives
+    /// from it.
+    pub seed: u64,
+    /// Edge of one cube face in pixels, [`PROCEDURAL_SKY_MIN_FACE`] to
+    /// [`PROCEDURAL_SKY_MAX_FACE`].
+    pub face_size: u32,
+    /// How crowded the starfield is, 0 (none) to 1.
+    pub star_density: f32,
+    /// Brightness of the brightest stars, 0 to 1.
+    pub star_brightness: f32,
+    /// The colours the nebula blends between. Empty means no nebula.
+    pub nebula_colors: Vec<[f32; 3]>,
+    /// How much of the sky the nebula covers and how bright it glows, 0 to 1.
+    pub nebula_strength: f32,
+    /// Size of the nebula's features: higher is finer, more broken-up cloud.
+    pub nebula_scale: f32,
+    /// An optional Milky-Way-style band of dense stars and glow.
+    pub galaxy_band: Option<GalaxyBandConfig>,
+    /// An optional distant sun disk and glow.
+    pub sun: Option<ProceduralSunConfig>,
+}
+
+impl Default for ProceduralSkyConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            face_size: 512,
+            star_density: 0.35,
+            star_brightness: 0.9,
+            nebula_colors: vec![[0.35, 0.12, 0.55], [0.08, 0.35, 0.45]],
+            nebula_strength: 0.45,
+            nebula_scale: 2.0,
+            galaxy_band: None,
+            sun: None,
+        }
+    }
+}
+
+/// A band of dense stars and glow around a great circle of the sky.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct GalaxyBandConfig {
+    /// The axis the band circles: the band lies in the plane perpendicular
+    /// to it.
+    pub normal: Vec3,
+    /// Angular half-width of the band, as the sine of its angle off the
+    /// plane (0.2 is about 12 degrees).
+    pub width: f32,
+    /// Glow at the band's centre line, 0 to 1.
+    pub brightness: f32,
+    /// The band's glow colour.
+    pub color: [f32; 3],
+}
+
+impl Default for GalaxyBandConfig {
+    fn default() -> Self {
+        Self {
+            normal: Vec3::new(0.3, 1.0, 0.2),
+            width: 0.2,
+            brightness: 0.35,
+            color: [0.85, 0.8, 0.7],
+        }
+    }
+}
+
+/// A distant sun: a bright disk with a soft glow around it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct ProceduralSunConfig {
+    /// World direction toward the sun. `None` follows the scenario's key
+    /// light (its brightest directional `Light`), so the disk sits where the
+    /// shading says the light comes from.
+    pub direction: Option<Vec3>,
+    /// The disk's colour.
+    pub color: [f32; 3],
+    /// Angular radius of the disk, in degrees.
+    pub size: f32,
+    /// Strength of the glow around the disk, 0 to 1.
+    pub glow: f32,
+}
+
+impl Default for ProceduralSunConfig {
+    fn default() -> Self {
+        Self {
+            direction: None,
+            color: [1.0, 0.95, 0.85],
+            size: 1.2,
+            glow: 0.5,
+        }
+    }
+}
+
+impl ProceduralSkyConfig {
+    /// The fields outside their documented range, by name. Content lint
+    /// reports these; the generator clamps them.
+    pub fn out_of_range(&self) -> Vec<&'static str> {
+        let unit = |value: f32| (0.0..=1.0).contains(&value);
+        let mut fields = Vec::new();
+        if !(PROCEDURAL_SKY_MIN_FACE..=PROCEDURAL_SKY_MAX_FACE).contains(&self.face_size) {
+            fields.push("face_size");
+        }
+        if !unit(self.star_density) {
+            fields.push("star_density");
+        }
+        if !unit(self.star_brightness) {
+            fields.push("star_brightness");
+        }
+        if !unit(self.nebula_strength) {
+            fields.push("nebula_strength");
+        }
+        if !(self.nebula_scale.is_finite() && self.nebula_scale > 0.0) {
+            fields.push("nebula_scale");
+        }
+        if self.nebula_colors.iter().flatten().any(|c| !unit(*c)) {
+            fields.push("nebula_colors");
+        }
+        if let Some(band) = &self.galaxy_band {
+            if band.normal.length_squared() == 0.0 || !band.normal.is_finite() {
+                fields.push("galaxy_band.normal");
+            }
+            if !(band.width.is_finite() && band.width > 0.0) {
+                fields.push("galaxy_band.width");
+            }
+            if !unit(band.brightness) {
+                fields.push("galaxy_band.brightness");
+            }
+        }
+        if let Some(sun) = &self.sun {
+            if sun
+                .direction
+                .is_some_and(|d| d.length_squared() == 0.0 || !d.is_finite())
+            {
+                fields.push("sun.direction");
+            }
+            if !(sun.size.is_finite() && (0.0..=45.0).contains(&sun.size)) {
+                fields.push("sun.size");
+            }
+            if !unit(sun.glow) {
+                fields.push("sun.glow");
+            }
+        }
+        fields
+    }
+
+    /// A stable hash of everything that shapes the output, for the cache
+    /// file name. FNV-1a over the fields' bits rather than `std`'s hasher,
+    /// whose output may change between Rust releases.
+    pub fn cache_key(&self) -> u64 {
+        let mut hash = Fnv::default();
+        hash.u32(PROCEDURAL_SKY_VERSION);
+        hash.u64(self.seed);
+        hash.u32(self.face_size);
+        for value in [
+            self.star_density,
+            self.star_brightness,
+            self.nebula_strength,
+            self.nebula_scale,
+        ] {
+            hash.f32(value);
+        }
+        hash.u32(self.nebula_colors.len() as u32);
+        for color in &self.nebula_colors {
+            color.iter().for_each(|c| hash.f32(*c));
+        }
+        match &self.galaxy_band {
+            None => hash.u32(0),
+            Some(band) => {
+                hash.u32(1);
+                band.normal.to_array().iter().for_each(|c| hash.f32(*c));
+                hash.f32(band.width);
+                hash.f32(band.brightness);
+                band.color.iter().for_each(|c| hash.f32(*c));
+            }
+        }
+        match &self.sun {
+            None => hash.u32(0),
+            Some(sun) => {
+                hash.u32(1);
+                // A sun still following the key light renders without a
+                // disk; it is keyed apart from every pointed one.
+                match sun.direction {
+                    None => hash.u32(0),
+                    Some(direction) => {
+                        hash.u32(1);
+                        direction.to_array().iter().for_each(|c| hash.f32(*c));
+                    }
+                }
+                sun.color.iter().for_each(|c| hash.f32(*c));
+                hash.f32(sun.size);
+                hash.f32(sun.glow);
+            }
+        }
+        hash.0
+    }
+
+    /// The cache file name for this config.
+    pub fn cache_file_name(&self) -> String {
+        format!(
+            "sky-v{PROCEDURAL_SKY_VERSION}-{:016x}.png",
+            self.cache_key()
+        )
+    }
+}
+
+/// 64-bit FNV-1a.
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv {
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes(&value.to_le_bytes());
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.bytes(&value.to_bits().to_le_bytes());
+    }
+}
+
+/// The sky cache directory: `$NOVA_SKY_CACHE_DIR`, else
+/// `<platform cache dir>/nova-protocol/skies`. `None` on the web, and where
+/// no cache dir resolves (headless CI without HOME, e.g.).
+fn sky_cache_dir() -> Option<PathBuf> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        match std::env::var_os(SKY_CACHE_DIR_ENV) {
+            Some(dir) => std::path::absolute(PathBuf::from(dir)).ok(),
+            None => dirs::cache_dir().map(|dir| dir.join("nova-protocol").join("skies")),
+        }
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        None
+    }
+}
+
+/// The cached sky for `config` under `cache_dir` if one decodes at the right
+/// size, else a freshly generated one - written back to the cache on a
+/// best-effort basis (a failed write warns; the sky still renders).
+fn load_or_generate(config: &ProceduralSkyConfig, cache_dir: Option<&Path>) -> Image {
+    let face = config
+        .face_size
+        .clamp(PROCEDURAL_SKY_MIN_FACE, PROCEDURAL_SKY_MAX_FACE);
+    let path = cache_dir.map(|dir| dir.join(config.cache_file_name()));
+    if let Some(path) = &path {
+        if let Some(image) = read_cached_sky(path, face) {
+            debug!("procedural sky: cache hit {}", path.display());
+            return image;
+        }
+    }
+
+    let image = generate_procedural_sky(config);
+    if let Some(path) = &path {
+        if let Err(error) = write_cached_sky(path, &image) {
+            warn!(
+                "procedural sky: could not cache {}: {error}",
+                path.display()
+            );
+        }
+    }
+    image
+}
+
+fn read_cached_sky(path: &Path, face: u32) -> Option<Image> {
+    let bytes = std::fs::read(path).ok()?;
+    let image = Image::from_buffer(
+        &bytes,
+        ImageType::Extension("png"),
+        CompressedImageFormats::NONE,
+        true,
+        ImageSampler::Default,
+        RenderAssetUsages::default(),
+    )
+    .ok()?;
+    // A truncated or foreign file is regenerated over, not installed.
+    (image.width() == face && image.height() == face * 6).then_some(image)
+}
+
+/// Write through a sibling temp file and rename, so a crash mid-write never
+/// leaves a truncated PNG under the real name.
+fn write_cached_sky(path: &Path, image: &Image) -> Result<(), String> {
+    let dir = path.parent().ok_or("cache path has no parent")?;
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let tmp = path.with_extension(format!("{}.tmp.png", std::process::id()));
+    let dynamic = image
+        .clone()
+        .try_into_dynamic()
+        .map_err(|e| e.to_string())?;
+    dynamic.save(&tmp).map_err(|e| e.to_string())?;
+    std::fs::rename(&tmp, path).map_err(|e| {
+        let _ = std::fs::remove_file(&tmp);
+        e.to_string()
+    })
+}
+
+/// The world direction through pixel (`a`, `b`) of cube face `face`, with `a`
+/// and `b` in -1..1 across and down the face.
+///
+/// Faces are in the stacked order the skybox expects (+X, -X, +Y, -Y, +Z,
+/// -Z) and use the GPU's cubemap convention. Bevy's skybox negates z before
+/// sampling (cubemaps are left-handed), so the z of the sampled direction is
+/// flipped back here to land in world space.
+fn face_direction(face: usize, a: f32, b: f32) -> Vec3 {
+    let sampled = match face {
+        0 => Vec3::new(1.0, -b, -a),
+        1 => Vec3::new(-1.0, -b, a),
+        2 => Vec3::new(a, 1.0, b),
+        3 => Vec3::new(a, -1.0, -b),
+        4 => Vec3::new(a, -b, 1.0),
+        _ => Vec3::new(-a, -b, -1.0),
+    };
+    Vec3::new(sampled.x, sampled.y, -sampled.z).normalize()
+}
+
+/// A hash of up to four lattice coordinates and the seed, as 0..1.
+fn hash01(seed: u64, x: i64, y: i64, z: i64) -> f32 {
+    let mut h = seed ^ 0x9e37_79b9_7f4a_7c15;
+    for v in [x, y, z] {
+        h ^= (v as u64).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        h = h.rotate_left(27).wrapping_mul(0x94d0_49bb_1331_11eb);
+    }
+    h ^= h >> 31;
+    (h >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Smooth 3D value noise, 0..1.
+fn value_noise(seed: u64, p: Vec3) -> f32 {
+    let cell = p.floor();
+    let f = p - cell;
+    let s = f * f * (Vec3::splat(3.0) - 2.0 * f);
+    let (x, y, z) = (cell.x as i64, cell.y as i64, cell.z as i64);
+    let corner = |dx: i64, dy: i64, dz: i64| hash01(seed, x + dx, y + dy, z + dz);
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    let x00 = lerp(corner(0, 0, 0), corner(1, 0, 0), s.x);
+    let x10 = lerp(corner(0, 1, 0), corner(1, 1, 0), s.x);
+    let x01 = lerp(corner(0, 0, 1), corner(1, 0, 1), s.x);
+    let x11 = lerp(corner(0, 1, 1), corner(1, 1, 1), s.x);
+    lerp(lerp(x00, x10, s.y), lerp(x01, x11, s.y), s.z)
+}
+
+/// Five octaves of value noise, 0..1.
+fn fbm(seed: u64, p: Vec3) -> f32 {
+    let (mut sum, mut amplitude, mut frequency, mut norm) = (0.0, 0.5, 1.0, 0.0);
+    for octave in 0..5 {
+        sum += amplitude * value_noise(seed.wrapping_add(octave), p * frequency);
+        norm += amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.03;
+    }
+    sum / norm
+}
+
+fn linear(color: [f32; 3]) -> Vec3 {
+    let linear = LinearRgba::from(Srgba::rgb(color[0], color[1], color[2]));
+    Vec3::new(linear.red, linear.green, linear.blue)
+}
+
+/// Render `config` into a stacked cubemap image: six `face_size` squares
+/// stacked vertically, sRGB, kept in the main world for the skybox's
+/// stacked-to-cube reinterpret. Out-of-range fields are clamped.
+pub fn generate_procedural_sky(config: &ProceduralSkyConfig) -> Image {
+    let face = config
+        .face_size
+        .clamp(PROCEDURAL_SKY_MIN_FACE, PROCEDURAL_SKY_MAX_FACE);
+    let seed = config.seed;
+    let star_density = config.star_density.clamp(0.0, 1.0);
+    let star_brightness = config.star_brightness.clamp(0.0, 1.0);
+    let nebula_strength = config.nebula_strength.clamp(0.0, 1.0);
+    let nebula_scale = if config.nebula_scale.is_finite() && config.nebula_scale > 0.0 {
+        config.nebula_scale
+    } else {
+        ProceduralSkyConfig::default().nebula_scale
+    };
+    let nebula_colors: Vec<Vec3> = config
+        .nebula_colors
+        .iter()
+        .map(|c| linear(c.map(|c| c.clamp(0.0, 1.0))))
+        .collect();
+    let band = config.galaxy_band.as_ref().and_then(|band| {
+        let normal = band.normal.try_normalize()?;
+        Some((
+            normal,
+            band.width.max(1e-3),
+            band.brightness.clamp(0.0, 1.0),
+            linear(band.color),
+        ))
+    });
+    let sun = config.sun.as_ref().and_then(|sun| {
+        let direction = sun.direction?.try_normalize()?;
+        Some((
+            direction,
+            sun.size.clamp(0.0, 45.0).to_radians().max(1e-3),
+            sun.glow.clamp(0.0, 1.0),
+            linear(sun.color),
+        ))
+    });
+    // Offsets decorrelate the noise fields that share the seed.
+    let warp = Vec3::new(17.3, -9.1, 4.7);
+
+    let mut data = Vec::with_capacity((face * face * 6 * 4) as usize);
+    for face_index in 0..6 {
+        for y in 0..face {
+            for x in 0..face {
+                let a = (x as f32 + 0.5) / face as f32 * 2.0 - 1.0;
+                let b = (y as f32 + 0.5) / face as f32 * 2.0 - 1.0;
+                let dir = face_direction(face_index, a, b);
+                let mut color = Vec3::ZERO;
+
+                // Nebula: a thresholded cloud, its hue drifting across the
+                // palette on a second, coarser field.
+                let band_weight = band.map_or(0.0, |(normal, width, _, _)| {
+                    (-(dir.dot(normal) / width).powi(2)).exp()
+                });
+                if !nebula_colors.is_empty() && nebula_strength > 0.0 {
+                    let cloud = fbm(seed, dir * nebula_scale + warp);
+                    let coverage = 1.0 - nebula_strength * 0.6;
+                    let density = ((cloud - coverage * 0.6) / 0.35).clamp(0.0, 1.0);
+                    let density = density * density * nebula_strength;
+                    let hue = fbm(seed ^ 0x5eed, dir * nebula_scale * 0.5) * 1.6 - 0.3;
+                    let t = hue.clamp(0.0, 1.0) * (nebula_colors.len() - 1) as f32;
+                    let i = (t.floor() as usize).min(nebula_colors.len() - 1);
+                    let j = (i + 1).min(nebula_colors.len() - 1);
+                    let tint = nebula_colors[i].lerp(nebula_colors[j], t - i as f32);
+                    color += tint * density * 0.6;
+                }
+
+                // Galaxy band: a soft glow, mottled by dust lanes.
+                if let Some((_, _, brightness, tint)) = band {
+                    let dust = fbm(seed ^ 0xd057, dir * 6.0);
+                    color += tint * band_weight * brightness * (0.35 + 0.65 * dust) * 0.5;
+                }
+
+                // Stars: per-pixel, denser in the band.
+                let roll = hash01(seed, face_index as i64, x as i64, y as i64);
+                let chance = star_density * 0.004 * (1.0 + 4.0 * band_weight);
+                if roll < chance {
+                    let magnitude = hash01(seed ^ 0x57a2, face_index as i64, y as i64, x as i64);
+                    let temperature = hash01(seed ^ 0x7e39, x as i64, face_index as i64, y as i64);
+                    let tint =
+                        Vec3::new(0.8, 0.85, 1.0).lerp(Vec3::new(1.0, 0.85, 0.65), temperature);
+                    color += tint * star_brightness * (0.15 + 0.85 * magnitude.powi(6));
+                }
+
+                // Sun: a hard disk inside a falling-off glow.
+                if let Some((direction, radius, glow, tint)) = sun {
+                    let angle = dir.dot(direction).clamp(-1.0, 1.0).acos();
+                    if angle <= radius {
+                        color += tint * 4.0;
+                    }
+                    color += tint * glow * (-(angle / (radius * 6.0))).exp();
+                }
+
+                let srgb = Srgba::from(LinearRgba::rgb(color.x, color.y, color.z));
+                let [r, g, b, _] = srgb.to_u8_array();
+                data.extend_from_slice(&[r, g, b, 255]);
+            }
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: face,
+            height: face * 6,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small(seed: u64) -> ProceduralSkyConfig {
+        ProceduralSkyConfig {
+            seed,
+            face_size: 32,
+            ..default()
+        }
+    }
+
+    /// The face order and handedness the skybox samples with: each face's
+    /// centre looks down its axis, with z flipped for the skybox's negation.
+    #[test]
+    fn face_centres_look_down_the_stacked_axes() {
+        let centres: Vec<Vec3> = (0..6).map(|face| face_direction(face, 0.0, 0.0)).collect();
+        assert_eq!(
+            centres,
+            [
+                Vec3::X,
+                Vec3::NEG_X,
+                Vec3::Y,
+                Vec3::NEG_Y,
+                Vec3::NEG_Z,
+                Vec3::Z
+            ]
+        );
+    }
+
+    #[test]
+    fn generation_is_deterministic_and_seeded() {
+        let a = generate_procedural_sky(&small(7));
+        assert_eq!((a.width(), a.height()), (32, 32 * 6));
+        assert_eq!(a.data, generate_procedural_sky(&small(7)).data);
+        assert_ne!(a.data, generate_procedural_sky(&small(8)).data);
+        assert_ne!(small(7).cache_key(), small(8).cache_key());
+        assert_eq!(small(7).cache_key(), small(7).cache_key());
+    }
+
+    /// The sun lands where its direction says, so a sun following the key
+    /// light sits where the shading comes from.
+    #[test]
+    fn the_sun_is_drawn_toward_its_direction() {
+        let toward = Vec3::new(1.0, 0.4, -0.3).normalize();
+        let config = ProceduralSkyConfig {
+            star_density: 0.0,
+            nebula_colors: vec![],
+            sun: Some(ProceduralSunConfig {
+                size: 5.0,
+                ..default()
+            }),
+            ..small(1)
+        };
+        let sky = SkyboxSource::from(config).with_key_light(Some(toward));
+        let SkyboxSource::Procedural(config) = sky else {
+            unreachable!()
+        };
+        assert_eq!(config.sun.as_ref().unwrap().direction, Some(toward));
+
+        let image = generate_procedural_sky(&config);
+        let data = image.data.as_ref().unwrap();
+        let face = config.face_size as usize;
+        let brightest = (0..data.len() / 4)
+            .max_by_key(|pixel| data[pixel * 4] as u32 + data[pixel * 4 + 1] as u32)
+            .unwrap();
+        let (face_index, row, column) = (
+            brightest / (face * face),
+            (brightest / face) % face,
+            brightest % face,
+        );
+        let a = (column as f32 + 0.5) / face as f32 * 2.0 - 1.0;
+        let b = (row as f32 + 0.5) / face as f32 * 2.0 - 1.0;
+        let dir = face_direction(face_index, a, b);
+        assert!(dir.angle_between(toward) < 0.1, "{dir} vs {toward}");
+    }
+
+    #[test]
+    fn a_second_load_reads_the_cache() {
+        let dir = std::env::temp_dir().join(format!("nova_sky_cache_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = small(42);
+
+        let generated = load_or_generate(&config, Some(&dir));
+        let file = dir.join(config.cache_file_name());
+        assert!(file.exists(), "the first load writes the cache");
+
+        let cached = read_cached_sky(&file, config.face_size).expect("the cache decodes");
+        assert_eq!(cached.data, generated.data, "the PNG round-trips exactly");
+        assert_eq!(load_or_generate(&config, Some(&dir)).data, generated.data);
+
+        // A cache file of the wrong size is regenerated over.
+        assert!(read_cached_sky(&file, 64).is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn out_of_range_fields_are_named() {
+        let config = ProceduralSkyConfig {
+            face_size: 8,
+            star_density: 2.0,
+            sun: Some(ProceduralSunConfig {
+                direction: Some(Vec3::ZERO),
+                ..default()
+            }),
+            ..default()
+        };
+        assert_eq!(
+            config.out_of_range(),
+            ["face_size", "star_density", "sun.direction"]
+        );
+        assert!(ProceduralSkyConfig::default().out_of_range().is_empty());
+    }
+}
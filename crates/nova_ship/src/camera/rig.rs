@@ -7,6 +7,7 @@ use bevy_enhanced_input::prelude::*;
 use nova_gameplay::prelude::*;
 
 use super::{chase::ChaseCamera, handback::CameraHandbackBlend};
+use crate::input::keymap::{Keymap, KeymapAction};
 
 /// Marker component to identify the camera controller for the player's
 /// spaceship.
@@ -162,9 +163,10 @@ pub(super) fn insert_player_input(
     add: On<Add, SpaceshipCameraController>,
     mut commands: Commands,
     q_camera: Query<Entity, (With<ChaseCamera>, With<SpaceshipCameraController>)>,
+    keymap: Res<Keymap>,
 ) {
     let entity = add.entity;
-    trace!("insert_camera_turret: entity {:?}", entity);
+    trace!("insert_player_input: entity {:?}", entity);
 
     let Ok(camera) = q_camera.get(entity) else {
         error!(
@@ -175,37 +177,68 @@ pub(super) fn insert_player_input(
     };
 
     // Spawn a player input controller entity to hold the input from the player
-    commands.entity(camera).with_children(|parent| {
-        parent.spawn((
-            Name::new("Player Input Controller"),
-            PlayerInputMarker,
-            actions!(
-                PlayerInputMarker[
-                    (
-                        Name::new("Input: Camera Rotate"),
-                        Action::<CameraInputRotate>::new(),
-                        Bindings::spawn((
-                            // Bevy requires single entities to be wrapped in
-                            // `Spawn`. You can attach modifiers to individual
-                            // bindings as well.
-                            Spawn((Binding::mouse_motion(), Scale::splat(0.001), Negate::all())),
-                            Axial::right_stick().with((Scale::splat(2.0), Negate::none())),
-                        )),
-                    ),
-                    (
-                        Name::new("Input: Free Look Mode"),
-                        Action::<FreeLookInput>::new(),
-                        bindings![KeyCode::AltLeft, GamepadButton::LeftTrigger],
-                    ),
-                    (
-                        Name::new("Input: Combat Mode"),
-                        Action::<CombatInput>::new(),
-                        bindings![MouseButton::Right, GamepadButton::LeftTrigger2],
-                    ),
-                ]
-            ),
-        ));
-    });
+    commands
+        .entity(camera)
+        .with_child(player_input_rig(&keymap));
+}
+
+/// The camera controller's action rig: look rotation plus the held free-look
+/// and combat (raise weapons) modes and the cockpit view toggle, the buttons
+/// bound from the player's [`Keymap`]. The look axes are gestures, not keymap rows, and stay fixed.
+fn player_input_rig(keymap: &Keymap) -> impl Bundle {
+    (
+        Name::new("Player Input Controller"),
+        PlayerInputMarker,
+        actions!(
+            PlayerInputMarker[
+                (
+                    Name::new("Input: Camera Rotate"),
+                    Action::<CameraInputRotate>::new(),
+                    Bindings::spawn((
+                        // Bevy requires single entities to be wrapped in
+                        // `Spawn`. You can attach modifiers to individual
+                        // bindings as well.
+                        Spawn((Binding::mouse_motion(), Scale::splat(0.001), Negate::all())),
+                        Axial::right_stick().with((Scale::splat(2.0), Negate::none())),
+                    )),
+                ),
+                (
+                    Name::new("Input: Free Look Mode"),
+                    Action::<FreeLookInput>::new(),
+                    Bindings::spawn(keymap.bindings(KeymapAction::FreeLook)),
+                ),
+                (
+                    Name::new("Input: Combat Mode"),
+                    Action::<CombatInput>::new(),
+                    Bindings::spawn(keymap.bindings(KeymapAction::RaiseWeapons)),
+                ),
+                (
+                    Name::new("Input: Cockpit View"),
+                    Action::<CockpitViewInput>::new(),
+                    Bindings::spawn(keymap.bindings(KeymapAction::CockpitView)),
+                ),
+            ]
+        ),
+    )
+}
+
+/// Rebuild the camera controller's action rig from the [`Keymap`] after a
+/// remap, the camera twin of the flight rig's respawn. The mode derivation
+/// re-reads the fresh rig's (released) triggers next frame.
+pub(super) fn respawn_player_input_on_keymap_change(
+    keymap: Res<Keymap>,
+    mut commands: Commands,
+    q_rig: Query<(Entity, &ChildOf), With<PlayerInputMarker>>,
+) {
+    if keymap.is_added() {
+        return;
+    }
+    for (rig, &ChildOf(camera)) in &q_rig {
+        commands.entity(rig).despawn();
+        commands
+            .entity(camera)
+            .with_child(player_input_rig(&keymap));
+    }
 }
 
 pub(super) fn destroy_camera_controller(
@@ -247,3 +280,7 @@ pub(super) struct FreeLookInput;
 #[derive(InputAction)]
 #[action_output(bool)]
 pub(super) struct CombatInput;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+pub(super) struct CockpitViewInput;
@@ -122,6 +122,8 @@ pub(super) fn autopilot_system(
     // target radius), inheriting the same statement: a ship target never
     // contributes a well radius - ships stay center-relative.
     q_wells: Query<(&Position, &GravityWell), Without<SpaceshipRootMarker>>,
+    // A GOTO at a docking port parks at the port's own approach distance.
+    q_ports: Query<&DockingPort>,
 ) {
     let dt = time.delta_secs();
 
@@ -292,95 +294,96 @@ pub(super) fn autopilot_system(
         // (`target_radius`, zero for unsized targets and GotoPos), so a big
         // body is given its size instead of being treated as a point.
         // Published distances are surface-relative too.
-        let arrival_desired = |goal: Vec3, target_radius: f32| -> (Vec3, ManeuverTelemetry) {
-            let standoff = arrival_standoff + target_radius.max(0.0);
-            let to_target = goal - position.0;
-            let distance = to_target.length();
-            // Zero only if the ship sits exactly on the goal center; the
-            // else branch below has distance > standoff > 0, so there the
-            // fallback never engages.
-            let closing_dir = to_target.normalize_or_zero();
-            let closing_speed = velocity.dot(closing_dir);
-            // Where the leg rests: the standoff boundary on the closing
-            // line. Capped at the ship's own distance so at or inside the
-            // envelope it degenerates to the ship position - the computer
-            // stops there, it never flies back out to the boundary.
-            let park_point = goal - closing_dir * standoff.min(distance);
-            if distance <= standoff {
-                (
-                    Vec3::ZERO,
-                    ManeuverTelemetry {
-                        goal,
-                        goal_entity: None,
-                        park_point,
-                        distance: (distance - target_radius.max(0.0)).max(0.0),
-                        closing_speed,
-                        brake_accel: 0.0,
-                        flip_point: None,
-                        seconds_to_flip: None,
-                        eta: None,
-                    },
-                )
-            } else {
-                let brake_dir = -closing_dir;
-                let brake_speed = velocity.length().max(settings.min_approach_speed);
-                let (accel, lead) = braking_plan(brake_dir, brake_speed);
-                let gravity = gravity_along(goal - closing_dir * standoff, closing_dir);
-                // The published deceleration is the effective one, so any
-                // instrument reading it sees the plan the computer actually
-                // flies (the field is currently write-only in the HUD).
-                // Zero means the pull exceeds the brake authority: no
-                // stopping plan (flip/eta are None and the desired velocity
-                // is zero - brake flat out).
-                let brake_accel = (accel * settings.decel_margin - gravity).max(0.0);
-                if brake_accel <= 0.0 && prev_telemetry.is_none_or(|t| t.brake_accel > 0.0) {
-                    // Once per degradation entry, not per tick: the
-                    // previous published plan still had brake authority.
-                    debug!(
-                        "autopilot_system: well pull {gravity} exceeds brake authority \
+        let arrival_desired =
+            |goal: Vec3, target_radius: f32, arrival_standoff: f32| -> (Vec3, ManeuverTelemetry) {
+                let standoff = arrival_standoff + target_radius.max(0.0);
+                let to_target = goal - position.0;
+                let distance = to_target.length();
+                // Zero only if the ship sits exactly on the goal center; the
+                // else branch below has distance > standoff > 0, so there the
+                // fallback never engages.
+                let closing_dir = to_target.normalize_or_zero();
+                let closing_speed = velocity.dot(closing_dir);
+                // Where the leg rests: the standoff boundary on the closing
+                // line. Capped at the ship's own distance so at or inside the
+                // envelope it degenerates to the ship position - the computer
+                // stops there, it never flies back out to the boundary.
+                let park_point = goal - closing_dir * standoff.min(distance);
+                if distance <= standoff {
+                    (
+                        Vec3::ZERO,
+                        ManeuverTelemetry {
+                            goal,
+                            goal_entity: None,
+                            park_point,
+                            distance: (distance - target_radius.max(0.0)).max(0.0),
+                            closing_speed,
+                            brake_accel: 0.0,
+                            flip_point: None,
+                            seconds_to_flip: None,
+                            eta: None,
+                        },
+                    )
+                } else {
+                    let brake_dir = -closing_dir;
+                    let brake_speed = velocity.length().max(settings.min_approach_speed);
+                    let (accel, lead) = braking_plan(brake_dir, brake_speed);
+                    let gravity = gravity_along(goal - closing_dir * standoff, closing_dir);
+                    // The published deceleration is the effective one, so any
+                    // instrument reading it sees the plan the computer actually
+                    // flies (the field is currently write-only in the HUD).
+                    // Zero means the pull exceeds the brake authority: no
+                    // stopping plan (flip/eta are None and the desired velocity
+                    // is zero - brake flat out).
+                    let brake_accel = (accel * settings.decel_margin - gravity).max(0.0);
+                    if brake_accel <= 0.0 && prev_telemetry.is_none_or(|t| t.brake_accel > 0.0) {
+                        // Once per degradation entry, not per tick: the
+                        // previous published plan still had brake authority.
+                        debug!(
+                            "autopilot_system: well pull {gravity} exceeds brake authority \
                          on the arrival leg of {ship:?}; no stopping plan"
-                    );
-                }
-                let flip = goto_flip_point(
-                    distance,
-                    closing_speed,
-                    accel * settings.decel_margin,
-                    lead,
-                    standoff,
-                    gravity,
-                );
-                let eta = arrival_eta(
-                    distance,
-                    closing_speed,
-                    accel * settings.decel_margin,
-                    lead,
-                    standoff,
-                    gravity,
-                );
-                (
-                    goto_desired_velocity(
-                        to_target,
-                        standoff,
-                        accel,
-                        settings.decel_margin,
+                        );
+                    }
+                    let flip = goto_flip_point(
+                        distance,
+                        closing_speed,
+                        accel * settings.decel_margin,
                         lead,
-                        settings.min_approach_speed,
+                        standoff,
                         gravity,
-                    ),
-                    ManeuverTelemetry {
-                        goal,
-                        goal_entity: None,
-                        park_point,
-                        distance: (distance - target_radius.max(0.0)).max(0.0),
+                    );
+                    let eta = arrival_eta(
+                        distance,
                         closing_speed,
-                        brake_accel,
-                        flip_point: flip.map(|(from_goal, _)| goal - closing_dir * from_goal),
-                        seconds_to_flip: flip.map(|(_, seconds)| seconds),
-                        eta,
-                    },
-                )
-            }
-        };
+                        accel * settings.decel_margin,
+                        lead,
+                        standoff,
+                        gravity,
+                    );
+                    (
+                        goto_desired_velocity(
+                            to_target,
+                            standoff,
+                            accel,
+                            settings.decel_margin,
+                            lead,
+                            settings.min_approach_speed,
+                            gravity,
+                        ),
+                        ManeuverTelemetry {
+                            goal,
+                            goal_entity: None,
+                            park_point,
+                            distance: (distance - target_radius.max(0.0)).max(0.0),
+                            closing_speed,
+                            brake_accel,
+                            flip_point: flip.map(|(from_goal, _)| goal - closing_dir * from_goal),
+                            seconds_to_flip: flip.map(|(_, seconds)| seconds),
+                            eta,
+                        },
+                    )
+                }
+            };
 
         // The goal, as a desired velocity right now. GOTO and STOP legs
         // also publish their live numbers as [`ManeuverTelemetry`] for the
@@ -465,10 +468,16 @@ pub(super) fn autopilot_system(
                         .get(target)
                         .map_or(0.0, |(_, well)| well.body_radius),
                 );
+                // A docking port is a point with its own standoff: the
+                // approach, not the global park distance.
+                let standoff = q_ports
+                    .get(target)
+                    .map_or(arrival_standoff, |port| port.approach);
                 let goal_position = target_position
                     .map(|p| p.0)
                     .unwrap_or_else(|| target_transform.translation());
-                let (desired, mut numbers) = arrival_desired(goal_position, target_radius);
+                let (desired, mut numbers) =
+                    arrival_desired(goal_position, target_radius, standoff);
                 // Arrived means INSIDE the park envelope, not merely
                 // "wants zero velocity": the degraded no-stopping-plan
                 // state also zeroes the desired velocity arbitrarily far
@@ -477,14 +486,14 @@ pub(super) fn autopilot_system(
                 // correction assumes it starts near the ring. The
                 // published distance is surface-relative, so the
                 // envelope test is against the bare standoff.
-                goto_arrived = numbers.distance <= arrival_standoff;
+                goto_arrived = numbers.distance <= standoff;
                 numbers.goal_entity = Some(target);
                 telemetry = Some(numbers);
                 desired
             }
             AutopilotAction::GotoPos { position } => {
                 // A bare position has no size: center-relative, as before.
-                let (desired, numbers) = arrival_desired(position, 0.0);
+                let (desired, numbers) = arrival_desired(position, 0.0, arrival_standoff);
                 telemetry = Some(numbers);
                 desired
             }
@@ -0,0 +1,325 @@
+//! Docking: the ports a station offers, the GOTO leg the computer flies to one,
+//! and the latch that holds a ship there until it burns away.
+//!
+//! A port is not a new maneuver. `G` on a station picks its nearest free
+//! [`DockingPort`] and engages an ordinary GOTO at it, tagged with a
+//! [`DockApproach`]; the arrival rule parks the ship [`DockingPort::approach`]
+//! from the port instead of the usual standoff. When that GOTO completes - the
+//! ship at rest, the engines cool - [`latch_docking_ships`] checks the ship is
+//! inside the port's [`DockingPort::latch_range`] and latches it with
+//! [`Docked`]. A trip that ends anywhere else (a manual burn, a new verb, the
+//! port gone) simply drops the approach.
+//!
+//! A docked ship is held at rest against its port by [`hold_docked_ships`],
+//! and any flight input releases it: a manual burn, or engaging any autopilot
+//! verb. What the station does for a docked ship is its [`StationServices`];
+//! the services themselves are NOVA OS verbs, a refit is picked in the
+//! editor's refit bay ([`OpenRefitBay`]) and answered by the object layer
+//! through [`RefitSection`], like a damage-control rebuild.
+
+use avian3d::prelude::*;
+use bevy::prelude::*;
+use nova_gameplay::prelude::*;
+
+use crate::prelude::*;
+
+/// Fastest a ship may drift (u/s) and still latch at the end of its approach.
+/// The GOTO only completes at rest, so this is slack for the settle, not a
+/// second arrival rule.
+const LATCH_SPEED: f32 = 1.0;
+
+/// A docking port, on a child entity of its station. Its position is where a
+/// docked ship is held off from.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component)]
+pub struct DockingPort {
+    /// How far from the port (world units) the approach GOTO comes to rest:
+    /// the per-leg standoff, measured from the port itself rather than a
+    /// surface.
+    pub approach: f32,
+    /// How close to the port (world units) a ship at rest must be to latch.
+    /// Larger than `approach`, so a settled approach always latches.
+    pub latch_range: f32,
+}
+
+impl Default for DockingPort {
+    fn default() -> Self {
+        Self {
+            approach: 12.0,
+            latch_range: 20.0,
+        }
+    }
+}
+
+/// On a ship root while its autopilot flies to a docking port. Removed when
+/// the ship latches or the trip is abandoned.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct DockApproach {
+    /// The port being approached.
+    pub port: Entity,
+}
+
+/// On a ship root latched to a station's port. Scenario events, the station
+/// services and the HUD all read this; removing it is undocking.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Docked {
+    /// The port the ship is latched to.
+    pub port: Entity,
+    /// The station that owns the port.
+    pub station: Entity,
+}
+
+/// What a station does for a ship docked at it, on the station root. Authored
+/// on the station object; an omitted field offers the service.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct StationServices {
+    /// Fills every weapon magazine.
+    pub rearm: bool,
+    /// Restores every standing section and restocks damage-control parts.
+    pub repair: bool,
+    /// Swaps a section for another catalog part of the same kind.
+    pub refit: bool,
+}
+
+impl Default for StationServices {
+    fn default() -> Self {
+        Self {
+            rearm: true,
+            repair: true,
+            refit: true,
+        }
+    }
+}
+
+/// A station refit: replace the section `section` on the ship root `entity`
+/// with the catalog part `part`, at the same mount. Answered by whoever
+/// spawned the ship.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct RefitSection {
+    /// The ship root.
+    pub entity: Entity,
+    /// The section's scenario id.
+    pub section: String,
+    /// The [`GameSections`] id of the part going on.
+    pub part: String,
+}
+
+/// Open the refit bay for the docked ship root `entity`: the editor's parts
+/// gallery, listing what the station can swap in. Raised by the NOVA OS and
+/// answered by the editor, which confirms through [`RefitSection`].
+#[derive(EntityEvent, Clone, Debug)]
+pub struct OpenRefitBay {
+    /// The ship root.
+    pub entity: Entity,
+}
+
+/// The port of a station a ship at `from` should dock at: the nearest of
+/// `ports` (entity, world position) that is not in `taken`.
+pub fn nearest_free_port(
+    from: Vec3,
+    ports: impl IntoIterator<Item = (Entity, Vec3)>,
+    taken: &[Entity],
+) -> Option<Entity> {
+    ports
+        .into_iter()
+        .filter(|(port, _)| !taken.contains(port))
+        .min_by(|(_, a), (_, b)| {
+            a.distance_squared(from)
+                .total_cmp(&b.distance_squared(from))
+        })
+        .map(|(port, _)| port)
+}
+
+/// The catalog parts a section built from `current` may be refitted with:
+/// every other part of the same kind, in catalog order.
+pub fn refit_parts<'a>(sections: &'a GameSections, current: &str) -> Vec<&'a SectionConfig> {
+    let Some(fitted) = sections.get_section(current) else {
+        return Vec::new();
+    };
+    let kind = std::mem::discriminant(&fitted.kind);
+    sections
+        .iter()
+        .filter(|config| config.base.id != current)
+        .filter(|config| std::mem::discriminant(&config.kind) == kind)
+        .collect()
+}
+
+/// Latch ships whose approach GOTO has finished at their port, and drop the
+/// approach of any ship whose trip went elsewhere.
+pub(super) fn latch_docking_ships(
+    mut commands: Commands,
+    q_ship: Query<
+        (
+            Entity,
+            &DockApproach,
+            Option<&Autopilot>,
+            &Position,
+            &LinearVelocity,
+        ),
+        With<SpaceshipRootMarker>,
+    >,
+    q_port: Query<(&DockingPort, &GlobalTransform, &ChildOf)>,
+) {
+    for (ship, approach, autopilot, position, velocity) in &q_ship {
+        let Ok((port, frame, &ChildOf(station))) = q_port.get(approach.port) else {
+            debug!("latch_docking_ships: port of {ship:?} is gone, dropping the approach");
+            commands.entity(ship).remove::<DockApproach>();
+            continue;
+        };
+        match autopilot.map(|autopilot| autopilot.action) {
+            // Still flying the approach.
+            Some(AutopilotAction::Goto { target }) if target == approach.port => continue,
+            // Re-tasked mid-approach.
+            Some(_) => {
+                commands.entity(ship).remove::<DockApproach>();
+            }
+            None => {
+                let distance = position.0.distance(frame.translation());
+                if distance <= port.latch_range && velocity.length() <= LATCH_SPEED {
+                    debug!("latch_docking_ships: {ship:?} latched to {station:?}");
+                    commands
+                        .entity(ship)
+                        .remove::<DockApproach>()
+                        .insert(Docked {
+                            port: approach.port,
+                            station,
+                        });
+                } else {
+                    debug!("latch_docking_ships: {ship:?} ended its approach {distance} u out");
+                    commands.entity(ship).remove::<DockApproach>();
+                }
+            }
+        }
+    }
+}
+
+/// Hold docked ships at rest, and release any whose pilot burned, engaged a
+/// verb, or whose port went away.
+pub(super) fn hold_docked_ships(
+    mut commands: Commands,
+    mut q_ship: Query<
+        (
+            Entity,
+            &Docked,
+            Option<&FlightIntent>,
+            Has<Autopilot>,
+            &mut LinearVelocity,
+            &mut AngularVelocity,
+        ),
+        With<SpaceshipRootMarker>,
+    >,
+    q_port: Query<(), With<DockingPort>>,
+) {
+    for (ship, docked, intent, engaged, mut linear, mut angular) in &mut q_ship {
+        let burning = intent.is_some_and(|intent| intent.burn > 0.0);
+        if burning || engaged || !q_port.contains(docked.port) {
+            debug!("hold_docked_ships: {ship:?} undocking");
+            commands.entity(ship).remove::<Docked>();
+            continue;
+        }
+        linear.0 = Vec3::ZERO;
+        angular.0 = Vec3::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn spawn_port(world: &mut World, at: Vec3) -> (Entity, Entity) {
+        let station = world.spawn(StationServices::default()).id();
+        let port = world
+            .spawn((
+                DockingPort::default(),
+                GlobalTransform::from_translation(at),
+                ChildOf(station),
+            ))
+            .id();
+        (station, port)
+    }
+
+    fn spawn_ship(world: &mut World, at: Vec3, speed: f32) -> Entity {
+        world
+            .spawn((
+                SpaceshipRootMarker,
+                Position(at),
+                LinearVelocity(Vec3::X * speed),
+                AngularVelocity::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn the_nearest_untaken_port_is_picked() {
+        let mut world = World::new();
+        let near = world.spawn_empty().id();
+        let far = world.spawn_empty().id();
+        let ports = [(near, Vec3::X * 10.0), (far, Vec3::X * 30.0)];
+
+        assert_eq!(nearest_free_port(Vec3::ZERO, ports, &[]), Some(near));
+        assert_eq!(nearest_free_port(Vec3::ZERO, ports, &[near]), Some(far));
+        assert_eq!(nearest_free_port(Vec3::ZERO, ports, &[near, far]), None);
+    }
+
+    /// A finished approach inside the latch range latches; one that ended
+    /// outside it, or was re-tasked, only drops the approach.
+    #[test]
+    fn a_finished_approach_latches_only_at_the_port() {
+        let mut world = World::new();
+        let (station, port) = spawn_port(&mut world, Vec3::ZERO);
+        let arrived = spawn_ship(&mut world, Vec3::X * 12.0, 0.1);
+        let short = spawn_ship(&mut world, Vec3::X * 80.0, 0.0);
+        let flying = spawn_ship(&mut world, Vec3::X * 200.0, 30.0);
+        let retasked = spawn_ship(&mut world, Vec3::X * 12.0, 0.0);
+        for ship in [arrived, short, flying, retasked] {
+            world.entity_mut(ship).insert(DockApproach { port });
+        }
+        world
+            .entity_mut(flying)
+            .insert(Autopilot::engage(AutopilotAction::Goto { target: port }));
+        world
+            .entity_mut(retasked)
+            .insert(Autopilot::engage(AutopilotAction::Stop));
+
+        world.run_system_once(latch_docking_ships).unwrap();
+
+        assert_eq!(
+            world.get::<Docked>(arrived),
+            Some(&Docked { port, station })
+        );
+        for ship in [short, retasked] {
+            assert!(world.get::<Docked>(ship).is_none());
+            assert!(world.get::<DockApproach>(ship).is_none());
+        }
+        assert!(
+            world.get::<DockApproach>(flying).is_some(),
+            "a ship still flying the approach keeps it"
+        );
+    }
+
+    /// A docked ship is held at rest until its pilot burns.
+    #[test]
+    fn a_docked_ship_is_held_until_it_burns() {
+        let mut world = World::new();
+        let (station, port) = spawn_port(&mut world, Vec3::ZERO);
+        let ship = spawn_ship(&mut world, Vec3::X * 12.0, 3.0);
+        world
+            .entity_mut(ship)
+            .insert((Docked { port, station }, FlightIntent::default()));
+
+        world.run_system_once(hold_docked_ships).unwrap();
+        assert_eq!(world.get::<LinearVelocity>(ship).unwrap().0, Vec3::ZERO);
+        assert!(world.get::<Docked>(ship).is_some());
+
+        world.get_mut::<FlightIntent>(ship).unwrap().burn = 1.0;
+        world.run_system_once(hold_docked_ships).unwrap();
+        assert!(world.get::<Docked>(ship).is_none(), "a burn undocks");
+    }
+}
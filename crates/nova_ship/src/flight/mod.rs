@@ -15,6 +15,9 @@
 //!     flip at the arrival curve (`v_allowed = sqrt(2 * a * margin * d)`),
 //!     decelerate, and come to rest at a standoff outside blast radius.
 //!
+//!   - `G` on a **station** flies the same GOTO to its nearest free docking
+//!     port and latches there (see `docking`).
+//!
 //!   Both are one rule: compute the desired velocity for the goal, face the
 //!   velocity *error*, and burn when aligned - the flip emerges naturally the
 //!   moment the error points backward. While engaged, the ship stops
@@ -38,6 +41,7 @@ use nova_gameplay::prelude::*;
 use crate::prelude::*;
 
 mod autopilot;
+mod docking;
 mod guidance;
 mod manual;
 mod state;
@@ -49,28 +53,36 @@ mod tests;
 // Only the input layer's turn-rate tests derive the rate independently.
 #[cfg(test)]
 pub(crate) use self::guidance::hull_turn_rate;
-pub use self::state::{
-    Autopilot, AutopilotAction, AutopilotPhase, BodyRadius, FlightArrivalStandoff, FlightIntent,
-    FlightSettings, FlightSpeedCap, ManeuverTelemetry, OrbitPlan, RcsActive, RcsIntent,
-    RcsReference, RcsSpeedCap,
-};
 use self::{
     autopilot::{autopilot_system, on_autopilot_removed_cool_engines},
+    docking::{hold_docked_ships, latch_docking_ships},
     manual::{decay_player_rcs_intent, manual_burn_system, rcs_burn_system},
     state::remove_maneuver_telemetry,
 };
+pub use self::{
+    docking::{
+        nearest_free_port, refit_parts, DockApproach, Docked, DockingPort, OpenRefitBay,
+        RefitSection, StationServices,
+    },
+    state::{
+        Autopilot, AutopilotAction, AutopilotPhase, BodyRadius, FlightArrivalStandoff,
+        FlightIntent, FlightSettings, FlightSpeedCap, ManeuverTelemetry, OrbitPlan, RcsActive,
+        RcsIntent, RcsReference, RcsSpeedCap,
+    },
+};
 pub(crate) use self::{
     guidance::{ship_turn_rate, slew_rotation},
     manual::accumulate_rcs_axis,
 };
 
 /// The flight intent, settings and speed caps, the autopilot and orbit plan, RCS state, maneuver
-/// telemetry, and `NovaFlightPlugin` with `NovaFlightSystems`.
+/// telemetry, docking, and `NovaFlightPlugin` with `NovaFlightSystems`.
 pub mod prelude {
     pub use super::{
-        Autopilot, AutopilotAction, AutopilotPhase, BodyRadius, FlightArrivalStandoff,
-        FlightIntent, FlightSettings, FlightSpeedCap, ManeuverTelemetry, NovaFlightPlugin,
-        NovaFlightSystems, OrbitPlan, RcsActive, RcsIntent, RcsSpeedCap,
+        nearest_free_port, refit_parts, Autopilot, AutopilotAction, AutopilotPhase, BodyRadius,
+        DockApproach, Docked, DockingPort, FlightArrivalStandoff, FlightIntent, FlightSettings,
+        FlightSpeedCap, ManeuverTelemetry, NovaFlightPlugin, NovaFlightSystems, OpenRefitBay,
+        OrbitPlan, RcsActive, RcsIntent, RcsSpeedCap, RefitSection, StationServices,
     };
 }
 
@@ -109,7 +121,11 @@ impl Plugin for NovaFlightPlugin {
             .register_type::<RcsIntent>()
             .register_type::<RcsSpeedCap>()
             .register_type::<RcsReference>()
-            .register_type::<RcsActive>();
+            .register_type::<RcsActive>()
+            .register_type::<DockingPort>()
+            .register_type::<DockApproach>()
+            .register_type::<Docked>()
+            .register_type::<StationServices>();
 
         app.add_observer(insert_flight_control);
         app.add_observer(on_autopilot_removed_cool_engines);
@@ -134,6 +150,11 @@ impl Plugin for NovaFlightPlugin {
             FixedUpdate,
             (
                 autopilot_system,
+                // After the computer so a finished approach latches on the
+                // tick it completes; before the burn so a held ship releases
+                // on the tick its pilot burns.
+                latch_docking_ships,
+                hold_docked_ships,
                 manual_burn_system,
                 rcs_burn_system,
                 decay_player_rcs_intent,
@@ -138,16 +138,19 @@ fn pick_ai_target(
     reason = "one query term per target-selection input"
 )]
 pub(super) fn update_ai_target(
-    q_candidates: Query<(
-        Entity,
-        &Transform,
-        Option<&ComputedCenterOfMass>,
-        Option<&Allegiance>,
-        Has<SpaceshipRootMarker>,
-        Option<&TorpedoProjectileMarker>,
-        Option<&TorpedoTargetChosen>,
-        Has<NeutralizedMarker>,
-    )>,
+    q_candidates: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&ComputedCenterOfMass>,
+            Option<&Allegiance>,
+            Has<SpaceshipRootMarker>,
+            Option<&TorpedoProjectileMarker>,
+            Option<&TorpedoTargetChosen>,
+            Has<NeutralizedMarker>,
+        ),
+        Without<GhostHullMarker>,
+    >,
     mut q_spaceship: Query<
         (
             Entity,
@@ -43,9 +43,10 @@ use crate::{input::point_defense::mount_may_shoot, prelude::*};
 ///   how long a committed ship flies before it can shoot.
 /// - `AI_THREAT_AIM_RANGE` (`threat.rs`) - tracks the gate: a nose held on
 ///   me from beyond weapon reach is not a threat yet.
-/// - `EFFECTIVE_RANGE_MARGIN` (`nova_authoring::balance`) - mirrors THIS
-///   constant to derive each ship's audited threat envelope. A lifetime
-///   change is a balance-audit change; re-run `balance_audit_gate`.
+/// - `EFFECTIVE_RANGE_MARGIN` (`nova_scenario::threat`) - mirrors THIS
+///   constant to derive each ship's threat envelope, which the balance audit
+///   grades and the survival generator spawns outside. A lifetime change is a
+///   balance-audit change; re-run `balance_audit_gate`.
 ///
 /// The factor stays at 0.9 rather than tightening to the ~0.75 that would be
 /// strictly safe against a target fleeing at the player's 25 u/s speed cap.
@@ -111,7 +112,9 @@ pub(super) fn update_turret_target_input(
     >,
     q_target: Query<(&Transform, Option<&ComputedCenterOfMass>)>,
     q_target_velocity: Query<&LinearVelocity>,
+    policy: Option<Res<DifficultyPolicy>>,
 ) {
+    let lead = policy.map_or(1.0, |policy| policy.ai_lead);
     // Iterated turret-first, not ship-first: every turret now resolves its own
     // gun target, so there is nothing left to hoist out of the inner loop.
     for (
@@ -125,17 +128,21 @@ pub(super) fn update_turret_target_input(
         let Ok((state, target, ship_defense)) = q_spaceship.get(*ship) else {
             continue;
         };
-        let (gun_target, _) = ai_turret_gun_target(turret_defense, ship_defense, state, target);
+        let (gun_target, defending) =
+            ai_turret_gun_target(turret_defense, ship_defense, state, target);
         // Aim at the live structure: fire converging on the root origin lands
         // in empty space once the front sections die.
         let aim = ai_target_anchor(gun_target, &q_target);
         // Feed the target root's velocity alongside the position so
         // lead_intercept_point computes a real lead for AI turrets - the AI-
         // side sibling of the player lock feed. The solve is shooter-frame-
-        // correct on its own.
+        // correct on its own. The difficulty's lead fraction is applied to
+        // offensive fire only: an easy enemy misses YOU, it does not forget
+        // how to shoot down a torpedo.
+        let lead = if defending { 1.0 } else { lead };
         let velocity = aim
             .and_then(|_| gun_target.and_then(|entity| q_target_velocity.get(entity).ok()))
-            .map(|velocity| **velocity)
+            .map(|velocity| **velocity * lead)
             .unwrap_or(Vec3::ZERO);
         **turret_input = aim;
         **turret_velocity = velocity;
@@ -149,7 +156,9 @@ pub(super) fn update_turret_target_input(
 /// The free-running burst cycle of an AI ship's guns: fire for
 /// `AI_BURST_FIRE_SECS`, hold for `AI_BURST_HOLD_SECS`, repeat. A ship
 /// fires only while the window is open (and every other gate passes), so AI
-/// fire reads as deliberate bursts instead of a continuous hose. Required by
+/// fire reads as deliberate bursts instead of a continuous hose. The hold is
+/// the AI's reaction delay, so the difficulty's `ai_burst_hold` stretches or
+/// shortens it; the fire window is fixed. Required by
 /// [`AISpaceshipMarker`]; ticked by `update_fire_cadence`.
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component)]
@@ -174,13 +183,20 @@ impl Default for AIFireCadence {
 impl AIFireCadence {
     /// Advance the cycle, flipping between fire and hold phases.
     pub(crate) fn tick(&mut self, delta: f32) {
+        self.tick_scaled(delta, 1.0);
+    }
+
+    /// [`tick`](Self::tick) with the hold phase scaled by `hold_scale`. The
+    /// scale is read when a hold STARTS, so a difficulty change lands on the
+    /// next hold rather than cutting the current one short.
+    pub(crate) fn tick_scaled(&mut self, delta: f32, hold_scale: f32) {
         self.timer.tick(delta);
         if self.timer.ready() {
             self.firing = !self.firing;
             let phase = if self.firing {
                 AI_BURST_FIRE_SECS
             } else {
-                AI_BURST_HOLD_SECS
+                AI_BURST_HOLD_SECS * hold_scale
             };
             // trigger_for, not trigger: the two phases have different lengths,
             // so the wait is set per phase rather than from one duration.
@@ -195,9 +211,11 @@ impl AIFireCadence {
 pub(super) fn update_fire_cadence(
     time: Res<Time>,
     mut q_spaceship: Query<&mut AIFireCadence, With<AISpaceshipMarker>>,
+    policy: Option<Res<DifficultyPolicy>>,
 ) {
+    let hold_scale = policy.map_or(1.0, |policy| policy.ai_burst_hold);
     for mut cadence in &mut q_spaceship {
-        cadence.tick(time.delta_secs());
+        cadence.tick_scaled(time.delta_secs(), hold_scale);
     }
 }
 
@@ -495,6 +513,18 @@ mod fire_discipline_tests {
         assert!(cadence.firing, "hold over: next burst");
     }
 
+    #[test]
+    fn the_difficulty_hold_scale_stretches_only_the_hold() {
+        let mut cadence = AIFireCadence::default();
+        cadence.tick_scaled(AI_BURST_FIRE_SECS + 0.01, 2.0);
+        assert!(!cadence.firing, "the fire window is not scaled");
+
+        cadence.tick_scaled(AI_BURST_HOLD_SECS + 0.01, 2.0);
+        assert!(!cadence.firing, "a doubled hold is still holding");
+        cadence.tick_scaled(AI_BURST_HOLD_SECS, 2.0);
+        assert!(cadence.firing, "doubled hold over: next burst");
+    }
+
     #[test]
     fn a_closed_burst_window_holds_fire_even_when_aligned() {
         let (mut world, turret, _) = firing_world(Vec3::new(0.0, 0.0, -100.0), Vec3::ZERO);
@@ -12,7 +12,7 @@
 //! layers treat AI and player ships identically. See the AI/behavior wiki page
 //! for the state-machine design.
 
-use bevy::prelude::*;
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
 use nova_gameplay::prelude::*;
 
 mod acquisition;
@@ -86,9 +86,10 @@ impl AIEngageGrace {
 ///
 /// The plugin straddles two schedules: `update_fire_cadence` alone runs in
 /// `FixedUpdate` so the burst clock does not move with the framerate, and
-/// everything else runs in `Update` because it reads eased poses. Both halves
-/// sit in [`SpaceshipInputSystems`](super::SpaceshipInputSystems), so the pause
-/// and scenario-teardown gates cover them in either schedule.
+/// everything else runs in `Update` because it reads eased poses - except in
+/// [lockstep](nova_gameplay::lockstep), where it moves to `FixedUpdate` too.
+/// Both halves sit in [`SpaceshipInputSystems`](super::SpaceshipInputSystems),
+/// so the pause and scenario-teardown gates cover them in either schedule.
 pub struct SpaceshipAIInputPlugin;
 
 impl Plugin for SpaceshipAIInputPlugin {
@@ -141,33 +142,41 @@ impl Plugin for SpaceshipAIInputPlugin {
         // and in FixedUpdate of frame N those eased poses still hold frame
         // N-1's values. These are decision reads, not impulses, and they are
         // correct against the frame they are deciding for.
-        app.add_systems(
-            Update,
-            (
-                update_ai_target,
-                update_point_defense_target,
-                update_behavior_state,
-                update_passive_flight,
-                update_controller_target_rotation_torque,
-                on_thruster_input,
-                update_turret_target_input,
-                on_projectile_input,
-                // NOTE: commit-on-launch runs before the trigger write: the frame
-                // after a launch then sees the freshly reset bay cooldown
-                // and drops the trigger, instead of holding it one frame
-                // on the stale elapsed one.
-                update_torpedo_target_input,
-                update_torpedo_section_input,
-            )
-                .chain()
-                // The per-turret assignment moved out to the shared point-
-                // defence chain (it never depended on the AI), and the gun
-                // systems below read what it writes - so the whole AI chain
-                // now declares the edge the old in-chain position used to give
-                // it for free.
-                .after(super::point_defense::SpaceshipPointDefenseSystems)
-                .in_set(super::SpaceshipInputSystems),
-        );
+        //
+        // The exception is lockstep (a run being recorded or played back),
+        // which needs the decisions on the tick rather than the frame: there
+        // the same chain runs in FixedUpdate instead, over the raw poses the
+        // lockstep plugin settles at the top of every step.
+        for (schedule, lockstep) in [(Update.intern(), false), (FixedUpdate.intern(), true)] {
+            app.add_systems(
+                schedule,
+                (
+                    update_ai_target,
+                    update_point_defense_target,
+                    update_behavior_state,
+                    update_passive_flight,
+                    update_controller_target_rotation_torque,
+                    on_thruster_input,
+                    update_turret_target_input,
+                    on_projectile_input,
+                    // NOTE: commit-on-launch runs before the trigger write: the frame
+                    // after a launch then sees the freshly reset bay cooldown
+                    // and drops the trigger, instead of holding it one frame
+                    // on the stale elapsed one.
+                    update_torpedo_target_input,
+                    update_torpedo_section_input,
+                )
+                    .chain()
+                    // The per-turret assignment moved out to the shared point-
+                    // defence chain (it never depended on the AI), and the gun
+                    // systems below read what it writes - so the whole AI chain
+                    // now declares the edge the old in-chain position used to give
+                    // it for free.
+                    .after(super::point_defense::SpaceshipPointDefenseSystems)
+                    .in_set(super::SpaceshipInputSystems)
+                    .run_if(simulation_lockstep(lockstep)),
+            );
+        }
     }
 }
 
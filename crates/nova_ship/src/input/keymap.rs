@@ -0,0 +1,598 @@
+//! The player's remappable keymap: which keyboard/mouse and gamepad buttons
+//! drive each flight, weapon, targeting, camera and NOVA OS action.
+//!
+//! [`Keymap`] is the one binding source the input rigs are built from - the
+//! flight rig (`player::flight_input_rig`), the camera controller's action rig
+//! and the NOVA OS toggle all read it, and a change respawns the live rigs so a
+//! remap takes effect mid-flight. The settings menu edits it and persists the
+//! non-default entries ([`Keymap::overrides`]); the HUD's verb hints keep
+//! reading the LIVE `Bindings`, so they follow a remap without knowing about
+//! this module.
+//!
+//! What is NOT here: the axes (mouse look, the right stick, the scroll wheel,
+//! RCS mouse aim) are gestures rather than buttons and stay fixed, as do the
+//! system keys in [`FIXED_SOURCES`] (pause, HUD level, comms, scenario advance).
+//! Per-section weapon triggers belong to the ship, not the player, and are
+//! rebound in the editor and the NOVA OS `ship` app - both check a new trigger
+//! against this keymap through [`Keymap::owner_label`].
+
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+use super::player::InputSource;
+
+/// `Keymap`, its actions and bindings, the fixed system sources and the
+/// display-label helper.
+pub mod prelude {
+    pub use super::{
+        source_display_label, Keymap, KeymapAction, KeymapBinding, KeymapDevice, FIXED_SOURCES,
+    };
+}
+
+/// One remappable player action - a row on the settings Controls page.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub enum KeymapAction {
+    /// Analog main-drive burn.
+    MainDrive,
+    /// Engage/disengage the STOP maneuver.
+    AutopilotStop,
+    /// Engage/disengage GOTO on the travel lock.
+    AutopilotGoto,
+    /// Engage/disengage ORBIT around the dominant well.
+    AutopilotOrbit,
+    /// Plain autopilot off.
+    AutopilotOff,
+    /// The RCS fine-adjust modifier (held).
+    RcsModifier,
+    /// Raise weapons into the turret/combat stance (held).
+    RaiseWeapons,
+    /// The radar gesture: hold to search, tap to clear. Also the lock-cycle
+    /// modifier.
+    Radar,
+    /// Step the component fine-lock (or, with the radar key held, the ship
+    /// lock) forward.
+    CycleNext,
+    /// Step the component fine-lock (or ship lock) back.
+    CyclePrev,
+    /// Free-look the chase camera (held).
+    FreeLook,
+    /// Swap the chase camera for the cockpit seat and back.
+    CockpitView,
+    /// Open the NOVA OS (and close it from the pad).
+    NovaOs,
+}
+
+impl KeymapAction {
+    /// Every action, in settings-page reading order.
+    pub const ALL: [KeymapAction; 13] = [
+        KeymapAction::MainDrive,
+        KeymapAction::AutopilotStop,
+        KeymapAction::AutopilotGoto,
+        KeymapAction::AutopilotOrbit,
+        KeymapAction::AutopilotOff,
+        KeymapAction::RcsModifier,
+        KeymapAction::RaiseWeapons,
+        KeymapAction::Radar,
+        KeymapAction::CycleNext,
+        KeymapAction::CyclePrev,
+        KeymapAction::FreeLook,
+        KeymapAction::CockpitView,
+        KeymapAction::NovaOs,
+    ];
+
+    /// The grouping header the action sits under on the Controls page.
+    pub fn section(self) -> &'static str {
+        match self {
+            KeymapAction::MainDrive
+            | KeymapAction::AutopilotStop
+            | KeymapAction::AutopilotGoto
+            | KeymapAction::AutopilotOrbit
+            | KeymapAction::AutopilotOff
+            | KeymapAction::RcsModifier => "FLIGHT",
+            KeymapAction::RaiseWeapons => "WEAPONS",
+            KeymapAction::Radar | KeymapAction::CycleNext | KeymapAction::CyclePrev => "TARGETING",
+            KeymapAction::FreeLook | KeymapAction::CockpitView => "CAMERA",
+            KeymapAction::NovaOs => "NOVA OS",
+        }
+    }
+
+    /// What the action does, as the Controls page reads it. The two cycle rows
+    /// name their fixed wheel twin, which is not remappable.
+    pub fn label(self) -> &'static str {
+        match self {
+            KeymapAction::MainDrive => "Main Drive",
+            KeymapAction::AutopilotStop => "Autopilot: Stop",
+            KeymapAction::AutopilotGoto => "Autopilot: Go To",
+            KeymapAction::AutopilotOrbit => "Autopilot: Orbit",
+            KeymapAction::AutopilotOff => "Autopilot: Off",
+            KeymapAction::RcsModifier => "RCS Fine Adjust (hold)",
+            KeymapAction::RaiseWeapons => "Raise Weapons",
+            KeymapAction::Radar => "Radar (hold search / tap clear)",
+            KeymapAction::CycleNext => "Lock / Component Next (or Scroll Up)",
+            KeymapAction::CyclePrev => "Lock / Component Prev (or Scroll Down)",
+            KeymapAction::FreeLook => "Free Look",
+            KeymapAction::CockpitView => "Cockpit / Chase View",
+            KeymapAction::NovaOs => "Open NOVA OS",
+        }
+    }
+
+    /// The stable key the persisted settings store this action under. Never
+    /// rename one: a saved remap would silently fall back to the default.
+    pub fn id(self) -> &'static str {
+        match self {
+            KeymapAction::MainDrive => "main_drive",
+            KeymapAction::AutopilotStop => "autopilot_stop",
+            KeymapAction::AutopilotGoto => "autopilot_goto",
+            KeymapAction::AutopilotOrbit => "autopilot_orbit",
+            KeymapAction::AutopilotOff => "autopilot_off",
+            KeymapAction::RcsModifier => "rcs_modifier",
+            KeymapAction::RaiseWeapons => "raise_weapons",
+            KeymapAction::Radar => "radar",
+            KeymapAction::CycleNext => "cycle_next",
+            KeymapAction::CyclePrev => "cycle_prev",
+            KeymapAction::FreeLook => "free_look",
+            KeymapAction::CockpitView => "cockpit_view",
+            KeymapAction::NovaOs => "nova_os",
+        }
+    }
+
+    /// The action a persisted [`KeymapAction::id`] names, or `None` for an id
+    /// this build does not know (a newer store).
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.id() == id)
+    }
+
+    /// The shipped binding.
+    pub fn default_binding(self) -> KeymapBinding {
+        use InputSource::{Keyboard, Mouse};
+        let (keyboard, gamepad) = match self {
+            KeymapAction::MainDrive => (
+                [
+                    Some(Keyboard(KeyCode::KeyW)),
+                    Some(Keyboard(KeyCode::Space)),
+                ],
+                Some(GamepadButton::RightTrigger),
+            ),
+            KeymapAction::AutopilotStop => (
+                [Some(Keyboard(KeyCode::KeyX)), None],
+                Some(GamepadButton::East),
+            ),
+            KeymapAction::AutopilotGoto => (
+                [Some(Keyboard(KeyCode::KeyG)), None],
+                Some(GamepadButton::North),
+            ),
+            // South: the scenario-advance confirm (loader.rs) was moved off
+            // South to DPadDown so this pad press cannot both skip the
+            // scenario and toggle a parking maneuver.
+            KeymapAction::AutopilotOrbit => (
+                [Some(Keyboard(KeyCode::KeyO)), None],
+                Some(GamepadButton::South),
+            ),
+            KeymapAction::AutopilotOff => (
+                [Some(Keyboard(KeyCode::KeyZ)), None],
+                Some(GamepadButton::West),
+            ),
+            // No pad default. It was LeftTrigger2, which Raise Weapons also
+            // holds, so entering RCS on the pad raised the guns with it, and
+            // every other button is taken (LeftThumb is the sandbox's editor
+            // switch); a player who wants pad RCS binds it on the Controls page.
+            KeymapAction::RcsModifier => (
+                [
+                    Some(Keyboard(KeyCode::ShiftLeft)),
+                    Some(Keyboard(KeyCode::ShiftRight)),
+                ],
+                None,
+            ),
+            KeymapAction::RaiseWeapons => (
+                [Some(Mouse(MouseButton::Right)), None],
+                Some(GamepadButton::LeftTrigger2),
+            ),
+            // Pad: DPadUp, freed by the target cycle's retirement.
+            KeymapAction::Radar => (
+                [
+                    Some(Keyboard(KeyCode::ControlLeft)),
+                    Some(Keyboard(KeyCode::ControlRight)),
+                ],
+                Some(GamepadButton::DPadUp),
+            ),
+            KeymapAction::CycleNext => (
+                [Some(Keyboard(KeyCode::BracketRight)), None],
+                Some(GamepadButton::DPadRight),
+            ),
+            KeymapAction::CyclePrev => (
+                [Some(Keyboard(KeyCode::BracketLeft)), None],
+                Some(GamepadButton::DPadLeft),
+            ),
+            KeymapAction::FreeLook => (
+                [Some(Keyboard(KeyCode::AltLeft)), None],
+                Some(GamepadButton::LeftTrigger),
+            ),
+            // P for pilot's seat: C is the replay spectator's camera swap. No
+            // pad default - every button is taken (see RcsModifier).
+            KeymapAction::CockpitView => ([Some(Keyboard(KeyCode::KeyP)), None], None),
+            // RightThumb, the one free pad button when the NOVA OS landed.
+            KeymapAction::NovaOs => (
+                [Some(Keyboard(KeyCode::Tab)), None],
+                Some(GamepadButton::RightThumb),
+            ),
+        };
+        KeymapBinding { keyboard, gamepad }
+    }
+}
+
+/// Which half of a binding a rebind replaces.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+pub enum KeymapDevice {
+    /// The keyboard/mouse half.
+    Keyboard,
+    /// The gamepad half.
+    Gamepad,
+}
+
+/// One action's binding: up to two keyboard/mouse sources (the shipped
+/// defaults pair both Shift, both Control, or W with Space) and one gamepad
+/// button. Rebinding a half replaces it with the single captured source.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeymapBinding {
+    /// Keyboard keys or mouse buttons, primary first.
+    pub keyboard: [Option<InputSource>; 2],
+    /// The gamepad button, `None` when unbound.
+    pub gamepad: Option<GamepadButton>,
+}
+
+impl KeymapBinding {
+    /// Every source this binding occupies, keyboard/mouse first.
+    pub fn sources(&self) -> impl Iterator<Item = InputSource> + '_ {
+        self.keyboard
+            .iter()
+            .flatten()
+            .copied()
+            .chain(self.gamepad.map(InputSource::Gamepad))
+    }
+
+    /// The binding as enhanced-input [`Binding`]s, ready for a rig's
+    /// `Bindings::spawn`.
+    pub fn bindings(&self) -> Vec<Binding> {
+        self.sources()
+            .map(|source| match source {
+                InputSource::Keyboard(key) => Binding::from(key),
+                InputSource::Mouse(button) => Binding::from(button),
+                InputSource::Gamepad(button) => Binding::from(button),
+            })
+            .collect()
+    }
+
+    /// Whether the keyboard/mouse half went down this frame - for the few
+    /// actions read straight off `ButtonInput` rather than through a rig (the
+    /// NOVA OS toggle).
+    pub fn keyboard_just_pressed(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: Option<&ButtonInput<MouseButton>>,
+    ) -> bool {
+        self.keyboard.iter().flatten().any(|source| match source {
+            InputSource::Keyboard(key) => keys.just_pressed(*key),
+            InputSource::Mouse(button) => mouse.is_some_and(|mouse| mouse.just_pressed(*button)),
+            InputSource::Gamepad(_) => false,
+        })
+    }
+
+    /// Whether the gamepad half went down this frame. `None` input (no gamepad
+    /// plugin in a headless rig) reads as not pressed.
+    pub fn gamepad_just_pressed(&self, gamepad: Option<&ButtonInput<GamepadButton>>) -> bool {
+        self.gamepad
+            .zip(gamepad)
+            .is_some_and(|(button, gamepad)| gamepad.just_pressed(button))
+    }
+
+    /// The keyboard/mouse half for display (`W / Space`), or `Unbound`.
+    pub fn keyboard_label(&self) -> String {
+        let labels: Vec<String> = self
+            .keyboard
+            .iter()
+            .flatten()
+            .map(source_display_label)
+            .collect();
+        if labels.is_empty() {
+            "Unbound".to_string()
+        } else {
+            labels.join(" / ")
+        }
+    }
+
+    /// The gamepad half for display, or `Unbound`.
+    pub fn gamepad_label(&self) -> String {
+        self.gamepad
+            .map(|button| source_display_label(&InputSource::Gamepad(button)))
+            .unwrap_or_else(|| "Unbound".to_string())
+    }
+}
+
+/// The system controls that are read straight off `ButtonInput` and cannot be
+/// remapped, paired with what they do. A capture refuses them so a remap can
+/// never shadow the pause key or the HUD toggle.
+pub const FIXED_SOURCES: &[(InputSource, &str)] = &[
+    (InputSource::Keyboard(KeyCode::Escape), "pause / menu"),
+    (InputSource::Gamepad(GamepadButton::Start), "pause / menu"),
+    (InputSource::Keyboard(KeyCode::Backquote), "HUD level"),
+    (InputSource::Gamepad(GamepadButton::Select), "HUD level"),
+    (InputSource::Keyboard(KeyCode::KeyV), "comms dismiss"),
+    (InputSource::Keyboard(KeyCode::KeyB), "comms skip"),
+    (InputSource::Keyboard(KeyCode::Enter), "scenario advance"),
+    (
+        InputSource::Gamepad(GamepadButton::DPadDown),
+        "scenario advance",
+    ),
+    (InputSource::Keyboard(KeyCode::F1), "back to editor"),
+    (
+        InputSource::Gamepad(GamepadButton::LeftThumb),
+        "back to editor",
+    ),
+];
+
+/// A friendly name for a source, in the register the Controls page and the
+/// old static reference used: `Left Ctrl`, `]`, `Right Mouse`, and the
+/// Xbox-style face letters for the pad.
+pub fn source_display_label(source: &InputSource) -> String {
+    let fixed = match source {
+        InputSource::Keyboard(KeyCode::ControlLeft) => "Left Ctrl",
+        InputSource::Keyboard(KeyCode::ControlRight) => "Right Ctrl",
+        InputSource::Keyboard(KeyCode::ShiftLeft) => "Left Shift",
+        InputSource::Keyboard(KeyCode::ShiftRight) => "Right Shift",
+        InputSource::Keyboard(KeyCode::AltLeft) => "Left Alt",
+        InputSource::Keyboard(KeyCode::AltRight) => "Right Alt",
+        InputSource::Keyboard(KeyCode::BracketLeft) => "[",
+        InputSource::Keyboard(KeyCode::BracketRight) => "]",
+        InputSource::Keyboard(KeyCode::Backquote) => "`",
+        InputSource::Keyboard(KeyCode::Escape) => "Esc",
+        InputSource::Mouse(MouseButton::Left) => "Left Mouse",
+        InputSource::Mouse(MouseButton::Right) => "Right Mouse",
+        InputSource::Mouse(MouseButton::Middle) => "Middle Mouse",
+        InputSource::Gamepad(GamepadButton::South) => "A",
+        InputSource::Gamepad(GamepadButton::East) => "B",
+        InputSource::Gamepad(GamepadButton::West) => "X",
+        InputSource::Gamepad(GamepadButton::North) => "Y",
+        InputSource::Gamepad(GamepadButton::LeftTrigger) => "Left Trigger",
+        InputSource::Gamepad(GamepadButton::RightTrigger) => "Right Trigger",
+        InputSource::Gamepad(GamepadButton::LeftTrigger2) => "Left Trigger 2",
+        InputSource::Gamepad(GamepadButton::RightTrigger2) => "Right Trigger 2",
+        InputSource::Gamepad(GamepadButton::LeftThumb) => "Left Stick Click",
+        InputSource::Gamepad(GamepadButton::RightThumb) => "Right Stick Click",
+        InputSource::Gamepad(GamepadButton::DPadUp) => "D-Pad Up",
+        InputSource::Gamepad(GamepadButton::DPadDown) => "D-Pad Down",
+        InputSource::Gamepad(GamepadButton::DPadLeft) => "D-Pad Left",
+        InputSource::Gamepad(GamepadButton::DPadRight) => "D-Pad Right",
+        other => return other.label(),
+    };
+    fixed.to_string()
+}
+
+/// The live player keymap. Defaults to the shipped bindings; the settings menu
+/// edits and persists it, and the input rigs respawn from it when it changes.
+#[derive(Resource, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct Keymap {
+    bindings: [KeymapBinding; KeymapAction::ALL.len()],
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self {
+            bindings: KeymapAction::ALL.map(KeymapAction::default_binding),
+        }
+    }
+}
+
+impl Keymap {
+    /// The keymap with `overrides` applied on top of the defaults - the
+    /// inverse of [`Keymap::overrides`].
+    pub fn from_overrides(
+        overrides: impl IntoIterator<Item = (KeymapAction, KeymapBinding)>,
+    ) -> Self {
+        let mut keymap = Self::default();
+        for (action, binding) in overrides {
+            *keymap.get_mut(action) = binding;
+        }
+        keymap
+    }
+
+    /// The entries that differ from the shipped defaults - all the settings
+    /// store needs to keep, so a later default change still reaches players
+    /// who never touched that row.
+    pub fn overrides(&self) -> Vec<(KeymapAction, KeymapBinding)> {
+        KeymapAction::ALL
+            .into_iter()
+            .filter(|action| *self.get(*action) != action.default_binding())
+            .map(|action| (action, *self.get(action)))
+            .collect()
+    }
+
+    /// The binding for `action`.
+    pub fn get(&self, action: KeymapAction) -> &KeymapBinding {
+        &self.bindings[Self::index(action)]
+    }
+
+    /// The binding for `action`, mutably.
+    pub fn get_mut(&mut self, action: KeymapAction) -> &mut KeymapBinding {
+        &mut self.bindings[Self::index(action)]
+    }
+
+    /// `action`'s bindings as enhanced-input [`Binding`]s, for a rig.
+    pub fn bindings(&self, action: KeymapAction) -> Vec<Binding> {
+        self.get(action).bindings()
+    }
+
+    /// Rebind one half of `action` to the single `source`. A source of the
+    /// wrong device for `device` is ignored (a pad button is never the
+    /// keyboard half).
+    pub fn rebind(&mut self, action: KeymapAction, device: KeymapDevice, source: InputSource) {
+        let binding = self.get_mut(action);
+        match (device, source) {
+            (KeymapDevice::Gamepad, InputSource::Gamepad(button)) => {
+                binding.gamepad = Some(button);
+            }
+            (KeymapDevice::Keyboard, InputSource::Keyboard(_) | InputSource::Mouse(_)) => {
+                binding.keyboard = [Some(source), None];
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether every action is on its shipped binding.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Every action `source` drives.
+    pub fn actions_on(&self, source: InputSource) -> Vec<KeymapAction> {
+        KeymapAction::ALL
+            .into_iter()
+            .filter(|action| self.get(*action).sources().any(|bound| bound == source))
+            .collect()
+    }
+
+    /// The OTHER actions sharing a source with `action` - non-empty means the
+    /// Controls page flags the row. Every rig action runs with
+    /// `consume_input: false`, so a shared source fires both.
+    pub fn conflicts_of(&self, action: KeymapAction) -> Vec<KeymapAction> {
+        let mut others = Vec::new();
+        for source in self.get(action).sources() {
+            for other in self.actions_on(source) {
+                if other != action && !others.contains(&other) {
+                    others.push(other);
+                }
+            }
+        }
+        others
+    }
+
+    /// Whether any two actions share a source.
+    pub fn has_conflicts(&self) -> bool {
+        KeymapAction::ALL
+            .into_iter()
+            .any(|action| !self.conflicts_of(action).is_empty())
+    }
+
+    /// What already owns `source`, as a short phrase for a refused or flagged
+    /// rebind: a keymap action (`Autopilot: Stop`) or a fixed system control
+    /// (`pause / menu`). `None` when the source is free.
+    pub fn owner_label(&self, source: InputSource) -> Option<String> {
+        if let Some(action) = self.actions_on(source).first() {
+            return Some(action.label().to_string());
+        }
+        FIXED_SOURCES
+            .iter()
+            .find(|(fixed, _)| *fixed == source)
+            .map(|(_, what)| (*what).to_string())
+    }
+
+    fn index(action: KeymapAction) -> usize {
+        KeymapAction::ALL
+            .iter()
+            .position(|known| *known == action)
+            .expect("KeymapAction::ALL lists every action")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The shipped map must not flag itself: a conflict here would light the
+    /// Controls page amber on a fresh install.
+    #[test]
+    fn the_default_keymap_has_no_conflicts() {
+        let keymap = Keymap::default();
+        for action in KeymapAction::ALL {
+            assert!(
+                keymap.conflicts_of(action).is_empty(),
+                "{action:?} shares a default source with {:?}",
+                keymap.conflicts_of(action)
+            );
+        }
+        for (fixed, what) in FIXED_SOURCES {
+            assert!(
+                keymap.actions_on(*fixed).is_empty(),
+                "a default binding shadows the fixed {what} control"
+            );
+        }
+    }
+
+    #[test]
+    fn a_shared_source_flags_both_rows() {
+        let mut keymap = Keymap::default();
+        keymap.rebind(
+            KeymapAction::AutopilotStop,
+            KeymapDevice::Keyboard,
+            InputSource::Keyboard(KeyCode::KeyG),
+        );
+        assert_eq!(
+            keymap.conflicts_of(KeymapAction::AutopilotStop),
+            vec![KeymapAction::AutopilotGoto]
+        );
+        assert_eq!(
+            keymap.conflicts_of(KeymapAction::AutopilotGoto),
+            vec![KeymapAction::AutopilotStop]
+        );
+        assert!(keymap.has_conflicts());
+        // The rebind replaced the keyboard half only; the pad half stays.
+        assert_eq!(
+            keymap.get(KeymapAction::AutopilotStop).gamepad,
+            Some(GamepadButton::East)
+        );
+    }
+
+    #[test]
+    fn a_rebind_ignores_the_wrong_device() {
+        let mut keymap = Keymap::default();
+        keymap.rebind(
+            KeymapAction::MainDrive,
+            KeymapDevice::Keyboard,
+            InputSource::Gamepad(GamepadButton::South),
+        );
+        assert!(
+            keymap.is_default(),
+            "a pad button is not a keyboard binding"
+        );
+    }
+
+    /// Only the touched rows persist, and they rebuild the same map.
+    #[test]
+    fn overrides_round_trip_through_the_defaults() {
+        let mut keymap = Keymap::default();
+        keymap.rebind(
+            KeymapAction::FreeLook,
+            KeymapDevice::Gamepad,
+            InputSource::Gamepad(GamepadButton::RightTrigger2),
+        );
+        let overrides = keymap.overrides();
+        assert_eq!(overrides.len(), 1);
+        assert_eq!(overrides[0].0, KeymapAction::FreeLook);
+        assert_eq!(Keymap::from_overrides(overrides), keymap);
+        for action in KeymapAction::ALL {
+            assert_eq!(KeymapAction::from_id(action.id()), Some(action));
+        }
+    }
+
+    #[test]
+    fn owner_label_names_actions_and_fixed_controls() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap
+                .owner_label(InputSource::Keyboard(KeyCode::KeyX))
+                .as_deref(),
+            Some("Autopilot: Stop")
+        );
+        assert_eq!(
+            keymap
+                .owner_label(InputSource::Keyboard(KeyCode::Escape))
+                .as_deref(),
+            Some("pause / menu")
+        );
+        assert_eq!(
+            keymap.owner_label(InputSource::Keyboard(KeyCode::KeyK)),
+            None
+        );
+    }
+}
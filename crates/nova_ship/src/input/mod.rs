@@ -3,9 +3,9 @@
 //! [`ai`] (the enemy behavior state machine), [`targeting`] (the player's
 //! lock/radar system that also derives weapons-safety) and [`point_defense`]
 //! (the autonomous answer to inbound ordnance, which both controllers share).
-//! [`keybind_reference`](prelude::keybind_reference)
-//! exposes the keybind table for the HUD. [`SpaceshipInputPlugin`] adds all
-//! four.
+//! [`keymap`] holds the player's remappable bindings the rigs are built from,
+//! and [`keybind_reference`](prelude::keybind_reference) the fixed system keys
+//! beside them. [`SpaceshipInputPlugin`] adds all four.
 //!
 //! Touch this module when adding a new way to command a ship. The intents these
 //! produce are consumed by the section plugins ([`sections`](crate::sections))
@@ -14,16 +14,18 @@
 use bevy::prelude::*;
 
 pub mod ai;
+pub mod keymap;
 pub mod player;
 pub mod point_defense;
 mod reference;
 pub mod targeting;
 
-/// The AI, player, targeting and point-defence preludes, the keybind reference,
-/// and `SpaceshipInputPlugin` with `SpaceshipInputSystems`.
+/// The AI, keymap, player, targeting and point-defence preludes, the keybind
+/// reference, and `SpaceshipInputPlugin` with `SpaceshipInputSystems`.
 pub mod prelude {
     pub use super::{
         ai::prelude::*,
+        keymap::prelude::*,
         player::prelude::*,
         point_defense::prelude::*,
         reference::{keybind_reference, KeybindEntry},
@@ -51,6 +53,9 @@ impl Plugin for SpaceshipInputPlugin {
     fn build(&self, app: &mut App) {
         trace!("SpaceshipInputPlugin: build");
 
+        app.init_resource::<keymap::Keymap>();
+        app.register_type::<keymap::Keymap>();
+
         app.add_plugins(player::SpaceshipPlayerInputPlugin);
         app.add_plugins(targeting::SpaceshipTargetingPlugin);
         app.add_plugins(point_defense::SpaceshipPointDefensePlugin {
@@ -7,8 +7,11 @@ use bevy_enhanced_input::prelude::*;
 use nova_gameplay::prelude::*;
 
 use crate::{
-    input::targeting::{
-        ComponentCycleNextInput, ComponentCyclePrevInput, RadarClearInput, RadarHoldInput,
+    input::{
+        keymap::{Keymap, KeymapAction},
+        targeting::{
+            ComponentCycleNextInput, ComponentCyclePrevInput, RadarClearInput, RadarHoldInput,
+        },
     },
     prelude::*,
 };
@@ -70,6 +73,7 @@ pub(super) fn on_player_added_spawn_flight_input(
     add: On<Add, PlayerSpaceshipMarker>,
     mut commands: Commands,
     q_existing: Query<(), With<FlightInputMarker>>,
+    keymap: Res<Keymap>,
 ) {
     trace!(
         "on_player_added_spawn_flight_input: entity {:?}",
@@ -80,12 +84,40 @@ pub(super) fn on_player_added_spawn_flight_input(
         return;
     }
 
-    commands.spawn(flight_input_rig());
+    commands.spawn(flight_input_rig(&keymap));
 }
 
-/// The flight rig bundle: all flight actions and their bindings. A named
-/// fn (not inlined in the observer) so the input tests can spawn the REAL
-/// rig and drive it with simulated devices.
+/// Rebuild the live flight rig from the [`Keymap`] after a remap, so a binding
+/// changed from the pause menu applies without a ship respawn. The rig is
+/// respawned whole, the same way a section's weapon rig is replaced when its
+/// binding changes; the verb hints re-read the new `Bindings` next frame.
+///
+/// A rig despawned mid-hold never sees its `Complete`, so the held state the
+/// release observers would clear is cleared here: the burn drops to zero and
+/// RCS mode ends, exactly as if the old keys had been let go.
+pub(super) fn respawn_flight_rig_on_keymap_change(
+    keymap: Res<Keymap>,
+    mut commands: Commands,
+    q_rig: Query<Entity, With<FlightInputMarker>>,
+    mut q_ship: Query<(Entity, &mut FlightIntent), With<PlayerSpaceshipMarker>>,
+) {
+    if keymap.is_added() || q_rig.is_empty() {
+        return;
+    }
+    for rig in &q_rig {
+        commands.entity(rig).despawn();
+    }
+    commands.spawn(flight_input_rig(&keymap));
+    for (ship, mut intent) in &mut q_ship {
+        intent.burn = 0.0;
+        commands.entity(ship).remove::<RcsActive>();
+    }
+}
+
+/// The flight rig bundle: all flight actions, bound from the player's
+/// [`Keymap`]. A named fn (not inlined in the observer) so the input tests can
+/// spawn the REAL rig and drive it with simulated devices. The wheel and mouse
+/// motion bindings are gestures, not keymap rows, and are added here fixed.
 ///
 /// The CTRL layer (cycle the SHIP lock instead of components) is NOT
 /// expressed as input conditions: a binding-level Chord ignores the binding's
@@ -94,7 +126,7 @@ pub(super) fn on_player_added_spawn_flight_input(
 /// Instead the modifier is a plain action whose state the cycle observers
 /// READ (input/targeting/component_lock.rs dispatch): plain wheel/brackets step components,
 /// the same gesture with the modifier held steps the ship lock.
-pub(crate) fn flight_input_rig() -> impl Bundle {
+pub(crate) fn flight_input_rig(keymap: &Keymap) -> impl Bundle {
     (
         Name::new("Input: Flight"),
         FlightInputMarker,
@@ -107,11 +139,7 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![
-                        KeyCode::KeyW,
-                        KeyCode::Space,
-                        GamepadButton::RightTrigger
-                    ],
+                    Bindings::spawn(keymap.bindings(KeymapAction::MainDrive)),
                 ),
                 (
                     Name::new("Input: Autopilot Stop"),
@@ -120,7 +148,7 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![KeyCode::KeyX, GamepadButton::East],
+                    Bindings::spawn(keymap.bindings(KeymapAction::AutopilotStop)),
                 ),
                 (
                     Name::new("Input: Autopilot Goto"),
@@ -129,7 +157,7 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![KeyCode::KeyG, GamepadButton::North],
+                    Bindings::spawn(keymap.bindings(KeymapAction::AutopilotGoto)),
                 ),
                 (
                     Name::new("Input: Autopilot Orbit"),
@@ -138,10 +166,7 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    // South: the scenario-advance confirm (loader.rs) was moved
-                    // off South to DPadDown so this pad press cannot both skip
-                    // the scenario and toggle a parking maneuver.
-                    bindings![KeyCode::KeyO, GamepadButton::South],
+                    Bindings::spawn(keymap.bindings(KeymapAction::AutopilotOrbit)),
                 ),
                 (
                     Name::new("Input: Autopilot Off"),
@@ -150,15 +175,13 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![KeyCode::KeyZ, GamepadButton::West],
+                    Bindings::spawn(keymap.bindings(KeymapAction::AutopilotOff)),
                 ),
                 (
                     // The radar hold: Start = search opens (slot latched),
                     // Fire = active, Complete = commit-on-release, Cancel =
                     // sub-threshold release (no commit; the Tap below is that
-                    // gesture). Pad: DPadUp, freed by the target cycle's
-                    // retirement - a provisional binding until the keybind
-                    // rework picks the pad gesture properly.
+                    // gesture).
                     Name::new("Input: Radar Hold"),
                     Action::<RadarHoldInput>::new(),
                     Hold::new(RADAR_TAP_SECS),
@@ -166,11 +189,7 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![
-                        KeyCode::ControlLeft,
-                        KeyCode::ControlRight,
-                        GamepadButton::DPadUp
-                    ],
+                    Bindings::spawn(keymap.bindings(KeymapAction::Radar)),
                 ),
                 (
                     // The tap clear, same key + threshold const as the hold
@@ -182,11 +201,7 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![
-                        KeyCode::ControlLeft,
-                        KeyCode::ControlRight,
-                        GamepadButton::DPadUp
-                    ],
+                    Bindings::spawn(keymap.bindings(KeymapAction::Radar)),
                 ),
                 (
                     Name::new("Input: Component Cycle Next"),
@@ -198,11 +213,10 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                     // Scroll up = next: the wheel is an axis (y = vertical),
                     // so swizzle y into the action value and clamp away the
                     // opposite direction so only up-scrolls actuate.
-                    bindings![
-                        KeyCode::BracketRight,
-                        GamepadButton::DPadRight,
-                        (Binding::mouse_wheel(), SwizzleAxis::YXZ, Clamp::pos()),
-                    ],
+                    Bindings::spawn((
+                        keymap.bindings(KeymapAction::CycleNext),
+                        Spawn((Binding::mouse_wheel(), SwizzleAxis::YXZ, Clamp::pos())),
+                    )),
                 ),
                 (
                     Name::new("Input: Component Cycle Prev"),
@@ -213,33 +227,28 @@ pub(crate) fn flight_input_rig() -> impl Bundle {
                     },
                     // Scroll down = prev: negate the (swizzled) wheel axis so
                     // down-scrolls become positive, then clamp like above.
-                    bindings![
-                        KeyCode::BracketLeft,
-                        GamepadButton::DPadLeft,
-                        (
+                    Bindings::spawn((
+                        keymap.bindings(KeymapAction::CyclePrev),
+                        Spawn((
                             Binding::mouse_wheel(),
                             SwizzleAxis::YXZ,
                             Negate::all(),
-                            Clamp::pos()
-                        ),
-                    ],
+                            Clamp::pos(),
+                        )),
+                    )),
                 ),
                 (
                     // The RCS fine-adjust modifier (SHIFT). Plain Down: Start on
                     // press, Complete on release; the observers read those into
                     // RcsActive. SHIFT is otherwise free (only CTRL is taken, by
-                    // the radar). Pad: LeftTrigger2 (a free analog-as-button).
+                    // the radar).
                     Name::new("Input: RCS Modifier"),
                     Action::<RcsModifierInput>::new(),
                     ActionSettings {
                         consume_input: false,
                         ..default()
                     },
-                    bindings![
-                        KeyCode::ShiftLeft,
-                        KeyCode::ShiftRight,
-                        GamepadButton::LeftTrigger2
-                    ],
+                    Bindings::spawn(keymap.bindings(KeymapAction::RcsModifier)),
                 ),
                 (
                     // The RCS aim: raw mouse motion, accumulated into RcsIntent's
@@ -278,11 +287,12 @@ pub(super) fn on_flight_burn_input(
     mut commands: Commands,
     ship: Single<(Entity, &mut FlightIntent, Has<Autopilot>), With<PlayerSpaceshipMarker>>,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // Observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
@@ -336,11 +346,12 @@ pub(super) fn on_autopilot_stop_input(
     ship: Single<(Entity, Option<&Autopilot>), With<PlayerSpaceshipMarker>>,
     q_verbs: ControllerVerbQuery,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // Observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
@@ -370,18 +381,30 @@ pub(super) fn on_autopilot_stop_input(
 pub(super) fn on_autopilot_goto_input(
     _: On<Start<AutopilotGotoInput>>,
     mut commands: Commands,
-    ship: Single<(Entity, Option<&Autopilot>, Option<&TravelLock>), With<PlayerSpaceshipMarker>>,
+    ship: Single<
+        (
+            Entity,
+            Option<&Autopilot>,
+            Option<&TravelLock>,
+            Option<&Docked>,
+        ),
+        With<PlayerSpaceshipMarker>,
+    >,
     q_verbs: ControllerVerbQuery,
+    q_frames: Query<&GlobalTransform>,
+    q_ports: Query<(Entity, &ChildOf), With<DockingPort>>,
+    q_berths: Query<(Entity, Option<&Docked>, Option<&DockApproach>)>,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // Observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
-    let (entity, autopilot, travel) = ship.into_inner();
+    let (entity, autopilot, travel, docked) = ship.into_inner();
 
     // Already flying somewhere? G toggles the trip off. Disengage stays
     // ungated so a verb disabled mid-trip can never strand the ship in GOTO.
@@ -412,6 +435,49 @@ pub(super) fn on_autopilot_goto_input(
         return;
     };
 
+    // A station is flown to one of its docking ports: the nearest one no
+    // other ship holds or is approaching. Already docked there, G does
+    // nothing - burning away is how a ship leaves.
+    let ports: Vec<(Entity, Vec3)> = q_ports
+        .iter()
+        .filter(|(_, &ChildOf(station))| station == target)
+        .filter_map(|(port, _)| {
+            q_frames
+                .get(port)
+                .ok()
+                .map(|frame| (port, frame.translation()))
+        })
+        .collect();
+    if !ports.is_empty() {
+        if docked.is_some_and(|docked| docked.station == target) {
+            debug!("on_autopilot_goto_input: already docked at {target:?}");
+            return;
+        }
+        let taken: Vec<Entity> = q_berths
+            .iter()
+            .filter(|(berth, ..)| *berth != entity)
+            .flat_map(|(_, docked, approach)| {
+                docked
+                    .map(|docked| docked.port)
+                    .into_iter()
+                    .chain(approach.map(|approach| approach.port))
+            })
+            .collect();
+        let from = q_frames
+            .get(entity)
+            .map_or(Vec3::ZERO, |frame| frame.translation());
+        let Some(port) = nearest_free_port(from, ports, &taken) else {
+            debug!("on_autopilot_goto_input: every port of {target:?} is taken");
+            return;
+        };
+        debug!("on_autopilot_goto_input: engaging GOTO to dock at {port:?}");
+        commands.entity(entity).insert((
+            Autopilot::engage(AutopilotAction::Goto { target: port }),
+            DockApproach { port },
+        ));
+        return;
+    }
+
     debug!("on_autopilot_goto_input: engaging GOTO {target:?}");
     commands
         .entity(entity)
@@ -424,11 +490,12 @@ pub(super) fn on_autopilot_orbit_input(
     ship: Single<(Entity, Option<&Autopilot>, Option<&DominantWell>), With<PlayerSpaceshipMarker>>,
     q_verbs: ControllerVerbQuery,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // Observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
@@ -479,11 +546,12 @@ pub(super) fn on_autopilot_off_input(
     mut commands: Commands,
     ship: Single<(Entity, Has<Autopilot>), With<PlayerSpaceshipMarker>>,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // Observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
@@ -510,8 +578,9 @@ pub(super) fn on_rcs_modifier_start(
     ship: Single<Entity, With<PlayerSpaceshipMarker>>,
     q_verbs: ControllerVerbQuery,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
-    if pause.get().is_frozen() {
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
     let entity = *ship;
@@ -554,8 +623,9 @@ pub(super) fn on_rcs_aim(
     fire: On<Fire<RcsAimInput>>,
     ship: Single<(&mut RcsIntent, Has<RcsActive>), With<PlayerSpaceshipMarker>>,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
-    if pause.get().is_frozen() {
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
     let (mut intent, active) = ship.into_inner();
@@ -615,7 +685,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         // Press G with GOTO withheld: nothing engages.
@@ -675,7 +745,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         // Open the NOVA OS, then press the throttle: intent stays put.
@@ -745,7 +815,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         // Press SHIFT: RCS entered, autopilot gone.
@@ -812,7 +882,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         app.world_mut()
@@ -851,7 +921,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         // Not in RCS yet: mouse motion must not move the intent.
@@ -931,7 +1001,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         let scroll_up = |app: &mut App| {
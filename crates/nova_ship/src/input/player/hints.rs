@@ -9,8 +9,9 @@ use nova_gameplay::prelude::*;
 use super::flight_rig::{flight_input_rig, FlightBurnInput, FlightInputMarker};
 use super::flight_rig::{
     AutopilotGotoInput, AutopilotOffInput, AutopilotOrbitInput, AutopilotStopInput,
+    RcsModifierInput,
 };
-use crate::prelude::*;
+use crate::{input::targeting::RadarHoldInput, prelude::*};
 
 /// One flight verb's hint state, for the keybind-hint HUD.
 #[derive(Clone, Debug, Default, PartialEq, Reflect)]
@@ -49,15 +50,15 @@ pub struct FlightVerbHints {
     /// Component fine-lock cycle (plain scroll). The key label is the fixed
     /// string "SCROLL" - a wheel binding has no keyboard label to read.
     pub component_cycle: VerbHint,
-    /// The radar gesture (hold CTRL = radar, tap = clear). Fixed "CTRL" label
-    /// like the wheel rows (the binding spans both Control keys plus a pad
-    /// button); available while the computer grants Lock (CTRL was missing
-    /// from the cluster entirely).
+    /// The radar gesture (hold CTRL = radar, tap = clear). Labelled "CTRL"
+    /// while it sits on a Control key (the default binding spans both), else
+    /// the remapped key; available while the computer grants Lock (CTRL was
+    /// missing from the cluster entirely).
     pub radar: VerbHint,
-    /// The RCS fine-adjust modifier (hold SHIFT). Fixed "SHIFT" label like
-    /// the wheel/CTRL rows; available while the computer grants the `Rcs`
-    /// verb, so the row shows only where RCS is enabled - the same opt-out
-    /// the mainline campaign uses while RCS is off pending rework.
+    /// The RCS fine-adjust modifier (hold SHIFT). Labelled "SHIFT" while it
+    /// sits on a Shift key, like the radar row; available while the computer
+    /// grants the `Rcs` verb, so the row shows only where RCS is enabled - the
+    /// same opt-out the mainline campaign uses while RCS is off pending rework.
     pub rcs: VerbHint,
     /// Whether any maneuver is engaged right now - explicit, so consumers
     /// (the GOTO cue hides mid-maneuver) do not have to proxy it through
@@ -75,6 +76,17 @@ fn cycle_label(label: &str, rig_exists: bool) -> String {
     }
 }
 
+/// The chip label for a held modifier gesture: both physical Control (or
+/// Shift) keys collapse to the one "CTRL" ("SHIFT") pseudo-label the keycap
+/// table maps, and any other key - a remap - reads like a verb key.
+fn modifier_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::ControlLeft | KeyCode::ControlRight => "CTRL".to_string(),
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => "SHIFT".to_string(),
+        other => keyboard_label(other),
+    }
+}
+
 /// A short chip label for a keyboard binding: `KeyX` -> `X`, `Digit1` -> `1`,
 /// everything else (Space, Enter...) as spelled. `nova_hud`'s key-glyph
 /// coverage test labels the real bindings with THIS function, so it crosses the
@@ -114,7 +126,8 @@ pub fn binding_label(bindings: &[Binding]) -> String {
 /// of modifiers and gesture conditions. Two bindings that name the same source
 /// drive the same physical input; that is exactly the silent double-drive a
 /// content `input_mapping` must not create against the always-on flight rig.
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Reflect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputSource {
     /// A keyboard key.
     Keyboard(KeyCode),
@@ -190,7 +203,6 @@ pub fn flight_rig_reserved_sources() -> Vec<(InputSource, &'static str)> {
         (Gamepad(GamepadButton::DPadLeft), "component cycle prev"),
         (Keyboard(KeyCode::ShiftLeft), "RCS modifier"),
         (Keyboard(KeyCode::ShiftRight), "RCS modifier"),
-        (Gamepad(GamepadButton::LeftTrigger2), "RCS modifier"),
     ]
 }
 
@@ -199,7 +211,8 @@ pub fn flight_rig_reserved_sources() -> Vec<(InputSource, &'static str)> {
 /// well, engagement, and a flyable ship - a live flight computer plus at
 /// least one live engine, else autopilot_system strips the maneuver on its
 /// next tick and a lit hint would be a lie), labels from the flight rig's
-/// actual `Bindings` so a future remap screen cannot desync the hints.
+/// actual `Bindings` so a [`Keymap`](crate::input::keymap::Keymap) remap
+/// cannot desync the hints.
 #[expect(clippy::type_complexity, reason = "one query per private action type")]
 pub(super) fn update_flight_verb_hints(
     mut hints: ResMut<FlightVerbHints>,
@@ -228,18 +241,23 @@ pub(super) fn update_flight_verb_hints(
     q_goto: Query<&Bindings, With<Action<AutopilotGotoInput>>>,
     q_orbit: Query<&Bindings, With<Action<AutopilotOrbitInput>>>,
     q_off: Query<&Bindings, With<Action<AutopilotOffInput>>>,
+    q_radar: Query<&Bindings, With<Action<RadarHoldInput>>>,
+    q_rcs: Query<&Bindings, With<Action<RcsModifierInput>>>,
     q_binding: Query<&Binding>,
 ) {
-    let label = |bindings: Option<&Bindings>| -> String {
+    let first_key = |bindings: Option<&Bindings>| -> Option<KeyCode> {
         bindings
             .into_iter()
             .flatten()
             .find_map(|entity| match q_binding.get(entity) {
-                Ok(Binding::Keyboard { key, .. }) => Some(keyboard_label(*key)),
+                Ok(Binding::Keyboard { key, .. }) => Some(*key),
                 _ => None,
             })
-            .unwrap_or_default()
     };
+    let label =
+        |bindings: Option<&Bindings>| first_key(bindings).map(keyboard_label).unwrap_or_default();
+    let modifier =
+        |bindings: Option<&Bindings>| first_key(bindings).map(modifier_label).unwrap_or_default();
 
     // Exactly one player ship, same rule as the Single-based observers.
     let (ship, autopilot, dominant, travel, combat, focus) = match q_ship.single() {
@@ -321,15 +339,15 @@ pub(super) fn update_flight_verb_hints(
             anchor: None,
         },
         radar: VerbHint {
-            key: cycle_label("CTRL", q_stop.single().is_ok()),
+            key: modifier(q_radar.single().ok()),
             available: verb_granted(FlightVerb::Lock),
             anchor: None,
         },
         rcs: VerbHint {
-            // Fixed "SHIFT" label (a modifier binding, no keyboard key to read),
-            // gated on the rig existing like the wheel/CTRL rows; shown only
-            // while the computer grants RCS.
-            key: cycle_label("SHIFT", q_stop.single().is_ok()),
+            // The held modifier's live key ("SHIFT" on the default binding);
+            // empty without a rig, like every other row. Shown only while the
+            // computer grants RCS.
+            key: modifier(q_rcs.single().ok()),
             available: verb_granted(FlightVerb::Rcs),
             anchor: None,
         },
@@ -348,11 +366,29 @@ mod tests {
     use super::*;
     use crate::input::player::test_support::{hint_world, spawn_flyable_ship};
 
+    /// The rig binds what the keymap says: spawned from a REMAPPED keymap,
+    /// every action's first keyboard key and pad button are the remapped ones,
+    /// so the Controls page, the rig and the hints cannot drift apart.
     #[test]
-    fn reference_rows_track_the_flight_rig() {
+    fn the_flight_rig_binds_the_keymap() {
         use bevy::input::InputPlugin;
 
-        use crate::input::reference::KEYBINDS;
+        use crate::input::{
+            keymap::{Keymap, KeymapAction, KeymapDevice},
+            targeting::{ComponentCycleNextInput, ComponentCyclePrevInput},
+        };
+
+        let mut keymap = Keymap::default();
+        keymap.rebind(
+            KeymapAction::AutopilotStop,
+            KeymapDevice::Keyboard,
+            InputSource::Keyboard(KeyCode::KeyK),
+        );
+        keymap.rebind(
+            KeymapAction::Radar,
+            KeymapDevice::Gamepad,
+            InputSource::Gamepad(GamepadButton::RightTrigger2),
+        );
 
         let mut app = App::new();
         app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin));
@@ -360,92 +396,79 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&keymap));
         app.update();
 
-        // The first keyboard key the rig binds to an action (its primary key,
-        // the one the reference row leads with).
-        fn primary_key<A: bevy_enhanced_input::prelude::InputAction>(app: &mut App) -> KeyCode {
+        // The discrete sources the rig binds to an action, in binding order.
+        fn sources<A: bevy_enhanced_input::prelude::InputAction>(
+            app: &mut App,
+        ) -> Vec<InputSource> {
             let mut q = app
                 .world_mut()
                 .query_filtered::<&Bindings, With<Action<A>>>();
             let world = app.world();
-            for bindings in q.iter(world) {
-                for binding_entity in bindings.iter() {
-                    if let Some(Binding::Keyboard { key, .. }) =
-                        world.get::<Binding>(binding_entity)
-                    {
-                        return *key;
-                    }
-                }
-            }
-            panic!("the flight rig binds no keyboard key to this action");
-        }
-
-        // The friendly label the reference uses for a rig key. An unmapped key
-        // panics on purpose: a remap to a new key must add its label here AND in
-        // the reference, so neither can drift silently.
-        fn display_label(key: KeyCode) -> &'static str {
-            match key {
-                KeyCode::KeyW => "W",
-                KeyCode::KeyX => "X",
-                KeyCode::KeyG => "G",
-                KeyCode::KeyO => "O",
-                KeyCode::KeyZ => "Z",
-                KeyCode::ControlLeft | KeyCode::ControlRight => "Ctrl",
-                KeyCode::BracketRight => "]",
-                KeyCode::BracketLeft => "[",
-                other => panic!(
-                    "flight rig binds {other:?}, which has no reference display \
-                     mapping - update reference.rs KEYBINDS and this test"
-                ),
-            }
+            q.iter(world)
+                .flat_map(|bindings| bindings.iter())
+                .filter_map(|entity| world.get::<Binding>(entity).and_then(binding_source))
+                .collect()
         }
 
-        // (reference action name, the rig key that row must display). The key is
-        // read LIVE from the rig, so it tracks a remap.
-        let rows: [(&str, KeyCode); 8] = [
-            ("Main Drive", primary_key::<FlightBurnInput>(&mut app)),
+        let rows = [
             (
-                "Autopilot: Stop",
-                primary_key::<AutopilotStopInput>(&mut app),
+                KeymapAction::MainDrive,
+                sources::<FlightBurnInput>(&mut app),
             ),
             (
-                "Autopilot: Go To",
-                primary_key::<AutopilotGotoInput>(&mut app),
+                KeymapAction::AutopilotStop,
+                sources::<AutopilotStopInput>(&mut app),
             ),
             (
-                "Autopilot: Orbit",
-                primary_key::<AutopilotOrbitInput>(&mut app),
+                KeymapAction::AutopilotGoto,
+                sources::<AutopilotGotoInput>(&mut app),
             ),
-            ("Autopilot: Off", primary_key::<AutopilotOffInput>(&mut app)),
             (
-                "Radar (hold search / tap clear)",
-                primary_key::<crate::input::targeting::RadarHoldInput>(&mut app),
+                KeymapAction::AutopilotOrbit,
+                sources::<AutopilotOrbitInput>(&mut app),
             ),
             (
-                "Lock / Component Next",
-                primary_key::<crate::input::targeting::ComponentCycleNextInput>(&mut app),
+                KeymapAction::AutopilotOff,
+                sources::<AutopilotOffInput>(&mut app),
             ),
             (
-                "Lock / Component Prev",
-                primary_key::<crate::input::targeting::ComponentCyclePrevInput>(&mut app),
+                KeymapAction::RcsModifier,
+                sources::<RcsModifierInput>(&mut app),
+            ),
+            (KeymapAction::Radar, sources::<RadarHoldInput>(&mut app)),
+            (
+                KeymapAction::CycleNext,
+                sources::<ComponentCycleNextInput>(&mut app),
+            ),
+            (
+                KeymapAction::CyclePrev,
+                sources::<ComponentCyclePrevInput>(&mut app),
             ),
         ];
-
-        for (action, key) in rows {
-            let row = KEYBINDS
-                .iter()
-                .find(|e| e.action == action)
-                .unwrap_or_else(|| panic!("missing keybind reference row for {action}"));
-            let label = display_label(key);
-            assert!(
-                row.keyboard.contains(label),
-                "reference row {action:?} shows keyboard {:?}, but the rig binds \
-                 {key:?} (displayed as {label:?}) - the readout has drifted",
-                row.keyboard
+        for (action, bound) in rows {
+            let expected: Vec<InputSource> = keymap.get(action).sources().collect();
+            assert_eq!(
+                bound, expected,
+                "the rig's {action:?} drifted from the keymap"
             );
         }
+        assert_eq!(
+            sources::<AutopilotStopInput>(&mut app)[0],
+            InputSource::Keyboard(KeyCode::KeyK),
+            "delivery guard: the remap reached the rig"
+        );
+    }
+
+    /// A held modifier moved off Control reads its new key on the dock chip;
+    /// on either Control key it keeps the shared "CTRL" keycap label.
+    #[test]
+    fn a_remapped_modifier_relabels_its_hint() {
+        assert_eq!(modifier_label(KeyCode::ControlRight), "CTRL");
+        assert_eq!(modifier_label(KeyCode::ShiftLeft), "SHIFT");
+        assert_eq!(modifier_label(KeyCode::KeyQ), "Q");
     }
 
     #[test]
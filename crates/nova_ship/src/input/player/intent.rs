@@ -621,7 +621,7 @@ mod command_lag_tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
 
         let mut rig_sources: HashSet<InputSource> = HashSet::new();
@@ -5,7 +5,8 @@
 //! ([`PlayerSpaceshipMarker`](nova_gameplay::markers::PlayerSpaceshipMarker)) and
 //! maintains [`FlightVerbHints`] for the verb-hint HUD.
 //!
-//! The reserved flight-rig sources ([`flight_rig_reserved_sources`]) must not be
+//! The rig is bound from the player's [`Keymap`](super::keymap::Keymap) and
+//! respawned when it changes. The reserved flight-rig sources ([`flight_rig_reserved_sources`]) must not be
 //! reused by content weapon bindings or flight silently double-drives; see that
 //! function's note. Autopilot verbs land as [`FlightIntent`](crate::flight) /
 //! [`Autopilot`](crate::flight) on the ship, consumed by
@@ -25,7 +26,7 @@ use flight_rig::{
     on_autopilot_goto_input, on_autopilot_off_input, on_autopilot_orbit_input,
     on_autopilot_stop_input, on_flight_burn_input, on_flight_burn_input_completed,
     on_player_added_spawn_flight_input, on_player_removed_despawn_flight_input, on_rcs_aim,
-    on_rcs_modifier_released, on_rcs_modifier_start,
+    on_rcs_modifier_released, on_rcs_modifier_start, respawn_flight_rig_on_keymap_change,
 };
 use hints::update_flight_verb_hints;
 use intent::{
@@ -106,15 +107,30 @@ impl Plugin for SpaceshipPlayerInputPlugin {
         app.add_systems(
             Update,
             (
-                update_controller_target_rotation_torque,
+                // The two camera-driven feeds stand down while a replay flies
+                // the ship: it writes the heading and the aim it recorded.
+                update_controller_target_rotation_torque.run_if(player_input_live),
                 // The turret feed reads the lock, focus and component state,
                 // so it runs after the targeting chain, same as the torpedo
                 // commit (previously a `.chain()` when they shared a module).
-                update_turret_target_input.after(super::targeting::SpaceshipTargetingSystems),
+                update_turret_target_input
+                    .after(super::targeting::SpaceshipTargetingSystems)
+                    .run_if(player_input_live),
                 update_torpedo_target_input.after(super::targeting::SpaceshipTargetingSystems),
                 update_flight_verb_hints.after(super::targeting::SpaceshipTargetingSystems),
+                // A remap rebuilds the rig before the hints re-read its keys.
+                respawn_flight_rig_on_keymap_change
+                    .run_if(resource_changed::<super::keymap::Keymap>)
+                    .before(update_flight_verb_hints),
             )
                 .in_set(super::SpaceshipInputSystems),
         );
     }
 }
+
+/// Run condition: the human is flying the player ship, i.e. no replay has
+/// suspended their input (see
+/// [`PlayerInputSuspended`](nova_gameplay::PlayerInputSuspended)).
+fn player_input_live(suspended: Option<Res<nova_gameplay::PlayerInputSuspended>>) -> bool {
+    !suspended.is_some_and(|suspended| suspended.0)
+}
@@ -7,11 +7,13 @@ use nova_gameplay::prelude::*;
 
 use super::flight_rig::{
     AutopilotGotoInput, AutopilotOffInput, AutopilotOrbitInput, AutopilotStopInput,
+    RcsModifierInput,
 };
-use crate::prelude::*;
+use crate::{input::targeting::RadarHoldInput, prelude::*};
 
-/// A world with the flight rig's four autopilot actions bound as in
-/// the real rig, plus the resources the resolver reads.
+/// A world with the flight rig's four autopilot actions and its two held
+/// modifiers (radar, RCS) bound as in the real rig, plus the resources the
+/// resolver reads.
 pub(super) fn hint_world() -> World {
     let mut world = World::new();
     world.init_resource::<FlightVerbHints>();
@@ -31,6 +33,18 @@ pub(super) fn hint_world() -> World {
         Action::<AutopilotOffInput>::new(),
         bindings![KeyCode::KeyZ, GamepadButton::West],
     ));
+    world.spawn((
+        Action::<RadarHoldInput>::new(),
+        bindings![
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            GamepadButton::DPadUp
+        ],
+    ));
+    world.spawn((
+        Action::<RcsModifierInput>::new(),
+        bindings![KeyCode::ShiftLeft, KeyCode::ShiftRight],
+    ));
     world
 }
 
@@ -77,11 +77,12 @@ pub(super) fn on_thruster_input(
     mut commands: Commands,
     mut q_input: Query<(&mut ThrusterSectionInput, Option<&ChildOf>), With<ThrusterInputMarker>>,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // NOTE: observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
@@ -172,11 +173,12 @@ pub(super) fn on_turret_input(
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
     >,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // NOTE: observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
@@ -271,11 +273,12 @@ pub(super) fn on_torpedo_input(
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
     >,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // NOTE: observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
 
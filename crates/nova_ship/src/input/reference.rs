@@ -1,18 +1,15 @@
-//! A read-only keybind reference for the settings menu.
+//! A read-only reference for the controls the keymap does NOT remap.
 //!
-//! The settings panel renders in the main menu, where no input rig is spawned
-//! (the flight/camera rigs only exist during a live scenario), so the
-//! reference cannot be read live off the `Bindings` the way the in-flight
-//! verb hints are ([`super::player::binding_label`]). It is therefore
-//! authored as static data here, next to the rigs it describes, and a parity
-//! test pins the flight rig's keyboard bindings so a future remap of the rig
-//! cannot silently desync this list. This is the read-only surface only;
-//! full remapping + key icons stay backlog (TODO(20260710-231927)).
-//!
-//! The camera-rig rows (aim / free-look / raise), comms controls, and the
-//! system rows (pause, HUD level) are static prose: they live outside the
-//! flight rig, are far lower-churn, and the parity test covers the flight rig
-//! where the actual key churn is.
+//! The remappable actions (flight verbs, weapons, targeting, free look, the
+//! NOVA OS key) live in [`super::keymap::Keymap`], which the settings menu
+//! renders and edits directly. What is left here is the fixed remainder the
+//! Controls page still lists so the page covers every control: the look axes
+//! (a stick/mouse-motion binding, not a button), the comms panel keys, and the
+//! system rows (pause, HUD level). The fine-lock wheel is named on the keymap's
+//! own cycle rows.
+//! These are static prose - they are far lower-churn than the flight rig, and
+//! the keymap's `FIXED_SOURCES` names the same buttons so a rebind cannot
+//! land on one.
 
 /// One row of the keybind reference: what the control does and how it is bound
 /// on keyboard/mouse and on a gamepad. All plain display strings - this is a
@@ -29,80 +26,16 @@ pub struct KeybindEntry {
     pub gamepad: &'static str,
 }
 
-/// The canonical player controls, in reading order and grouped by section.
-/// Sourced from the flight rig (`super::player::flight_input_rig`), the
-/// targeting actions, the camera controller rig, comms panel keys, and the
-/// pause toggle.
+/// The fixed player controls, in reading order and grouped by section: the
+/// look axes, the comms panel keys, and the pause/HUD toggles.
 pub const KEYBINDS: &[KeybindEntry] = &[
-    // FLIGHT - the flight rig in input/player/flight_rig.rs.
+    // FLIGHT - the camera rig's look axes in camera/rig.rs.
     KeybindEntry {
         section: "FLIGHT",
         action: "Aim",
         keyboard: "Mouse",
         gamepad: "Right Stick",
     },
-    KeybindEntry {
-        section: "FLIGHT",
-        action: "Main Drive",
-        keyboard: "W / Space",
-        gamepad: "Right Trigger",
-    },
-    KeybindEntry {
-        section: "FLIGHT",
-        action: "Autopilot: Stop",
-        keyboard: "X",
-        gamepad: "B",
-    },
-    KeybindEntry {
-        section: "FLIGHT",
-        action: "Autopilot: Go To",
-        keyboard: "G",
-        gamepad: "Y",
-    },
-    KeybindEntry {
-        section: "FLIGHT",
-        action: "Autopilot: Orbit",
-        keyboard: "O",
-        gamepad: "A",
-    },
-    KeybindEntry {
-        section: "FLIGHT",
-        action: "Autopilot: Off",
-        keyboard: "Z",
-        gamepad: "X",
-    },
-    // TARGETING - the radar gestures and the fine-lock cycle.
-    KeybindEntry {
-        section: "TARGETING",
-        action: "Raise Weapons",
-        keyboard: "Right Mouse",
-        gamepad: "Left Trigger 2",
-    },
-    KeybindEntry {
-        section: "TARGETING",
-        action: "Radar (hold search / tap clear)",
-        keyboard: "Ctrl",
-        gamepad: "D-Pad Up",
-    },
-    KeybindEntry {
-        section: "TARGETING",
-        action: "Lock / Component Next",
-        keyboard: "] / Scroll Up",
-        gamepad: "D-Pad Right",
-    },
-    KeybindEntry {
-        section: "TARGETING",
-        action: "Lock / Component Prev",
-        keyboard: "[ / Scroll Down",
-        gamepad: "D-Pad Left",
-    },
-    // CAMERA - the chase-camera controller rig in camera/.
-    KeybindEntry {
-        section: "CAMERA",
-        action: "Free Look",
-        keyboard: "Left Alt",
-        gamepad: "Left Trigger",
-    },
     // COMMS - the HUD comms stack in hud/comms_panel.rs.
     KeybindEntry {
         section: "COMMS",
@@ -132,7 +65,7 @@ pub const KEYBINDS: &[KeybindEntry] = &[
     },
 ];
 
-/// The read-only keybind reference for the settings menu.
+/// The read-only reference rows the settings menu lists after the keymap.
 pub fn keybind_reference() -> &'static [KeybindEntry] {
     KEYBINDS
 }
@@ -141,10 +74,22 @@ pub fn keybind_reference() -> &'static [KeybindEntry] {
 mod tests {
     use super::*;
 
-    // The rig-parity test (the reference's keyboard labels must match the live
-    // flight rig) lives in `input::player`'s test module, where the private
-    // flight action types are visible; it asserts against these public
-    // `KEYBINDS`. See `reference_rows_track_the_flight_rig` there.
+    /// The two lists partition the controls: no fixed row repeats an action
+    /// the keymap already renders.
+    #[test]
+    fn no_row_duplicates_a_keymap_action() {
+        use crate::input::keymap::KeymapAction;
+
+        for entry in KEYBINDS {
+            assert!(
+                KeymapAction::ALL
+                    .iter()
+                    .all(|action| action.label() != entry.action),
+                "{} is a keymap row",
+                entry.action
+            );
+        }
+    }
 
     #[test]
     fn every_entry_is_fully_populated() {
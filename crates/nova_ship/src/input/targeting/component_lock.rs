@@ -245,11 +245,12 @@ pub(crate) fn on_component_cycle_next(
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
     >,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // NOTE: observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
     for (lock, focus, mut component, rcs_active, rcs_intent) in &mut q_ship {
@@ -281,11 +282,12 @@ pub(super) fn on_component_cycle_prev(
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
     >,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
 ) {
     // NOTE: observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
     for (lock, focus, mut component, rcs_active, rcs_intent) in &mut q_ship {
@@ -63,6 +63,7 @@ pub(super) type Lockable = (Entity, Vec3, bool, bool);
 
 /// The scanner query every collection pass walks. Turret bullets are excluded
 /// outright: they are dynamic bodies that stream straight down the aim ray.
+/// So are ghost hulls, which are only a replay of the player's own run.
 pub(super) type LockableQuery<'w, 's> = Query<
     'w,
     's,
@@ -77,7 +78,10 @@ pub(super) type LockableQuery<'w, 's> = Query<
         Option<&'static TorpedoTargetChosen>,
         Option<&'static Allegiance>,
     ),
-    Without<TurretBulletProjectileMarker>,
+    (
+        Without<TurretBulletProjectileMarker>,
+        Without<GhostHullMarker>,
+    ),
 >;
 
 /// Collect every body the scanner can currently see from `origin`, applying
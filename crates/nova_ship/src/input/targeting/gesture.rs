@@ -39,6 +39,7 @@ pub(super) fn on_radar_start(
     _: On<Start<RadarHoldInput>>,
     mut commands: Commands,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
     mut denied: MessageWriter<RadarDenied>,
     q_controllers: Query<
         (&ChildOf, Option<&WithheldVerbs>),
@@ -50,9 +51,9 @@ pub(super) fn on_radar_start(
     q_ship: Query<Entity, (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>)>,
 ) {
     // Observers bypass system-set gating; freeze intent changes while the
-    // pause overlay is up. Releases stay ungated so held keys clear cleanly
-    // during a pause.
-    if pause.get().is_frozen() {
+    // pause overlay is up or a replay flies the ship. Releases stay ungated
+    // so held keys clear cleanly during a pause.
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
     for ship in &q_ship {
@@ -134,6 +135,7 @@ pub(super) fn on_lock_clear_tap(
     _: On<Fire<RadarClearInput>>,
     mut commands: Commands,
     pause: Res<State<nova_gameplay::PauseStates>>,
+    suspended: Option<Res<PlayerInputSuspended>>,
     mut toasts: MessageWriter<LockClearedToast>,
     mut q_ship: Query<
         (
@@ -146,7 +148,7 @@ pub(super) fn on_lock_clear_tap(
         (With<SpaceshipRootMarker>, With<PlayerSpaceshipMarker>),
     >,
 ) {
-    if pause.get().is_frozen() {
+    if pause.get().is_frozen() || suspended.is_some_and(|suspended| suspended.0) {
         return;
     }
     for (ship, raised, mut travel, mut combat, autopilot) in &mut q_ship {
@@ -284,7 +286,7 @@ mod tests {
         app.finish();
         app.cleanup();
         app.update();
-        app.world_mut().spawn(flight_input_rig());
+        app.world_mut().spawn(flight_input_rig(&Keymap::default()));
         app.update();
         (app, ship)
     }
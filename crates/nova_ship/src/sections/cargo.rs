@@ -0,0 +1,257 @@
+//! Ship cargo: typed item stacks held on the ship root, with room supplied by
+//! hull sections.
+//!
+//! A hull section may carry a [`SectionCargoCapacity`] (authored as
+//! `HullSectionConfig::cargo_capacity`). The ship root's [`CargoHold`] is
+//! derived from them: [`sync_cargo_capacity`] sums the capacity of the
+//! sections still standing and inserts the hold the first time the sum is
+//! non-zero. A ship with no cargo-bearing hull never gets a hold, so every
+//! ship authored before cargo existed is unchanged.
+//!
+//! Items are plain string ids (`"ore"`, `"ledger_page"`) counted in whole
+//! units, one unit per unit of capacity. The hold has no per-item rules - a
+//! scenario gives, takes and queries counts; what an item MEANS is the
+//! scenario's business.
+//!
+//! Losing a cargo-bearing section loses the cargo it held. The hold does not
+//! track which section a unit sits in, so the loss is proportional: every
+//! stack keeps the surviving fraction of the room, rounded down.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use nova_gameplay::prelude::*;
+
+/// `CargoHold`, `SectionCargoCapacity` and `sync_cargo_capacity`.
+pub mod prelude {
+    pub use super::{sync_cargo_capacity, CargoHold, SectionCargoCapacity};
+}
+
+/// Units of cargo room a hull section adds to its ship's [`CargoHold`].
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct SectionCargoCapacity(pub u32);
+
+/// A ship's cargo: item stacks keyed by item id, bounded by the room its
+/// standing hull sections provide.
+///
+/// Lives on the ship ROOT. `capacity` is owned by [`sync_cargo_capacity`];
+/// everything else goes through [`give`](Self::give) and
+/// [`take`](Self::take), which never let the hold overfill or a stack go
+/// negative.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct CargoHold {
+    /// Total units the hold can carry.
+    pub capacity: u32,
+    /// Units held per item id. A stack that reaches zero is removed, so every
+    /// entry is at least one.
+    stacks: BTreeMap<String, u32>,
+}
+
+impl CargoHold {
+    /// An empty hold with room for `capacity` units.
+    pub fn new(capacity: u32) -> Self {
+        Self {
+            capacity,
+            stacks: BTreeMap::new(),
+        }
+    }
+
+    /// Units of `item` held; zero when there is no stack.
+    pub fn count(&self, item: &str) -> u32 {
+        self.stacks.get(item).copied().unwrap_or(0)
+    }
+
+    /// Units held across every stack.
+    pub fn used(&self) -> u32 {
+        self.stacks.values().sum()
+    }
+
+    /// Units of room left.
+    pub fn free(&self) -> u32 {
+        self.capacity.saturating_sub(self.used())
+    }
+
+    /// The stacks in item-id order, for display and snapshots.
+    pub fn stacks(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.stacks
+            .iter()
+            .map(|(item, count)| (item.as_str(), *count))
+    }
+
+    /// Load up to `count` units of `item`, as many as fit. Returns the units
+    /// actually accepted; the rest stays with the caller.
+    pub fn give(&mut self, item: &str, count: u32) -> u32 {
+        let accepted = count.min(self.free());
+        if accepted > 0 {
+            *self.stacks.entry(item.to_string()).or_insert(0) += accepted;
+        }
+        accepted
+    }
+
+    /// Unload up to `count` units of `item`. Returns the units actually
+    /// removed, which is less than `count` when the stack is short.
+    pub fn take(&mut self, item: &str, count: u32) -> u32 {
+        let Some(stack) = self.stacks.get_mut(item) else {
+            return 0;
+        };
+        let taken = count.min(*stack);
+        *stack -= taken;
+        if *stack == 0 {
+            self.stacks.remove(item);
+        }
+        taken
+    }
+
+    /// Set the room to `capacity`. Growing only adds room; shrinking keeps
+    /// `count * capacity / old` of every stack (rounded down) and returns the
+    /// units lost per item.
+    pub fn set_capacity(&mut self, capacity: u32) -> BTreeMap<String, u32> {
+        let old = self.capacity;
+        self.capacity = capacity;
+        let mut lost = BTreeMap::new();
+        if capacity >= old {
+            return lost;
+        }
+        for (item, count) in self.stacks.iter_mut() {
+            let kept = (u64::from(*count) * u64::from(capacity) / u64::from(old)) as u32;
+            if kept < *count {
+                lost.insert(item.clone(), *count - kept);
+                *count = kept;
+            }
+        }
+        self.stacks.retain(|_, count| *count > 0);
+        lost
+    }
+}
+
+/// Keep every ship's [`CargoHold`] capacity equal to the summed
+/// [`SectionCargoCapacity`] of its standing sections.
+///
+/// A section stops counting the moment it is depleted or disabled, not when
+/// it finally despawns, so the cargo goes with the hit that killed the
+/// section. A severed section leaves the ship's `Children` with its wreck
+/// fragment and stops counting the same way.
+pub fn sync_cargo_capacity(
+    mut commands: Commands,
+    mut q_root: Query<
+        (Entity, Option<&Children>, Option<&mut CargoHold>),
+        With<SpaceshipRootMarker>,
+    >,
+    q_section: Query<
+        &SectionCargoCapacity,
+        (Without<HealthZeroMarker>, Without<IntegrityDisabledMarker>),
+    >,
+) {
+    for (root, children, hold) in &mut q_root {
+        let capacity: u32 = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| q_section.get(child).ok())
+            .map(|section| section.0)
+            .sum();
+        match hold {
+            Some(mut hold) => {
+                if hold.capacity == capacity {
+                    continue;
+                }
+                let lost = hold.set_capacity(capacity);
+                if !lost.is_empty() {
+                    info!("sync_cargo_capacity: ship {root:?} lost cargo {lost:?}");
+                }
+            }
+            None if capacity > 0 => {
+                commands.entity(root).insert(CargoHold::new(capacity));
+            }
+            None => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn give_accepts_only_what_fits() {
+        let mut hold = CargoHold::new(5);
+        assert_eq!(hold.give("ore", 3), 3);
+        assert_eq!(hold.give("ice", 4), 2, "two units of room were left");
+        assert_eq!(hold.count("ore"), 3);
+        assert_eq!(hold.count("ice"), 2);
+        assert_eq!(hold.free(), 0);
+        assert_eq!(hold.give("ore", 1), 0);
+    }
+
+    #[test]
+    fn take_stops_at_the_stack_and_drops_empty_stacks() {
+        let mut hold = CargoHold::new(10);
+        hold.give("ore", 3);
+        assert_eq!(hold.take("ore", 5), 3);
+        assert_eq!(hold.count("ore"), 0);
+        assert_eq!(hold.stacks().count(), 0, "an emptied stack is removed");
+        assert_eq!(hold.take("ice", 1), 0);
+    }
+
+    #[test]
+    fn shrinking_keeps_the_surviving_fraction_of_every_stack() {
+        let mut hold = CargoHold::new(10);
+        hold.give("ore", 6);
+        hold.give("ice", 1);
+
+        let lost = hold.set_capacity(5);
+
+        assert_eq!(hold.count("ore"), 3);
+        assert_eq!(hold.count("ice"), 0, "half a unit rounds down to none");
+        assert_eq!(lost.get("ore"), Some(&3));
+        assert_eq!(lost.get("ice"), Some(&1));
+        assert!(hold.used() <= hold.capacity);
+
+        assert!(hold.set_capacity(8).is_empty(), "growing loses nothing");
+        assert_eq!(hold.count("ore"), 3);
+    }
+
+    fn spawn_ship(app: &mut App, capacities: &[u32]) -> (Entity, Vec<Entity>) {
+        let root = app.world_mut().spawn(SpaceshipRootMarker).id();
+        let sections = capacities
+            .iter()
+            .map(|capacity| {
+                app.world_mut()
+                    .spawn((ChildOf(root), SectionCargoCapacity(*capacity)))
+                    .id()
+            })
+            .collect();
+        (root, sections)
+    }
+
+    #[test]
+    fn a_destroyed_cargo_section_takes_its_share_of_the_cargo() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_cargo_capacity);
+        let (root, sections) = spawn_ship(&mut app, &[4, 4]);
+        app.update();
+        app.world_mut()
+            .get_mut::<CargoHold>(root)
+            .expect("a cargo-bearing hull gets a hold")
+            .give("ore", 8);
+
+        app.world_mut()
+            .entity_mut(sections[0])
+            .insert(HealthZeroMarker);
+        app.update();
+
+        let hold = app.world().get::<CargoHold>(root).unwrap();
+        assert_eq!(hold.capacity, 4);
+        assert_eq!(hold.count("ore"), 4);
+    }
+
+    #[test]
+    fn a_ship_without_cargo_sections_gets_no_hold() {
+        let mut app = App::new();
+        app.add_systems(Update, sync_cargo_capacity);
+        let (root, _) = spawn_ship(&mut app, &[]);
+        app.update();
+        assert!(app.world().get::<CargoHold>(root).is_none());
+    }
+}
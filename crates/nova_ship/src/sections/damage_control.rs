@@ -0,0 +1,731 @@
+//! Damage control: repairs that take time and parts, and rebuilding the
+//! sections a ship has lost.
+//!
+//! A ship root carrying [`DamageControl`] holds a stock of repair PARTS and
+//! remembers how long ago it was last hit. Two kinds of job spend them:
+//!
+//! - a [`SectionRepairJob`] on a damaged section raises its [`Health`] toward
+//!   full at [`REPAIR_RATE`] hit points a second. It costs
+//!   [`REPAIR_PART_COST`] up front and any hit on the section cancels it. The
+//!   part is gone either way, so patching a section under fire is a gamble.
+//! - a [`SectionRebuildJob`] on the ship root brings back a section the ship
+//!   has lost, destroyed or severed with a wreck. It costs
+//!   [`REBUILD_PART_COST`], takes [`REBUILD_SECONDS`], needs the ship out of
+//!   combat or docked (a station's berth stands in for the quiet the crew
+//!   would otherwise need), and only builds onto a standing neighbour: the lost
+//!   section must mate a live section through its original link points. A hit
+//!   anywhere on the ship cancels it.
+//!
+//! What a lost section WAS comes from the [`ShipBlueprint`]: every section
+//! that has stood on the ship, recorded as it arrives (id, mount and sockets).
+//! A finished rebuild triggers [`RebuildSection`] on the root and the object
+//! layer, which knows how to build a section from its id, answers it. This
+//! module never needs a section's config, and the integrity graph takes the
+//! new section in as it would any late arrival. A severed section left on a
+//! wreck gives up its id to the rebuilt one ([`retire_wreck_section_ids`]), so
+//! the id names one section again.
+//!
+//! The NOVA OS ship app and the HUD only start jobs and draw them; the ticking,
+//! the costs and the interruption all live here.
+
+use bevy::prelude::*;
+use nova_events::prelude::EntityId;
+use nova_gameplay::prelude::*;
+
+use super::link_points::prelude::*;
+
+/// The damage-control components, the rebuild event, the tuning constants and
+/// the systems that run them.
+pub mod prelude {
+    pub use super::{
+        note_damage_control_hits, retire_wreck_section_ids, survey_ship_blueprints,
+        tick_damage_control, BlueprintSection, DamageControl, DamageControlRefusal, RebuildSection,
+        SectionRebuildJob, SectionRepairJob, ShipBlueprint, WreckOf, DEFAULT_REPAIR_PARTS,
+        OUT_OF_COMBAT_SECONDS, REBUILD_PART_COST, REBUILD_SECONDS, REPAIR_PART_COST, REPAIR_RATE,
+    };
+}
+
+/// Hit points a repair job restores per second.
+pub const REPAIR_RATE: f32 = 20.0;
+
+/// Parts one repair job costs, paid when it starts.
+pub const REPAIR_PART_COST: u32 = 1;
+
+/// Parts one rebuild costs, paid when it starts.
+pub const REBUILD_PART_COST: u32 = 3;
+
+/// Seconds a rebuild takes from start to the section standing again.
+pub const REBUILD_SECONDS: f32 = 15.0;
+
+/// Seconds without taking a hit before a ship counts as out of combat.
+pub const OUT_OF_COMBAT_SECONDS: f32 = 8.0;
+
+/// Parts a player ship carries when its scenario does not say, before the
+/// difficulty scales it.
+pub const DEFAULT_REPAIR_PARTS: u32 = 6;
+
+/// A ship's damage-control station: the parts it has left and how long ago it
+/// was last hit.
+///
+/// Lives on the ship ROOT. A ship without one cannot repair or rebuild at all,
+/// which is every ship but the player's today.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+#[require(ShipBlueprint)]
+pub struct DamageControl {
+    /// Repair parts left.
+    pub parts: u32,
+    /// Parts the ship was stocked with: its authored allowance after the
+    /// difficulty scaled it. What a station's repair restocks to.
+    pub allowance: u32,
+    /// Seconds since the ship last took a hit. Owned by [`tick_damage_control`]
+    /// and [`note_damage_control_hits`].
+    pub since_hit: f32,
+}
+
+impl DamageControl {
+    /// A station stocked with `parts`, its full allowance, starting out of
+    /// combat.
+    pub fn new(parts: u32) -> Self {
+        Self {
+            parts,
+            allowance: parts,
+            since_hit: OUT_OF_COMBAT_SECONDS,
+        }
+    }
+
+    /// Whether the ship took a hit within the last [`OUT_OF_COMBAT_SECONDS`].
+    pub fn in_combat(&self) -> bool {
+        self.since_hit < OUT_OF_COMBAT_SECONDS
+    }
+
+    /// Pay `cost` parts, or refuse without spending any.
+    fn spend(&mut self, cost: u32) -> Result<(), DamageControlRefusal> {
+        if self.parts < cost {
+            return Err(DamageControlRefusal::NoParts {
+                need: cost,
+                have: self.parts,
+            });
+        }
+        self.parts -= cost;
+        Ok(())
+    }
+
+    /// Start patching a section at `health`, paying for it. `busy` is whether
+    /// the section already has a job. The caller inserts the returned job on
+    /// the section.
+    pub fn start_repair(
+        &mut self,
+        health: &Health,
+        busy: bool,
+    ) -> Result<SectionRepairJob, DamageControlRefusal> {
+        if busy {
+            return Err(DamageControlRefusal::Underway);
+        }
+        if health.max <= 0.0 || health.current <= 0.0 || health.current >= health.max {
+            return Err(DamageControlRefusal::NothingToRepair);
+        }
+        self.spend(REPAIR_PART_COST)?;
+        Ok(SectionRepairJob {
+            from: health.current,
+        })
+    }
+
+    /// Start rebuilding the lost section `id` from `blueprint`, paying for it.
+    /// `busy` is whether the ship already has a rebuild underway, `docked`
+    /// whether it is latched to a station: a docked ship may rebuild in
+    /// combat, anywhere else it must have stood down. The caller inserts the
+    /// returned job on the ship root.
+    pub fn start_rebuild(
+        &mut self,
+        blueprint: &ShipBlueprint,
+        id: &str,
+        busy: bool,
+        docked: bool,
+    ) -> Result<SectionRebuildJob, DamageControlRefusal> {
+        let Some(entry) = blueprint.get(id) else {
+            return Err(DamageControlRefusal::UnknownSection);
+        };
+        if entry.standing {
+            return Err(DamageControlRefusal::NotLost);
+        }
+        if busy {
+            return Err(DamageControlRefusal::Underway);
+        }
+        if self.in_combat() && !docked {
+            return Err(DamageControlRefusal::InCombat);
+        }
+        if !entry.attachable {
+            return Err(DamageControlRefusal::NoStandingNeighbour);
+        }
+        self.spend(REBUILD_PART_COST)?;
+        Ok(SectionRebuildJob {
+            section: entry.id.clone(),
+            elapsed: 0.0,
+        })
+    }
+}
+
+/// Why damage control would not start a job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DamageControlRefusal {
+    /// The section is at full health, or has no health to restore.
+    NothingToRepair,
+    /// A job of the same kind is already running there.
+    Underway,
+    /// Not enough parts left.
+    NoParts {
+        /// Parts the job costs.
+        need: u32,
+        /// Parts the ship has.
+        have: u32,
+    },
+    /// The ship was hit too recently to rebuild, and is not docked.
+    InCombat,
+    /// The blueprint has no section by that id.
+    UnknownSection,
+    /// The section is still standing.
+    NotLost,
+    /// Nothing standing mates the lost section's link points.
+    NoStandingNeighbour,
+}
+
+impl std::fmt::Display for DamageControlRefusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NothingToRepair => write!(f, "nothing to repair"),
+            Self::Underway => write!(f, "already underway"),
+            Self::NoParts { need, have } => write!(f, "needs {need} parts, {have} aboard"),
+            Self::InCombat => write!(f, "not in combat unless docked"),
+            Self::UnknownSection => write!(f, "no such section on the blueprint"),
+            Self::NotLost => write!(f, "still standing"),
+            Self::NoStandingNeighbour => write!(f, "nothing standing to build onto"),
+        }
+    }
+}
+
+/// A repair in progress on a SECTION: its health climbs at [`REPAIR_RATE`]
+/// until full, and a hit removes the job.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SectionRepairJob {
+    /// Health when the job started, so progress reads from where the patch
+    /// began rather than from zero.
+    pub from: f32,
+}
+
+impl SectionRepairJob {
+    /// Fraction of the patch done for a section now at `health`, `0.0..=1.0`.
+    pub fn progress(&self, health: &Health) -> f32 {
+        let span = health.max - self.from;
+        if span > 0.0 {
+            ((health.current - self.from) / span).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// A rebuild in progress on a ship ROOT. One at a time per ship.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SectionRebuildJob {
+    /// The blueprint id of the section coming back.
+    pub section: String,
+    /// Seconds of work done.
+    pub elapsed: f32,
+}
+
+impl SectionRebuildJob {
+    /// Fraction of the rebuild done, `0.0..=1.0`.
+    pub fn progress(&self) -> f32 {
+        (self.elapsed / REBUILD_SECONDS).clamp(0.0, 1.0)
+    }
+}
+
+/// One section a ship has had, as it stood when it arrived.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct BlueprintSection {
+    /// The section's scenario id ([`EntityId`]).
+    pub id: String,
+    /// Its display name.
+    pub name: String,
+    /// Its mount on the ship root.
+    pub transform: Transform,
+    /// Its sockets, in its own frame.
+    pub link_points: Vec<LinkPoint>,
+    /// Whether a section with this id stands on the ship now.
+    pub standing: bool,
+    /// For a lost section: whether a standing section mates one of its link
+    /// points, so a rebuild has something to attach to.
+    pub attachable: bool,
+}
+
+/// Every section that has stood on a ship, in arrival order, with whether it
+/// still does. Kept by [`survey_ship_blueprints`].
+#[derive(Component, Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ShipBlueprint(pub Vec<BlueprintSection>);
+
+impl ShipBlueprint {
+    /// The entry for `id`, if the ship has ever had it.
+    pub fn get(&self, id: &str) -> Option<&BlueprintSection> {
+        self.0.iter().find(|entry| entry.id == id)
+    }
+
+    /// The sections no longer standing, in arrival order.
+    pub fn lost(&self) -> impl Iterator<Item = &BlueprintSection> {
+        self.0.iter().filter(|entry| !entry.standing)
+    }
+}
+
+/// A finished rebuild: build the blueprint section `section` back onto the
+/// ship root `entity`. Answered by whoever spawned the ship.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct RebuildSection {
+    /// The ship root.
+    pub entity: Entity,
+    /// The blueprint id of the section to build.
+    pub section: String,
+}
+
+/// Where a wreck fragment came from: the ship whose sections it was cut from.
+/// Inserted by the integrity layer when it severs a ship, and carried to any
+/// wreck cut from that wreck in turn.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct WreckOf(pub Entity);
+
+/// Take the id off any section of the rebuilt one's ship that still floats on
+/// a wreck, so the rebuilt section is the only one answering to it.
+///
+/// Section ids are ship-local, so only wrecks [`WreckOf`] the rebuilding ship
+/// are searched. The wreck section stays, inert and damageable, as wreckage
+/// with no scenario identity - which is what a wreck is.
+pub fn retire_wreck_section_ids(
+    rebuild: On<RebuildSection>,
+    mut commands: Commands,
+    q_wrecks: Query<(&WreckOf, &Children)>,
+    q_ids: Query<&EntityId, With<SectionMarker>>,
+) {
+    for (&WreckOf(ship), children) in &q_wrecks {
+        if ship != rebuild.entity {
+            continue;
+        }
+        for child in children.iter() {
+            if q_ids.get(child).is_ok_and(|id| id.0 == rebuild.section) {
+                debug!(
+                    "retire_wreck_section_ids: wreck section {child:?} gives up '{}'",
+                    rebuild.section
+                );
+                commands.entity(child).remove::<EntityId>();
+            }
+        }
+    }
+}
+
+/// Record new sections into each [`ShipBlueprint`] and mark which entries still
+/// stand and which lost ones could be rebuilt.
+///
+/// Runs only when a ship's children change: sections arrive, die and sever as
+/// changes to `Children`, so a quiet ship costs nothing. A section counts as
+/// gone the frame it is depleted, before it despawns, matching the cargo hold.
+pub fn survey_ship_blueprints(
+    mut q_root: Query<
+        (Option<&Children>, &mut ShipBlueprint),
+        (
+            With<SpaceshipRootMarker>,
+            Or<(Changed<Children>, Added<ShipBlueprint>)>,
+        ),
+    >,
+    q_section: Query<
+        (&EntityId, Option<&Name>, &Transform, &SectionLinkPoints),
+        (
+            With<SectionMarker>,
+            Without<HealthZeroMarker>,
+            Without<IntegrityDestroyMarker>,
+        ),
+    >,
+) {
+    for (children, mut blueprint) in &mut q_root {
+        let standing: Vec<_> = children
+            .into_iter()
+            .flat_map(|children| children.iter())
+            .filter_map(|child| q_section.get(child).ok())
+            .collect();
+
+        for &(id, name, transform, link_points) in &standing {
+            if blueprint.get(&id.0).is_none() {
+                blueprint.0.push(BlueprintSection {
+                    id: id.0.clone(),
+                    name: name.map_or_else(|| id.0.clone(), |name| name.as_str().to_string()),
+                    transform: *transform,
+                    link_points: link_points.0.clone(),
+                    standing: true,
+                    attachable: false,
+                });
+            }
+        }
+
+        let placed: Vec<_> = standing
+            .iter()
+            .map(|(_, _, transform, link_points)| PlacedSectionLinkPoints {
+                position: transform.translation,
+                rotation: transform.rotation,
+                link_points: &link_points.0,
+            })
+            .collect();
+        let survey: Vec<_> = blueprint
+            .0
+            .iter()
+            .map(|entry| {
+                let standing = standing.iter().any(|(id, ..)| id.0 == entry.id);
+                (
+                    standing,
+                    !standing && mates_a_standing_section(&placed, entry),
+                )
+            })
+            .collect();
+        for (entry, (standing, attachable)) in blueprint.0.iter_mut().zip(survey) {
+            entry.standing = standing;
+            entry.attachable = attachable;
+        }
+    }
+}
+
+/// Whether the lost `entry`, offered back in its old place, mates any of the
+/// `standing` sections through its link points.
+fn mates_a_standing_section(
+    standing: &[PlacedSectionLinkPoints<'_>],
+    entry: &BlueprintSection,
+) -> bool {
+    let lost = standing.len();
+    let mut placed = standing.to_vec();
+    placed.push(PlacedSectionLinkPoints {
+        position: entry.transform.translation,
+        rotation: entry.transform.rotation,
+        link_points: &entry.link_points,
+    });
+    candidate_link_point_mates(&placed)
+        .iter()
+        .any(|mate| (mate.a.section_index == lost) != (mate.b.section_index == lost))
+}
+
+/// Cancel damage-control work on whatever a hit lands on, and restart the
+/// out-of-combat clock of a ship it reaches.
+///
+/// `HealthApplyDamage` propagates from the section that was hit up to the ship
+/// root, so this sees the section (its repair job) and then the root (its
+/// rebuild and its clock). A zero amount is a hit on a corpse and changes
+/// nothing.
+pub fn note_damage_control_hits(
+    damage: On<HealthApplyDamage>,
+    mut commands: Commands,
+    mut q_control: Query<(&mut DamageControl, Has<SectionRebuildJob>)>,
+    q_repairing: Query<(), With<SectionRepairJob>>,
+) {
+    if damage.amount <= 0.0 {
+        return;
+    }
+    let entity = damage.entity;
+    if q_repairing.contains(entity) {
+        debug!("note_damage_control_hits: repair on {entity:?} interrupted");
+        commands.entity(entity).try_remove::<SectionRepairJob>();
+    }
+    if let Ok((mut control, rebuilding)) = q_control.get_mut(entity) {
+        control.since_hit = 0.0;
+        if rebuilding {
+            debug!("note_damage_control_hits: rebuild on {entity:?} interrupted");
+            commands.entity(entity).try_remove::<SectionRebuildJob>();
+        }
+    }
+}
+
+/// Advance every ship's out-of-combat clock and every running job. A repair
+/// that reaches full health, or whose section is depleted, ends; a rebuild
+/// that reaches [`REBUILD_SECONDS`] ends and triggers [`RebuildSection`].
+pub fn tick_damage_control(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q_control: Query<(Entity, &mut DamageControl, Option<&mut SectionRebuildJob>)>,
+    mut q_repairs: Query<(Entity, &mut Health, &SectionRepairJob)>,
+) {
+    let dt = time.delta_secs();
+    for (root, mut control, rebuild) in &mut q_control {
+        control.since_hit += dt;
+        let Some(mut rebuild) = rebuild else {
+            continue;
+        };
+        rebuild.elapsed += dt;
+        if rebuild.elapsed >= REBUILD_SECONDS {
+            let section = rebuild.section.clone();
+            debug!("tick_damage_control: {root:?} rebuilt {section}");
+            commands.entity(root).remove::<SectionRebuildJob>();
+            commands.trigger(RebuildSection {
+                entity: root,
+                section,
+            });
+        }
+    }
+    for (section, mut health, _) in &mut q_repairs {
+        if health.current <= 0.0 {
+            commands.entity(section).try_remove::<SectionRepairJob>();
+            continue;
+        }
+        health.current = (health.current + REPAIR_RATE * dt).min(health.max);
+        if health.current >= health.max {
+            commands.entity(section).try_remove::<SectionRepairJob>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::time::TimeUpdateStrategy;
+
+    use super::*;
+
+    fn damage_control_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        app.add_observer(note_damage_control_hits);
+        app.add_systems(Update, (survey_ship_blueprints, tick_damage_control));
+        app
+    }
+
+    fn spawn_section(app: &mut App, root: Entity, id: &str, at: Vec3) -> Entity {
+        app.world_mut()
+            .spawn((
+                ChildOf(root),
+                SectionMarker,
+                EntityId::new(id.to_string()),
+                Transform::from_translation(at),
+                SectionLinkPoints(unit_cube_link_points()),
+                Health::new(100.0),
+            ))
+            .id()
+    }
+
+    fn hit(app: &mut App, target: Entity, amount: f32) {
+        app.world_mut().trigger(HealthApplyDamage {
+            entity: target,
+            source: None,
+            amount,
+        });
+        app.world_mut().flush();
+    }
+
+    #[test]
+    fn a_repair_costs_a_part_and_climbs_to_full() {
+        let mut app = damage_control_app();
+        let root = app
+            .world_mut()
+            .spawn((SpaceshipRootMarker, DamageControl::new(2)))
+            .id();
+        let section = spawn_section(&mut app, root, "a", Vec3::ZERO);
+        app.world_mut().get_mut::<Health>(section).unwrap().current = 50.0;
+
+        let job = {
+            let health = app.world().get::<Health>(section).unwrap().clone();
+            let mut control = app.world_mut().get_mut::<DamageControl>(root).unwrap();
+            control.start_repair(&health, false).unwrap()
+        };
+        app.world_mut().entity_mut(section).insert(job);
+        assert_eq!(app.world().get::<DamageControl>(root).unwrap().parts, 1);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        let health = app.world().get::<Health>(section).unwrap();
+        assert!(
+            health.current > 50.0 && health.current < 100.0,
+            "{health:?}"
+        );
+
+        for _ in 0..20 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<Health>(section).unwrap().current, 100.0);
+        assert!(app.world().get::<SectionRepairJob>(section).is_none());
+    }
+
+    #[test]
+    fn a_hit_interrupts_the_repair_and_puts_the_ship_in_combat() {
+        let mut app = damage_control_app();
+        let root = app
+            .world_mut()
+            .spawn((SpaceshipRootMarker, DamageControl::new(1)))
+            .id();
+        let section = spawn_section(&mut app, root, "a", Vec3::ZERO);
+        app.world_mut()
+            .entity_mut(section)
+            .insert(SectionRepairJob { from: 50.0 });
+        app.world_mut().get_mut::<Health>(section).unwrap().current = 50.0;
+
+        hit(&mut app, section, 5.0);
+
+        assert!(app.world().get::<SectionRepairJob>(section).is_none());
+        assert!(app.world().get::<DamageControl>(root).unwrap().in_combat());
+    }
+
+    #[test]
+    fn a_repair_is_refused_without_parts_or_damage() {
+        let mut control = DamageControl::new(0);
+        let mut health = Health::new(100.0);
+        assert_eq!(
+            control.start_repair(&health, false),
+            Err(DamageControlRefusal::NothingToRepair)
+        );
+        health.current = 10.0;
+        assert_eq!(
+            control.start_repair(&health, false),
+            Err(DamageControlRefusal::NoParts { need: 1, have: 0 })
+        );
+        control.parts = 5;
+        assert_eq!(
+            control.start_repair(&health, true),
+            Err(DamageControlRefusal::Underway)
+        );
+        assert_eq!(control.parts, 5, "a refusal spends nothing");
+    }
+
+    #[test]
+    fn a_lost_section_beside_a_standing_one_can_be_rebuilt_out_of_combat() {
+        let mut app = damage_control_app();
+        let root = app
+            .world_mut()
+            .spawn((SpaceshipRootMarker, DamageControl::new(REBUILD_PART_COST)))
+            .id();
+        spawn_section(&mut app, root, "core", Vec3::ZERO);
+        let wing = spawn_section(&mut app, root, "wing", Vec3::X);
+        let tip = spawn_section(&mut app, root, "tip", Vec3::X * 2.0);
+        app.update();
+
+        app.world_mut().entity_mut(wing).despawn();
+        app.world_mut().entity_mut(tip).despawn();
+        app.update();
+
+        let blueprint = app.world().get::<ShipBlueprint>(root).unwrap().clone();
+        let lost: Vec<_> = blueprint.lost().map(|entry| entry.id.as_str()).collect();
+        assert_eq!(lost, ["wing", "tip"]);
+        assert!(blueprint.get("wing").unwrap().attachable);
+        assert!(
+            !blueprint.get("tip").unwrap().attachable,
+            "the tip's only neighbour is the lost wing"
+        );
+
+        let mut control = *app.world().get::<DamageControl>(root).unwrap();
+        assert_eq!(
+            control.start_rebuild(&blueprint, "tip", false, false),
+            Err(DamageControlRefusal::NoStandingNeighbour)
+        );
+        assert_eq!(
+            control.start_rebuild(&blueprint, "core", false, false),
+            Err(DamageControlRefusal::NotLost)
+        );
+        control.since_hit = 0.0;
+        assert_eq!(
+            control.start_rebuild(&blueprint, "wing", false, false),
+            Err(DamageControlRefusal::InCombat)
+        );
+        control.since_hit = OUT_OF_COMBAT_SECONDS;
+        let job = control
+            .start_rebuild(&blueprint, "wing", false, false)
+            .unwrap();
+        assert_eq!(job.section, "wing");
+        assert_eq!(control.parts, 0);
+    }
+
+    #[derive(Resource, Default)]
+    struct Rebuilt(Vec<String>);
+
+    #[test]
+    fn a_finished_rebuild_asks_for_the_section_and_a_hit_cancels_one() {
+        let mut app = damage_control_app();
+        app.init_resource::<Rebuilt>();
+        app.add_observer(
+            |rebuild: On<RebuildSection>, mut rebuilt: ResMut<Rebuilt>| {
+                rebuilt.0.push(rebuild.section.clone());
+            },
+        );
+        let root = app
+            .world_mut()
+            .spawn((
+                SpaceshipRootMarker,
+                DamageControl::new(0),
+                SectionRebuildJob {
+                    section: "wing".to_string(),
+                    elapsed: 0.0,
+                },
+            ))
+            .id();
+
+        hit(&mut app, root, 1.0);
+        assert!(app.world().get::<SectionRebuildJob>(root).is_none());
+
+        app.world_mut().entity_mut(root).insert(SectionRebuildJob {
+            section: "wing".to_string(),
+            elapsed: REBUILD_SECONDS - 0.1,
+        });
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().resource::<Rebuilt>().0, ["wing"]);
+        assert!(app.world().get::<SectionRebuildJob>(root).is_none());
+    }
+
+    /// A docked ship rebuilds even with the guns still warm: the berth is the
+    /// other way to stand down.
+    #[test]
+    fn a_docked_ship_may_rebuild_in_combat() {
+        let blueprint = ShipBlueprint(vec![BlueprintSection {
+            id: "wing".to_string(),
+            name: "wing".to_string(),
+            transform: Transform::default(),
+            link_points: Vec::new(),
+            standing: false,
+            attachable: true,
+        }]);
+        let mut control = DamageControl::new(REBUILD_PART_COST);
+        control.since_hit = 0.0;
+
+        assert_eq!(
+            control.start_rebuild(&blueprint, "wing", false, false),
+            Err(DamageControlRefusal::InCombat)
+        );
+        assert!(control
+            .start_rebuild(&blueprint, "wing", false, true)
+            .is_ok());
+    }
+
+    /// A rebuilt section takes its id back from the severed one still drifting
+    /// on the ship's wreck; another ship's wreck section of the same id keeps
+    /// its own.
+    #[test]
+    fn a_rebuild_takes_the_id_back_from_the_wreck() {
+        let mut app = damage_control_app();
+        app.add_observer(retire_wreck_section_ids);
+        let ship = app.world_mut().spawn(SpaceshipRootMarker).id();
+        let other = app.world_mut().spawn(SpaceshipRootMarker).id();
+        let wreck = app.world_mut().spawn(WreckOf(ship)).id();
+        let foreign = app.world_mut().spawn(WreckOf(other)).id();
+        let severed = spawn_section(&mut app, wreck, "wing", Vec3::X);
+        let kept = spawn_section(&mut app, wreck, "tip", Vec3::X * 2.0);
+        let theirs = spawn_section(&mut app, foreign, "wing", Vec3::X);
+
+        app.world_mut().trigger(RebuildSection {
+            entity: ship,
+            section: "wing".to_string(),
+        });
+        app.world_mut().flush();
+
+        assert!(app.world().get::<EntityId>(severed).is_none());
+        assert!(app.world().get::<EntityId>(kept).is_some());
+        assert!(app.world().get::<EntityId>(theirs).is_some());
+    }
+}
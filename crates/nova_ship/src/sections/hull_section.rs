@@ -4,7 +4,8 @@ use bevy::prelude::*;
 use nova_gameplay::prelude::{AssetRef, SectionClass};
 
 use crate::prelude::{
-    PlaceholderArt, RenderMeshTransform, SectionRenderMeshTransform, SectionRenderOf,
+    PlaceholderArt, RenderMeshTransform, SectionCargoCapacity, SectionRenderMeshTransform,
+    SectionRenderOf,
 };
 
 /// The `hull_section` spawner, its config, marker and `HullSectionPlugin`.
@@ -30,6 +31,15 @@ pub struct HullSectionConfig {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub render_mesh_transform: Option<RenderMeshTransform>,
+    /// Units of cargo room this hull adds to its ship's `CargoHold`. Zero (the
+    /// default) carries no cargo.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub cargo_capacity: u32,
+}
+
+#[cfg(feature = "serde")]
+fn is_zero(value: &u32) -> bool {
+    *value == 0
 }
 
 /// Helper function to create a hull section entity bundle.
@@ -41,6 +51,7 @@ pub fn hull_section(config: HullSectionConfig) -> impl Bundle {
         SectionClass::Hull,
         HullSectionRenderMesh(config.render_mesh),
         SectionRenderMeshTransform(config.render_mesh_transform),
+        SectionCargoCapacity(config.cargo_capacity),
     )
 }
 
@@ -141,6 +152,7 @@ mod test {
         let config = HullSectionConfig {
             render_mesh: Some(custom_scene.clone().into()),
             render_mesh_transform: None,
+            ..default()
         };
         let id = app.world_mut().spawn(hull_section(config)).id();
 
@@ -175,6 +187,7 @@ mod test {
             app.world_mut().spawn(hull_section(HullSectionConfig {
                 render_mesh: Some(AssetRef::from("gltf/hull-01.glb#Scene0".to_string())),
                 render_mesh_transform: xf,
+                ..default()
             }));
             app.world_mut().flush();
             app.update();
@@ -216,6 +229,7 @@ mod test {
                 position: Vec3::new(1.0, 0.0, 0.0),
                 ..default()
             }),
+            ..default()
         };
         let ron = ron::ser::to_string(&authored).expect("serialize");
         let back: HullSectionConfig = ron::from_str(&ron).expect("deserialize");
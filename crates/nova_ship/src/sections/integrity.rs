@@ -9,7 +9,7 @@ use bevy::prelude::*;
 use nova_events::prelude::EntityId;
 use nova_gameplay::prelude::*;
 
-use super::link_points::prelude::*;
+use super::{damage_control::WreckOf, link_points::prelude::*};
 
 /// Ship graph publication, disabled-section behavior, and aggregate health.
 pub mod prelude {
@@ -242,6 +242,7 @@ fn sever_disconnected_structures(
             &AngularVelocity,
             Has<SpaceshipRootMarker>,
             Has<StructuralCollapseMarker>,
+            Option<&WreckOf>,
         ),
         (
             With<IntegrityRoot>,
@@ -271,6 +272,7 @@ fn sever_disconnected_structures(
             angular_velocity,
             is_spaceship,
             collapsing,
+            wreck_of,
         )) = q_roots.get(root)
         else {
             continue;
@@ -348,6 +350,13 @@ fn sever_disconnected_structures(
             .map(|(index, _)| index)
             .expect("a split has components");
 
+        // A wreck remembers the ship it came off, through any number of cuts,
+        // so a rebuild can find the sections it would duplicate.
+        let origin = if is_spaceship {
+            Some(WreckOf(root))
+        } else {
+            wreck_of.copied()
+        };
         let mut bodies = vec![root];
         for (index, component) in components.iter().enumerate() {
             if index == retained {
@@ -368,6 +377,9 @@ fn sever_disconnected_structures(
                     TransformInterpolation,
                 ))
                 .id();
+            if let Some(origin) = origin {
+                commands.entity(fragment).insert(origin);
+            }
             bodies.push(fragment);
             for section in component {
                 let Ok((_, transform, ..)) = q_sections.get(*section) else {
@@ -1411,6 +1423,11 @@ mod physics_tests {
             .collect();
         assert_eq!(fragments.len(), 1, "one detached component makes one wreck");
         let fragment = fragments[0];
+        assert_eq!(
+            app.world().get::<WreckOf>(fragment),
+            Some(&WreckOf(root)),
+            "a wreck remembers the ship it came off"
+        );
         assert_eq!(
             app.world().get::<ColliderOf>(left).unwrap().body,
             root,
@@ -1486,6 +1503,12 @@ mod physics_tests {
         let right_body = app.world().get::<ColliderOf>(right).unwrap().body;
         let rear_body = app.world().get::<ColliderOf>(rear).unwrap().body;
         assert_ne!(right_body, rear_body, "the cut made two wreck bodies");
+        assert!(
+            fragment_roots
+                .iter()
+                .all(|&wreck| app.world().get::<WreckOf>(wreck) == Some(&WreckOf(root))),
+            "a wreck cut from a wreck still names the ship"
+        );
 
         // Removing the only section from the new wreck also removes its
         // otherwise-healthless root. Stable tie resolution decides which side
@@ -6,9 +6,11 @@ use nova_gameplay::gravity::prelude::NovaGravitySystems;
 
 pub mod ammo;
 pub mod base_section;
+pub mod cargo;
 pub mod catalog_ids;
 pub mod clearance;
 pub mod controller_section;
+pub mod damage_control;
 pub mod damage_cracks;
 pub mod damage_effects;
 pub mod damage_plume;
@@ -32,15 +34,15 @@ pub mod turret_section;
 /// `SpaceshipSectionPlugin` with `SpaceshipSectionSystems`.
 pub mod prelude {
     pub use super::{
-        ammo::prelude::*, base_section::prelude::*, catalog_ids::prelude::*, clearance::prelude::*,
-        controller_section::prelude::*, damage_cracks::prelude::*, damage_effects::prelude::*,
-        damage_plume::prelude::*, damage_sparks::prelude::*, fixture::prelude::*,
-        hull_section::prelude::*, integrity::prelude::*, link_points::prelude::*,
-        live_structure_anchor, placeholder_art::prelude::*, shell_shape::prelude::*,
-        shell_skin::prelude::*, skin_decor::prelude::*, skin_reading::prelude::*,
-        skin_report::prelude::*, skin_style::prelude::*, thruster_section::prelude::*,
-        torpedo_section::prelude::*, turret_section::prelude::*, SpaceshipSectionPlugin,
-        SpaceshipSectionSystems,
+        ammo::prelude::*, base_section::prelude::*, cargo::prelude::*, catalog_ids::prelude::*,
+        clearance::prelude::*, controller_section::prelude::*, damage_control::prelude::*,
+        damage_cracks::prelude::*, damage_effects::prelude::*, damage_plume::prelude::*,
+        damage_sparks::prelude::*, fixture::prelude::*, hull_section::prelude::*,
+        integrity::prelude::*, link_points::prelude::*, live_structure_anchor,
+        placeholder_art::prelude::*, shell_shape::prelude::*, shell_skin::prelude::*,
+        skin_decor::prelude::*, skin_reading::prelude::*, skin_report::prelude::*,
+        skin_style::prelude::*, thruster_section::prelude::*, torpedo_section::prelude::*,
+        turret_section::prelude::*, SpaceshipSectionPlugin, SpaceshipSectionSystems,
     };
 }
 
@@ -217,7 +219,32 @@ impl Plugin for SpaceshipSectionPlugin {
 
         app.register_type::<ammo::SectionAmmo>();
         app.register_type::<ammo::SectionReload>();
+        app.register_type::<cargo::SectionCargoCapacity>();
+        app.register_type::<cargo::CargoHold>();
+        app.register_type::<damage_control::DamageControl>();
+        app.register_type::<damage_control::SectionRepairJob>();
+        app.register_type::<damage_control::SectionRebuildJob>();
+        app.register_type::<damage_control::ShipBlueprint>();
+        app.register_type::<damage_control::WreckOf>();
         app.add_plugins(integrity::ShipIntegrityPlugin);
+        // After the integrity pass, so a section depleted this frame has
+        // already stopped counting when the hold is resized.
+        app.add_systems(
+            Update,
+            cargo::sync_cargo_capacity.after(nova_gameplay::prelude::IntegritySystems),
+        );
+        // Same edge for the blueprint survey: a section depleted this frame is
+        // already lost when the rebuild list is drawn up.
+        app.add_observer(damage_control::note_damage_control_hits);
+        app.add_observer(damage_control::retire_wreck_section_ids);
+        app.add_systems(
+            Update,
+            (
+                damage_control::tick_damage_control,
+                damage_control::survey_ship_blueprints
+                    .after(nova_gameplay::prelude::IntegritySystems),
+            ),
+        );
         // A successful shot resets reload progress. Run the reload pass after
         // every section fire system so the shot wins an exact completion tick.
         app.add_systems(
@@ -196,6 +196,31 @@ pub struct TurretSectionConfig {
         serde(default, skip_serializing_if = "Option::is_none")
     )]
     pub reload: Option<SectionReloadConfig>,
+    /// What one round carves out of ROCK, as a multiple of `bullet_damage`.
+    /// Rock pays for a hit in material, so this is the mining knob: a mining
+    /// laser authors a low `bullet_damage` and a high multiplier, and cuts rock
+    /// fast while barely scratching a ship. 1.0 (the default) is a combat gun.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "default_rock_multiplier",
+            skip_serializing_if = "is_default_rock_multiplier"
+        )
+    )]
+    pub rock_multiplier: f32,
+}
+
+/// A turret that authors no `rock_multiplier` is a combat gun: rock takes
+/// exactly what a ship would.
+pub(super) fn default_rock_multiplier() -> f32 {
+    1.0
+}
+
+/// Skip serializing `rock_multiplier` on every gun that is not a mining tool,
+/// so the combat catalog reads as it did before mining existed.
+#[cfg(feature = "serde")]
+fn is_default_rock_multiplier(rock_multiplier: &f32) -> bool {
+    *rock_multiplier == default_rock_multiplier()
 }
 
 impl Default for TurretSectionConfig {
@@ -272,6 +297,7 @@ impl Default for TurretSectionConfig {
             dry_fire_sound: None,
             ammo_capacity: None,
             reload: None,
+            rock_multiplier: default_rock_multiplier(),
         }
     }
 }
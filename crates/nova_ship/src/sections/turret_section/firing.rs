@@ -298,7 +298,8 @@ pub(super) fn shoot_spawn_projectile(
                     // not a hardcoded type, so a future ammo switch changes
                     // what this stamps. The closing-speed scaling is applied
                     // at the HIT, not here - the target is not known yet.
-                    ProjectileDamage::new(bullet_damage, bullet_kind),
+                    ProjectileDamage::new(bullet_damage, bullet_kind)
+                        .with_rock_multiplier(config.rock_multiplier),
                     TurretSectionPartOf(turret),
                     TurretSectionMuzzleEntity(muzzle),
                     BulletProjectileRenderMesh(config.projectile_render_mesh.clone()),
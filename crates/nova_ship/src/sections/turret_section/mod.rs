@@ -40,7 +40,7 @@ pub mod prelude {
         muzzle_aim_error, muzzle_on_target, turret_section, LoadedBullet, MuzzleConfig,
         TurretJoint, TurretSectionAimPoint, TurretSectionAimSystems, TurretSectionArc,
         TurretSectionBarrelMuzzleMarker, TurretSectionConfig, TurretSectionConfigHelper,
-        TurretSectionInput, TurretSectionMuzzleEntity, TurretSectionPlugin,
+        TurretSectionInput, TurretSectionMuzzleEntity, TurretSectionPartOf, TurretSectionPlugin,
         TurretSectionTargetEntity, TurretSectionTargetInput, TurretSectionTargetTrack,
         TurretSectionTargetVelocity, CLOSE_ENGAGEMENT_RANGE, HULL_HIT_RADIUS, TURRET_ON_TARGET_RAD,
     };
@@ -131,12 +131,14 @@ pub struct TurretSectionTargetEntity(pub Option<Entity>);
 #[derive(Component, Clone, Copy, Debug, Default, Deref, DerefMut, Reflect)]
 pub struct TurretSectionAimPoint(pub Option<Vec3>);
 
-/// The Turret "parent" entity of the turret component.
+/// The Turret "parent" entity of the turret component: on its joints and on
+/// every round it fires.
 ///
-/// `pub(crate)` so the audio module can key each gun's fire SFX by its turret
-/// entity (multiple guns each sound). Not re-exported from the public prelude.
+/// The audio module keys each gun's fire SFX by it (multiple guns each
+/// sound); pub so the scenario debrief can credit a round's hit to the turret
+/// that fired it, as [`TorpedoSectionPartOf`] does for a bay's torpedoes.
 #[derive(Component, Clone, Copy, Debug, Deref, DerefMut, Reflect)]
-pub(crate) struct TurretSectionPartOf(pub Entity);
+pub struct TurretSectionPartOf(pub Entity);
 
 #[derive(Component, Clone, Debug, Deref, DerefMut, Reflect)]
 struct BulletProjectileRenderMesh(#[reflect(ignore)] Option<AssetRef<WorldAsset>>);
@@ -1,12 +1,18 @@
 //! The continuous loops: one looping audio entity per distinct authored
 //! thruster hum, plus the RCS fine-adjust loop, each tracking how hard
 //! the ships it belongs to are burning and muting behind a frozen sim.
+//!
+//! Each loop is a spatial sink parked on its loudest ship, so a hum pans with
+//! the ship that is making it, and plays on the SFX bus.
 
 use std::collections::HashMap;
 
-use bevy::{audio::Volume, prelude::*};
+use bevy::{
+    audio::{SpatialAudioSink, Volume},
+    prelude::*,
+};
 use nova_gameplay::{
-    audio::{distance_attenuation, listener_position, SfxListenerMarker},
+    audio::{distance_attenuation, listener_position, sfx_spatial_scale, SfxListenerMarker},
     prelude::*,
 };
 
@@ -28,7 +34,8 @@ pub(super) struct ThrusterLoopSfx(Handle<AudioSource>);
 /// Spawn a looping engine-hum entity for every hum handle the compute pass
 /// discovered that has no loop entity yet. Each starts silent;
 /// [`apply_thruster_loop_volume`] raises it with its handle's smoothed level.
-/// `PlaybackSettings::LOOP` keeps it playing for the whole session.
+/// `PlaybackSettings::LOOP` keeps it playing for the whole session; the sink is
+/// spatial, and the apply pass moves it onto whichever ship is loudest.
 pub(super) fn ensure_thruster_loops(
     hum: Res<ThrusterHumVolume>,
     existing: Query<&ThrusterLoopSfx>,
@@ -42,13 +49,23 @@ pub(super) fn ensure_thruster_loops(
             Name::new("Thruster Loop Sfx"),
             ThrusterLoopSfx(handle.clone()),
             AudioPlayer(handle.clone()),
-            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+            loop_playback(),
+            Transform::default(),
         ));
     }
 }
 
+/// The playback every session loop starts with: looping, silent until the
+/// apply pass raises it, and spatial at the shared SFX scale so it pans.
+fn loop_playback() -> PlaybackSettings {
+    PlaybackSettings::LOOP
+        .with_volume(Volume::Linear(0.0))
+        .with_spatial(true)
+        .with_spatial_scale(sfx_spatial_scale())
+}
+
 /// One hum's live volume pair: where it wants to be this frame and the
-/// smoothed level chasing it.
+/// smoothed level chasing it, plus where it is heard from.
 #[derive(Default, Debug)]
 struct HumLevels {
     /// The loudest per-ship contribution for this handle, each
@@ -56,6 +73,47 @@ struct HumLevels {
     target: f32,
     /// The smoothed volume actually applied to the sink, chasing `target`.
     smoothed: f32,
+    /// World position of the loudest contributor, the point the loop pans
+    /// from. Kept when nobody burns, so a fading hum does not jump sides.
+    source: Option<Vec3>,
+}
+
+/// Fold this frame's per-handle `(level, source)` targets into the persistent
+/// map and smooth every entry toward its target. Unseen handles keep an entry
+/// targeting 0 (their loop smooths down and idles), new handles join.
+/// Exponential smoothing per handle, framerate-independent: ~8 units/s of
+/// catch-up.
+fn fold_loop_targets(
+    levels: &mut HashMap<Handle<AudioSource>, HumLevels>,
+    targets: HashMap<Handle<AudioSource>, (f32, Option<Vec3>)>,
+    dt: f32,
+) {
+    let alpha = (dt * 8.0).clamp(0.0, 1.0);
+    for entry in levels.values_mut() {
+        entry.target = 0.0;
+    }
+    for (handle, (target, source)) in targets {
+        let entry = levels.entry(handle).or_default();
+        entry.target = target;
+        entry.source = source.or(entry.source);
+    }
+    for entry in levels.values_mut() {
+        entry.smoothed += (entry.target - entry.smoothed) * alpha;
+    }
+}
+
+/// Record `level` for `handle` if it is the loudest contribution so far,
+/// taking `source` with it as the point the loop pans from.
+fn raise_loop_target(
+    targets: &mut HashMap<Handle<AudioSource>, (f32, Option<Vec3>)>,
+    handle: Handle<AudioSource>,
+    level: f32,
+    source: Option<Vec3>,
+) {
+    let slot = targets.entry(handle).or_insert((f32::NEG_INFINITY, None));
+    if level > slot.0 {
+        *slot = (level, source);
+    }
 }
 
 /// The live engine-hum volumes PER RESOLVED HANDLE, written by
@@ -143,36 +201,24 @@ pub(super) fn compute_thruster_hum_volume(
     // Per handle: loudest ship wins. Max, not sum: distinct ships burning the
     // SAME hum do not stack its loop past the per-ship ceiling; DIFFERENT hums
     // are independent loops and may sound together.
-    let mut targets: HashMap<Handle<AudioSource>, f32> = HashMap::new();
+    let mut targets = HashMap::new();
     for ((handle, source), (sum, count)) in &per_pair {
         let avg_throttle = sum / *count as f32;
+        let pose = q_pose.get(*source).ok().map(|p| p.translation());
         let attenuation = if q_is_player.contains(*source) {
             1.0
         } else {
-            match (listener, q_pose.get(*source)) {
-                (Some(l), Ok(pose)) => distance_attenuation(l.distance(pose.translation())),
+            match (listener, pose) {
+                (Some(l), Some(pose)) => distance_attenuation(l.distance(pose)),
                 // No listener or no pose: full volume, like the one-shots.
                 _ => 1.0,
             }
         };
         let level = engine_volume(avg_throttle) * attenuation;
-        let slot = targets.entry(handle.clone()).or_insert(0.0);
-        *slot = slot.max(level);
+        raise_loop_target(&mut targets, handle.clone(), level, pose);
     }
 
-    // Fold into the persistent map: unseen handles keep an entry targeting 0
-    // (their loop smooths down and idles), new handles join. Exponential
-    // smoothing per handle, framerate-independent: ~8 units/s of catch-up.
-    let alpha = (time.delta_secs() * 8.0).clamp(0.0, 1.0);
-    for levels in hum.hums.values_mut() {
-        levels.target = 0.0;
-    }
-    for (handle, target) in targets {
-        hum.hums.entry(handle).or_default().target = target;
-    }
-    for levels in hum.hums.values_mut() {
-        levels.smoothed += (levels.target - levels.smoothed) * alpha;
-    }
+    fold_loop_targets(&mut hum.hums, targets, time.delta_secs());
 }
 
 /// Copy the computed hum volume onto the loop's sink. The `AudioSink` appears
@@ -181,22 +227,43 @@ pub(super) fn compute_thruster_hum_volume(
 /// sink is absent, so a scene that loads with hot engines starts the loop at
 /// the caught-up volume instead of fading up from silence - those first
 /// frames have nothing to fade from, and a correct level beats a late ramp.
-/// `master` is `Option` so audio-only test rigs that never add the settings
-/// plugin keep full volume instead of panicking on a missing resource; the
-/// loop is scaled by [`MasterVolume`] here because it sets its own sink volume
-/// every frame and so bypasses the `GlobalVolume` path bevy applies to
-/// freshly-spawned one-shot sinks.
+/// The volume inputs are `Option` so audio-only test rigs that never add the
+/// settings plugin keep full volume instead of panicking on a missing
+/// resource; the loop is scaled by [`bus_output_gain`] here because it sets its
+/// own sink volume every frame and so bypasses the `GlobalVolume` path bevy
+/// applies to freshly-spawned one-shot sinks. The loop entity follows its
+/// loudest ship, which is what pans it.
 pub(super) fn apply_thruster_loop_volume(
     hum: Res<ThrusterHumVolume>,
-    master: Option<Res<nova_gameplay::settings::MasterVolume>>,
-    mute: Option<Res<nova_gameplay::settings::HarnessMute>>,
-    mut q_sink: Query<(&mut AudioSink, &ThrusterLoopSfx)>,
+    master: Option<Res<MasterVolume>>,
+    buses: Option<Res<BusVolumes>>,
+    mute: Option<Res<HarnessMute>>,
+    mut q_sink: Query<(&mut SpatialAudioSink, &mut Transform, &ThrusterLoopSfx)>,
+) {
+    let gain = bus_output_gain(
+        AudioBus::Sfx,
+        master.as_deref(),
+        buses.as_deref(),
+        mute.as_deref(),
+    );
+    for (mut sink, mut transform, sfx) in &mut q_sink {
+        apply_loop_levels(hum.hums.get(&sfx.0), gain, &mut sink, &mut transform);
+    }
+}
+
+/// Write one loop's smoothed level and pan position onto its sink.
+fn apply_loop_levels(
+    levels: Option<&HumLevels>,
+    gain: f32,
+    sink: &mut SpatialAudioSink,
+    transform: &mut Transform,
 ) {
-    let mute = mute.map(|m| *m).unwrap_or_default();
-    let master = master.map(|m| m.output_gain(mute)).unwrap_or(1.0);
-    for (mut sink, sfx) in &mut q_sink {
-        let smoothed = hum.hums.get(&sfx.0).map(|l| l.smoothed).unwrap_or(0.0);
-        sink.set_volume(Volume::Linear(smoothed * master));
+    let smoothed = levels.map(|l| l.smoothed).unwrap_or(0.0);
+    sink.set_volume(Volume::Linear(smoothed * gain));
+    if let Some(source) = levels.and_then(|l| l.source) {
+        if transform.translation != source {
+            transform.translation = source;
+        }
     }
 }
 
@@ -232,7 +299,8 @@ pub(super) fn ensure_rcs_loops(
             Name::new("RCS Loop Sfx"),
             RcsLoopSfx(handle.clone()),
             AudioPlayer(handle.clone()),
-            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+            loop_playback(),
+            Transform::default(),
         ));
     }
 }
@@ -265,7 +333,7 @@ pub(super) fn compute_rcs_loop_volume(
     let listener = listener_position(&q_camera);
 
     // Per handle: the loudest ship burning that authored rcs_loop wins.
-    let mut targets: HashMap<Handle<AudioSource>, f32> = HashMap::new();
+    let mut targets = HashMap::new();
     for (&ChildOf(root), sounds, withheld) in &q_controllers {
         // Same capability gate as rcs_burn_system: no Rcs verb, no hiss.
         if !withheld.is_none_or(|w| w.granted(FlightVerb::Rcs)) {
@@ -283,45 +351,41 @@ pub(super) fn compute_rcs_loop_volume(
         if effort <= 1e-4 {
             continue;
         }
+        let pose = q_pose.get(root).ok().map(|p| p.translation());
         let attenuation = if q_is_player.contains(root) {
             1.0
         } else {
-            match (listener, q_pose.get(root)) {
-                (Some(l), Ok(pose)) => distance_attenuation(l.distance(pose.translation())),
+            match (listener, pose) {
+                (Some(l), Some(pose)) => distance_attenuation(l.distance(pose)),
                 _ => 1.0,
             }
         };
         let level = rcs_volume(effort) * attenuation;
-        let slot = targets.entry(handle).or_insert(0.0);
-        *slot = slot.max(level);
+        raise_loop_target(&mut targets, handle, level, pose);
     }
 
-    let alpha = (time.delta_secs() * 8.0).clamp(0.0, 1.0);
-    for levels in vol.loops.values_mut() {
-        levels.target = 0.0;
-    }
-    for (handle, target) in targets {
-        vol.loops.entry(handle).or_default().target = target;
-    }
-    for levels in vol.loops.values_mut() {
-        levels.smoothed += (levels.target - levels.smoothed) * alpha;
-    }
+    fold_loop_targets(&mut vol.loops, targets, time.delta_secs());
 }
 
 /// Copy the computed RCS-loop volume onto the loop's sink. Mirrors
 /// [`apply_thruster_loop_volume`] (no-ops until the sink appears; scales by
-/// [`MasterVolume`] because it sets its own sink volume every frame).
+/// [`bus_output_gain`] because it sets its own sink volume every frame; follows
+/// its loudest ship).
 pub(super) fn apply_rcs_loop_volume(
     vol: Res<RcsLoopVolume>,
-    master: Option<Res<nova_gameplay::settings::MasterVolume>>,
-    mute: Option<Res<nova_gameplay::settings::HarnessMute>>,
-    mut q_sink: Query<(&mut AudioSink, &RcsLoopSfx)>,
+    master: Option<Res<MasterVolume>>,
+    buses: Option<Res<BusVolumes>>,
+    mute: Option<Res<HarnessMute>>,
+    mut q_sink: Query<(&mut SpatialAudioSink, &mut Transform, &RcsLoopSfx)>,
 ) {
-    let mute = mute.map(|m| *m).unwrap_or_default();
-    let master = master.map(|m| m.output_gain(mute)).unwrap_or(1.0);
-    for (mut sink, sfx) in &mut q_sink {
-        let smoothed = vol.loops.get(&sfx.0).map(|l| l.smoothed).unwrap_or(0.0);
-        sink.set_volume(Volume::Linear(smoothed * master));
+    let gain = bus_output_gain(
+        AudioBus::Sfx,
+        master.as_deref(),
+        buses.as_deref(),
+        mute.as_deref(),
+    );
+    for (mut sink, mut transform, sfx) in &mut q_sink {
+        apply_loop_levels(vol.loops.get(&sfx.0), gain, &mut sink, &mut transform);
     }
 }
 
@@ -331,8 +395,8 @@ pub(super) fn apply_rcs_loop_volume(
 /// sinks do not follow `Time<Virtual>`, so without this a loop keeps roaring at
 /// its last volume while the game is frozen.
 pub(super) fn pause_loops(
-    q_thruster: Query<&AudioSink, With<ThrusterLoopSfx>>,
-    q_rcs: Query<&AudioSink, With<RcsLoopSfx>>,
+    q_thruster: Query<&SpatialAudioSink, With<ThrusterLoopSfx>>,
+    q_rcs: Query<&SpatialAudioSink, With<RcsLoopSfx>>,
 ) {
     for sink in &q_thruster {
         sink.pause();
@@ -363,8 +427,8 @@ pub(super) fn silence_loops_on_scenario_unload(
 }
 
 pub(super) fn resume_loops(
-    q_thruster: Query<&AudioSink, With<ThrusterLoopSfx>>,
-    q_rcs: Query<&AudioSink, With<RcsLoopSfx>>,
+    q_thruster: Query<&SpatialAudioSink, With<ThrusterLoopSfx>>,
+    q_rcs: Query<&SpatialAudioSink, With<RcsLoopSfx>>,
 ) {
     for sink in &q_thruster {
         sink.play();
@@ -540,6 +604,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn the_hum_pans_from_its_loudest_ship() {
+        // The loop is parked where the loudest contributor is, so a hum off
+        // the port side is heard there; the quieter ship does not pull it.
+        let mut app = hum_app();
+        spawn_listener_at(&mut app, Vec3::ZERO);
+        let loud = Vec3::new(-40.0, 0.0, 0.0);
+        spawn_burning_ship(&mut app, Vec3::new(40.0, 0.0, 0.0), 0.3);
+        spawn_burning_ship(&mut app, loud, 1.0);
+        app.update();
+
+        let handle = rig_hum_handle(&app);
+        let source = app.world().resource::<ThrusterHumVolume>().hums[&handle].source;
+        assert_eq!(source, Some(loud));
+    }
+
     #[test]
     fn a_rootless_thruster_attenuates_at_its_own_pose() {
         // Torpedo shape: the thruster hangs off a projectile root that is NOT
@@ -103,14 +103,18 @@ impl Plugin for ShipAudioPlugin {
         }
 
         // Audio sinks do not follow Time<Virtual>: without this the thruster
-        // hum keeps roaring at its last volume behind a frozen sim. BOTH
-        // frozen overlays need it - the pause overlay and the Tab
-        // ship-computer NOVA OS, which freezes the same way (see
-        // PauseStates::is_frozen).
+        // hum keeps roaring at its last volume behind a frozen sim. EVERY
+        // frozen overlay needs it - the pause overlay, its photo mode, the
+        // Tab ship-computer NOVA OS and the docked refit bay, which freeze the
+        // same way (see PauseStates::is_frozen).
         app.add_systems(OnEnter(nova_gameplay::PauseStates::Paused), pause_loops);
         app.add_systems(OnExit(nova_gameplay::PauseStates::Paused), resume_loops);
+        app.add_systems(OnEnter(nova_gameplay::PauseStates::Photo), pause_loops);
+        app.add_systems(OnExit(nova_gameplay::PauseStates::Photo), resume_loops);
         app.add_systems(OnEnter(nova_gameplay::PauseStates::NovaOs), pause_loops);
         app.add_systems(OnExit(nova_gameplay::PauseStates::NovaOs), resume_loops);
+        app.add_systems(OnEnter(nova_gameplay::PauseStates::Refit), pause_loops);
+        app.add_systems(OnExit(nova_gameplay::PauseStates::Refit), resume_loops);
         app.add_systems(
             OnExit(nova_gameplay::GameStates::Playing),
             silence_loops_on_scenario_unload,
@@ -7,7 +7,9 @@
 //! `widget` holds the skin-aware themed button + selection machinery and small
 //! layout helpers; `hud` holds the flight-HUD chip language (phosphor-only
 //! chrome projected over the world); `font` holds the shared UI typeface handle
-//! preloaded at startup; `status_bar` holds the top-right metrics bar.
+//! preloaded at startup; `status_bar` holds the top-right metrics bar;
+//! `text_scale` holds the [`TextScale`](text_scale::TextScale) multiplier over
+//! every UI font size.
 
 #![warn(missing_docs)]
 
@@ -18,6 +20,7 @@ pub mod hud;
 pub mod screen;
 pub mod skin;
 pub mod status_bar;
+pub mod text_scale;
 pub mod theme;
 pub mod units;
 pub mod widget;
@@ -47,6 +50,11 @@ impl Plugin for NovaUiPlugin {
         widget::build(app);
         status_bar::build(app);
         screen::build(app);
+        text_scale::build(app);
+        // NOTE: owned here like `UiSkin`; settings writes it through the
+        // accessibility settings.
+        app.init_resource::<theme::semantic::Palette>();
+        app.register_type::<theme::semantic::Palette>();
     }
 }
 
@@ -55,13 +63,13 @@ impl Plugin for NovaUiPlugin {
 /// themed-button widgets ([`themed_button`](widget::themed_button),
 /// [`ThemedButton`](widget::ThemedButton), [`Selected`](widget::Selected), ...)
 /// into scope, plus the [`screen`] composition helpers and the [`status_bar`]
-/// names the composition root spawns.
+/// names the composition root spawns and the [`text_scale`] multiplier.
 ///
 /// Each module owns its own `prelude`, so publishing a new name is a one-file
 /// edit rather than an edit here as well.
 pub mod prelude {
     pub use crate::{
         font::prelude::*, hud::prelude::*, screen::prelude::*, skin::prelude::*,
-        status_bar::prelude::*, theme, units, widget::prelude::*,
+        status_bar::prelude::*, text_scale::prelude::*, theme, units, widget::prelude::*,
     };
 }
@@ -0,0 +1,168 @@
+//! UI text scaling: one multiplier over every authored UI font size.
+//!
+//! Text sites keep spawning their authored `FontSize::Px` sizes; the
+//! [`TextScale`] resource multiplies them in place. The authored size is kept
+//! on a [`BaseFontSize`] the first time a span is seen, so a scale change
+//! rescales from the authored size and never compounds. A site that writes a
+//! new size later simply becomes the new authored size. Only UI text (`Text`
+//! and `TextSpan`) scales - world-space `Text2d` labels keep their size.
+//!
+//! Settings owns the choice (through the accessibility settings); this module
+//! only applies it, so menu-less apps run at 1.0 with no I/O.
+
+/// Glob-import surface for the text-scale resource.
+pub mod prelude {
+    pub use super::{BaseFontSize, TextScale};
+}
+
+use bevy::prelude::*;
+
+/// Multiplier over every UI font size. Default 1.0 (the authored sizes).
+#[derive(Resource, Reflect, Clone, Copy, PartialEq, Debug)]
+#[reflect(Resource)]
+pub struct TextScale(pub f32);
+
+impl Default for TextScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+impl TextScale {
+    /// The smallest scale a setting may take.
+    pub const MIN: f32 = 0.75;
+    /// The largest scale a setting may take. The HUD corners are laid out for
+    /// the authored sizes; past this the chips crowd each other.
+    pub const MAX: f32 = 1.75;
+
+    /// The clamped multiplier, so a corrupt persisted value cannot blow the
+    /// UI up or shrink it to nothing.
+    pub fn factor(self) -> f32 {
+        self.0.clamp(Self::MIN, Self::MAX)
+    }
+}
+
+/// The authored pixel size of a scaled text span and the size last written
+/// over it, recorded the first time [`apply_text_scale`] sees the span.
+#[derive(Component, Clone, Copy, PartialEq, Debug)]
+pub struct BaseFontSize {
+    /// The size the spawning site asked for.
+    pub authored: f32,
+    /// The size the scale last wrote. A span showing any other size was
+    /// re-authored since.
+    pub applied: f32,
+}
+
+pub(crate) fn build(app: &mut App) {
+    app.init_resource::<TextScale>();
+    app.register_type::<TextScale>();
+    // NOTE: before `UiSystems::Content`, like the font router, so a freshly
+    // spawned span is measured at its scaled size on its first frame.
+    app.add_systems(
+        PostUpdate,
+        apply_text_scale.before(bevy::ui::UiSystems::Content),
+    );
+}
+
+/// Keep every UI span at `authored * scale`. A span showing a size this
+/// system did not write is new or was re-authored, so that size becomes its
+/// authored size. On a scale change every span is rewritten.
+fn apply_text_scale(
+    scale: Res<TextScale>,
+    mut commands: Commands,
+    mut spans: Query<
+        (Entity, &mut TextFont, Option<&mut BaseFontSize>),
+        Or<(With<Text>, With<TextSpan>)>,
+    >,
+) {
+    let factor = scale.factor();
+    let rescale_all = scale.is_changed();
+    for (entity, mut font, base) in &mut spans {
+        if !rescale_all && !font.is_changed() {
+            continue;
+        }
+        let FontSize::Px(px) = font.font_size else {
+            continue;
+        };
+        let wanted = match base {
+            Some(mut base) => {
+                if px != base.applied {
+                    base.authored = px;
+                }
+                base.applied = base.authored * factor;
+                base.applied
+            }
+            None => {
+                commands.entity(entity).insert(BaseFontSize {
+                    authored: px,
+                    applied: px * factor,
+                });
+                px * factor
+            }
+        };
+        if px != wanted {
+            font.font_size = FontSize::Px(wanted);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scale_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        build(&mut app);
+        app
+    }
+
+    fn px(app: &App, entity: Entity) -> f32 {
+        match app.world().get::<TextFont>(entity).unwrap().font_size {
+            FontSize::Px(px) => px,
+            other => panic!("unexpected font size {other:?}"),
+        }
+    }
+
+    /// A scale change rescales from the AUTHORED size - two changes in a row
+    /// must not compound - and a site that re-authors the size moves the base.
+    #[test]
+    fn text_scales_from_its_authored_size() {
+        let mut app = scale_app();
+        app.insert_resource(TextScale(1.5));
+        let text = app
+            .world_mut()
+            .spawn((Text::new("hull"), TextFont::from_font_size(10.0)))
+            .id();
+        app.update();
+        assert_eq!(px(&app, text), 15.0);
+
+        app.insert_resource(TextScale(1.25));
+        app.update();
+        assert_eq!(px(&app, text), 12.5, "rescaled from 10, not from 15");
+
+        app.world_mut().get_mut::<TextFont>(text).unwrap().font_size = FontSize::Px(20.0);
+        app.update();
+        assert_eq!(px(&app, text), 25.0, "a re-authored size becomes the base");
+        app.update();
+        assert_eq!(px(&app, text), 25.0, "its own write is not re-authoring");
+    }
+
+    #[test]
+    fn world_text_keeps_its_size() {
+        let mut app = scale_app();
+        app.insert_resource(TextScale(1.5));
+        let label = app
+            .world_mut()
+            .spawn((Text2d::new("beacon"), TextFont::from_font_size(10.0)))
+            .id();
+        app.update();
+        assert_eq!(px(&app, label), 10.0);
+    }
+
+    #[test]
+    fn a_corrupt_scale_is_clamped() {
+        assert_eq!(TextScale(9.0).factor(), TextScale::MAX);
+        assert_eq!(TextScale(0.0).factor(), TextScale::MIN);
+    }
+}
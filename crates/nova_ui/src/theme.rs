@@ -102,8 +102,13 @@ pub const ICON: f32 = 44.0;
 /// visually. Per-widget tuned variants (the many slightly-different combat
 /// reds/ambers) intentionally stay local to their file; only the shared,
 /// exactly-repeated accents live here.
+///
+/// The accents are exposed twice: as the `Standard` consts, and through the
+/// [`Palette`](semantic::Palette) resource, whose colour-blind variants re-hue
+/// the allegiance, lock and objective accents. A HUD site that colours by
+/// meaning reads the resource so the player's choice reaches it.
 pub mod semantic {
-    use bevy::prelude::Color;
+    use bevy::prelude::*;
 
     /// Navigation / flight-computer accent (nav crosshair, flight chips).
     pub const NAV: Color = Color::srgba(0.3, 0.9, 1.0, 0.9);
@@ -119,6 +124,88 @@ pub mod semantic {
     /// The recurring dark readout backdrop (health bar, focus meter).
     pub const BACKDROP: Color = Color::srgba(0.15, 0.15, 0.15, 0.8);
 
+    /// Which hue set the semantic accents render in. Settings owns the choice
+    /// (through the accessibility settings); the HUD reads this resource.
+    ///
+    /// Only the meaning-carrying pairs a colour-blind player confuses are
+    /// re-hued - threat against ally, and both against the objective gold.
+    /// [`NAV`] and [`BACKDROP`] stay put in every variant. The variants lean on
+    /// the Okabe-Ito set: hue AND lightness differ, so a pair stays apart even
+    /// where the hue collapses.
+    #[derive(Resource, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    pub enum Palette {
+        /// The shipped hues (the consts above).
+        #[default]
+        Standard,
+        /// Red-green, green-weak: threat orange, ally blue, objective yellow.
+        Deuteranopia,
+        /// Red-green, red-weak: as deuteranopia, with a brighter threat so it
+        /// does not sink into the dark.
+        Protanopia,
+        /// Blue-yellow: threat red, ally teal, objective pink.
+        Tritanopia,
+    }
+
+    impl Palette {
+        /// Every variant in settings order.
+        pub const ALL: [Palette; 4] = [
+            Palette::Standard,
+            Palette::Deuteranopia,
+            Palette::Protanopia,
+            Palette::Tritanopia,
+        ];
+
+        /// Short display label for the segmented button.
+        pub fn label(self) -> &'static str {
+            match self {
+                Palette::Standard => "Standard",
+                Palette::Deuteranopia => "Deuteran",
+                Palette::Protanopia => "Protan",
+                Palette::Tritanopia => "Tritan",
+            }
+        }
+
+        /// The [`NAV`] accent. The same in every variant.
+        pub fn nav(self) -> Color {
+            NAV
+        }
+
+        /// The [`OBJECTIVE`] accent in this variant.
+        pub fn objective(self) -> Color {
+            match self {
+                Palette::Standard => OBJECTIVE,
+                Palette::Deuteranopia => Color::srgba(0.94, 0.89, 0.26, 0.95),
+                Palette::Protanopia => Color::srgba(1.0, 0.97, 0.55, 0.95),
+                Palette::Tritanopia => Color::srgba(1.0, 0.6, 0.9, 0.95),
+            }
+        }
+
+        /// The [`THREAT`] accent in this variant.
+        pub fn threat(self) -> Color {
+            match self {
+                Palette::Standard | Palette::Tritanopia => THREAT,
+                Palette::Deuteranopia => Color::srgba(0.9, 0.45, 0.0, 1.0),
+                Palette::Protanopia => Color::srgba(1.0, 0.62, 0.1, 1.0),
+            }
+        }
+
+        /// The [`ALLY`] accent in this variant.
+        pub fn ally(self) -> Color {
+            match self {
+                Palette::Standard => ALLY,
+                Palette::Deuteranopia | Palette::Protanopia => Color::srgba(0.4, 0.6, 1.0, 1.0),
+                Palette::Tritanopia => Color::srgba(0.25, 0.85, 0.8, 1.0),
+            }
+        }
+
+        /// The [`NEUTRAL`] accent. The same in every variant: light steel is
+        /// already apart from every hue above.
+        pub fn neutral(self) -> Color {
+            NEUTRAL
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -136,5 +223,35 @@ pub mod semantic {
             assert_eq!(NEUTRAL, Color::srgba(0.85, 0.88, 0.9, 0.9));
             assert_eq!(BACKDROP, Color::srgba(0.15, 0.15, 0.15, 0.8));
         }
+
+        /// The standard palette IS the consts, so picking it changes nothing.
+        #[test]
+        fn the_standard_palette_is_the_shipped_accents() {
+            let palette = Palette::Standard;
+            assert_eq!(palette.nav(), NAV);
+            assert_eq!(palette.objective(), OBJECTIVE);
+            assert_eq!(palette.threat(), THREAT);
+            assert_eq!(palette.ally(), ALLY);
+            assert_eq!(palette.neutral(), NEUTRAL);
+        }
+
+        /// No variant may fold two meanings onto one colour.
+        #[test]
+        fn every_palette_keeps_its_accents_apart() {
+            for palette in Palette::ALL {
+                let accents = [
+                    palette.nav(),
+                    palette.objective(),
+                    palette.threat(),
+                    palette.ally(),
+                    palette.neutral(),
+                ];
+                for (i, a) in accents.iter().enumerate() {
+                    for b in &accents[i + 1..] {
+                        assert_ne!(a, b, "{palette:?} repeats an accent");
+                    }
+                }
+            }
+        }
     }
 }
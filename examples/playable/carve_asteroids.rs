@@ -806,6 +806,7 @@ fn rock(game_assets: &GameAssets, index: usize) -> ScenarioObjectConfig {
             // One seed for the whole row: the only thing that differs between
             // rocks is what has been shot off them.
             seed: Some(ROCK_SEED),
+            ore: Default::default(),
         }),
     }
 }
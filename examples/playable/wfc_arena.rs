@@ -1076,6 +1076,7 @@ fn rock_ring(
                 invulnerable: false,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
         asteroid_radius: Some(radius),
@@ -1319,6 +1320,7 @@ fn planetoid(game_assets: &GameAssets) -> EventActionConfig {
             invulnerable: true,
             seed: Some(PLANETOID_SEED),
             lock_signature: None,
+            ore: Default::default(),
         }),
     })
 }
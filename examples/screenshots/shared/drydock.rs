@@ -147,6 +147,7 @@ pub fn planetoid(game_assets: &GameAssets) -> EventActionConfig {
             invulnerable: true,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     })
 }
@@ -120,6 +120,7 @@ impl NearField {
                     invulnerable: false,
                     seed: None,
                     lock_signature: None,
+                    ore: Default::default(),
                 }),
             },
             asteroid_radius: Some(self.radius),
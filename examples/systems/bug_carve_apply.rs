@@ -314,6 +314,7 @@ fn rock(game_assets: &GameAssets) -> ScenarioObjectConfig {
             invulnerable: false,
             lock_signature: None,
             seed: Some(ROCK_SEED),
+            ore: Default::default(),
         }),
     }
 }
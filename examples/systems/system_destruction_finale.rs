@@ -707,6 +707,7 @@ fn finale_rig(game_assets: &GameAssets, sections: &GameSections) -> ScenarioConf
                                 invulnerable: false,
                                 lock_signature: None,
                                 seed: Some(7),
+                                ore: Default::default(),
                             }),
                         }),
                     ],
@@ -326,6 +326,7 @@ fn turret_range(game_assets: &GameAssets, sections: &GameSections, id: &str) ->
             invulnerable: false,
             seed: None,
             lock_signature: None,
+            ore: Default::default(),
         }),
     };
 
@@ -367,6 +368,7 @@ fn turret_range(game_assets: &GameAssets, sections: &GameSections, id: &str) ->
                 invulnerable: true,
                 seed: None,
                 lock_signature: None,
+                ore: Default::default(),
             }),
         },
     );
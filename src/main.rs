@@ -24,6 +24,12 @@ struct Cli {
     #[cfg(not(target_arch = "wasm32"))]
     #[arg(long, value_name = "PATH")]
     scenario_file: Option<std::path::PathBuf>,
+    /// Boot with this saved mod profile active (its enabled set and load
+    /// order). Wins over `NOVA_MOD_PROFILE`; an unknown name warns and keeps
+    /// the previously active set.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[arg(long, value_name = "NAME")]
+    mod_profile: Option<String>,
     #[cfg(feature = "debug")]
     #[arg(long)]
     debugdump: bool,
@@ -91,6 +97,13 @@ fn main() -> ExitCode {
 
     let mut app = editor_app(render, startup_scenario);
 
+    // AFTER the builder, for the same reason as `--mute` below: the assets
+    // plugin reads `NOVA_MOD_PROFILE` at its own build, and the flag wins.
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(name) = cli.mod_profile.clone() {
+        app.insert_resource(LaunchModProfile(Some(name)));
+    }
+
     // AFTER the builder: `NovaSettingsPlugin` resolves `HarnessMute` from the
     // environment at its own build, and the flag has to win over that. Only
     // when asked - without it the env answer stands, so `NOVA_MUTE=0` still
@@ -20,7 +20,8 @@
 //! state this whole set exists to make impossible.
 
 use nova_assets::{
-    mod_cache::MOD_CACHE_ROOT_ENV, portal::PORTAL_URL_ENV, storage::CONFIG_ROOT_ENV,
+    mod_cache::MOD_CACHE_ROOT_ENV, mod_profiles::MOD_PROFILE_ENV, portal::PORTAL_URL_ENV,
+    storage::CONFIG_ROOT_ENV,
 };
 use nova_autopilot::prelude::*;
 use nova_gameplay::prelude::{HARNESS_ENVS, MUTE_ENV};
@@ -31,6 +32,7 @@ use nova_probe::{
     STEPDIAG_BODIES_PARAM, STEPDIAG_PARAM, TIMELINE_PARAM, WARMUP_PARAM,
 };
 use nova_scenario::prelude::CAPTURE_DIR_ENV;
+use nova_ship::prelude::SKY_CACHE_DIR_ENV;
 
 /// The harness: what drives a run and where its pictures go. Owned by
 /// `nova_autopilot`.
@@ -83,6 +85,7 @@ fn the_modding_variables_carry_their_own_prefix_and_the_config_root_does_not() {
     assert_eq!(MOD_CACHE_ROOT_ENV, "NOVA_MODDING_CACHE_ROOT");
     assert_eq!(PORTAL_URL_ENV, "NOVA_MODDING_PORTAL_URL");
     assert_eq!(CONFIG_ROOT_ENV, "NOVA_CONFIG_ROOT");
+    assert_eq!(MOD_PROFILE_ENV, "NOVA_MOD_PROFILE");
 }
 
 /// The menu's capture pin, which belongs to the menu rather than to the
@@ -92,6 +95,13 @@ fn the_menu_backdrop_pin_is_the_documented_one() {
     assert_eq!(MENU_BACKDROP_ENV, "NOVA_MENU_BACKDROP");
 }
 
+/// The procedural sky cache. A shareable cache rather than state, so the probe
+/// sandbox leaves it alone.
+#[test]
+fn the_sky_cache_override_is_the_documented_one() {
+    assert_eq!(SKY_CACHE_DIR_ENV, "NOVA_SKY_CACHE_DIR");
+}
+
 /// Every `NOVA_*` name the game reads or writes, and the crate that declares
 /// it. The ROSTER, in the sense `catalog_drift` uses the word: the scan below
 /// walks the source and fails on anything not listed here.
@@ -138,10 +148,14 @@ const ROSTER: &[&str] = &[
     // Modding - nova_assets.
     "NOVA_MODDING_CACHE_ROOT",
     "NOVA_MODDING_PORTAL_URL",
+    // The launch mod profile - nova_assets, beside the store it names into.
+    "NOVA_MOD_PROFILE",
     // The settings store root - nova_assets, deliberately not modding.
     "NOVA_CONFIG_ROOT",
     // The menu's backdrop pin - nova_menu.
     "NOVA_MENU_BACKDROP",
+    // The procedural sky cache - nova_ship.
+    "NOVA_SKY_CACHE_DIR",
 ];
 
 /// Walk `crates/` and `src/` and fail on any `NOVA_*` string the roster above
@@ -241,4 +255,9 @@ fn every_re_spelled_name_matches_the_crate_that_owns_it() {
         MOD_CACHE_ROOT_ENV,
         "the probe sandbox redirects the mod cache nova_assets owns"
     );
+    assert_eq!(
+        nova_probe_cli::native::profile_sandbox::MOD_PROFILE_ENV,
+        MOD_PROFILE_ENV,
+        "probe hands a child its mod profile under the name nova_assets reads"
+    );
 }
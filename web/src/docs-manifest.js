@@ -169,7 +169,7 @@ const WIKI_PAGES = [
         category: "Interface",
         tags: ["ui"],
         summary:
-            "What the heads-up display shows: visibility tiers, the diegetic flight readouts, lock brackets and reticles, the target viewfinder, and the story comms panel.",
+            "What the heads-up display shows: visibility tiers, the diegetic flight readouts, lock brackets and reticles, the target viewfinder, the story comms panel, and the race timer.",
         related: ["targeting-radar", "flight-autopilot", "keybinds", "nova-os"],
         headings: [
             "Visibility tiers",
@@ -177,6 +177,7 @@ const WIKI_PAGES = [
             "Locks and reticles",
             "Target viewfinder",
             "Comms and objectives",
+            "Race timer",
         ],
     },
     {
@@ -215,7 +216,7 @@ const WIKI_PAGES = [
         category: "Flying",
         tags: ["flight"],
         summary:
-            "How ships move: Newtonian manual flight, center-of-mass thrust balancing, mass-legible handling, the GOTO / ORBIT / STOP autopilot verbs that fly the real hull, and RCS fine docking thrusters.",
+            "How ships move: Newtonian manual flight, center-of-mass thrust balancing, mass-legible handling, the GOTO / ORBIT / STOP autopilot verbs that fly the real hull, docking at stations, and RCS fine docking thrusters.",
         related: ["gravity-wells", "sections", "keybinds", "settings"],
         headings: [
             "Flight assist",
@@ -224,6 +225,7 @@ const WIKI_PAGES = [
             "GOTO",
             "ORBIT",
             "STOP",
+            "Docking",
             "RCS",
         ],
     },
@@ -295,12 +297,13 @@ const WIKI_PAGES = [
         category: "World",
         tags: ["world", "modding"],
         summary:
-            "What a scenario places into the world and how objectives are wired through events, filters and actions; the scenarios that ship today.",
+            "What a scenario places into the world and how objectives are wired through events, filters and actions; the scenarios that ship today, and watching a run again.",
         related: ["gravity-wells", "sections"],
         headings: [
             "Shipped scenarios",
             "Objectives and events",
             "Beacons and salvage",
+            "Watching a replay",
         ],
     },
 ];
@@ -504,7 +507,7 @@ const CREATE_PAGES = [
         toc: true,
         tags: ["modding", "reference"],
         summary:
-            "Everything that can fire a scenario handler: sixteen event kinds, payloads, lifecycle edges, and dispatch order.",
+            "Everything that can fire a scenario handler: twenty-one event kinds, payloads, lifecycle edges, and dispatch order.",
         related: ["filters", "actions", "scenarios"],
         headings: [
             "OnStart",
@@ -515,6 +518,10 @@ const CREATE_PAGES = [
             "OnNeutralized",
             "OnEnter",
             "OnExit",
+            "OnMined",
+            "Docking",
+            "OnCourseFinished",
+            "OnCameraTrackEnd",
             "Orbit lifecycle",
             "Lock lifecycle",
             "Dispatch order",
@@ -548,7 +555,7 @@ const CREATE_PAGES = [
         toc: true,
         tags: ["modding", "reference"],
         summary:
-            "All 25 actions a scenario handler can run, grouped by spawning, mission, flow, ship state, timers, and camera.",
+            "All 30 actions a scenario handler can run, grouped by spawning, mission, flow, ship state, timers, camera, and music.",
         related: ["objects", "events", "expressions"],
         headings: [
             "SpawnScenarioObject",
@@ -574,8 +581,10 @@ const CREATE_PAGES = [
             "VariableSet",
             "DebugMessage",
             "SetCamera",
+            "PlayCameraTrack",
             "Screenshot",
             "SetSkybox",
+            "SetPostProfile",
         ],
     },
     {
@@ -587,16 +596,19 @@ const CREATE_PAGES = [
         toc: true,
         tags: ["modding", "reference"],
         summary:
-            "The six spawnable scenario object kinds: Anchor, Asteroid, Spaceship, Beacon, SalvageCrate, and Light.",
+            "The eight spawnable scenario object kinds: Anchor, Asteroid, Spaceship, Beacon, SalvageCrate, Station, Course, and Light.",
         related: ["actions", "base-content", "sections"],
         headings: [
             "Anchor",
             "Asteroid",
+            "Mining",
             "Spaceship",
             "The controller",
             "The sections list",
             "Beacon",
             "SalvageCrate",
+            "Station",
+            "Course",
             "Light",
             "Traps for the unwary",
         ],
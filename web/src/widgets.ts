@@ -4924,8 +4924,44 @@ const NOVA_OS_COMMANDS: NovaOsCommand[] = [
         summary: "Repair a section",
         dispatch: "action",
         outcome:
-            "Repairs that section on the live ship and prints the " +
-            "restored integrity.",
+            "Starts a damage-control repair on that section for one " +
+            "part; its integrity climbs until full or until it is hit.",
+    },
+    {
+        name: "ship rebuild [id]",
+        summary: "List or rebuild lost sections",
+        dispatch: "action",
+        outcome:
+            "Bare, lists the lost sections; with an id, starts a " +
+            "15-second rebuild for three parts, out of combat or docked.",
+    },
+    {
+        name: "dock",
+        summary: "Show the station you are docked at",
+        dispatch: "action",
+        outcome: "Prints the station you are docked at and what it offers.",
+    },
+    {
+        name: "dock rearm",
+        summary: "Fill every magazine",
+        dispatch: "action",
+        outcome: "Fills every weapon magazine at once, free.",
+    },
+    {
+        name: "dock repair",
+        summary: "Restore every section and restock parts",
+        dispatch: "action",
+        outcome:
+            "Restores every standing section at once and restocks " +
+            "damage control to the ship's full allowance.",
+    },
+    {
+        name: "dock refit [section] [part]",
+        summary: "List or swap a section's part",
+        dispatch: "action",
+        outcome:
+            "Bare, lists each section with the parts it could take; " +
+            "with both, swaps that section for another part of its kind.",
     },
 ];
 